@@ -0,0 +1,220 @@
+// examples/vault_integration/src/lib.rs
+//
+// VAULT INTEGRATION EXAMPLE - PARTNER PROGRAM
+// ===========================================
+//
+// AUDIT NOTES:
+// Living integration code for partners building on the H2Coin vault. It is compiled
+// against the real h2coin_vault_share crate (feature `cpi`), so any change to the
+// deposit accounts or the InvestmentInfo layout breaks this build first.
+// - deposit_to_vault: CPI into deposit_token_to_vault on behalf of the signing payer
+// - read_investment_state: typed, owner-checked read of InvestmentInfo
+//
+// SECURITY CONSIDERATIONS:
+// - The example holds no funds and signs nothing itself; the payer signature is
+//   forwarded unchanged, so the vault program performs every check
+// - InvestmentInfo is loaded as Account<InvestmentInfo>, which checks the owner is
+//   h2coin_vault_share and the discriminator matches
+// - PDA seeds are re-derived under the vault program id (seeds::program)
+
+#![allow(unexpected_cfgs)]
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    memo::Memo,
+    token::{Mint, Token, TokenAccount},
+};
+
+use h2coin_vault_share::cpi::accounts::DepositTokenToVault;
+use h2coin_vault_share::program::H2coinVaultShare;
+use h2coin_vault_share::state::{GlobalConfig, InvestmentInfo, InvestmentState};
+
+// Program ID - CRITICAL: This must match the deployed example program address
+declare_id!("2DFZyndqTv9YW7o89Pc88vfepcJaAEou4ojM4YuLN8oM");
+
+/// Example partner program
+///
+/// AUDIT NOTES:
+/// - Demonstrates the two integration points partners need: depositing and reading state
+/// - Not part of the protocol; deploy only to localnet / devnet
+#[program]
+pub mod vault_integration {
+
+    use super::*;
+
+    /// Deposit the profit mint (USDT, USDC) or H2COIN into an investment vault through CPI
+    ///
+    /// AUDIT CRITICAL:
+    /// - Pre-checks the investment state so partners fail early with their own error
+    /// - The vault program repeats every check; the pre-check is a convenience only
+    /// - reference_id is forwarded unchanged into the vault's deposit event
+    pub fn deposit_to_vault(
+        ctx: Context<DepositToVault>,
+        amount: u64,
+        reference_id: Option<[u8; 16]>,
+    ) -> Result<()> {
+        let info = &ctx.accounts.investment_info;
+
+        // AUDIT: Deposits are only accepted by active, completed investments
+        require!(
+            info.is_active && info.state == InvestmentState::Completed,
+            IntegrationError::InvestmentNotAcceptingDeposits
+        );
+
+        // AUDIT: The payer signed the outer transaction; its signature carries through the CPI
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.vault_program.to_account_info(),
+            DepositTokenToVault {
+                investment_info: ctx.accounts.investment_info.to_account_info(),
+                global_config: ctx.accounts.global_config.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.from.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                memo_program: ctx.accounts.memo_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            },
+        );
+        h2coin_vault_share::cpi::deposit_token_to_vault(cpi_ctx, amount, reference_id)?;
+
+        msg!("🟢 Deposited {} through vault_integration", amount);
+        Ok(())
+    }
+
+    /// Read an investment's state and return it as transaction return data
+    ///
+    /// AUDIT: Read-only; clients obtain the snapshot by simulating the instruction
+    pub fn read_investment_state(ctx: Context<ReadInvestmentState>) -> Result<InvestmentSnapshot> {
+        let info = &ctx.accounts.investment_info;
+
+        Ok(InvestmentSnapshot {
+            investment_id: info.investment_id,
+            version: info.version,
+            state: info.state.clone(),
+            is_active: info.is_active,
+            vault: info.vault,
+            investment_upper_limit: info.investment_upper_limit,
+            withdraw_sequence: info.withdraw_sequence,
+            paused_families: info.paused_families,
+            frozen: info.frozen_at != 0,
+            profit_mint: info.profit_mint,
+        })
+    }
+}
+
+/// Summary of an InvestmentInfo returned by read_investment_state
+///
+/// AUDIT: A copy for display and routing decisions; never an authorization input
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InvestmentSnapshot {
+    pub investment_id: [u8; 15],
+    pub version: [u8; 4],
+    pub state: InvestmentState,
+    pub is_active: bool,
+    pub vault: Pubkey,
+    pub investment_upper_limit: u64,
+    pub withdraw_sequence: u64,
+    pub paused_families: u8,
+    pub frozen: bool,
+    pub profit_mint: Pubkey,
+}
+
+/// Account validation context for depositing through CPI
+///
+/// AUDIT CRITICAL:
+/// - Mirrors DepositTokenToVault of h2coin_vault_share
+/// - investment_info and vault seeds are checked under the vault program id
+#[derive(Accounts)]
+pub struct DepositToVault<'info> {
+    /// InvestmentInfo of the target investment
+    ///
+    /// AUDIT: Owner and discriminator checked by Account<InvestmentInfo>
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump,
+        seeds::program = vault_program.key()
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// GlobalConfig of the vault program (H2COIN mint)
+    /// 
+    /// AUDIT: Owner and discriminator checked by Account<GlobalConfig>
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump,
+        seeds::program = vault_program.key()
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Token mint (the investment's profit_mint or H2COIN)
+    pub mint: Account<'info, Mint>,
+
+    /// Payer's source token account
+    #[account(mut)]
+    pub from: Account<'info, TokenAccount>,
+
+    /// Vault PDA of the investment
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump,
+        seeds::program = vault_program.key()
+    )]
+    /// CHECK: Vault PDA holding SOL; verified by seeds and again by the vault program
+    pub vault: UncheckedAccount<'info>,
+
+    /// Vault associated token account for the mint
+    #[account(mut)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Depositor and fee payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The H2Coin vault share program
+    pub vault_program: Program<'info, H2coinVaultShare>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program; the vault program tags the deposit with a memo
+    pub memo_program: Program<'info, Memo>,
+
+    /// Associated token program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for reading investment state
+///
+/// AUDIT: Read-only; no signer beyond the fee payer of the simulation
+#[derive(Accounts)]
+pub struct ReadInvestmentState<'info> {
+    /// InvestmentInfo to read
+    ///
+    /// AUDIT: Owner and discriminator checked by Account<InvestmentInfo>
+    pub investment_info: Account<'info, InvestmentInfo>,
+}
+
+/// Errors of the example program
+#[error_code]
+pub enum IntegrationError {
+    /// The investment is inactive or not completed
+    #[msg("🔴 Investment is not accepting deposits.")]
+    InvestmentNotAcceptingDeposits,
+}
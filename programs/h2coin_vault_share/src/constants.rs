@@ -28,7 +28,7 @@ use anchor_lang::prelude::*;
 /// AUDIT CRITICAL:
 /// - Different mint addresses for different networks
 /// - Must match actual deployed token addresses
-/// - Used for token validation in all operations
+/// - Default of GlobalConfig; instructions read the mint from that account
 /// 
 /// SECURITY IMPLICATIONS:
 /// - Incorrect mint address could lead to token loss
@@ -52,12 +52,45 @@ pub fn get_usdt_mint() -> Pubkey {
     }
 }
 
+/// Stablecoin mints an investment may distribute profits in
+/// 
+/// AUDIT CRITICAL:
+/// - Default of GlobalConfig.allowed_profit_mints, which initialize_investment_info checks
+/// - The chosen mint is stored in InvestmentInfo.profit_mint and never changes
+/// - Further stablecoins are added through set_global_config
+/// 
+/// SECURITY IMPLICATIONS:
+/// - An allowlist keeps arbitrary or malicious mints out of profit distribution
+/// - All listed mints are SPL Token mints with 6 decimals, like USDT
+/// 
+/// NETWORK MAPPING:
+/// - localnet: USDT only
+/// - devnet: USDT, USDC 4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU
+/// - mainnet: USDT, USDC EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
+pub fn get_allowed_profit_mints() -> Vec<Pubkey> {
+    if cfg!(feature = "localnet") {
+        vec![get_usdt_mint()]
+    } else if cfg!(feature = "devnet") {
+        vec![
+            get_usdt_mint(),
+            // Devnet USDC mint (Circle faucet)
+            pubkey!("4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU"),
+        ]
+    } else {
+        vec![
+            get_usdt_mint(),
+            // Mainnet USDC mint (production)
+            pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+        ]
+    }
+}
+
 /// Get H2COIN mint address based on network configuration
 /// 
 /// AUDIT CRITICAL:
 /// - Different mint addresses for different networks
 /// - Must match actual deployed token addresses
-/// - Used for token validation in all operations
+/// - Default of GlobalConfig; instructions read the mint from that account
 /// 
 /// SECURITY IMPLICATIONS:
 /// - Incorrect mint address could lead to token loss
@@ -95,6 +128,26 @@ pub fn get_hcoin_mint() -> Pubkey {
 /// - Prevents DoS through oversized whitelist validation
 pub const MAX_WHITELIST_LEN: usize = 5;
 
+/// Number of matching whitelist signatures required by unweighted multisig checks
+/// 
+/// AUDIT CRITICAL:
+/// - The "3" of 3-of-5; applies whenever no SignerWeights override is configured
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Must stay a strict majority of MAX_WHITELIST_LEN
+pub const MULTISIG_THRESHOLD: usize = 3;
+
+/// Length of a whitelist member label, e.g. "CFO" or "Custodian-A" (UTF-8, zero-padded)
+/// 
+/// AUDIT: Display metadata only; labels never take part in authorization
+pub const WHITELIST_LABEL_LEN: usize = 16;
+
+/// Maximum length in bytes of a distribution note, e.g. "2025 Q2 profit, batch 7"
+/// 
+/// AUDIT: Bounds the SPL Memo attached to every transfer of an execution, which stays
+/// within CU_PER_TRANSFER
+pub const MAX_TRANSFER_NOTE_LEN: usize = 64;
+
 /// Maximum number of supported investment stages
 /// 
 /// AUDIT CRITICAL:
@@ -109,6 +162,18 @@ pub const MAX_WHITELIST_LEN: usize = 5;
 /// - Limits complexity of investment structures
 pub const MAX_STAGE: usize = 3;
 
+/// Neutral profit stage multiplier in basis points (1×)
+/// 
+/// AUDIT: Recorded on profit entries estimated while stage weighting is off
+pub const PROFIT_STAGE_MULTIPLIER_ONE_BP: u16 = 10_000;
+
+/// Maximum profit stage multiplier in basis points (5×)
+/// 
+/// AUDIT CRITICAL:
+/// - Bounds set_profit_stage_multipliers
+/// - Keeps weighted USDT amounts well inside u64 and ratio_bp inside u16
+pub const MAX_PROFIT_STAGE_MULTIPLIER_BP: u16 = 50_000;
+
 /// Maximum number of investment record entries per profit/refund batch
 /// 
 /// AUDIT CRITICAL:
@@ -123,11 +188,22 @@ pub const MAX_STAGE: usize = 3;
 /// - Prevents account size from exceeding Solana limits
 pub const MAX_ENTRIES_PER_BATCH: usize = 30;
 
+/// Maximum number of payout wallets in an account-level payout split
+/// 
+/// AUDIT CRITICAL:
+/// - Each split wallet becomes its own cache entry during estimation
+/// - Kept small so split batches still fit MAX_ENTRIES_PER_BATCH
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Bounds the number of transfers a single record can fan out into
+pub const MAX_PAYOUT_SPLITS: usize = 3;
+
 /// Maximum duration (in seconds) that ProfitShareCache or RefundShareCache remains valid
 /// 
 /// AUDIT CRITICAL:
 /// - Prevents stale cache execution
 /// - Forces re-estimation after expiration
+/// - Default and hard ceiling of GlobalConfig.share_cache_expire_secs
 /// - Default: 25 days × 86400 seconds/day = 2,160,000 seconds
 /// 
 /// SECURITY IMPLICATIONS:
@@ -138,6 +214,40 @@ pub const MAX_ENTRIES_PER_BATCH: usize = 30;
 /// - Ensures calculations reflect current market conditions
 pub const SHARE_CACHE_EXPIRE_SECS: i64 = 25 * 86400;
 
+/// Default minimum interval (in seconds) between estimates of the same cache
+/// 
+/// AUDIT CRITICAL:
+/// - Stored per investment in InvestmentConfig.min_estimate_interval_secs
+/// - Default: 1 hour
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Keeps cache contents stable between co-signer review and execution signing
+/// - Prevents an operator from thrashing a cache with rapid re-estimates
+pub const DEFAULT_MIN_ESTIMATE_INTERVAL_SECS: u32 = 3600;
+
+/// Holding period (in seconds) for the share of a revoked record
+/// 
+/// AUDIT CRITICAL:
+/// - Default: 30 days × 86400 seconds/day = 2,592,000 seconds
+/// - Inside the window, estimations keep the record's share as an escrowed entry
+/// - The investor can dispute the revocation with contest_revocation during the window
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Revoked funds are not redistributable to others until the window passes
+/// - A contested record stays escrowed until the committee resolves the contest
+pub const REVOCATION_HOLD_SECS: i64 = 30 * 86400;
+
+/// Window (in seconds) in which an investor must acknowledge a provisional record
+/// 
+/// AUDIT CRITICAL:
+/// - Default: 14 days × 86400 seconds/day = 1,209,600 seconds
+/// - Counted from record creation; acknowledge_record fails afterwards
+/// 
+/// SECURITY IMPLICATIONS:
+/// - A provisional record never takes part in distributions until acknowledged
+/// - An expired provisional record stays inert; the committee revokes and re-adds it
+pub const RECORD_ACK_WINDOW_SECS: i64 = 14 * 86400;
+
 /// The starting year index (0-based) when refund distributions begin
 /// 
 /// AUDIT CRITICAL:
@@ -168,6 +278,19 @@ pub const START_YEAR_INDEX: u8 = 3;
 /// - Prevents infinite refund calculations
 pub const MAX_YEAR_INDEX: u8 = 9;
 
+/// Maximum waivers accepted by one archive_investment call
+/// 
+/// AUDIT: Bounds the instruction data and the event size; an investment needing more
+/// waivers settles the missing rounds first
+pub const MAX_SETTLEMENT_WAIVERS: usize = 32;
+
+/// Length of one refund year in seconds (365 days, no leap days)
+/// 
+/// AUDIT CRITICAL:
+/// - year_index k covers [end_at + k × SECONDS_PER_YEAR, end_at + (k + 1) × SECONDS_PER_YEAR)
+/// - Every year_index computation goes through YearWindow, which uses this value
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
 /// Estimated base SOL cost for executing a profit or refund share instruction
 /// 
 /// AUDIT CRITICAL:
@@ -180,7 +303,7 @@ pub const MAX_YEAR_INDEX: u8 = 9;
 /// - Underestimation could lead to failed transactions
 /// - Overestimation wastes user funds
 /// - Affects transaction success rates
-/// - Must be updated if network fees change
+/// - Default of GlobalConfig.estimate_sol_base; changed through set_global_config
 pub const ESTIMATE_SOL_BASE: u64 = 100_000;
 
 /// Estimated SOL cost per entry in a batch execution
@@ -195,5 +318,281 @@ pub const ESTIMATE_SOL_BASE: u64 = 100_000;
 /// - Underestimation could lead to failed transactions
 /// - Overestimation wastes user funds
 /// - Affects batch size optimization
-/// - Must be updated if token transfer costs change
+/// - Default of GlobalConfig.estimate_sol_per_entry; changed through set_global_config
 pub const ESTIMATE_SOL_PER_ENTRY: u64 = 5_000;
+
+
+/// Number of failed payout attempts after which a cache entry is escrowed
+/// 
+/// AUDIT CRITICAL:
+/// - Each failed transfer increments the entry's failure_count
+/// - Once failure_count reaches this value the entry stops being retried
+/// - The entry amount stays in the vault until release_escrowed_entry requeues it
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Prevents a single broken recipient from blocking a batch forever
+/// - Escrowed amounts are excluded from push retries, preventing double payment
+pub const MAX_ENTRY_FAILURES: u8 = 3;
+
+/// Decimal convention of record amounts and estimation inputs
+/// 
+/// AUDIT CRITICAL:
+/// - InvestmentRecord.amount_usdt / amount_hcoin, total_profit_usdt and total_invest_usdt
+///   are always expressed with 6 decimals, the convention of USDT and H2COIN
+/// - Estimation converts every computed share to the decimals of the mint that pays it
+///   (InvestmentInfo.profit_decimals / hcoin_decimals)
+/// 
+/// SECURITY IMPLICATIONS:
+/// - A mint with other decimals pays the same value instead of a silently mis-scaled amount
+pub const AMOUNT_DECIMALS: u8 = 6;
+
+/// Fixed-point scale for the H2COIN-per-USDT conversion rate
+/// 
+/// AUDIT CRITICAL:
+/// - HcoinRateRange bounds are expressed as amount_hcoin * RATE_SCALE / amount_usdt
+/// - 1_000_000 gives six decimal places of rate precision
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Changing this value reinterprets every stored rate range
+pub const RATE_SCALE: u64 = 1_000_000;
+
+/// Maximum number of planned rounds held by a DistributionSchedule
+/// 
+/// AUDIT CRITICAL:
+/// - Bounds the DistributionSchedule account size
+/// - Covers yearly profit rounds plus every refund year of a 10-year investment
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Prevents unbounded account growth
+pub const MAX_SCHEDULE_SLOTS: usize = 32;
+
+/// Compute units reserved per payout transfer in execute_profit_share / execute_refund_share
+/// 
+/// AUDIT CRITICAL:
+/// - Covers one transfer_checked CPI, its SPL Memo CPI, the ATA lookup and entry bookkeeping
+/// - Used by the pre-transfer compute budget guard
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Too low lets a batch run out of compute mid-loop with an opaque error
+/// - Too high rejects batches that would have fit; split the batch or raise the CU limit
+pub const CU_PER_TRANSFER: u64 = 30_000;
+
+/// Compute units reserved after the payout loop
+/// 
+/// AUDIT CRITICAL:
+/// - Covers settlement checks, schedule update, the execution event and cache close
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Guarantees the transaction can finish once the last transfer has been made
+pub const CU_EXECUTE_TAIL: u64 = 30_000;
+
+/// Compute unit limit of a single transaction
+/// 
+/// AUDIT CRITICAL:
+/// - The most SetComputeUnitLimit can request
+/// - A full MAX_ENTRIES_PER_BATCH batch must be reservable by the compute budget guard
+///   within it (30 * CU_PER_TRANSFER + CU_EXECUTE_TAIL = 930,000 CU)
+pub const MAX_TX_COMPUTE_UNITS: u64 = 1_400_000;
+
+// AUDIT: Fails the build if the guard could never admit a full batch
+const _: () = assert!(
+    CU_PER_TRANSFER * MAX_ENTRIES_PER_BATCH as u64 + CU_EXECUTE_TAIL <= MAX_TX_COMPUTE_UNITS
+);
+
+/// Maximum length of a scheduled program upgrade window
+/// 
+/// AUDIT CRITICAL:
+/// - Bounds how long an approved buffer stays deployable
+/// - Default: 7 days
+/// 
+/// SECURITY IMPLICATIONS:
+/// - A stale approval cannot be used long after the committee voted
+pub const MAX_UPGRADE_WINDOW_SECS: i64 = 7 * 86400;
+
+/// Shortest period of a standing operating allowance
+/// 
+/// AUDIT CRITICAL:
+/// - Prevents an allowance from being claimed many times in quick succession
+/// - Default: 1 day (a monthly allowance uses 30 days)
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Caps the outflow rate of a compromised or misconfigured allowance
+pub const MIN_ALLOWANCE_PERIOD_SECS: i64 = 86400;
+
+/// Number of record_ids covered by one emit_record_page page
+/// 
+/// AUDIT CRITICAL:
+/// - Page N covers record_id N * RECORD_PAGE_SIZE .. (N + 1) * RECORD_PAGE_SIZE
+/// - Bounded so the page fits in one transaction and one log entry
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Pages never overlap, so exports cannot double-count a record
+pub const RECORD_PAGE_SIZE: u64 = 20;
+
+/// Number of recent idempotency keys remembered per investment
+/// 
+/// AUDIT CRITICAL:
+/// - Ring buffer on InvestmentInfo; the oldest key is overwritten first
+/// - Covers whitelist patches, record wallet updates and withdrawals
+/// 
+/// SECURITY IMPLICATIONS:
+/// - A retried transaction is rejected while its key is still in the ring
+/// - Kept small because InvestmentInfo is loaded by every instruction
+pub const RECENT_IDEMPOTENCY_KEYS: usize = 8;
+
+/// Ceiling of a tenant's default platform fee in basis points
+/// 
+/// AUDIT CRITICAL:
+/// - Checked by initialize_tenant and update_tenant
+/// - Inherited by new investments of the tenant
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Bounds what a tenant admin can record as the platform fee (10%)
+pub const MAX_TENANT_FEE_BP: u16 = 1_000;
+
+/// Maximum entries in the exchange registry
+/// 
+/// AUDIT CRITICAL:
+/// - Bounds the ExchangeRegistry account size
+/// - Each entry is an exchange deposit program owner or hot wallet address
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Keeps the per-record screening cost constant
+pub const MAX_EXCHANGE_OWNERS: usize = 16;
+
+/// Maximum records linked by one InvestorDirectory
+/// 
+/// AUDIT CRITICAL:
+/// - Bounds the InvestorDirectory account size (program-wide, per account_id)
+/// - Record creation fails with InvestorDirectoryFull once reached
+pub const MAX_DIRECTORY_ENTRIES: usize = 64;
+
+/// Lifetime of a multisig proposal
+/// 
+/// AUDIT CRITICAL:
+/// - Approvals and execution are rejected after created_at + PROPOSAL_TTL_SECS
+/// - Long enough for geographically distributed signers to approve over several days
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Stale approvals cannot be executed long after the signers' intent
+pub const PROPOSAL_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Timelock between queuing and applying an execute / update whitelist patch
+/// 
+/// AUDIT CRITICAL:
+/// - patch_execute_whitelist and patch_update_whitelist only queue the change
+/// - apply_whitelist_patch activates it from queued_at + WHITELIST_PATCH_DELAY_SECS
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Three compromised keys cannot take over a whitelist instantly; the remaining
+///   members have this window to cancel
+pub const WHITELIST_PATCH_DELAY_SECS: i64 = 48 * 60 * 60;
+
+/// Distinct whitelist members needed to cancel a pending whitelist patch
+/// 
+/// AUDIT: Below the 3-of-5 threshold so the two members not involved in a
+/// compromised patch can still stop it
+pub const WHITELIST_PATCH_CANCEL_SIGNERS: u8 = 2;
+
+/// Number of RBAC roles (variants of Role)
+/// 
+/// AUDIT: Fixes the RoleConfig size; a new role requires a migration
+pub const ROLE_COUNT: usize = 5;
+
+/// Number of withdrawal tiers held by InvestmentInfo
+/// 
+/// AUDIT: Fixes the InvestmentInfo size; more tiers require a migration
+pub const MAX_WITHDRAW_TIERS: usize = 4;
+
+/// Maximum number of allowed profit mints held by GlobalConfig
+/// 
+/// AUDIT: Fixes the GlobalConfig size; more mints require a migration
+pub const MAX_PROFIT_MINTS: usize = 4;
+
+/// Maximum additional mints listed by one VaultTokenRegistry
+/// 
+/// AUDIT: Fixes the VaultTokenRegistry size; the profit mint and H2COIN are not counted
+pub const MAX_VAULT_MINTS: usize = 8;
+
+/// Maximum number of sibling hashes in a Merkle profit claim proof
+/// 
+/// AUDIT: Bounds the compute of claim_profit_with_proof; 24 levels cover 16,777,216 entries
+pub const MAX_MERKLE_PROOF_LEN: usize = 24;
+
+/// Bounds of InvestmentInfo.withdraw_delay_secs accepted by set_withdraw_delay
+/// 
+/// AUDIT CRITICAL:
+/// - propose_withdrawal schedules execution withdraw_delay_secs later
+/// 
+/// SECURITY IMPLICATIONS:
+/// - The lower bound leaves off-chain monitoring a full day to react and cancel
+/// - The upper bound keeps legitimate withdrawals from being stalled indefinitely
+pub const MIN_WITHDRAW_DELAY_SECS: u32 = 24 * 60 * 60;
+pub const MAX_WITHDRAW_DELAY_SECS: u32 = 72 * 60 * 60;
+
+/// Buckets of the rolling withdrawal rate-limit window
+/// 
+/// AUDIT CRITICAL:
+/// - The window is tracked as RATE_LIMIT_BUCKETS buckets of window_secs / RATE_LIMIT_BUCKETS
+/// - Fixes the WithdrawRateLimit size; the window is exact to one bucket
+pub const RATE_LIMIT_BUCKETS: usize = 8;
+
+/// Bounds of WithdrawRateLimit.window_secs accepted by set_withdraw_rate_limit
+/// 
+/// AUDIT: 0 turns the rate limit off; otherwise between one hour and 30 days
+pub const MIN_RATE_LIMIT_WINDOW_SECS: u32 = 60 * 60;
+pub const MAX_RATE_LIMIT_WINDOW_SECS: u32 = 30 * 24 * 60 * 60;
+
+/// Minimum spacing between two checkpoints of an investment
+/// 
+/// AUDIT: emit_checkpoint is permissionless; the interval keeps the event stream
+/// and the checkpoint sequence from being flooded
+pub const CHECKPOINT_MIN_INTERVAL_SECS: i64 = 10 * 60;
+
+/// Instruction family bits of InvestmentInfo.paused_families
+/// 
+/// AUDIT CRITICAL:
+/// - Set by the update committee through set_instruction_pause
+/// - Each protected instruction checks its own family bit
+/// 
+/// SECURITY IMPLICATIONS:
+/// - Finer-grained incident response than the program-wide pause, e.g. stopping
+///   withdrawals while distributions continue
+/// 
+/// Records: add_investment_record, update_investment_record_wallets,
+/// revoked_investment_record, bind_wallet
+pub const PAUSE_RECORDS: u8 = 1 << 0;
+
+/// Estimation: estimate_profit_share, estimate_refund_share
+pub const PAUSE_ESTIMATE: u8 = 1 << 1;
+
+/// Distribution: execute_profit_share, execute_refund_share, settle_accrual
+pub const PAUSE_DISTRIBUTION: u8 = 1 << 2;
+
+/// Withdrawal: withdraw_from_vault, propose_withdrawal, execute_withdrawal,
+/// claim_operating_allowance
+pub const PAUSE_WITHDRAW: u8 = 1 << 3;
+
+/// Deposit: deposit_sol_to_vault, deposit_token_to_vault
+pub const PAUSE_DEPOSIT: u8 = 1 << 4;
+
+/// Every defined instruction family
+pub const PAUSE_ALL_FAMILIES: u8 =
+    PAUSE_RECORDS | PAUSE_ESTIMATE | PAUSE_DISTRIBUTION | PAUSE_WITHDRAW | PAUSE_DEPOSIT;
+
+/// Instruction families blocked by an emergency freeze
+/// 
+/// AUDIT CRITICAL:
+/// - freeze_investment (any single execute / update member) stops every instruction
+///   that moves funds out of the vault
+/// - Independent of paused_families: set_instruction_pause cannot lift a freeze,
+///   only unfreeze_investment can
+pub const FREEZE_FAMILIES: u8 = PAUSE_DISTRIBUTION | PAUSE_WITHDRAW;
+
+
+/// Squads v4 multisig program id (same address on every cluster)
+/// 
+/// AUDIT CRITICAL:
+/// - InvestmentConfig.squads_authority is always derived under this program
+/// - A wrong id would accept a vault PDA nobody's Squads quorum controls
+pub const SQUADS_PROGRAM_ID: Pubkey = pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
@@ -22,11 +22,14 @@
 // - Proper account mutability flags
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_spl::{
     associated_token::AssociatedToken,
+    memo::Memo,
     token::{Mint, Token, TokenAccount},
 };
 
+use crate::program::H2coinVaultShare;
 use crate::state::*;
 
 /// Account validation context for initializing investment info
@@ -64,9 +67,37 @@ pub struct InitializeInvestmentInfo<'info> {
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// USDT mint account for validation
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
     /// 
-    /// AUDIT: Must match expected USDT mint address
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig PDA account to be created
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Holds whitelists and stage ratios (cold data)
+    /// - Fixed size allocation prevents overflow
+    #[account(
+        init,
+        payer = payer,
+        space = InvestmentConfig::SIZE,
+        seeds = [
+            b"investment_config", 
+            investment_id.as_ref(), 
+            version.as_ref()
+        ],
+        bump,
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Profit mint of the new investment (USDT or another allowlisted stablecoin)
+    /// 
+    /// AUDIT: Must be listed in GlobalConfig.allowed_profit_mints; stored as InvestmentInfo.profit_mint
     pub usdt_mint: Account<'info, Mint>,
     
     /// H2COIN mint account for validation
@@ -125,6 +156,22 @@ pub struct InitializeInvestmentInfo<'info> {
     )]
     pub vault_hcoin_account: Account<'info, TokenAccount>,
 
+    /// Tenant the investment is created under (optional)
+    /// 
+    /// AUDIT: When provided, tenant_admin must sign and the tenant defaults are inherited
+    #[account(
+        mut,
+        seeds = [b"tenant", tenant.tenant_id.as_ref()],
+        bump = tenant.bump
+    )]
+    pub tenant: Option<Account<'info, Tenant>>,
+
+    /// Tenant admin (required together with tenant)
+    /// 
+    /// AUDIT: Checked against tenant.admin in instruction; separate from payer so the
+    /// admin key can stay cold
+    pub tenant_admin: Option<Signer<'info>>,
+
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for account creation and rent
@@ -178,109 +225,103 @@ pub struct UpdateInvestmentInfo<'info> {
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
-    
-    /// Transaction payer account
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
     /// 
-    /// AUDIT: Pays for transaction fees
-    pub payer: Signer<'info>,
-}
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
 
-/// Account validation context for completing investment info
-/// 
-/// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from update_whitelist
-/// - Changes investment state to Completed
-/// - Prevents further modifications
-/// 
-/// SECURITY CHECKS:
-/// - Investment info PDA validation
-/// - Investment state validation
-/// - Multisig validation through remaining_accounts
-#[derive(Accounts)]
-pub struct CompletedInvestmentInfo<'info> {
-    /// InvestmentInfo account to be completed
+    /// InvestmentConfig account containing stage ratios and rate range
     /// 
     /// AUDIT CRITICAL:
-    /// - Must be mutable for state change
+    /// - Must be mutable for configuration updates
     /// - PDA validation prevents spoofing
-    /// - State validation prevents invalid completion
+    /// - Companion of investment_info (same investment_id and version)
     #[account(
         mut,
         seeds = [
-            b"investment",
+            b"investment_config",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.config_bump
     )]
-    pub investment_info: Account<'info, InvestmentInfo>,
-    
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Approved Proposal authorizing this call instead of co-signers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Optional; when omitted, signers are passed through remaining_accounts
+    /// - Investment, action and approvals validated in instruction; marked executed
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for transaction fees
-    #[account(mut)]
     pub payer: Signer<'info>,
 }
 
-/// Account validation context for deactivating investment info
+/// Account validation context for simulating a stage ratio change
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from update_whitelist
-/// - Only allowed when investment is completed
-/// - Prevents all further operations
+/// - Read-only and permissionless: nothing is written
 /// 
 /// SECURITY CHECKS:
-/// - Investment info PDA validation
-/// - Investment state validation (must be completed)
-/// - Multisig validation through remaining_accounts
+/// - Investment info validation
+/// - Sample record PDA validation in instruction
 #[derive(Accounts)]
-pub struct DeactivateInvestmentInfo<'info> {
-    /// InvestmentInfo account to be deactivated
+pub struct SimulateStageRatio<'info> {
+    /// InvestmentInfo account for validation
     /// 
-    /// AUDIT CRITICAL:
-    /// - Must be mutable for deactivation
-    /// - PDA validation prevents spoofing
-    /// - State validation prevents invalid deactivation
+    /// AUDIT: Provides investment_id and version for record PDA derivation
     #[account(
-        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
+    /// Sample InvestmentRecord
+    /// 
+    /// AUDIT: Supplies stage and amount_hcoin for the simulation
+    pub sample_record: Account<'info, InvestmentRecord>,
+
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for transaction fees
-    #[account(mut)]
     pub payer: Signer<'info>,
 }
 
-/// Account validation context for updating execute whitelist
+/// Account validation context for completing investment info
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from current execute_whitelist
-/// - Allows replacement of whitelist members
-/// - Affects profit/refund execution authorization
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Changes investment state to Completed
+/// - Prevents further modifications
 /// 
 /// SECURITY CHECKS:
 /// - Investment info PDA validation
 /// - Investment state validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-pub struct UpdateExecuteWallet<'info> {
-    /// InvestmentInfo account containing whitelist
+pub struct CompletedInvestmentInfo<'info> {
+    /// InvestmentInfo account to be completed
     /// 
     /// AUDIT CRITICAL:
-    /// - Must be mutable for whitelist updates
+    /// - Must be mutable for state change
     /// - PDA validation prevents spoofing
-    /// - Contains execute_whitelist to be updated
+    /// - State validation prevents invalid completion
     #[account(
         mut,
         seeds = [
@@ -288,10 +329,23 @@ pub struct UpdateExecuteWallet<'info> {
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+    
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for transaction fees
@@ -299,25 +353,23 @@ pub struct UpdateExecuteWallet<'info> {
     pub payer: Signer<'info>,
 }
 
-/// Account validation context for updating update whitelist
+/// Account validation context for cancelling investment info
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from current update_whitelist
-/// - Allows replacement of whitelist members
-/// - Affects investment info update authorization
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Changes investment state from Pending to Cancelled
 /// 
 /// SECURITY CHECKS:
 /// - Investment info PDA validation
 /// - Investment state validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-pub struct UpdateUpdateWallet<'info> {
-    /// InvestmentInfo account containing whitelist
+pub struct CancelInvestmentInfo<'info> {
+    /// InvestmentInfo account to be cancelled
     /// 
     /// AUDIT CRITICAL:
-    /// - Must be mutable for whitelist updates
+    /// - Must be mutable for state change
     /// - PDA validation prevents spoofing
-    /// - Contains update_whitelist to be updated
     #[account(
         mut,
         seeds = [
@@ -325,10 +377,23 @@ pub struct UpdateUpdateWallet<'info> {
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+    
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for transaction fees
@@ -336,25 +401,23 @@ pub struct UpdateUpdateWallet<'info> {
     pub payer: Signer<'info>,
 }
 
-/// Account validation context for updating withdraw whitelist
+/// Account validation context for opening investment info
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from current withdraw_whitelist
-/// - Allows replacement of whitelist members
-/// - Affects vault withdrawal authorization
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Changes investment state from Init to Pending
 /// 
 /// SECURITY CHECKS:
 /// - Investment info PDA validation
 /// - Investment state validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-pub struct UpdateWithdrawWallet<'info> {
-    /// InvestmentInfo account containing whitelist
+pub struct OpenInvestment<'info> {
+    /// InvestmentInfo account to be opened
     /// 
     /// AUDIT CRITICAL:
-    /// - Must be mutable for whitelist updates
+    /// - Must be mutable for state change
     /// - PDA validation prevents spoofing
-    /// - Contains withdraw_whitelist to be updated
     #[account(
         mut,
         seeds = [
@@ -362,10 +425,23 @@ pub struct UpdateWithdrawWallet<'info> {
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+    
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for transaction fees
@@ -373,291 +449,164 @@ pub struct UpdateWithdrawWallet<'info> {
     pub payer: Signer<'info>,
 }
 
-/// Account validation context for adding investment records
+/// Account validation context for pausing instruction families
 /// 
 /// AUDIT CRITICAL:
-/// - Creates individual investment records
-/// - Transfers tokens from recipient to vault
-/// - Validates token accounts and amounts
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes InvestmentInfo.paused_families
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Investment record PDA derivation
-/// - Token account ownership validation
-/// - Token transfer validation
+/// - Investment info PDA validation
+/// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
-pub struct AddInvestmentRecords<'info> {
-    /// InvestmentInfo account for validation
+pub struct SetInstructionPause<'info> {
+    /// InvestmentInfo account holding the pause mask
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is active
-    /// - Provides investment parameters
-    /// - PDA validation prevents spoofing
+    /// AUDIT: Must be mutable to store the mask; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// InvestmentRecord account to be created
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Derived from investment_id, version, batch_id, record_id, account_id
-    /// - Fixed size allocation prevents overflow
-    /// - Stores individual investment details
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
     #[account(
-        init,
-        payer = payer,
-        space = InvestmentRecord::SIZE,
         seeds = [
-            b"record",
+            b"investment_config",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            record_id.to_le_bytes().as_ref(),
-            account_id.as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump,
-    )]
-    pub investment_record: Account<'info, InvestmentRecord>,
-
-    /// USDT mint account for validation
-    /// 
-    /// AUDIT: Must match expected USDT mint address
-    pub usdt_mint: Account<'info, Mint>,
-    
-    /// H2COIN mint account for validation
-    /// 
-    /// AUDIT: Must match expected H2COIN mint address
-    pub hcoin_mint: Account<'info, Mint>,
-    
-    /// Recipient account for token transfers
-    /// 
-    /// AUDIT CRITICAL:
-    /// - Source of token transfers to vault
-    /// - Manually validated in instruction
-    ///   CHECK: recipient lamport target, manually validated
-    pub recipient_account: UncheckedAccount<'info>,
-
-    /// Recipient associated token account for USDT
-    /// 
-    /// AUDIT CRITICAL:
-    /// - Source of USDT transfers
-    /// - Ownership validated against recipient
-    /// - Created if needed
-    #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = usdt_mint,
-        associated_token::authority = recipient_account,
-        associated_token::token_program = token_program,
+        bump = investment_info.config_bump
     )]
-    pub recipient_usdt_account: Account<'info, TokenAccount>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
-    /// Recipient associated token account for H2COIN
+    /// RBAC role configuration (optional)
     /// 
-    /// AUDIT CRITICAL:
-    /// - Source of H2COIN transfers
-    /// - Ownership validated against recipient
-    /// - Created if needed
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
     #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = hcoin_mint,
-        associated_token::authority = recipient_account,
-        associated_token::token_program = token_program,
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
     )]
-    pub recipient_hcoin_account: Account<'info, TokenAccount>,
+    pub role_config: Option<Account<'info, RoleConfig>>,
 
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for account creation and token transfers
-    #[account(mut)]
+    /// AUDIT: Pays for transaction fees
     pub payer: Signer<'info>,
-    
-    /// Rent sysvar for account creation
-    /// 
-    /// AUDIT: Required for account initialization
-    pub rent: Sysvar<'info, Rent>,
-    
-    /// System program for account creation
-    /// 
-    /// AUDIT: Required for account initialization
-    pub system_program: Program<'info, System>,
-    
-    /// Token program for token operations
-    /// 
-    /// AUDIT: Required for token transfers
-    pub token_program: Program<'info, Token>,
-    
-    /// Associated token program for ATA creation
-    /// 
-    /// AUDIT: Required for ATA creation
-    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-/// Account validation context for updating investment record wallets
+/// Account validation context for assigning an RBAC role
 /// 
 /// AUDIT CRITICAL:
 /// - Requires 3-of-5 multisig from update_whitelist
-/// - Updates wallet addresses for existing records
-/// - Affects future profit/refund distributions
+/// - RoleConfig PDA is created on first use
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Record existence validation
+/// - Investment info, config and role config PDA validation
 /// - Multisig validation through remaining_accounts
-/// - Token account validation
 #[derive(Accounts)]
-#[instruction(account_id: [u8; 15])]
-pub struct UpdateInvestmentRecordWallets<'info> {
+pub struct SetRoleAssignment<'info> {
     /// InvestmentInfo account for validation
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is active
-    /// - Provides investment parameters
-    /// - PDA validation prevents spoofing
+    /// AUDIT: Provides investment_id and version for PDA derivation
     #[account(
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// USDT mint account for validation
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT: Must match expected USDT mint address
-    pub usdt_mint: Account<'info, Mint>,
-    
-    /// H2COIN mint account for validation
-    /// 
-    /// AUDIT: Must match expected H2COIN mint address
-    pub hcoin_mint: Account<'info, Mint>,
-
-    /// New recipient account for token transfers
-    /// 
-    /// AUDIT CRITICAL:
-    /// - New destination for future distributions
-    /// - Manually validated in instruction
-    ///   CHECK: recipient lamport target, manually validated
-    pub recipient_account: UncheckedAccount<'info>,
-
-    /// New recipient associated token account for USDT
-    /// 
-    /// AUDIT CRITICAL:
-    /// - New destination for USDT distributions
-    /// - Ownership validated against recipient
-    /// - Created if needed
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
     #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = usdt_mint,
-        associated_token::authority = recipient_account,
-        associated_token::token_program = token_program,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
     )]
-    pub recipient_usdt_account: Account<'info, TokenAccount>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
-    /// New recipient associated token account for H2COIN
+    /// RBAC role configuration
     /// 
-    /// AUDIT CRITICAL:
-    /// - New destination for H2COIN distributions
-    /// - Ownership validated against recipient
-    /// - Created if needed
+    /// AUDIT: One per investment, created if needed
     #[account(
         init_if_needed,
         payer = payer,
-        associated_token::mint = hcoin_mint,
-        associated_token::authority = recipient_account,
-        associated_token::token_program = token_program,
+        space = RoleConfig::SIZE,
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
     )]
-    pub recipient_hcoin_account: Account<'info, TokenAccount>,
+    pub role_config: Account<'info, RoleConfig>,
 
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for ATA creation and transaction fees
-    /// CHECK: validated manually via 3-of-5 multisig inside instruction
+    /// AUDIT: Pays for the role config on first use
     #[account(mut)]
     pub payer: Signer<'info>,
-    
-    /// Rent sysvar for account creation
-    /// 
-    /// AUDIT: Required for ATA initialization
-    pub rent: Sysvar<'info, Rent>,
-    
-    /// System program for account creation
-    /// 
-    /// AUDIT: Required for account initialization
+
+    /// System program
     pub system_program: Program<'info, System>,
-    
-    /// Token program for token operations
-    /// 
-    /// AUDIT: Required for ATA creation
-    pub token_program: Program<'info, Token>,
-    
-    /// Associated token program for ATA creation
-    /// 
-    /// AUDIT: Required for ATA creation
-    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-/// Account validation context for revoking investment records
+/// Account validation context for setting the withdrawal tiers
 /// 
 /// AUDIT CRITICAL:
 /// - Requires 3-of-5 multisig from update_whitelist
-/// - Marks record as revoked with timestamp
-/// - Prevents record from distributions
+/// - Writes InvestmentInfo.withdraw_tiers
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Record existence validation
-/// - Record state validation (not already revoked)
+/// - Investment info and config PDA validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
-pub struct RevokeInvestmentRecord<'info> {
-    /// InvestmentInfo account for validation
+pub struct SetWithdrawTiers<'info> {
+    /// InvestmentInfo account holding the tiers
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is active
-    /// - Provides investment parameters
-    /// - PDA validation prevents spoofing
+    /// AUDIT: Must be mutable to store the tiers; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// InvestmentRecord account to be revoked
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Must be mutable for revocation
-    /// - PDA validation prevents spoofing
-    /// - State validation prevents double revocation
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
     #[account(
-        mut,
         seeds = [
-            b"record",
+            b"investment_config",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            record_id.to_le_bytes().as_ref(),
-            account_id.as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.config_bump
     )]
-    pub investment_record: Account<'info, InvestmentRecord>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
     /// Transaction payer account
     /// 
@@ -665,586 +614,673 @@ pub struct RevokeInvestmentRecord<'info> {
     pub payer: Signer<'info>,
 }
 
-/// Account validation context for estimating profit share
+/// Account validation context for setting the withdrawal timelock
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from execute_whitelist
-/// - Creates profit share cache for batch distribution
-/// - Calculates profit distribution amounts
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes InvestmentInfo.withdraw_delay_secs
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Investment type validation (Standard only)
-/// - Cache PDA derivation
+/// - Investment info and config PDA validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(batch_id: u16)]
-pub struct EstimateProfitShare<'info> {
-    /// InvestmentInfo account for validation
+pub struct SetWithdrawDelay<'info> {
+    /// InvestmentInfo account holding the delay
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is completed
-    /// - Provides investment parameters
-    /// - Investment type validation (Standard only)
+    /// AUDIT: Must be mutable to store the delay; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// ProfitShareCache account to be created
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Derived from investment_id, version, and batch_id
-    /// - Fixed size allocation prevents overflow
-    /// - Stores profit distribution calculations
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
     #[account(
-        init_if_needed,
-        payer = payer,
-        space = ProfitShareCache::SIZE,
         seeds = [
-            b"profit_cache", 
+            b"investment_config",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump,
+        bump = investment_info.config_bump
     )]
-    pub cache: Account<'info, ProfitShareCache>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for cache creation and transaction fees
-    #[account(mut)]
+    /// AUDIT: Pays for transaction fees
     pub payer: Signer<'info>,
-    
-    /// Rent sysvar for account creation
-    /// 
-    /// AUDIT: Required for cache initialization
-    pub rent: Sysvar<'info, Rent>,
-    
-    /// System program for account creation
-    /// 
-    /// AUDIT: Required for cache initialization
-    pub system_program: Program<'info, System>,
 }
 
-/// Account validation context for estimating refund share
+/// Account validation context for setting the caps of a withdrawal purpose
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from execute_whitelist
-/// - Creates refund share cache for batch distribution
-/// - Calculates refund distribution amounts
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes the caps of one WithdrawalBudget line; totals are left untouched
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Year index validation (3-9)
-/// - Cache PDA derivation
+/// - Investment info, config and budget PDA validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(batch_id: u16, year_index: u8)]
-pub struct EstimateRefundShare<'info> {
+pub struct SetWithdrawBudget<'info> {
     /// InvestmentInfo account for validation
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is completed
-    /// - Provides investment parameters and stage ratios
-    /// - Used for refund percentage calculations
+    /// AUDIT: Provides investment_id and version for PDA derivation
     #[account(
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// RefundShareCache account to be created
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Withdrawal budget of the investment
     /// 
     /// AUDIT CRITICAL:
-    /// - Derived from investment_id, version, batch_id, and year_index
-    /// - Fixed size allocation prevents overflow
-    /// - Stores refund distribution calculations
+    /// - Holds the caps and cumulative totals of every purpose
+    /// - Created on first use
     #[account(
         init_if_needed,
         payer = payer,
-        space = RefundShareCache::SIZE,
+        space = WithdrawalBudget::SIZE,
         seeds = [
-            b"refund_cache",
+            b"withdraw_budget",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            year_index.to_le_bytes().as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump,
+        bump
     )]
-    pub cache: Account<'info, RefundShareCache>,
-    
+    pub withdraw_budget: Account<'info, WithdrawalBudget>,
+
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for cache creation and transaction fees
+    /// AUDIT: Pays for transaction fees and, on first use, the withdrawal budget rent
     #[account(mut)]
     pub payer: Signer<'info>,
-    
-    /// Rent sysvar for account creation
-    /// 
-    /// AUDIT: Required for cache initialization
-    pub rent: Sysvar<'info, Rent>,
-    
-    /// System program for account creation
-    /// 
-    /// AUDIT: Required for cache initialization
+
+    /// System program for withdrawal budget creation
     pub system_program: Program<'info, System>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
 }
 
-/// Account validation context for executing profit share
+/// Account validation context for configuring the withdrawal rate limit
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from execute_whitelist
-/// - Transfers USDT from vault to recipients
-/// - Uses pre-calculated profit share cache
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes the window and caps of WithdrawRateLimit
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Cache validation (not expired, not executed)
-/// - Vault balance validation
-/// - Token transfer validation
+/// - Investment info, config and rate limit PDA validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(batch_id: u16)]
-pub struct ExecuteProfitShare<'info> {
+pub struct SetWithdrawRateLimit<'info> {
     /// InvestmentInfo account for validation
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is completed
-    /// - Provides investment parameters
-    /// - Used for vault PDA derivation
+    /// AUDIT: Provides investment_id and version for PDA derivation
     #[account(
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// ProfitShareCache account for execution
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Must be mutable for execution tracking
-    /// - PDA validation prevents spoofing
-    /// - Contains profit distribution data
-    #[account(mut,
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
         seeds = [
-            b"profit_cache", 
+            b"investment_config",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump,
+        bump = investment_info.config_bump
     )]
-    pub cache: Account<'info, ProfitShareCache>,
-
-    /// USDT mint account for validation
-    /// 
-    /// AUDIT: Must match expected USDT mint address
-    pub mint: Account<'info, Mint>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
-    /// Vault PDA account for token transfers
+    /// Withdrawal rate limit of the investment
     /// 
     /// AUDIT CRITICAL:
-    /// - Derived from investment_id and version
-    /// - Used as token transfer authority
-    /// - No deserialization needed (AccountInfo)
-    #[account(mut,
+    /// - Holds the rolling-window caps and buckets
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawRateLimit::SIZE,
         seeds = [
-            b"vault", 
+            b"withdraw_rate_limit",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
+            investment_info.version.as_ref()
         ],
         bump
     )]
-    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
-    pub vault: AccountInfo<'info>,
-
-    /// Vault associated token account for USDT
-    /// 
-    /// AUDIT CRITICAL:
-    /// - Source of USDT transfers
-    /// - Ownership validated against vault PDA
-    /// - Must have sufficient balance
-    #[account(mut,
-        associated_token::mint = mint,
-        associated_token::authority = vault,
-        associated_token::token_program = token_program,
-    )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub withdraw_rate_limit: Account<'info, WithdrawRateLimit>,
 
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for transaction fees
+    /// AUDIT: Pays for transaction fees and, on first use, the rate limit rent
     #[account(mut)]
     pub payer: Signer<'info>,
-    
-    /// System program for account operations
-    /// 
-    /// AUDIT: Required for account operations
+
+    /// System program for rate limit creation
     pub system_program: Program<'info, System>,
-    
-    /// Token program for token transfers
-    /// 
-    /// AUDIT: Required for token transfers
-    pub token_program: Program<'info, Token>,
-    
-    /// Associated token program for ATA operations
-    /// 
-    /// AUDIT: Required for ATA operations
-    pub associated_token_program: Program<'info, AssociatedToken>,
 
-    // 👉 ProfitShareCache accounts and recipient ATAs will be passed in through `ctx.remaining_accounts`
-    // ✅ Each ProfitShareCache will be verified dynamically using batch_id
-    // ✅ Each recipient ATA (for token transfer) will be matched by Pubkey
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
 }
 
-/// Account validation context for executing refund share
+/// Account validation context for switching the four-eyes rule of record edits
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from execute_whitelist
-/// - Transfers H2COIN from vault to recipients
-/// - Uses pre-calculated refund share cache
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes InvestmentInfo.record_four_eyes
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Cache validation (not expired, not executed)
-/// - Vault balance validation
-/// - Token transfer validation
+/// - Investment info and config PDA validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(batch_id: u16, year_index: u8)]
-pub struct ExecuteRefundShare<'info> {
-    /// InvestmentInfo account for validation
+pub struct SetRecordFourEyes<'info> {
+    /// InvestmentInfo account holding the rule
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is completed
-    /// - Provides investment parameters
-    /// - Used for vault PDA derivation
+    /// AUDIT: Must be mutable to store the rule; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// RefundShareCache account for execution
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Must be mutable for execution tracking
-    /// - PDA validation prevents spoofing
-    /// - Contains refund distribution data
-    #[account(mut,
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
         seeds = [
-            b"refund_cache", 
+            b"investment_config",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            year_index.to_le_bytes().as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump,
+        bump = investment_info.config_bump
     )]
-    pub cache: Account<'info, RefundShareCache>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
-    /// H2COIN mint account for validation
+    /// Transaction payer account
     /// 
-    /// AUDIT: Must match expected H2COIN mint address
-    pub mint: Account<'info, Mint>,
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
 
-    /// Vault PDA account for token transfers
+/// Account validation context for designating the recovery wallet of foreign token sweeps
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes InvestmentInfo.recovery_wallet
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct SetRecoveryWallet<'info> {
+    /// InvestmentInfo account holding the recovery wallet
     /// 
-    /// AUDIT CRITICAL:
-    /// - Derived from investment_id and version
-    /// - Used as token transfer authority
-    /// - No deserialization needed (AccountInfo)
-    #[account(mut,
+    /// AUDIT: Must be mutable to store the wallet; PDA validation prevents spoofing
+    #[account(
+        mut,
         seeds = [
-            b"vault", 
+            b"investment",
             investment_info.investment_id.as_ref(),
-            investment_info.version.as_ref(),
+            investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
-    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
-    pub vault: AccountInfo<'info>,
+    pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// Vault associated token account for H2COIN
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Source of H2COIN transfers
-    /// - Ownership validated against vault PDA
-    /// - Must have sufficient balance
-    #[account(mut,
-        associated_token::mint = mint,
-        associated_token::authority = vault,
-        associated_token::token_program = token_program,
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
     /// Transaction payer account
     /// 
     /// AUDIT: Pays for transaction fees
-    #[account(mut)]
     pub payer: Signer<'info>,
-    
-    /// System program for account operations
-    /// 
-    /// AUDIT: Required for account operations
-    pub system_program: Program<'info, System>,
-    
-    /// Token program for token transfers
-    /// 
-    /// AUDIT: Required for token transfers
-    pub token_program: Program<'info, Token>,
-    
-    /// Associated token program for ATA operations
-    /// 
-    /// AUDIT: Required for ATA operations
-    pub associated_token_program: Program<'info, AssociatedToken>,
-
-    // 👉 RefundShareCache accounts and recipient ATAs will be passed in through `ctx.remaining_accounts`
-    // ✅ Each RefundShareCache will be verified dynamically using batch_id
-    // ✅ Each recipient ATA (for token transfer) will be matched by Pubkey
 }
 
-/// Account validation context for depositing SOL to vault
+/// Account validation context for setting the profit stage multipliers
 /// 
 /// AUDIT CRITICAL:
-/// - Transfers SOL from payer to vault PDA
-/// - Used for covering transaction fees
-/// - No authorization required (anyone can deposit)
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Writes InvestmentInfo.profit_stage_multipliers_bp
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Vault PDA validation
-/// - SOL transfer validation
+/// - Investment info and config PDA validation
+/// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-#[instruction(amount: u64)]
-pub struct DepositSolToVault<'info> {
-    /// InvestmentInfo account for validation
+pub struct SetProfitStageMultipliers<'info> {
+    /// InvestmentInfo account holding the multipliers
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is active
-    /// - Provides investment parameters
-    /// - Used for vault PDA derivation
+    /// AUDIT: Must be mutable to store the multipliers; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
-    pub investment_info: Account<'info, InvestmentInfo>, 
+    pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// Vault PDA account for SOL storage
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Derived from investment_id and version
-    /// - Destination for SOL transfers
-    /// - No deserialization needed (AccountInfo)
-    #[account(mut,
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
         seeds = [
-            b"vault", 
-            investment_info.investment_id.as_ref(), 
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.config_bump
     )]
-    ///   CHECK: This vault PDA holds SOL, no deserialization needed
-    pub vault: AccountInfo<'info>,
+    pub investment_config: Account<'info, InvestmentConfig>,
 
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for SOL transfer and transaction fees
-    #[account(mut)]
+    /// AUDIT: Pays for transaction fees
     pub payer: Signer<'info>,
-    
-    /// System program for SOL transfers
-    /// 
-    /// AUDIT: Required for SOL transfers
-    pub system_program: Program<'info, System>,
 }
 
-/// Account validation context for depositing tokens to vault
+/// Account validation context for the emergency freeze
 /// 
 /// AUDIT CRITICAL:
-/// - Transfers USDT/H2COIN from payer to vault
-/// - Used for profit/refund distributions
-/// - No authorization required (anyone can deposit)
+/// - member must belong to the execute or update whitelist (checked in instruction)
+/// - Writes InvestmentInfo.frozen_at
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Token mint validation (USDT/H2COIN only)
-/// - Token account ownership validation
-/// - Token transfer validation
+/// - Investment info and config PDA validation
 #[derive(Accounts)]
-#[instruction(amount: u64)]
-pub struct DepositTokenToVault<'info> {
-    /// InvestmentInfo account for validation
+pub struct FreezeInvestment<'info> {
+    /// InvestmentInfo account holding the freeze flag
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is active
-    /// - Provides investment parameters
-    /// - Used for vault PDA derivation
+    /// AUDIT: Must be mutable to store frozen_at; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// Token mint account for validation
-    /// 
-    /// AUDIT: Must be USDT or H2COIN mint
-    pub mint: Account<'info, Mint>,
-
-    /// Source token account for transfers
-    /// 
-    /// AUDIT CRITICAL:
-    /// - Source of token transfers to vault
-    /// - Must be mutable for transfers
-    /// - Ownership validated in instruction
-    #[account(mut)]
-    pub from: Account<'info, TokenAccount>,
-
-    /// Vault PDA account for token storage
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT CRITICAL:
-    /// - Derived from investment_id and version
-    /// - Used as token account authority
-    /// - No deserialization needed (AccountInfo)
-    #[account(mut,
+    /// AUDIT: Provides the execute and update whitelists; PDA validation prevents spoofing
+    #[account(
         seeds = [
-            b"vault", 
-            investment_info.investment_id.as_ref(), 
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
-    )]
-    ///   CHECK: This vault PDA holds SOL, no deserialization needed
-    pub vault: AccountInfo<'info>,
-
-    /// Vault associated token account for destination
-    /// 
-    /// AUDIT CRITICAL:
-    /// - Destination for token transfers
-    /// - Ownership validated against vault PDA
-    /// - Must be mutable for transfers
-    #[account(mut,
-        associated_token::mint = mint,
-        associated_token::authority = vault,
-        associated_token::token_program = token_program,
+        bump = investment_info.config_bump
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,    
+    pub investment_config: Account<'info, InvestmentConfig>,
 
-    /// Transaction payer account
-    /// 
-    /// AUDIT: Pays for token transfers and transaction fees
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    
-    /// System program for account operations
-    /// 
-    /// AUDIT: Required for account operations
-    pub system_program: Program<'info, System>,
-    
-    /// Token program for token transfers
-    /// 
-    /// AUDIT: Required for token transfers
-    pub token_program: Program<'info, Token>,
-    
-    /// Associated token program for ATA operations
+    /// Whitelist member triggering the freeze
     /// 
-    /// AUDIT: Required for ATA operations
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// AUDIT: A single signature suffices; also pays the transaction fees
+    pub member: Signer<'info>,
 }
 
-/// Account validation context for withdrawing from vault
+/// Account validation context for lifting the emergency freeze
 /// 
 /// AUDIT CRITICAL:
-/// - Requires 3-of-5 multisig from withdraw_whitelist
-/// - Transfers all vault funds to recipient
-/// - Can transfer SOL, USDT, and H2COIN
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Clears InvestmentInfo.frozen_at
 /// 
 /// SECURITY CHECKS:
-/// - Investment info validation
-/// - Recipient whitelist validation
-/// - Vault balance validation
-/// - Token transfer validation
+/// - Investment info and config PDA validation
 /// - Multisig validation through remaining_accounts
 #[derive(Accounts)]
-pub struct WithdrawFromVault<'info> {
-    /// InvestmentInfo account for validation
+pub struct UnfreezeInvestment<'info> {
+    /// InvestmentInfo account holding the freeze flag
     /// 
-    /// AUDIT CRITICAL:
-    /// - Validates investment exists and is active
-    /// - Provides investment parameters and withdraw whitelist
-    /// - Used for vault PDA derivation
+    /// AUDIT: Must be mutable to clear frozen_at; PDA validation prevents spoofing
     #[account(
+        mut,
         seeds = [
             b"investment",
             investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
-        bump
+        bump = investment_info.bump
     )]
     pub investment_info: Account<'info, InvestmentInfo>,
 
-    /// USDT mint account for validation
+    /// InvestmentConfig account for authorization
     /// 
-    /// AUDIT: Must match expected USDT mint address
-    pub usdt_mint: Account<'info, Mint>,
-    
-    /// H2COIN mint account for validation
-    /// 
-    /// AUDIT: Must match expected H2COIN mint address
-    pub hcoin_mint: Account<'info, Mint>,
-
-    /// Vault PDA account for fund transfers
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for deactivating investment info
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Only allowed when investment is completed
+/// - Prevents all further operations
+/// 
+/// SECURITY CHECKS:
+/// - Investment info PDA validation
+/// - Investment state validation (must be completed)
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct DeactivateInvestmentInfo<'info> {
+    /// InvestmentInfo account to be deactivated
     /// 
     /// AUDIT CRITICAL:
-    /// - Derived from investment_id and version
-    /// - Source of all fund transfers
-    /// - No deserialization needed (AccountInfo)
-    #[account(mut,
+    /// - Must be mutable for deactivation
+    /// - PDA validation prevents spoofing
+    /// - State validation prevents invalid deactivation
+    #[account(
+        mut,
         seeds = [
-            b"vault", 
-            investment_info.investment_id.as_ref(), 
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for archiving an investment after its final settlement
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Only allowed on an active, completed investment
+/// - Terminal: the investment becomes Archived and inactive
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config, batch counter and vault PDA validation
+/// - Vault ATAs validated against the vault PDA and the USDT / H2COIN mints
+/// - Caches in remaining_accounts validated in instruction
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct ArchiveInvestment<'info> {
+    /// InvestmentInfo account to be archived
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for the state transition
+    /// - PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Batch counter of the investment; batches 1..=batch_id must be settled
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Omitted only when no record was ever created (checked in instruction)
+    #[account(
+        seeds = [
+            b"batch_counter",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = batch_counter.bump
+    )]
+    pub batch_counter: Option<Account<'info, BatchCounter>>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Derived from investment_id and vault_version; its lamports are reported
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only read and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    #[account(
+        associated_token::mint = usdt_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Vault associated token account for H2COIN
+    #[account(
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Token program of the vault ATAs
+    pub token_program: Program<'info, Token>,
+
+    // 👉 Signers, then ProfitShareCache / RefundShareCache accounts, are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for closing a deactivated investment's vault
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Closes both vault ATAs and empties the vault PDA into a withdraw whitelist wallet
+/// - An archived investment first sweeps residual USDT / H2COIN to the recipient's ATAs
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Vault ATAs validated against the vault PDA and the USDT / H2COIN mints
+/// - Recipient ATAs validated against the recipient and the mints
+/// - Recipient whitelist validation (in instruction)
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct CloseVault<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be deactivated and own its vault (checked in instruction)
+    /// - Holds the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist and withdraw_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
             investment_info.version.as_ref()
         ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
         bump
     )]
-    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA account to be emptied
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and vault_version
+    /// - Authority of the ATAs and source of the residual SOL
+    #[account(mut,
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token and SOL transfer authority and validated via seeds.
     pub vault: AccountInfo<'info>,
 
     /// Vault associated token account for USDT
     /// 
     /// AUDIT CRITICAL:
-    /// - Source of USDT transfers
-    /// - Ownership validated against vault PDA
-    /// - Must be mutable for transfers
-    #[account(mut, 
-        associated_token::mint = usdt_mint, 
+    /// - Must be empty unless the investment is archived; closed by the instruction
+    #[account(mut,
+        associated_token::mint = usdt_mint,
         associated_token::authority = vault,
         associated_token::token_program = token_program,
     )]
@@ -1253,79 +1289,6395 @@ pub struct WithdrawFromVault<'info> {
     /// Vault associated token account for H2COIN
     /// 
     /// AUDIT CRITICAL:
-    /// - Source of H2COIN transfers
-    /// - Ownership validated against vault PDA
-    /// - Must be mutable for transfers
-    #[account(mut, 
-        associated_token::mint = hcoin_mint, 
+    /// - Must be empty unless the investment is archived; closed by the instruction
+    #[account(mut,
+        associated_token::mint = hcoin_mint,
         associated_token::authority = vault,
         associated_token::token_program = token_program,
     )]
     pub vault_hcoin_account: Account<'info, TokenAccount>,
 
-    /// Recipient account for fund transfers
+    /// Recipient of the reclaimed lamports
     /// 
     /// AUDIT CRITICAL:
-    /// - Destination for all fund transfers
     /// - Must be in withdraw whitelist
     /// - Manually validated in instruction
+    ///   CHECK: Only receives lamports
     #[account(mut)]
     pub recipient_account: UncheckedAccount<'info>,
 
-    /// Recipient associated token account for USDT
+    /// Recipient USDT ATA receiving the residual USDT of an archived investment
     /// 
-    /// AUDIT CRITICAL:
-    /// - Destination for USDT transfers
-    /// - Ownership validated against recipient
-    /// - Created if needed
-    #[account(
-        init_if_needed,
-        payer = payer,
+    /// AUDIT: Required only when the archived vault still holds USDT
+    #[account(mut,
         associated_token::mint = usdt_mint,
         associated_token::authority = recipient_account,
         associated_token::token_program = token_program,
     )]
-    pub recipient_usdt_account: Account<'info, TokenAccount>,
+    pub recipient_usdt_account: Option<Account<'info, TokenAccount>>,
 
-    /// Recipient associated token account for H2COIN
+    /// Recipient H2COIN ATA receiving the residual H2COIN of an archived investment
     /// 
-    /// AUDIT CRITICAL:
-    /// - Destination for H2COIN transfers
-    /// - Ownership validated against recipient
-    /// - Created if needed
-    #[account(
-        init_if_needed,
-        payer = payer,
+    /// AUDIT: Required only when the archived vault still holds H2COIN
+    #[account(mut,
         associated_token::mint = hcoin_mint,
         associated_token::authority = recipient_account,
         associated_token::token_program = token_program,
     )]
-    pub recipient_hcoin_account: Account<'info, TokenAccount>,
+    pub recipient_hcoin_account: Option<Account<'info, TokenAccount>>,
 
     /// Transaction payer account
     /// 
-    /// AUDIT: Pays for ATA creation and transaction fees
-    /// CHECK: validated manually via 3-of-5 multisig inside instruction
-    #[account(mut)]
+    /// AUDIT: Pays for transaction fees
     pub payer: Signer<'info>,
-    
-    /// Rent sysvar for account creation
-    /// 
-    /// AUDIT: Required for ATA initialization
-    pub rent: Sysvar<'info, Rent>,
-    
-    /// System program for account operations
-    /// 
-    /// AUDIT: Required for account operations
+
+    /// System program for the residual SOL transfer
     pub system_program: Program<'info, System>,
-    
-    /// Token program for token transfers
-    /// 
-    /// AUDIT: Required for token transfers
+
+    /// Token program for closing the ATAs
     pub token_program: Program<'info, Token>,
-    
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags the residual sweep of an archived investment; required only for that sweep
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for linking a version to an earlier version's vault
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from both versions' update_whitelists
+/// - The earlier (source) version is deactivated by the link
+/// - The new version's own vault ATAs must be empty
+/// 
+/// SECURITY CHECKS:
+/// - Both InvestmentInfo / InvestmentConfig PDAs share the investment_id
+/// - Own vault ATA balances and addresses in instruction
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct LinkSharedVault<'info> {
+    /// InvestmentInfo of the new version
+    /// 
+    /// AUDIT: Must be mutable to store the vault link
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig of the new version
+    /// 
+    /// AUDIT: Provides the new version's update_whitelist
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// InvestmentInfo of the earlier version owning the vault
+    /// 
+    /// AUDIT: Same investment_id; deactivated by the link
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            source_info.version.as_ref()
+        ],
+        bump = source_info.bump
+    )]
+    pub source_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig of the earlier version
+    /// 
+    /// AUDIT: Provides the earlier version's update_whitelist
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            source_info.version.as_ref()
+        ],
+        bump = source_info.config_bump
+    )]
+    pub source_config: Account<'info, InvestmentConfig>,
+
+    /// New version's own USDT vault ATA
+    /// 
+    /// AUDIT: Must be empty; it becomes unreachable once linked
+    pub own_vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// New version's own H2COIN vault ATA
+    /// 
+    /// AUDIT: Must be empty; it becomes unreachable once linked
+    pub own_vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for updating execute whitelist
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from current execute_whitelist
+/// - Allows replacement of whitelist members
+/// - Affects profit/refund execution authorization
+/// 
+/// SECURITY CHECKS:
+/// - Investment info PDA validation
+/// - Investment state validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct UpdateExecuteWallet<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - PDA validation prevents spoofing
+    /// - Provides lifecycle state; execute_whitelist lives in investment_config
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account containing execute_whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for configuration updates
+    /// - PDA validation prevents spoofing
+    /// - Companion of investment_info (same investment_id and version)
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Pending patch of the execute_whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Created here and applied or cancelled after the timelock
+    /// - init fails while another patch of the same whitelist is pending
+    #[account(
+        init,
+        payer = payer,
+        space = PendingWhitelistPatch::SIZE,
+        seeds = [
+            b"whitelist_patch",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            &[0]
+        ],
+        bump
+    )]
+    pub pending_patch: Account<'info, PendingWhitelistPatch>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees and the pending patch rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for applying a pending whitelist patch
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless once the timelock has elapsed (checked in instruction)
+/// - Closes the pending patch to the account that paid for it
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and pending patch PDA validation
+#[derive(Accounts)]
+pub struct ApplyWhitelistPatch<'info> {
+    /// InvestmentInfo account holding the config hash
+    /// 
+    /// AUDIT: Must be mutable to refresh config_hash; PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account containing the whitelists
+    /// 
+    /// AUDIT: Must be mutable to apply the patch; PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Pending patch to apply
+    /// 
+    /// AUDIT: Closed to queued_by once applied
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            b"whitelist_patch",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            &[pending_patch.is_update as u8]
+        ],
+        bump = pending_patch.bump
+    )]
+    pub pending_patch: Account<'info, PendingWhitelistPatch>,
+
+    /// Account that paid for the pending patch
+    /// 
+    /// AUDIT: Receives the rent of the closed pending patch
+    /// CHECK: Address fixed to pending_patch.queued_by
+    #[account(mut, address = pending_patch.queued_by)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for accepting a pending whitelist seat
+/// 
+/// AUDIT CRITICAL:
+/// - new_member must be the pending patch's `to` key (checked in instruction)
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and pending patch PDA validation
+#[derive(Accounts)]
+pub struct AcceptWhitelistSeat<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Pending patch offering the seat
+    /// 
+    /// AUDIT: Must be mutable to record the acceptance
+    #[account(
+        mut,
+        seeds = [
+            b"whitelist_patch",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            &[pending_patch.is_update as u8]
+        ],
+        bump = pending_patch.bump
+    )]
+    pub pending_patch: Account<'info, PendingWhitelistPatch>,
+
+    /// Incoming whitelist member
+    /// 
+    /// AUDIT: Its signature proves the key is valid and its holder consents
+    pub new_member: Signer<'info>,
+}
+
+/// Account validation context for cancelling a pending whitelist patch
+/// 
+/// AUDIT CRITICAL:
+/// - Requires WHITELIST_PATCH_CANCEL_SIGNERS members of the patched whitelist
+/// - Closes the pending patch to the account that paid for it
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and pending patch PDA validation
+/// - Member signatures through remaining_accounts
+#[derive(Accounts)]
+pub struct CancelWhitelistPatch<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides the whitelists; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Pending patch to cancel
+    /// 
+    /// AUDIT: Closed to queued_by once cancelled
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            b"whitelist_patch",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            &[pending_patch.is_update as u8]
+        ],
+        bump = pending_patch.bump
+    )]
+    pub pending_patch: Account<'info, PendingWhitelistPatch>,
+
+    /// Account that paid for the pending patch
+    /// 
+    /// AUDIT: Receives the rent of the closed pending patch
+    /// CHECK: Address fixed to pending_patch.queued_by
+    #[account(mut, address = pending_patch.queued_by)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Cancelling members are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for updating update whitelist
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from current update_whitelist
+/// - Allows replacement of whitelist members
+/// - Affects investment info update authorization
+/// 
+/// SECURITY CHECKS:
+/// - Investment info PDA validation
+/// - Investment state validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct UpdateUpdateWallet<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - PDA validation prevents spoofing
+    /// - Provides lifecycle state; update_whitelist lives in investment_config
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account containing update_whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for configuration updates
+    /// - PDA validation prevents spoofing
+    /// - Companion of investment_info (same investment_id and version)
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Pending patch of the update_whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Created here and applied or cancelled after the timelock
+    /// - init fails while another patch of the same whitelist is pending
+    #[account(
+        init,
+        payer = payer,
+        space = PendingWhitelistPatch::SIZE,
+        seeds = [
+            b"whitelist_patch",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            &[1]
+        ],
+        bump
+    )]
+    pub pending_patch: Account<'info, PendingWhitelistPatch>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees and the pending patch rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for updating withdraw whitelist
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from current withdraw_whitelist
+/// - Allows replacement of whitelist members
+/// - Affects vault withdrawal authorization
+/// 
+/// SECURITY CHECKS:
+/// - Investment info PDA validation
+/// - Investment state validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct UpdateWithdrawWallet<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - PDA validation prevents spoofing
+    /// - Provides lifecycle state; withdraw_whitelist lives in investment_config
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account containing withdraw_whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for configuration updates
+    /// - PDA validation prevents spoofing
+    /// - Companion of investment_info (same investment_id and version)
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for setting whitelist signer weights
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the target whitelist's current multisig rule
+/// - Weights apply to execute_whitelist or update_whitelist
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct UpdateSignerWeights<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Validates investment exists and is active
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account holding whitelists and weights
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for weight updates
+    /// - PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for setting whitelist member labels
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the multisig that patches the labelled whitelist
+/// - Labels are part of the config hash, so it is refreshed
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct SetWhitelistLabels<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Validates investment exists and is active; holds the config hash
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account holding whitelists and labels
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for label updates
+    /// - PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for setting the Squads authority
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Changes authorization, so the config hash is refreshed
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct SetSquadsAuthority<'info> {
+    /// InvestmentInfo account holding the config hash
+    /// 
+    /// AUDIT: Must be mutable to refresh config_hash; PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account holding the Squads authority
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable to store the vault address
+    /// - PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for adding investment records
+/// 
+/// AUDIT CRITICAL:
+/// - Creates individual investment records
+/// - Transfers tokens from recipient to vault
+/// - Validates token accounts and amounts
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Investment record PDA derivation
+/// - Token account ownership validation
+/// - Token transfer validation
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct AddInvestmentRecords<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides investment parameters
+    /// - Mutable to count the record in invested_usdt
+    /// - PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// BatchCounter of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Resolves batch_id = 0 to the next batch with free capacity
+    /// - Declared before investment_record, whose PDA uses the resolved batch_id
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BatchCounter::SIZE,
+        seeds = [
+            b"batch_counter",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub batch_counter: Account<'info, BatchCounter>,
+
+    /// InvestmentRecord account to be created
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id, version, resolved batch_id, record_id, account_id
+    /// - Fixed size allocation prevents overflow
+    /// - Stores individual investment details
+    #[account(
+        init,
+        payer = payer,
+        space = InvestmentRecord::SIZE,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_counter.resolve_batch_id(batch_id).to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump,
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Program-wide InvestorDirectory of account_id
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from sha256(account_id), independent of the investment
+    /// - Created on the investor's first record; the new record is linked to it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = InvestorDirectory::SIZE,
+        seeds = [
+            b"investor_directory",
+            InvestorDirectory::account_id_hash(&account_id).as_ref(),
+        ],
+        bump
+    )]
+    pub investor_directory: Account<'info, InvestorDirectory>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+    
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+    
+    /// Recipient account for token transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of token transfers to vault
+    /// - Manually validated in instruction
+    /// - Omitted for non-custodial investors; the record is created unbound
+    ///   and the wallet is set later through `bind_wallet`
+    ///   CHECK: recipient lamport target, manually validated
+    pub recipient_account: Option<UncheckedAccount<'info>>,
+
+    /// Recipient associated token account for USDT
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of USDT transfers
+    /// - Ownership validated against recipient
+    /// - Created if needed
+    /// - Must be omitted together with recipient_account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdt_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdt_account: Option<Account<'info, TokenAccount>>,
+
+    /// Recipient associated token account for H2COIN
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of H2COIN transfers
+    /// - Ownership validated against recipient
+    /// - Created if needed
+    /// - Must be omitted together with recipient_account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_hcoin_account: Option<Account<'info, TokenAccount>>,
+
+    /// ExchangeRegistry singleton
+    /// 
+    /// AUDIT: Address fixed by seeds so it cannot be swapped; an uninitialized
+    /// registry screens nothing
+    /// CHECK: Owner and contents validated in instruction
+    #[account(
+        seeds = [b"exchange_registry"],
+        bump
+    )]
+    pub exchange_registry: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for account creation and token transfers
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Rent sysvar for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token operations
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program for ATA creation
+    /// 
+    /// AUDIT: Required for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for updating investment record wallets
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Updates wallet addresses for existing records
+/// - Affects future profit/refund distributions
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record existence validation
+/// - Multisig validation through remaining_accounts
+/// - Token account validation
+#[derive(Accounts)]
+#[instruction(account_id: [u8; 15])]
+pub struct UpdateInvestmentRecordWallets<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides investment parameters
+    /// - PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+    
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// New recipient account for token transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - New destination for future distributions
+    /// - Manually validated in instruction
+    ///   CHECK: recipient lamport target, manually validated
+    pub recipient_account: UncheckedAccount<'info>,
+
+    /// New recipient associated token account for USDT
+    /// 
+    /// AUDIT CRITICAL:
+    /// - New destination for USDT distributions
+    /// - Ownership validated against recipient
+    /// - Created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdt_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdt_account: Account<'info, TokenAccount>,
+
+    /// New recipient associated token account for H2COIN
+    /// 
+    /// AUDIT CRITICAL:
+    /// - New destination for H2COIN distributions
+    /// - Ownership validated against recipient
+    /// - Created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_hcoin_account: Account<'info, TokenAccount>,
+
+    /// ExchangeRegistry singleton
+    /// 
+    /// AUDIT: Address fixed by seeds so it cannot be swapped; an uninitialized
+    /// registry screens nothing
+    /// CHECK: Owner and contents validated in instruction
+    #[account(
+        seeds = [b"exchange_registry"],
+        bump
+    )]
+    pub exchange_registry: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for ATA creation and transaction fees
+    /// CHECK: validated manually via 3-of-5 multisig inside instruction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Rent sysvar for account creation
+    /// 
+    /// AUDIT: Required for ATA initialization
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token operations
+    /// 
+    /// AUDIT: Required for ATA creation
+    pub token_program: Program<'info, Token>,
+    
+    /// Associated token program for ATA creation
+    /// 
+    /// AUDIT: Required for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for revoking investment records
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Marks record as revoked with timestamp
+/// - Prevents record from distributions
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record existence validation
+/// - Record state validation (not already revoked)
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct RevokeInvestmentRecord<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides investment parameters
+    /// - PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// InvestmentRecord account to be revoked
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for revocation
+    /// - PDA validation prevents spoofing
+    /// - State validation prevents double revocation
+    #[account(
+        mut,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for binding an investor wallet to an unbound record
+/// 
+/// AUDIT CRITICAL:
+/// - The investor signs as the wallet being bound
+/// - One update_whitelist member co-signs through remaining_accounts to attest
+///   that the wallet belongs to the record's account_id
+/// - Only records created without a wallet can be bound
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA derivation
+/// - Investor ATAs derived from the signing wallet
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct BindInvestmentRecordWallet<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides the update_whitelist for attestation
+    /// - PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// InvestmentRecord account to bind
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable to store the wallet
+    /// - PDA validation prevents spoofing
+    /// - Wallet must still be unset
+    #[account(
+        mut,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Investor wallet being bound
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must sign, proving control of the wallet
+    /// - Pays for its own ATAs if they do not exist yet
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    /// Investor associated token account for USDT
+    /// 
+    /// AUDIT: Created if needed so profit payouts can land
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = usdt_mint,
+        associated_token::authority = investor,
+        associated_token::token_program = token_program,
+    )]
+    pub investor_usdt_account: Account<'info, TokenAccount>,
+
+    /// Investor associated token account for H2COIN
+    /// 
+    /// AUDIT: Created if needed so refund payouts can land
+    #[account(
+        init_if_needed,
+        payer = investor,
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = investor,
+        associated_token::token_program = token_program,
+    )]
+    pub investor_hcoin_account: Account<'info, TokenAccount>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for ATA creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program for token operations
+    /// 
+    /// AUDIT: Required for ATA creation
+    pub token_program: Program<'info, Token>,
+
+    /// Associated token program for ATA creation
+    /// 
+    /// AUDIT: Required for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for acknowledging a provisional record
+/// 
+/// AUDIT CRITICAL:
+/// - Signed by the investor wallet stored on the record
+/// - Only valid within the acknowledgment window
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA derivation
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct AcknowledgeRecord<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment parameters; mutable to return the record to the profit denominator
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentRecord account being acknowledged
+    /// 
+    /// AUDIT: Must be mutable to store the acknowledgment timestamp
+    #[account(
+        mut,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Investor wallet
+    /// 
+    /// AUDIT: Must match investment_record.wallet
+    pub investor: Signer<'info>,
+}
+
+/// Account validation context for contesting a revocation
+/// 
+/// AUDIT CRITICAL:
+/// - Signed by the investor wallet stored on the record
+/// - Only valid during the revocation holding period
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA derivation
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct ContestRevocation<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment parameters
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentRecord account being contested
+    /// 
+    /// AUDIT: Must be mutable to store the contest timestamp
+    #[account(
+        mut,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Investor wallet
+    /// 
+    /// AUDIT: Must match investment_record.wallet
+    pub investor: Signer<'info>,
+}
+
+/// Account validation context for resolving a revocation contest
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Reinstates or upholds a contested revocation
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA derivation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct ResolveRevocationContest<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment parameters and update_whitelist
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// InvestmentRecord account with the open contest
+    /// 
+    /// AUDIT: Must be mutable to clear the contest / revocation
+    #[account(
+        mut,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for releasing a revocation hold
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless: the record's revocation state decides
+/// - Removes a finally revoked record from the profit denominator
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA derivation
+#[derive(Accounts)]
+#[instruction(batch_id: u16, record_id: u64, account_id: [u8; 15])]
+pub struct ReleaseRevocationHold<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Mutable to move the record into inactive_invested_usdt
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentRecord account whose hold is released
+    /// 
+    /// AUDIT: Must be mutable to store the release timestamp
+    #[account(
+        mut,
+        seeds = [
+            b"record",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for reporting the impact of a revocation
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only and permissionless: nothing is written
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Record PDA validation in instruction
+#[derive(Accounts)]
+pub struct ReportRevocationImpact<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id, version and end_at
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account
+    /// 
+    /// AUDIT: Provides stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// InvestmentRecord under evaluation
+    /// 
+    /// AUDIT: Supplies stage and amounts for the report
+    pub investment_record: Account<'info, InvestmentRecord>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for the year_index window view
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only and permissionless: nothing is written
+/// 
+/// SECURITY CHECKS:
+/// - Investment info PDA validation
+#[derive(Accounts)]
+pub struct YearIndexWindow<'info> {
+    /// InvestmentInfo account
+    /// 
+    /// AUDIT: Provides end_at; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for emitting an event stream checkpoint
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless; only the checkpoint counter is written
+/// - Checkpoint PDA is created on first use
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config, batch counter and checkpoint PDA validation
+#[derive(Accounts)]
+pub struct EmitCheckpoint<'info> {
+    /// InvestmentInfo account
+    /// 
+    /// AUDIT: Hashed into the checkpoint; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account
+    /// 
+    /// AUDIT: Hashed into the checkpoint; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Batch counter of the investment (optional)
+    /// 
+    /// AUDIT: Omitted only before the first record was added
+    #[account(
+        seeds = [
+            b"batch_counter",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = batch_counter.bump
+    )]
+    pub batch_counter: Option<Account<'info, BatchCounter>>,
+
+    /// Checkpoint counter
+    /// 
+    /// AUDIT: One per investment; carries the sequence across calls
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CheckpointState::SIZE,
+        seeds = [
+            b"checkpoint",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub checkpoint_state: Account<'info, CheckpointState>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for the checkpoint PDA on first use
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for pre-validating a planned signer set
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only and permissionless: nothing is written
+/// - Planned signers are passed through remaining_accounts
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+#[derive(Accounts)]
+pub struct PrevalidateSigners<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for the config PDA
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account
+    /// 
+    /// AUDIT: Provides the whitelists; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for setting an account-level payout split
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Calling again replaces the split in place
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Split PDA derivation binds it to the investment and account_id
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(account_id: [u8; 15])]
+pub struct SetPayoutSplit<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// PayoutSplit account
+    /// 
+    /// AUDIT: One per investment and account_id; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PayoutSplit::SIZE,
+        seeds = [
+            b"split",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            account_id.as_ref(),
+        ],
+        bump
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for split creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for removing an account-level payout split
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Rent is returned to the payer
+#[derive(Accounts)]
+#[instruction(account_id: [u8; 15])]
+pub struct ClearPayoutSplit<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// PayoutSplit account
+    /// 
+    /// AUDIT: Closed to the payer
+    #[account(
+        mut,
+        seeds = [
+            b"split",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            account_id.as_ref(),
+        ],
+        bump = payout_split.bump,
+        close = payer
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Receives the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for estimating profit share
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Creates profit share cache for batch distribution
+/// - Calculates profit distribution amounts
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Investment type validation (Standard only)
+/// - Cache PDA derivation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct EstimateProfitShare<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is completed
+    /// - Provides investment parameters
+    /// - Investment type validation (Standard only)
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// ProfitShareCache account to be created
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id, version, and batch_id
+    /// - Fixed size allocation prevents overflow
+    /// - Stores profit distribution calculations
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ProfitShareCache::SIZE,
+        seeds = [
+            b"profit_cache", 
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, ProfitShareCache>,
+
+    /// ProfitMerkleCache of the batch, initialized or not
+    /// 
+    /// AUDIT: An open or partly claimed Merkle round of the batch blocks this profit round
+    /// CHECK: Address fixed by seeds; owner and contents validated in instruction
+    #[account(
+        seeds = [
+            b"profit_merkle",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub merkle_cache: UncheckedAccount<'info>,
+
+    /// VaultTokenRegistry of the investment (optional)
+    /// 
+    /// AUDIT: Required only when payout_mint is a registered vault mint
+    #[account(
+        seeds = [
+            b"vault_token_registry",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = vault_token_registry.bump
+    )]
+    pub vault_token_registry: Option<Account<'info, VaultTokenRegistry>>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for cache creation and transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Rent sysvar for account creation
+    /// 
+    /// AUDIT: Required for cache initialization
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for cache initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for estimating refund share
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Creates refund share cache for batch distribution
+/// - Calculates refund distribution amounts
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Year index validation (3-9)
+/// - Cache PDA derivation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16, year_index: u8)]
+pub struct EstimateRefundShare<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is completed
+    /// - Provides investment parameters and stage ratios
+    /// - Used for refund percentage calculations
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// RefundShareCache account to be created
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id, version, batch_id, and year_index
+    /// - Fixed size allocation prevents overflow
+    /// - Stores refund distribution calculations
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RefundShareCache::SIZE,
+        seeds = [
+            b"refund_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            year_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, RefundShareCache>,
+    
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for cache creation and transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Rent sysvar for account creation
+    /// 
+    /// AUDIT: Required for cache initialization
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for cache initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for executing profit share
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Transfers USDT from vault to recipients
+/// - Uses pre-calculated profit share cache
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Cache validation (not expired, not executed)
+/// - Vault balance validation
+/// - Token transfer validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct ExecuteProfitShare<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is completed
+    /// - Provides investment parameters
+    /// - Used for vault PDA derivation
+    /// - Mutable for the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Distribution schedule (optional)
+    /// 
+    /// AUDIT: When provided, the matching slot is marked executed on settlement
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump
+    )]
+    pub schedule: Option<Account<'info, DistributionSchedule>>,
+
+    /// ProfitShareCache account for execution
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for execution tracking
+    /// - PDA validation prevents spoofing
+    /// - Contains profit distribution data
+    #[account(mut,
+        seeds = [
+            b"profit_cache", 
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, ProfitShareCache>,
+
+    /// ProfitMerkleCache of the batch, initialized or not
+    /// 
+    /// AUDIT: An open or partly claimed Merkle round of the batch blocks this profit round
+    /// CHECK: Address fixed by seeds; owner and contents validated in instruction
+    #[account(
+        seeds = [
+            b"profit_merkle",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub merkle_cache: UncheckedAccount<'info>,
+
+    /// Payout mint account for validation
+    /// 
+    /// AUDIT: Must match ProfitShareCache.payout_mint and its recorded decimals
+    /// SECURITY: SPL Token mints only; a Token-2022 mint (and any transfer fee) is rejected here
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account for token transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Used as token transfer authority
+    /// - No deserialization needed (AccountInfo)
+    #[account(mut,
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for the payout mint
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of the profit transfers
+    /// - Ownership validated against vault PDA
+    /// - Must have sufficient balance
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for account operations
+    /// 
+    /// AUDIT: Required for account operations
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+    
+    /// Associated token program for ATA operations
+    /// 
+    /// AUDIT: Required for ATA operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // 👉 ProfitShareCache accounts and recipient ATAs will be passed in through `ctx.remaining_accounts`
+    // ✅ Each ProfitShareCache will be verified dynamically using batch_id
+    // ✅ Each recipient ATA (for token transfer) will be matched by Pubkey
+}
+
+/// Account validation context for executing refund share
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Transfers H2COIN from vault to recipients
+/// - Uses pre-calculated refund share cache
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Cache validation (not expired, not executed)
+/// - Vault balance validation
+/// - Token transfer validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16, year_index: u8)]
+pub struct ExecuteRefundShare<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is completed
+    /// - Provides investment parameters
+    /// - Used for vault PDA derivation
+    /// - Mutable for the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Distribution schedule (optional)
+    /// 
+    /// AUDIT: When provided, the matching slot is marked executed on settlement
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump
+    )]
+    pub schedule: Option<Account<'info, DistributionSchedule>>,
+
+    /// RefundShareCache account for execution
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be mutable for execution tracking
+    /// - PDA validation prevents spoofing
+    /// - Contains refund distribution data
+    #[account(mut,
+        seeds = [
+            b"refund_cache", 
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            year_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, RefundShareCache>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    /// SECURITY: SPL Token mints only; a Token-2022 mint (and any transfer fee) is rejected here
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account for token transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Used as token transfer authority
+    /// - No deserialization needed (AccountInfo)
+    #[account(mut,
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for H2COIN
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of H2COIN transfers
+    /// - Ownership validated against vault PDA
+    /// - Must have sufficient balance
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for account operations
+    /// 
+    /// AUDIT: Required for account operations
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+    
+    /// Associated token program for ATA operations
+    /// 
+    /// AUDIT: Required for ATA operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // 👉 RefundShareCache accounts and recipient ATAs will be passed in through `ctx.remaining_accounts`
+    // ✅ Each RefundShareCache will be verified dynamically using batch_id
+    // ✅ Each recipient ATA (for token transfer) will be matched by Pubkey
+}
+
+/// Account validation context for paying a requeued cache entry
+/// 
+/// AUDIT CRITICAL:
+/// - member must belong to the execute whitelist (checked in instruction)
+/// - Pays exactly one Requeued entry of a profit or refund cache
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and cache PDA validation
+/// - Exactly one of profit_cache / refund_cache (checked in instruction)
+/// - Recipient ATA validated against the entry wallet and mint (in instruction)
+#[derive(Accounts)]
+#[instruction(batch_id: u16, year_index: u8)]
+pub struct RequeueEntry<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Provides vault seeds and lifecycle state
+    /// - Holds the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// ProfitShareCache of the batch (profit entry)
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub profit_cache: Option<Account<'info, ProfitShareCache>>,
+
+    /// RefundShareCache of the batch and year (refund entry)
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"refund_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            year_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub refund_cache: Option<Account<'info, RefundShareCache>>,
+
+    /// Mint paid by the cache (USDT for profit, H2COIN for refund)
+    /// 
+    /// AUDIT: Checked against the cache kind in instruction
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account, authority of the vault ATA
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and vault_version
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for the mint
+    /// 
+    /// AUDIT: Source of the payout
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Recreated recipient ATA of the entry wallet
+    /// 
+    /// AUDIT: Address, owner and mint validated in instruction
+    ///   CHECK: Deserialized and checked by validate_recipient_ata
+    #[account(mut)]
+    pub recipient_ata: UncheckedAccount<'info>,
+
+    /// Execute whitelist member submitting the payout
+    /// 
+    /// AUDIT: One member is enough; the amount and recipient are fixed by the cache
+    pub member: Signer<'info>,
+
+    /// Token program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// Account validation context for releasing an escrowed cache entry
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the Executor role (3-of-5 execute_whitelist until assigned)
+/// - Only changes the entry status; requeue_entry moves the funds
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and cache PDA validation
+/// - Exactly one of profit_cache / refund_cache (checked in instruction)
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16, year_index: u8)]
+pub struct ReleaseEscrowedEntry<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// ProfitShareCache of the batch (profit entry)
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub profit_cache: Option<Account<'info, ProfitShareCache>>,
+
+    /// RefundShareCache of the batch and year (refund entry)
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"refund_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            year_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub refund_cache: Option<Account<'info, RefundShareCache>>,
+
+    /// Transaction payer
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for cancelling a profit cache
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the Executor role (3-of-5 execute_whitelist until assigned)
+/// - Voids the estimate; no funds move
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and cache PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct CancelProfitCache<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// ProfitShareCache to cancel
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, ProfitShareCache>,
+
+    /// Transaction payer
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for cancelling a Merkle profit round
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the Executor role (3-of-5 execute_whitelist until assigned)
+/// - Stops claims; no funds move
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and Merkle cache PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct CancelProfitMerkle<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// ProfitMerkleCache to cancel
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_merkle",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump = merkle_cache.bump,
+    )]
+    pub merkle_cache: Account<'info, ProfitMerkleCache>,
+
+    /// Transaction payer
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for opening a profit cache for claims
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the Executor role (3-of-5 execute_whitelist until assigned)
+/// - Only flags the cache; no funds move
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and cache PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct OpenProfitClaims<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides lifecycle state and the config hash
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT: Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// ProfitShareCache to open
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, ProfitShareCache>,
+
+    /// Transaction payer
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for claiming a profit cache entry
+/// 
+/// AUDIT CRITICAL:
+/// - claimant must be the wallet of the claimed entry (checked in instruction)
+/// - Pays exactly one Pending entry of a cache opened for claims
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and cache PDA validation
+/// - Vault ATA bound to the vault PDA and the mint
+/// - Claimant ATA derived from (claimant, mint); created at the claimant's expense
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct ClaimProfit<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Provides vault seeds and lifecycle state
+    /// - Holds the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT: Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Program-wide parameters
+    /// 
+    /// AUDIT: Provides the claim relayer and the per-claim SOL reimbursement
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Distribution schedule (optional)
+    /// 
+    /// AUDIT: When provided, the matching slot is marked executed when the claim settles the cache
+    #[account(
+        mut,
+        seeds = [
+            b"schedule",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump
+    )]
+    pub schedule: Option<Account<'info, DistributionSchedule>>,
+
+    /// ProfitShareCache holding the entry
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub cache: Account<'info, ProfitShareCache>,
+
+    /// Payout mint of the cache
+    /// 
+    /// AUDIT: Must match ProfitShareCache.payout_mint and its recorded decimals
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account, authority of the vault ATA
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and vault_version
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for the mint
+    /// 
+    /// AUDIT: Source of the payout
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Claimant's associated token account for the mint
+    /// 
+    /// AUDIT: Created if missing, paid by the fee payer
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program,
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// Entry wallet claiming its share
+    /// 
+    /// AUDIT: Must sign; the payout goes to its ATA
+    pub claimant: Signer<'info>,
+
+    /// Fee payer of the claim
+    /// 
+    /// AUDIT: The claimant, or GlobalConfig.claim_relayer when sponsored; pays the
+    /// transaction and rents and is reimbursed from the vault's SOL when sponsored
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    /// System program for ATA creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// Associated token program for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// Account validation context for publishing a Merkle profit root
+/// 
+/// AUDIT CRITICAL:
+/// - Requires the Executor role (3-of-5 execute_whitelist until assigned)
+/// - Creates the ProfitMerkleCache on first publication
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and cache PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct EstimateProfitShareMerkle<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides lifecycle state, the profit mint and the config hash
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT: Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// VaultTokenRegistry of the investment (optional)
+    /// 
+    /// AUDIT: Required only when payout_mint is a registered vault mint
+    #[account(
+        seeds = [
+            b"vault_token_registry",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = vault_token_registry.bump
+    )]
+    pub vault_token_registry: Option<Account<'info, VaultTokenRegistry>>,
+
+    /// ProfitShareCache of the batch, initialized or not
+    /// 
+    /// AUDIT: A live or executed cache round of the batch blocks the Merkle round
+    /// CHECK: Address fixed by seeds; owner and contents validated in instruction
+    #[account(
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub profit_cache: UncheckedAccount<'info>,
+
+    /// ProfitMerkleCache of the batch
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Created on first publication; replaceable only until the first claim
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ProfitMerkleCache::SIZE,
+        seeds = [
+            b"profit_merkle",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub merkle_cache: Account<'info, ProfitMerkleCache>,
+
+    /// Transaction payer
+    /// 
+    /// AUDIT: Pays the rent of the ProfitMerkleCache
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for claiming a Merkle profit entry
+/// 
+/// AUDIT CRITICAL:
+/// - The leaf is proved with the claimant's wallet (checked in instruction)
+/// - The MerkleClaimReceipt is created with `init`, so each entry is claimed once
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, cache and receipt PDA validation
+/// - Vault ATA bound to the vault PDA and the mint
+/// - Claimant ATA derived from (claimant, mint); created at the claimant's expense
+#[derive(Accounts)]
+#[instruction(batch_id: u16, entry_index: u32)]
+pub struct ClaimProfitWithProof<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Provides vault seeds and lifecycle state
+    /// - Holds the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT: Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Program-wide parameters
+    /// 
+    /// AUDIT: Provides the claim relayer and the per-claim SOL reimbursement
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// ProfitMerkleCache of the batch
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        mut,
+        seeds = [
+            b"profit_merkle",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump = merkle_cache.bump,
+    )]
+    pub merkle_cache: Account<'info, ProfitMerkleCache>,
+
+    /// Claim marker of the entry
+    /// 
+    /// AUDIT CRITICAL:
+    /// - `init` fails when the entry was already claimed
+    #[account(
+        init,
+        payer = fee_payer,
+        space = MerkleClaimReceipt::SIZE,
+        seeds = [
+            b"merkle_claim",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            entry_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_receipt: Account<'info, MerkleClaimReceipt>,
+
+    /// Payout mint of the batch
+    /// 
+    /// AUDIT: Must match ProfitMerkleCache.payout_mint and its recorded decimals
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account, authority of the vault ATA
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and vault_version
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for the mint
+    /// 
+    /// AUDIT: Source of the payout
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Claimant's associated token account for the mint
+    /// 
+    /// AUDIT: Created if missing, paid by the fee payer
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program,
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// Wallet of the claimed leaf
+    /// 
+    /// AUDIT: Must sign; the payout goes to its ATA
+    pub claimant: Signer<'info>,
+
+    /// Fee payer of the claim
+    /// 
+    /// AUDIT: The claimant, or GlobalConfig.claim_relayer when sponsored; pays the
+    /// transaction and rents and is reimbursed from the vault's SOL when sponsored
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// Associated token program for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// Account validation context for reconciling a batch against its cache
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only: records are compared with the cache and a report is emitted
+/// - Exactly one of profit_cache / refund_cache must be provided
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Cache PDA derivation through seeds
+/// - Signer validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16, year_index: u8)]
+pub struct ReconcileBatch<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment parameters, stage ratios and whitelists
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// ProfitShareCache of the batch (profit reconciliation)
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"profit_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub profit_cache: Option<Account<'info, ProfitShareCache>>,
+
+    /// RefundShareCache of the batch and year (refund reconciliation)
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"refund_cache",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            year_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub refund_cache: Option<Account<'info, RefundShareCache>>,
+
+    // 👉 Signer and InvestmentRecord accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for exporting a page of investment records
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only and permissionless: records are summarized into an event
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA, batch and page validation in instruction
+#[derive(Accounts)]
+pub struct EmitRecordPage<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for record PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 InvestmentRecord accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for batch statistics
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only and permissionless: records are summarized into an event
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Record PDA and batch validation in instruction
+#[derive(Accounts)]
+pub struct EmitBatchStatistics<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for record PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 InvestmentRecord accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for depositing SOL to vault
+/// 
+/// AUDIT CRITICAL:
+/// - Transfers SOL from payer to vault PDA
+/// - Used for covering transaction fees
+/// - No authorization required (anyone can deposit)
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Vault PDA validation
+/// - SOL transfer validation
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DepositSolToVault<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides investment parameters
+    /// - Used for vault PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>, 
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Vault PDA account for SOL storage
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Destination for SOL transfers
+    /// - No deserialization needed (AccountInfo)
+    #[account(mut,
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(), 
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This vault PDA holds SOL, no deserialization needed
+    pub vault: AccountInfo<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for SOL transfer and transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for SOL transfers
+    /// 
+    /// AUDIT: Required for SOL transfers
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for depositing tokens to vault
+/// 
+/// AUDIT CRITICAL:
+/// - Transfers USDT/H2COIN from payer to vault
+/// - Used for profit/refund distributions
+/// - No authorization required (anyone can deposit)
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Token mint validation (USDT/H2COIN only)
+/// - Token account ownership validation
+/// - Token transfer validation
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DepositTokenToVault<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides investment parameters
+    /// - Used for vault PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Token mint account for validation
+    /// 
+    /// AUDIT: Must be USDT or H2COIN mint
+    pub mint: Account<'info, Mint>,
+
+    /// Source token account for transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of token transfers to vault
+    /// - Must be mutable for transfers
+    /// - Ownership validated in instruction
+    #[account(mut)]
+    pub from: Account<'info, TokenAccount>,
+
+    /// Vault PDA account for token storage
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Used as token account authority
+    /// - No deserialization needed (AccountInfo)
+    #[account(mut,
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(), 
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This vault PDA holds SOL, no deserialization needed
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for destination
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Destination for token transfers
+    /// - Ownership validated against vault PDA
+    /// - Must be mutable for transfers
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,    
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for token transfers and transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// System program for account operations
+    /// 
+    /// AUDIT: Required for account operations
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+    
+    /// Associated token program for ATA operations
+    /// 
+    /// AUDIT: Required for ATA operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for withdrawing from vault
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from withdraw_whitelist
+/// - Transfers all vault funds to recipient
+/// - Can transfer SOL, USDT, and H2COIN
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Recipient whitelist validation
+/// - Vault balance validation
+/// - Token transfer validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct WithdrawFromVault<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Validates investment exists and is active
+    /// - Provides investment parameters and withdraw whitelist
+    /// - Used for vault PDA derivation
+    /// - Holds the withdrawal sequence (anti-replay) and the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+    
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA account for fund transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Source of all fund transfers
+    /// - No deserialization needed (AccountInfo)
+    #[account(mut,
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(), 
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of USDT transfers
+    /// - Ownership validated against vault PDA
+    /// - Must be mutable for transfers
+    #[account(mut, 
+        associated_token::mint = usdt_mint, 
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Vault associated token account for H2COIN
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Source of H2COIN transfers
+    /// - Ownership validated against vault PDA
+    /// - Must be mutable for transfers
+    #[account(mut, 
+        associated_token::mint = hcoin_mint, 
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Recipient account for fund transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Destination for all fund transfers
+    /// - Must be in withdraw whitelist
+    /// - Manually validated in instruction
+    #[account(mut)]
+    pub recipient_account: UncheckedAccount<'info>,
+
+    /// Recipient associated token account for USDT
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Destination for USDT transfers
+    /// - Ownership validated against recipient
+    /// - Created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdt_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdt_account: Account<'info, TokenAccount>,
+
+    /// Recipient associated token account for H2COIN
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Destination for H2COIN transfers
+    /// - Ownership validated against recipient
+    /// - Created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Approved Proposal authorizing this call instead of co-signers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Optional; when omitted, signers are passed through remaining_accounts
+    /// - Investment, action and approvals validated in instruction; marked executed
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    /// Withdrawal budget of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The payout is counted against, and capped by, the line of its purpose
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawalBudget::SIZE,
+        seeds = [
+            b"withdraw_budget",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_budget: Account<'info, WithdrawalBudget>,
+
+    /// Withdrawal rate limit of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The payout must fit the rolling-window caps
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawRateLimit::SIZE,
+        seeds = [
+            b"withdraw_rate_limit",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_rate_limit: Account<'info, WithdrawRateLimit>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for ATA creation and transaction fees
+    /// CHECK: validated manually via 3-of-5 multisig inside instruction
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Rent sysvar for account creation
+    /// 
+    /// AUDIT: Required for ATA initialization
+    pub rent: Sysvar<'info, Rent>,
+    
+    /// System program for account operations
+    /// 
+    /// AUDIT: Required for account operations
+    pub system_program: Program<'info, System>,
+    
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+    
+    /// Associated token program for ATA operations
+    /// 
+    /// AUDIT: Required for ATA operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for proposing a timelocked withdrawal
+/// 
+/// AUDIT CRITICAL:
+/// - Same authorization as a co-signed withdraw_from_vault
+/// - Vault balances are read to fix the amounts; nothing is transferred here
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config, vault and vault ATA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides the delay, sequence, tiers and configuration hash
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// RBAC role configuration (optional)
+    /// 
+    /// AUDIT: When provided and the role is assigned, its members and threshold
+    /// replace the legacy whitelist rule
+    #[account(
+        seeds = [
+            b"role_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = role_config.bump
+    )]
+    pub role_config: Option<Account<'info, RoleConfig>>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT: Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA of the investment
+    /// 
+    /// AUDIT: Derived from investment_id and vault_version
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT: Ownership validated against vault PDA
+    #[account(
+        associated_token::mint = usdt_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Vault associated token account for H2COIN
+    /// 
+    /// AUDIT: Ownership validated against vault PDA
+    #[account(
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Recipient of the withdrawal
+    /// 
+    /// AUDIT: Must be in withdraw whitelist (checked in instruction)
+    /// CHECK: Only its address is recorded
+    pub recipient_account: UncheckedAccount<'info>,
+
+    /// Pending withdrawal
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Created here and executed or cancelled after the timelock
+    /// - init fails while another withdrawal of the investment is pending
+    #[account(
+        init,
+        payer = payer,
+        space = PendingWithdrawal::SIZE,
+        seeds = [
+            b"pending_withdrawal",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// Withdrawal budget of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read to reject a withdrawal its purpose cap cannot cover; nothing is counted here
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawalBudget::SIZE,
+        seeds = [
+            b"withdraw_budget",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_budget: Account<'info, WithdrawalBudget>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees and the pending withdrawal rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program owning the vault ATAs
+    pub token_program: Program<'info, Token>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for executing a pending withdrawal
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless once the timelock has elapsed (checked in instruction)
+/// - Transfers the recorded amounts and closes the pending withdrawal to its payer
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config, vault and pending withdrawal PDA validation
+/// - Recipient fixed to the pending withdrawal's recipient
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Holds the withdrawal sequence (anti-replay) and the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for the recipient check
+    /// 
+    /// AUDIT: Provides withdraw_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT: Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA of the investment
+    /// 
+    /// AUDIT: Derived from investment_id and vault_version
+    #[account(mut,
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT: Ownership validated against vault PDA
+    #[account(mut,
+        associated_token::mint = usdt_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Vault associated token account for H2COIN
+    /// 
+    /// AUDIT: Ownership validated against vault PDA
+    #[account(mut,
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Recipient of the withdrawal
+    /// 
+    /// AUDIT: Fixed to the recipient recorded at proposal time
+    /// CHECK: Address constrained to pending_withdrawal.recipient
+    #[account(mut, address = pending_withdrawal.recipient)]
+    pub recipient_account: UncheckedAccount<'info>,
+
+    /// Recipient associated token account for USDT
+    /// 
+    /// AUDIT: Ownership validated against recipient; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdt_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdt_account: Account<'info, TokenAccount>,
+
+    /// Recipient associated token account for H2COIN
+    /// 
+    /// AUDIT: Ownership validated against recipient; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Pending withdrawal to execute
+    /// 
+    /// AUDIT: Closed to proposed_by once executed
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            b"pending_withdrawal",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = pending_withdrawal.bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// Account that paid for the pending withdrawal
+    /// 
+    /// AUDIT: Receives the rent of the closed pending withdrawal
+    /// CHECK: Address fixed to pending_withdrawal.proposed_by
+    #[account(mut, address = pending_withdrawal.proposed_by)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Withdrawal budget of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The payout is counted against, and capped by, the line of the recorded purpose
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawalBudget::SIZE,
+        seeds = [
+            b"withdraw_budget",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_budget: Account<'info, WithdrawalBudget>,
+
+    /// Withdrawal rate limit of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The payout must fit the rolling-window caps at execution time
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawRateLimit::SIZE,
+        seeds = [
+            b"withdraw_rate_limit",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_rate_limit: Account<'info, WithdrawRateLimit>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for recipient ATA creation and transaction fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for SOL transfers and account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+
+    /// Associated token program for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for cancelling a pending withdrawal
+/// 
+/// AUDIT CRITICAL:
+/// - member must belong to any whitelist (checked in instruction)
+/// - Closes the pending withdrawal to the account that paid for it
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and pending withdrawal PDA validation
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides the whitelists; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Pending withdrawal to cancel
+    /// 
+    /// AUDIT: Closed to proposed_by once cancelled
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            b"pending_withdrawal",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = pending_withdrawal.bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+
+    /// Account that paid for the pending withdrawal
+    /// 
+    /// AUDIT: Receives the rent of the closed pending withdrawal
+    /// CHECK: Address fixed to pending_withdrawal.proposed_by
+    #[account(mut, address = pending_withdrawal.proposed_by)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Whitelist member cancelling the withdrawal
+    /// 
+    /// AUDIT: A single signature suffices; also pays the transaction fees
+    pub member: Signer<'info>,
+}
+
+/// Account validation context for sweeping a foreign SPL token out of the vault
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Moves a token other than USDT / H2COIN to InvestmentInfo.recovery_wallet
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and config PDA validation
+/// - Source token account must hold the given mint and be owned by the vault PDA
+/// - Mint must not be USDT, H2COIN or a registered vault mint (checked in instruction)
+/// - Recovery wallet must match InvestmentInfo (checked in instruction)
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct SweepForeignToken<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Provides the recovery wallet and vault seeds
+    /// - Holds the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides execute_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Mint of the foreign token
+    /// 
+    /// AUDIT: Rejected in instruction if it is USDT or H2COIN
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account, authority of the source token account
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and vault_version
+    /// - Signs the transfer
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault-owned token account holding the foreign token
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Any token account of the mint owned by the vault, ATA or not
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// VaultTokenRegistry of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Address fixed by seeds, so the registry cannot be withheld or replaced
+    /// - May not exist yet; registered mints are rejected when it does (checked in instruction)
+    ///   CHECK: Deserialized in instruction only when owned by this program
+    #[account(
+        seeds = [
+            b"vault_token_registry",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub vault_token_registry: UncheckedAccount<'info>,
+
+    /// Recovery wallet
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must equal InvestmentInfo.recovery_wallet (checked in instruction)
+    ///   CHECK: Only used as the authority of the recovery token account
+    pub recovery_wallet: UncheckedAccount<'info>,
+
+    /// Recovery wallet associated token account for the mint
+    /// 
+    /// AUDIT: Destination of the sweep; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recovery_wallet,
+        associated_token::token_program = token_program,
+    )]
+    pub recovery_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for the recovery token account if it does not exist
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+
     /// Associated token program for ATA operations
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for the vault balance snapshot
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless; reads balances and writes only the optional snapshot PDA
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, vault and vault ATA validation
+/// - Caches in remaining_accounts validated in instruction
+#[derive(Accounts)]
+pub struct SnapshotVault<'info> {
+    /// InvestmentInfo of the vault
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Derived from investment_id and vault_version; its lamports are reported
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only read and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    #[account(
+        associated_token::mint = usdt_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Vault associated token account for H2COIN
+    #[account(
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Latest snapshot of the investment (optional)
+    /// 
+    /// AUDIT: Supplied to persist the snapshot; created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LatestVaultSnapshot::SIZE,
+        seeds = [
+            b"vault_snapshot",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub snapshot: Option<Account<'info, LatestVaultSnapshot>>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for the snapshot PDA if it does not exist
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for snapshot creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program of the vault ATAs
+    pub token_program: Program<'info, Token>,
+
+    // 👉 ProfitShareCache / RefundShareCache accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for the invariant health check
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless and read-only; no signer beyond the fee payer
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, vault and vault ATA validation
+/// - Caches and ledgers in remaining_accounts validated in instruction
+#[derive(Accounts)]
+pub struct HealthCheck<'info> {
+    /// InvestmentInfo of the vault
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// H2COIN mint account for validation
+    /// 
+    /// AUDIT: Must match expected H2COIN mint address
+    pub hcoin_mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Derived from investment_id and vault_version; authority of the vault ATAs
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only read and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    #[account(
+        associated_token::mint = usdt_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Vault associated token account for H2COIN
+    #[account(
+        associated_token::mint = hcoin_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_hcoin_account: Account<'info, TokenAccount>,
+
+    /// Token program of the vault ATAs
+    pub token_program: Program<'info, Token>,
+
+    // 👉 ProfitShareCache / RefundShareCache / ProfitAccrualLedger accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for registering an additional vault mint
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Creates the registry on first use and the vault ATA of the mint if needed
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config and vault PDA validation
+/// - Registry PDA derivation binds it to the investment
+/// - Mint must not be the profit mint or H2COIN (checked in instruction)
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct RegisterVaultMint<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id, version, vault seeds and the profit mint
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// VaultTokenRegistry account
+    /// 
+    /// AUDIT: One per investment; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = VaultTokenRegistry::SIZE,
+        seeds = [
+            b"vault_token_registry",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub vault_token_registry: Account<'info, VaultTokenRegistry>,
+
+    /// Mint to register
+    /// 
+    /// AUDIT: Rejected in instruction if it is the profit mint or H2COIN
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Authority of the new vault ATA
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account of the mint
+    /// 
+    /// AUDIT: Canonical ATA; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for the registry and the vault ATA
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program of the vault ATA
+    pub token_program: Program<'info, Token>,
+
+    /// Associated token program for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for deregistering an additional vault mint
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - The vault ATA of the mint must be empty (checked in instruction)
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config, registry and vault PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct DeregisterVaultMint<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id, version and vault seeds
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides update_whitelist; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// VaultTokenRegistry account
+    /// 
+    /// AUDIT: Must list the mint
+    #[account(
+        mut,
+        seeds = [
+            b"vault_token_registry",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = vault_token_registry.bump
+    )]
+    pub vault_token_registry: Account<'info, VaultTokenRegistry>,
+
+    /// Mint to deregister
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Authority of the vault ATA
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account of the mint
+    /// 
+    /// AUDIT: Its balance must be zero
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Accountable party for the change
+    pub payer: Signer<'info>,
+
+    /// Token program of the vault ATA
+    pub token_program: Program<'info, Token>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for re-creating a closed vault ATA
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless; the payer funds the ATA rent
+/// - The ATA address is checked against the canonical vault ATA in instruction
+/// 
+/// SECURITY CHECKS:
+/// - Investment info and vault PDA validation
+/// - Mint must be USDT or H2COIN (checked in instruction)
+#[derive(Accounts)]
+pub struct RepairVaultAta<'info> {
+    /// InvestmentInfo of the vault
+    /// 
+    /// AUDIT: PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide parameters (mints, SOL fee estimates, cache expiry)
+    /// 
+    /// AUDIT: Singleton PDA created by initialize_global_config
+    #[account(
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// USDT or H2COIN mint of the ATA
+    /// 
+    /// AUDIT: Must match the expected USDT or H2COIN mint address
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Authority of the ATA; signs only to clear tampered authorities
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Canonical vault ATA of the mint
+    /// 
+    /// AUDIT: Created through the associated token program when missing
+    /// CHECK: Address and contents validated in instruction
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays the rent of a re-created ATA
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for ATA creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program of the vault ATAs
+    pub token_program: Program<'info, Token>,
+
+    /// Associated token program for ATA creation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Account validation context for initializing the program config
+/// 
+/// AUDIT CRITICAL:
+/// - Creates the singleton ProgramConfig PDA
+/// - Signer must be the program upgrade authority, or be approved by the upgrade
+///   committee once the governance PDA holds (or renounced) it (checked in instruction)
+/// 
+/// SECURITY CHECKS:
+/// - ProgramData account must belong to this program
+/// - Upgrade authority must match the signer, else governance committee 3-of-5
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    /// ProgramConfig account to be created
+    /// 
+    /// AUDIT: Singleton PDA, can only be initialized once
+    #[account(
+        init,
+        payer = payer,
+        space = ProgramConfig::SIZE,
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// This program
+    /// 
+    /// AUDIT: Used to locate its ProgramData account
+    pub program: Program<'info, H2coinVaultShare>,
+
+    /// ProgramData account of this program
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Holds the upgrade authority
+    /// - Validated against the program's programdata address in instruction
+    pub program_data: Account<'info, ProgramData>,
+
+    /// UpgradeGovernance account (optional)
+    /// 
+    /// AUDIT: Required when the governance PDA holds or renounced the upgrade authority;
+    /// its committee then approves the config authority
+    #[account(
+        seeds = [b"upgrade_governance"],
+        bump = governance.bump
+    )]
+    pub governance: Option<Account<'info, UpgradeGovernance>>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: The upgrade authority itself, or a key approved by the upgrade committee;
+    /// may stay a cold key
+    pub authority: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for toggling the program-wide pause
+/// 
+/// AUDIT CRITICAL:
+/// - Signer must be the ProgramConfig authority (checked in instruction)
+#[derive(Accounts)]
+pub struct SetProgramPause<'info> {
+    /// ProgramConfig account
+    /// 
+    /// AUDIT: Must be mutable to store the pause flag
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: Must match program_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for publishing the program limits
+/// 
+/// AUDIT CRITICAL:
+/// - Creates the singleton LimitsConfig PDA
+/// - Signer must be the ProgramConfig authority (checked in instruction)
+#[derive(Accounts)]
+pub struct InitializeLimitsConfig<'info> {
+    /// ProgramConfig singleton
+    /// 
+    /// AUDIT: Provides the config authority
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// LimitsConfig account to be created
+    /// 
+    /// AUDIT: Singleton PDA, can only be initialized once
+    #[account(
+        init,
+        payer = payer,
+        space = LimitsConfig::SIZE,
+        seeds = [b"limits_config"],
+        bump
+    )]
+    pub limits_config: Account<'info, LimitsConfig>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: Must match program_config.authority
+    pub authority: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for overriding published limits
+/// 
+/// AUDIT CRITICAL:
+/// - Signer must be the ProgramConfig authority (checked in instruction)
+#[derive(Accounts)]
+pub struct SetLimitsConfig<'info> {
+    /// ProgramConfig singleton
+    /// 
+    /// AUDIT: Provides the config authority
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// LimitsConfig account
+    /// 
+    /// AUDIT: Must be mutable to store the overrides
+    #[account(
+        mut,
+        seeds = [b"limits_config"],
+        bump
+    )]
+    pub limits_config: Account<'info, LimitsConfig>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: Must match program_config.authority
+    pub authority: Signer<'info>,
+}
+
+/// Account validation context for creating the global parameters
+/// 
+/// AUDIT CRITICAL:
+/// - Creates the singleton GlobalConfig PDA
+/// - Signer must be the ProgramConfig authority (checked in instruction)
+#[derive(Accounts)]
+pub struct InitializeGlobalConfig<'info> {
+    /// ProgramConfig singleton
+    /// 
+    /// AUDIT: Provides the config authority
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// GlobalConfig account to be created
+    /// 
+    /// AUDIT: Singleton PDA, can only be initialized once
+    #[account(
+        init,
+        payer = payer,
+        space = GlobalConfig::SIZE,
+        seeds = [b"global_config"],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: Must match program_config.authority
+    pub authority: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for changing the global parameters
+/// 
+/// AUDIT CRITICAL:
+/// - 3-of-5 committee signatures are passed as remaining accounts
+#[derive(Accounts)]
+pub struct SetGlobalConfig<'info> {
+    /// GlobalConfig account
+    /// 
+    /// AUDIT: Must be mutable to store the new parameters
+    #[account(
+        mut,
+        seeds = [b"global_config"],
+        bump = global_config.bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Transaction payer
+    /// 
+    /// AUDIT: Pays the fee; authorization comes from the committee signatures
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for the deployment readiness check
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only and permissionless
+/// - Accounts are unchecked on purpose: every mismatch is reported, not rejected
+/// 
+/// SECURITY CHECKS:
+/// - All PDA, mint and ATA checks performed in instruction
+#[derive(Accounts)]
+pub struct BootstrapCheck<'info> {
+    /// ProgramConfig singleton
+    /// 
+    /// CHECK: Address and contents validated in instruction
+    pub program_config: UncheckedAccount<'info>,
+
+    /// GlobalConfig singleton
+    /// 
+    /// CHECK: Address and contents validated in instruction
+    pub global_config: UncheckedAccount<'info>,
+
+    /// InvestmentInfo to check
+    /// 
+    /// AUDIT: Provides investment_id, version and bumps for derivations
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig of the investment
+    /// 
+    /// CHECK: Address and contents validated in instruction
+    pub investment_config: UncheckedAccount<'info>,
+
+    /// Vault PDA of the investment
+    /// 
+    /// CHECK: Address and rent validated in instruction
+    pub vault: UncheckedAccount<'info>,
+
+    /// USDT mint
+    /// 
+    /// CHECK: Compared against the expected mint in instruction
+    pub usdt_mint: UncheckedAccount<'info>,
+
+    /// H2COIN mint
+    /// 
+    /// CHECK: Compared against the expected mint in instruction
+    pub hcoin_mint: UncheckedAccount<'info>,
+
+    /// Vault USDT associated token account
+    /// 
+    /// CHECK: ATA address, mint and owner validated in instruction
+    pub vault_usdt_account: UncheckedAccount<'info>,
+
+    /// Vault H2COIN associated token account
+    /// 
+    /// CHECK: ATA address, mint and owner validated in instruction
+    pub vault_hcoin_account: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+}
+
+/// Account validation context for scheduling a distribution round
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Creates the DistributionSchedule PDA on first use
+/// 
+/// SECURITY CHECKS:
+/// - Investment info validation
+/// - Schedule PDA derivation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct ScheduleDistribution<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment parameters and update_whitelist
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// DistributionSchedule account
+    /// 
+    /// AUDIT: One per investment, created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DistributionSchedule::SIZE,
+        seeds = [
+            b"schedule",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump
+    )]
+    pub schedule: Account<'info, DistributionSchedule>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for schedule creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for creating the upgrade governance PDA
+/// 
+/// AUDIT CRITICAL:
+/// - Signer must be the current program upgrade authority (checked in instruction)
+/// - The upgrade authority is handed to the governance PDA in the same instruction
+/// 
+/// SECURITY CHECKS:
+/// - ProgramData must belong to this program
+/// - PDA `init` prevents re-initialization
+#[derive(Accounts)]
+pub struct InitializeUpgradeGovernance<'info> {
+    /// UpgradeGovernance account to be created
+    /// 
+    /// AUDIT: Singleton PDA, becomes the upgrade authority
+    #[account(
+        init,
+        payer = payer,
+        space = UpgradeGovernance::SIZE,
+        seeds = [b"upgrade_governance"],
+        bump
+    )]
+    pub governance: Account<'info, UpgradeGovernance>,
+
+    /// This program
+    /// 
+    /// AUDIT: Used to locate its ProgramData account
+    pub program: Program<'info, H2coinVaultShare>,
+
+    /// ProgramData account of this program
+    /// 
+    /// AUDIT: Mutated by the loader when the authority changes
+    #[account(mut)]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Current upgrade authority
+    /// 
+    /// AUDIT: Signs the authority handoff
+    pub authority: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// BPF upgradeable loader
+    /// 
+    /// AUDIT: Address constraint prevents CPI to an arbitrary program
+    /// CHECK: Address checked against the loader ID
+    #[account(address = anchor_lang::solana_program::bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for handing off or renouncing the upgrade authority
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from the upgrade committee
+/// - new_authority is only used by set_upgrade_authority
+/// 
+/// SECURITY CHECKS:
+/// - Governance PDA derivation with stored bump
+/// - ProgramData must belong to this program
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct ManageUpgradeAuthority<'info> {
+    /// UpgradeGovernance account
+    /// 
+    /// AUDIT: Current upgrade authority, signs the loader CPI
+    #[account(
+        mut,
+        seeds = [b"upgrade_governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, UpgradeGovernance>,
+
+    /// This program
+    /// 
+    /// AUDIT: Used to locate its ProgramData account
+    pub program: Program<'info, H2coinVaultShare>,
+
+    /// ProgramData account of this program
+    /// 
+    /// AUDIT: Mutated by the loader when the authority changes
+    #[account(mut)]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// New upgrade authority (e.g. a multisig vault or governance PDA)
+    /// 
+    /// AUDIT: Required by set_upgrade_authority, ignored by renounce_upgrade_authority
+    /// CHECK: Any address may become the authority; chosen by the committee
+    pub new_authority: Option<UncheckedAccount<'info>>,
+
+    /// BPF upgradeable loader
+    /// 
+    /// AUDIT: Address constraint prevents CPI to an arbitrary program
+    /// CHECK: Address checked against the loader ID
+    #[account(address = anchor_lang::solana_program::bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Committee signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for scheduling a program upgrade window
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from the upgrade committee
+/// - Pins the buffer that may be deployed
+#[derive(Accounts)]
+pub struct ScheduleUpgradeWindow<'info> {
+    /// UpgradeGovernance account
+    /// 
+    /// AUDIT: Stores the approved buffer and window
+    #[account(
+        mut,
+        seeds = [b"upgrade_governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, UpgradeGovernance>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    // 👉 Committee signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for deploying the scheduled upgrade
+/// 
+/// AUDIT CRITICAL:
+/// - Only the buffer approved by the committee, only inside its window
+/// - Executor must be a committee member
+/// 
+/// SECURITY CHECKS:
+/// - Governance PDA derivation with stored bump
+/// - ProgramData must belong to this program
+/// - Buffer must equal governance.window_buffer
+#[derive(Accounts)]
+pub struct ExecuteProgramUpgrade<'info> {
+    /// UpgradeGovernance account
+    /// 
+    /// AUDIT: Upgrade authority, signs the loader CPI; window is consumed
+    #[account(
+        mut,
+        seeds = [b"upgrade_governance"],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, UpgradeGovernance>,
+
+    /// This program
+    /// 
+    /// AUDIT: Writable, as required by the loader upgrade instruction
+    /// CHECK: Address checked against this program's ID
+    #[account(mut, address = crate::ID)]
+    pub program: UncheckedAccount<'info>,
+
+    /// ProgramData account of this program
+    /// 
+    /// AUDIT: Receives the new program bytes
+    #[account(mut)]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// Buffer holding the approved program bytes
+    /// 
+    /// AUDIT: Must equal governance.window_buffer
+    /// CHECK: Validated against the scheduled buffer in instruction; owned by the loader
+    #[account(mut)]
+    pub buffer: UncheckedAccount<'info>,
+
+    /// Receives the buffer's lamports after the upgrade
+    /// 
+    /// CHECK: Plain lamport recipient
+    #[account(mut)]
+    pub spill: UncheckedAccount<'info>,
+
+    /// Rent sysvar required by the loader
+    pub rent: Sysvar<'info, Rent>,
+
+    /// Clock sysvar required by the loader
+    pub clock: Sysvar<'info, Clock>,
+
+    /// BPF upgradeable loader
+    /// 
+    /// AUDIT: Address constraint prevents CPI to an arbitrary program
+    /// CHECK: Address checked against the loader ID
+    #[account(address = anchor_lang::solana_program::bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: UncheckedAccount<'info>,
+
+    /// Committee member executing the approved upgrade
+    /// 
+    /// AUDIT: Must be in governance.committee
+    pub executor: Signer<'info>,
+}
+
+/// Account validation context for setting the standing operating allowance
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Recipient must be on withdraw_whitelist (checked in instruction)
+/// 
+/// SECURITY CHECKS:
+/// - PDA derivation for investment info, config and allowance
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+pub struct SetOperatingAllowance<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Validates investment exists and is active
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// OperatingAllowance account
+    /// 
+    /// AUDIT: One per investment, created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OperatingAllowance::SIZE,
+        seeds = [
+            b"operating_allowance",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump
+    )]
+    pub allowance: Account<'info, OperatingAllowance>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for allowance creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+
+    // 👉 Signers are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for claiming the operating allowance
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless: any payer may submit the claim
+/// - Funds only move from the vault USDT ATA to the allowance recipient's USDT ATA
+/// 
+/// SECURITY CHECKS:
+/// - PDA derivation for investment info, allowance and vault
+/// - Recipient and mint validated in instruction
+/// - Program-wide pause is respected
+#[derive(Accounts)]
+pub struct ClaimOperatingAllowance<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Validates investment exists and is active; mutable for the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// OperatingAllowance account
+    /// 
+    /// AUDIT: Tracks the amount claimed in the current period
+    #[account(
+        mut,
+        seeds = [
+            b"operating_allowance",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+        ],
+        bump = allowance.bump
+    )]
+    pub allowance: Account<'info, OperatingAllowance>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// Vault PDA account used as transfer authority
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - No deserialization needed (AccountInfo)
+    #[account(
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(), 
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT: Source of the allowance transfer
+    #[account(mut, 
+        associated_token::mint = usdt_mint, 
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Recipient wallet
+    /// 
+    /// AUDIT: Must equal allowance.recipient
+    /// CHECK: Validated against the allowance in instruction
+    pub recipient_account: UncheckedAccount<'info>,
+
+    /// Recipient associated token account for USDT
+    /// 
+    /// AUDIT: Destination of the allowance transfer
+    #[account(mut, 
+        associated_token::mint = usdt_mint, 
+        associated_token::authority = recipient_account,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdt_account: Account<'info, TokenAccount>,
+
+    /// Withdrawal budget of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Claims are counted against, and capped by, the OpEx line
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawalBudget::SIZE,
+        seeds = [
+            b"withdraw_budget",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_budget: Account<'info, WithdrawalBudget>,
+
+    /// Withdrawal rate limit of the investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The claim must fit the rolling-window USDT cap
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = WithdrawRateLimit::SIZE,
+        seeds = [
+            b"withdraw_rate_limit",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump
+    )]
+    pub withdraw_rate_limit: Account<'info, WithdrawRateLimit>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees and, on first use, the withdrawal budget rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for withdrawal budget creation
+    pub system_program: Program<'info, System>,
+
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+}
+
+/// Account validation context for returning deposits of a cancelled investment
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Pays each record's amount_usdt from the vault to the record wallet's USDT ATA
+/// 
+/// SECURITY CHECKS:
+/// - PDA derivation for investment info, config and vault
+/// - Record PDA and batch validation in instruction
+/// - Program-wide pause is respected
+#[derive(Accounts)]
+pub struct ReturnDeposits<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Must be in the Cancelled state
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub usdt_mint: Account<'info, Mint>,
+
+    /// Vault PDA account used as transfer authority
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - No deserialization needed (AccountInfo)
+    #[account(
+        seeds = [
+            b"vault", 
+            investment_info.investment_id.as_ref(), 
+            investment_info.vault_version.as_ref()
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT: Source of the returned deposits
+    #[account(mut, 
+        associated_token::mint = usdt_mint, 
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_usdt_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for transaction fees
+    pub payer: Signer<'info>,
+
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+
+    // 👉 remaining_accounts: [signer x3, record_1..record_n (mut), recipient USDT ATAs (mut)]
+}
+
+/// Account validation context for generating an annual statement
+/// 
+/// AUDIT CRITICAL:
+/// - Statement is rebuilt from the records and caches passed in remaining_accounts
+/// - Signer must be in execute_whitelist or update_whitelist (checked in instruction)
+/// 
+/// SECURITY CHECKS:
+/// - PDA derivation for investment info, config and statement
+/// - Record and cache PDA validation in instruction
+#[derive(Accounts)]
+#[instruction(account_id: [u8; 15], year: u16)]
+pub struct GenerateAnnualStatement<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// AnnualStatement account
+    /// 
+    /// AUDIT: One per investment, account_id and year; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AnnualStatement::SIZE,
+        seeds = [
+            b"statement",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            account_id.as_ref(),
+            year.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub statement: Account<'info, AnnualStatement>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for statement creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+
+    // 👉 Signer, InvestmentRecord and cache accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for registering a notification channel
+/// 
+/// AUDIT CRITICAL:
+/// - The wallet signs and pays for its own registration
+/// - Calling again updates the channel and contact in place
+/// 
+/// SECURITY CHECKS:
+/// - PDA derivation binds the registration to the signing wallet
+#[derive(Accounts)]
+pub struct RegisterNotification<'info> {
+    /// NotificationRegistration account
+    /// 
+    /// AUDIT: One per wallet, created if needed
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = NotificationRegistration::SIZE,
+        seeds = [b"notification", wallet.key().as_ref()],
+        bump
+    )]
+    pub registration: Account<'info, NotificationRegistration>,
+
+    /// Investor wallet
+    /// 
+    /// AUDIT: Must sign; pays for the registration
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for removing a notification registration
+/// 
+/// AUDIT CRITICAL:
+/// - Only the registered wallet can close its registration
+/// - Rent is returned to the wallet
+#[derive(Accounts)]
+pub struct UnregisterNotification<'info> {
+    /// NotificationRegistration account
+    /// 
+    /// AUDIT: PDA of the signing wallet; closed to the wallet
+    #[account(
+        mut,
+        seeds = [b"notification", wallet.key().as_ref()],
+        bump = registration.bump,
+        close = wallet
+    )]
+    pub registration: Account<'info, NotificationRegistration>,
+
+    /// Investor wallet
+    /// 
+    /// AUDIT: Must sign; receives the rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+}
+
+/// Account validation context for crediting quarterly profit to an accrual ledger
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from execute_whitelist
+/// - Creates the batch ledger on first accrual
+/// - Reads the vault USDT balance to keep accruals backed
+/// 
+/// SECURITY CHECKS:
+/// - Investment info, config, ledger and vault PDA validation
+/// - Multisig validation through remaining_accounts
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct AccrueProfit<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Validates investment exists and is completed
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides whitelists and stage ratios; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// ProfitAccrualLedger account
+    /// 
+    /// AUDIT: One per investment and batch; created if needed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ProfitAccrualLedger::SIZE,
+        seeds = [
+            b"accrual",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub ledger: Account<'info, ProfitAccrualLedger>,
+
+    /// USDT mint account for validation
     /// 
-    /// AUDIT: Required for ATA operations
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// AUDIT: Must match expected USDT mint address
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account
+    /// 
+    /// AUDIT: Derived from investment_id and version
+    #[account(
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA, validated via seeds. Only its ATA balance is read.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT: Balance must cover every outstanding accrual
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Pays for ledger creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+
+    /// Token program
+    /// 
+    /// AUDIT: Required for the vault ATA constraint
+    pub token_program: Program<'info, Token>,
+
+    // 👉 Signers and InvestmentRecord accounts are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for settling an accrued balance
+/// 
+/// AUDIT CRITICAL:
+/// - Permissionless: the investor or any crank may trigger it
+/// - Funds only move to the wallets recorded in the ledger
+/// 
+/// SECURITY CHECKS:
+/// - Program pause flag
+/// - Ledger and vault PDA validation
+/// - Recipient ATAs matched by address in the instruction
+#[derive(Accounts)]
+#[instruction(batch_id: u16)]
+pub struct SettleAccrual<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment parameters and vault derivation; mutable for the reentrancy flag
+    #[account(
+        mut,
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// Program-wide configuration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Singleton PDA checked for the emergency pause
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Instructions sysvar
+    /// 
+    /// AUDIT: Identifies the top-level program when invoked through CPI
+    /// CHECK: Address constrained to the instructions sysvar
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// ProfitAccrualLedger account
+    /// 
+    /// AUDIT: Must be mutable to zero settled balances
+    #[account(
+        mut,
+        seeds = [
+            b"accrual",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        bump = ledger.bump
+    )]
+    pub ledger: Account<'info, ProfitAccrualLedger>,
+
+    /// USDT mint account for validation
+    /// 
+    /// AUDIT: Must match expected USDT mint address
+    pub mint: Account<'info, Mint>,
+
+    /// Vault PDA account for token transfers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Derived from investment_id and version
+    /// - Used as token transfer authority
+    #[account(mut,
+        seeds = [
+            b"vault",
+            investment_info.investment_id.as_ref(),
+            investment_info.vault_version.as_ref(),
+        ],
+        bump = investment_info.vault_bump
+    )]
+    ///   CHECK: This is a derived vault PDA. It is only used as a token transfer authority and validated via seeds.
+    pub vault: AccountInfo<'info>,
+
+    /// Vault associated token account for USDT
+    /// 
+    /// AUDIT: Source of the settlement transfers
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    /// Transaction payer account
+    /// 
+    /// AUDIT: Investor or crank; pays transaction fees only
+    pub payer: Signer<'info>,
+
+    /// Token program for token transfers
+    /// 
+    /// AUDIT: Required for token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// SPL Memo program
+    /// 
+    /// AUDIT: Tags every token transfer with its reconciliation memo
+    pub memo_program: Program<'info, Memo>,
+
+    // 👉 Recipient ATAs are passed in through `ctx.remaining_accounts`
+}
+
+/// Account validation context for creating a tenant
+/// 
+/// AUDIT CRITICAL:
+/// - Creates the Tenant PDA for one platform customer
+/// - Signer must be the ProgramConfig authority (checked in instruction)
+#[derive(Accounts)]
+#[instruction(tenant_id: [u8; 15])]
+pub struct InitializeTenant<'info> {
+    /// ProgramConfig singleton
+    /// 
+    /// AUDIT: Provides the config authority
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Tenant account to be created
+    /// 
+    /// AUDIT: PDA derived from tenant_id, can only be initialized once
+    #[account(
+        init,
+        payer = payer,
+        space = Tenant::SIZE,
+        seeds = [b"tenant", tenant_id.as_ref()],
+        bump
+    )]
+    pub tenant: Account<'info, Tenant>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: Must match program_config.authority
+    pub authority: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for changing a tenant's admin or defaults
+/// 
+/// AUDIT CRITICAL:
+/// - Signer must be the tenant admin (checked in instruction)
+/// - Existing investments keep the settings they inherited
+#[derive(Accounts)]
+pub struct UpdateTenant<'info> {
+    /// Tenant account
+    /// 
+    /// AUDIT: Must be mutable to store the new defaults
+    #[account(
+        mut,
+        seeds = [b"tenant", tenant.tenant_id.as_ref()],
+        bump = tenant.bump
+    )]
+    pub tenant: Account<'info, Tenant>,
+
+    /// Tenant admin
+    /// 
+    /// AUDIT: Must match tenant.admin
+    pub admin: Signer<'info>,
+}
+
+/// Account validation context for setting the exchange registry
+/// 
+/// AUDIT CRITICAL:
+/// - Creates the ExchangeRegistry singleton on first use
+/// - Signer must be the ProgramConfig authority (checked in instruction)
+#[derive(Accounts)]
+pub struct SetExchangeRegistry<'info> {
+    /// ProgramConfig singleton
+    /// 
+    /// AUDIT: Provides the config authority
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// ExchangeRegistry singleton
+    /// 
+    /// AUDIT: Created if needed; the entry list is replaced as a whole
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ExchangeRegistry::SIZE,
+        seeds = [b"exchange_registry"],
+        bump
+    )]
+    pub exchange_registry: Account<'info, ExchangeRegistry>,
+
+    /// Config authority
+    /// 
+    /// AUDIT: Must match program_config.authority
+    pub authority: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for creating a multisig proposal
+/// 
+/// AUDIT CRITICAL:
+/// - Creates the Proposal PDA with the proposer as first approval
+/// - Proposer must be a member of the whitelist approving the action (checked in instruction)
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CreateProposal<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides the whitelists; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Proposal account to be created
+    /// 
+    /// AUDIT: PDA derived from investment_id, version and proposal_id; initialized once
+    #[account(
+        init,
+        payer = payer,
+        space = Proposal::SIZE,
+        seeds = [
+            b"proposal",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Proposing whitelist member
+    /// 
+    /// AUDIT: Counts as the first approval
+    pub proposer: Signer<'info>,
+
+    /// Fee payer
+    /// 
+    /// AUDIT: Pays for the account; may be a hot wallet distinct from the authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program for account creation
+    /// 
+    /// AUDIT: Required for account initialization
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation context for approving a multisig proposal
+/// 
+/// AUDIT CRITICAL:
+/// - Approver must be a member of the whitelist approving the action (checked in instruction)
+/// - Each approval is its own transaction, so members can approve asynchronously
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// InvestmentInfo account for validation
+    /// 
+    /// AUDIT: Provides investment_id and version for PDA derivation
+    #[account(
+        seeds = [
+            b"investment",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.bump
+    )]
+    pub investment_info: Account<'info, InvestmentInfo>,
+
+    /// InvestmentConfig account for authorization
+    /// 
+    /// AUDIT: Provides the whitelists; PDA validation prevents spoofing
+    #[account(
+        seeds = [
+            b"investment_config",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref()
+        ],
+        bump = investment_info.config_bump
+    )]
+    pub investment_config: Account<'info, InvestmentConfig>,
+
+    /// Proposal account
+    /// 
+    /// AUDIT: Must be mutable to record the approval
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            investment_info.investment_id.as_ref(),
+            investment_info.version.as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Approving whitelist member
+    pub approver: Signer<'info>,
 }
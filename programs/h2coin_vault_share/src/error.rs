@@ -8,14 +8,20 @@
 // Each error represents a specific validation failure or security check.
 // Proper error handling is critical for security and user experience.
 //
-// ERROR CATEGORIES:
-// - General validation errors
-// - Investment info validation errors
-// - Investment record validation errors
-// - Whitelist validation errors
-// - Token validation errors
-// - Profit/refund cache errors
-// - Vault operation errors
+// ERROR CATEGORIES (code ranges):
+// - 6000..=6099 Auth: multisig, whitelists, governance, replay protection
+// - 6100..=6199 State: investment lifecycle and records
+// - 6200..=6299 Token: mints, token accounts, vault funds and transfers
+// - 6300..=6399 Cache: estimation, caches, execution and accrual
+// - 6400..=6499 Config: configuration parameter validation
+// - 6500..=6599 Math: arithmetic failures
+//
+// NUMBERING POLICY:
+// - Each category owns a block of ERROR_CATEGORY_SPAN codes; its first variant
+//   carries the explicit discriminant of the block
+// - New variants are appended at the end of their category
+// - Published codes are never renumbered or reused; a retired variant keeps its slot
+// - error_category() classifies a raw code without string matching
 //
 // SECURITY CONSIDERATIONS:
 // - Error messages should not reveal sensitive information
@@ -36,22 +42,16 @@ use anchor_lang::prelude::*;
 /// - Error categorization helps with security auditing
 #[error_code]
 pub enum ErrorCode {
+    // ════════════════════════════════
+    // 🔐 AUTH ERRORS: 6000..=6099
+    // ════════════════════════════════
+    // AUDIT: Authorization, multisig whitelists, governance and replay protection
+
     // ────────────────────────────────
-    // 📦 GENERAL ERRORS
+    // 🔏 MULTISIG ERRORS
     // ────────────────────────────────
-    // AUDIT: These errors cover fundamental validation failures
-    // SECURITY: Critical for preventing basic attacks and ensuring data integrity
-    
-    /// Mathematical overflow detected during calculations
-    /// 
-    /// AUDIT CRITICAL:
-    /// - Prevents integer overflow attacks
-    /// - Critical for financial calculations
-    /// - Must be checked in all arithmetic operations
-    /// - Prevents calculation errors that could lead to fund loss
-    /// - Common attack vector in DeFi protocols
-    #[msg("🔴 Math overflow.")]
-    NumericalOverflow,
+    // AUDIT: These errors cover multisig signer validation
+    // SECURITY: Critical for preventing unauthorized access to protected operations
 
     /// Unauthorized signer or insufficient signatures for multisig operation
     /// 
@@ -62,7 +62,7 @@ pub enum ErrorCode {
     /// - Critical for protecting vault funds and configuration
     /// - Prevents single point of failure attacks
     #[msg("🔴 Unauthorized signer or not enough signatures.")]
-    UnauthorizedSigner,
+    UnauthorizedSigner = 0,
 
     /// Withdraw whitelist size validation failure
     /// 
@@ -76,76 +76,218 @@ pub enum ErrorCode {
     WhitelistLengthInvalid,
 
     // ────────────────────────────────
-    // 🏗️ INVESTMENT INFO ERRORS
+    // 📋 WHITELIST ERRORS
     // ────────────────────────────────
-    // AUDIT: These errors validate investment configuration
-    // SECURITY: Critical for ensuring proper investment setup and preventing configuration attacks
-    
-    /// Investment ID length validation failure
+    // AUDIT: These errors validate whitelist operations
+    // SECURITY: Critical for ensuring proper multisig configuration and preventing whitelist manipulation
+
+    /// Whitelist size validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures consistent ID format across all operations
-    /// - Prevents ID manipulation attacks
-    /// - Must be exactly 15 bytes
-    /// - Prevents buffer overflow and format confusion
-    /// - Ensures proper PDA derivation
-    #[msg("🔴 Investment ID is too long or too short, must be 15 bytes.")]
-    InvalidInvestmentIdLength,
+    /// - Ensures whitelist has exactly 5 members
+    /// - Required for 3-of-5 multisig security
+    /// - Prevents invalid multisig configurations
+    /// - Prevents DoS through oversized whitelists
+    /// - Ensures consistent security model
+    #[msg("🔴 Whitelist must contain exactly 5 members")]
+    WhitelistMustBeFive,
 
-    /// Stage ratio array length validation failure
+    /// Whitelist duplicate address validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures proper stage ratio configuration
-    /// - Prevents array out-of-bounds access
-    /// - Each stage must have exactly 10 elements
-    /// - Prevents memory corruption and calculation errors
-    /// - Ensures consistent refund distribution structure
-    #[msg("🔴 stage_ratio length per stage must be exactly 10 elements.")]
-    InvalidStageRatioLength,
+    /// - Prevents duplicate addresses in whitelist
+    /// - Ensures unique multisig members
+    /// - Prevents multisig manipulation
+    /// - Prevents concentration of power
+    /// - Ensures proper multisig diversity
+    #[msg("🔴 Target address already exists in whitelist")]
+    WhitelistAddressExists,
 
-    /// Stage ratio value validation failure
+    /// Whitelist address not found validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures percentage values are within valid range
-    /// - Prevents invalid refund calculations
-    /// - Must be between 0 and 100
-    /// - Prevents mathematical errors in distribution
-    /// - Ensures proper percentage representation
-    #[msg("🔴 Stage ratio value must be between 0 and 100.")]
-    InvalidStageRatioValue,
+    /// - Ensures address exists before replacement
+    /// - Prevents unauthorized whitelist modifications
+    /// - Address must exist to be replaced
+    /// - Prevents whitelist manipulation attacks
+    /// - Ensures proper whitelist management
+    #[msg("🔴 Address to be replaced not found in whitelist")]
+    WhitelistAddressNotFound,
 
-    /// Stage ratio sum validation failure
+    // ────────────────────────────────
+    // 🛑 PROGRAM CONFIG ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate the program-wide configuration
+    // SECURITY: Critical for the emergency stop across all investments
+
+    /// Program-wide pause is active
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures total percentage doesn't exceed 100%
-    /// - Prevents over-allocation of refunds
-    /// - Sum for a single stage must not exceed 100
-    /// - Prevents fund exhaustion attacks
-    /// - Ensures proper allocation distribution
-    #[msg("🔴 Stage ratio sum for a single stage must not exceed 100.")]
-    InvalidStageRatioSum,
+    /// - Execution and withdrawal instructions are blocked for every investment
+    /// - Set by the program config authority during incidents
+    #[msg("🔴 Program is paused by the config authority.")]
+    ProgramPaused,
 
-    /// Stage ratio contiguity validation failure
+    /// Signer is not the program config authority
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures non-zero values are contiguous
-    /// - Prevents gaps in refund distribution
-    /// - Once non-zero values begin, must be contiguous
-    /// - Prevents confusion in refund timing
-    /// - Ensures proper refund schedule
-    #[msg("🔴 Stage ratio must be contiguous once non-zero values begin.")]
-    NonContiguousStage,
+    /// - Only the config authority may toggle the pause
+    /// - Initialization requires the program upgrade authority
+    #[msg("🔴 Signer is not the program config authority.")]
+    UnauthorizedConfigAuthority,
 
-    /// Empty stage ratio validation failure
+    // ────────────────────────────────
+    // 🛠️ UPGRADE GOVERNANCE ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors protect program upgradability
+
+    /// Signer is not the current upgrade authority
+    /// 
+    /// AUDIT: Only the current upgrade authority may hand it to the governance PDA
+    #[msg("🔴 Signer is not the program upgrade authority.")]
+    UnauthorizedUpgradeAuthority,
+
+    /// Governance PDA no longer controls upgrades
+    /// 
+    /// AUDIT: Authority was handed off or renounced
+    #[msg("🔴 Upgrade governance no longer holds the upgrade authority.")]
+    UpgradeAuthorityReleased,
+
+    /// Invalid upgrade window
+    /// 
+    /// AUDIT: Window must end in the future, after it starts, within MAX_UPGRADE_WINDOW_SECS
+    #[msg("🔴 Invalid upgrade window.")]
+    InvalidUpgradeWindow,
+
+    /// No open upgrade window for this buffer
+    /// 
+    /// AUDIT: Upgrades only deploy the approved buffer inside its window
+    #[msg("🔴 Upgrade window is not open for this buffer.")]
+    UpgradeWindowClosed,
+
+    // ────────────────────────────────
+    // 🔁 WITHDRAWAL REPLAY ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors prevent re-broadcast of signed withdrawals
+
+    /// Withdrawal sequence mismatch
+    /// 
+    /// AUDIT: Argument must equal InvestmentInfo.withdraw_sequence
+    #[msg("🔴 Withdrawal sequence does not match; re-sign with the current sequence.")]
+    WithdrawSequenceMismatch,
+
+    // ────────────────────────────────
+    // ⚖️ SIGNER WEIGHT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors guard weighted multisig configuration
+
+    /// Signer weights are invalid
+    /// 
+    /// AUDIT: Threshold must be reachable by the whitelist but not by any single member
+    #[msg("🔴 Invalid signer weights or threshold.")]
+    InvalidSignerWeights,
+
+    // ────────────────────────────────
+    // 🔑 IDEMPOTENCY ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors stop retried administrative transactions from applying twice
+
+    /// Idempotency key already consumed
+    /// 
+    /// AUDIT: The operation carrying this key has already been applied
+    #[msg("🔴 Idempotency key was already used; the operation has been applied.")]
+    IdempotencyKeyReused,
+
+    // ────────────────────────────────
+    // 🧬 CONFIG SNAPSHOT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors bind execution approvals to an exact configuration
+
+    /// Configuration hash mismatch
+    /// 
+    /// AUDIT: The configuration changed after the signers approved the transaction
+    #[msg("🔴 Investment configuration hash does not match the approved snapshot.")]
+    ConfigHashMismatch,
+
+    // ────────────────────────────────
+    // 🏢 TENANT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors guard tenant administration
+
+    /// Signer is not the tenant admin
+    /// 
+    /// AUDIT: Only the tenant admin maintains tenant defaults and creates investments under it
+    #[msg("🔴 Signer is not the tenant admin.")]
+    UnauthorizedTenantAdmin,
+
+    // ────────────────────────────────
+    // 🗳️ PROPOSAL ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors guard asynchronous multisig approval through Proposal PDAs
+
+    /// Proposal is past its approval window
+    /// 
+    /// AUDIT: Approvals and execution stop at expires_at; a new proposal must be created
+    #[msg("🔴 Proposal has expired.")]
+    ProposalExpired,
+
+    /// Proposal was already executed
+    /// 
+    /// AUDIT: A proposal authorizes exactly one execution
+    #[msg("🔴 Proposal has already been executed.")]
+    ProposalAlreadyExecuted,
+
+    /// Signer already approved the proposal
+    /// 
+    /// AUDIT: Each whitelist member counts once
+    #[msg("🔴 Signer has already approved this proposal.")]
+    ProposalAlreadyApproved,
+
+    /// Executed action differs from the proposal
+    /// 
+    /// AUDIT: The instruction arguments and investment must equal what the approvers signed off on
+    #[msg("🔴 Instruction does not match the approved proposal.")]
+    ProposalActionMismatch,
+
+    /// Fund-moving instruction invoked through CPI from an untrusted program
+    /// 
+    /// AUDIT: Only top-level calls, or a direct CPI from the Squads program, may move vault funds
+    #[msg("🔴 Instruction must not be invoked through CPI from this program.")]
+    UntrustedCpiCaller,
+
+    /// Transaction payer is one of the approving signers
+    /// 
+    /// AUDIT: With the four-eyes rule of record edits enabled, the preparer (payer) must differ from every approving signer
+    #[msg("🔴 Payer must not be one of the approving signers.")]
+    PayerIsApprover,
+
+    /// Claim fee payer is neither the claimant nor the claim relayer
+    /// 
+    /// AUDIT: Sponsored claims are paid by GlobalConfig.claim_relayer only; Pubkey::default() disables sponsorship
+    #[msg("🔴 Claim fee payer is not the configured claim relayer.")]
+    UnauthorizedClaimRelayer,
+
+    // ════════════════════════════════
+    // 🏗️ STATE ERRORS: 6100..=6199
+    // ════════════════════════════════
+    // AUDIT: Investment lifecycle and investment record state
+
+    // ────────────────────────────────
+    // 🏗️ INVESTMENT INFO ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate investment configuration
+    // SECURITY: Critical for ensuring proper investment setup and preventing configuration attacks
+
+    /// Investment ID length validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures at least one stage has non-zero ratios
-    /// - Prevents investment with no refund allocation
-    /// - At least one stage must have non-zero values
-    /// - Prevents investments with no refund mechanism
-    /// - Ensures proper investment structure
-    #[msg("🔴 All stage ratio values are zero.")]
-    EmptyStageRatio,
+    /// - Ensures consistent ID format across all operations
+    /// - Prevents ID manipulation attacks
+    /// - Must be exactly 15 bytes
+    /// - Prevents buffer overflow and format confusion
+    /// - Ensures proper PDA derivation
+    #[msg("🔴 Investment ID is too long or too short, must be 15 bytes.")]
+    InvalidInvestmentIdLength = 100,
 
     /// Investment info account not found
     /// 
@@ -207,7 +349,7 @@ pub enum ErrorCode {
     // ────────────────────────────────
     // AUDIT: These errors validate investment record operations
     // SECURITY: Critical for ensuring proper record management and preventing record manipulation
-    
+
     /// Record ID mismatch validation failure
     /// 
     /// AUDIT CRITICAL:
@@ -218,7 +360,7 @@ pub enum ErrorCode {
     /// - Ensures proper record identification
     #[msg("🔴 Record ID mismatch.")]
     RecordIdMismatch,
-    
+
     /// Account ID mismatch validation failure
     /// 
     /// AUDIT CRITICAL:
@@ -295,52 +437,275 @@ pub enum ErrorCode {
     /// - Ensures proper operation validation
     #[msg("🔴 No record has been updated.")]
     NoRecordsUpdated,
-    
+
     // ────────────────────────────────
-    // 📋 WHITELIST ERRORS
+    // 🔗 WALLET BINDING ERRORS
     // ────────────────────────────────
-    // AUDIT: These errors validate whitelist operations
-    // SECURITY: Critical for ensuring proper multisig configuration and preventing whitelist manipulation
-    
-    /// Whitelist size validation failure
+    // AUDIT: These errors validate records created without a wallet
+    // SECURITY: Critical for ensuring only the attested investor binds a record
+
+    /// Partial recipient account set
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures whitelist has exactly 5 members
-    /// - Required for 3-of-5 multisig security
-    /// - Prevents invalid multisig configurations
-    /// - Prevents DoS through oversized whitelists
-    /// - Ensures consistent security model
-    #[msg("🔴 Whitelist must contain exactly 5 members")]
-    WhitelistMustBeFive,
+    /// - recipient_account and both ATAs must be provided or omitted together
+    /// - Prevents records with a wallet but unvalidated token accounts
+    #[msg("🔴 Recipient account and its token accounts must be provided together.")]
+    IncompleteRecipientAccounts,
 
-    /// Whitelist duplicate address validation failure
+    /// Record wallet already bound
     /// 
     /// AUDIT CRITICAL:
-    /// - Prevents duplicate addresses in whitelist
-    /// - Ensures unique multisig members
-    /// - Prevents multisig manipulation
-    /// - Prevents concentration of power
-    /// - Ensures proper multisig diversity
-    #[msg("🔴 Target address already exists in whitelist")]
-    WhitelistAddressExists,
+    /// - bind_wallet only applies to records created without a wallet
+    /// - Changing a bound wallet requires the 3-of-5 update flow
+    #[msg("🔴 Record wallet is already bound.")]
+    WalletAlreadyBound,
 
-    /// Whitelist address not found validation failure
+    // ────────────────────────────────
+    // ⚖️ REVOCATION CONTEST ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate revocation disputes
+    // SECURITY: Only the affected investor may contest, within the holding period
+
+    /// Signer is not the record's investor
     /// 
-    /// AUDIT CRITICAL:
-    /// - Ensures address exists before replacement
-    /// - Prevents unauthorized whitelist modifications
-    /// - Address must exist to be replaced
-    /// - Prevents whitelist manipulation attacks
-    /// - Ensures proper whitelist management
-    #[msg("🔴 Address to be replaced not found in whitelist")]
-    WhitelistAddressNotFound,
+    /// AUDIT: contest_revocation must be signed by the record wallet
+    #[msg("🔴 Signer is not the investor of this record.")]
+    UnauthorizedInvestor,
+
+    /// Record is not revoked
+    /// 
+    /// AUDIT: Only revoked records can be contested
+    #[msg("🔴 Investment record is not revoked.")]
+    RecordNotRevoked,
+
+    /// Revocation already contested
+    /// 
+    /// AUDIT: A record can have at most one open contest
+    #[msg("🔴 Revocation has already been contested.")]
+    RevocationAlreadyContested,
+
+    /// Holding period elapsed
+    /// 
+    /// AUDIT: Contests are only accepted within REVOCATION_HOLD_SECS
+    #[msg("🔴 Revocation holding period has expired.")]
+    RevocationHoldExpired,
+
+    /// No open contest
+    /// 
+    /// AUDIT: resolve_revocation_contest requires an open contest
+    #[msg("🔴 Revocation is not contested.")]
+    RevocationNotContested,
+
+    // ────────────────────────────────
+    // 📤 RECORD EXPORT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors keep exported record pages well-defined
+
+    /// Record outside the requested page
+    /// 
+    /// AUDIT: record_id must fall within the page's RECORD_PAGE_SIZE range
+    #[msg("🔴 Record is outside the requested page.")]
+    RecordOutsidePage,
+
+    // ────────────────────────────────
+    // ↩️ CANCELLATION ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors guard the cancelled state and deposit returns
+
+    /// Investment is not pending
+    /// 
+    /// AUDIT: Only a pending investment can be cancelled
+    #[msg("🔴 Investment info is not pending.")]
+    InvestmentInfoNotPending,
+
+    /// Investment has been cancelled
+    /// 
+    /// AUDIT: Cancelled investments accept no records, completion or distributions
+    #[msg("🔴 Investment info has been cancelled.")]
+    InvestmentInfoCancelled,
+
+    /// Investment has not been cancelled
+    /// 
+    /// AUDIT: Deposits are only returned for cancelled investments
+    #[msg("🔴 Investment info has not been cancelled.")]
+    InvestmentInfoNotCancelled,
+
+    // ────────────────────────────────
+    // ✍️ RECORD ACKNOWLEDGMENT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors protect the two-phase record addition flow
+
+    /// Provisional record has no wallet
+    /// 
+    /// AUDIT: The investor wallet must be known so it can acknowledge the record
+    #[msg("🔴 A provisional record requires recipient accounts.")]
+    ProvisionalRecordUnbound,
+
+    /// Record is not awaiting acknowledgment
+    /// 
+    /// AUDIT: The record was added without the two-phase flow or is already acknowledged
+    #[msg("🔴 Record is not awaiting acknowledgment.")]
+    RecordNotProvisional,
+
+    /// Acknowledgment window has closed
+    /// 
+    /// AUDIT: RECORD_ACK_WINDOW_SECS elapsed since the record was added
+    #[msg("🔴 Record acknowledgment window has closed.")]
+    AcknowledgmentWindowClosed,
+
+    // ────────────────────────────────
+    // 🚪 INVESTMENT OPENING ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors guard the Init review window
+
+    /// Investment info not in Init
+    /// 
+    /// AUDIT: Only an investment initialized with defer_open can be opened, and only once
+    #[msg("🔴 Investment info is not in Init.")]
+    InvestmentInfoNotInit,
+
+    // ────────────────────────────────
+    // ⏸️ INSTRUCTION PAUSE ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors enforce per-investment pauses of instruction families
+
+    /// Instruction family paused for this investment
+    /// 
+    /// AUDIT: Set by the update committee through set_instruction_pause; other families keep running
+    #[msg("🔴 This instruction family is paused for the investment.")]
+    InstructionFamilyPaused,
+
+    /// Pending whitelist patch is still in its timelock
+    /// 
+    /// AUDIT: apply_whitelist_patch is only allowed from the pending patch's effective_at
+    #[msg("🔴 Whitelist patch timelock has not elapsed.")]
+    WhitelistPatchNotReady,
+
+    /// Backfilled created_at lies outside the investment period
+    /// 
+    /// AUDIT: backfill_investment_record requires start_at <= created_at <= min(end_at, now)
+    #[msg("🔴 Backfill timestamp is outside the investment period.")]
+    InvalidBackfillTimestamp,
+
+    /// Incoming whitelist member has not accepted the seat
+    /// 
+    /// AUDIT: apply_whitelist_patch requires accept_whitelist_seat signed by the pending patch's `to` key
+    #[msg("🔴 New whitelist member has not accepted the seat.")]
+    WhitelistSeatNotAccepted,
+
+    /// Checkpoint emitted before CHECKPOINT_MIN_INTERVAL_SECS elapsed
+    /// 
+    /// AUDIT: emit_checkpoint is rate-limited per investment so the sequence cannot be spammed
+    #[msg("🔴 Checkpoint interval has not elapsed.")]
+    CheckpointTooSoon,
+
+    /// Investment is under an emergency freeze
+    /// 
+    /// AUDIT: Set by any single execute / update member through freeze_investment; blocks FREEZE_FAMILIES until unfreeze_investment
+    #[msg("🔴 Investment is frozen.")]
+    InvestmentFrozen,
+
+    /// unfreeze_investment called on an investment that is not frozen
+    /// 
+    /// AUDIT: Rejects a no-op unfreeze so the InvestmentUnfrozen event always ends a freeze
+    #[msg("🔴 Investment is not frozen.")]
+    InvestmentNotFrozen,
+
+    /// Fund-moving instruction re-entered while another one is running
+    /// 
+    /// AUDIT: InvestmentInfo.execution_in_progress is persisted at entry and cleared at exit
+    #[msg("🔴 Another fund-moving instruction is in progress for this investment.")]
+    ExecutionInProgress,
+
+    /// Direct withdrawal attempted while the withdrawal timelock is on
+    /// 
+    /// AUDIT: With InvestmentInfo.withdraw_delay_secs set, funds leave the vault only through propose_withdrawal / execute_withdrawal
+    #[msg("🔴 Withdrawal timelock is active; use propose_withdrawal.")]
+    WithdrawTimelockActive,
+
+    /// Withdrawal proposed while no withdrawal timelock is set
+    /// 
+    /// AUDIT: propose_withdrawal requires set_withdraw_delay to have been called first
+    #[msg("🔴 Withdrawal timelock is not configured.")]
+    WithdrawTimelockDisabled,
+
+    /// Pending withdrawal is still in its timelock
+    /// 
+    /// AUDIT: execute_withdrawal is only allowed from the pending withdrawal's executable_at
+    #[msg("🔴 Withdrawal timelock has not elapsed.")]
+    WithdrawalNotReady,
+
+    /// Withdrawal exceeds the budget of its purpose
+    /// 
+    /// AUDIT: A capped cumulative total of the WithdrawalBudget line would pass its committee-approved cap
+    #[msg("🔴 Withdrawal exceeds the budget cap of its purpose.")]
+    WithdrawBudgetExceeded,
+
+    /// Withdrawal exceeds the rolling-window rate limit
+    /// 
+    /// AUDIT: USDT or H2COIN withdrawn within the current WithdrawRateLimit window plus this payout would pass its cap
+    #[msg("🔴 Withdrawal exceeds the rate limit of the current window.")]
+    WithdrawRateLimitExceeded,
+
+    /// Investment is still active
+    /// 
+    /// AUDIT: close_vault only runs after deactivate_investment_info
+    #[msg("🔴 Investment must be deactivated first.")]
+    InvestmentStillActive,
+
+    /// Vault is operated by a later version
+    /// 
+    /// AUDIT: link_shared_vault handed this vault to a later version; only that version may close it
+    #[msg("🔴 Vault has been handed off to a later version.")]
+    VaultHandedOff,
+
+    /// Cache entry is not awaiting a requeued payout
+    /// 
+    /// AUDIT: requeue_entry only pays entries that execution marked Requeued
+    #[msg("🔴 Cache entry is not requeued.")]
+    EntryNotRequeued,
+
+    /// Investor directory capacity failure
+    /// 
+    /// AUDIT: An InvestorDirectory links at most MAX_DIRECTORY_ENTRIES records
+    #[msg("🔴 Investor directory is full.")]
+    InvestorDirectoryFull,
+
+    /// Investment upper limit failure
+    /// 
+    /// AUDIT: The cumulative amount_usdt of all records (sum of InvestmentInfo.invested_usdt) may never exceed investment_upper_limit
+    #[msg("🔴 Investment upper limit would be exceeded.")]
+    InvestmentUpperLimitExceeded,
+
+    /// A profit round or refund year is neither settled nor waived
+    /// 
+    /// AUDIT: archive_investment requires every round of every batch to be executed with all entries paid, or explicitly waived
+    #[msg("🔴 Final settlement incomplete: a distribution round is neither settled nor waived.")]
+    SettlementIncomplete,
+
+    /// Revocation hold has not ended
+    /// 
+    /// AUDIT: release_revocation_hold waits until REVOCATION_HOLD_SECS have passed and no contest is open
+    #[msg("🔴 Revocation is still within its holding period or contested.")]
+    RevocationStillHeld,
+
+    /// Revocation hold already released
+    /// 
+    /// AUDIT: A revoked record leaves the profit denominator once
+    #[msg("🔴 Revocation hold has already been released.")]
+    RevocationHoldAlreadyReleased,
+
+    // ════════════════════════════════
+    // 💰 TOKEN ERRORS: 6200..=6299
+    // ════════════════════════════════
+    // AUDIT: Mints, token accounts, vault funds and transfers
 
     // ────────────────────────────────
     // 💰 TOKEN VALIDATION ERRORS
     // ────────────────────────────────
     // AUDIT: These errors validate token operations
     // SECURITY: Critical for ensuring proper token handling and preventing token theft
-    
+
     /// Vault PDA validation failure
     /// 
     /// AUDIT CRITICAL:
@@ -350,7 +715,7 @@ pub enum ErrorCode {
     /// - Prevents unauthorized vault access
     /// - Ensures proper vault ownership
     #[msg("🔴 Invalid Vault PDA")]
-    InvalidVaultPda,
+    InvalidVaultPda = 200,
 
     /// Vault token account mint validation failure
     /// 
@@ -418,12 +783,216 @@ pub enum ErrorCode {
     #[msg("🔴 Recipient token account owner mismatch.")]
     InvalidRecipientOwner,
 
+    // ────────────────────────────────
+    // 📈 DEPOSIT / WITHDRAW ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate vault deposit and withdrawal operations
+    // SECURITY: Critical for ensuring proper fund management and preventing unauthorized access
+
+    /// Empty whitelist validation failure
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Ensures whitelist has at least one member
+    /// - Prevents operations with empty whitelist
+    /// - Must have at least one authorized member
+    /// - Prevents operations without authorization
+    /// - Ensures proper access control
+    #[msg("🔴 Whitelist must contain at least one wallet.")]
+    EmptyWhitelist,
+
+    /// Invalid recipient wallet address
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Ensures valid recipient address format
+    /// - Prevents transfers to invalid addresses
+    /// - Address must be valid Pubkey
+    /// - Prevents fund loss to invalid addresses
+    /// - Ensures proper address validation
+    #[msg("🔴 Invalid Recipient wallet Address")]
+    InvalidRecipientAddress,
+
+    /// Unauthorized recipient validation failure
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Ensures recipient is in withdraw whitelist
+    /// - Prevents unauthorized withdrawals
+    /// - Recipient must be authorized
+    /// - Prevents fund theft
+    /// - Ensures proper withdrawal authorization
+    #[msg("🔴 Recipient wallet is not in the withdraw whitelist.")]
+    UnauthorizedRecipient,
+
+    /// Invalid associated token account
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Ensures correct ATA derivation
+    /// - Prevents ATA spoofing attacks
+    /// - ATA must match expected address
+    /// - Prevents unauthorized token account access
+    /// - Ensures proper token account ownership
+    #[msg("🔴 Invalid associated token account.")]
+    InvalidAssociatedTokenAccount,
+
+    /// Invalid token program ID
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Ensures correct token program is used
+    /// - Prevents unauthorized token operations
+    /// - Must be Token 2020 (Legacy) program
+    /// - Prevents use of unauthorized token programs
+    /// - Ensures proper token program validation
+    #[msg("🔴 Invalid token program ID. Must be Token 2020(Legacy).")]
+    InvalidTokenProgramID,
+
+    /// Invalid associated token program ID
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Ensures correct ATA program is used
+    /// - Prevents unauthorized ATA operations
+    /// - Must be Associated Token Program
+    /// - Prevents use of unauthorized ATA programs
+    /// - Ensures proper ATA program validation
+    #[msg("🔴 Invalid associated token program ID.")]
+    InvalidAssociatedTokenProgramID,
+
+    // ────────────────────────────────
+    // 🧾 OPERATING ALLOWANCE ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors bound standing operating-expense withdrawals
+
+    /// Invalid allowance period
+    /// 
+    /// AUDIT: period_secs must be at least MIN_ALLOWANCE_PERIOD_SECS
+    #[msg("🔴 Invalid operating allowance period.")]
+    InvalidAllowancePeriod,
+
+    /// Allowance disabled
+    /// 
+    /// AUDIT: amount is 0 or the allowance was never set
+    #[msg("🔴 Operating allowance is not active.")]
+    OperatingAllowanceInactive,
+
+    /// Claim exceeds the remaining allowance for the period
+    /// 
+    /// AUDIT: claimed_in_period + amount must not exceed the allowance
+    #[msg("🔴 Claim exceeds the operating allowance for this period.")]
+    OperatingAllowanceExceeded,
+
+    // ────────────────────────────────
+    // ⛽ VAULT GAS ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors keep the vault able to pay for operations
+
+    /// Vault would not be rent-exempt
+    /// 
+    /// AUDIT: Post-deposit lamports must cover the rent-exempt minimum
+    #[msg("🔴 Vault balance would be below the rent-exempt minimum.")]
+    VaultBelowRentExempt,
+
+    // ────────────────────────────────
+    // 🔗 SHARED VAULT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors protect vault co-location between versions
+
+    /// Vault link is invalid
+    /// 
+    /// AUDIT: Versions must differ, the new version must not be linked yet and its own vault ATAs must be empty
+    #[msg("🔴 Invalid shared vault link.")]
+    InvalidVaultLink,
+
+    // ────────────────────────────────
+    // 🎯 RECIPIENT ATA ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors explain why an execution entry was skipped
+
+    /// Recipient ATA not initialized
+    /// 
+    /// AUDIT: The address matches the derived ATA but the account is closed or was never created
+    #[msg("🔴 Recipient ATA is closed or not initialized.")]
+    RecipientAtaNotInitialized,
+
+    // ────────────────────────────────
+    // 🏦 EXCHANGE WALLET ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors keep record wallets away from exchange deposit wallets unless explicitly intended
+
+    /// Recipient wallet belongs to a listed exchange
+    /// 
+    /// AUDIT: Payouts to exchange deposit wallets are frequently lost; pass allow_exchange_wallet for intentional cases
+    #[msg("🔴 Recipient wallet matches the exchange registry; set allow_exchange_wallet to proceed.")]
+    ExchangeWalletNotAllowed,
+
+    /// Amount given for an asset not selected by assets
+    /// 
+    /// AUDIT: withdraw_from_vault rejects amount_usdt / amount_hcoin / amount_sol for an asset that assets excludes
+    #[msg("🔴 Withdrawal amount given for an unselected asset.")]
+    WithdrawAmountForUnselectedAsset,
+
+    /// Requested withdrawal amount exceeds the vault balance
+    /// 
+    /// AUDIT: USDT / H2COIN are capped by the vault ATA balance, SOL by the balance above the rent-exempt minimum and execution reserve
+    #[msg("🔴 Withdrawal amount exceeds the vault balance.")]
+    WithdrawAmountExceedsBalance,
+
+    /// Memo program is not SPL Memo
+    /// 
+    /// AUDIT: Every token transfer is tagged through the SPL Memo program (anchor_spl::memo::ID)
+    #[msg("🔴 Invalid memo program.")]
+    InvalidMemoProgram,
+
+    /// Sweep targets a USDT or H2COIN token account
+    /// 
+    /// AUDIT: sweep_foreign_token only moves mints other than USDT / H2COIN; those leave the vault through the withdrawal paths
+    #[msg("🔴 USDT and H2coin cannot be swept; use a withdrawal.")]
+    NotForeignToken,
+
+    /// Vault token account still holds tokens
+    /// 
+    /// AUDIT: close_vault requires both vault ATAs to be empty; withdraw the balances first
+    #[msg("🔴 Vault token account is not empty.")]
+    VaultNotEmpty,
+
+    /// Mint decimals differ from the investment
+    /// 
+    /// AUDIT: The payout mint has other decimals than recorded in InvestmentInfo at initialization, so cached amounts would be mis-scaled
+    #[msg("🔴 Mint decimals do not match the investment.")]
+    MintDecimalsMismatch,
+
+    /// Mint already held by the vault
+    /// 
+    /// AUDIT: register_vault_mint rejects the profit mint, H2COIN and mints already in the VaultTokenRegistry
+    #[msg("🔴 Mint is already registered for this vault.")]
+    VaultMintAlreadyRegistered,
+
+    /// Mint not registered
+    /// 
+    /// AUDIT: deregister_vault_mint only removes mints listed in the VaultTokenRegistry
+    #[msg("🔴 Mint is not registered for this vault.")]
+    VaultMintNotRegistered,
+
+    /// Vault token registry full
+    /// 
+    /// AUDIT: At most MAX_VAULT_MINTS additional mints per vault
+    #[msg("🔴 Vault token registry is full.")]
+    VaultTokenRegistryFull,
+
+    /// Registered vault ATA not empty
+    /// 
+    /// AUDIT: A mint is deregistered only once its vault ATA is empty, so no balance is left untracked
+    #[msg("🔴 Vault still holds tokens of this mint.")]
+    VaultMintBalanceNotZero,
+
+    // ════════════════════════════════
+    // 📈 CACHE ERRORS: 6300..=6399
+    // ════════════════════════════════
+    // AUDIT: Profit/refund estimation, caches, execution and accrual
+
     // ────────────────────────────────
     // 📈 PROFIT SHARE CACHE ERRORS
     // ────────────────────────────────
     // AUDIT: These errors validate profit distribution operations
     // SECURITY: Critical for ensuring proper profit distribution and preventing financial attacks
-    
+
     /// Investment type validation failure for profit sharing
     /// 
     /// AUDIT CRITICAL:
@@ -433,7 +1002,7 @@ pub enum ErrorCode {
     /// - Prevents unauthorized profit distributions
     /// - Ensures proper investment type handling
     #[msg("🔴 Investment type must be `Standard`.")]
-    StandardOnly,
+    StandardOnly = 300,
 
     /// Total share validation failure
     /// 
@@ -456,7 +1025,7 @@ pub enum ErrorCode {
     /// - Ensures proper profit distribution workflow
     #[msg("🔴 Profit share cache not found.")]
     ProfitCacheNotFound,
-    
+
     /// Profit share cache expiration validation failure
     /// 
     /// AUDIT CRITICAL:
@@ -583,7 +1152,7 @@ pub enum ErrorCode {
     // ────────────────────────────────
     // AUDIT: These errors validate refund distribution operations
     // SECURITY: Critical for ensuring proper refund distribution and preventing financial attacks
-    
+
     /// Refund share cache expiration validation failure
     /// 
     /// AUDIT CRITICAL:
@@ -662,74 +1231,496 @@ pub enum ErrorCode {
     InvalidRefundCachePda,
 
     // ────────────────────────────────
-    // 📈 DEPOSIT / WITHDRAW ERRORS
+    // 📅 DISTRIBUTION SCHEDULE ERRORS
     // ────────────────────────────────
-    // AUDIT: These errors validate vault deposit and withdrawal operations
-    // SECURITY: Critical for ensuring proper fund management and preventing unauthorized access
-    
-    /// Empty whitelist validation failure
+    // AUDIT: These errors validate the distribution calendar
+    // SECURITY: Keeps planned rounds unique and bounded
+
+    /// Schedule is full
+    /// 
+    /// AUDIT: At most MAX_SCHEDULE_SLOTS rounds can be planned
+    #[msg("🔴 Distribution schedule is full.")]
+    ScheduleFull,
+
+    /// Round already scheduled
+    /// 
+    /// AUDIT: (kind, batch_id, year_index) must be unique
+    #[msg("🔴 This distribution round is already scheduled.")]
+    ScheduleSlotExists,
+
+    /// No slot for the executed round
+    /// 
+    /// AUDIT: An execution referencing a schedule must match one of its slots
+    #[msg("🔴 No schedule slot matches this distribution round.")]
+    ScheduleSlotNotFound,
+
+    // ────────────────────────────────
+    // 🧾 RECONCILIATION ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate reconcile_batch inputs
+
+    /// Wrong cache selection for reconciliation or a requeued payout
+    /// 
+    /// AUDIT: Exactly one of profit_cache / refund_cache must be provided (reconcile_batch, requeue_entry)
+    #[msg("🔴 Provide exactly one profit or refund cache.")]
+    ReconcileCacheMismatch,
+
+    // ────────────────────────────────
+    // ⏱️ COMPUTE BUDGET ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors replace opaque compute exhaustion in payout loops
+
+    /// Compute budget too small for the remaining payouts
+    /// 
+    /// AUDIT: Raised before a transfer the remaining CU budget cannot finish
+    #[msg("🔴 Remaining compute units cannot cover the batch; raise the CU limit or split the batch.")]
+    ComputeBudgetExceeded,
+
+    // ────────────────────────────────
+    // ⏱️ ESTIMATION RATE LIMIT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors keep caches stable between review and execution
+
+    /// Cache was estimated too recently
+    /// 
+    /// AUDIT: Re-estimation must wait min_estimate_interval_secs after the last estimate
+    #[msg("🔴 Cache was estimated too recently; wait for the minimum interval.")]
+    EstimateTooSoon,
+
+    /// Estimate interval is invalid
+    /// 
+    /// AUDIT: Must be shorter than GlobalConfig.share_cache_expire_secs
+    #[msg("🔴 Minimum estimate interval must be shorter than the cache lifetime.")]
+    InvalidEstimateInterval,
+
+    // ────────────────────────────────
+    // 📒 PROFIT ACCRUAL ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors protect quarterly accrual balances
+
+    /// Accrual quarter was already credited
+    /// 
+    /// AUDIT: Quarter index must be greater than the ledger's last_quarter
+    #[msg("🔴 Accrual quarter must be later than the last credited quarter.")]
+    AccrualQuarterNotIncreasing,
+
+    /// Nothing to settle
+    /// 
+    /// AUDIT: The account has no unsettled balance in this ledger
+    #[msg("🔴 No accrued balance to settle for this account.")]
+    NothingToSettle,
+
+    /// Account is not a cache of this investment
+    /// 
+    /// AUDIT: snapshot_vault counts only ProfitShareCache / RefundShareCache accounts owned by this program for the same investment_id and version, each once
+    #[msg("🔴 Snapshot account is not a distinct profit or refund cache of this investment.")]
+    InvalidSnapshotCache,
+
+    /// Account is not a cache or ledger of this investment
+    /// 
+    /// AUDIT: health_check counts only ProfitShareCache / RefundShareCache / ProfitAccrualLedger accounts owned by this program for the same investment_id and version, each once
+    #[msg("🔴 Health check account is not a distinct cache or ledger of this investment.")]
+    InvalidHealthCheckAccount,
+
+    /// Account supplied to archive_investment is not a cache of this investment
+    /// 
+    /// AUDIT: Only ProfitShareCache / RefundShareCache PDAs of the investment, each once, are accepted
+    #[msg("🔴 Invalid account supplied for final settlement.")]
+    InvalidSettlementAccount,
+
+    /// Executor SOL reimbursement above the cache estimate
+    /// 
+    /// AUDIT: Reimbursement is capped at subtotal_estimate_sol approved with the cache
+    #[msg("🔴 SOL reimbursement exceeds the cache subtotal_estimate_sol")]
+    ReimbursementExceedsEstimate,
+
+    /// Profit cache not opened for claims
+    /// 
+    /// AUDIT: claim_profit only pays from a cache the executors opened with open_profit_claims
+    #[msg("🔴 Profit cache is not open for claims.")]
+    ProfitClaimsNotOpen,
+
+    /// Profit cache already opened for claims
+    /// 
+    /// AUDIT: open_profit_claims runs once per estimation; re-estimation resets it
+    #[msg("🔴 Profit cache is already open for claims.")]
+    ProfitClaimsAlreadyOpen,
+
+    /// Cache entry not claimable
+    /// 
+    /// AUDIT: Only Pending entries are claimable; Paid, Escrowed and Requeued entries are not
+    #[msg("🔴 Cache entry is not claimable.")]
+    EntryNotClaimable,
+
+    /// Claimant is not the entry wallet
+    /// 
+    /// AUDIT: Only the wallet recorded on the entry may claim it
+    #[msg("🔴 Claimant is not the wallet of this entry.")]
+    ClaimantNotEntryWallet,
+
+    /// Merkle publication parameters invalid
+    /// 
+    /// AUDIT: The root must be non-zero, with at least one entry and a non-zero subtotal
+    #[msg("🔴 Invalid Merkle root, entry count or subtotal.")]
+    InvalidMerkleRoot,
+
+    /// Merkle proof verification failure
+    /// 
+    /// AUDIT: The leaf (investment, batch, index, account, claimant wallet, amount) must prove to the published root
+    #[msg("🔴 Merkle proof does not match the published root.")]
+    InvalidMerkleProof,
+
+    /// Merkle root already claimed against
+    /// 
+    /// AUDIT: The root is replaceable only until the first claim; receipts are keyed by leaf index
+    #[msg("🔴 Merkle root cannot be replaced after claims started.")]
+    MerkleClaimsStarted,
+
+    /// Profit cache cancelled
+    /// 
+    /// AUDIT: cancel_profit_cache voided this estimate; re-estimate the batch before executing
+    #[msg("🔴 Profit cache has been cancelled.")]
+    ProfitCacheCancelled,
+
+    /// Profit cache has paid entries
+    /// 
+    /// AUDIT: A cache is cancelled only before any entry is paid, so no payout loses its record
+    #[msg("🔴 Profit cache has paid entries and cannot be cancelled.")]
+    ProfitCacheHasPayouts,
+
+    /// Live estimate would be overwritten
+    /// 
+    /// AUDIT: Re-estimating an unexecuted, unexpired, not cancelled cache needs overwrite = true or a prior cancel_profit_cache
+    #[msg("🔴 A live estimate exists; pass overwrite or cancel the cache first.")]
+    EstimateOverwriteRequired,
+
+    /// Cache entry is not escrowed by failed payouts
+    /// 
+    /// AUDIT: release_escrowed_entry only releases entries escrowed after MAX_ENTRY_FAILURES
+    #[msg("🔴 Cache entry is not escrowed after failed payouts.")]
+    EntryNotEscrowed,
+
+    /// Cache supplied to a reinstatement does not hold the record's share
+    /// 
+    /// AUDIT: resolve_revocation_contest only takes profit / refund caches of the record's batch, once each, and only when reinstating
+    #[msg("🔴 Invalid cache supplied to the revocation reinstatement.")]
+    InvalidReinstatementCache,
+
+    /// Merkle round cancelled
+    /// 
+    /// AUDIT: cancel_profit_merkle stopped the round; its remaining entries cannot be claimed
+    #[msg("🔴 Merkle profit round has been cancelled.")]
+    MerkleRoundCancelled,
+
+    /// Merkle claim window over
+    /// 
+    /// AUDIT: Claims are accepted until ProfitMerkleCache.expires_at; the unclaimed remainder is no longer reserved in the vault
+    #[msg("🔴 Merkle profit round claim window has expired.")]
+    MerkleRoundExpired,
+
+    /// Refund cache has paid entries
+    /// 
+    /// AUDIT: estimate_refund_share never resets a cache once an execution has paid any of its entries
+    #[msg("🔴 Refund cache has paid entries and cannot be re-estimated.")]
+    RefundCacheHasPayouts,
+
+    /// Batch already has a profit round of the other kind
+    /// 
+    /// AUDIT: A batch is never paid through a ProfitShareCache round and a Merkle round at once
+    #[msg("🔴 Batch has a conflicting profit round in progress.")]
+    ProfitRoundConflict,
+
+    // ════════════════════════════════
+    // ⚙️ CONFIG ERRORS: 6400..=6499
+    // ════════════════════════════════
+    // AUDIT: Configuration parameter validation
+
+    // ────────────────────────────────
+    // 📊 STAGE RATIO ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate refund stage ratio tables
+
+    /// Stage ratio array length validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures whitelist has at least one member
-    /// - Prevents operations with empty whitelist
-    /// - Must have at least one authorized member
-    /// - Prevents operations without authorization
-    /// - Ensures proper access control
-    #[msg("🔴 Whitelist must contain at least one wallet.")]
-    EmptyWhitelist,
+    /// - Ensures proper stage ratio configuration
+    /// - Prevents array out-of-bounds access
+    /// - Each stage must have exactly 10 elements
+    /// - Prevents memory corruption and calculation errors
+    /// - Ensures consistent refund distribution structure
+    #[msg("🔴 stage_ratio length per stage must be exactly 10 elements.")]
+    InvalidStageRatioLength = 400,
 
-    /// Invalid recipient wallet address
+    /// Stage ratio value validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures valid recipient address format
-    /// - Prevents transfers to invalid addresses
-    /// - Address must be valid Pubkey
-    /// - Prevents fund loss to invalid addresses
-    /// - Ensures proper address validation
-    #[msg("🔴 Invalid Recipient wallet Address")]
-    InvalidRecipientAddress,
+    /// - Ensures percentage values are within valid range
+    /// - Prevents invalid refund calculations
+    /// - Must be between 0 and 100
+    /// - Prevents mathematical errors in distribution
+    /// - Ensures proper percentage representation
+    #[msg("🔴 Stage ratio value must be between 0 and 100.")]
+    InvalidStageRatioValue,
 
-    /// Unauthorized recipient validation failure
+    /// Stage ratio sum validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures recipient is in withdraw whitelist
-    /// - Prevents unauthorized withdrawals
-    /// - Recipient must be authorized
-    /// - Prevents fund theft
-    /// - Ensures proper withdrawal authorization
-    #[msg("🔴 Recipient wallet is not in the withdraw whitelist.")]
-    UnauthorizedRecipient,
+    /// - Ensures total percentage doesn't exceed 100%
+    /// - Prevents over-allocation of refunds
+    /// - Sum for a single stage must not exceed 100
+    /// - Prevents fund exhaustion attacks
+    /// - Ensures proper allocation distribution
+    #[msg("🔴 Stage ratio sum for a single stage must not exceed 100.")]
+    InvalidStageRatioSum,
 
-    /// Invalid associated token account
+    /// Stage ratio contiguity validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures correct ATA derivation
-    /// - Prevents ATA spoofing attacks
-    /// - ATA must match expected address
-    /// - Prevents unauthorized token account access
-    /// - Ensures proper token account ownership
-    #[msg("🔴 Invalid associated token account.")]
-    InvalidAssociatedTokenAccount,
+    /// - Ensures non-zero values are contiguous
+    /// - Prevents gaps in refund distribution
+    /// - Once non-zero values begin, must be contiguous
+    /// - Prevents confusion in refund timing
+    /// - Ensures proper refund schedule
+    #[msg("🔴 Stage ratio must be contiguous once non-zero values begin.")]
+    NonContiguousStage,
 
-    /// Invalid token program ID
+    /// Empty stage ratio validation failure
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures correct token program is used
-    /// - Prevents unauthorized token operations
-    /// - Must be Token 2020 (Legacy) program
-    /// - Prevents use of unauthorized token programs
-    /// - Ensures proper token program validation
-    #[msg("🔴 Invalid token program ID. Must be Token 2020(Legacy).")]
-    InvalidTokenProgramID,
+    /// - Ensures at least one stage has non-zero ratios
+    /// - Prevents investment with no refund allocation
+    /// - At least one stage must have non-zero values
+    /// - Prevents investments with no refund mechanism
+    /// - Ensures proper investment structure
+    #[msg("🔴 All stage ratio values are zero.")]
+    EmptyStageRatio,
 
-    /// Invalid associated token program ID
+    // ────────────────────────────────
+    // 💱 CONVERSION RATE ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate the USDT / H2COIN amounts of investment records
+    // SECURITY: Critical for catching data-entry mismatches before they skew refunds
+
+    /// Invalid conversion rate range
     /// 
     /// AUDIT CRITICAL:
-    /// - Ensures correct ATA program is used
-    /// - Prevents unauthorized ATA operations
-    /// - Must be Associated Token Program
-    /// - Prevents use of unauthorized ATA programs
-    /// - Ensures proper ATA program validation
-    #[msg("🔴 Invalid associated token program ID.")]
-    InvalidAssociatedTokenProgramID,
+    /// - Ensures the configured minimum rate does not exceed the maximum
+    /// - Prevents a range that would reject every record
+    #[msg("🔴 H2COIN rate range is invalid (min must not exceed max).")]
+    InvalidHcoinRateRange,
+
+    /// Record amounts outside the configured conversion rate range
+    /// 
+    /// AUDIT CRITICAL:
+    /// - amount_hcoin does not match amount_usdt at the configured rate
+    /// - Prevents mistyped amounts from skewing refund calculations
+    #[msg("🔴 amount_hcoin is outside the accepted rate range for amount_usdt.")]
+    HcoinAmountOutOfRateRange,
+
+    // ────────────────────────────────
+    // 🔔 NOTIFICATION ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors guard the notification registry
+
+    /// Notification contact is empty
+    /// 
+    /// AUDIT: A registration must carry a contact hash or channel identifier
+    #[msg("🔴 Notification contact must not be empty.")]
+    InvalidNotificationContact,
+
+    // ────────────────────────────────
+    // 📅 STAGE RATIO SCHEDULE ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors protect the terms of refund years already open
+
+    /// Stage ratio effective year is invalid
+    /// 
+    /// AUDIT: Must be a future refund year within MAX_YEAR_INDEX and come with a new stage ratio
+    #[msg("🔴 Stage ratio effective year must be a future refund year.")]
+    InvalidStageRatioEffectiveYear,
+
+    // ────────────────────────────────
+    // ➗ PAYOUT SPLIT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors keep account-level payout splits exact
+
+    /// Payout split is invalid
+    /// 
+    /// AUDIT: Used wallets must be distinct and non-default, percentages must sum to 100
+    #[msg("🔴 Invalid payout split wallets or percentages.")]
+    InvalidPayoutSplit,
+
+    // ────────────────────────────────
+    // 📏 LIMITS CONFIG ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors protect the published program limits
+
+    /// Limits override is invalid
+    /// 
+    /// AUDIT: Overrides must be positive and may only tighten the compiled constants
+    #[msg("🔴 Limits override must be positive and within the compiled limit.")]
+    InvalidLimitsConfig,
+
+    // ────────────────────────────────
+    // 🏢 TENANT DEFAULT ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate the defaults new investments inherit
+
+    /// Tenant defaults are invalid
+    /// 
+    /// AUDIT: Fee must not exceed MAX_TENANT_FEE_BP; default whitelists are empty or 5 distinct members
+    #[msg("🔴 Invalid tenant fee or default whitelist.")]
+    InvalidTenantDefaults,
+
+    // ────────────────────────────────
+    // 🏦 EXCHANGE REGISTRY ERRORS
+    // ────────────────────────────────
+    // AUDIT: These errors validate the exchange owner registry
+
+    /// Exchange registry entries are invalid
+    /// 
+    /// AUDIT: At most MAX_EXCHANGE_OWNERS distinct, non-default entries
+    #[msg("🔴 Exchange registry entries are invalid.")]
+    InvalidExchangeRegistry,
+
+    /// Pause mask contains unknown bits
+    /// 
+    /// AUDIT: Only PAUSE_ALL_FAMILIES bits may be set
+    #[msg("🔴 Pause mask contains unknown instruction families.")]
+    InvalidPauseFlags,
+
+    /// Role members or threshold are invalid
+    /// 
+    /// AUDIT: Members must be distinct non-default keys (at most MAX_WHITELIST_LEN) and 1 <= threshold <= members; an empty list with threshold 0 clears the role
+    #[msg("🔴 Invalid role assignment.")]
+    InvalidRoleAssignment,
+
+    /// Invalid withdrawal tiers
+    /// 
+    /// AUDIT: At most MAX_WITHDRAW_TIERS tiers with 1 <= required_signers <= MAX_WHITELIST_LEN, strictly ascending max_amount_usdt and non-decreasing required_signers
+    #[msg("🔴 Invalid withdrawal tiers.")]
+    InvalidWithdrawTiers,
+
+    /// Invalid withdrawal timelock
+    /// 
+    /// AUDIT: withdraw_delay_secs must lie within MIN_WITHDRAW_DELAY_SECS..=MAX_WITHDRAW_DELAY_SECS (24h to 72h)
+    #[msg("🔴 Invalid withdrawal delay.")]
+    InvalidWithdrawDelay,
+
+    /// Invalid rate-limit window
+    /// 
+    /// AUDIT: window_secs must be 0 (off) or lie within MIN_RATE_LIMIT_WINDOW_SECS..=MAX_RATE_LIMIT_WINDOW_SECS (1h to 30 days)
+    #[msg("🔴 Invalid withdrawal rate-limit window.")]
+    InvalidRateLimitWindow,
+
+    /// No recovery wallet designated
+    /// 
+    /// AUDIT: sweep_foreign_token requires InvestmentInfo.recovery_wallet to be set through set_recovery_wallet
+    #[msg("🔴 Recovery wallet is not set.")]
+    RecoveryWalletNotSet,
+
+    /// Invalid recovery wallet
+    /// 
+    /// AUDIT: The recovery wallet must not be the vault PDA itself
+    #[msg("🔴 Invalid recovery wallet.")]
+    InvalidRecoveryWallet,
+
+    /// Profit stage multiplier validation failure
+    /// 
+    /// AUDIT: set_profit_stage_multipliers requires all multipliers zero (weighting off) or all within 1..=MAX_PROFIT_STAGE_MULTIPLIER_BP
+    #[msg("🔴 Profit stage multipliers must be all zero or all between 1 and 50000 bp.")]
+    InvalidProfitStageMultipliers,
+
+    /// Replay fixture is malformed or inconsistent
+    /// 
+    /// AUDIT: Raised only by the off-chain replay-fixtures module (bad magic, version, ordering or contents)
+    #[msg("🔴 Invalid replay fixture.")]
+    InvalidReplayFixture,
+
+    /// Settlement waiver is out of range, duplicated or waives a settled round
+    /// 
+    /// AUDIT: Checked by archive_investment before the rounds are verified
+    #[msg("🔴 Invalid settlement waiver.")]
+    InvalidSettlementWaiver,
+
+    /// Profit mint not in the allowlist
+    /// 
+    /// AUDIT: The profit mint passed to initialize_investment_info is not listed in GlobalConfig.allowed_profit_mints
+    #[msg("🔴 Profit mint is not an allowed stablecoin.")]
+    ProfitMintNotAllowed,
+
+    /// Invalid GlobalConfig parameters
+    /// 
+    /// AUDIT: A mint is unset, the profit mint list is empty, too long, duplicated or contains H2COIN, a SOL fee estimate is zero, or the cache expiry is outside 1..=SHARE_CACHE_EXPIRE_SECS
+    #[msg("🔴 Invalid global config parameters.")]
+    InvalidGlobalConfig,
+
+    /// Distribution note rejected
+    /// 
+    /// AUDIT: Notes are 1..=MAX_TRANSFER_NOTE_LEN bytes without control characters, quotes or backslashes, so the memo stays valid JSON
+    #[msg("🔴 Invalid distribution note.")]
+    InvalidTransferNote,
+
+    // ════════════════════════════════
+    // 🧮 MATH ERRORS: 6500..=6599
+    // ════════════════════════════════
+    // AUDIT: Arithmetic failures
+
+    /// Mathematical overflow detected during calculations
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Prevents integer overflow attacks
+    /// - Critical for financial calculations
+    /// - Must be checked in all arithmetic operations
+    /// - Prevents calculation errors that could lead to fund loss
+    /// - Common attack vector in DeFi protocols
+    #[msg("🔴 Math overflow.")]
+    NumericalOverflow = 500,
+}
+
+/// Number of codes reserved for each error category
+/// 
+/// AUDIT: Category index = (code - ERROR_CODE_OFFSET) / ERROR_CATEGORY_SPAN
+pub const ERROR_CATEGORY_SPAN: u32 = 100;
+
+/// Machine-readable category of a program error
+/// 
+/// AUDIT CRITICAL:
+/// - Variant order matches the code ranges of ErrorCode
+/// - Lets monitoring classify failures without matching messages
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorCategory {
+    Auth,   // 6000..=6099
+    State,  // 6100..=6199
+    Token,  // 6200..=6299
+    Cache,  // 6300..=6399
+    Config, // 6400..=6499
+    Math,   // 6500..=6599
+}
+
+impl ErrorCategory {
+    /// Categories in code-range order
+    pub const ALL: [ErrorCategory; 6] = [
+        ErrorCategory::Auth,
+        ErrorCategory::State,
+        ErrorCategory::Token,
+        ErrorCategory::Cache,
+        ErrorCategory::Config,
+        ErrorCategory::Math,
+    ];
+}
+
+impl ErrorCode {
+    /// Category of this error, derived from its code range
+    pub fn category(self) -> ErrorCategory {
+        ErrorCategory::ALL[(self as u32 / ERROR_CATEGORY_SPAN) as usize]
+    }
+}
+
+/// Classify a raw program error code
+/// 
+/// AUDIT: Returns None for Anchor framework codes and codes outside the program's ranges
+pub fn error_category(code: u32) -> Option<ErrorCategory> {
+    let index = code.checked_sub(anchor_lang::error::ERROR_CODE_OFFSET)? / ERROR_CATEGORY_SPAN;
+    ErrorCategory::ALL.get(index as usize).copied()
 }
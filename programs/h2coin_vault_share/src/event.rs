@@ -27,6 +27,13 @@
 
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_STAGE;
+use crate::state::SettlementWaiver;
+use crate::state::{
+    DistributionKind, HcoinRateRange, NotificationChannel, NotificationKind, ProposalAction,
+    BudgetCap, RecordChangeReason, Role, SignerRole, WithdrawAssets, WithdrawPurpose, WithdrawTier,
+};
+
 //
 // 🔄 INVESTMENT MANAGEMENT EVENTS
 //
@@ -74,6 +81,14 @@ pub struct InvestmentInfoInitialized {
     /// AUDIT: Creation time for audit trail
     /// SECURITY: Provides temporal context
     pub created_at: i64,
+
+    /// Whether the investment started in Pending
+    /// AUDIT: false means it waits in Init for open_investment
+    pub opened: bool,
+
+    /// Tenant the investment was created under
+    /// AUDIT: Pubkey::default() when created without a tenant
+    pub tenant: Pubkey,
 }
 
 /// Event emitted when investment info is updated
@@ -111,6 +126,19 @@ pub struct InvestmentUpdated {
     /// AUDIT: Tracks investment limit changes
     /// SECURITY: Records risk management changes
     pub new_upper_limit: Option<u64>,
+
+    /// New H2COIN-per-USDT rate range (if updated)
+    /// AUDIT: Tracks record amount validation changes
+    /// SECURITY: Records data-entry control changes
+    pub new_hcoin_rate_range: Option<HcoinRateRange>,
+
+    /// New minimum estimate interval in seconds (if updated)
+    /// AUDIT: Tracks cache re-estimation rate limit changes
+    pub new_min_estimate_interval_secs: Option<u32>,
+
+    /// Refund year from which new_stage_ratio applies (None = immediately)
+    /// AUDIT: Earlier refund years keep the previous ratio
+    pub new_stage_ratio_effective_year: Option<u8>,
     
     /// The updater of this investment info
     /// AUDIT: Accountable party for the update
@@ -170,125 +198,78 @@ pub struct InvestmentInfoCompleted {
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when investment info is deactivated
+/// Event emitted when a pending investment is cancelled
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks final deactivation of investment
-/// - Includes all signers for multisig accountability
-/// - Prevents all further operations
-/// - Provides audit trail for deactivation
-/// - Enables monitoring of investment termination
-/// 
-/// SECURITY:
-/// - Records final state of investment
-/// - Prevents all further operations
+/// - Marks a failed raise; distributions are blocked from here on
 /// - Records all multisig signers
-/// - Enables termination verification
 #[event]
-pub struct InvestmentInfoDeactivated {
+pub struct InvestmentInfoCancelled {
     /// Investment ID (fixed-length string)
     /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
     pub investment_id: [u8; 15],
-    
+
     /// Git commit version
     /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
     pub version: [u8; 4],
-    
-    /// The deactivator of this investment info
-    /// AUDIT: Accountable party for deactivation
-    /// SECURITY: Records responsible party
-    pub deactivated_by: Pubkey,
-    
+
+    /// The updater of this investment info
+    /// AUDIT: Accountable party for cancellation
+    pub updated_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Deactivation time for audit trail
-    /// SECURITY: Provides temporal context
-    pub deactivated_at: i64,
-    
+    /// AUDIT: Cancellation time for audit trail
+    pub updated_at: i64,
+
     /// All signers involved in the multisig operation
     /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-//
-// 📑 WHITELIST UPDATE EVENTS
-//
-// AUDIT: These events track whitelist membership changes
-// SECURITY: Include signer information for accountability
-// TRANSPARENCY: Enable monitoring of access control changes
-
-/// Event emitted when whitelist members are updated
+/// Event emitted when an investment leaves its Init review window
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks whitelist membership changes
-/// - Includes all signers for multisig accountability
-/// - Records specific wallet changes
-/// - Provides audit trail for access control changes
-/// - Enables monitoring of authorization changes
-/// 
-/// SECURITY:
-/// - Records access control modifications
+/// - Marks the point from which records are accepted
 /// - Records all multisig signers
-/// - Tracks specific wallet changes
-/// - Enables authorization verification
 #[event]
-pub struct WhitelistUpdated {
+pub struct InvestmentInfoOpened {
     /// Investment ID (fixed-length string)
     /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
     pub investment_id: [u8; 15],
-    
+
     /// Git commit version
     /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
     pub version: [u8; 4],
-    
-    /// Updated wallet address
-    /// AUDIT: Specific wallet that was changed
-    /// SECURITY: Records specific authorization change
-    pub wallet: Pubkey,
-    
-    /// The updater of this whitelist
-    /// AUDIT: Accountable party for the change
-    /// SECURITY: Records responsible party
+
+    /// The updater of this investment info
+    /// AUDIT: Accountable party for opening
     pub updated_by: Pubkey,
-    
+
     /// UNIX timestamp
-    /// AUDIT: Update time for audit trail
-    /// SECURITY: Provides temporal context
+    /// AUDIT: Opening time for audit trail
     pub updated_at: i64,
-    
+
     /// All signers involved in the multisig operation
     /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-//
-// 📄 INVESTMENT RECORD EVENTS
-//
-// AUDIT: These events track individual investment record operations
-// SECURITY: Include signer information for accountability
-// TRANSPARENCY: Enable monitoring of individual investments
-
-/// Event emitted when a new investment record is added
+/// Event emitted when investment info is deactivated
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks creation of individual investment records
-/// - Records investment amounts and recipient
+/// - Tracks final deactivation of investment
 /// - Includes all signers for multisig accountability
-/// - Provides audit trail for investment tracking
-/// - Enables monitoring of individual investments
+/// - Prevents all further operations
+/// - Provides audit trail for deactivation
+/// - Enables monitoring of investment termination
 /// 
 /// SECURITY:
-/// - Records individual investment details
+/// - Records final state of investment
+/// - Prevents all further operations
 /// - Records all multisig signers
-/// - Tracks investment amounts
-/// - Enables investment verification
+/// - Enables termination verification
 #[event]
-pub struct InvestmentRecordAdded {
+pub struct InvestmentInfoDeactivated {
     /// Investment ID (fixed-length string)
     /// AUDIT: Unique identifier for the investment
     /// SECURITY: Enables tracking of specific investments
@@ -299,30 +280,15 @@ pub struct InvestmentRecordAdded {
     /// SECURITY: Enables code audit trail
     pub version: [u8; 4],
     
-    /// Unique record identifier
-    /// AUDIT: Links to specific investment record
-    /// SECURITY: Enables record tracking
-    pub record_id: u64,
-    
-    /// Account identifier (15 bytes)
-    /// AUDIT: Links to specific account
-    /// SECURITY: Enables account tracking
-    pub account_id: [u8; 15],
-    
-    /// USDT investment amount
-    /// AUDIT: Investment amount for profit calculations
-    /// SECURITY: Records investment value
-    pub amount_usdt: u64,
-    
-    /// The adder of this investment record
-    /// AUDIT: Accountable party for record creation
+    /// The deactivator of this investment info
+    /// AUDIT: Accountable party for deactivation
     /// SECURITY: Records responsible party
-    pub added_by: Pubkey,
+    pub deactivated_by: Pubkey,
     
     /// UNIX timestamp
-    /// AUDIT: Creation time for audit trail
+    /// AUDIT: Deactivation time for audit trail
     /// SECURITY: Provides temporal context
-    pub added_at: i64,
+    pub deactivated_at: i64,
     
     /// All signers involved in the multisig operation
     /// AUDIT: Complete signer list for accountability
@@ -330,361 +296,670 @@ pub struct InvestmentRecordAdded {
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when investment record wallet is updated
+/// Event emitted when an investment is archived after its final settlement
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks wallet address changes for investment records
-/// - Includes all signers for multisig accountability
-/// - Records specific wallet updates
-/// - Provides audit trail for recipient changes
-/// - Enables monitoring of recipient updates
+/// - Final settlement attestation: cumulative totals per asset over every supplied cache
+/// - Lists every waived round with the multisig signers that approved it
+/// - The investment is Archived and deactivated; close_vault may then sweep the residue
 /// 
 /// SECURITY:
-/// - Records recipient address changes
-/// - Records all multisig signers
-/// - Tracks specific account updates
-/// - Enables recipient verification
+/// - unsettled_* report amounts of waived rounds that were never paid
+/// - Vault balances are those left for close_vault at archival time
 #[event]
-pub struct InvestmentRecordWalletUpdated {
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct InvestmentArchived {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Account identifier (15 bytes)
-    /// AUDIT: Links to specific account
-    /// SECURITY: Enables account tracking
-    pub account_id: [u8; 15],
-    
-    /// New wallet address
-    /// AUDIT: Updated recipient address
-    /// SECURITY: Records new recipient
-    pub new_wallet: Pubkey,
-    
-    /// The updater of this wallet
-    /// AUDIT: Accountable party for the update
-    /// SECURITY: Records responsible party
-    pub updated_by: Pubkey,
-    
+
+    /// Batches covered by the attestation (1..=batches)
+    pub batches: u16,
+
+    /// Profit rounds executed with every entry paid
+    pub profit_rounds_settled: u16,
+
+    /// Refund years executed with every entry paid
+    pub refund_years_settled: u16,
+
+    /// Rounds explicitly waived
+    pub waivers: Vec<SettlementWaiver>,
+
+    /// Cumulative amount_usdt of all records
+    pub total_invested_usdt: u64,
+
+    /// Cumulative USDT paid by profit caches
+    pub total_profit_paid_usdt: u64,
+
+    /// Cumulative H2COIN paid by refund caches
+    pub total_refund_paid_hcoin: u64,
+
+    /// USDT of supplied profit caches not paid (waived rounds only)
+    pub unsettled_profit_usdt: u64,
+
+    /// H2COIN of supplied refund caches not paid (waived rounds only)
+    pub unsettled_refund_hcoin: u64,
+
+    /// Vault USDT balance at archival
+    pub vault_usdt_balance: u64,
+
+    /// Vault H2COIN balance at archival
+    pub vault_hcoin_balance: u64,
+
+    /// Vault PDA lamports at archival
+    pub vault_lamports: u64,
+
+    /// The executor of the archival
+    pub archived_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Update time for audit trail
-    /// SECURITY: Provides temporal context
-    pub updated_at: i64,
-    
+    pub archived_at: i64,
+
     /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when an investment record is revoked
+/// Event emitted when a deactivated investment's vault is closed
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks revocation of investment records
-/// - Includes all signers for multisig accountability
-/// - Prevents revoked records from distributions
-/// - Provides audit trail for record invalidation
-/// - Enables monitoring of record revocations
+/// - Tracks the final outflow of the vault: ATA rent and residual SOL
+/// - The recipient is on withdraw_whitelist
 /// 
 /// SECURITY:
-/// - Records record invalidation
+/// - Records the lamports reclaimed from each source
 /// - Records all multisig signers
-/// - Prevents further operations on revoked records
-/// - Enables revocation verification
 #[event]
-pub struct InvestmentRecordRevoked {
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct VaultClosed {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Unique record identifier
-    /// AUDIT: Links to specific investment record
-    /// SECURITY: Enables record tracking
-    pub record_id: u64,
-    
-    /// The revoker of this investment record
-    /// AUDIT: Accountable party for revocation
-    /// SECURITY: Records responsible party
-    pub revoked_by: Pubkey,
-    
+
+    /// Vault PDA that was emptied
+    pub vault: Pubkey,
+
+    /// Wallet receiving the reclaimed lamports
+    pub recipient: Pubkey,
+
+    /// Rent reclaimed from the closed USDT and H2COIN ATAs
+    pub ata_rent_lamports: u64,
+
+    /// Residual SOL moved out of the vault PDA
+    pub vault_lamports: u64,
+
+    /// Residual USDT swept to the recipient (archived investments only)
+    pub swept_usdt: u64,
+
+    /// Residual H2COIN swept to the recipient (archived investments only)
+    pub swept_hcoin: u64,
+
+    /// The executor of the closure
+    pub closed_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Revocation time for audit trail
-    /// SECURITY: Provides temporal context
-    pub revoked_at: i64,
-    
+    pub closed_at: i64,
+
     /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when withdraw whitelist is updated
+/// Event emitted when an execute / update whitelist patch is queued
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks withdraw authorization changes
-/// - Includes all signers for multisig accountability
-/// - Records complete whitelist update
-/// - Provides audit trail for withdrawal access
-/// - Enables monitoring of withdrawal authorization
-/// 
-/// SECURITY:
-/// - Records withdrawal authorization changes
+/// - The patch takes effect through apply_whitelist_patch from effective_at
+/// - Monitoring should alert the whitelist members so they can cancel in time
 /// - Records all multisig signers
-/// - Tracks complete whitelist state
-/// - Enables authorization verification
 #[event]
-pub struct WithdrawWhitelistUpdated {
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct WhitelistPatchQueued {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Updated wallet addresses
-    /// AUDIT: Complete new whitelist
-    /// SECURITY: Records complete authorization state
-    pub wallets: Vec<Pubkey>,
-    
-    /// The updater of this whitelist
-    /// AUDIT: Accountable party for the change
-    /// SECURITY: Records responsible party
-    pub updated_by: Pubkey,
-    
-    /// UNIX timestamp
-    /// AUDIT: Update time for audit trail
-    /// SECURITY: Provides temporal context
-    pub updated_at: i64,
-    
-    /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
+
+    /// true for update_whitelist, false for execute_whitelist
+    pub is_update: bool,
+
+    /// Member to be replaced
+    pub from: Pubkey,
+
+    /// Label of the member to be replaced
+    pub from_label: [u8; 16],
+
+    /// Replacement member
+    pub to: Pubkey,
+
+    /// Label the replacement member takes over the slot with
+    pub to_label: [u8; 16],
+
+    /// First timestamp at which the patch may be applied
+    pub effective_at: i64,
+
+    /// Account that queued the patch
+    pub queued_by: Pubkey,
+
+    /// Queue timestamp
+    pub queued_at: i64,
+
+    /// List of signers who authorized the patch
     pub signers: Vec<Pubkey>,
 }
 
-//
-// 📤 PROFIT/REFUND ESTIMATION AND EXECUTION EVENTS
-//
-// AUDIT: These events track profit and refund distribution operations
-// SECURITY: Include signer information and amounts for transparency
-// TRANSPARENCY: Enable monitoring of profit and refund distributions
+/// Event emitted when the incoming member accepts a pending whitelist seat
+/// 
+/// AUDIT CRITICAL:
+/// - Signed by `to` itself; apply_whitelist_patch is only possible afterwards
+#[event]
+pub struct WhitelistSeatAccepted {
+    /// Investment ID
+    pub investment_id: [u8; 15],
 
-/// Event emitted when profit share is estimated
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// true for update_whitelist, false for execute_whitelist
+    pub is_update: bool,
+
+    /// Member to be replaced
+    pub from: Pubkey,
+
+    /// Incoming member that accepted
+    pub to: Pubkey,
+
+    /// Label of the incoming member
+    pub to_label: [u8; 16],
+
+    /// Acceptance timestamp
+    pub accepted_at: i64,
+}
+
+/// Event emitted when a pending whitelist patch is cancelled
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks profit distribution calculations
-/// - Includes all signers for multisig accountability
-/// - Records estimated amounts and gas costs
-/// - Provides audit trail for profit calculations
-/// - Enables monitoring of profit estimation
+/// - Records the cancelling whitelist members
+#[event]
+pub struct WhitelistPatchCancelled {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// true for update_whitelist, false for execute_whitelist
+    pub is_update: bool,
+
+    /// Member that would have been replaced
+    pub from: Pubkey,
+
+    /// Replacement that was proposed
+    pub to: Pubkey,
+
+    /// Label proposed for the replacement
+    pub to_label: [u8; 16],
+
+    /// Account that submitted the cancellation
+    pub cancelled_by: Pubkey,
+
+    /// Cancellation timestamp
+    pub cancelled_at: i64,
+
+    /// Whitelist members who cancelled the patch
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a historical record is backfilled from the legacy system
 /// 
-/// SECURITY:
-/// - Records profit calculation details
+/// AUDIT CRITICAL:
+/// - Emitted together with InvestmentRecordAdded
+/// - created_at is the original timestamp; backfilled_at is when it was migrated
 /// - Records all multisig signers
-/// - Tracks estimated amounts and costs
-/// - Enables calculation verification
 #[event]
-pub struct ProfitShareEstimated {
-    /// Each batch_id handles up to 30 investment records
-    /// AUDIT: Links to specific batch of records
-    /// SECURITY: Enables batch tracking
-    pub batch_id: u16,
-    
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct InvestmentRecordBackfilled {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Total USDT amount to be distributed
-    /// AUDIT: Total profit amount for transparency
-    /// SECURITY: Records total distribution amount
-    pub subtotal_profit_usdt: u64,
-    
-    /// Estimated SOL cost for execution
-    /// AUDIT: Gas cost estimation for transparency
-    /// SECURITY: Records estimated transaction costs
-    pub subtotal_estimate_sol: u64,
-    
-    /// The estimator of this profit share
-    /// AUDIT: Accountable party for estimation
-    /// SECURITY: Records responsible party
-    pub created_by: Pubkey,
-    
-    /// UNIX timestamp
-    /// AUDIT: Estimation time for audit trail
-    /// SECURITY: Provides temporal context
+
+    /// Backfilled record identifier
+    pub record_id: u64,
+
+    /// Account identifier (15 bytes)
+    pub account_id: [u8; 15],
+
+    /// Original investment timestamp stored on the record
     pub created_at: i64,
-    
-    /// Number of entries in this batch
-    /// AUDIT: Batch size for transparency
-    /// SECURITY: Records batch complexity
-    pub entry_count: u16,
-    
-    /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
+
+    /// Account that performed the backfill
+    pub backfilled_by: Pubkey,
+
+    /// Migration timestamp
+    pub backfilled_at: i64,
+
+    /// List of signers who authorized the backfill
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when refund share is estimated
+/// Event emitted when the Squads authorization mode of an investment changes
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks refund distribution calculations
-/// - Includes all signers for multisig accountability
-/// - Records estimated amounts and gas costs
-/// - Provides audit trail for refund calculations
-/// - Enables monitoring of refund estimation
+/// - Carries the multisig, vault index and derived vault address
+/// - Pubkey::default() as squads_authority means the mode is disabled
+/// - Records all multisig signers
+#[event]
+pub struct SquadsAuthorityUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Squads multisig account (default when disabled)
+    pub multisig: Pubkey,
+
+    /// Squads vault index
+    pub vault_index: u8,
+
+    /// Vault accepted before the change
+    pub previous_authority: Pubkey,
+
+    /// Vault accepted after the change
+    pub squads_authority: Pubkey,
+
+    /// Account that performed the update
+    pub updated_by: Pubkey,
+
+    /// Update timestamp
+    pub updated_at: i64,
+
+    /// List of signers who authorized the update
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when an RBAC role is assigned or cleared
 /// 
-/// SECURITY:
-/// - Records refund calculation details
+/// AUDIT CRITICAL:
+/// - Empty members with threshold 0 means the role fell back to its whitelist rule
 /// - Records all multisig signers
-/// - Tracks estimated amounts and costs
-/// - Enables calculation verification
 #[event]
-pub struct RefundShareEstimated {
-    /// Each batch_id handles up to 30 investment records
-    /// AUDIT: Links to specific batch of records
-    /// SECURITY: Enables batch tracking
-    pub batch_id: u16,
-    
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct RoleAssignmentUpdated {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Year index for this refund (0-9)
-    /// AUDIT: Specific year for refund calculation
-    /// SECURITY: Records refund timing
-    pub year_index: u8,
-    
-    /// Total H2COIN amount to be distributed
-    /// AUDIT: Total refund amount for transparency
-    /// SECURITY: Records total distribution amount
-    pub subtotal_refund_hcoin: u64,
-    
-    /// Estimated SOL cost for execution
-    /// AUDIT: Gas cost estimation for transparency
-    /// SECURITY: Records estimated transaction costs
-    pub subtotal_estimate_sol: u64,
-    
-    /// The estimator of this refund share
-    /// AUDIT: Accountable party for estimation
-    /// SECURITY: Records responsible party
-    pub created_by: Pubkey,
-    
+
+    /// Role changed
+    pub role: Role,
+
+    /// New members
+    pub members: Vec<Pubkey>,
+
+    /// New threshold
+    pub threshold: u8,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Estimation time for audit trail
-    /// SECURITY: Provides temporal context
-    pub created_at: i64,
-    
-    /// Number of entries in this batch
-    /// AUDIT: Batch size for transparency
-    /// SECURITY: Records batch complexity
-    pub entry_count: u16,
-    
+    pub updated_at: i64,
+
     /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when profit share is executed
+/// Event emitted when the withdrawal tiers of an investment change
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks actual profit distribution execution
-/// - Includes all signers for multisig accountability
-/// - Records actual transfer amounts
-/// - Provides audit trail for profit execution
-/// - Enables monitoring of profit distributions
-/// 
-/// SECURITY:
-/// - Records actual distribution execution
+/// - Carries the previous and new tiers (empty slots omitted)
 /// - Records all multisig signers
-/// - Tracks actual transfer amounts
-/// - Enables execution verification
 #[event]
-pub struct ProfitShareExecuted {
-    /// Batch identifier for this execution
-    /// AUDIT: Links to specific batch of records
-    /// SECURITY: Enables batch tracking
-    pub batch_id: u16,
-    
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct WithdrawTiersUpdated {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Total USDT amount actually transferred
-    /// AUDIT: Actual distribution amount for transparency
-    /// SECURITY: Records actual transfer amount
-    pub total_transfer_usdt: u64,
-    
-    /// The executor of this profit share
-    /// AUDIT: Accountable party for execution
-    /// SECURITY: Records responsible party
-    pub executed_by: Pubkey,
-    
+
+    /// Tiers before the change
+    pub previous_tiers: Vec<WithdrawTier>,
+
+    /// Tiers after the change; empty means the Withdrawer role rule applies
+    pub tiers: Vec<WithdrawTier>,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Execution time for audit trail
-    /// SECURITY: Provides temporal context
-    pub executed_at: i64,
-    
+    pub updated_at: i64,
+
     /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when refund share is executed
+/// Event emitted when the withdrawal timelock of an investment changes
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks actual refund distribution execution
-/// - Includes all signers for multisig accountability
-/// - Records actual transfer amounts
-/// - Provides audit trail for refund execution
-/// - Enables monitoring of refund distributions
+/// - previous_delay_secs == 0 marks the switch from direct to timelocked withdrawals
+/// - Records all multisig signers
+#[event]
+pub struct WithdrawDelayUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Delay before the change (0 = timelock off)
+    pub previous_delay_secs: u32,
+
+    /// Delay after the change
+    pub delay_secs: u32,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the committee changes the caps of a withdrawal purpose
+/// 
+/// AUDIT CRITICAL:
+/// - A cap below the withdrawn total blocks further withdrawals under the purpose
+/// - Records all multisig signers
+#[event]
+pub struct WithdrawBudgetUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Purpose whose caps changed
+    pub purpose: WithdrawPurpose,
+
+    /// Caps before the change
+    pub previous_cap: BudgetCap,
+
+    /// Caps after the change
+    pub cap: BudgetCap,
+
+    /// USDT withdrawn under the purpose so far
+    pub withdrawn_usdt: u64,
+
+    /// H2COIN withdrawn under the purpose so far
+    pub withdrawn_hcoin: u64,
+
+    /// SOL withdrawn under the purpose so far (lamports)
+    pub withdrawn_sol: u64,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the withdrawal rate limit of an investment changes
+/// 
+/// AUDIT CRITICAL:
+/// - window_secs == 0 turns the limit off; a changed window restarts the count
+/// - Records all multisig signers
+#[event]
+pub struct WithdrawRateLimitUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Window before the change (0 = off)
+    pub previous_window_secs: u32,
+
+    /// Window after the change (0 = off)
+    pub window_secs: u32,
+
+    /// USDT allowed per window (None = uncapped)
+    pub cap_usdt: Option<u64>,
+
+    /// H2COIN allowed per window (None = uncapped)
+    pub cap_hcoin: Option<u64>,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the recovery wallet of foreign token sweeps changes
+/// 
+/// AUDIT CRITICAL:
+/// - sweep_foreign_token pays only this wallet
+/// - Records all multisig signers
+#[event]
+pub struct RecoveryWalletUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Recovery wallet before the change (default = unset)
+    pub previous_wallet: Pubkey,
+
+    /// Recovery wallet after the change (default = unset)
+    pub new_wallet: Pubkey,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the four-eyes rule of record edits is switched
+/// 
+/// AUDIT CRITICAL:
+/// - Switching it off lets a single operator prepare and approve record edits again
+/// - Records all multisig signers
+#[event]
+pub struct RecordFourEyesUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Whether the rule is enforced after the change
+    pub enabled: bool,
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the profit stage multipliers are changed
+/// 
+/// AUDIT CRITICAL:
+/// - Applies to profit caches estimated afterwards; existing caches keep their entries
+/// - Records all multisig signers
+#[event]
+pub struct ProfitStageMultipliersUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Multipliers per stage before the change (bp)
+    pub previous_multipliers_bp: [u16; MAX_STAGE],
+
+    /// Multipliers per stage after the change (bp, all zero = weighting off)
+    pub new_multipliers_bp: [u16; MAX_STAGE],
+
+    /// The payer of the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when an investment is put under an emergency freeze
+/// 
+/// AUDIT CRITICAL:
+/// - Triggered by a single execute / update member; review frozen_by promptly
+#[event]
+pub struct InvestmentFrozen {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Whitelist member that triggered the freeze
+    pub frozen_by: Pubkey,
+
+    /// UNIX timestamp
+    pub frozen_at: i64,
+}
+
+/// Event emitted when an emergency freeze is lifted
+/// 
+/// AUDIT CRITICAL:
+/// - Records all multisig signers that lifted the freeze
+#[event]
+pub struct InvestmentUnfrozen {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Timestamp the freeze started
+    pub frozen_at: i64,
+
+    /// The payer of the change
+    pub unfrozen_by: Pubkey,
+
+    /// UNIX timestamp
+    pub unfrozen_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the paused instruction families of an investment change
+/// 
+/// AUDIT CRITICAL:
+/// - Carries the previous and new PAUSE_* masks
+/// - Records all multisig signers
+#[event]
+pub struct InstructionPauseUpdated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Mask before the change
+    pub previous_families: u8,
+
+    /// Mask after the change (PAUSE_* bits)
+    pub paused_families: u8,
+
+    /// The payer of the change
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a version is linked to an earlier version's vault
+/// 
+/// AUDIT CRITICAL:
+/// - From now on the new version pays from and deposits into the shared vault
+/// - The source version is deactivated in the same instruction
+#[event]
+pub struct SharedVaultLinked {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Version that now uses the shared vault
+    pub version: [u8; 4],
+
+    /// Version that owned the vault (deactivated)
+    pub source_version: [u8; 4],
+
+    /// Version whose seeds derive the shared vault
+    pub vault_version: [u8; 4],
+
+    /// Shared vault PDA
+    pub vault: Pubkey,
+
+    /// The submitter of the link
+    /// AUDIT: Accountable party
+    pub linked_by: Pubkey,
+
+    /// UNIX timestamp
+    pub linked_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+//
+// 📑 WHITELIST UPDATE EVENTS
+//
+// AUDIT: These events track whitelist membership changes
+// SECURITY: Include signer information for accountability
+// TRANSPARENCY: Enable monitoring of access control changes
+
+/// Event emitted when whitelist members are updated
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks whitelist membership changes
+/// - Includes all signers for multisig accountability
+/// - Records specific wallet changes
+/// - Provides audit trail for access control changes
+/// - Enables monitoring of authorization changes
 /// 
 /// SECURITY:
-/// - Records actual distribution execution
+/// - Records access control modifications
 /// - Records all multisig signers
-/// - Tracks actual transfer amounts
-/// - Enables execution verification
+/// - Tracks specific wallet changes
+/// - Enables authorization verification
 #[event]
-pub struct RefundShareExecuted {
-    /// Batch identifier for this execution
-    /// AUDIT: Links to specific batch of records
-    /// SECURITY: Enables batch tracking
-    pub batch_id: u16,
-    
+pub struct WhitelistUpdated {
     /// Investment ID (fixed-length string)
     /// AUDIT: Unique identifier for the investment
     /// SECURITY: Enables tracking of specific investments
@@ -692,187 +967,2916 @@ pub struct RefundShareExecuted {
     
     /// Git commit version
     /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Updated wallet address
+    /// AUDIT: Specific wallet that was changed
+    /// SECURITY: Records specific authorization change
+    pub wallet: Pubkey,
+    
+    /// Label of the updated wallet
+    /// AUDIT: Human-readable identity of the new member (e.g. "CFO")
+    pub label: [u8; 16],
+    
+    /// The updater of this whitelist
+    /// AUDIT: Accountable party for the change
+    /// SECURITY: Records responsible party
+    pub updated_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Update time for audit trail
+    /// SECURITY: Provides temporal context
+    pub updated_at: i64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+}
+
+//
+// 📄 INVESTMENT RECORD EVENTS
+//
+// AUDIT: These events track individual investment record operations
+// SECURITY: Include signer information for accountability
+// TRANSPARENCY: Enable monitoring of individual investments
+
+/// Event emitted when a new investment record is added
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks creation of individual investment records
+/// - Records investment amounts and recipient
+/// - Includes all signers for multisig accountability
+/// - Provides audit trail for investment tracking
+/// - Enables monitoring of individual investments
+/// 
+/// SECURITY:
+/// - Records individual investment details
+/// - Records all multisig signers
+/// - Tracks investment amounts
+/// - Enables investment verification
+#[event]
+pub struct InvestmentRecordAdded {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+
+    /// Batch the record was added to
+    /// AUDIT: Resolved value when the caller passed batch_id = 0
+    pub batch_id: u16,
+    
+    /// Unique record identifier
+    /// AUDIT: Links to specific investment record
+    /// SECURITY: Enables record tracking
+    pub record_id: u64,
+    
+    /// Account identifier (15 bytes)
+    /// AUDIT: Links to specific account
+    /// SECURITY: Enables account tracking
+    pub account_id: [u8; 15],
+    
+    /// USDT investment amount
+    /// AUDIT: Investment amount for profit calculations
+    /// SECURITY: Records investment value
+    pub amount_usdt: u64,
+
+    /// Acknowledgment deadline (0 if the record is not provisional)
+    /// AUDIT: A provisional record is inert until acknowledge_record
+    pub ack_deadline: i64,
+    
+    /// The adder of this investment record
+    /// AUDIT: Accountable party for record creation
+    /// SECURITY: Records responsible party
+    pub added_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Creation time for audit trail
+    /// SECURITY: Provides temporal context
+    pub added_at: i64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when investment record wallet is updated
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks wallet address changes for investment records
+/// - Includes all signers for multisig accountability
+/// - Records specific wallet updates
+/// - Provides audit trail for recipient changes
+/// - Enables monitoring of recipient updates
+/// 
+/// SECURITY:
+/// - Records recipient address changes
+/// - Records all multisig signers
+/// - Tracks specific account updates
+/// - Enables recipient verification
+#[event]
+pub struct InvestmentRecordWalletUpdated {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Account identifier (15 bytes)
+    /// AUDIT: Links to specific account
+    /// SECURITY: Enables account tracking
+    pub account_id: [u8; 15],
+    
+    /// New wallet address
+    /// AUDIT: Updated recipient address
+    /// SECURITY: Records new recipient
+    pub new_wallet: Pubkey,
+
+    /// Operator reason code for the update
+    /// AUDIT: Distinguishes investor requests, KYC issues, fraud, etc.
+    pub reason: RecordChangeReason,
+    
+    /// The updater of this wallet
+    /// AUDIT: Accountable party for the update
+    /// SECURITY: Records responsible party
+    pub updated_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Update time for audit trail
+    /// SECURITY: Provides temporal context
+    pub updated_at: i64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when an investment record is revoked
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks revocation of investment records
+/// - Includes all signers for multisig accountability
+/// - Prevents revoked records from distributions
+/// - Provides audit trail for record invalidation
+/// - Enables monitoring of record revocations
+/// 
+/// SECURITY:
+/// - Records record invalidation
+/// - Records all multisig signers
+/// - Prevents further operations on revoked records
+/// - Enables revocation verification
+#[event]
+pub struct InvestmentRecordRevoked {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Unique record identifier
+    /// AUDIT: Links to specific investment record
+    /// SECURITY: Enables record tracking
+    pub record_id: u64,
+    
+    /// Operator reason code for the revocation
+    /// AUDIT: Distinguishes fraud, KYC failure, investor request, etc.
+    pub reason: RecordChangeReason,
+
+    /// The revoker of this investment record
+    /// AUDIT: Accountable party for revocation
+    /// SECURITY: Records responsible party
+    pub revoked_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Revocation time for audit trail
+    /// SECURITY: Provides temporal context
+    pub revoked_at: i64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when an investor binds a wallet to an unbound record
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks the first wallet assignment of non-custodial records
+/// - Includes the attesting update_whitelist signer
+/// - Provides audit trail for investor onboarding
+/// 
+/// SECURITY:
+/// - Records the wallet that will receive distributions
+/// - Records who attested the investor's identity
+#[event]
+pub struct InvestmentRecordWalletBound {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the bound record
+    /// AUDIT: Locates the record PDA
+    pub batch_id: u16,
+
+    /// Record identifier
+    /// AUDIT: Identifies the bound record
+    pub record_id: u64,
+
+    /// Investor account identifier
+    /// AUDIT: Links the wallet to the off-chain investor
+    pub account_id: [u8; 15],
+
+    /// Newly bound wallet (the signing investor)
+    /// AUDIT: Future distribution destination
+    pub wallet: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Binding time for audit trail
+    pub bound_at: i64,
+
+    /// Signers of the binding transaction (investor and attester)
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when an investor acknowledges a provisional record
+/// 
+/// AUDIT CRITICAL:
+/// - Marks the point from which the record counts toward distributions
+/// - Signed by the record wallet
+#[event]
+pub struct InvestmentRecordAcknowledged {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the acknowledged record
+    pub batch_id: u16,
+
+    /// Record identifier
+    pub record_id: u64,
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Investor wallet that acknowledged
+    /// AUDIT: Accountable party
+    pub acknowledged_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Acknowledgment time for audit trail
+    pub acknowledged_at: i64,
+}
+
+/// Event emitted when an investor contests a revocation
+/// 
+/// AUDIT CRITICAL:
+/// - Opens a dispute that keeps the record's share escrowed
+/// - Signed by the record wallet
+#[event]
+pub struct RevocationContested {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the contested record
+    pub batch_id: u16,
+
+    /// Record identifier
+    pub record_id: u64,
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Investor wallet that contested
+    /// AUDIT: Accountable party
+    pub contested_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Contest time for audit trail
+    pub contested_at: i64,
+}
+
+/// Event emitted when the committee resolves a revocation contest
+/// 
+/// AUDIT CRITICAL:
+/// - Records whether the revocation was cancelled or upheld
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct RevocationContestResolved {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the record
+    pub batch_id: u16,
+
+    /// Record identifier
+    pub record_id: u64,
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// true if the revocation was cancelled
+    /// AUDIT: Reinstated records return to distributions
+    pub reinstated: bool,
+
+    /// The committee member submitting the resolution
+    /// AUDIT: Accountable party
+    pub resolved_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Resolution time for audit trail
+    pub resolved_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a revoked record leaves the profit denominator
+/// 
+/// AUDIT CRITICAL:
+/// - Emitted once per revoked record, after its hold and any contest are over
+/// - Later estimates no longer count the record in total_invest_usdt
+#[event]
+pub struct RevocationHoldReleased {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the record
+    pub batch_id: u16,
+
+    /// Record identifier
+    pub record_id: u64,
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// amount_usdt removed from the profit denominator (0 for an unacknowledged record)
+    /// AUDIT: Unweighted; the stage bucket is the record's stage
+    pub released_usdt: u64,
+
+    /// Account that cranked the release
+    /// AUDIT: Permissionless; recorded for the audit trail
+    pub released_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Release time for audit trail
+    pub released_at: i64,
+}
+
+/// Event emitted by report_revocation_impact
+/// 
+/// AUDIT CRITICAL:
+/// - Informational only; nothing is revoked
+/// - Lets the committee weigh a revocation before signing it
+#[event]
+pub struct RevocationImpactReported {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the record
+    pub batch_id: u16,
+
+    /// Record identifier
+    pub record_id: u64,
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Whether the record is already revoked
+    pub already_revoked: bool,
+
+    /// First refund year covered by refund_hcoin_by_year
+    pub from_year_index: u8,
+
+    /// H2COIN refund freed per remaining year (from_year_index..=MAX_YEAR_INDEX)
+    pub refund_hcoin_by_year: Vec<u64>,
+
+    /// Total H2COIN refund freed across the remaining years
+    pub freed_refund_hcoin: u64,
+
+    /// Share of every future profit round freed, in basis points
+    pub freed_profit_ratio_bp: u16,
+
+    /// The requester of the report
+    pub reported_by: Pubkey,
+
+    /// UNIX timestamp
+    pub reported_at: i64,
+}
+
+/// Event emitted when an account-level payout split is set
+/// 
+/// AUDIT CRITICAL:
+/// - Records where the account's future pending shares will be sent
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct PayoutSplitUpdated {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Payout wallets (unused slots are the default key)
+    pub wallets: [Pubkey; 3],
+
+    /// Percentage per wallet
+    pub percents: [u8; 3],
+
+    /// The updater of the split
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when an account-level payout split is removed
+/// 
+/// AUDIT: Later estimates pay the account's record wallets again
+#[event]
+pub struct PayoutSplitCleared {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// The committee member removing the split
+    /// AUDIT: Accountable party for the change
+    pub cleared_by: Pubkey,
+
+    /// UNIX timestamp
+    pub cleared_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when withdraw whitelist is updated
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks withdraw authorization changes
+/// - Includes all signers for multisig accountability
+/// - Records complete whitelist update
+/// - Provides audit trail for withdrawal access
+/// - Enables monitoring of withdrawal authorization
+/// 
+/// SECURITY:
+/// - Records withdrawal authorization changes
+/// - Records all multisig signers
+/// - Tracks complete whitelist state
+/// - Enables authorization verification
+#[event]
+pub struct WithdrawWhitelistUpdated {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Updated wallet addresses
+    /// AUDIT: Complete new whitelist
+    /// SECURITY: Records complete authorization state
+    pub wallets: Vec<Pubkey>,
+    
+    /// Labels of the new wallets
+    /// AUDIT: Index-aligned with wallets
+    pub labels: Vec<[u8; 16]>,
+    
+    /// The updater of this whitelist
+    /// AUDIT: Accountable party for the change
+    /// SECURITY: Records responsible party
+    pub updated_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Update time for audit trail
+    /// SECURITY: Provides temporal context
+    pub updated_at: i64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when whitelist signer weights are set
+/// 
+/// AUDIT CRITICAL:
+/// - Records the multisig rule now in force for the whitelist
+/// - threshold == 0 means the plain 3-of-5 rule
+#[event]
+pub struct SignerWeightsUpdated {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// true for update_whitelist, false for execute_whitelist
+    pub is_update: bool,
+
+    /// Per-member weights (index-aligned with the whitelist)
+    pub weights: [u8; 5],
+
+    /// Weight threshold
+    pub threshold: u8,
+
+    /// The updater of the weights
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the member labels of a whitelist are set
+/// 
+/// AUDIT CRITICAL:
+/// - Labels are display metadata; the whitelist itself is unchanged
+/// - Records all multisig signers
+#[event]
+pub struct WhitelistLabelsUpdated {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Whitelist whose labels were set
+    pub role: SignerRole,
+
+    /// Whitelist members
+    pub wallets: Vec<Pubkey>,
+
+    /// Labels now in force (index-aligned with wallets)
+    pub labels: Vec<[u8; 16]>,
+
+    /// The updater of the labels
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+//
+// 📤 PROFIT/REFUND ESTIMATION AND EXECUTION EVENTS
+//
+// AUDIT: These events track profit and refund distribution operations
+// SECURITY: Include signer information and amounts for transparency
+// TRANSPARENCY: Enable monitoring of profit and refund distributions
+
+/// Event emitted when profit share is estimated
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks profit distribution calculations
+/// - Includes all signers for multisig accountability
+/// - Records estimated amounts and gas costs
+/// - Provides audit trail for profit calculations
+/// - Enables monitoring of profit estimation
+/// 
+/// SECURITY:
+/// - Records profit calculation details
+/// - Records all multisig signers
+/// - Tracks estimated amounts and costs
+/// - Enables calculation verification
+#[event]
+pub struct ProfitShareEstimated {
+    /// Each batch_id handles up to 30 investment records
+    /// AUDIT: Links to specific batch of records
+    /// SECURITY: Enables batch tracking
+    pub batch_id: u16,
+    
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Total USDT amount to be distributed
+    /// AUDIT: Total profit amount for transparency
+    /// SECURITY: Records total distribution amount
+    pub subtotal_profit_usdt: u64,
+    
+    /// Estimated SOL cost for execution
+    /// AUDIT: Gas cost estimation for transparency
+    /// SECURITY: Records estimated transaction costs
+    pub subtotal_estimate_sol: u64,
+
+    /// Priority fee (micro-lamports per CU) included in the SOL estimate
+    pub priority_fee_per_cu: u64,
+
+    /// Mint the round will be paid in
+    /// AUDIT: subtotal_profit_usdt and the breakdown are in this mint's base units
+    pub payout_mint: Pubkey,
+    
+    /// The estimator of this profit share
+    /// AUDIT: Accountable party for estimation
+    /// SECURITY: Records responsible party
+    pub created_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Estimation time for audit trail
+    /// SECURITY: Provides temporal context
+    pub created_at: i64,
+    
+    /// Number of entries in this batch
+    /// AUDIT: Batch size for transparency
+    /// SECURITY: Records batch complexity
+    pub entry_count: u16,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+
+    /// Per-record shares and the cache entry each was aggregated into
+    /// AUDIT: Entries may combine several records of the same account and wallet
+    pub breakdown: Vec<RecordShare>,
+
+    /// Revoked records loaded but excluded after their holding period, ascending
+    /// AUDIT: Proves every exclusion was a revocation, not a silently dropped investor
+    pub skipped_revoked_record_ids: Vec<u64>,
+}
+
+/// One record's share within an aggregated cache entry
+/// 
+/// AUDIT: Carried by ProfitShareEstimated / RefundShareEstimated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecordShare {
+    pub record_id: u64,
+    pub entry_index: u16,
+    pub amount: u64,
+}
+
+/// Event emitted when refund share is estimated
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks refund distribution calculations
+/// - Includes all signers for multisig accountability
+/// - Records estimated amounts and gas costs
+/// - Provides audit trail for refund calculations
+/// - Enables monitoring of refund estimation
+/// 
+/// SECURITY:
+/// - Records refund calculation details
+/// - Records all multisig signers
+/// - Tracks estimated amounts and costs
+/// - Enables calculation verification
+#[event]
+pub struct RefundShareEstimated {
+    /// Each batch_id handles up to 30 investment records
+    /// AUDIT: Links to specific batch of records
+    /// SECURITY: Enables batch tracking
+    pub batch_id: u16,
+    
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Year index for this refund (0-9)
+    /// AUDIT: Specific year for refund calculation
+    /// SECURITY: Records refund timing
+    pub year_index: u8,
+    
+    /// Total H2COIN amount to be distributed
+    /// AUDIT: Total refund amount for transparency
+    /// SECURITY: Records total distribution amount
+    pub subtotal_refund_hcoin: u64,
+    
+    /// Estimated SOL cost for execution
+    /// AUDIT: Gas cost estimation for transparency
+    /// SECURITY: Records estimated transaction costs
+    pub subtotal_estimate_sol: u64,
+
+    /// Priority fee (micro-lamports per CU) included in the SOL estimate
+    pub priority_fee_per_cu: u64,
+    
+    /// The estimator of this refund share
+    /// AUDIT: Accountable party for estimation
+    /// SECURITY: Records responsible party
+    pub created_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Estimation time for audit trail
+    /// SECURITY: Provides temporal context
+    pub created_at: i64,
+    
+    /// Number of entries in this batch
+    /// AUDIT: Batch size for transparency
+    /// SECURITY: Records batch complexity
+    pub entry_count: u16,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+
+    /// Per-record shares and the cache entry each was aggregated into
+    /// AUDIT: Entries may combine several records of the same account and wallet
+    pub breakdown: Vec<RecordShare>,
+
+    /// Revoked records loaded but excluded after their holding period, ascending
+    /// AUDIT: Proves every exclusion was a revocation, not a silently dropped investor
+    pub skipped_revoked_record_ids: Vec<u64>,
+}
+
+/// Event emitted when profit share is executed
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks actual profit distribution execution
+/// - Includes all signers for multisig accountability
+/// - Records actual transfer amounts
+/// - Provides audit trail for profit execution
+/// - Enables monitoring of profit distributions
+/// 
+/// SECURITY:
+/// - Records actual distribution execution
+/// - Records all multisig signers
+/// - Tracks actual transfer amounts
+/// - Enables execution verification
+#[event]
+pub struct ProfitShareExecuted {
+    /// Batch identifier for this execution
+    /// AUDIT: Links to specific batch of records
+    /// SECURITY: Enables batch tracking
+    pub batch_id: u16,
+    
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Total USDT amount actually transferred
+    /// AUDIT: Actual distribution amount for transparency
+    /// SECURITY: Records actual transfer amount
+    pub total_transfer_usdt: u64,
+    
+    /// The executor of this profit share
+    /// AUDIT: Accountable party for execution
+    /// SECURITY: Records responsible party
+    pub executed_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Execution time for audit trail
+    /// SECURITY: Provides temporal context
+    pub executed_at: i64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+
+    /// Whether the cache was closed and its rent returned to the vault
+    /// AUDIT: Closed caches no longer exist on-chain after this event
+    pub cache_closed: bool,
+
+    /// Note appended to every transfer memo of this execution
+    /// AUDIT: Descriptor only (e.g. "2025 Q2 profit, batch 7"); never used for reconciliation
+    pub note: Option<String>,
+
+    /// Lamports reimbursed from the vault to executed_by for fees fronted
+    /// AUDIT: Non-zero only in the execution that settled the cache; never above subtotal_estimate_sol
+    pub sol_reimbursed: u64,
+}
+
+/// Event emitted when refund share is executed
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks actual refund distribution execution
+/// - Includes all signers for multisig accountability
+/// - Records actual transfer amounts
+/// - Provides audit trail for refund execution
+/// - Enables monitoring of refund distributions
+/// 
+/// SECURITY:
+/// - Records actual distribution execution
+/// - Records all multisig signers
+/// - Tracks actual transfer amounts
+/// - Enables execution verification
+#[event]
+pub struct RefundShareExecuted {
+    /// Batch identifier for this execution
+    /// AUDIT: Links to specific batch of records
+    /// SECURITY: Enables batch tracking
+    pub batch_id: u16,
+    
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Year index for this refund (0-9)
+    /// AUDIT: Specific year for refund calculation
+    /// SECURITY: Records refund timing
+    pub year_index: u8,
+    
+    /// Total H2COIN amount actually transferred
+    /// AUDIT: Actual distribution amount for transparency
+    /// SECURITY: Records actual transfer amount
+    pub total_transfer_hcoin: u64,
+
+    /// The executor of this refund share
+    /// AUDIT: Accountable party for execution
+    /// SECURITY: Records responsible party
+    pub executed_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Execution time for audit trail
+    /// SECURITY: Provides temporal context
+    pub executed_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+
+    /// Whether the cache was closed and its rent returned to the vault
+    /// AUDIT: Closed caches no longer exist on-chain after this event
+    pub cache_closed: bool,
+
+    /// Note appended to every transfer memo of this execution
+    /// AUDIT: Descriptor only (e.g. "2025 Q2 profit, batch 7"); never used for reconciliation
+    pub note: Option<String>,
+
+    /// Lamports reimbursed from the vault to executed_by for fees fronted
+    /// AUDIT: Non-zero only in the execution that settled the cache; never above subtotal_estimate_sol
+    pub sol_reimbursed: u64,
+}
+
+/// Event emitted for every individual entry paid by a profit or refund execution
+///
+/// AUDIT CRITICAL:
+/// - One receipt per successful transfer, not per batch
+/// - Gives every payout its own log artifact without per-entry rent
+/// - A log event, not a compressed account (compressed receipts are open, not delivered)
+/// - Only emitted when the program is built with the `payout-receipts` feature
+///
+/// SECURITY:
+/// - Records recipient wallet, mint and amount actually transferred
+/// - Links the payout to its batch (and year for refunds)
+#[event]
+pub struct PayoutReceipt {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the executed cache
+    /// AUDIT: Links receipt to a specific batch
+    pub batch_id: u16,
+
+    /// Refund year index (None for profit payouts)
+    /// AUDIT: Distinguishes profit from refund receipts
+    pub year_index: Option<u8>,
+
+    /// Position of the paid entry in its cache
+    /// AUDIT: Aligns the receipt with the cache entry and its ATA
+    pub entry_index: u16,
+
+    /// Account identifier of the paid entry
+    /// AUDIT: Links receipt to the investor account
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet
+    /// AUDIT: Owner of the credited associated token account
+    pub wallet: Pubkey,
+
+    /// Mint of the transferred token (USDT or H2COIN)
+    pub mint: Pubkey,
+
+    /// Amount transferred to the recipient
+    /// AUDIT: Must equal the cached entry amount
+    pub amount: u64,
+
+    /// UNIX timestamp
+    /// AUDIT: Payout time for audit trail
+    pub paid_at: i64,
+}
+
+/// Event emitted when a cache entry is escrowed after repeated payout failures
+///
+/// AUDIT CRITICAL:
+/// - Emitted once an entry reaches MAX_ENTRY_FAILURES failed attempts
+/// - The entry is no longer retried by execution
+/// - The amount stays in the vault, reserved for the escrow/claim path
+///
+/// SECURITY:
+/// - Makes every skipped recipient visible to off-chain monitoring
+/// - Records the reserved amount for reconciliation
+#[event]
+pub struct EntryEscrowed {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the cache holding the entry
+    pub batch_id: u16,
+
+    /// Refund year index (None for profit entries)
+    pub year_index: Option<u8>,
+
+    /// Position of the escrowed entry in its cache
+    pub entry_index: u16,
+
+    /// Account identifier of the escrowed entry
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet whose transfers kept failing
+    pub wallet: Pubkey,
+
+    /// Amount reserved in the vault for this entry
+    pub amount: u64,
+
+    /// Number of failed attempts recorded for the entry
+    pub failure_count: u8,
+
+    /// UNIX timestamp
+    /// AUDIT: Escrow time for audit trail
+    pub escrowed_at: i64,
+}
+
+/// Event emitted when a cache entry is requeued because its recipient ATA is closed
+///
+/// AUDIT CRITICAL:
+/// - The entry is no longer retried by execution and does not count as a failure
+/// - The amount stays in the vault until requeue_entry pays it
+///
+/// SECURITY:
+/// - Tells off-chain tooling which investor must recreate an ATA
+#[event]
+pub struct EntryRequeued {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the cache holding the entry
+    pub batch_id: u16,
+
+    /// Refund year index (None for profit entries)
+    pub year_index: Option<u8>,
+
+    /// Position of the requeued entry in its cache
+    pub entry_index: u16,
+
+    /// Account identifier of the requeued entry
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet whose ATA is closed
+    pub wallet: Pubkey,
+
+    /// Amount reserved in the vault for this entry
+    pub amount: u64,
+
+    /// UNIX timestamp
+    pub requeued_at: i64,
+}
+
+/// Event emitted when requeue_entry pays a requeued cache entry
+///
+/// AUDIT CRITICAL:
+/// - Closes the loop opened by EntryRequeued; the entry is now Paid
+/// - Authorized by a single execute whitelist member
+#[event]
+pub struct RequeuedEntryPaid {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the cache holding the entry
+    pub batch_id: u16,
+
+    /// Refund year index (None for profit entries)
+    pub year_index: Option<u8>,
+
+    /// Position of the paid entry in its cache
+    pub entry_index: u16,
+
+    /// Account identifier of the paid entry
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet
+    pub wallet: Pubkey,
+
+    /// Mint paid (USDT for profit, H2COIN for refund)
+    pub mint: Pubkey,
+
+    /// Amount transferred
+    pub amount: u64,
+
+    /// The execute whitelist member who submitted the payout
+    pub paid_by: Pubkey,
+
+    /// UNIX timestamp
+    pub paid_at: i64,
+}
+
+/// Event emitted when release_escrowed_entry requeues an escrowed cache entry
+///
+/// AUDIT CRITICAL:
+/// - Closes the loop opened by EntryEscrowed; requeue_entry pays the entry next
+/// - Authorized by the Executor role
+#[event]
+pub struct EscrowedEntryReleased {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the cache holding the entry
+    pub batch_id: u16,
+
+    /// Refund year index (None for profit entries)
+    pub year_index: Option<u8>,
+
+    /// Position of the released entry in its cache
+    pub entry_index: u16,
+
+    /// Account identifier of the released entry
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet
+    pub wallet: Pubkey,
+
+    /// Amount held for the entry (USDT for profit, H2COIN for refund)
+    pub amount: u64,
+
+    /// Payer of the release transaction
+    pub released_by: Pubkey,
+
+    /// Approving signers
+    pub signers: Vec<Pubkey>,
+
+    /// UNIX timestamp
+    pub released_at: i64,
+}
+
+/// Event emitted when cancel_profit_cache voids a profit estimate
+///
+/// AUDIT CRITICAL:
+/// - Approved by the Executor role; no entry had been paid
+/// - Records what was voided; the cache now holds no entries
+#[event]
+pub struct ProfitCacheCancelled {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the cancelled cache
+    pub batch_id: u16,
+
+    /// Subtotal of the voided estimate
+    pub subtotal_profit_usdt: u64,
+
+    /// total_profit_usdt input of the voided estimate
+    pub total_profit_usdt: u64,
+
+    /// Number of entries removed
+    pub entry_count: u16,
+
+    /// Transaction payer
+    pub cancelled_by: Pubkey,
+
+    /// Multisig signers who approved the cancellation
+    pub signers: Vec<Pubkey>,
+
+    /// UNIX timestamp
+    pub cancelled_at: i64,
+}
+
+/// Event emitted when open_profit_claims lets investors pull a profit cache
+///
+/// AUDIT CRITICAL:
+/// - Approved by the Executor role (3-of-5 execute_whitelist until assigned)
+/// - From now on Pending entries may be paid by claim_profit as well as by execution
+#[event]
+pub struct ProfitClaimsOpened {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the opened cache
+    pub batch_id: u16,
+
+    /// Mint the entries are claimed in (the cache's payout_mint)
+    pub payout_mint: Pubkey,
+
+    /// Number of entries still Pending when claims were opened
+    pub pending_entries: u16,
+
+    /// Transaction payer
+    pub opened_by: Pubkey,
+
+    /// Multisig signers who approved the opening
+    pub signers: Vec<Pubkey>,
+
+    /// UNIX timestamp
+    pub opened_at: i64,
+}
+
+/// Event emitted when an investor claims a profit cache entry
+///
+/// AUDIT CRITICAL:
+/// - Signed by the entry wallet itself; the entry is now Paid
+/// - settled is true when this claim left no Pending entry and set executed_at
+#[event]
+pub struct ProfitClaimed {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier of the cache holding the entry
+    pub batch_id: u16,
+
+    /// Position of the claimed entry in its cache
+    pub entry_index: u16,
+
+    /// Account identifier of the claimed entry
+    pub account_id: [u8; 15],
+
+    /// Claiming wallet (the entry wallet)
+    pub wallet: Pubkey,
+
+    /// Mint paid (the cache's payout_mint)
+    pub mint: Pubkey,
+
+    /// Amount transferred
+    pub amount: u64,
+
+    /// Whether this claim settled the cache
+    pub settled: bool,
+
+    /// Account that paid the transaction and any rent (the claimant unless sponsored)
+    pub fee_payer: Pubkey,
+
+    /// Lamports reimbursed from the vault to a sponsoring relayer; 0 when not sponsored
+    pub sol_reimbursed: u64,
+
+    /// UNIX timestamp
+    pub claimed_at: i64,
+}
+
+/// Event emitted when estimate_profit_share_merkle publishes a batch's Merkle root
+///
+/// AUDIT CRITICAL:
+/// - Approved by the Executor role; the entries themselves are published off-chain
+/// - replaced is true when an unclaimed earlier root was overwritten
+#[event]
+pub struct ProfitMerkleRootPublished {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier
+    pub batch_id: u16,
+
+    /// Published root
+    pub merkle_root: [u8; 32],
+
+    /// Number of leaves
+    pub entry_count: u32,
+
+    /// Sum of all leaf amounts
+    pub subtotal_profit_usdt: u64,
+
+    /// Mint the entries are claimed in
+    pub payout_mint: Pubkey,
+
+    /// Whether an earlier root was replaced
+    pub replaced: bool,
+
+    /// End of the claim window
+    pub expires_at: i64,
+
+    /// Transaction payer
+    pub created_by: Pubkey,
+
+    /// Multisig signers who approved the root
+    pub signers: Vec<Pubkey>,
+
+    /// UNIX timestamp
+    pub created_at: i64,
+}
+
+/// Event emitted when cancel_profit_merkle stops a Merkle profit round
+///
+/// AUDIT CRITICAL:
+/// - Approved by the Executor role; claims already made stay paid
+/// - unclaimed_usdt is no longer reserved in the vault
+#[event]
+pub struct ProfitMerkleCancelled {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier
+    pub batch_id: u16,
+
+    /// Root of the cancelled round
+    pub merkle_root: [u8; 32],
+
+    /// Entries claimed before cancellation
+    pub claimed_count: u32,
+
+    /// Amount claimed before cancellation
+    pub claimed_usdt: u64,
+
+    /// Part of the subtotal left unclaimed
+    pub unclaimed_usdt: u64,
+
+    /// Transaction payer
+    pub cancelled_by: Pubkey,
+
+    /// Multisig signers who approved the cancellation
+    pub signers: Vec<Pubkey>,
+
+    /// UNIX timestamp
+    pub cancelled_at: i64,
+}
+
+/// Event emitted when an investor claims a Merkle profit entry
+///
+/// AUDIT CRITICAL:
+/// - The claimant proved the leaf with its own wallet; a MerkleClaimReceipt now exists
+/// - settled is true when this claim was the last of the batch
+#[event]
+pub struct MerkleProfitClaimed {
+    /// Investment ID (fixed-length string)
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    pub version: [u8; 4],
+
+    /// Batch identifier
+    pub batch_id: u16,
+
+    /// Claimed leaf index
+    pub entry_index: u32,
+
+    /// Account identifier of the leaf
+    pub account_id: [u8; 15],
+
+    /// Claiming wallet
+    pub wallet: Pubkey,
+
+    /// Mint paid (the cache's payout_mint)
+    pub mint: Pubkey,
+
+    /// Amount transferred
+    pub amount: u64,
+
+    /// Whether this claim settled the batch
+    pub settled: bool,
+
+    /// Account that paid the transaction and any rent (the claimant unless sponsored)
+    pub fee_payer: Pubkey,
+
+    /// Lamports reimbursed from the vault to a sponsoring relayer; 0 when not sponsored
+    pub sol_reimbursed: u64,
+
+    /// UNIX timestamp
+    pub claimed_at: i64,
+}
+
+/// Event emitted by reconcile_batch
+/// 
+/// AUDIT CRITICAL:
+/// - Reports how a batch's records line up with its profit or refund cache
+/// - Catches estimation-time omissions after the fact
+/// - Read-only report; no state was changed
+#[event]
+pub struct BatchReconciled {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Reconciled batch
+    pub batch_id: u16,
+
+    /// Refund year (None for a profit cache)
+    pub year_index: Option<u8>,
+
+    /// Distributable records examined
+    pub records_checked: u16,
+
+    /// Records matched by a cache entry with the expected amount
+    pub entries_matched: u16,
+
+    /// record_ids with no cache entry
+    /// AUDIT: Omitted at estimation time
+    pub missing_records: Vec<u64>,
+
+    /// record_ids whose cache entry amount differs from the re-derived amount
+    pub amount_mismatches: Vec<u64>,
+
+    /// Cache entries not accounted for by any supplied record
+    pub orphan_entries: u16,
+
+    /// true when nothing is missing, mismatched or orphaned
+    pub is_consistent: bool,
+
+    /// Whitelisted signer that requested the report
+    /// AUDIT: Accountable party
+    pub reconciled_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Reconciliation time for audit trail
+    pub reconciled_at: i64,
+}
+
+/// Compact summary of one investment record
+/// 
+/// AUDIT: Carried by RecordPageEmitted; mirrors InvestmentRecord fields used in reconciliation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecordSummary {
+    pub record_id: u64,
+    pub account_id: [u8; 15],
+    pub wallet: Pubkey,
+    pub amount_usdt: u64,
+    pub amount_hcoin: u64,
+    pub stage: u8,
+    pub revoked_at: i64,
+}
+
+/// Event emitted by emit_record_page
+/// 
+/// AUDIT CRITICAL:
+/// - Chain-native export of a batch's records for reconciliation tools
+/// - Page N covers record_id N * RECORD_PAGE_SIZE .. (N + 1) * RECORD_PAGE_SIZE
+/// - Read-only report; no state was changed
+#[event]
+pub struct RecordPageEmitted {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Exported batch
+    pub batch_id: u16,
+
+    /// Page number
+    pub page: u32,
+
+    /// Records of the page, ordered by record_id
+    pub records: Vec<RecordSummary>,
+
+    /// UNIX timestamp
+    pub emitted_at: i64,
+}
+
+/// Event emitted by emit_batch_statistics
+/// 
+/// AUDIT CRITICAL:
+/// - Portfolio statistics over the supplied records of one batch
+/// - Amounts are amount_usdt; only acknowledged, non-revoked records are counted
+/// - Read-only report; no state was changed
+#[event]
+pub struct BatchStatisticsEmitted {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Summarized batch
+    pub batch_id: u16,
+
+    /// Records counted in the statistics
+    pub record_count: u16,
+
+    /// Supplied records left out (revoked or unacknowledged)
+    pub excluded_count: u16,
+
+    /// Sum of counted investments
+    pub total_usdt: u64,
+
+    /// Smallest counted investment
+    pub min_usdt: u64,
+
+    /// Largest counted investment
+    pub max_usdt: u64,
+
+    /// Mean investment (floored)
+    pub mean_usdt: u64,
+
+    /// Median investment (floored mean of the middle pair for even counts)
+    pub median_usdt: u64,
+
+    /// Counted records per stage (index 0 = stage 1)
+    pub stage_counts: [u16; 3],
+
+    /// Counted investment per stage (index 0 = stage 1)
+    pub stage_usdt: [u64; 3],
+
+    /// UNIX timestamp
+    pub emitted_at: i64,
+}
+
+//
+// 💰 VAULT DEPOSIT AND WITHDRAWAL EVENTS
+//
+// AUDIT: These events track vault fund movements
+// SECURITY: Include amounts and addresses for transparency
+// TRANSPARENCY: Enable monitoring of fund movements
+
+/// Event emitted when SOL is deposited to vault
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks SOL deposits to vault
+/// - Records depositor for accountability
+/// - Provides audit trail for fund inflows
+/// - Enables monitoring of vault funding
+/// 
+/// SECURITY:
+/// - Records fund inflows
+/// - Records depositor identity
+/// - Tracks deposit amounts
+/// - Enables fund flow verification
+#[event]
+pub struct VaultDepositSolEvent {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Depositor wallet address
+    /// AUDIT: Source of the deposit
+    /// SECURITY: Records fund source
+    pub from: Pubkey,
+    
+    /// SOL amount deposited (in lamports)
+    /// AUDIT: Deposit amount for transparency
+    /// SECURITY: Records deposit value
+    pub amount_usdt: u64,
+    
+    /// Vault balance after the deposit (in lamports)
+    /// AUDIT: Tracks the gas budget
+    pub vault_lamports: u64,
+    
+    /// Reserve the vault should hold (rent-exempt minimum plus a full batch estimate)
+    /// AUDIT: vault_lamports below this means the next batch may be underfunded
+    pub required_reserve: u64,
+    
+    /// Back-office transfer order ID supplied by the depositor
+    /// AUDIT: Matches on-chain deposits to internal transfer orders; not validated
+    pub reference_id: Option<[u8; 16]>,
+    
+    /// UNIX timestamp
+    /// AUDIT: Deposit time for audit trail
+    /// SECURITY: Provides temporal context
+    pub deposit_at: i64,
+}
+
+/// Event emitted when tokens are deposited to vault
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks token deposits to vault
+/// - Records depositor and token type
+/// - Provides audit trail for token inflows
+/// - Enables monitoring of token funding
+/// 
+/// SECURITY:
+/// - Records token inflows
+/// - Records depositor identity
+/// - Tracks token types and amounts
+/// - Enables token flow verification
+#[event]
+pub struct VaultDepositTokenEvent {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Depositor wallet address
+    /// AUDIT: Source of the deposit
+    /// SECURITY: Records fund source
+    pub from: Pubkey,
+    
+    /// Token mint address
+    /// AUDIT: Type of token deposited
+    /// SECURITY: Records token type
+    pub mint: Pubkey,
+    
+    /// Token amount deposited
+    /// AUDIT: Deposit amount for transparency
+    /// SECURITY: Records deposit value
+    pub amount: u64,
+    
+    /// Back-office transfer order ID supplied by the depositor
+    /// AUDIT: Matches on-chain deposits to internal transfer orders; not validated
+    pub reference_id: Option<[u8; 16]>,
+    
+    /// UNIX timestamp
+    /// AUDIT: Deposit time for audit trail
+    /// SECURITY: Provides temporal context
+    pub deposit_at: i64,
+}
+
+/// Event emitted when funds are withdrawn from vault
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks vault withdrawals
+/// - Includes all signers for multisig accountability
+/// - Records all token types and amounts
+/// - Provides audit trail for fund outflows
+/// - Enables monitoring of vault withdrawals
+/// 
+/// SECURITY:
+/// - Records fund outflows
+/// - Records all multisig signers
+/// - Tracks all token types and amounts
+/// - Enables withdrawal verification
+#[event]
+pub struct VaultTransferred {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    /// SECURITY: Enables tracking of specific investments
+    pub investment_id: [u8; 15],
+    
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    /// SECURITY: Enables code audit trail
+    pub version: [u8; 4],
+    
+    /// Recipient wallet address
+    /// AUDIT: Destination of the withdrawal
+    /// SECURITY: Records fund destination
+    pub recipient: Pubkey,
+    
+    /// Assets selected for this withdrawal
+    /// AUDIT: Unselected assets report 0 and stay in the vault
+    pub assets: WithdrawAssets,
+
+    /// Purpose code of this withdrawal
+    /// AUDIT: Budget line the amounts were counted against
+    pub purpose: WithdrawPurpose,
+    
+    /// USDT amount withdrawn
+    /// AUDIT: USDT withdrawal amount for transparency
+    /// SECURITY: Records USDT outflow
+    pub usdt_amount: u64,
+    
+    /// H2COIN amount withdrawn
+    /// AUDIT: H2COIN withdrawal amount for transparency
+    /// SECURITY: Records H2COIN outflow
+    pub hcoin_amount: u64,
+    
+    /// SOL amount withdrawn
+    /// AUDIT: SOL withdrawal amount for transparency
+    /// SECURITY: Records SOL outflow
+    pub sol_amount: u64,
+    
+    /// The executor of this withdrawal
+    /// AUDIT: Accountable party for withdrawal
+    /// SECURITY: Records responsible party
+    pub executed_by: Pubkey,
+    
+    /// UNIX timestamp
+    /// AUDIT: Withdrawal time for audit trail
+    /// SECURITY: Provides temporal context
+    pub executed_at: i64,
+    
+    /// Withdrawal sequence number consumed by this withdrawal
+    /// AUDIT: Matches the instruction argument
+    /// SECURITY: Proves each withdrawal was signed individually
+    pub sequence: u64,
+    
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    /// SECURITY: Records all authorized parties
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted by snapshot_vault
+/// 
+/// AUDIT CRITICAL:
+/// - Attests vault balances at a slot for auditors and dashboards
+/// - Obligations cover only the caches supplied (caches_counted)
+/// 
+/// SECURITY:
+/// - Permissionless and read-only apart from the optional snapshot PDA
+#[event]
+pub struct VaultSnapshot {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Vault PDA
+    pub vault: Pubkey,
+
+    /// Lamports held by the vault PDA
+    pub sol_lamports: u64,
+
+    /// USDT held by the vault ATA
+    pub usdt_balance: u64,
+
+    /// H2COIN held by the vault ATA
+    pub hcoin_balance: u64,
+
+    /// USDT still owed by the supplied profit caches
+    pub obligations_usdt: u64,
+
+    /// H2COIN still owed by the supplied refund caches
+    pub obligations_hcoin: u64,
+
+    /// Number of caches counted in the obligations
+    pub caches_counted: u16,
+
+    /// Slot of the snapshot
+    pub slot: u64,
+
+    /// Payer who took the snapshot
+    pub taken_by: Pubkey,
+
+    /// UNIX timestamp
+    pub taken_at: i64,
+}
+
+/// Event emitted by the invariant health check
+/// 
+/// AUDIT CRITICAL:
+/// - healthy = usdt_covered && hcoin_covered && reentrancy_clear
+/// - Obligations cover only the caches and ledgers supplied (caches_counted, ledgers_counted)
+/// 
+/// SECURITY:
+/// - Permissionless and read-only; ops alert on healthy = false
+#[event]
+pub struct HealthReport {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// USDT held by the vault ATA
+    pub usdt_balance: u64,
+
+    /// H2COIN held by the vault ATA
+    pub hcoin_balance: u64,
+
+    /// Unpaid USDT of unexecuted, unexpired profit caches
+    pub pending_profit_usdt: u64,
+
+    /// Unsettled USDT of the supplied accrual ledgers
+    pub outstanding_accrual_usdt: u64,
+
+    /// Unpaid H2COIN of unexecuted, unexpired refund caches
+    pub pending_refund_hcoin: u64,
+
+    /// Caches supplied (executed and expired caches included)
+    pub caches_counted: u16,
+
+    /// Accrual ledgers supplied
+    pub ledgers_counted: u16,
+
+    /// pending_profit_usdt + outstanding_accrual_usdt <= usdt_balance
+    pub usdt_covered: bool,
+
+    /// pending_refund_hcoin <= hcoin_balance
+    pub hcoin_covered: bool,
+
+    /// InvestmentInfo.execution_in_progress is not stuck
+    pub reentrancy_clear: bool,
+
+    /// All checks passed
+    pub healthy: bool,
+
+    /// Slot of the check
+    pub slot: u64,
+
+    /// UNIX timestamp
+    pub checked_at: i64,
+}
+
+/// Event emitted when repair_vault_ata checks a vault ATA
+/// 
+/// AUDIT CRITICAL:
+/// - recreated = true means the canonical ATA was missing and has been created empty
+/// - A frozen ATA cannot be repaired by the program and needs the mint freeze authority
+#[event]
+pub struct VaultAtaRepaired {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// USDT or H2COIN mint
+    pub mint: Pubkey,
+
+    /// Canonical vault ATA
+    pub token_account: Pubkey,
+
+    /// Whether the ATA was missing and has been created
+    pub recreated: bool,
+
+    /// Token balance after the repair
+    pub balance: u64,
+
+    /// Whether the ATA is frozen by the mint freeze authority
+    pub is_frozen: bool,
+
+    /// Payer of the repair
+    pub repaired_by: Pubkey,
+
+    /// UNIX timestamp
+    pub repaired_at: i64,
+}
+
+/// Event emitted when a foreign SPL token is swept out of the vault
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks tokens other than USDT / H2COIN leaving the vault
+/// - The recipient is always InvestmentInfo.recovery_wallet
+/// 
+/// SECURITY:
+/// - Records the mint, source token account and amount
+/// - Records all multisig signers
+#[event]
+pub struct ForeignTokenSwept {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Mint of the swept token
+    pub mint: Pubkey,
+
+    /// Vault-owned token account the tokens were swept from
+    pub source: Pubkey,
+
+    /// Recovery wallet receiving the tokens
+    pub recipient: Pubkey,
+
+    /// Amount swept (raw units of the mint)
+    pub amount: u64,
+
+    /// The executor of the sweep
+    pub executed_by: Pubkey,
+
+    /// UNIX timestamp
+    pub executed_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a mint is added to the vault token registry
+/// 
+/// AUDIT CRITICAL:
+/// - The vault ATA of the mint exists from this point on
+/// - Records all multisig signers
+#[event]
+pub struct VaultMintRegistered {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Registered mint
+    pub mint: Pubkey,
+
+    /// Vault associated token account of the mint
+    pub ata: Pubkey,
+
+    /// Mint decimals
+    pub decimals: u8,
+
+    /// The registrar of the mint
+    pub registered_by: Pubkey,
+
+    /// UNIX timestamp
+    pub registered_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a mint is removed from the vault token registry
+/// 
+/// AUDIT CRITICAL:
+/// - The vault ATA was empty; later deposits of the mint count as foreign
+/// - Records all multisig signers
+#[event]
+pub struct VaultMintDeregistered {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Deregistered mint
+    pub mint: Pubkey,
+
+    /// Vault associated token account of the mint
+    pub ata: Pubkey,
+
+    /// The remover of the mint
+    pub deregistered_by: Pubkey,
+
+    /// UNIX timestamp
+    pub deregistered_at: i64,
+
+    /// All signers involved in the multisig operation
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a timelocked withdrawal is proposed
+/// 
+/// AUDIT CRITICAL:
+/// - Monitoring should alert all whitelist members so they can cancel before executable_at
+/// - Amounts are final; execute_withdrawal transfers exactly these
+/// - Records all multisig signers
+#[event]
+pub struct WithdrawalProposed {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Destination wallet
+    pub recipient: Pubkey,
+
+    /// Assets selected for the withdrawal
+    pub assets: WithdrawAssets,
+
+    /// Purpose code of the withdrawal
+    pub purpose: WithdrawPurpose,
+
+    /// USDT to transfer
+    pub usdt_amount: u64,
+
+    /// H2COIN to transfer
+    pub hcoin_amount: u64,
+
+    /// SOL to transfer (lamports)
+    pub sol_amount: u64,
+
+    /// Withdrawal sequence the proposal consumes when executed
+    pub sequence: u64,
+
+    /// First timestamp at which the withdrawal may be executed
+    pub executable_at: i64,
+
+    /// Account that proposed the withdrawal
+    pub proposed_by: Pubkey,
+
+    /// Proposal timestamp
+    pub proposed_at: i64,
+
+    /// List of signers who authorized the withdrawal
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when a pending withdrawal is cancelled
+/// 
+/// AUDIT CRITICAL:
+/// - A single whitelist member can cancel; review cancelled_by
+#[event]
+pub struct WithdrawalCancelled {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Destination wallet of the cancelled withdrawal
+    pub recipient: Pubkey,
+
+    /// Purpose code of the cancelled withdrawal
+    pub purpose: WithdrawPurpose,
+
+    /// USDT that would have been transferred
+    pub usdt_amount: u64,
+
+    /// H2COIN that would have been transferred
+    pub hcoin_amount: u64,
+
+    /// SOL that would have been transferred (lamports)
+    pub sol_amount: u64,
+
+    /// Withdrawal sequence of the cancelled withdrawal
+    pub sequence: u64,
+
+    /// Whitelist member who cancelled
+    pub cancelled_by: Pubkey,
+
+    /// Cancellation timestamp
+    pub cancelled_at: i64,
+}
+
+//
+// 🛑 PROGRAM CONFIG EVENTS
+//
+// AUDIT: These events track program-wide configuration changes
+// SECURITY: Emergency stop toggles must be observable off-chain
+
+/// Event emitted when the program config is initialized
+/// 
+/// AUDIT CRITICAL:
+/// - Records the config authority (program upgrade authority)
+#[event]
+pub struct ProgramConfigInitialized {
+    /// Config authority
+    /// AUDIT: Party allowed to toggle the pause
+    pub authority: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Creation time for audit trail
+    pub created_at: i64,
+}
+
+/// Event emitted when the program-wide pause is toggled
+/// 
+/// AUDIT CRITICAL:
+/// - Tracks every emergency stop and resume
+/// - Enables alerting on incident response actions
+#[event]
+pub struct ProgramPauseUpdated {
+    /// New pause state
+    /// AUDIT: true blocks execution and withdrawals
+    pub paused: bool,
+
+    /// The authority that toggled the pause
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Toggle time for audit trail
+    pub updated_at: i64,
+}
+
+/// Event emitted when the published limits are initialized or overridden
+/// 
+/// AUDIT CRITICAL:
+/// - Carries the full overridable set so indexers never need a follow-up read
+#[event]
+pub struct LimitsConfigUpdated {
+    /// Maximum entries per batch now published
+    pub max_entries_per_batch: u16,
+
+    /// Cache validity now published
+    pub share_cache_expire_secs: i64,
+
+    /// Maximum upgrade window now published
+    pub max_upgrade_window_secs: i64,
+
+    /// The authority that made the change
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Change time for audit trail
+    pub updated_at: i64,
+}
+
+/// Event emitted when the global parameters are initialized or changed
+/// 
+/// AUDIT CRITICAL:
+/// - Carries the full parameter set so indexers never need a follow-up read
+/// - signers is empty on initialization, which the config authority performs alone
+#[event]
+pub struct GlobalConfigUpdated {
+    /// Program-level USDT mint
+    pub usdt_mint: Pubkey,
+
+    /// H2COIN mint
+    pub hcoin_mint: Pubkey,
+
+    /// Stablecoins new investments may choose as profit mint
+    pub allowed_profit_mints: Vec<Pubkey>,
+
+    /// Base SOL charged per execution batch
+    pub estimate_sol_base: u64,
+
+    /// SOL charged per executed entry
+    pub estimate_sol_per_entry: u64,
+
+    /// Cache validity in seconds
+    pub share_cache_expire_secs: i64,
+
+    /// Relayer allowed to sponsor claims (default = sponsorship disabled)
+    pub claim_relayer: Pubkey,
+
+    /// The wallet that submitted the change
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Change time for audit trail
+    pub updated_at: i64,
+
+    /// Committee members that signed the change
+    /// AUDIT: Multisig signers for audit trail
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted by bootstrap_check
+/// 
+/// AUDIT CRITICAL:
+/// - Read-only readiness report used as a deployment gate
+/// - `ready` is true only when every check passed, the program is not paused
+///   and the investment is active
+#[event]
+pub struct BootstrapReport {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// ProgramConfig exists at its PDA and deserializes
+    pub program_config_ok: bool,
+
+    /// Program-wide pause flag (false when the config is missing)
+    pub paused: bool,
+
+    /// GlobalConfig exists at its PDA and deserializes
+    pub global_config_ok: bool,
+
+    /// USDT mint matches the expected mint and is a token mint
+    pub usdt_mint_ok: bool,
+
+    /// H2COIN mint matches GlobalConfig.hcoin_mint and is a token mint
+    pub hcoin_mint_ok: bool,
+
+    /// InvestmentInfo sits at its PDA with the stored bump
+    pub investment_info_ok: bool,
+
+    /// InvestmentConfig exists at its PDA and deserializes
+    pub investment_config_ok: bool,
+
+    /// Vault matches its PDA and InvestmentInfo.vault, and is rent-exempt
+    pub vault_ok: bool,
+
+    /// Vault USDT ATA exists with the right mint and owner
+    pub vault_usdt_ata_ok: bool,
+
+    /// Vault H2COIN ATA exists with the right mint and owner
+    pub vault_hcoin_ata_ok: bool,
+
+    /// InvestmentInfo is active
+    pub is_active: bool,
+
+    /// All checks passed
+    pub ready: bool,
+
+    /// Requester of the check
+    pub checked_by: Pubkey,
+
+    /// UNIX timestamp
+    pub checked_at: i64,
+}
+
+//
+// 📅 DISTRIBUTION SCHEDULE EVENTS
+//
+// AUDIT: These events track the on-chain distribution calendar
+// SECURITY: Include signer information for accountability
+
+/// Event emitted when a distribution round is added to the schedule
+/// 
+/// AUDIT CRITICAL:
+/// - Gives investors a predictable on-chain calendar
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct DistributionScheduled {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Profit or refund round
+    pub kind: DistributionKind,
+
+    /// Batch of the planned round
+    pub batch_id: u16,
+
+    /// Refund year (0 for profit rounds)
+    pub year_index: u8,
+
+    /// Planned execution date (UNIX timestamp)
+    pub target_at: i64,
+
+    /// The committee member submitting the schedule
+    /// AUDIT: Accountable party
+    pub scheduled_by: Pubkey,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+//
+// 🛠️ UPGRADE GOVERNANCE EVENTS
+//
+// AUDIT: These events track control over the program upgrade authority
+// SECURITY: Include signer information for accountability
+
+/// Event emitted when the upgrade authority is handed to the governance PDA
+/// 
+/// AUDIT CRITICAL:
+/// - Marks the end of single-key upgrade control
+/// - Records the committee that now controls upgrades
+#[event]
+pub struct UpgradeGovernanceInitialized {
+    /// Governance PDA (new upgrade authority)
+    pub governance: Pubkey,
+
+    /// Upgrade committee
+    /// AUDIT: 3-of-5 required for every upgrade action
+    pub committee: Vec<Pubkey>,
+
+    /// Upgrade authority before the handoff
+    /// AUDIT: Accountable party for the handoff
+    pub previous_authority: Pubkey,
+
+    /// Initialization timestamp
+    pub created_at: i64,
+}
+
+/// Event emitted when the committee hands off or renounces the upgrade authority
+/// 
+/// AUDIT CRITICAL:
+/// - `None` means the program is now immutable
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct UpgradeAuthorityTransferred {
+    /// New upgrade authority (None when renounced)
+    pub new_authority: Option<Pubkey>,
+
+    /// Transfer timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when the committee approves a buffer and upgrade window
+/// 
+/// AUDIT CRITICAL:
+/// - Gives users advance notice of the exact bytes to be deployed
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct UpgradeWindowScheduled {
+    /// Approved buffer account
+    pub buffer: Pubkey,
+
+    /// Window start (UNIX timestamp)
+    pub window_start: i64,
+
+    /// Window end (UNIX timestamp)
+    pub window_end: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+
+    /// Scheduling timestamp
+    pub scheduled_at: i64,
+}
+
+/// Event emitted when the approved upgrade is deployed
+/// 
+/// AUDIT CRITICAL:
+/// - Links the deployed buffer to the committee member who executed it
+#[event]
+pub struct ProgramUpgraded {
+    /// Buffer that was deployed
+    pub buffer: Pubkey,
+
+    /// Committee member who executed the upgrade
+    /// AUDIT: Accountable party
+    pub executed_by: Pubkey,
+
+    /// Execution timestamp
+    pub executed_at: i64,
+}
+
+//
+// 🧾 OPERATING ALLOWANCE EVENTS
+//
+// AUDIT: These events track standing operating-expense withdrawals
+// SECURITY: Setting includes signers; every claim records amount and period
+
+/// Event emitted when the committee sets or changes the operating allowance
+/// 
+/// AUDIT CRITICAL:
+/// - amount 0 disables the allowance
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct OperatingAllowanceSet {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Wallet receiving the allowance
+    pub recipient: Pubkey,
+
+    /// Maximum USDT (base units) per period
+    pub amount: u64,
+
+    /// Period length in seconds
+    pub period_secs: i64,
+
+    /// UNIX timestamp
+    pub updated_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+/// Event emitted when part of the operating allowance is claimed
+/// 
+/// AUDIT CRITICAL:
+/// - Claims are permissionless; funds only reach the committee-set recipient
+#[event]
+pub struct OperatingAllowanceClaimed {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Wallet receiving the allowance
+    pub recipient: Pubkey,
+
+    /// USDT transferred by this claim
+    pub amount: u64,
+
+    /// Total claimed in the current period, including this claim
+    pub claimed_in_period: u64,
+
+    /// Start of the current period
+    pub period_start: i64,
+
+    /// Wallet that submitted the claim
+    pub claimed_by: Pubkey,
+
+    /// UNIX timestamp
+    pub claimed_at: i64,
+}
+
+//
+// ↩️ DEPOSIT RETURN EVENTS
+//
+// AUDIT: These events track USDT returned to investors of a cancelled investment
+// SECURITY: Include signer information for accountability
+
+/// Event emitted when recorded deposits are returned
+/// 
+/// AUDIT CRITICAL:
+/// - Only for cancelled investments
+/// - Each returned record is stamped so it cannot be returned twice
+#[event]
+pub struct DepositsReturned {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch of the returned records
+    pub batch_id: u16,
+
+    /// record_ids returned in this transaction
+    pub record_ids: Vec<u64>,
+
+    /// Total USDT returned in this transaction
+    pub total_usdt: u64,
+
+    /// The executor of this return
+    /// AUDIT: Accountable party
+    pub executed_by: Pubkey,
+
+    /// UNIX timestamp
+    pub executed_at: i64,
+
+    /// All signers involved in the multisig operation
+    /// AUDIT: Complete signer list for accountability
+    pub signers: Vec<Pubkey>,
+}
+
+//
+// 🧮 ANNUAL STATEMENT EVENTS
+//
+// AUDIT: These events track generation of per-investor yearly statements
+// SECURITY: Record the requesting whitelist signer
+
+/// Event emitted when an annual statement is (re)generated
+/// 
+/// AUDIT CRITICAL:
+/// - Mirrors the totals written to the AnnualStatement account
+/// - Counts let tax tooling verify every record and settled cache was supplied
+#[event]
+pub struct AnnualStatementGenerated {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Calendar year (UTC)
+    pub year: u16,
+
+    /// USDT invested in the year
+    pub invested_usdt: u64,
+
+    /// H2COIN allocated in the year
+    pub invested_hcoin: u64,
+
+    /// USDT profit received in the year
+    pub profit_usdt: u64,
+
+    /// H2COIN refunds received in the year
+    pub refund_hcoin: u64,
+
+    /// Records of the investor counted
+    pub records_counted: u16,
+
+    /// Settled caches of the year examined
+    pub caches_counted: u16,
+
+    /// Whitelisted signer that requested the statement
+    /// AUDIT: Accountable party
+    pub generated_by: Pubkey,
+
+    /// UNIX timestamp
+    pub generated_at: i64,
+}
+
+//
+// 🔔 NOTIFICATION EVENTS
+//
+// AUDIT: These events feed the off-chain notification service
+// SECURITY: Reference registrations only; no contact data is emitted beyond the registered hash
+
+/// Event emitted when a wallet registers or updates its notification channel
+/// 
+/// AUDIT CRITICAL:
+/// - Signed by the wallet itself
+#[event]
+pub struct NotificationRegistered {
+    /// Registered wallet
+    pub wallet: Pubkey,
+
+    /// Registration PDA
+    pub registration: Pubkey,
+
+    /// Delivery channel
+    pub channel: NotificationChannel,
+
+    /// Encrypted contact hash or push-channel identifier
+    pub contact: [u8; 32],
+
+    /// UNIX timestamp
+    pub registered_at: i64,
+}
+
+/// Event emitted when a wallet removes its registration
+#[event]
+pub struct NotificationUnregistered {
+    /// Registered wallet
+    pub wallet: Pubkey,
+
+    /// Closed registration PDA
+    pub registration: Pubkey,
+
+    /// UNIX timestamp
+    pub unregistered_at: i64,
+}
+
+/// Event emitted per registered investor at estimation and payout
+/// 
+/// AUDIT CRITICAL:
+/// - Only emitted when the wallet's registration was supplied and validated
+/// - The service resolves `registration` to the delivery channel
+#[event]
+pub struct InvestorNotification {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Batch identifier
+    pub batch_id: u16,
+
+    /// Refund year index; None for profit notifications
+    pub year_index: Option<u8>,
+
+    /// Notification kind
+    pub kind: NotificationKind,
+
+    /// Investor wallet
+    pub wallet: Pubkey,
+
+    /// Registration PDA of the wallet
+    pub registration: Pubkey,
+
+    /// Estimated or paid amount (USDT for profit, H2COIN for refund)
+    pub amount: u64,
+
+    /// UNIX timestamp
+    pub notified_at: i64,
+}
+
+//
+// 🛡️ VAULT TOKEN ACCOUNT GUARD EVENTS
+//
+// AUDIT: These events report tampering with vault token accounts
+// SECURITY: Emitted before payouts whenever a delegate or close authority is found
+
+/// Event emitted when a vault token account carries a delegate or close authority
+/// 
+/// AUDIT CRITICAL:
+/// - Delegates are always revoked; close authorities only when held by the vault
+/// - close_authority set with close_authority_cleared == false needs manual follow-up
+#[event]
+pub struct VaultTokenAccountGuarded {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
+    pub version: [u8; 4],
+
+    /// Vault token account inspected
+    pub token_account: Pubkey,
+
+    /// Mint of the token account
+    pub mint: Pubkey,
+
+    /// Delegate found, if any
+    pub delegate: Option<Pubkey>,
+
+    /// Whether the delegate was revoked
+    pub delegate_revoked: bool,
+
+    /// Close authority found, if any
+    pub close_authority: Option<Pubkey>,
+
+    /// Whether the close authority was cleared
+    pub close_authority_cleared: bool,
+
+    /// UNIX timestamp
+    pub detected_at: i64,
+}
+
+//
+// 📒 PROFIT ACCRUAL EVENTS
+//
+// AUDIT: These events track quarterly profit accruals and their settlement
+// SECURITY: Accruals are multisig approved; settlements only pay recorded wallets
+
+/// Event emitted when a quarter's profit is credited to a batch's accrual ledger
+/// 
+/// AUDIT CRITICAL:
+/// - No tokens move; balances become settleable
+/// - Includes all signers for multisig accountability
+#[event]
+pub struct ProfitAccrued {
+    /// Investment ID (fixed-length string)
+    /// AUDIT: Unique identifier for the investment
+    pub investment_id: [u8; 15],
+
+    /// Git commit version
+    /// AUDIT: Links to specific code version
     pub version: [u8; 4],
-    
-    /// Year index for this refund (0-9)
-    /// AUDIT: Specific year for refund calculation
-    /// SECURITY: Records refund timing
-    pub year_index: u8,
-    
-    /// Total H2COIN amount actually transferred
-    /// AUDIT: Actual distribution amount for transparency
-    /// SECURITY: Records actual transfer amount
-    pub total_transfer_hcoin: u64,
-    
-    /// The executor of this refund share
-    /// AUDIT: Accountable party for execution
-    /// SECURITY: Records responsible party
-    pub executed_by: Pubkey,
-    
+
+    /// Batch identifier
+    pub batch_id: u16,
+
+    /// Quarter index credited
+    pub quarter: u16,
+
+    /// Profit of the quarter distributed across the investment
+    pub total_profit_usdt: u64,
+
+    /// USDT credited to this batch's investors
+    pub subtotal_accrued_usdt: u64,
+
+    /// Number of ledger entries after the accrual
+    pub entry_count: u16,
+
+    /// Per-record split of the credited amount
+    /// AUDIT: entry_index points into the ledger entries
+    pub breakdown: Vec<RecordShare>,
+
+    /// The submitter of the accrual
+    /// AUDIT: Accountable party
+    pub accrued_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Execution time for audit trail
-    /// SECURITY: Provides temporal context
-    pub executed_at: i64,
-    
+    pub accrued_at: i64,
+
     /// All signers involved in the multisig operation
     /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
     pub signers: Vec<Pubkey>,
 }
 
-//
-// 💰 VAULT DEPOSIT AND WITHDRAWAL EVENTS
-//
-// AUDIT: These events track vault fund movements
-// SECURITY: Include amounts and addresses for transparency
-// TRANSPARENCY: Enable monitoring of fund movements
-
-/// Event emitted when SOL is deposited to vault
-/// 
-/// AUDIT CRITICAL:
-/// - Tracks SOL deposits to vault
-/// - Records depositor for accountability
-/// - Provides audit trail for fund inflows
-/// - Enables monitoring of vault funding
+/// Event emitted when an accrued balance is transferred
 /// 
-/// SECURITY:
-/// - Records fund inflows
-/// - Records depositor identity
-/// - Tracks deposit amounts
-/// - Enables fund flow verification
+/// AUDIT: One event per settled ledger entry
 #[event]
-pub struct VaultDepositSolEvent {
+pub struct AccrualSettled {
     /// Investment ID (fixed-length string)
     /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
     pub investment_id: [u8; 15],
-    
+
     /// Git commit version
     /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
     pub version: [u8; 4],
-    
-    /// Depositor wallet address
-    /// AUDIT: Source of the deposit
-    /// SECURITY: Records fund source
-    pub from: Pubkey,
-    
-    /// SOL amount deposited (in lamports)
-    /// AUDIT: Deposit amount for transparency
-    /// SECURITY: Records deposit value
+
+    /// Batch identifier
+    pub batch_id: u16,
+
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet
+    pub wallet: Pubkey,
+
+    /// USDT transferred
     pub amount_usdt: u64,
-    
+
+    /// Transaction payer (investor or crank)
+    pub settled_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Deposit time for audit trail
-    /// SECURITY: Provides temporal context
-    pub deposit_at: i64,
+    pub settled_at: i64,
 }
 
-/// Event emitted when tokens are deposited to vault
+/// Event emitted when a tenant is created or its admin / defaults change
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks token deposits to vault
-/// - Records depositor and token type
-/// - Provides audit trail for token inflows
-/// - Enables monitoring of token funding
+/// - Carries the full default set so indexers never need a follow-up read
+/// - Existing investments are not affected by the change
+#[event]
+pub struct TenantUpdated {
+    /// Tenant identifier
+    pub tenant_id: [u8; 15],
+
+    /// Tenant admin after the change
+    pub admin: Pubkey,
+
+    /// Default platform fee in basis points
+    pub fee_bp: u16,
+
+    /// Default platform fee recipient
+    pub fee_recipient: Pubkey,
+
+    /// Default execute whitelist (empty = no default)
+    pub execute_whitelist: Vec<Pubkey>,
+
+    /// Default update whitelist (empty = no default)
+    pub update_whitelist: Vec<Pubkey>,
+
+    /// Default withdraw whitelist (empty = no default)
+    pub withdraw_whitelist: Vec<Pubkey>,
+
+    /// The authority or admin that made the change
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Change time for audit trail
+    pub updated_at: i64,
+}
+
+/// Event emitted when the exchange registry is set
 /// 
-/// SECURITY:
-/// - Records token inflows
-/// - Records depositor identity
-/// - Tracks token types and amounts
-/// - Enables token flow verification
+/// AUDIT CRITICAL:
+/// - Carries the full entry list so indexers never need a follow-up read
 #[event]
-pub struct VaultDepositTokenEvent {
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct ExchangeRegistryUpdated {
+    /// Exchange deposit program owners and hot wallet addresses
+    pub owners: Vec<Pubkey>,
+
+    /// The config authority that made the change
+    /// AUDIT: Accountable party for the change
+    pub updated_by: Pubkey,
+
+    /// UNIX timestamp
+    /// AUDIT: Change time for audit trail
+    pub updated_at: i64,
+}
+
+/// Event emitted when a record wallet matching the exchange registry is accepted
+/// 
+/// AUDIT CRITICAL:
+/// - Only emitted when the operators passed allow_exchange_wallet
+/// - Flags records whose payouts may be lost or credited to an exchange account
+#[event]
+pub struct ExchangeWalletFlagged {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Depositor wallet address
-    /// AUDIT: Source of the deposit
-    /// SECURITY: Records fund source
-    pub from: Pubkey,
-    
-    /// Token mint address
-    /// AUDIT: Type of token deposited
-    /// SECURITY: Records token type
-    pub mint: Pubkey,
-    
-    /// Token amount deposited
-    /// AUDIT: Deposit amount for transparency
-    /// SECURITY: Records deposit value
-    pub amount: u64,
-    
+
+    /// Investor account ID
+    pub account_id: [u8; 15],
+
+    /// Flagged wallet
+    pub wallet: Pubkey,
+
+    /// Program owning the wallet account
+    pub wallet_owner: Pubkey,
+
+    /// Payer of the record change
+    /// AUDIT: Accountable party for the override
+    pub flagged_by: Pubkey,
+
     /// UNIX timestamp
-    /// AUDIT: Deposit time for audit trail
-    /// SECURITY: Provides temporal context
-    pub deposit_at: i64,
+    pub flagged_at: i64,
+
+    /// Multisig signers who approved the override
+    pub signers: Vec<Pubkey>,
 }
 
-/// Event emitted when funds are withdrawn from vault
+/// Event emitted when a multisig proposal is created
 /// 
 /// AUDIT CRITICAL:
-/// - Tracks vault withdrawals
-/// - Includes all signers for multisig accountability
-/// - Records all token types and amounts
-/// - Provides audit trail for fund outflows
-/// - Enables monitoring of vault withdrawals
+/// - Carries the exact action the approvers are asked to sign off on
+#[event]
+pub struct ProposalCreated {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Proposal identifier
+    pub proposal_id: u64,
+
+    /// Proposed action with its arguments
+    pub action: ProposalAction,
+
+    /// Proposing whitelist member (first approval)
+    pub proposer: Pubkey,
+
+    /// UNIX timestamp
+    pub created_at: i64,
+
+    /// End of the approval window
+    pub expires_at: i64,
+}
+
+/// Event emitted when a whitelist member approves a proposal
+#[event]
+pub struct ProposalApproved {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Proposal identifier
+    pub proposal_id: u64,
+
+    /// Approving whitelist member
+    pub approver: Pubkey,
+
+    /// All approvals so far
+    pub approvals: Vec<Pubkey>,
+
+    /// UNIX timestamp
+    pub approved_at: i64,
+}
+
+/// Event emitted when a proposal authorizes its target instruction
 /// 
-/// SECURITY:
-/// - Records fund outflows
-/// - Records all multisig signers
-/// - Tracks all token types and amounts
-/// - Enables withdrawal verification
+/// AUDIT CRITICAL:
+/// - Emitted alongside the target instruction's own event, whose signers are the approvals
 #[event]
-pub struct VaultTransferred {
-    /// Investment ID (fixed-length string)
-    /// AUDIT: Unique identifier for the investment
-    /// SECURITY: Enables tracking of specific investments
+pub struct ProposalExecuted {
+    /// Investment ID
     pub investment_id: [u8; 15],
-    
-    /// Git commit version
-    /// AUDIT: Links to specific code version
-    /// SECURITY: Enables code audit trail
+
+    /// Investment version
     pub version: [u8; 4],
-    
-    /// Recipient wallet address
-    /// AUDIT: Destination of the withdrawal
-    /// SECURITY: Records fund destination
-    pub recipient: Pubkey,
-    
-    /// USDT amount withdrawn
-    /// AUDIT: USDT withdrawal amount for transparency
-    /// SECURITY: Records USDT outflow
-    pub usdt_amount: u64,
-    
-    /// H2COIN amount withdrawn
-    /// AUDIT: H2COIN withdrawal amount for transparency
-    /// SECURITY: Records H2COIN outflow
-    pub hcoin_amount: u64,
-    
-    /// SOL amount withdrawn
-    /// AUDIT: SOL withdrawal amount for transparency
-    /// SECURITY: Records SOL outflow
-    pub sol_amount: u64,
-    
-    /// The executor of this withdrawal
-    /// AUDIT: Accountable party for withdrawal
-    /// SECURITY: Records responsible party
+
+    /// Proposal identifier
+    pub proposal_id: u64,
+
+    /// Payer of the executing transaction
     pub executed_by: Pubkey,
-    
+
     /// UNIX timestamp
-    /// AUDIT: Withdrawal time for audit trail
-    /// SECURITY: Provides temporal context
     pub executed_at: i64,
-    
-    /// All signers involved in the multisig operation
-    /// AUDIT: Complete signer list for accountability
-    /// SECURITY: Records all authorized parties
-    pub signers: Vec<Pubkey>,
-}
\ No newline at end of file
+
+    /// Approvals that authorized the execution
+    pub approvals: Vec<Pubkey>,
+}
+
+/// Event emitted by emit_checkpoint
+/// 
+/// AUDIT CRITICAL:
+/// - sequence increases by exactly one per investment, so indexers detect gaps
+/// - Hashes are SHA-256 of the raw account data at emission time; an indexer that
+///   replays its ingested events to the same state reproduces them
+#[event]
+pub struct Checkpoint {
+    /// Investment ID
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Checkpoint sequence number (starts at 1)
+    pub sequence: u64,
+
+    /// Timestamp of the previous checkpoint (0 for the first)
+    pub previous_checkpoint_at: i64,
+
+    /// InvestmentInfo.withdraw_sequence (withdrawals executed so far)
+    pub withdraw_sequence: u64,
+
+    /// BatchCounter.batch_id (0 when no batch was assigned yet)
+    pub batch_id: u16,
+
+    /// BatchCounter.records_in_batch
+    pub records_in_batch: u16,
+
+    /// Hash of the InvestmentInfo account data
+    pub investment_info_hash: [u8; 32],
+
+    /// Hash of the InvestmentConfig account data
+    pub investment_config_hash: [u8; 32],
+
+    /// Hash of the BatchCounter account data (zero when it does not exist)
+    pub batch_counter_hash: [u8; 32],
+
+    /// Caller that paid for the checkpoint
+    pub emitted_by: Pubkey,
+
+    /// UNIX timestamp
+    pub emitted_at: i64,
+}
@@ -0,0 +1,233 @@
+// programs/h2coin_vault_share/src/fixture.rs
+//
+// H2COIN VAULT SHARE PROGRAM - REPLAY FIXTURES
+// ============================================
+//
+// AUDIT NOTES:
+// Off-chain tooling, compiled only with the `replay-fixtures` feature. It captures the
+// raw account set of one investment (InvestmentInfo, records, caches, vault token
+// accounts) into a single Borsh-encoded fixture, and restores that set into a local
+// program-test bank so a production incident can be replayed byte for byte.
+// - ReplayFixture::push / capture_infos: collect accounts (RPC dumps or AccountInfos)
+// - ReplayFixture::to_bytes / from_bytes: deterministic, versioned encoding
+// - ReplayFixture::restore: hand every account to a loader such as ProgramTest::add_account
+//
+// SECURITY CONSIDERATIONS:
+// - Never built into the deployed program; the feature must stay off for `anchor build`
+// - Accounts are stored verbatim (data, owner, lamports), so a fixture of a mainnet
+//   investment contains investor wallets and amounts; treat fixture files as confidential
+// - Decoding rejects unknown magic, unknown format versions, duplicate addresses and
+//   trailing bytes, so a truncated or hand-edited file fails loudly instead of replaying
+//   a different state
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::ID as TOKEN_PROGRAM_ID;
+
+use crate::error::ErrorCode;
+use crate::state::*;
+
+/// Magic prefix of every fixture file
+///
+/// AUDIT: Distinguishes fixtures from other Borsh blobs before decoding
+pub const FIXTURE_MAGIC: [u8; 8] = *b"H2CFIX\0\0";
+
+/// Current fixture encoding version
+///
+/// AUDIT: Bump on any change to FixtureAccount or ReplayFixture; old files are rejected
+pub const FIXTURE_FORMAT_VERSION: u16 = 1;
+
+/// Role of a captured account, derived from its owner and discriminator
+///
+/// AUDIT: Informational only; restore() loads every account regardless of role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixtureRole {
+    InvestmentInfo,
+    InvestmentRecord,
+    ProfitShareCache,
+    RefundShareCache,
+    TokenAccount,
+    Other,
+}
+
+impl FixtureRole {
+    /// Classify an account by owner and the 8-byte Anchor discriminator
+    pub fn classify(owner: &Pubkey, data: &[u8]) -> Self {
+        if *owner == TOKEN_PROGRAM_ID {
+            return FixtureRole::TokenAccount;
+        }
+        if *owner != crate::ID {
+            return FixtureRole::Other;
+        }
+
+        let known: [(&[u8], FixtureRole); 4] = [
+            (InvestmentInfo::DISCRIMINATOR, FixtureRole::InvestmentInfo),
+            (InvestmentRecord::DISCRIMINATOR, FixtureRole::InvestmentRecord),
+            (ProfitShareCache::DISCRIMINATOR, FixtureRole::ProfitShareCache),
+            (RefundShareCache::DISCRIMINATOR, FixtureRole::RefundShareCache),
+        ];
+        known
+            .iter()
+            .find(|(disc, _)| data.starts_with(disc))
+            .map(|(_, role)| *role)
+            .unwrap_or(FixtureRole::Other)
+    }
+}
+
+/// One captured account, stored verbatim
+///
+/// AUDIT: Field set mirrors solana Account so restore() is lossless
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FixtureAccount {
+    pub role: FixtureRole,
+    pub address: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data: Vec<u8>,
+}
+
+/// Captured account set of one investment at one slot
+///
+/// AUDIT CRITICAL:
+/// - Accounts are kept sorted by address, so the same chain state always encodes
+///   to the same bytes regardless of capture order
+/// - slot and captured_at record where the snapshot was taken; they are not used
+///   by restore() and the replay clock is set by the test itself
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReplayFixture {
+    pub magic: [u8; 8],
+    pub format_version: u16,
+    pub investment_id: [u8; 15],
+    pub version: [u8; 4],
+    pub slot: u64,
+    pub captured_at: i64,
+    pub accounts: Vec<FixtureAccount>,
+}
+
+impl ReplayFixture {
+    /// Start an empty fixture for one investment
+    pub fn new(investment_id: [u8; 15], version: [u8; 4], slot: u64, captured_at: i64) -> Self {
+        Self {
+            magic: FIXTURE_MAGIC,
+            format_version: FIXTURE_FORMAT_VERSION,
+            investment_id,
+            version,
+            slot,
+            captured_at,
+            accounts: Vec::new(),
+        }
+    }
+
+    /// Add one raw account, e.g. from getMultipleAccounts
+    ///
+    /// AUDIT: Rejects a second copy of the same address; keeps address order
+    pub fn push(
+        &mut self,
+        address: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        executable: bool,
+        rent_epoch: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let position = match self.accounts.binary_search_by(|a| a.address.cmp(&address)) {
+            Ok(_) => return err!(ErrorCode::InvalidReplayFixture),
+            Err(position) => position,
+        };
+        self.accounts.insert(
+            position,
+            FixtureAccount {
+                role: FixtureRole::classify(&owner, &data),
+                address,
+                owner,
+                lamports,
+                executable,
+                rent_epoch,
+                data,
+            },
+        );
+        Ok(())
+    }
+
+    /// Add every account of an instruction or program-test bank
+    ///
+    /// AUDIT: Used to capture the state after a local replay for byte comparison
+    pub fn capture_infos(&mut self, infos: &[AccountInfo]) -> Result<()> {
+        for info in infos {
+            self.push(
+                info.key(),
+                *info.owner,
+                info.lamports(),
+                info.executable,
+                info.rent_epoch,
+                info.try_borrow_data()?.to_vec(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Encode the fixture
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.try_to_vec()
+            .map_err(|_| error!(ErrorCode::InvalidReplayFixture))
+    }
+
+    /// Decode and validate a fixture
+    ///
+    /// AUDIT CRITICAL:
+    /// - Magic and format version must match this build
+    /// - Addresses must be strictly ascending (sorted, no duplicates)
+    /// - The whole buffer must be consumed
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = bytes;
+        let fixture = Self::deserialize(&mut cursor)
+            .map_err(|_| error!(ErrorCode::InvalidReplayFixture))?;
+
+        require!(cursor.is_empty(), ErrorCode::InvalidReplayFixture);
+        require!(fixture.magic == FIXTURE_MAGIC, ErrorCode::InvalidReplayFixture);
+        require!(
+            fixture.format_version == FIXTURE_FORMAT_VERSION,
+            ErrorCode::InvalidReplayFixture
+        );
+        require!(
+            fixture.accounts.windows(2).all(|w| w[0].address < w[1].address),
+            ErrorCode::InvalidReplayFixture
+        );
+
+        Ok(fixture)
+    }
+
+    /// Hand every account to a loader, in address order
+    ///
+    /// AUDIT: With solana-program-test the loader is
+    /// `|address, a| program_test.add_account(address, Account { lamports: a.lamports,
+    /// data: a.data.clone(), owner: a.owner, executable: a.executable, rent_epoch: a.rent_epoch })`
+    pub fn restore<F: FnMut(Pubkey, &FixtureAccount)>(&self, mut load: F) {
+        for account in &self.accounts {
+            load(account.address, account);
+        }
+    }
+
+    /// Accounts captured with the given role
+    pub fn accounts_with_role(&self, role: FixtureRole) -> impl Iterator<Item = &FixtureAccount> {
+        self.accounts.iter().filter(move |a| a.role == role)
+    }
+
+    /// Decode the captured InvestmentInfo
+    ///
+    /// AUDIT: Requires exactly one InvestmentInfo matching the fixture's id and version
+    pub fn investment_info(&self) -> Result<InvestmentInfo> {
+        let mut infos = self.accounts_with_role(FixtureRole::InvestmentInfo);
+        let account = infos.next().ok_or(error!(ErrorCode::InvalidReplayFixture))?;
+        require!(infos.next().is_none(), ErrorCode::InvalidReplayFixture);
+
+        let info = InvestmentInfo::try_deserialize(&mut account.data.as_slice())?;
+        require!(
+            info.investment_id == self.investment_id && info.version == self.version,
+            ErrorCode::InvalidReplayFixture
+        );
+        Ok(info)
+    }
+}
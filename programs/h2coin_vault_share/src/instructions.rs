@@ -84,16 +84,32 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{
     pubkey::Pubkey,
     account_info::{AccountInfo},
+    compute_units::sol_remaining_compute_units,
+    hash::hash,
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    program::{invoke, invoke_signed},
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+// AUDIT: Loader instruction builders; re-exported from solana-loader-v3-interface
+#[allow(deprecated)]
+use anchor_lang::solana_program::bpf_loader_upgradeable::{
+    set_upgrade_authority as loader_set_upgrade_authority,
+    upgrade as loader_upgrade,
 };
 
 use anchor_lang::system_program::{self, Transfer};
 
 use anchor_spl::{
-    token::{self, TransferChecked, ID as TOKEN_PROGRAM_ID},
-    associated_token::{get_associated_token_address},
+    memo::{self, BuildMemo},
+    token::{
+        self, spl_token::instruction::AuthorityType, CloseAccount, Revoke, SetAuthority, TransferChecked,
+        ID as TOKEN_PROGRAM_ID,
+    },
+    associated_token::{self, get_associated_token_address},
 };
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use crate::context::*;
 use crate::event::*;
@@ -116,7 +132,7 @@ use crate::error::ErrorCode;
 /// - Whitelist size validation (must be exactly 5 members for each whitelist)
 /// - Stage ratio validation (0-100%, contiguous non-zero values)
 /// - PDA derivation verification for both investment info and vault
-/// - Token mint validation (USDT and H2COIN)
+/// - Token mint validation (allowlisted profit mint and H2COIN)
 /// - Vault ATA ownership validation
 /// - Investment period validation (start_at < end_at)
 /// 
@@ -137,6 +153,13 @@ use crate::error::ErrorCode;
 /// - execute_whitelist: 5-member whitelist for profit/refund execution
 /// - update_whitelist: 5-member whitelist for investment updates
 /// - withdraw_whitelist: 5-member whitelist for vault withdrawals
+/// - defer_open: Start in Init so the configuration can be reviewed before
+///   open_investment moves it to Pending; otherwise start in Pending
+/// 
+/// TENANT INHERITANCE:
+/// When the optional tenant account is passed, the payer must be its admin. Every
+/// whitelist passed empty is taken from the tenant defaults, and the tenant's fee
+/// settings are copied into the investment config.
 #[allow(clippy::too_many_arguments)]
 pub fn initialize_investment_info(
     ctx: Context<InitializeInvestmentInfo>,
@@ -150,9 +173,34 @@ pub fn initialize_investment_info(
     execute_whitelist: Vec<Pubkey>,
     update_whitelist: Vec<Pubkey>,
     withdraw_whitelist: Vec<Pubkey>,
+    defer_open: bool,
 ) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
+
+    // AUDIT: Whitelists passed empty are inherited from the tenant, whose admin must sign
+    let tenant_admin = ctx.accounts.tenant_admin.as_ref().map(|admin| admin.key());
+    let (execute_whitelist, update_whitelist, withdraw_whitelist, tenant_key, fee_bp, fee_recipient) =
+        match ctx.accounts.tenant.as_mut() {
+            Some(tenant) => {
+                require!(tenant_admin == Some(tenant.admin), ErrorCode::UnauthorizedTenantAdmin);
+                tenant.investment_count = tenant
+                    .investment_count
+                    .checked_add(1)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                (
+                    Tenant::inherit_whitelist(execute_whitelist, &tenant.execute_whitelist),
+                    Tenant::inherit_whitelist(update_whitelist, &tenant.update_whitelist),
+                    Tenant::inherit_whitelist(withdraw_whitelist, &tenant.withdraw_whitelist),
+                    tenant.key(),
+                    tenant.fee_bp,
+                    tenant.fee_recipient,
+                )
+            }
+            None => (execute_whitelist, update_whitelist, withdraw_whitelist, Pubkey::default(), 0, Pubkey::default()),
+        };
+
     let info = &mut ctx.accounts.investment_info;
+    let config = &mut ctx.accounts.investment_config;
     let vault = &ctx.accounts.vault;
     let vault_usdt_account = &ctx.accounts.vault_usdt_account;
     let vault_hcoin_account = &ctx.accounts.vault_hcoin_account;
@@ -186,30 +234,64 @@ pub fn initialize_investment_info(
     );
     require_keys_eq!(vault_pda.key(), vault.key(), ErrorCode::InvalidInvestmentInfoPda);
 
+    // AUDIT: The profit mint must be an allowlisted stablecoin; it is fixed for the investment's lifetime
+    require!(
+        ctx.accounts.global_config.allowed_profit_mints.contains(&ctx.accounts.usdt_mint.key()),
+        ErrorCode::ProfitMintNotAllowed
+    );
+    require_keys_eq!(
+        ctx.accounts.hcoin_mint.key(),
+        ctx.accounts.global_config.hcoin_mint,
+        ErrorCode::InvalidTokenMint
+    );
+
     // AUDIT: Validate vault token account ownership and mints for secure token management
     require_keys_eq!(vault_usdt_account.mint, ctx.accounts.usdt_mint.key(), ErrorCode::InvalidTokenMint);
     require_keys_eq!(vault_usdt_account.owner, vault.key(), ErrorCode::InvalidVaultOwner);
     require_keys_eq!(vault_hcoin_account.mint, ctx.accounts.hcoin_mint.key(), ErrorCode::InvalidTokenMint);
     require_keys_eq!(vault_hcoin_account.owner, vault.key(), ErrorCode::InvalidVaultOwner);
 
-    // AUDIT: Initialize investment info with provided parameters
+    // AUDIT: Initialize investment info (hot) with provided parameters
     info.investment_id = investment_id;
     info.investment_type = investment_type;
-    info.stage_ratio = stage_ratio;
     info.version = version;
     info.start_at = start_at;
     info.end_at = end_at;
     info.investment_upper_limit = investment_upper_limit;
-    info.execute_whitelist = execute_whitelist;
-    info.update_whitelist = update_whitelist;
-    info.withdraw_whitelist = withdraw_whitelist;
     info.vault = vault_pda;
-    info.state = InvestmentState::Pending;
+    info.vault_version = version;
+    info.profit_mint = ctx.accounts.usdt_mint.key();
+    // AUDIT: Payout amounts are normalized to these decimals at estimation
+    info.profit_decimals = ctx.accounts.usdt_mint.decimals;
+    info.hcoin_decimals = ctx.accounts.hcoin_mint.decimals;
+    // AUDIT: A deferred investment accepts no records until open_investment
+    info.state = if defer_open { InvestmentState::Init } else { InvestmentState::Pending };
     info.is_active = true;
     info.created_at = now;
+    info.bump = ctx.bumps.investment_info;
+    info.vault_bump = ctx.bumps.vault;
+    info.config_bump = ctx.bumps.investment_config;
+
+    // AUDIT: Initialize investment config (cold) with whitelists and stage ratios
+    config.investment_id = investment_id;
+    config.version = version;
+    config.stage_ratio = stage_ratio;
+    config.execute_whitelist = execute_whitelist;
+    config.update_whitelist = update_whitelist;
+    config.withdraw_whitelist = withdraw_whitelist;
+    config.hcoin_rate_range = HcoinRateRange::default();
+    config.execute_weights = SignerWeights::default();
+    config.update_weights = SignerWeights::default();
+    config.min_estimate_interval_secs = DEFAULT_MIN_ESTIMATE_INTERVAL_SECS;
+    config.scheduled_stage_ratio = [[0u8; 10]; MAX_STAGE];
+    config.stage_ratio_effective_year = 0;
+    config.tenant = tenant_key;
+    config.fee_bp = fee_bp;
+    config.fee_recipient = fee_recipient;
 
     // AUDIT: Validate stage ratio configuration for mathematical correctness
-    info.validate_stage_ratio()?;
+    config.validate_stage_ratio()?;
+    info.refresh_config_hash(config)?;
 
     // AUDIT: Emit initialization event for audit trail
     emit!(InvestmentInfoInitialized {
@@ -218,6 +300,8 @@ pub fn initialize_investment_info(
         vault: info.vault,
         created_by: ctx.accounts.payer.key(),
         created_at: info.created_at,
+        opened: !defer_open,
+        tenant: tenant_key,
     });
 
     Ok(())
@@ -232,7 +316,7 @@ pub fn initialize_investment_info(
 /// SECURITY:
 /// - Only processes actual signers (is_signer = true)
 /// - Returns vector of corresponding Pubkeys for validation
-/// - Used in enforce_3_of_5_signers validation
+/// - Used in enforce_signers validation
 /// 
 /// AUDIT POINTS:
 /// [ ] Verify signer filtering logic is correct
@@ -241,6 +325,104 @@ fn extract_signer_keys(infos: &[AccountInfo]) -> Vec<Pubkey> {
     infos.iter().filter(|i| i.is_signer).map(|i| i.key()).collect()
 }
 
+/// Split remaining_accounts into the leading signer accounts and the data accounts after them
+/// 
+/// AUDIT CRITICAL - SIGNER LAYOUT:
+/// Signers are the leading run of accounts whose is_signer flag is set; the runtime sets
+/// that flag only for keys that signed the transaction. Any number of signers in any order
+/// is accepted, so clients no longer pad to exactly three. The run ends at the first
+/// non-signer account, so data accounts (record PDAs, ATAs) can never be read as signers.
+/// 
+/// SECURITY:
+/// - Authorization still goes through enforce_signers on the returned signers
+/// - A data account that also signed the transaction must not directly follow the
+///   signers, or it is taken as one
+fn split_signer_accounts<'c, 'info>(
+    infos: &'c [AccountInfo<'info>],
+) -> (&'c [AccountInfo<'info>], &'c [AccountInfo<'info>]) {
+    let signer_count = infos.iter().take_while(|info| info.is_signer).count();
+    infos.split_at(signer_count)
+}
+
+/// Collect notification registrations supplied among remaining_accounts
+/// 
+/// AUDIT: Returns wallet -> registration PDA. Other accounts (records, ATAs) are ignored,
+/// and a registration is only used when its address matches the PDA of its wallet.
+fn collect_notification_registrations<'info>(
+    program_id: &Pubkey,
+    infos: &'info [AccountInfo<'info>],
+) -> BTreeMap<Pubkey, Pubkey> {
+    let mut registrations = BTreeMap::new();
+    for acc_info in infos.iter() {
+        if let Ok(registration) = Account::<NotificationRegistration>::try_from(acc_info) {
+            let expected = Pubkey::create_program_address(
+                &[b"notification", registration.wallet.as_ref(), &[registration.bump]],
+                program_id,
+            );
+            if expected.ok() == Some(acc_info.key()) {
+                registrations.insert(registration.wallet, acc_info.key());
+            }
+        }
+    }
+    registrations
+}
+
+/// Collect account-level payout splits supplied among remaining_accounts
+/// 
+/// AUDIT: Returns account_id -> split. Other accounts are ignored, and a split is only
+/// used when its address matches the PDA of this investment and its account_id.
+fn collect_payout_splits<'info>(
+    program_id: &Pubkey,
+    investment_id: &[u8; 15],
+    version: &[u8; 4],
+    infos: &'info [AccountInfo<'info>],
+) -> BTreeMap<[u8; 15], Account<'info, PayoutSplit>> {
+    let mut splits = BTreeMap::new();
+    for acc_info in infos.iter() {
+        if let Ok(split) = Account::<PayoutSplit>::try_from(acc_info) {
+            let expected = Pubkey::create_program_address(
+                &[
+                    b"split",
+                    investment_id.as_ref(),
+                    version.as_ref(),
+                    split.account_id.as_ref(),
+                    &[split.bump],
+                ],
+                program_id,
+            );
+            if expected.ok() == Some(acc_info.key()) {
+                splits.insert(split.account_id, split);
+            }
+        }
+    }
+    splits
+}
+
+/// Resolve the mint a profit round is paid in, with its decimals
+/// 
+/// AUDIT CRITICAL:
+/// - None selects InvestmentInfo.profit_mint
+/// - Otherwise the mint must be the profit mint, GlobalConfig.hcoin_mint or listed in
+///   the investment's VaultTokenRegistry, i.e. a mint the vault holds on purpose
+fn resolve_payout_mint(
+    info: &InvestmentInfo,
+    global: &GlobalConfig,
+    registry: Option<&VaultTokenRegistry>,
+    payout_mint: Option<Pubkey>,
+) -> Result<(Pubkey, u8)> {
+    let mint = payout_mint.unwrap_or(info.profit_mint);
+    if mint == info.profit_mint {
+        return Ok((mint, info.profit_decimals));
+    }
+    if mint == global.hcoin_mint {
+        return Ok((mint, info.hcoin_decimals));
+    }
+    registry
+        .and_then(|r| r.entries.iter().find(|e| e.mint == mint))
+        .map(|e| (mint, e.decimals))
+        .ok_or(error!(ErrorCode::VaultMintNotRegistered))
+}
+
 /// Update investment info parameters
 /// 
 /// AUDIT CRITICAL - INVESTMENT UPDATE:
@@ -263,13 +445,23 @@ fn extract_signer_keys(infos: &[AccountInfo]) -> Vec<Pubkey> {
 /// PARAMETERS:
 /// - new_stage_ratio: Optional new refund percentage configuration
 /// - new_upper_limit: Optional new investment limit
+/// - new_hcoin_rate_range: Optional new H2COIN-per-USDT rate range (zero range disables the check)
+/// - new_min_estimate_interval_secs: Optional new minimum interval between estimates of a cache
+/// - new_stage_ratio_effective_year: Optional future year_index from which new_stage_ratio applies
+///   (None replaces the current stage ratio immediately)
+/// 
+/// Instead of co-signers, an approved `Proposal` carrying the same arguments may be passed.
 pub fn update_investment_info(
     ctx: Context<UpdateInvestmentInfo>,
     new_stage_ratio: Option<[[u8; 10]; 3]>,
     new_upper_limit: Option<u64>,
+    new_hcoin_rate_range: Option<HcoinRateRange>,
+    new_min_estimate_interval_secs: Option<u32>,
+    new_stage_ratio_effective_year: Option<u8>,
 ) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let info = &mut ctx.accounts.investment_info;
+    let config = &mut ctx.accounts.investment_config;
 
     // AUDIT: Reject if investment has been deactivated
     require!(
@@ -277,12 +469,24 @@ pub fn update_investment_info(
         ErrorCode::InvestmentInfoDeactivated
     );
 
-    // AUDIT: Extract signer information for multisig validation
-    let signer_infos = &ctx.remaining_accounts;
-    let signer_keys = extract_signer_keys(signer_infos);
-    
-    // AUDIT: Validate 3-of-5 multisig from update_whitelist
-    info.enforce_3_of_5_signers(signer_infos, true)?;
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist, co-signed or through an approved proposal
+    let signer_keys = match ctx.accounts.proposal.as_mut() {
+        Some(proposal) => {
+            let action = ProposalAction::UpdateInvestmentInfo {
+                new_stage_ratio,
+                new_upper_limit,
+                new_hcoin_rate_range,
+                new_min_estimate_interval_secs,
+                new_stage_ratio_effective_year,
+            };
+            execute_proposal(proposal, info, config, &action, ctx.accounts.payer.key(), now)?
+        }
+        None => {
+            let signer_infos = &ctx.remaining_accounts;
+            config.enforce_signers(signer_infos, true)?;
+            extract_signer_keys(signer_infos)
+        }
+    };
 
     // AUDIT: Reject if this InvestmentInfo account has not been initialized
     require!(
@@ -290,15 +494,42 @@ pub fn update_investment_info(
         ErrorCode::InvestmentInfoNotFound
     );
 
-    // AUDIT: Update investment upper limit if provided
+    // AUDIT: Update investment upper limit if provided; it cannot drop below the recorded total
     if let Some(limit) = new_upper_limit {
+        require!(
+            limit >= info.total_invested_usdt()?,
+            ErrorCode::InvestmentUpperLimitExceeded
+        );
         info.investment_upper_limit = limit;
     }
 
-    // AUDIT: Update stage ratio if provided
-    if let Some(stage_ratio) = new_stage_ratio {
-        info.stage_ratio = stage_ratio;
+    // AUDIT: Update stage ratio if provided, either now or from a future refund year
+    match (new_stage_ratio, new_stage_ratio_effective_year) {
+        (Some(stage_ratio), None) => config.stage_ratio = stage_ratio,
+        (Some(stage_ratio), Some(effective_year)) => {
+            // AUDIT: Same year arithmetic as estimate_refund_share
+            let current_year = YearWindow::index_at(info.end_at, now);
+            config.schedule_stage_ratio(stage_ratio, effective_year, current_year)?;
+        }
+        (None, Some(_)) => return err!(ErrorCode::InvalidStageRatioEffectiveYear),
+        (None, None) => {}
+    }
+
+    // AUDIT: Update conversion rate range if provided
+    if let Some(rate_range) = new_hcoin_rate_range {
+        rate_range.validate()?;
+        config.hcoin_rate_range = rate_range;
+    }
+
+    // AUDIT: Update estimate interval if provided; it must stay below the cache lifetime
+    if let Some(interval) = new_min_estimate_interval_secs {
+        require!(
+            (interval as i64) < ctx.accounts.global_config.share_cache_expire_secs,
+            ErrorCode::InvalidEstimateInterval
+        );
+        config.min_estimate_interval_secs = interval;
     }
+    info.refresh_config_hash(config)?;
 
     // AUDIT: Log update information for audit trail
     msg!("🟢 Update triggered by: {}", ctx.accounts.payer.key());
@@ -309,6 +540,9 @@ pub fn update_investment_info(
         version: info.version,
         new_stage_ratio,
         new_upper_limit,
+        new_hcoin_rate_range,
+        new_min_estimate_interval_secs,
+        new_stage_ratio_effective_year,
         updated_by: ctx.accounts.payer.key(),
         updated_at: now,
         signers: signer_keys,
@@ -317,6 +551,60 @@ pub fn update_investment_info(
     Ok(())
 }
 
+/// Simulate the per-year refunds of a sample record under a proposed stage ratio
+/// 
+/// AUDIT CRITICAL - RATIO SIMULATION:
+/// Lets the update committee evaluate a ratio change before signing
+/// `update_investment_info`. The result is returned through return data as one
+/// amount per year index (0..=MAX_YEAR_INDEX); nothing is written.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Proposed ratio passes the same validation as initialization
+/// - Sample record PDA verification
+/// 
+/// PARAMETERS:
+/// - new_ratio: Proposed refund percentage configuration
+pub fn simulate_stage_ratio(
+    ctx: Context<SimulateStageRatio>,
+    new_ratio: [[u8; 10]; 3],
+) -> Result<Vec<u64>> {
+    let info = &ctx.accounts.investment_info;
+    let record = &ctx.accounts.sample_record;
+
+    // AUDIT: Validate sample record PDA with info.investment_id
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            record.batch_id.to_le_bytes().as_ref(),
+            record.record_id.to_le_bytes().as_ref(),
+            record.account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+    // AUDIT: Reject ratios update_investment_info should never store
+    InvestmentConfig::check_stage_ratio(&new_ratio)?;
+
+    let refunds = (0..=MAX_YEAR_INDEX)
+        .map(|year_index| {
+            RefundShareCache::compute_refund(&new_ratio, record.stage, year_index, record.amount_hcoin)
+                .and_then(|refund| info.hcoin_units(refund))
+        })
+        .collect::<Result<Vec<u64>>>()?;
+
+    msg!(
+        "🟢 Simulated refunds for record_id={} (stage {}): {:?}",
+        record.record_id,
+        record.stage,
+        refunds
+    );
+
+    Ok(refunds)
+}
+
 /// Mark investment as completed
 /// 
 /// AUDIT CRITICAL - INVESTMENT COMPLETION:
@@ -337,6 +625,7 @@ pub fn update_investment_info(
 /// [ ] Review event emission for audit trail
 pub fn completed_investment_info(ctx: Context<CompletedInvestmentInfo>) -> Result<()> {
     let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
     // AUDIT: Reject if InvestmentInfo has been deactivated
     require!(
@@ -344,11 +633,21 @@ pub fn completed_investment_info(ctx: Context<CompletedInvestmentInfo>) -> Resul
         ErrorCode::InvestmentInfoDeactivated
     );
     
-    // AUDIT: Reject if InvestmentInfo is already completed
+    // AUDIT: Reject if InvestmentInfo is already completed or has been cancelled
     require!(
         info.state != InvestmentState::Completed, 
         ErrorCode::InvestmentInfoHasCompleted
     );
+    require!(
+        info.state != InvestmentState::Cancelled,
+        ErrorCode::InvestmentInfoCancelled
+    );
+
+    // AUDIT: Reject if the investment was never opened
+    require!(
+        info.state != InvestmentState::Init,
+        ErrorCode::InvestmentInfoNotPending
+    );
     
     // AUDIT: Reject if this InvestmentInfo has not been initialized
     require!(
@@ -372,7 +671,7 @@ pub fn completed_investment_info(ctx: Context<CompletedInvestmentInfo>) -> Resul
     let signer_keys = extract_signer_keys(signer_infos);
     
     // AUDIT: Validate 3-of-5 multisig from update_whitelist
-    info.enforce_3_of_5_signers(signer_infos, true)?;
+    config.enforce_signers(signer_infos, true)?;
 
     // AUDIT: Set InvestmentInfo state to completed
     info.state = InvestmentState::Completed;
@@ -392,1839 +691,10177 @@ pub fn completed_investment_info(ctx: Context<CompletedInvestmentInfo>) -> Resul
     Ok(())
 }
 
-/// Deactivate investment info
+/// Cancel a pending investment whose raise failed
 /// 
-/// AUDIT CRITICAL - INVESTMENT DEACTIVATION:
-/// This function permanently deactivates an investment, preventing all further operations.
-/// It requires 3-of-5 multisig authorization and can only be called on completed investments.
+/// AUDIT CRITICAL - INVESTMENT CANCELLATION:
+/// Moves the investment from Pending to Cancelled. Distributions stay blocked
+/// (they require Completed) and return_deposits becomes available so investors
+/// get their recorded USDT back.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
+/// - Investment must be active and Pending
 /// - 3-of-5 multisig validation from update_whitelist
-/// - Investment state validation (must be completed)
-/// - Investment deactivation check
-/// - PDA verification to prevent address spoofing
-/// - Investment initialization check
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify deactivation is irreversible
-/// [ ] Confirm state validation prevents premature deactivation
-/// [ ] Check multisig validation uses correct whitelist
-/// [ ] Review event emission for audit trail
-pub fn deactivate_investment_info(ctx: Context<DeactivateInvestmentInfo>) -> Result<()> {
+pub fn cancel_investment_info(ctx: Context<CancelInvestmentInfo>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
     let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: Reject if investment has been deactivated
-    require!(
-        info.is_active, 
-        ErrorCode::InvestmentInfoDeactivated
-    );
-    
-    // AUDIT: Reject if investment is not completed yet
-    require!(
-        info.state == InvestmentState::Completed, 
-        ErrorCode::InvestmentInfoNotCompleted
-    );
-    
-    // AUDIT: Reject if this InvestmentInfo has not been initialized
-    require!(
-        !info.to_account_info().data_is_empty(),
-        ErrorCode::InvestmentInfoNotFound
-    );
-
-    // AUDIT: Validate investment info PDA derivation to prevent address spoofing
-    let (expected_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"investment",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require_keys_eq!(info.key(), expected_pda, ErrorCode::InvalidInvestmentInfoPda);
+    // AUDIT: Only an active, pending investment can be cancelled
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Pending, ErrorCode::InvestmentInfoNotPending);
 
-    // AUDIT: Extract signer information for multisig validation
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
     let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    
-    // AUDIT: Validate 3-of-5 multisig from update_whitelist
-    info.enforce_3_of_5_signers(signer_infos, true)?;
+    config.enforce_signers(signer_infos, true)?;
 
-    // AUDIT: Deactivate the investment
-    info.is_active = false;
+    info.state = InvestmentState::Cancelled;
 
-    // AUDIT: Log deactivation for audit trail
-    msg!("🟢 Investment {} deactivated", String::from_utf8_lossy(&info.investment_id));
+    msg!("🟡 Investment {} cancelled", String::from_utf8_lossy(&info.investment_id));
 
-    // AUDIT: Emit deactivation event for audit trail
-    emit!(InvestmentInfoDeactivated {
+    emit!(InvestmentInfoCancelled {
         investment_id: info.investment_id,
         version: info.version,
-        deactivated_by: ctx.accounts.payer.key(),
-        deactivated_at: Clock::get()?.unix_timestamp,
-        signers: signer_keys
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
     });
 
     Ok(())
 }
 
-
-//================ WHITELIST MANAGEMENT ================
-// AUDIT: These functions manage whitelist configurations for different operations
-// SECURITY: All operations require proper multisig authorization
-
-/// Patch execute whitelist entry
+/// Open an investment that was initialized in Init
 /// 
-/// AUDIT CRITICAL - EXECUTE WHITELIST PATCH:
-/// This function replaces one entry in the execute_whitelist with another.
-/// It requires 3-of-5 multisig authorization from the execute_whitelist.
+/// AUDIT CRITICAL - INVESTMENT OPENING:
+/// Ends the review window of an investment created with defer_open. Moves the
+/// investment from Init to Pending, after which add_investment_record accepts
+/// records and the investment can be completed or cancelled as usual.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - 3-of-5 multisig validation from execute_whitelist
-/// - Investment state validation (must be active)
-/// - PDA verification to prevent address spoofing
-/// - Whitelist entry validation (from must exist, to must not exist)
-/// - Duplicate address prevention
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify multisig validation uses correct whitelist (execute_whitelist)
-/// [ ] Confirm whitelist entry replacement logic
-/// [ ] Check duplicate address prevention
-/// [ ] Review event emission for audit trail
-pub fn patch_execute_whitelist(ctx: Context<UpdateExecuteWallet>) -> Result<()> {
+/// - Investment must be active and in Init
+/// - 3-of-5 multisig validation from update_whitelist
+pub fn open_investment(ctx: Context<OpenInvestment>) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let info = &mut ctx.accounts.investment_info;
-    
-    // AUDIT: Reject if investment has been deactivated
-    require!(
-        info.is_active, 
-        ErrorCode::InvestmentInfoDeactivated
-    );
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: Validate investment info PDA derivation to prevent address spoofing
-    let (expected_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"investment",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require_keys_eq!(info.key(), expected_pda, ErrorCode::InvalidInvestmentInfoPda);
+    // AUDIT: Only an active investment still in Init can be opened
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Init, ErrorCode::InvestmentInfoNotInit);
 
-    // AUDIT: Extract and validate 3-of-5 multisig from execute_whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
-    msg!("🟢 execute signer count: {}", signer_infos.len());
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    msg!("🟢 Signers: {:?}", signer_keys);
-    info.enforce_3_of_5_signers(signer_infos, false)?;
-    
-    // AUDIT: Extract from and to wallet addresses from remaining accounts
-    let from = ctx.remaining_accounts[3].key();
-    let to = ctx.remaining_accounts[4].key();
-    
-    // AUDIT: Reject if target wallet is the same as from wallet (no-op prevention)
-    require!(
-        from != to, 
-        ErrorCode::WhitelistAddressExists
-    );
-    
-    // AUDIT: Reject if from wallet address does not exist in whitelist
-    require!(
-        info.execute_whitelist.contains(&from),
-        ErrorCode::WhitelistAddressNotFound
-    );
-
-    // AUDIT: Reject if target wallet address already exists in whitelist
-    require!(
-        !info.execute_whitelist.contains(&to),
-        ErrorCode::WhitelistAddressExists
-    );
-
-    // AUDIT: Find the index of the from wallet for replacement
-    let index = info
-        .execute_whitelist
-        .iter()
-        .position(|x| x == &from)
-        .ok_or(ErrorCode::WhitelistAddressNotFound)?;
+    config.enforce_signers(signer_infos, true)?;
 
-    // AUDIT: Replace the whitelist entry
-    info.execute_whitelist[index] = to;
+    info.state = InvestmentState::Pending;
 
-    // AUDIT: Log whitelist update for audit trail
-    msg!("🟢 Replaced execute whitelist entry: from={} to={}", from, to);
-    msg!("🟢 New execute whitelist: {:?}", info.execute_whitelist);
+    msg!("🟢 Investment {} opened", String::from_utf8_lossy(&info.investment_id));
 
-    // AUDIT: Emit whitelist update event for audit trail
-    emit!(WhitelistUpdated {
+    emit!(InvestmentInfoOpened {
         investment_id: info.investment_id,
         version: info.version,
-        wallet: to,
         updated_by: ctx.accounts.payer.key(),
         updated_at: now,
-        signers: signer_keys.clone(),
+        signers: signer_keys,
     });
 
     Ok(())
 }
 
-/// Patch update whitelist entry
+/// Pause or resume instruction families of one investment
 /// 
-/// AUDIT CRITICAL - UPDATE WHITELIST PATCH:
-/// This function replaces one entry in the update_whitelist with another.
-/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// AUDIT CRITICAL - INSTRUCTION PAUSE:
+/// Replaces InvestmentInfo.paused_families with `paused_families`. Each protected
+/// instruction checks its family bit, so the committee can e.g. stop withdrawals
+/// while distributions continue. The program-wide pause still applies on top.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
 /// - 3-of-5 multisig validation from update_whitelist
-/// - Investment state validation (must be active)
-/// - Whitelist entry validation (from must exist, to must not exist)
-/// - Duplicate address prevention
+/// - Only PAUSE_ALL_FAMILIES bits may be set
 /// 
-/// AUDIT POINTS:
-/// [ ] Verify multisig validation uses correct whitelist (update_whitelist)
-/// [ ] Confirm whitelist entry replacement logic
-/// [ ] Check duplicate address prevention
-/// [ ] Review event emission for audit trail
-pub fn patch_update_whitelist(ctx: Context<UpdateUpdateWallet>) -> Result<()> {
+/// PARAMETERS:
+/// - paused_families: PAUSE_* bits to pause; 0 resumes every family
+pub fn set_instruction_pause(ctx: Context<SetInstructionPause>, paused_families: u8) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: Reject if investment has been deactivated
-    require!(
-        info.is_active, 
-        ErrorCode::InvestmentInfoDeactivated
-    );
-
-    // AUDIT: Extract and validate 3-of-5 multisig from update_whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
-    msg!("🟢 execute signer count: {}", signer_infos.len());
+    // AUDIT: Pauser role (3-of-5 update_whitelist until assigned)
+    let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    msg!("🟢 Signers: {:?}", signer_keys);
-    info.enforce_3_of_5_signers(signer_infos, true)?;
-    
-    // AUDIT: Extract from and to wallet addresses from remaining accounts
-    let from = ctx.remaining_accounts[3].key();
-    let to = ctx.remaining_accounts[4].key();
-    
-    // AUDIT: Reject if target wallet is the same as from wallet (no-op prevention)
-    require!(
-        from != to, 
-        ErrorCode::WhitelistAddressExists
-    );
-    
-    // AUDIT: Reject if from wallet address does not exist in whitelist
-    require!(
-        info.update_whitelist.contains(&from),
-        ErrorCode::WhitelistAddressNotFound
-    );
-
-    // AUDIT: Reject if target wallet address already exists in whitelist
-    require!(
-        !info.update_whitelist.contains(&to),
-        ErrorCode::WhitelistAddressExists
-    );
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Pauser, signer_infos)?;
 
-    // AUDIT: Find the index of the from wallet for replacement
-    let index = info
-        .update_whitelist
-        .iter()
-        .position(|x| x == &from)
-        .ok_or(ErrorCode::WhitelistAddressNotFound)?;
+    // AUDIT: Unknown bits would silently pause nothing
+    require!(paused_families & !PAUSE_ALL_FAMILIES == 0, ErrorCode::InvalidPauseFlags);
 
-    // AUDIT: Replace the whitelist entry
-    info.update_whitelist[index] = to;
+    let previous_families = info.paused_families;
+    info.paused_families = paused_families;
 
-    // AUDIT: Log whitelist update for audit trail
-    msg!("🟢 Replaced update whitelist entry: from={} to={}", from, to);
-    msg!("🟢 New update whitelist: {:?}", info.update_whitelist);
+    msg!("⏸️ Paused families {:#07b} -> {:#07b}", previous_families, paused_families);
 
-    // AUDIT: Emit whitelist update event for audit trail
-    emit!(WhitelistUpdated {
+    emit!(InstructionPauseUpdated {
         investment_id: info.investment_id,
         version: info.version,
-        wallet: to,
+        previous_families,
+        paused_families,
         updated_by: ctx.accounts.payer.key(),
         updated_at: now,
-        signers: signer_keys.clone(),
+        signers: signer_keys,
     });
 
     Ok(())
 }
 
-/// Patch withdraw whitelist entries
+/// Assign or clear an RBAC role
 /// 
-/// AUDIT CRITICAL - WITHDRAW WHITELIST PATCH:
-/// This function replaces the entire withdraw_whitelist with a new list.
-/// It requires 3-of-5 multisig authorization from the execute_whitelist.
+/// AUDIT CRITICAL - ROLE ASSIGNMENT:
+/// Stores the members and threshold of `role` in the investment's RoleConfig. The
+/// role-gated instructions then check their role against this assignment instead of
+/// the fixed execute / update whitelist rule. An empty member list with threshold 0
+/// clears the role, restoring the whitelist rule.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - 3-of-5 multisig validation from execute_whitelist
-/// - Investment state validation (must be active)
-/// - PDA verification to prevent address spoofing
-/// - Whitelist length validation (1 to MAX_WHITELIST_LEN)
-/// - Input validation for wallet addresses
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Members distinct and non-default, 1 <= threshold <= members
 /// 
-/// AUDIT POINTS:
-/// [ ] Verify multisig validation uses correct whitelist (execute_whitelist)
-/// [ ] Confirm whitelist length bounds checking
-/// [ ] Check wallet address validation
-/// [ ] Review event emission for audit trail
-pub fn patch_withdraw_whitelist(ctx: Context<UpdateWithdrawWallet>) -> Result<()> {
+/// PARAMETERS:
+/// - role: Role to assign
+/// - members: Up to MAX_WHITELIST_LEN member keys
+/// - threshold: Distinct members that must sign
+pub fn set_role_assignment(
+    ctx: Context<SetRoleAssignment>,
+    role: Role,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    let info = &mut ctx.accounts.investment_info;
-
-    // AUDIT: Reject if investment has been deactivated
-    require!(
-        info.is_active, 
-        ErrorCode::InvestmentInfoDeactivated
-    );
-
-    // AUDIT: Validate investment info PDA derivation to prevent address spoofing
-    let (expected_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"investment",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require_keys_eq!(info.key(), expected_pda, ErrorCode::InvalidInvestmentInfoPda);
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let role_config = &mut ctx.accounts.role_config;
 
-    // AUDIT: Extract and validate 3-of-5 multisig from execute_whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
-    msg!("🟢 execute signer count: {}", signer_infos.len());
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    msg!("🟢 Signers: {:?}", signer_keys);
-    info.enforce_3_of_5_signers(signer_infos, false)?;
-
-    // AUDIT: Extract and validate new wallet list from remaining accounts
-    let wallet_infos = &ctx.remaining_accounts[signer_infos.len()..];
-    require!(
-        !wallet_infos.is_empty() && wallet_infos.len() <= MAX_WHITELIST_LEN,
-        ErrorCode::WhitelistLengthInvalid
-    );
+    config.enforce_signers(signer_infos, true)?;
 
-    // AUDIT: Extract and validate new wallet list
-    let new_wallets: Vec<Pubkey> = wallet_infos.iter().map(|a| a.key()).collect();
+    let assignment = RoleAssignment::new(&members, threshold)?;
 
-    require!(
-        (1..=MAX_WHITELIST_LEN).contains(&new_wallets.len()),
-        ErrorCode::WhitelistLengthInvalid
-    );
+    // AUDIT: Bind a newly created role config to its investment
+    if role_config.investment_id == [0u8; 15] {
+        role_config.investment_id = info.investment_id;
+        role_config.version = info.version;
+        role_config.bump = ctx.bumps.role_config;
+    }
+    role_config.assignments[role as usize] = assignment;
+    role_config.updated_at = now;
 
-    // AUDIT: Update withdraw whitelist with new wallet list
-    info.withdraw_whitelist = new_wallets.clone();
+    msg!("🎭 Role {:?}: {} members, threshold {}", role, members.len(), threshold);
 
-    // AUDIT: Emit withdraw whitelist update event for audit trail
-    emit!(WithdrawWhitelistUpdated {
+    emit!(RoleAssignmentUpdated {
         investment_id: info.investment_id,
         version: info.version,
-        wallets: info.withdraw_whitelist.clone(),
+        role,
+        members,
+        threshold,
         updated_by: ctx.accounts.payer.key(),
         updated_at: now,
-        signers: signer_keys.clone(),
+        signers: signer_keys,
     });
-    
-    // AUDIT: Log whitelist update for audit trail
-    msg!("🟢 Withdraw whitelist replaced");
+
     Ok(())
 }
 
-
-//================ INVESTMENT RECORD MANAGEMENT ================
-// AUDIT: These functions manage individual investment records for investors
-// SECURITY: All operations require proper multisig authorization and validation
-
-/// Adds a new investment record for an investor
+/// Set the amount-based signer tiers of withdraw_from_vault
 /// 
-/// AUDIT CRITICAL - INVESTMENT RECORD CREATION:
-/// This function creates a new investment record for an investor.
-/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// AUDIT CRITICAL - WITHDRAWAL TIERS:
+/// Replaces InvestmentInfo.withdraw_tiers. withdraw_from_vault then requires the
+/// signer count of the first tier covering the withdrawn USDT amount, and every
+/// Withdrawer member above the highest tier, e.g. [(1_000 USDT, 2), (100_000 USDT, 3)]
+/// lets two members move petty cash while emptying a large vault needs 5-of-5.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
 /// - 3-of-5 multisig validation from update_whitelist
-/// - Investment state validation (must be active, not completed)
-/// - Record PDA verification to prevent address spoofing
-/// - Token account ownership validation
-/// - Token mint validation (USDT and H2COIN)
-/// - Input parameter validation
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify record PDA derivation is consistent
-/// [ ] Confirm multisig validation uses correct whitelist
-/// [ ] Check token account ownership validation
-/// [ ] Review input parameter bounds checking
-/// [ ] Validate event emission for audit trail
+/// - Tiers strictly ascending by amount with non-decreasing signer counts
 /// 
 /// PARAMETERS:
-/// - batch_id: Batch identifier for grouping records
-/// - record_id: Unique record identifier
-/// - account_id: 15-byte investor account identifier
-/// - amount_usdt: USDT investment amount
-/// - amount_hcoin: H2COIN investment amount
-/// - stage: Investment stage (0-2)
-#[allow(clippy::too_many_arguments)]
-pub fn add_investment_record(
-    ctx: Context<AddInvestmentRecords>,
-    batch_id: u16,
-    record_id: u64,
-    account_id: [u8; 15],
-    amount_usdt: u64,
-    amount_hcoin: u64,
-    stage: u8,
-) -> Result<()> {
+/// - tiers: Up to MAX_WITHDRAW_TIERS tiers; empty restores the Withdrawer role rule
+pub fn set_withdraw_tiers(ctx: Context<SetWithdrawTiers>, tiers: Vec<WithdrawTier>) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let info = &mut ctx.accounts.investment_info;
-    let record = &mut ctx.accounts.investment_record;
-    
-    let usdt_mint = &ctx.accounts.usdt_mint;
-    let hcoin_mint = &ctx.accounts.hcoin_mint;
-
-    let recipient_account = &ctx.accounts.recipient_account;
-    let recipient_usdt_account = &ctx.accounts.recipient_usdt_account;
-    let recipient_hcoin_account = &ctx.accounts.recipient_hcoin_account;
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: Validate record PDA derivation to prevent address spoofing
-    let (expected_record_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"record",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            record_id.to_le_bytes().as_ref(),
-            account_id.as_ref()
-        ],
-        ctx.program_id,
-    );
-    // AUDIT: Prevent invalid record PDA
-    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);    
-    
-    // AUDIT: Validate investment is active and not completed
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state != InvestmentState::Completed, ErrorCode::InvestmentInfoHasCompleted);
-    
-    // AUDIT: Verify 3-of-5 multisig signer set from update_whitelist
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
     let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    info.enforce_3_of_5_signers(signer_infos, true)?;    
+    config.enforce_signers(signer_infos, true)?;
 
-    // AUDIT: Validate token account ownership and mint addresses
-    require_keys_eq!(recipient_usdt_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
-    require_keys_eq!(recipient_hcoin_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
-    require_keys_eq!(recipient_usdt_account.mint, usdt_mint.key(), ErrorCode::InvalidRecipientMint);
-    require_keys_eq!(recipient_hcoin_account.mint, hcoin_mint.key(), ErrorCode::InvalidRecipientMint);
+    let packed = WithdrawTier::pack(&tiers)?;
+    let previous_tiers: Vec<WithdrawTier> = info
+        .withdraw_tiers
+        .iter()
+        .filter(|tier| tier.is_set())
+        .copied()
+        .collect();
+    info.withdraw_tiers = packed;
 
-    // AUDIT: Write record data with validation
-    record.batch_id = batch_id;
-    record.record_id = record_id;
-    record.account_id = account_id;
-    record.investment_id = info.investment_id;
-    record.version = info.version;
-    record.wallet = recipient_account.key();
-    record.amount_usdt = amount_usdt;
-    record.amount_hcoin = amount_hcoin;
-    record.stage = stage;
-    record.revoked_at = 0;
-    record.created_at = now;
+    msg!("🪜 Withdrawal tiers: {} -> {}", previous_tiers.len(), tiers.len());
 
-    // AUDIT: Emit record addition event for audit trail
-    emit!(InvestmentRecordAdded {
+    emit!(WithdrawTiersUpdated {
         investment_id: info.investment_id,
         version: info.version,
-        account_id,
-        record_id,
-        amount_usdt,
-        added_by: ctx.accounts.payer.key(),
-        added_at: now,
+        previous_tiers,
+        tiers,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
         signers: signer_keys,
     });
 
-    // AUDIT: Log record addition for audit trail
-    msg!("🟢 Added record {} for investor {:?}", record_id, account_id);
-
     Ok(())
 }
 
-
-/// Updates the wallet address for matching InvestmentRecords under a given `account_id`
+/// Set the withdrawal timelock of an investment
 /// 
-/// AUDIT CRITICAL - INVESTMENT RECORD WALLET UPDATE:
-/// This function updates the wallet address for all InvestmentRecords matching a specific account_id.
-/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// AUDIT CRITICAL - WITHDRAWAL TIMELOCK:
+/// Stores InvestmentInfo.withdraw_delay_secs. From then on withdraw_from_vault is
+/// rejected and every withdrawal goes through propose_withdrawal, waits out the delay
+/// and is paid by execute_withdrawal, giving off-chain monitoring a window to
+/// cancel_withdrawal. The delay can be changed within its bounds but never removed.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
 /// - 3-of-5 multisig validation from update_whitelist
-/// - Investment state validation (must be active)
-/// - Token account ownership validation for new wallet
-/// - Token mint validation (USDT and H2COIN)
-/// - Record matching validation (account_id, investment_id, version)
-/// - Duplicate wallet prevention
-/// - Record update count validation
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify multisig validation uses correct whitelist (update_whitelist)
-/// [ ] Check token account ownership validation
-/// [ ] Review record matching logic
-/// [ ] Confirm duplicate wallet prevention
-/// [ ] Validate record update count requirement
-/// [ ] Review event emission for audit trail
+/// - MIN_WITHDRAW_DELAY_SECS <= delay_secs <= MAX_WITHDRAW_DELAY_SECS
 /// 
 /// PARAMETERS:
-/// - account_id: 15-byte investor account identifier to match records
-/// 
-/// - Requires 3-of-5 multisig approval
-/// - Validates associated token accounts for USDT and H2COIN of the new wallet
-/// - Iterates over remaining accounts to find and update matching InvestmentRecords
-/// - Emits `InvestmentRecordWalletUpdated` event after success
-pub fn update_investment_record_wallets<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, UpdateInvestmentRecordWallets<'info>>,
-    account_id: [u8; 15],
-) -> Result<()> 
-where 
-    'c: 'info,
-{
+/// - delay_secs: Seconds between propose_withdrawal and execute_withdrawal
+pub fn set_withdraw_delay(ctx: Context<SetWithdrawDelay>, delay_secs: u32) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    let info = &ctx.accounts.investment_info;
-    let usdt_mint = &ctx.accounts.usdt_mint;
-    let hcoin_mint = &ctx.accounts.hcoin_mint;
-
-    let recipient_account = &ctx.accounts.recipient_account;
-    let recipient_usdt_account = &ctx.accounts.recipient_usdt_account;
-    let recipient_hcoin_account = &ctx.accounts.recipient_hcoin_account;
-    
-    // AUDIT: Validate investment_info is active and recipient_account
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require_keys_eq!(recipient_usdt_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
-    require_keys_eq!(recipient_hcoin_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
-    require_keys_eq!(recipient_usdt_account.mint, usdt_mint.key(), ErrorCode::InvalidRecipientMint);
-    require_keys_eq!(recipient_hcoin_account.mint, hcoin_mint.key(), ErrorCode::InvalidRecipientMint);
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: 3-of-5 multisig validation from update_whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    info.enforce_3_of_5_signers(signer_infos, true)?;
-
-    // AUDIT: Load records from remaining_accounts for batch processing
-    let records = &ctx.remaining_accounts[signer_infos.len()..];
-    let mut updated_count = 0;
-
-    for acc_info in records {
-        // AUDIT: Skip if not owned by this program for security
-        if acc_info.owner != ctx.program_id {
-            continue;
-        }
-
-        // AUDIT: Deserialize from account data with error handling
-        let mut data = acc_info.try_borrow_mut_data()?;
-        let mut record = InvestmentRecord::try_deserialize(&mut &data[..])?;
-
-        // AUDIT: Match records by account_id, investment_id, and version
-        if record.account_id != account_id {
-            continue;
-        }
-
-        if record.investment_id != info.investment_id {
-            continue;
-        }
-
-        if record.version != info.version {
-            continue;
-        }
-
-        // AUDIT: Skip if wallet is already the target wallet (no-op prevention)
-        if record.wallet == recipient_account.key() {
-            continue;
-        }
-
-        // AUDIT: Update the wallet address
-        record.wallet = recipient_account.key();
+    config.enforce_signers(signer_infos, true)?;
 
-        // AUDIT: Serialize back to account data
-        record.try_serialize(&mut &mut data[..])?;
+    // AUDIT: 0 is not accepted, so an enabled timelock cannot be switched off
+    require!(
+        (MIN_WITHDRAW_DELAY_SECS..=MAX_WITHDRAW_DELAY_SECS).contains(&delay_secs),
+        ErrorCode::InvalidWithdrawDelay
+    );
 
-        // AUDIT: Increment updated count for validation
-        updated_count += 1;        
-    }
+    let previous_delay_secs = info.withdraw_delay_secs;
+    info.withdraw_delay_secs = delay_secs;
 
-    // AUDIT: Require at least one record to be updated
-    require!(updated_count > 0, ErrorCode::NoRecordsUpdated);
+    msg!("⏳ Withdrawal delay: {}s -> {}s", previous_delay_secs, delay_secs);
 
-    // AUDIT: Emit wallet update event for audit trail
-    emit!(InvestmentRecordWalletUpdated {
+    emit!(WithdrawDelayUpdated {
         investment_id: info.investment_id,
         version: info.version,
-        account_id,
-        new_wallet: recipient_account.key(),
+        previous_delay_secs,
+        delay_secs,
         updated_by: ctx.accounts.payer.key(),
         updated_at: now,
-        signers: signer_keys.clone(),
+        signers: signer_keys,
     });
-    
-    // AUDIT: Log update count for audit trail
-    msg!("🟢 record update count: {}", updated_count);
+
     Ok(())
 }
 
-
-/// Revokes an investment record by marking it as revoked
+/// Set the caps of one withdrawal purpose
 /// 
-/// AUDIT CRITICAL - INVESTMENT RECORD REVOCATION:
-/// This function revokes an investment record by setting its revoked_at timestamp.
-/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// AUDIT CRITICAL - WITHDRAWAL BUDGET:
+/// Every vault withdrawal carries a WithdrawPurpose and is counted in the matching
+/// WithdrawalBudget line. The update committee may bound the cumulative USDT,
+/// H2COIN and SOL withdrawn under a purpose; withdrawals that would pass a cap fail
+/// with WithdrawBudgetExceeded. Totals are never reset by this instruction.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
 /// - 3-of-5 multisig validation from update_whitelist
-/// - Investment state validation (must be active)
-/// - Record PDA verification to prevent address spoofing
-/// - Record parameter validation (batch_id, record_id, account_id)
-/// - Record initialization check
-/// - Double revocation prevention
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify record PDA derivation is consistent
-/// [ ] Confirm multisig validation uses correct whitelist
-/// [ ] Check record parameter validation
-/// [ ] Review double revocation prevention
-/// [ ] Validate event emission for audit trail
 /// 
 /// PARAMETERS:
-/// - batch_id: Batch identifier for the record
-/// - record_id: Unique record identifier
-/// - account_id: 15-byte investor account identifier
-pub fn revoked_investment_record(
-    ctx: Context<RevokeInvestmentRecord>,
-    batch_id: u16,
-    record_id: u64,
-    account_id: [u8; 15],
+/// - purpose: Budget line to change
+/// - cap: New caps; None leaves an asset uncapped, Some(0) blocks it
+pub fn set_withdraw_budget(
+    ctx: Context<SetWithdrawBudget>,
+    purpose: WithdrawPurpose,
+    cap: BudgetCap,
 ) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-
     let info = &ctx.accounts.investment_info;
-    let record = &mut ctx.accounts.investment_record;
-
-    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
-    let (expected_record_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"record",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            record_id.to_le_bytes().as_ref(),
-            account_id.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
-    require!(record.record_id == record_id, ErrorCode::RecordIdMismatch);
-    require!(record.account_id == account_id, ErrorCode::AccountIdMismatch);
-
-    // AUDIT: Validate investment is active
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-
-    // AUDIT: Reject if this InvestmentRecord account has not been initialized
-    require!(
-        !record.to_account_info().data_is_empty(),
-        ErrorCode::InvestmentRecordNotFound
-    );
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: Multisig validation from update_whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    info.enforce_3_of_5_signers(signer_infos, true)?;
-
-    // AUDIT: Prevent double revocation
-    require!(record.revoked_at == 0, ErrorCode::RecordAlreadyRevoked);
-    require!(record.record_id == record_id, ErrorCode::RecordIdMismatch);
-    require!(record.account_id == account_id, ErrorCode::AccountIdMismatch);
+    config.enforce_signers(signer_infos, true)?;
 
-    // AUDIT: Mark record as revoked with timestamp
-    record.revoked_at = now;
+    let budget = &mut ctx.accounts.withdraw_budget;
+    budget.bind(info, ctx.bumps.withdraw_budget);
+    let line = &mut budget.lines[purpose.index()];
+    let previous_cap = line.cap;
+    line.cap = cap;
+    let line = *line;
+    budget.updated_at = now;
 
-    // AUDIT: Log revocation for audit trail
-    msg!(
-        "🟢 Revoked record_id={} for account_id={}, wallet={}",
-        record.record_id,
-        String::from_utf8_lossy(&record.account_id),
-        record.wallet
-    );
+    msg!("📒 Withdrawal budget of {:?}: {:?} -> {:?}", purpose, previous_cap, cap);
 
-    // AUDIT: Emit revocation event for audit trail
-    emit!(InvestmentRecordRevoked {
-        investment_id: record.investment_id,
+    emit!(WithdrawBudgetUpdated {
+        investment_id: info.investment_id,
         version: info.version,
-        record_id: record.record_id,
-        revoked_by: ctx.accounts.payer.key(),
-        revoked_at: now,
+        purpose,
+        previous_cap,
+        cap,
+        withdrawn_usdt: line.withdrawn_usdt,
+        withdrawn_hcoin: line.withdrawn_hcoin,
+        withdrawn_sol: line.withdrawn_sol,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
         signers: signer_keys,
     });
 
     Ok(())
 }
 
-
-//================ handle profit share and refund share ================
-/// Estimates the profit share for a single batch_id.
-/// This function checks investment state, validates the signer against whitelists,
-/// and generates a list of ProfitEntry items by matching each InvestmentRecord
-/// with its corresponding InvestorAccount using the `account_id` key.
-/// The result is stored in the on-chain `ProfitShareCache` account.
-/// - `batch_id`: The target batch of records to estimate.
-/// - `total_profit_usdt`: The profit to distribute for this batch.
-/// - `total_invest_usdt`: The total amount of USDT invested under this investment_id (across all batches).
-pub fn estimate_profit_share<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, EstimateProfitShare<'info>>,
-    batch_id: u16,
-    total_profit_usdt: u64,
-    total_invest_usdt: u64,
-) -> Result<()>
-where
-    'c: 'info,
-{
+/// Configure the rolling-window withdrawal rate limit
+/// 
+/// AUDIT CRITICAL - WITHDRAWAL RATE LIMIT:
+/// Caps the USDT and H2COIN paid out by withdraw_from_vault, execute_withdrawal and
+/// claim_operating_allowance within any window_secs, across all purposes and signers.
+/// Even a compromised withdrawal quorum can then move at most one window's cap
+/// before the update committee reacts. The window is tracked in RATE_LIMIT_BUCKETS
+/// buckets, so it rolls instead of resetting at a fixed boundary.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - window_secs is 0 or within MIN_RATE_LIMIT_WINDOW_SECS..=MAX_RATE_LIMIT_WINDOW_SECS
+/// - A changed window clears the buckets, whose slots depend on the bucket length
+/// 
+/// PARAMETERS:
+/// - window_secs: Length of the rolling window (0 turns the limit off)
+/// - cap_usdt / cap_hcoin: Amount allowed per window; None leaves the asset uncapped
+pub fn set_withdraw_rate_limit(
+    ctx: Context<SetWithdrawRateLimit>,
+    window_secs: u32,
+    cap_usdt: Option<u64>,
+    cap_hcoin: Option<u64>,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let info = &ctx.accounts.investment_info;
-    let cache = &mut ctx.accounts.cache;
-
-    // AUDIT: Validate cache PDA with info.investment_id to prevent address spoofing
-    let (expected_cache_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"profit_cache",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require_keys_eq!(cache.key(), expected_cache_pda, ErrorCode::InvalidProfitCachePda);
-
-    // AUDIT: Validate investment is active and completed
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
-    require!(info.investment_type == InvestmentType::Standard, ErrorCode::StandardOnly);
+    let config = &ctx.accounts.investment_config;
 
-    // AUDIT: Validate signer against combined whitelists
-    let signer_infos = &ctx.remaining_accounts[..1];
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
     let signer_keys = extract_signer_keys(signer_infos);
-    let mut combined: HashSet<Pubkey> = info.execute_whitelist.iter().cloned().collect();
-    combined.extend(info.update_whitelist.iter().cloned());
+    config.enforce_signers(signer_infos, true)?;
 
     require!(
-        signer_keys.iter().any(|key| combined.contains(key)),
-        ErrorCode::UnauthorizedSigner
+        window_secs == 0
+            || (MIN_RATE_LIMIT_WINDOW_SECS..=MAX_RATE_LIMIT_WINDOW_SECS).contains(&window_secs),
+        ErrorCode::InvalidRateLimitWindow
     );
 
-    // AUDIT: Check data accounts does not exceed 255 for gas limit protection
-    let data_accounts = &ctx.remaining_accounts[1..];
-    require!(
-        data_accounts.len() <= MAX_ENTRIES_PER_BATCH,
-        ErrorCode::TooManyRecordsLoaded
-    );
+    let rate_limit = &mut ctx.accounts.withdraw_rate_limit;
+    rate_limit.bind(info, ctx.bumps.withdraw_rate_limit);
+    let previous_window_secs = rate_limit.window_secs;
+    if window_secs != previous_window_secs {
+        rate_limit.buckets = [RateBucket::default(); RATE_LIMIT_BUCKETS];
+    }
+    rate_limit.window_secs = window_secs;
+    rate_limit.cap_usdt = cap_usdt;
+    rate_limit.cap_hcoin = cap_hcoin;
+    rate_limit.updated_at = now;
 
-    // AUDIT: Mapping accounts to records with validation
-    let mut record_map = BTreeMap::new();
+    msg!(
+        "🚦 Withdrawal rate limit: window {}s -> {}s, usdt cap {:?}, hcoin cap {:?}",
+        previous_window_secs,
+        window_secs,
+        cap_usdt,
+        cap_hcoin
+    );
 
-    for acc_info in data_accounts.iter() {
-        match Account::<InvestmentRecord>::try_from(acc_info) {
-            Ok(record) => {
-                // AUDIT: Validate record PDA with info.investment_id
-                let (expected_record_pda, _bump) = Pubkey::find_program_address(
-                    &[
-                        b"record",
-                        info.investment_id.as_ref(),
-                        info.version.as_ref(),
-                        batch_id.to_le_bytes().as_ref(),
-                        record.record_id.to_le_bytes().as_ref(),
-                        record.account_id.as_ref(),
-                    ],
-                    ctx.program_id,
-                );
-                require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
-                require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+    emit!(WithdrawRateLimitUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        previous_window_secs,
+        window_secs,
+        cap_usdt,
+        cap_hcoin,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
 
-                // AUDIT: Reject if record_id is duplicate
-                require!(
-                    !record_map.contains_key(&record.record_id),
-                    ErrorCode::DuplicateRecord
-                );
+    Ok(())
+}
 
-                record_map.insert(record.record_id, record);
-            }
-            Err(e) => {
-                msg!("🔴 Reason: {}, {:?}", acc_info.key(), e);
-            }
-        }
-    }
+/// Switch the four-eyes rule of record edits
+/// 
+/// AUDIT CRITICAL - SEPARATION OF DUTIES:
+/// While InvestmentInfo.record_four_eyes is set, update_investment_record_wallets and
+/// revoked_investment_record reject a transaction whose payer is also one of its
+/// approving signers, so the operator preparing a record edit cannot approve it.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// 
+/// PARAMETERS:
+/// - enabled: Whether the rule is enforced
+pub fn set_record_four_eyes(ctx: Context<SetRecordFourEyes>, enabled: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-    require!(
-        !record_map.is_empty() && record_map.len() <= MAX_ENTRIES_PER_BATCH,
-        ErrorCode::TooManyRecordsLoaded
-    );
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
 
-    // AUDIT: Compute profit entries with mathematical overflow protection
-    let mut entries: Vec<ProfitEntry> = Vec::new();
-    let mut subtotal_profit_usdt: u64 = 0;
+    info.record_four_eyes = enabled;
 
-    for (_record_id, record) in record_map.iter() {
-        require!(record.account_id.len() == 15, ErrorCode::InvalidAccountIdLength);
-        
-        // AUDIT: Skip revoked records
-        if record.revoked_at != 0 {
-           msg!(
-                "🟡 Skipping revoked record_id={} for account_id={}",
-                record.record_id,
-                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
-            );
-            continue;
-        }
+    msg!("👀 Four-eyes rule of record edits: {}", enabled);
 
-        let wallet = record.wallet;
+    emit!(RecordFourEyesUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        enabled,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
 
-        // AUDIT: Calculate ratio with overflow protection
-        let ratio_bp = u16::try_from(
-            record.amount_usdt.saturating_mul(10_000) / total_invest_usdt
-        ).map_err(|_| ErrorCode::BpRatioOverflow)?;
+    Ok(())
+}
 
-        // AUDIT: Calculate amount with overflow protection
-        let amount = total_profit_usdt
-            .saturating_mul(ratio_bp as u64)
-            / 10_000;
+/// Designate the recovery wallet of foreign token sweeps
+/// 
+/// AUDIT CRITICAL:
+/// sweep_foreign_token pays only InvestmentInfo.recovery_wallet. Passing the default
+/// pubkey clears it, which disables sweeping.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig from update_whitelist
+/// - Wallet must not be the vault PDA
+pub fn set_recovery_wallet(ctx: Context<SetRecoveryWallet>, wallet: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-        // AUDIT: Add to subtotal with overflow protection
-        subtotal_profit_usdt = subtotal_profit_usdt
-            .checked_add(amount)
-            .ok_or(ErrorCode::NumericalOverflow)?;        
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
 
-        entries.push(ProfitEntry {
-            account_id: record.account_id,
-            wallet,
-            amount_usdt: amount,
-            ratio_bp,
-        });
-    }
+    // AUDIT: Sweeping to the vault itself would be a no-op that hides the tokens again
+    require_keys_neq!(wallet, info.vault, ErrorCode::InvalidRecoveryWallet);
 
-    // AUDIT: Estimate SOL cost for execution
-    let entry_count = entries.len() as u16;
-    let subtotal_estimate_sol =
-        ESTIMATE_SOL_BASE + (entry_count as u64) * ESTIMATE_SOL_PER_ENTRY;
+    let previous_wallet = info.recovery_wallet;
+    info.recovery_wallet = wallet;
 
-    // AUDIT: Store result to cache with validation
-    cache.batch_id = batch_id;
-    cache.investment_id = info.investment_id;
-    cache.subtotal_profit_usdt = subtotal_profit_usdt;
-    cache.subtotal_estimate_sol = subtotal_estimate_sol;
-    cache.executed_at = 0;
-    cache.created_at = now;
-    cache.entries = entries;
+    msg!("🧹 Recovery wallet: {} -> {}", previous_wallet, wallet);
 
-    // AUDIT: Emit event
-    emit!(ProfitShareEstimated {
-        batch_id,
+    emit!(RecoveryWalletUpdated {
         investment_id: info.investment_id,
         version: info.version,
-        subtotal_profit_usdt,
-        subtotal_estimate_sol,
-        created_by: ctx.accounts.payer.key(),
-        created_at: now,
-        entry_count,
+        previous_wallet,
+        new_wallet: wallet,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
         signers: signer_keys,
     });
 
-    msg!(
-        "Estimated profit share: {} entries, {} USDT total",
-        entry_count,
-        subtotal_profit_usdt
-    );
-
     Ok(())
 }
 
-
-/// Estimates the refund share for a single `batch_id` in a specific refund year
+/// Set the profit multipliers per investment stage
 /// 
-/// AUDIT CRITICAL - REFUND SHARE ESTIMATION:
-/// This function estimates H2COIN refund distribution for a batch of investment records.
-/// It calculates refund shares based on investment stage ratios and stores results in cache.
+/// AUDIT CRITICAL - STAGE-WEIGHTED PROFIT:
+/// Earlier-stage investors may be contractually entitled to a larger profit multiple.
+/// While InvestmentInfo.profit_stage_multipliers_bp is set, estimate_profit_share (and
+/// reconcile_batch, accrue_profit, report_revocation_impact) weight each record's
+/// amount_usdt by its stage multiplier. estimate_profit_share weights its on-chain
+/// invested total the same way; the total_invest_usdt passed to accrue_profit and
+/// report_revocation_impact must then be the weighted total. All zero switches weighting off.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - Investment state validation (must be active and completed)
-/// - Signer validation against combined whitelists
-/// - Cache PDA verification to prevent address spoofing
-/// - Record PDA verification for each record
-/// - Batch size validation (max 255 records)
-/// - Duplicate record prevention
-/// - Refund period validation (year_index bounds checking)
-/// - Mathematical overflow protection in calculations
-/// - Revoked record filtering
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify cache PDA derivation is consistent
-/// [ ] Check signer validation against whitelists
-/// [ ] Review refund period validation logic
-/// [ ] Confirm mathematical calculations for overflow
-/// [ ] Validate record filtering logic
-/// [ ] Review cache storage security
-/// [ ] Validate event emission for audit trail
+/// - 3-of-5 multisig from update_whitelist
+/// - Multipliers all zero or all within 1..=MAX_PROFIT_STAGE_MULTIPLIER_BP
 /// 
 /// PARAMETERS:
-/// - batch_id: The target batch of investment records to estimate
-/// - year_index: The number of years passed since the refund period started
+/// - multipliers_bp: Multiplier of stage 1..=MAX_STAGE in basis points (10_000 = 1×)
+pub fn set_profit_stage_multipliers(
+    ctx: Context<SetProfitStageMultipliers>,
+    multipliers_bp: [u16; MAX_STAGE],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    // AUDIT: A zero multiplier next to non-zero ones would silently drop a stage's profit
+    require!(
+        multipliers_bp == [0u16; MAX_STAGE]
+            || multipliers_bp
+                .iter()
+                .all(|m| (1..=MAX_PROFIT_STAGE_MULTIPLIER_BP).contains(m)),
+        ErrorCode::InvalidProfitStageMultipliers
+    );
+
+    let previous_multipliers_bp = info.profit_stage_multipliers_bp;
+    info.profit_stage_multipliers_bp = multipliers_bp;
+
+    msg!(
+        "⚖️ Profit stage multipliers: {:?} -> {:?} bp",
+        previous_multipliers_bp,
+        multipliers_bp
+    );
+
+    emit!(ProfitStageMultipliersUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        previous_multipliers_bp,
+        new_multipliers_bp: multipliers_bp,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Put an investment under an emergency freeze
 /// 
-/// This uses the investment stage ratios to calculate H2COIN refunds per investor,
-/// storing the results in the `RefundShareCache` account.
+/// AUDIT CRITICAL - EMERGENCY FREEZE:
+/// A circuit breaker for a suspected key compromise. Any single execute or update
+/// member can set InvestmentInfo.frozen_at, which blocks FREEZE_FAMILIES (profit /
+/// refund execution, accrual settlement, withdrawals) until unfreeze_investment is
+/// approved by the update committee. Freezing can only stop funds, never move them,
+/// so one signature is enough. Freezing a frozen investment keeps the first frozen_at.
 /// 
-/// - `batch_id`: The target batch of investment records to estimate.
-/// - `year_index`: The number of years passed since the refund period started (e.g., 0 = year 1, 1 = year 2, ...).
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be an execute or update member, or the Squads authority
+pub fn freeze_investment(ctx: Context<FreezeInvestment>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let member = ctx.accounts.member.key();
+
+    // AUDIT: One member of either committee is enough to freeze
+    require!(
+        config.is_execute_or_update_member(&member) || config.is_squads_signer(&[member]),
+        ErrorCode::UnauthorizedSigner
+    );
+
+    if info.frozen_at != 0 {
+        msg!("🧊 Investment already frozen since {}", info.frozen_at);
+        return Ok(());
+    }
+
+    info.frozen_at = now;
+
+    msg!("🧊 Investment frozen by {}", member);
+
+    emit!(InvestmentFrozen {
+        investment_id: info.investment_id,
+        version: info.version,
+        frozen_by: member,
+        frozen_at: now,
+    });
+
+    Ok(())
+}
+
+/// Lift an emergency freeze
 /// 
-/// Refunds typically begin after a lock period (e.g., after year 3).
-pub fn estimate_refund_share<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, EstimateRefundShare<'info>>,
-    batch_id: u16, 
-    year_index: u8
-) -> Result<()>
-where
-    'c: 'info,
-{
+/// AUDIT CRITICAL - EMERGENCY UNFREEZE:
+/// Clears InvestmentInfo.frozen_at. Unlike freezing, this needs the full quorum, so
+/// a compromised key that can freeze cannot also unfreeze.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment must be frozen
+pub fn unfreeze_investment(ctx: Context<UnfreezeInvestment>) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    let info = &ctx.accounts.investment_info;
-    let cache = &mut ctx.accounts.cache;    
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
 
+    require!(info.frozen_at != 0, ErrorCode::InvestmentNotFrozen);
 
-    // Validate the expected vault PDA
-    let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+    let frozen_at = info.frozen_at;
+    info.frozen_at = 0;
+
+    msg!("🔥 Investment unfrozen (frozen since {})", frozen_at);
+
+    emit!(InvestmentUnfrozen {
+        investment_id: info.investment_id,
+        version: info.version,
+        frozen_at,
+        unfrozen_by: ctx.accounts.payer.key(),
+        unfrozen_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Deactivate investment info
+/// 
+/// AUDIT CRITICAL - INVESTMENT DEACTIVATION:
+/// This function permanently deactivates an investment, preventing all further operations.
+/// It requires 3-of-5 multisig authorization and can only be called on completed investments.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment state validation (must be completed or cancelled)
+/// - Investment deactivation check
+/// - PDA verification to prevent address spoofing
+/// - Investment initialization check
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify deactivation is irreversible
+/// [ ] Confirm state validation prevents premature deactivation
+/// [ ] Check multisig validation uses correct whitelist
+/// [ ] Review event emission for audit trail
+pub fn deactivate_investment_info(ctx: Context<DeactivateInvestmentInfo>) -> Result<()> {
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(
+        info.is_active, 
+        ErrorCode::InvestmentInfoDeactivated
+    );
+    
+    // AUDIT: Reject if investment is not completed (or cancelled) yet
+    require!(
+        info.state == InvestmentState::Completed || info.state == InvestmentState::Cancelled, 
+        ErrorCode::InvestmentInfoNotCompleted
+    );
+    
+    // AUDIT: Reject if this InvestmentInfo has not been initialized
+    require!(
+        !info.to_account_info().data_is_empty(),
+        ErrorCode::InvestmentInfoNotFound
+    );
+
+    // AUDIT: Validate investment info PDA derivation to prevent address spoofing
+    let (expected_pda, _bump) = Pubkey::find_program_address(
         &[
-            b"refund_cache",
+            b"investment",
             info.investment_id.as_ref(),
             info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            year_index.to_le_bytes().as_ref(),
         ],
         ctx.program_id,
     );
-    require_keys_eq!(cache.key(), expected_cache_pda, ErrorCode::InvalidRefundCachePda);
+    require_keys_eq!(info.key(), expected_pda, ErrorCode::InvalidInvestmentInfoPda);
 
+    // AUDIT: Extract signer information for multisig validation
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    config.enforce_signers(signer_infos, true)?;
 
-    // Validate state
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    // AUDIT: Deactivate the investment
+    info.is_active = false;
 
+    // AUDIT: Log deactivation for audit trail
+    msg!("🟢 Investment {} deactivated", String::from_utf8_lossy(&info.investment_id));
 
-    // Validate signer
-    let signer_infos = &ctx.remaining_accounts[..1];
-    let signer_keys = extract_signer_keys(signer_infos);
-    let mut combined: HashSet<Pubkey> = info.execute_whitelist.iter().cloned().collect();
-    combined.extend(info.update_whitelist.iter().cloned());
+    // AUDIT: Emit deactivation event for audit trail
+    emit!(InvestmentInfoDeactivated {
+        investment_id: info.investment_id,
+        version: info.version,
+        deactivated_by: ctx.accounts.payer.key(),
+        deactivated_at: Clock::get()?.unix_timestamp,
+        signers: signer_keys
+    });
 
+    Ok(())
+}
 
-    require!(
-        signer_keys.iter().any(|key| combined.contains(key)),
-        ErrorCode::UnauthorizedSigner
-    );
+/// Attest the final settlement of an investment and archive it
+/// 
+/// AUDIT CRITICAL - FINAL SETTLEMENT:
+/// Terminal step of a completed investment. For every batch 1..=batch_counter.batch_id
+/// the profit round (Standard investments only) and every refund year
+/// START_YEAR_INDEX..=MAX_YEAR_INDEX must be settled, i.e. its cache is supplied in
+/// remaining_accounts, executed and every entry Paid, or be listed in `waivers`.
+/// Cumulative paid totals per asset over the supplied caches, the unpaid amounts of
+/// waived rounds and the vault balances left behind are emitted in InvestmentArchived.
+/// The investment then becomes Archived and inactive, which unlocks close_vault to
+/// sweep the residual USDT / H2COIN and close the vault in one governed step.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig from update_whitelist
+/// - Investment active, completed and not frozen
+/// - Batch counter omitted only when no record was ever created
+/// - Each cache is a PDA of this investment and supplied once
+/// - Waivers in range, unique, and never for a settled round
+/// 
+/// PARAMETERS:
+/// - waivers: Rounds explicitly waived (at most MAX_SETTLEMENT_WAIVERS)
+pub fn archive_investment<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ArchiveInvestment<'info>>,
+    waivers: Vec<SettlementWaiver>,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-    
-    // Check data accounts does not exceed 25
-    let data_accounts = &ctx.remaining_accounts[1..];
-    require!(
-        data_accounts.len() <= MAX_ENTRIES_PER_BATCH,
-        ErrorCode::TooManyRecordsLoaded
-    );
+    // AUDIT: Only an active, completed and unfrozen investment can be archived
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    require!(info.frozen_at == 0, ErrorCode::InvestmentFrozen);
 
+    require_keys_eq!(ctx.accounts.usdt_mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+    require_keys_eq!(ctx.accounts.hcoin_mint.key(), ctx.accounts.global_config.hcoin_mint, ErrorCode::InvalidTokenMint);
 
-    // Mapping accounts to records and records
-    let mut record_map = BTreeMap::new();
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    // AUDIT: Without a batch counter no record exists, so there is nothing to settle
+    let batches = match &ctx.accounts.batch_counter {
+        Some(counter) => counter.batch_id,
+        None => {
+            require!(info.total_invested_usdt()? == 0, ErrorCode::SettlementIncomplete);
+            0
+        }
+    };
+    let has_profit = info.investment_type == InvestmentType::Standard;
+
+    // AUDIT: A round is keyed by (batch_id, None) for profit, (batch_id, Some(year)) for refund
+    require!(waivers.len() <= MAX_SETTLEMENT_WAIVERS, ErrorCode::InvalidSettlementWaiver);
+    let mut waived: BTreeSet<(u16, Option<u8>)> = BTreeSet::new();
+    for waiver in waivers.iter() {
+        let in_range = match waiver.year_index {
+            Some(year) => (START_YEAR_INDEX..=MAX_YEAR_INDEX).contains(&year),
+            None => has_profit,
+        };
+        require!(
+            in_range && (1..=batches).contains(&waiver.batch_id),
+            ErrorCode::InvalidSettlementWaiver
+        );
+        require!(
+            waived.insert((waiver.batch_id, waiver.year_index)),
+            ErrorCode::InvalidSettlementWaiver
+        );
+    }
+
+    let mut settled: BTreeSet<(u16, Option<u8>)> = BTreeSet::new();
+    let mut seen: HashSet<Pubkey> = HashSet::new();
+    let mut total_profit_paid_usdt: u64 = 0;
+    let mut total_refund_paid_hcoin: u64 = 0;
+    let mut unsettled_profit_usdt: u64 = 0;
+    let mut unsettled_refund_hcoin: u64 = 0;
 
     for acc_info in data_accounts.iter() {
-        
-        match Account::<InvestmentRecord>::try_from(acc_info) {
-            Ok(record) => {
-                // Validate record PDA with info.investment_id
-                let (expected_record_pda, _bump) = Pubkey::find_program_address(
-                    &[
-                        b"record",
-                        info.investment_id.as_ref(),
-                        info.version.as_ref(),
-                        batch_id.to_le_bytes().as_ref(),
-                        record.record_id.to_le_bytes().as_ref(),
-                        record.account_id.as_ref(),
-                    ],
-                    ctx.program_id,
-                );
-                require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
-                require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+        require!(seen.insert(acc_info.key()), ErrorCode::InvalidSettlementAccount);
+
+        if let Ok(cache) = Account::<ProfitShareCache>::try_from(acc_info) {
+            let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+                &[
+                    b"profit_cache",
+                    info.investment_id.as_ref(),
+                    info.version.as_ref(),
+                    cache.batch_id.to_le_bytes().as_ref(),
+                ],
+                ctx.program_id,
+            );
+            require_keys_eq!(acc_info.key(), expected_cache_pda, ErrorCode::InvalidProfitCachePda);
 
-                // reject if record_id is duplicate or not
-                require!(
-                    !record_map.contains_key(&record.record_id),
-                    ErrorCode::DuplicateRecord
-                );
+            let paid = cache
+                .entries
+                .iter()
+                .filter(|e| e.status == EntryStatus::Paid)
+                .try_fold(0u64, |acc, e| acc.checked_add(e.amount_usdt))
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            let unpaid = cache
+                .subtotal_profit_usdt
+                .checked_sub(paid)
+                .ok_or(ErrorCode::NumericalOverflow)?;
 
-                record_map.insert(record.record_id, record);
+            // AUDIT: Rounds paid in H2COIN add to the H2COIN totals; rounds paid in a
+            // registered vault mint settle the round without adding to either total
+            let (paid_total, unsettled_total) = if cache.payout_mint == info.profit_mint {
+                (&mut total_profit_paid_usdt, &mut unsettled_profit_usdt)
+            } else if cache.payout_mint == ctx.accounts.global_config.hcoin_mint {
+                (&mut total_refund_paid_hcoin, &mut unsettled_refund_hcoin)
+            } else {
+                (&mut 0u64, &mut 0u64)
+            };
+            *paid_total = paid_total
+                .checked_add(paid)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            if cache.executed_at != 0 && unpaid == 0 {
+                settled.insert((cache.batch_id, None));
+            } else {
+                *unsettled_total = unsettled_total
+                    .checked_add(unpaid)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
             }
-            Err(e) => {
-                msg!("🔴 Reason: {}, {:?}", acc_info.key(), e);
+        } else if let Ok(cache) = Account::<RefundShareCache>::try_from(acc_info) {
+            let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+                &[
+                    b"refund_cache",
+                    info.investment_id.as_ref(),
+                    info.version.as_ref(),
+                    cache.batch_id.to_le_bytes().as_ref(),
+                    cache.year_index.to_le_bytes().as_ref(),
+                ],
+                ctx.program_id,
+            );
+            require_keys_eq!(acc_info.key(), expected_cache_pda, ErrorCode::InvalidRefundCachePda);
+
+            let paid = cache
+                .entries
+                .iter()
+                .filter(|e| e.status == EntryStatus::Paid)
+                .try_fold(0u64, |acc, e| acc.checked_add(e.amount_hcoin))
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            let unpaid = cache
+                .subtotal_refund_hcoin
+                .checked_sub(paid)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+
+            total_refund_paid_hcoin = total_refund_paid_hcoin
+                .checked_add(paid)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+            if cache.executed_at != 0 && unpaid == 0 {
+                settled.insert((cache.batch_id, Some(cache.year_index)));
+            } else {
+                unsettled_refund_hcoin = unsettled_refund_hcoin
+                    .checked_add(unpaid)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
             }
+        } else {
+            return err!(ErrorCode::InvalidSettlementAccount);
+        }
+    }
+
+    // AUDIT: A settled round must not also be waived, so the attestation never hides a payout
+    require!(waived.is_disjoint(&settled), ErrorCode::InvalidSettlementWaiver);
+
+    // AUDIT: Every round of every batch is either settled or waived
+    for batch_id in 1..=batches {
+        let profit_round = has_profit.then_some(None);
+        let refund_years = (START_YEAR_INDEX..=MAX_YEAR_INDEX).map(Some);
+        for round in profit_round.into_iter().chain(refund_years) {
+            require!(
+                settled.contains(&(batch_id, round)) || waived.contains(&(batch_id, round)),
+                ErrorCode::SettlementIncomplete
+            );
         }
     }
 
-    require!(
-        !record_map.is_empty() && record_map.len() <= MAX_ENTRIES_PER_BATCH,
-        ErrorCode::TooManyRecordsLoaded
-    );
+    let profit_rounds_settled = settled.iter().filter(|(_, year)| year.is_none()).count() as u16;
+    let refund_years_settled = settled.len() as u16 - profit_rounds_settled;
+
+    let info = &mut ctx.accounts.investment_info;
+    info.state = InvestmentState::Archived;
+    info.is_active = false;
+
+    msg!(
+        "🗄️ Investment {} archived: {} profit rounds and {} refund years settled, {} waived",
+        String::from_utf8_lossy(&info.investment_id),
+        profit_rounds_settled,
+        refund_years_settled,
+        waivers.len()
+    );
+
+    emit!(InvestmentArchived {
+        investment_id: info.investment_id,
+        version: info.version,
+        batches,
+        profit_rounds_settled,
+        refund_years_settled,
+        waivers,
+        total_invested_usdt: info.total_invested_usdt()?,
+        total_profit_paid_usdt,
+        total_refund_paid_hcoin,
+        unsettled_profit_usdt,
+        unsettled_refund_hcoin,
+        vault_usdt_balance: ctx.accounts.vault_usdt_account.amount,
+        vault_hcoin_balance: ctx.accounts.vault_hcoin_account.amount,
+        vault_lamports: ctx.accounts.vault.lamports(),
+        archived_by: ctx.accounts.payer.key(),
+        archived_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Close a deactivated investment's vault and reclaim its rent
+/// 
+/// AUDIT CRITICAL - VAULT CLOSURE:
+/// After deactivate_investment_info nothing can use the vault again, yet its two ATAs
+/// and the vault PDA keep holding rent and any SOL left behind. This closes both
+/// (empty) ATAs and moves every remaining lamport of the vault PDA to a withdraw
+/// whitelist wallet, which removes the vault account. Token balances must be
+/// withdrawn before deactivation; foreign tokens must be swept first. An investment
+/// archived through archive_investment is the exception: its residual USDT / H2COIN
+/// is swept to the recipient's ATAs before the vault ATAs are closed.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; withdrawals not paused and investment not frozen
+/// - Investment deactivated and its vault not handed off to a later version
+/// - 3-of-5 multisig from execute_whitelist
+/// - Mints are USDT / H2COIN; both vault ATAs are empty unless the investment is archived
+/// - Recipient on withdraw_whitelist
+pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let vault = &ctx.accounts.vault;
+    let recipient = &ctx.accounts.recipient_account;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_WITHDRAW)?;
+
+    // AUDIT: Only a deactivated investment that still owns its vault may close it
+    require!(!info.is_active, ErrorCode::InvestmentStillActive);
+    require!(!info.vault_handed_off, ErrorCode::VaultHandedOff);
+    require_keys_eq!(vault.key(), info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: Verify 3-of-5 signers from execute_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, false)?;
+
+    // AUDIT: Check recipient is on withdraw whitelist for authorization
+    require!(!config.withdraw_whitelist.is_empty(), ErrorCode::EmptyWhitelist);
+    require!(config.withdraw_whitelist.contains(&recipient.key()), ErrorCode::UnauthorizedRecipient);
+
+    require_keys_eq!(ctx.accounts.usdt_mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+    require_keys_eq!(ctx.accounts.hcoin_mint.key(), ctx.accounts.global_config.hcoin_mint, ErrorCode::InvalidTokenMint);
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    let signer: &[&[&[u8]]] = &[signer_seeds];
+
+    // AUDIT: Only an archived investment may sweep its residue; otherwise closing an ATA
+    // with a balance would fail in the token program, so fail early and clearly
+    let archived = info.state == InvestmentState::Archived;
+    let mut ata_rent_lamports: u64 = 0;
+    let mut swept = [0u64; 2];
+    let sweeps = [
+        (&ctx.accounts.vault_usdt_account, &ctx.accounts.usdt_mint, &ctx.accounts.recipient_usdt_account),
+        (&ctx.accounts.vault_hcoin_account, &ctx.accounts.hcoin_mint, &ctx.accounts.recipient_hcoin_account),
+    ];
+    for (index, (vault_ata, mint, recipient_ata)) in sweeps.into_iter().enumerate() {
+        require!(archived || vault_ata.amount == 0, ErrorCode::VaultNotEmpty);
+
+        guard_vault_token_account(
+            ctx.accounts.token_program.to_account_info(),
+            vault_ata,
+            vault.to_account_info(),
+            signer_seeds,
+            info,
+            now,
+        )?;
+
+        if vault_ata.amount > 0 {
+            let recipient_ata = recipient_ata
+                .as_ref()
+                .ok_or(ErrorCode::MissingAssociatedTokenAccount)?;
+            let memo_program = ctx
+                .accounts
+                .memo_program
+                .as_ref()
+                .ok_or(ErrorCode::InvalidMemoProgram)?;
+
+            transfer_token_checked(
+                ctx.accounts.token_program.to_account_info(),
+                vault_ata.to_account_info(),
+                recipient_ata.to_account_info(),
+                mint.to_account_info(),
+                vault.to_account_info(),
+                Some(signer_seeds),
+                vault_ata.amount,
+                mint.decimals,
+                memo_program.to_account_info(),
+                &transfer_memo(info, "archive_sweep", None, &[]),
+            )?;
+            swept[index] = vault_ata.amount;
+        }
+
+        ata_rent_lamports = ata_rent_lamports
+            .checked_add(vault_ata.to_account_info().lamports())
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault_ata.to_account_info(),
+                destination: recipient.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    // AUDIT: Moving every lamport out of the data-less vault PDA removes the account
+    let vault_lamports = vault.lamports();
+    if vault_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: recipient.to_account_info(),
+                },
+                signer,
+            ),
+            vault_lamports,
+        )?;
+    }
+
+    msg!(
+        "🟢 Vault {} closed: {} lamports of ATA rent and {} lamports of SOL to {}",
+        vault.key(),
+        ata_rent_lamports,
+        vault_lamports,
+        recipient.key()
+    );
+
+    emit!(VaultClosed {
+        investment_id: info.investment_id,
+        version: info.version,
+        vault: vault.key(),
+        recipient: recipient.key(),
+        ata_rent_lamports,
+        vault_lamports,
+        swept_usdt: swept[0],
+        swept_hcoin: swept[1],
+        closed_by: ctx.accounts.payer.key(),
+        closed_at: now,
+        signers: signer_keys,
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+/// Link a new version of an investment to an earlier version's vault
+/// 
+/// AUDIT CRITICAL - SHARED VAULT:
+/// Opt-in for re-versioning an investment mid-flight without moving funds. The new
+/// version's vault reference (vault, vault_bump, vault_version) is replaced by the
+/// source version's, so every vault seed derivation of the new version resolves to
+/// the shared vault. The source version is deactivated so only one version can pay
+/// out of the vault.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from both versions' update_whitelists
+/// - Versions differ and both are active; the new version is not yet linked
+/// - Both versions use the same profit mint
+/// - New version's own vault ATAs are the canonical ATAs and hold no tokens
+pub fn link_shared_vault(ctx: Context<LinkSharedVault>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let source = &mut ctx.accounts.source_info;
+    let config = &ctx.accounts.investment_config;
+    let source_config = &ctx.accounts.source_config;
+
+    // AUDIT: Both versions must approve the link under their own rules
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+    source_config.enforce_signers(signer_infos, true)?;
+
+    require!(info.is_active && source.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.version != source.version, ErrorCode::InvalidVaultLink);
+    require!(info.vault_version == info.version, ErrorCode::InvalidVaultLink);
+    // AUDIT: The shared vault holds one profit mint; both versions must pay profits in it
+    require_keys_eq!(info.profit_mint, source.profit_mint, ErrorCode::InvalidVaultLink);
+
+    // AUDIT: Own vault ATAs become unreachable after linking, so they must be empty
+    for (ata, mint) in [
+        (&ctx.accounts.own_vault_usdt_account, info.profit_mint),
+        (&ctx.accounts.own_vault_hcoin_account, ctx.accounts.global_config.hcoin_mint),
+    ] {
+        require_keys_eq!(
+            ata.key(),
+            get_associated_token_address(&info.vault, &mint),
+            ErrorCode::InvalidVaultAta
+        );
+        require!(ata.amount == 0, ErrorCode::InvalidVaultLink);
+    }
+
+    info.vault = source.vault;
+    info.vault_bump = source.vault_bump;
+    info.vault_version = source.vault_version;
+    source.is_active = false;
+    source.vault_handed_off = true;
+
+    msg!(
+        "🟢 Version {} linked to vault of version {}",
+        String::from_utf8_lossy(&info.version),
+        String::from_utf8_lossy(&source.version)
+    );
+
+    emit!(SharedVaultLinked {
+        investment_id: info.investment_id,
+        version: info.version,
+        source_version: source.version,
+        vault_version: info.vault_version,
+        vault: info.vault,
+        linked_by: ctx.accounts.payer.key(),
+        linked_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+
+//================ WHITELIST MANAGEMENT ================
+// AUDIT: These functions manage whitelist configurations for different operations
+// SECURITY: All operations require proper multisig authorization
+
+/// Patch execute whitelist entry
+/// 
+/// AUDIT CRITICAL - EXECUTE WHITELIST PATCH:
+/// This function queues the replacement of one entry in the execute_whitelist.
+/// The change is held in a PendingWhitelistPatch and takes effect through
+/// apply_whitelist_patch after WHITELIST_PATCH_DELAY_SECS.
+/// It requires 3-of-5 multisig authorization from the execute_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Investment state validation (must be active)
+/// - PDA verification to prevent address spoofing
+/// - Whitelist entry validation (from must exist, to must not exist)
+/// - Duplicate address prevention
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify multisig validation uses correct whitelist (execute_whitelist)
+/// [ ] Confirm whitelist entry replacement logic
+/// [ ] Check duplicate address prevention
+/// [ ] Review event emission for audit trail
+pub fn patch_execute_whitelist(
+    ctx: Context<UpdateExecuteWallet>,
+    to_label: [u8; WHITELIST_LABEL_LEN],
+    idempotency_key: Option<[u8; 16]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    
+    // AUDIT: Reject if investment has been deactivated
+    require!(
+        info.is_active, 
+        ErrorCode::InvestmentInfoDeactivated
+    );
+
+    // AUDIT: Validate investment info PDA derivation to prevent address spoofing
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"investment",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(info.key(), expected_pda, ErrorCode::InvalidInvestmentInfoPda);
+
+    // AUDIT: Extract and validate 3-of-5 multisig from execute_whitelist
+    // remaining_accounts: [signer_1, ..., signer_n, from, to]
+    let account_count = ctx.remaining_accounts.len();
+    require!(account_count >= 2, ErrorCode::UnauthorizedSigner);
+    let (signer_infos, wallet_infos) = ctx.remaining_accounts.split_at(account_count - 2);
+    msg!("🟢 execute signer count: {}", signer_infos.len());
+    let signer_keys = extract_signer_keys(signer_infos);
+    msg!("🟢 Signers: {:?}", signer_keys);
+    config.enforce_signers(signer_infos, false)?;
+
+    // AUDIT: Reject a retried patch that already applied
+    info.consume_idempotency_key(idempotency_key)?;
+    
+    // AUDIT: Extract from and to wallet addresses from remaining accounts
+    let from = wallet_infos[0].key();
+    let to = wallet_infos[1].key();
+    
+    // AUDIT: Reject if target wallet is the same as from wallet (no-op prevention)
+    require!(
+        from != to, 
+        ErrorCode::WhitelistAddressExists
+    );
+    
+    // AUDIT: Reject if from wallet address does not exist in whitelist
+    require!(
+        config.execute_whitelist.contains(&from),
+        ErrorCode::WhitelistAddressNotFound
+    );
+
+    // AUDIT: Reject if target wallet address already exists in whitelist
+    require!(
+        !config.execute_whitelist.contains(&to),
+        ErrorCode::WhitelistAddressExists
+    );
+
+    // AUDIT: Queue the replacement; apply_whitelist_patch activates it after the timelock
+    let pending = &mut ctx.accounts.pending_patch;
+    queue_whitelist_patch(pending, info, false, from, to, to_label, ctx.accounts.payer.key(), now);
+    pending.bump = ctx.bumps.pending_patch;
+
+    // AUDIT: Log whitelist patch for audit trail
+    msg!("🟡 Queued execute whitelist patch: from={} to={}, effective at {}", from, to, pending.effective_at);
+
+    // AUDIT: Emit whitelist patch event for audit trail
+    emit!(WhitelistPatchQueued {
+        investment_id: info.investment_id,
+        version: info.version,
+        is_update: false,
+        from,
+        from_label: config.label_of(SignerRole::Execute, &from),
+        to,
+        to_label,
+        effective_at: pending.effective_at,
+        queued_by: ctx.accounts.payer.key(),
+        queued_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Patch update whitelist entry
+/// 
+/// AUDIT CRITICAL - UPDATE WHITELIST PATCH:
+/// This function queues the replacement of one entry in the update_whitelist.
+/// The change is held in a PendingWhitelistPatch and takes effect through
+/// apply_whitelist_patch after WHITELIST_PATCH_DELAY_SECS.
+/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment state validation (must be active)
+/// - Whitelist entry validation (from must exist, to must not exist)
+/// - Duplicate address prevention
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify multisig validation uses correct whitelist (update_whitelist)
+/// [ ] Confirm whitelist entry replacement logic
+/// [ ] Check duplicate address prevention
+/// [ ] Review event emission for audit trail
+pub fn patch_update_whitelist(
+    ctx: Context<UpdateUpdateWallet>,
+    to_label: [u8; WHITELIST_LABEL_LEN],
+    idempotency_key: Option<[u8; 16]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(
+        info.is_active, 
+        ErrorCode::InvestmentInfoDeactivated
+    );
+
+    // AUDIT: Extract and validate 3-of-5 multisig from update_whitelist
+    // remaining_accounts: [signer_1, ..., signer_n, from, to]
+    let account_count = ctx.remaining_accounts.len();
+    require!(account_count >= 2, ErrorCode::UnauthorizedSigner);
+    let (signer_infos, wallet_infos) = ctx.remaining_accounts.split_at(account_count - 2);
+    msg!("🟢 execute signer count: {}", signer_infos.len());
+    let signer_keys = extract_signer_keys(signer_infos);
+    msg!("🟢 Signers: {:?}", signer_keys);
+    config.enforce_signers(signer_infos, true)?;
+
+    // AUDIT: Reject a retried patch that already applied
+    info.consume_idempotency_key(idempotency_key)?;
+    
+    // AUDIT: Extract from and to wallet addresses from remaining accounts
+    let from = wallet_infos[0].key();
+    let to = wallet_infos[1].key();
+    
+    // AUDIT: Reject if target wallet is the same as from wallet (no-op prevention)
+    require!(
+        from != to, 
+        ErrorCode::WhitelistAddressExists
+    );
+    
+    // AUDIT: Reject if from wallet address does not exist in whitelist
+    require!(
+        config.update_whitelist.contains(&from),
+        ErrorCode::WhitelistAddressNotFound
+    );
+
+    // AUDIT: Reject if target wallet address already exists in whitelist
+    require!(
+        !config.update_whitelist.contains(&to),
+        ErrorCode::WhitelistAddressExists
+    );
+
+    // AUDIT: Queue the replacement; apply_whitelist_patch activates it after the timelock
+    let pending = &mut ctx.accounts.pending_patch;
+    queue_whitelist_patch(pending, info, true, from, to, to_label, ctx.accounts.payer.key(), now);
+    pending.bump = ctx.bumps.pending_patch;
+
+    // AUDIT: Log whitelist patch for audit trail
+    msg!("🟡 Queued update whitelist patch: from={} to={}, effective at {}", from, to, pending.effective_at);
+
+    // AUDIT: Emit whitelist patch event for audit trail
+    emit!(WhitelistPatchQueued {
+        investment_id: info.investment_id,
+        version: info.version,
+        is_update: true,
+        from,
+        from_label: config.label_of(SignerRole::Update, &from),
+        to,
+        to_label,
+        effective_at: pending.effective_at,
+        queued_by: ctx.accounts.payer.key(),
+        queued_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Fill a freshly created PendingWhitelistPatch
+/// 
+/// AUDIT: effective_at = now + WHITELIST_PATCH_DELAY_SECS
+fn queue_whitelist_patch(
+    pending: &mut PendingWhitelistPatch,
+    info: &InvestmentInfo,
+    is_update: bool,
+    from: Pubkey,
+    to: Pubkey,
+    to_label: [u8; WHITELIST_LABEL_LEN],
+    queued_by: Pubkey,
+    now: i64,
+) {
+    pending.investment_id = info.investment_id;
+    pending.version = info.version;
+    pending.is_update = is_update;
+    pending.from = from;
+    pending.to = to;
+    pending.to_label = to_label;
+    pending.queued_by = queued_by;
+    pending.queued_at = now;
+    pending.effective_at = now.saturating_add(WHITELIST_PATCH_DELAY_SECS);
+    pending.accepted_at = 0;
+}
+
+/// Accept a seat offered by a pending whitelist patch
+/// 
+/// AUDIT CRITICAL - SEAT ACCEPTANCE:
+/// The incoming member signs with the key named as `to` in the pending patch. Until
+/// then apply_whitelist_patch is rejected, so a mistyped or unwanted key can never
+/// take a seat and brick the multisig.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the pending patch's `to` key
+/// - Investment must be active
+pub fn accept_whitelist_seat(ctx: Context<AcceptWhitelistSeat>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let pending = &mut ctx.accounts.pending_patch;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: Only the incoming member can accept its own seat
+    require_keys_eq!(ctx.accounts.new_member.key(), pending.to, ErrorCode::UnauthorizedSigner);
+
+    pending.accepted_at = now;
+
+    msg!(
+        "🟢 {} accepted its {} whitelist seat",
+        pending.to,
+        if pending.is_update { "update" } else { "execute" }
+    );
+
+    emit!(WhitelistSeatAccepted {
+        investment_id: info.investment_id,
+        version: info.version,
+        is_update: pending.is_update,
+        from: pending.from,
+        to: pending.to,
+        to_label: pending.to_label,
+        accepted_at: now,
+    });
+
+    Ok(())
+}
+
+/// Apply a pending execute / update whitelist patch
+/// 
+/// AUDIT CRITICAL - WHITELIST PATCH TIMELOCK:
+/// Activates a patch queued by patch_execute_whitelist / patch_update_whitelist once
+/// its effective_at has passed and the incoming member has accepted. Permissionless: the 3-of-5 approval was given when the
+/// patch was queued. The PendingWhitelistPatch is closed to its original payer.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment must be active
+/// - Timelock must have elapsed
+/// - Seat must have been accepted by `to`
+/// - from must still be a member and to must still be absent
+pub fn apply_whitelist_patch(ctx: Context<ApplyWhitelistPatch>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config: &mut InvestmentConfig = &mut ctx.accounts.investment_config;
+    let pending = &ctx.accounts.pending_patch;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: The remaining members had the whole delay to cancel
+    require!(now >= pending.effective_at, ErrorCode::WhitelistPatchNotReady);
+
+    // AUDIT: The incoming member proved control of its key
+    require!(pending.accepted_at != 0, ErrorCode::WhitelistSeatNotAccepted);
+
+    if pending.is_update {
+        pending.apply_to(&mut config.update_whitelist, &mut config.update_labels)?;
+    } else {
+        pending.apply_to(&mut config.execute_whitelist, &mut config.execute_labels)?;
+    }
+    info.refresh_config_hash(config)?;
+
+    // AUDIT: Log whitelist update for audit trail
+    msg!(
+        "🟢 Applied {} whitelist patch: from={} to={}",
+        if pending.is_update { "update" } else { "execute" },
+        pending.from,
+        pending.to
+    );
+
+    // AUDIT: Emit whitelist update event for audit trail
+    emit!(WhitelistUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        wallet: pending.to,
+        label: pending.to_label,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: vec![],
+    });
+
+    Ok(())
+}
+
+/// Cancel a pending execute / update whitelist patch
+/// 
+/// AUDIT CRITICAL - WHITELIST PATCH TIMELOCK:
+/// Lets WHITELIST_PATCH_CANCEL_SIGNERS members of the patched whitelist stop a queued
+/// patch, so the members outside a compromised 3-of-5 quorum can still react.
+/// The PendingWhitelistPatch is closed to its original payer.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - WHITELIST_PATCH_CANCEL_SIGNERS distinct members (or the Squads authority)
+///   of the whitelist being patched
+pub fn cancel_whitelist_patch(ctx: Context<CancelWhitelistPatch>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let pending = &ctx.accounts.pending_patch;
+
+    // AUDIT: Cancellation needs fewer members than the patch itself
+    let signer_keys = extract_signer_keys(ctx.remaining_accounts);
+    let role = if pending.is_update { SignerRole::Update } else { SignerRole::Execute };
+    require!(
+        config.is_squads_signer(&signer_keys)
+            || config.count_whitelisted_signers(&signer_keys, role) >= WHITELIST_PATCH_CANCEL_SIGNERS,
+        ErrorCode::UnauthorizedSigner
+    );
+
+    msg!(
+        "🟠 Cancelled {} whitelist patch: from={} to={}",
+        if pending.is_update { "update" } else { "execute" },
+        pending.from,
+        pending.to
+    );
+
+    emit!(WhitelistPatchCancelled {
+        investment_id: info.investment_id,
+        version: info.version,
+        is_update: pending.is_update,
+        from: pending.from,
+        to: pending.to,
+        to_label: pending.to_label,
+        cancelled_by: ctx.accounts.payer.key(),
+        cancelled_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Patch withdraw whitelist entries
+/// 
+/// AUDIT CRITICAL - WITHDRAW WHITELIST PATCH:
+/// This function replaces the entire withdraw_whitelist with a new list.
+/// It requires 3-of-5 multisig authorization from the execute_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Investment state validation (must be active)
+/// - PDA verification to prevent address spoofing
+/// - Whitelist length validation (1 to MAX_WHITELIST_LEN)
+/// - One label per wallet
+/// - Input validation for wallet addresses
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify multisig validation uses correct whitelist (execute_whitelist)
+/// [ ] Confirm whitelist length bounds checking
+/// [ ] Check wallet address validation
+/// [ ] Review event emission for audit trail
+pub fn patch_withdraw_whitelist(
+    ctx: Context<UpdateWithdrawWallet>,
+    labels: Vec<[u8; WHITELIST_LABEL_LEN]>,
+    idempotency_key: Option<[u8; 16]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &mut ctx.accounts.investment_config;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(
+        info.is_active, 
+        ErrorCode::InvestmentInfoDeactivated
+    );
+
+    // AUDIT: Validate investment info PDA derivation to prevent address spoofing
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"investment",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(info.key(), expected_pda, ErrorCode::InvalidInvestmentInfoPda);
+
+    // AUDIT: Extract and validate 3-of-5 multisig from execute_whitelist
+    let (signer_infos, wallet_infos) = split_signer_accounts(ctx.remaining_accounts);
+    msg!("🟢 execute signer count: {}", signer_infos.len());
+    let signer_keys = extract_signer_keys(signer_infos);
+    msg!("🟢 Signers: {:?}", signer_keys);
+    config.enforce_signers(signer_infos, false)?;
+
+    // AUDIT: Reject a retried patch that already applied
+    info.consume_idempotency_key(idempotency_key)?;
+
+    // AUDIT: Validate new wallet list from remaining accounts
+    require!(
+        !wallet_infos.is_empty() && wallet_infos.len() <= MAX_WHITELIST_LEN,
+        ErrorCode::WhitelistLengthInvalid
+    );
+
+    // AUDIT: Extract and validate new wallet list
+    let new_wallets: Vec<Pubkey> = wallet_infos.iter().map(|a| a.key()).collect();
+
+    require!(
+        (1..=MAX_WHITELIST_LEN).contains(&new_wallets.len()),
+        ErrorCode::WhitelistLengthInvalid
+    );
+
+    // AUDIT: Labels are index-aligned with the new wallet list
+    require!(labels.len() == new_wallets.len(), ErrorCode::WhitelistLengthInvalid);
+
+    // AUDIT: Update withdraw whitelist with new wallet list
+    config.withdraw_whitelist = new_wallets.clone();
+    config.withdraw_labels = [[0u8; WHITELIST_LABEL_LEN]; MAX_WHITELIST_LEN];
+    config.withdraw_labels[..labels.len()].copy_from_slice(&labels);
+    info.refresh_config_hash(config)?;
+
+    // AUDIT: Emit withdraw whitelist update event for audit trail
+    emit!(WithdrawWhitelistUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        wallets: config.withdraw_whitelist.clone(),
+        labels,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys.clone(),
+    });
+    
+    // AUDIT: Log whitelist update for audit trail
+    msg!("🟢 Withdraw whitelist replaced");
+    Ok(())
+}
+
+/// Set signer weights for the execute or update whitelist
+/// 
+/// AUDIT CRITICAL - WEIGHTED MULTISIG:
+/// Assigns a weight to each whitelist member (index-aligned) and a weight threshold
+/// that replaces the 3-of-5 count, e.g. CEO = 2, others = 1, threshold = 3.
+/// A threshold of 0 with all-zero weights restores the plain 3-of-5 rule.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Current multisig rule of the target whitelist (weighted or 3-of-5)
+/// - Investment must be active
+/// - No single member may reach the threshold; the whitelist must reach it
+/// 
+/// PARAMETERS:
+/// - is_update: true for update_whitelist, false for execute_whitelist
+/// - weights: Per-member weights and threshold
+pub fn set_signer_weights(
+    ctx: Context<UpdateSignerWeights>,
+    is_update: bool,
+    weights: SignerWeights,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &mut ctx.accounts.investment_config;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: The target whitelist approves its own weights under the rule in force
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, is_update)?;
+
+    weights.validate()?;
+
+    if is_update {
+        config.update_weights = weights;
+    } else {
+        config.execute_weights = weights;
+    }
+    info.refresh_config_hash(config)?;
+
+    msg!(
+        "🟢 {} whitelist weights {:?}, threshold {}",
+        if is_update { "Update" } else { "Execute" },
+        weights.weights,
+        weights.threshold
+    );
+
+    emit!(SignerWeightsUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        is_update,
+        weights: weights.weights,
+        threshold: weights.threshold,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Set the member labels of a whitelist
+/// 
+/// AUDIT CRITICAL - WHITELIST LABELS:
+/// Attaches a short label (e.g. "CFO", "Custodian-A") to each member of the execute,
+/// update or withdraw whitelist, index-aligned with the whitelist. Labels are shown
+/// in whitelist events and signer UIs; they never take part in authorization.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Same multisig as the whitelist's own patch: execute_whitelist for the execute
+///   and withdraw whitelists, update_whitelist for the update whitelist
+/// - Investment must be active
+/// - Exactly one label per current member
+/// 
+/// PARAMETERS:
+/// - role: Whitelist to label
+/// - labels: One UTF-8, zero-padded label per member, in whitelist order
+pub fn set_whitelist_labels(
+    ctx: Context<SetWhitelistLabels>,
+    role: SignerRole,
+    labels: Vec<[u8; WHITELIST_LABEL_LEN]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config: &mut InvestmentConfig = &mut ctx.accounts.investment_config;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: The committee that patches the whitelist also labels it
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, role == SignerRole::Update)?;
+
+    let (wallets, slots) = match role {
+        SignerRole::Execute => (&config.execute_whitelist, &mut config.execute_labels),
+        SignerRole::Update => (&config.update_whitelist, &mut config.update_labels),
+        SignerRole::Withdraw => (&config.withdraw_whitelist, &mut config.withdraw_labels),
+    };
+    require!(labels.len() == wallets.len(), ErrorCode::WhitelistLengthInvalid);
+
+    *slots = [[0u8; WHITELIST_LABEL_LEN]; MAX_WHITELIST_LEN];
+    slots[..labels.len()].copy_from_slice(&labels);
+    let wallets = wallets.clone();
+    info.refresh_config_hash(config)?;
+
+    msg!("🟢 {:?} whitelist labels set for {} members", role, wallets.len());
+
+    emit!(WhitelistLabelsUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        role,
+        wallets,
+        labels,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Enable, change or disable the Squads authorization mode
+/// 
+/// AUDIT CRITICAL - SQUADS AUTHORIZATION:
+/// Stores the Squads v4 vault PDA of `multisig` as InvestmentConfig.squads_authority.
+/// From then on a transaction signed by that vault satisfies verify_signers for the
+/// execute and update whitelists, so the Squads quorum can drive withdrawals and
+/// updates. The vault is passed among the signers in remaining_accounts.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist (or the current Squads vault)
+/// - Investment must be active
+/// - The vault address is derived here, never taken from the caller
+/// 
+/// PARAMETERS:
+/// - multisig: Squads multisig account; None disables the mode
+/// - vault_index: Squads vault index, usually 0
+pub fn set_squads_authority(
+    ctx: Context<SetSquadsAuthority>,
+    multisig: Option<Pubkey>,
+    vault_index: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &mut ctx.accounts.investment_config;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: Validate 3-of-5 multisig from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    let previous_authority = config.squads_authority;
+    config.squads_authority = multisig
+        .map(|multisig| InvestmentConfig::squads_vault(&multisig, vault_index))
+        .unwrap_or_default();
+    info.refresh_config_hash(config)?;
+
+    msg!("🟢 Squads authority {} -> {}", previous_authority, config.squads_authority);
+
+    emit!(SquadsAuthorityUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        multisig: multisig.unwrap_or_default(),
+        vault_index,
+        previous_authority,
+        squads_authority: config.squads_authority,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+
+//================ INVESTMENT RECORD MANAGEMENT ================
+// AUDIT: These functions manage individual investment records for investors
+// SECURITY: All operations require proper multisig authorization and validation
+
+/// Adds a new investment record for an investor
+/// 
+/// AUDIT CRITICAL - INVESTMENT RECORD CREATION:
+/// This function creates a new investment record for an investor.
+/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment state validation (must be active, not completed)
+/// - Record PDA verification to prevent address spoofing
+/// - Token account ownership validation
+/// - Token mint validation (USDT and H2COIN)
+/// - Input parameter validation
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify record PDA derivation is consistent
+/// [ ] Confirm multisig validation uses correct whitelist
+/// [ ] Check token account ownership validation
+/// [ ] Review input parameter bounds checking
+/// [ ] Validate event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for grouping records; 0 assigns the next batch with free capacity
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+/// - amount_usdt: USDT investment amount
+/// - amount_hcoin: H2COIN investment amount
+/// - stage: Investment stage (0-2)
+/// - allow_exchange_wallet: Accept a wallet matching the exchange registry (flagged by event)
+/// 
+/// Recipient accounts may be omitted for non-custodial investors; the record is
+/// then stored with `Pubkey::default()` as wallet until `bind_wallet` is called.
+#[allow(clippy::too_many_arguments)]
+pub fn add_investment_record(
+    ctx: Context<AddInvestmentRecords>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+    amount_usdt: u64,
+    amount_hcoin: u64,
+    stage: u8,
+    provisional: bool,
+    allow_exchange_wallet: bool,
+) -> Result<()> {
+    create_investment_record(
+        ctx,
+        batch_id,
+        record_id,
+        account_id,
+        amount_usdt,
+        amount_hcoin,
+        stage,
+        provisional,
+        allow_exchange_wallet,
+        None,
+    )
+}
+
+/// Backfills a historical investment record with its original timestamp
+/// 
+/// AUDIT CRITICAL - LEGACY MIGRATION:
+/// Same checks and accounts as add_investment_record, but created_at is taken from
+/// the legacy system instead of the clock, so time-based logic and audits see the
+/// original investment date. The record is never provisional; it counts as
+/// acknowledged at its original date.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Everything add_investment_record checks (3-of-5 update_whitelist, state, PDA, wallet)
+/// - start_at <= created_at <= min(end_at, now)
+/// 
+/// PARAMETERS:
+/// - created_at: Original investment timestamp from the legacy system
+/// - Other parameters as in add_investment_record
+#[allow(clippy::too_many_arguments)]
+pub fn backfill_investment_record(
+    ctx: Context<AddInvestmentRecords>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+    amount_usdt: u64,
+    amount_hcoin: u64,
+    stage: u8,
+    allow_exchange_wallet: bool,
+    created_at: i64,
+) -> Result<()> {
+    create_investment_record(
+        ctx,
+        batch_id,
+        record_id,
+        account_id,
+        amount_usdt,
+        amount_hcoin,
+        stage,
+        false,
+        allow_exchange_wallet,
+        Some(created_at),
+    )
+}
+
+/// Shared body of add_investment_record and backfill_investment_record
+/// 
+/// AUDIT: `original_created_at` = None stamps the record with the current clock
+#[allow(clippy::too_many_arguments)]
+fn create_investment_record(
+    ctx: Context<AddInvestmentRecords>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+    amount_usdt: u64,
+    amount_hcoin: u64,
+    stage: u8,
+    provisional: bool,
+    allow_exchange_wallet: bool,
+    original_created_at: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let record = &mut ctx.accounts.investment_record;
+    
+    let usdt_mint = &ctx.accounts.usdt_mint;
+    let hcoin_mint = &ctx.accounts.hcoin_mint;
+
+    let recipient_account = &ctx.accounts.recipient_account;
+    let recipient_usdt_account = &ctx.accounts.recipient_usdt_account;
+    let recipient_hcoin_account = &ctx.accounts.recipient_hcoin_account;
+
+    // AUDIT: batch_id = 0 takes the batch assigned by the counter, as in the record PDA seeds
+    let counter = &mut ctx.accounts.batch_counter;
+    let assigned = batch_id == 0;
+    let batch_id = counter.resolve_batch_id(batch_id);
+
+    // AUDIT: Validate record PDA derivation to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref()
+        ],
+        ctx.program_id,
+    );
+    // AUDIT: Prevent invalid record PDA
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);    
+    
+    // AUDIT: Validate investment is active, open, not completed and not cancelled
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_RECORDS)?;
+    require!(info.state != InvestmentState::Completed, ErrorCode::InvestmentInfoHasCompleted);
+    require!(info.state != InvestmentState::Cancelled, ErrorCode::InvestmentInfoCancelled);
+    require!(info.state != InvestmentState::Init, ErrorCode::InvestmentInfoNotPending);
+    
+    // AUDIT: RecordAdmin role (3-of-5 update_whitelist until assigned)
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::RecordAdmin, signer_infos)?;
+
+    // AUDIT: A backfilled timestamp must lie within the investment period and not in the future
+    let created_at = match original_created_at {
+        Some(created_at) => {
+            require!(
+                created_at >= info.start_at && created_at <= info.end_at.min(now),
+                ErrorCode::InvalidBackfillTimestamp
+            );
+            created_at
+        }
+        None => now,
+    };
+
+    // AUDIT: Recipient accounts are all-or-nothing; an unbound record keeps the default wallet
+    let wallet = match (recipient_account, recipient_usdt_account, recipient_hcoin_account) {
+        (Some(recipient_account), Some(recipient_usdt_account), Some(recipient_hcoin_account)) => {
+            // AUDIT: Validate token account ownership and mint addresses
+            require_keys_eq!(recipient_usdt_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
+            require_keys_eq!(recipient_hcoin_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
+            require_keys_eq!(recipient_usdt_account.mint, usdt_mint.key(), ErrorCode::InvalidRecipientMint);
+            require_keys_eq!(recipient_hcoin_account.mint, hcoin_mint.key(), ErrorCode::InvalidRecipientMint);
+            require_keys_neq!(recipient_account.key(), Pubkey::default(), ErrorCode::InvalidRecipientAddress);
+            recipient_account.key()
+        }
+        (None, None, None) => Pubkey::default(),
+        _ => return err!(ErrorCode::IncompleteRecipientAccounts),
+    };
+
+    // AUDIT: Exchange deposit wallets are rejected unless explicitly allowed
+    let exchange_flagged = match recipient_account {
+        Some(recipient_account) => screen_exchange_wallet(
+            ctx.program_id,
+            &ctx.accounts.exchange_registry,
+            recipient_account,
+            allow_exchange_wallet,
+        )?
+        .then(|| *recipient_account.owner),
+        None => None,
+    };
+
+    // AUDIT: Cross-check USDT and H2COIN amounts against the configured rate range
+    config.hcoin_rate_range.check_amounts(amount_usdt, amount_hcoin)?;
+
+    // AUDIT: Enforce investment_upper_limit over the cumulative amount_usdt of all records
+    info.register_investment(stage, amount_usdt)?;
+
+    // AUDIT: A provisional record must name the wallet that will acknowledge it
+    let ack_deadline = if provisional {
+        require_keys_neq!(wallet, Pubkey::default(), ErrorCode::ProvisionalRecordUnbound);
+        now.checked_add(RECORD_ACK_WINDOW_SECS).ok_or(ErrorCode::NumericalOverflow)?
+    } else {
+        0
+    };
+
+    // AUDIT: Count the record in its batch
+    if counter.bump == 0 {
+        counter.investment_id = info.investment_id;
+        counter.version = info.version;
+        counter.bump = ctx.bumps.batch_counter;
+    }
+    counter.register(batch_id, assigned)?;
+
+    // AUDIT: Link the record in the investor's program-wide directory
+    let directory = &mut ctx.accounts.investor_directory;
+    if directory.bump == 0 {
+        directory.account_id_hash = InvestorDirectory::account_id_hash(&account_id);
+        directory.bump = ctx.bumps.investor_directory;
+    }
+    directory.link(
+        DirectoryEntry {
+            investment_id: info.investment_id,
+            version: info.version,
+            batch_id,
+            record: record.key(),
+        },
+        now,
+    )?;
+
+    // AUDIT: Write record data with validation
+    record.batch_id = batch_id;
+    record.record_id = record_id;
+    record.account_id = account_id;
+    record.investment_id = info.investment_id;
+    record.version = info.version;
+    record.wallet = wallet;
+    record.amount_usdt = amount_usdt;
+    record.amount_hcoin = amount_hcoin;
+    record.stage = stage;
+    record.revoked_at = 0;
+    record.contested_at = 0;
+    record.created_at = created_at;
+    record.ack_deadline = ack_deadline;
+    record.acknowledged_at = if provisional { 0 } else { created_at };
+    record.hold_released_at = 0;
+
+    // AUDIT: A confirmed record joins the profit denominator now; a provisional one on acknowledgment
+    if provisional {
+        info.deactivate_investment(stage, amount_usdt)?;
+    }
+
+    // AUDIT: Emit record addition event for audit trail
+    emit!(InvestmentRecordAdded {
+        investment_id: info.investment_id,
+        version: info.version,
+        account_id,
+        batch_id,
+        record_id,
+        amount_usdt,
+        ack_deadline,
+        added_by: ctx.accounts.payer.key(),
+        added_at: now,
+        signers: signer_keys.clone(),
+    });
+
+    if original_created_at.is_some() {
+        emit!(InvestmentRecordBackfilled {
+            investment_id: info.investment_id,
+            version: info.version,
+            record_id,
+            account_id,
+            created_at,
+            backfilled_by: ctx.accounts.payer.key(),
+            backfilled_at: now,
+            signers: signer_keys.clone(),
+        });
+        msg!("🟢 Record {} backfilled with created_at {}", record_id, created_at);
+    }
+
+    if let Some(wallet_owner) = exchange_flagged {
+        emit!(ExchangeWalletFlagged {
+            investment_id: info.investment_id,
+            version: info.version,
+            account_id,
+            wallet,
+            wallet_owner,
+            flagged_by: ctx.accounts.payer.key(),
+            flagged_at: now,
+            signers: signer_keys,
+        });
+        msg!("🟡 Record {} wallet {} matches the exchange registry (allowed)", record_id, wallet);
+    }
+
+    // AUDIT: Log record addition for audit trail
+    msg!("🟢 Added record {} to batch {} for investor {:?}", record_id, batch_id, account_id);
+    if wallet == Pubkey::default() {
+        msg!("🟡 Record {} created without wallet, awaiting bind_wallet", record_id);
+    }
+
+    Ok(())
+}
+
+
+/// Updates the wallet address for matching InvestmentRecords under a given `account_id`
+/// 
+/// AUDIT CRITICAL - INVESTMENT RECORD WALLET UPDATE:
+/// This function updates the wallet address for all InvestmentRecords matching a specific account_id.
+/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment state validation (must be active)
+/// - Token account ownership validation for new wallet
+/// - Token mint validation (USDT and H2COIN)
+/// - Record matching validation (account_id, investment_id, version)
+/// - Duplicate wallet prevention
+/// - Record update count validation
+/// - Four-eyes rule (payer is not an approver) when enabled
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify multisig validation uses correct whitelist (update_whitelist)
+/// [ ] Check token account ownership validation
+/// [ ] Review record matching logic
+/// [ ] Confirm duplicate wallet prevention
+/// [ ] Validate record update count requirement
+/// [ ] Review event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - account_id: 15-byte investor account identifier to match records
+/// - reason: Operator reason code recorded in the event
+/// - allow_exchange_wallet: Accept a wallet matching the exchange registry (flagged by event)
+/// 
+/// - Requires 3-of-5 multisig approval
+/// - Validates associated token accounts for USDT and H2COIN of the new wallet
+/// - Iterates over remaining accounts to find and update matching InvestmentRecords
+/// - Emits `InvestmentRecordWalletUpdated` event after success
+pub fn update_investment_record_wallets<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, UpdateInvestmentRecordWallets<'info>>,
+    account_id: [u8; 15],
+    reason: RecordChangeReason,
+    idempotency_key: Option<[u8; 16]>,
+    allow_exchange_wallet: bool,
+) -> Result<()> 
+where 
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let usdt_mint = &ctx.accounts.usdt_mint;
+    let hcoin_mint = &ctx.accounts.hcoin_mint;
+
+    let recipient_account = &ctx.accounts.recipient_account;
+    let recipient_usdt_account = &ctx.accounts.recipient_usdt_account;
+    let recipient_hcoin_account = &ctx.accounts.recipient_hcoin_account;
+    
+    // AUDIT: Validate investment_info is active and recipient_account
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_RECORDS)?;
+    require_keys_eq!(recipient_usdt_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
+    require_keys_eq!(recipient_hcoin_account.owner, recipient_account.key(), ErrorCode::InvalidRecipientOwner);
+    require_keys_eq!(recipient_usdt_account.mint, usdt_mint.key(), ErrorCode::InvalidRecipientMint);
+    require_keys_eq!(recipient_hcoin_account.mint, hcoin_mint.key(), ErrorCode::InvalidRecipientMint);
+
+    // AUDIT: RecordAdmin role (3-of-5 update_whitelist until assigned)
+    let (signer_infos, records) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::RecordAdmin, signer_infos)?;
+
+    // AUDIT: Four-eyes rule - the preparer must not also approve
+    info.require_four_eyes(&ctx.accounts.payer.key(), &signer_keys)?;
+
+    // AUDIT: Reject a retried update that already applied
+    info.consume_idempotency_key(idempotency_key)?;
+
+    // AUDIT: Exchange deposit wallets are rejected unless explicitly allowed
+    let exchange_flagged = screen_exchange_wallet(
+        ctx.program_id,
+        &ctx.accounts.exchange_registry,
+        recipient_account,
+        allow_exchange_wallet,
+    )?;
+
+    // AUDIT: Records follow the signers in remaining_accounts
+    let mut updated_count = 0;
+
+    for acc_info in records {
+        // AUDIT: Skip if not owned by this program for security
+        if acc_info.owner != ctx.program_id {
+            continue;
+        }
+
+        // AUDIT: Deserialize from account data with error handling
+        let mut data = acc_info.try_borrow_mut_data()?;
+        let mut record = InvestmentRecord::try_deserialize(&mut &data[..])?;
+
+        // AUDIT: Match records by account_id, investment_id, and version
+        if record.account_id != account_id {
+            continue;
+        }
+
+        if record.investment_id != info.investment_id {
+            continue;
+        }
+
+        if record.version != info.version {
+            continue;
+        }
+
+        // AUDIT: Skip if wallet is already the target wallet (no-op prevention)
+        if record.wallet == recipient_account.key() {
+            continue;
+        }
+
+        // AUDIT: Update the wallet address
+        record.wallet = recipient_account.key();
+
+        // AUDIT: Serialize back to account data
+        record.try_serialize(&mut &mut data[..])?;
+
+        // AUDIT: Increment updated count for validation
+        updated_count += 1;        
+    }
+
+    // AUDIT: Require at least one record to be updated
+    require!(updated_count > 0, ErrorCode::NoRecordsUpdated);
+
+    // AUDIT: Emit wallet update event for audit trail
+    emit!(InvestmentRecordWalletUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        account_id,
+        new_wallet: recipient_account.key(),
+        reason,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys.clone(),
+    });
+
+    if exchange_flagged {
+        emit!(ExchangeWalletFlagged {
+            investment_id: info.investment_id,
+            version: info.version,
+            account_id,
+            wallet: recipient_account.key(),
+            wallet_owner: *recipient_account.owner,
+            flagged_by: ctx.accounts.payer.key(),
+            flagged_at: now,
+            signers: signer_keys,
+        });
+        msg!("🟡 New wallet {} matches the exchange registry (allowed)", recipient_account.key());
+    }
+    
+    // AUDIT: Log update count for audit trail
+    msg!("🟢 record update count: {}", updated_count);
+    Ok(())
+}
+
+
+/// Revokes an investment record by marking it as revoked
+/// 
+/// AUDIT CRITICAL - INVESTMENT RECORD REVOCATION:
+/// This function revokes an investment record by setting its revoked_at timestamp.
+/// It requires 3-of-5 multisig authorization from the update_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment state validation (must be active)
+/// - Record PDA verification to prevent address spoofing
+/// - Record parameter validation (batch_id, record_id, account_id)
+/// - Record initialization check
+/// - Double revocation prevention
+/// - Four-eyes rule (payer is not an approver) when enabled
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify record PDA derivation is consistent
+/// [ ] Confirm multisig validation uses correct whitelist
+/// [ ] Check record parameter validation
+/// [ ] Review double revocation prevention
+/// [ ] Validate event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for the record
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+/// - reason: Operator reason code recorded in the event
+pub fn revoked_investment_record(
+    ctx: Context<RevokeInvestmentRecord>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+    reason: RecordChangeReason,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let info = &ctx.accounts.investment_info;
+
+    let config = &ctx.accounts.investment_config;
+    let record = &mut ctx.accounts.investment_record;
+
+    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+    require!(record.record_id == record_id, ErrorCode::RecordIdMismatch);
+    require!(record.account_id == account_id, ErrorCode::AccountIdMismatch);
+
+    // AUDIT: Validate investment is active
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_RECORDS)?;
+
+    // AUDIT: Reject if this InvestmentRecord account has not been initialized
+    require!(
+        !record.to_account_info().data_is_empty(),
+        ErrorCode::InvestmentRecordNotFound
+    );
+
+    // AUDIT: RecordAdmin role (3-of-5 update_whitelist until assigned)
+    let (signer_infos, _) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::RecordAdmin, signer_infos)?;
+
+    // AUDIT: Four-eyes rule - the preparer must not also approve
+    info.require_four_eyes(&ctx.accounts.payer.key(), &signer_keys)?;
+
+    // AUDIT: Prevent double revocation
+    require!(record.revoked_at == 0, ErrorCode::RecordAlreadyRevoked);
+    require!(record.record_id == record_id, ErrorCode::RecordIdMismatch);
+    require!(record.account_id == account_id, ErrorCode::AccountIdMismatch);
+
+    // AUDIT: Mark record as revoked with timestamp; its share is escrowed for REVOCATION_HOLD_SECS
+    record.revoked_at = now;
+    record.contested_at = 0;
+
+    // AUDIT: Log revocation for audit trail
+    msg!(
+        "🟢 Revoked record_id={} for account_id={}, wallet={}, reason={:?}",
+        record.record_id,
+        String::from_utf8_lossy(&record.account_id),
+        record.wallet,
+        reason
+    );
+
+    // AUDIT: Emit revocation event for audit trail
+    emit!(InvestmentRecordRevoked {
+        investment_id: record.investment_id,
+        version: info.version,
+        record_id: record.record_id,
+        reason,
+        revoked_by: ctx.accounts.payer.key(),
+        revoked_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+
+/// Binds an investor wallet to a record that was created without one
+/// 
+/// AUDIT CRITICAL - INVESTMENT RECORD WALLET BINDING:
+/// Records for non-custodial investors are created with `Pubkey::default()` as wallet.
+/// The investor later signs this instruction with the wallet to be bound, and one
+/// update_whitelist member co-signs to attest the wallet belongs to `account_id`.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investor signature (Signer constraint)
+/// - One update_whitelist co-signer in remaining_accounts
+/// - Record PDA verification to prevent address spoofing
+/// - Record must be unbound and not revoked
+/// - Investor ATA ownership and mint validation
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify an already bound record can only be changed via update_investment_record_wallets
+/// [ ] Confirm the attesting signer is checked against update_whitelist
+/// [ ] Review event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for the record
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+pub fn bind_wallet(
+    ctx: Context<BindInvestmentRecordWallet>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let info = &ctx.accounts.investment_info;
+
+    let config = &ctx.accounts.investment_config;
+    let record = &mut ctx.accounts.investment_record;
+    let investor = &ctx.accounts.investor;
+
+    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+    require!(record.record_id == record_id, ErrorCode::RecordIdMismatch);
+    require!(record.account_id == account_id, ErrorCode::AccountIdMismatch);
+
+    // AUDIT: Validate investment is active
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_RECORDS)?;
+
+    // AUDIT: One update_whitelist member attests the investor's identity
+    let signer_keys = extract_signer_keys(ctx.remaining_accounts);
+    require!(
+        signer_keys.iter().any(|key| config.update_whitelist.contains(key)),
+        ErrorCode::UnauthorizedSigner
+    );
+
+    // AUDIT: Only unbound, live records can be bound
+    require!(record.revoked_at == 0, ErrorCode::RecordAlreadyRevoked);
+    require_keys_eq!(record.wallet, Pubkey::default(), ErrorCode::WalletAlreadyBound);
+
+    // AUDIT: Validate investor token accounts
+    require_keys_eq!(ctx.accounts.investor_usdt_account.owner, investor.key(), ErrorCode::InvalidRecipientOwner);
+    require_keys_eq!(ctx.accounts.investor_hcoin_account.owner, investor.key(), ErrorCode::InvalidRecipientOwner);
+    require_keys_eq!(ctx.accounts.investor_usdt_account.mint, ctx.accounts.usdt_mint.key(), ErrorCode::InvalidRecipientMint);
+    require_keys_eq!(ctx.accounts.investor_hcoin_account.mint, ctx.accounts.hcoin_mint.key(), ErrorCode::InvalidRecipientMint);
+
+    record.wallet = investor.key();
+
+    msg!(
+        "🟢 Bound wallet={} to record_id={} for account_id={}",
+        record.wallet,
+        record.record_id,
+        String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+    );
+
+    // AUDIT: Emit binding event for audit trail
+    emit!(InvestmentRecordWalletBound {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_id,
+        account_id,
+        wallet: record.wallet,
+        bound_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Activates a provisional record once the investor confirms its data
+/// 
+/// AUDIT CRITICAL - TWO-PHASE RECORD ADDITION:
+/// A record added with `provisional = true` is inert: estimations, reconciliation and
+/// accruals skip it. The investor co-signs this instruction within
+/// RECORD_ACK_WINDOW_SECS to confirm the onboarding data, after which the record
+/// counts like any other.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the record's wallet
+/// - Record PDA verification to prevent address spoofing
+/// - Record must be provisional, unacknowledged, not revoked and inside the window
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for the record
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+pub fn acknowledge_record(
+    ctx: Context<AcknowledgeRecord>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let info = &mut ctx.accounts.investment_info;
+    let record = &mut ctx.accounts.investment_record;
+
+    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+    // AUDIT: Only the investor behind the record can acknowledge it
+    require_keys_eq!(ctx.accounts.investor.key(), record.wallet, ErrorCode::UnauthorizedInvestor);
+
+    // AUDIT: Record must be provisional, live and inside the acknowledgment window
+    require!(
+        record.ack_deadline != 0 && !record.is_acknowledged(),
+        ErrorCode::RecordNotProvisional
+    );
+    require!(record.revoked_at == 0, ErrorCode::RecordAlreadyRevoked);
+    require!(now <= record.ack_deadline, ErrorCode::AcknowledgmentWindowClosed);
+
+    record.acknowledged_at = now;
+
+    // AUDIT: The acknowledged record joins the profit denominator
+    info.activate_investment(record.stage, record.amount_usdt)?;
+
+    msg!(
+        "🟢 Record acknowledged record_id={} account_id={}",
+        record.record_id,
+        String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+    );
+
+    emit!(InvestmentRecordAcknowledged {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_id,
+        account_id,
+        acknowledged_by: record.wallet,
+        acknowledged_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Lets the investor dispute a revocation during the holding period
+/// 
+/// AUDIT CRITICAL - REVOCATION CONTEST:
+/// A revoked record's share stays escrowed for REVOCATION_HOLD_SECS. Within that window
+/// the record's wallet can contest; a contested record stays escrowed until the committee
+/// resolves the contest with `resolve_revocation_contest`.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the record's wallet
+/// - Record PDA verification to prevent address spoofing
+/// - Record must be revoked, not yet contested, and inside the holding period
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for the record
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+pub fn contest_revocation(
+    ctx: Context<ContestRevocation>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let info = &ctx.accounts.investment_info;
+    let record = &mut ctx.accounts.investment_record;
+
+    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+    // AUDIT: Only the investor behind the record can contest
+    require_keys_eq!(ctx.accounts.investor.key(), record.wallet, ErrorCode::UnauthorizedInvestor);
+
+    // AUDIT: Record must be revoked, uncontested and within the holding period
+    require!(record.revoked_at != 0, ErrorCode::RecordNotRevoked);
+    require!(record.contested_at == 0, ErrorCode::RevocationAlreadyContested);
+    require!(
+        now < record.revoked_at.saturating_add(REVOCATION_HOLD_SECS),
+        ErrorCode::RevocationHoldExpired
+    );
+
+    record.contested_at = now;
+
+    msg!(
+        "🟡 Revocation contested for record_id={} account_id={}",
+        record.record_id,
+        String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+    );
+
+    emit!(RevocationContested {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_id,
+        account_id,
+        contested_by: record.wallet,
+        contested_at: now,
+    });
+
+    Ok(())
+}
+
+/// Resolves a contested revocation
+/// 
+/// AUDIT CRITICAL - REVOCATION CONTEST RESOLUTION:
+/// The committee either reinstates the record (revocation cancelled) or upholds the
+/// revocation, after which the record's share is released once the holding period ends
+/// and release_revocation_hold takes it out of the profit denominator.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Record PDA verification to prevent address spoofing
+/// - Record must have an open contest
+/// - On reinstatement, every supplied cache must be a profit or refund cache of the
+///   record's batch holding an escrowed share of the record
+/// 
+/// Shares escrowed for the revocation hold by earlier estimations are moved to Requeued
+/// on reinstatement, so requeue_entry pays them to the record's wallet. Caches not
+/// supplied keep the share escrowed.
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for the record
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+/// - reinstate: true cancels the revocation, false upholds it
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n, cache_1, ..., cache_m]
+pub fn resolve_revocation_contest(
+    ctx: Context<ResolveRevocationContest>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+    reinstate: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let info = &ctx.accounts.investment_info;
+
+    let config = &ctx.accounts.investment_config;
+    let record = &mut ctx.accounts.investment_record;
+
+    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+    // AUDIT: Validate investment is active
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: Multisig validation from update_whitelist; caches follow the signers
+    let (signer_infos, cache_infos) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    // AUDIT: Only an open contest can be resolved
+    require!(record.contested_at != 0, ErrorCode::RevocationNotContested);
+
+    // AUDIT: Caches are only touched by a reinstatement
+    require!(reinstate || cache_infos.is_empty(), ErrorCode::InvalidReinstatementCache);
+
+    record.contested_at = 0;
+    if reinstate {
+        record.revoked_at = 0;
+    }
+
+    // AUDIT: Release the record's hold-escrowed share in each supplied cache to requeue_entry
+    let mut seen: HashSet<Pubkey> = HashSet::new();
+    for cache_info in cache_infos {
+        require!(
+            cache_info.owner == ctx.program_id && seen.insert(cache_info.key()),
+            ErrorCode::InvalidReinstatementCache
+        );
+        let mut data = cache_info.try_borrow_mut_data()?;
+
+        // AUDIT: Hold escrows carry their record_id; failed payouts go through release_escrowed_entry
+        let held = |status: EntryStatus, held_record_id: Option<u64>| {
+            status == EntryStatus::Escrowed && held_record_id == Some(record_id)
+        };
+
+        let (entry_index, amount, year_index) = if let Ok(mut cache) = ProfitShareCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id
+                    && cache.version == info.version
+                    && cache.batch_id == batch_id,
+                ErrorCode::InvalidReinstatementCache
+            );
+            let entry = cache
+                .entries
+                .iter_mut()
+                .find(|e| held(e.status, e.held_record_id))
+                .ok_or(ErrorCode::EntryNotEscrowed)?;
+            entry.status = EntryStatus::Requeued;
+            let (entry_index, amount) = (entry.entry_index, entry.amount_usdt);
+            cache.try_serialize(&mut &mut data[..])?;
+            (entry_index, amount, None)
+        } else if let Ok(mut cache) = RefundShareCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id
+                    && cache.version == info.version
+                    && cache.batch_id == batch_id,
+                ErrorCode::InvalidReinstatementCache
+            );
+            let entry = cache
+                .entries
+                .iter_mut()
+                .find(|e| held(e.status, e.held_record_id))
+                .ok_or(ErrorCode::EntryNotEscrowed)?;
+            entry.status = EntryStatus::Requeued;
+            let (entry_index, amount) = (entry.entry_index, entry.amount_hcoin);
+            let year_index = cache.year_index;
+            cache.try_serialize(&mut &mut data[..])?;
+            (entry_index, amount, Some(year_index))
+        } else {
+            return err!(ErrorCode::InvalidReinstatementCache);
+        };
+
+        msg!("🟢 Held entry {} of batch {} released to requeue", entry_index, batch_id);
+
+        emit!(EscrowedEntryReleased {
+            investment_id: info.investment_id,
+            version: info.version,
+            batch_id,
+            year_index,
+            entry_index,
+            account_id,
+            wallet: record.wallet,
+            amount,
+            released_by: ctx.accounts.payer.key(),
+            signers: signer_keys.clone(),
+            released_at: now,
+        });
+    }
+
+    msg!(
+        "🟢 Revocation contest resolved for record_id={}: reinstated={}",
+        record.record_id,
+        reinstate
+    );
+
+    emit!(RevocationContestResolved {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_id,
+        account_id,
+        reinstated: reinstate,
+        resolved_by: ctx.accounts.payer.key(),
+        resolved_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Takes a revoked record out of the profit denominator once its hold is over
+/// 
+/// AUDIT CRITICAL - REVOCATION HOLD RELEASE:
+/// A revoked record keeps its amount_usdt in the profit denominator while its share is
+/// held in escrow (REVOCATION_HOLD_SECS, or until an open contest is resolved), so every
+/// batch estimated meanwhile divides by the same investment-wide total. Once the hold is
+/// over the revocation is final and this crank removes the record. Until it runs, later
+/// estimates still count the record and leave its share unallocated in the vault.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Permissionless; the record state alone decides
+/// - Record PDA verification to prevent address spoofing
+/// - Record must be revoked, no longer held and not yet released
+/// - An unacknowledged record was never counted and is only marked released
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch identifier for the record
+/// - record_id: Unique record identifier
+/// - account_id: 15-byte investor account identifier
+pub fn release_revocation_hold(
+    ctx: Context<ReleaseRevocationHold>,
+    batch_id: u16,
+    record_id: u64,
+    account_id: [u8; 15],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let info = &mut ctx.accounts.investment_info;
+    let record = &mut ctx.accounts.investment_record;
+
+    // AUDIT: Validate record PDA with info.investment_id to prevent address spoofing
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            record_id.to_le_bytes().as_ref(),
+            account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+    // AUDIT: Only a final revocation leaves the denominator, and only once
+    require!(record.revoked_at != 0, ErrorCode::RecordNotRevoked);
+    require!(!record.revocation_held(now), ErrorCode::RevocationStillHeld);
+    require!(record.hold_released_at == 0, ErrorCode::RevocationHoldAlreadyReleased);
+
+    record.hold_released_at = now;
+
+    // AUDIT: A provisional record never entered the denominator
+    let released_usdt = if record.is_acknowledged() {
+        info.deactivate_investment(record.stage, record.amount_usdt)?;
+        record.amount_usdt
+    } else {
+        0
+    };
+
+    msg!(
+        "🟢 Revocation hold released for record_id={} account_id={}",
+        record.record_id,
+        String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+    );
+
+    emit!(RevocationHoldReleased {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_id,
+        account_id,
+        released_usdt,
+        released_by: ctx.accounts.payer.key(),
+        released_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Report how much future allocation revoking a record would free
+/// 
+/// AUDIT CRITICAL - REVOCATION IMPACT:
+/// Read-only helper for the update committee before signing
+/// `revoked_investment_record`. Emits the record's refund for every remaining refund
+/// year (from the current year, or START_YEAR_INDEX if later, through MAX_YEAR_INDEX)
+/// under the stage ratio in force for that year, and its share of every future profit
+/// round. Nothing is written.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Record PDA verification
+/// - total_invest_usdt must be non-zero
+/// 
+/// PARAMETERS:
+/// - total_invest_usdt: Total USDT invested, as stored in ProfitShareCache.total_invest_usdt
+///   by estimate_profit_share
+pub fn report_revocation_impact(
+    ctx: Context<ReportRevocationImpact>,
+    total_invest_usdt: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let record = &ctx.accounts.investment_record;
+
+    // AUDIT: Validate record PDA with info.investment_id
+    let (expected_record_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"record",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            record.batch_id.to_le_bytes().as_ref(),
+            record.record_id.to_le_bytes().as_ref(),
+            record.account_id.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(record.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+    require!(total_invest_usdt > 0, ErrorCode::InvalidTotalUsdt);
+
+    // AUDIT: Same year arithmetic as estimate_refund_share
+    let current_year = YearWindow::index_at(info.end_at, now);
+    let from_year_index = current_year.max(START_YEAR_INDEX);
+
+    let refund_hcoin_by_year = (from_year_index..=MAX_YEAR_INDEX)
+        .map(|year_index| {
+            RefundShareCache::compute_refund(
+                config.stage_ratio_for_year(year_index),
+                record.stage,
+                year_index,
+                record.amount_hcoin,
+            )
+            .and_then(|refund| info.hcoin_units(refund))
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    let freed_refund_hcoin = refund_hcoin_by_year
+        .iter()
+        .try_fold(0u64, |acc, x| acc.checked_add(*x))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    let (weighted_usdt, _) = info.weighted_profit_usdt(record.stage, record.amount_usdt)?;
+    let (freed_profit_ratio_bp, _) = ProfitShareCache::compute_share(weighted_usdt, 0, total_invest_usdt)?;
+
+    msg!(
+        "🧾 Revoking record_id={} frees {} H2COIN over years {}..={} and {} bp of future profit",
+        record.record_id,
+        freed_refund_hcoin,
+        from_year_index,
+        MAX_YEAR_INDEX,
+        freed_profit_ratio_bp
+    );
+
+    emit!(RevocationImpactReported {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id: record.batch_id,
+        record_id: record.record_id,
+        account_id: record.account_id,
+        already_revoked: record.revoked_at != 0,
+        from_year_index,
+        refund_hcoin_by_year,
+        freed_refund_hcoin,
+        freed_profit_ratio_bp,
+        reported_by: ctx.accounts.payer.key(),
+        reported_at: now,
+    });
+
+    Ok(())
+}
+
+/// Count the planned signers that belong to a role's whitelist
+/// 
+/// AUDIT CRITICAL - SIGNER PRE-VALIDATION:
+/// Lets client tooling check a planned signer set before building an expensive
+/// execution transaction. Only remaining_accounts flagged as signers are counted, and
+/// each whitelist member counts once. The count is returned via return data; nothing
+/// is written and nothing is authorized.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment info and config PDA validation
+/// 
+/// PARAMETERS:
+/// - role: Whitelist to check against (execute, update or withdraw)
+pub fn prevalidate_signers<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, PrevalidateSigners<'info>>,
+    role: SignerRole,
+) -> Result<u8> {
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Same signer filtering as enforce_signers
+    let signer_keys = extract_signer_keys(ctx.remaining_accounts);
+    let matched = config.count_whitelisted_signers(&signer_keys, role);
+
+    msg!(
+        "🔏 {:?} whitelist: {} of {} planned signers matched",
+        role,
+        matched,
+        signer_keys.len()
+    );
+
+    Ok(matched)
+}
+
+/// UTC date window of a refund year_index
+/// 
+/// AUDIT CRITICAL - YEAR WINDOW VIEW:
+/// Returns the [start_at, end_at) window of `year_index`, or of the year in force now
+/// when None, through return data. Uses the same YearWindow arithmetic as
+/// estimate_refund_share, so clients never compute year boundaries on their own.
+/// Nothing is written.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment info PDA validation
+/// - year_index must not exceed MAX_YEAR_INDEX
+/// 
+/// PARAMETERS:
+/// - year_index: Refund year to resolve; None resolves the current year
+pub fn year_index_window(ctx: Context<YearIndexWindow>, year_index: Option<u8>) -> Result<YearWindow> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+
+    let window = match year_index {
+        Some(year_index) => {
+            require!(year_index <= MAX_YEAR_INDEX, ErrorCode::RefundPeriodInvalid);
+            YearWindow::of(info.end_at, year_index)
+        }
+        None => YearWindow::at(info.end_at, now),
+    };
+
+    msg!(
+        "📅 year_index {}: [{}, {})",
+        window.year_index,
+        window.start_at,
+        window.end_at
+    );
+
+    Ok(window)
+}
+
+/// Emit an event stream checkpoint
+/// 
+/// AUDIT CRITICAL - EVENT STREAM CHECKPOINT:
+/// Emits Checkpoint with a per-investment sequence number, the withdrawal and batch
+/// counters and hashes of the key accounts. Indexers detect a gap when a sequence
+/// number is skipped, and resynchronize by comparing the hashes with the state they
+/// rebuilt from ingested events. Permissionless, at most once per
+/// CHECKPOINT_MIN_INTERVAL_SECS.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment info, config, batch counter and checkpoint PDA validation
+/// - Rate limited per investment
+pub fn emit_checkpoint(ctx: Context<EmitCheckpoint>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let checkpoint = &mut ctx.accounts.checkpoint_state;
+
+    // AUDIT: Bind a newly created checkpoint counter to its investment
+    if checkpoint.sequence == 0 {
+        checkpoint.investment_id = info.investment_id;
+        checkpoint.version = info.version;
+        checkpoint.bump = ctx.bumps.checkpoint_state;
+    } else {
+        require!(
+            now >= checkpoint.last_checkpoint_at.saturating_add(CHECKPOINT_MIN_INTERVAL_SECS),
+            ErrorCode::CheckpointTooSoon
+        );
+    }
+
+    let previous_checkpoint_at = checkpoint.last_checkpoint_at;
+    checkpoint.sequence = checkpoint.sequence
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    checkpoint.last_checkpoint_at = now;
+
+    let investment_info_hash = hash(&info.to_account_info().try_borrow_data()?).to_bytes();
+    let investment_config_hash =
+        hash(&ctx.accounts.investment_config.to_account_info().try_borrow_data()?).to_bytes();
+    let (batch_id, records_in_batch, batch_counter_hash) = match &ctx.accounts.batch_counter {
+        Some(counter) => (
+            counter.batch_id,
+            counter.records_in_batch,
+            hash(&counter.to_account_info().try_borrow_data()?).to_bytes(),
+        ),
+        None => (0, 0, [0u8; 32]),
+    };
+
+    msg!("📍 Checkpoint {} at {}", checkpoint.sequence, now);
+
+    emit!(Checkpoint {
+        investment_id: info.investment_id,
+        version: info.version,
+        sequence: checkpoint.sequence,
+        previous_checkpoint_at,
+        withdraw_sequence: info.withdraw_sequence,
+        batch_id,
+        records_in_batch,
+        investment_info_hash,
+        investment_config_hash,
+        batch_counter_hash,
+        emitted_by: ctx.accounts.payer.key(),
+        emitted_at: now,
+    });
+
+    Ok(())
+}
+
+/// Set an account-level payout split
+/// 
+/// AUDIT CRITICAL - PAYOUT SPLIT:
+/// Lets an account_id (typically a corporate investor) route its proceeds to up to
+/// MAX_PAYOUT_SPLITS wallets by percentage, e.g. 70/20/10. Estimation expands the
+/// account's pending shares into one share per split wallet when the split PDA is
+/// supplied among its remaining_accounts. Escrowed (held) shares are never split.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment must be active
+/// - Distinct non-default wallets, percentages summing to 100
+/// 
+/// PARAMETERS:
+/// - account_id: Investor account the split applies to
+/// - wallets: Payout wallets (unused slots are the default key)
+/// - percents: Percentage per wallet (unused slots are 0)
+pub fn set_payout_split(
+    ctx: Context<SetPayoutSplit>,
+    account_id: [u8; 15],
+    wallets: [Pubkey; MAX_PAYOUT_SPLITS],
+    percents: [u8; MAX_PAYOUT_SPLITS],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let split = &mut ctx.accounts.payout_split;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: Multisig validation from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    PayoutSplit::validate(&wallets, &percents)?;
+
+    split.investment_id = info.investment_id;
+    split.version = info.version;
+    split.account_id = account_id;
+    split.wallets = wallets;
+    split.percents = percents;
+    split.bump = ctx.bumps.payout_split;
+    split.updated_at = now;
+
+    msg!(
+        "🟢 Payout split set for account_id={}: {:?}",
+        String::from_utf8_lossy(&account_id).trim_end_matches('\0'),
+        percents
+    );
+
+    emit!(PayoutSplitUpdated {
+        investment_id: info.investment_id,
+        version: info.version,
+        account_id,
+        wallets,
+        percents,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Remove an account-level payout split
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - Caches estimated before removal keep their split entries
+/// 
+/// PARAMETERS:
+/// - account_id: Investor account whose split is removed
+pub fn clear_payout_split(ctx: Context<ClearPayoutSplit>, account_id: [u8; 15]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Multisig validation from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    msg!(
+        "🟢 Payout split cleared for account_id={}",
+        String::from_utf8_lossy(&account_id).trim_end_matches('\0')
+    );
+
+    emit!(PayoutSplitCleared {
+        investment_id: info.investment_id,
+        version: info.version,
+        account_id,
+        cleared_by: ctx.accounts.payer.key(),
+        cleared_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+
+//================ handle profit share and refund share ================
+/// Estimates the profit share for a single batch_id.
+/// This function checks investment state, validates the signer against whitelists,
+/// and generates a list of ProfitEntry items by matching each InvestmentRecord
+/// with its corresponding InvestorAccount using the `account_id` key.
+/// Pending shares of the same account_id and wallet are aggregated into one entry;
+/// the per-record split is emitted in `ProfitShareEstimated.breakdown`.
+/// The result is stored in the on-chain `ProfitShareCache` account.
+/// - `batch_id`: The target batch of records to estimate.
+/// - `total_profit_usdt`: The profit to distribute for this batch.
+/// - `priority_fee_per_cu`: Optional priority fee in micro-lamports per CU, added to the SOL estimate.
+/// - `payout_mint`: Mint the round is paid in; None = profit_mint. H2COIN or a mint registered
+///   in the VaultTokenRegistry lets a round be paid when profit_mint liquidity is short.
+/// - `overwrite`: Must be true to replace a live (unexecuted, unexpired, not cancelled) estimate.
+/// 
+/// The denominator is derived on-chain: InvestmentInfo.invested_usdt minus
+/// inactive_invested_usdt (acknowledged records of every batch, revoked ones included until
+/// release_revocation_hold), stage-weighted with the current multipliers. Held revocations
+/// take escrowed shares of that same total. It is stored as `ProfitShareCache.total_invest_usdt`.
+/// 
+/// Amounts use AMOUNT_DECIMALS; total_profit_usdt is expressed in the payout mint, so
+/// any conversion from USDT is agreed off-chain before estimation. Each share is converted
+/// to the payout mint's base units before it is stored.
+/// 
+/// A batch whose Merkle round is open or partly claimed cannot be estimated
+/// (`ProfitRoundConflict`); the same check runs again at execution.
+pub fn estimate_profit_share<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, EstimateProfitShare<'info>>,
+    batch_id: u16,
+    total_profit_usdt: u64,
+    priority_fee_per_cu: Option<u64>,
+    payout_mint: Option<Pubkey>,
+    overwrite: bool,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    cu_checkpoint("start");
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.cache;
+
+    // AUDIT: Validate cache PDA with info.investment_id to prevent address spoofing
+    let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"profit_cache",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(cache.key(), expected_cache_pda, ErrorCode::InvalidProfitCachePda);
+
+    // AUDIT: Rate-limit re-estimation so reviewed contents stay stable until signing;
+    // a cancelled cache may be re-estimated at once
+    require!(
+        cache.created_at == 0
+            || cache.cancelled_at != 0
+            || now.saturating_sub(cache.created_at) >= config.min_estimate_interval_secs as i64,
+        ErrorCode::EstimateTooSoon
+    );
+
+    // AUDIT: A live estimate (unexecuted, unexpired, not cancelled) is replaced only on request
+    let live_estimate = cache.created_at != 0
+        && cache.executed_at == 0
+        && cache.cancelled_at == 0
+        && now.saturating_sub(cache.created_at) <= ctx.accounts.global_config.share_cache_expire_secs;
+    require!(!live_estimate || overwrite, ErrorCode::EstimateOverwriteRequired);
+    // AUDIT: An unexecuted cache with paid claims is still the record of a round in progress;
+    // an executed cache is settled and its entries are reset below
+    require!(
+        cache.executed_at != 0 || cache.entries.iter().all(|e| e.status != EntryStatus::Paid),
+        ErrorCode::ProfitCacheHasPayouts
+    );
+    // AUDIT: A batch is paid through one kind of profit round at a time
+    require!(
+        !merkle_round_active(&ctx.accounts.merkle_cache, ctx.program_id, now)?,
+        ErrorCode::ProfitRoundConflict
+    );
+
+    // AUDIT: Validate investment is active and completed
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_ESTIMATE)?;
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    require!(info.investment_type == InvestmentType::Standard, ErrorCode::StandardOnly);
+
+    // AUDIT: The round may only be paid in a mint the vault holds on purpose
+    let (payout_mint, payout_decimals) = resolve_payout_mint(
+        info,
+        &ctx.accounts.global_config,
+        ctx.accounts.vault_token_registry.as_deref(),
+        payout_mint,
+    )?;
+
+    // AUDIT: Estimator role (1 member of execute ∪ update until assigned)
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Estimator, signer_infos)?;
+
+    // AUDIT: Check data accounts does not exceed 255 for gas limit protection
+    // (each record may be accompanied by its wallet's notification registration
+    // and its account's payout split)
+    require!(
+        data_accounts.len() <= MAX_ENTRIES_PER_BATCH * 3,
+        ErrorCode::TooManyRecordsLoaded
+    );
+    let registrations = collect_notification_registrations(ctx.program_id, data_accounts);
+    let splits = collect_payout_splits(ctx.program_id, &info.investment_id, &info.version, data_accounts);
+
+    // AUDIT: Mapping accounts to records with validation
+    let mut record_map = BTreeMap::new();
+
+    for acc_info in data_accounts.iter() {
+        match Account::<InvestmentRecord>::try_from(acc_info) {
+            Ok(record) => {
+                // AUDIT: Validate record PDA with info.investment_id
+                let (expected_record_pda, _bump) = Pubkey::find_program_address(
+                    &[
+                        b"record",
+                        info.investment_id.as_ref(),
+                        info.version.as_ref(),
+                        batch_id.to_le_bytes().as_ref(),
+                        record.record_id.to_le_bytes().as_ref(),
+                        record.account_id.as_ref(),
+                    ],
+                    ctx.program_id,
+                );
+                require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+                require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+                // AUDIT: Reject if record_id is duplicate
+                require!(
+                    !record_map.contains_key(&record.record_id),
+                    ErrorCode::DuplicateRecord
+                );
+
+                record_map.insert(record.record_id, record);
+            }
+            Err(_) if registrations.values().any(|key| key == acc_info.key) => {}
+            Err(_) if splits.values().any(|split| split.key() == acc_info.key()) => {}
+            Err(e) => {
+                msg!("🔴 Reason: {}, {:?}", acc_info.key(), e);
+            }
+        }
+    }
+
+    require!(
+        !record_map.is_empty() && record_map.len() <= MAX_ENTRIES_PER_BATCH,
+        ErrorCode::TooManyRecordsLoaded
+    );
+
+    // AUDIT: Compute profit entries with mathematical overflow protection
+    let mut entries: Vec<ProfitEntry> = Vec::new();
+    let mut breakdown: Vec<RecordShare> = Vec::new();
+    let mut skipped_revoked_record_ids: Vec<u64> = Vec::new();
+    let mut subtotal_profit_usdt: u64 = 0;
+
+    // AUDIT: Entries are created in ascending record_id order; sorted explicitly so
+    // entry_index never depends on the map's iteration order
+    let mut ordered_records: Vec<&Account<InvestmentRecord>> = record_map.values().collect();
+    ordered_records.sort_by_key(|record| record.record_id);
+
+    // AUDIT: Denominator from the on-chain aggregate; held revocations stay in it, so their
+    // escrowed shares come out of the same investment-wide total as every other batch's
+    let total_invest_usdt = info.active_profit_denominator()?;
+    require!(total_invest_usdt > 0, ErrorCode::InvalidTotalUsdt);
+
+    for record in ordered_records {
+        require!(record.account_id.len() == 15, ErrorCode::InvalidAccountIdLength);
+
+        // AUDIT: Provisional records never count until the investor acknowledges them
+        if !record.is_acknowledged() {
+            msg!(
+                "🟡 Skipping unacknowledged record_id={} for account_id={}",
+                record.record_id,
+                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+            );
+            continue;
+        }
+        
+        // AUDIT: Revoked records inside the holding period keep their share escrowed
+        let held = record.revocation_held(now);
+
+        // AUDIT: Skip revoked records once the holding period is over; listed in the event
+        if record.revoked_at != 0 && !held {
+           msg!(
+                "🟡 Skipping revoked record_id={} for account_id={}",
+                record.record_id,
+                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+            );
+            skipped_revoked_record_ids.push(record.record_id);
+            continue;
+        }
+
+        // AUDIT: Skip records still awaiting bind_wallet; paying Pubkey::default() would burn funds
+        if !held && record.wallet == Pubkey::default() {
+            msg!(
+                "🟡 Skipping unbound record_id={} for account_id={}",
+                record.record_id,
+                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+            );
+            continue;
+        }
+
+        let wallet = record.wallet;
+
+        // AUDIT: Weight the invested amount by the record's stage multiplier (1× while off)
+        let (weighted_usdt, multiplier_bp) = info.weighted_profit_usdt(record.stage, record.amount_usdt)?;
+
+        // AUDIT: Calculate ratio and amount with overflow protection
+        let (ratio_bp, amount) = ProfitShareCache::compute_share(
+            weighted_usdt,
+            total_profit_usdt,
+            total_invest_usdt,
+        )?;
+        // AUDIT: Shares are computed in AMOUNT_DECIMALS and paid in payout mint units
+        let amount = normalize_amount(amount, AMOUNT_DECIMALS, payout_decimals)?;
+
+        // AUDIT: Add to subtotal with overflow protection
+        subtotal_profit_usdt = subtotal_profit_usdt
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;        
+
+        // AUDIT: Pending shares follow the account's payout split; escrowed shares never do
+        let split = if held { None } else { splits.get(&record.account_id).map(|s| &**s) };
+
+        for (wallet, share) in PayoutSplit::shares(split, wallet, amount)? {
+            let share_ratio_bp = if amount == 0 {
+                0
+            } else {
+                ((ratio_bp as u128) * (share as u128) / (amount as u128)) as u16
+            };
+
+            // AUDIT: Pending shares of the same account, wallet and multiplier share one entry
+            // (one transfer); escrowed shares stay per record so each holding period resolves on its own
+            let existing = if held {
+                None
+            } else {
+                entries.iter().position(|e| {
+                    e.status == EntryStatus::Pending
+                        && e.account_id == record.account_id
+                        && e.wallet == wallet
+                        && e.multiplier_bp == multiplier_bp
+                })
+            };
+
+            let entry_index = match existing {
+                Some(index) => {
+                    let entry = &mut entries[index];
+                    entry.amount_usdt = entry
+                        .amount_usdt
+                        .checked_add(share)
+                        .ok_or(ErrorCode::NumericalOverflow)?;
+                    entry.ratio_bp = entry.ratio_bp.saturating_add(share_ratio_bp);
+                    index
+                }
+                None => {
+                    entries.push(ProfitEntry {
+                        entry_index: entries.len() as u16,
+                        account_id: record.account_id,
+                        wallet,
+                        amount_usdt: share,
+                        ratio_bp: share_ratio_bp,
+                        multiplier_bp,
+                        failure_count: 0,
+                        status: if held { EntryStatus::Escrowed } else { EntryStatus::Pending },
+                        held_record_id: held.then_some(record.record_id),
+                    });
+                    entries.len() - 1
+                }
+            };
+
+            breakdown.push(RecordShare {
+                record_id: record.record_id,
+                entry_index: entry_index as u16,
+                amount: share,
+            });
+        }
+    }
+
+    // AUDIT: Split payouts add entries; the cache holds at most MAX_ENTRIES_PER_BATCH
+    require!(entries.len() <= MAX_ENTRIES_PER_BATCH, ErrorCode::TooManyRecordsLoaded);
+
+    // AUDIT: Notify registered investors of their pending share
+    for entry in entries.iter().filter(|e| e.status == EntryStatus::Pending) {
+        if let Some(registration) = registrations.get(&entry.wallet) {
+            emit!(InvestorNotification {
+                investment_id: info.investment_id,
+                version: info.version,
+                batch_id,
+                year_index: None,
+                kind: NotificationKind::ProfitEstimated,
+                wallet: entry.wallet,
+                registration: *registration,
+                amount: entry.amount_usdt,
+                notified_at: now,
+            });
+        }
+    }
+
+    // AUDIT: Estimate SOL cost for execution, including the requested priority fee
+    let entry_count = entries.len() as u16;
+    let priority_fee_per_cu = priority_fee_per_cu.unwrap_or(0);
+    let subtotal_estimate_sol = estimate_execution_sol(&ctx.accounts.global_config, entry_count, priority_fee_per_cu)?;
+
+    // AUDIT: Store result to cache with validation
+    cache.batch_id = batch_id;
+    cache.investment_id = info.investment_id;
+    cache.subtotal_profit_usdt = subtotal_profit_usdt;
+    cache.total_profit_usdt = total_profit_usdt;
+    cache.total_invest_usdt = total_invest_usdt;
+    cache.payout_mint = payout_mint;
+    cache.payout_decimals = payout_decimals;
+    cache.subtotal_estimate_sol = subtotal_estimate_sol;
+    cache.priority_fee_per_cu = priority_fee_per_cu;
+    cache.executed_at = 0;
+    cache.claims_opened_at = 0;
+    cache.cancelled_at = 0;
+    cache.created_at = now;
+    cache.entries = entries;
+
+    // AUDIT: Self-audit of the freshly built cache (debug-invariants builds only)
+    #[cfg(feature = "debug-invariants")]
+    cache.assert_invariants();
+
+    // AUDIT: Emit event
+    emit!(ProfitShareEstimated {
+        batch_id,
+        investment_id: info.investment_id,
+        version: info.version,
+        subtotal_profit_usdt,
+        subtotal_estimate_sol,
+        priority_fee_per_cu,
+        payout_mint,
+        created_by: ctx.accounts.payer.key(),
+        created_at: now,
+        entry_count,
+        signers: signer_keys,
+        breakdown,
+        skipped_revoked_record_ids,
+    });
+
+    msg!(
+        "Estimated profit share: {} entries, {} of mint {} total",
+        entry_count,
+        subtotal_profit_usdt,
+        payout_mint
+    );
+
+    cu_checkpoint("end");
+    Ok(())
+}
+
+
+/// Estimates the refund share for a single `batch_id` in a specific refund year
+/// 
+/// AUDIT CRITICAL - REFUND SHARE ESTIMATION:
+/// This function estimates H2COIN refund distribution for a batch of investment records.
+/// It calculates refund shares based on investment stage ratios and stores results in cache.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment state validation (must be active and completed)
+/// - Signer validation against combined whitelists
+/// - Cache PDA verification to prevent address spoofing
+/// - Record PDA verification for each record
+/// - Batch size validation (max 255 records)
+/// - Duplicate record prevention
+/// - Refund period validation (year_index bounds checking)
+/// - Mathematical overflow protection in calculations
+/// - Revoked record filtering
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify cache PDA derivation is consistent
+/// [ ] Check signer validation against whitelists
+/// [ ] Review refund period validation logic
+/// [ ] Confirm mathematical calculations for overflow
+/// [ ] Validate record filtering logic
+/// [ ] Review cache storage security
+/// [ ] Validate event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - batch_id: The target batch of investment records to estimate
+/// - year_index: The number of years passed since the refund period started
+/// - priority_fee_per_cu: Optional priority fee in micro-lamports per CU, added to the SOL estimate
+/// 
+/// This uses the investment stage ratios to calculate H2COIN refunds per investor,
+/// storing the results in the `RefundShareCache` account. Pending refunds of the same
+/// account_id, wallet and stage are aggregated into one entry; the per-record split is
+/// emitted in `RefundShareEstimated.breakdown`.
+/// 
+/// - `batch_id`: The target batch of investment records to estimate.
+/// - `year_index`: The number of years passed since the refund period started (e.g., 0 = year 1, 1 = year 2, ...).
+/// 
+/// Refunds typically begin after a lock period (e.g., after year 3).
+/// 
+/// Record amount_hcoin uses AMOUNT_DECIMALS; each refund is converted to H2COIN mint
+/// base units (InvestmentInfo.hcoin_decimals) before it is stored.
+pub fn estimate_refund_share<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, EstimateRefundShare<'info>>,
+    batch_id: u16, 
+    year_index: u8,
+    priority_fee_per_cu: Option<u64>,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    cu_checkpoint("start");
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.cache;    
+
+
+
+    // Validate the expected vault PDA
+    let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"refund_cache",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            year_index.to_le_bytes().as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(cache.key(), expected_cache_pda, ErrorCode::InvalidRefundCachePda);
+
+    // AUDIT: A refund year is paid once; re-estimating an executed or partly paid cache
+    // would reset its entries to Pending and pay them again
+    require!(cache.executed_at == 0, ErrorCode::RefundAlreadyExecuted);
+    require!(
+        cache.entries.iter().all(|e| e.status != EntryStatus::Paid),
+        ErrorCode::RefundCacheHasPayouts
+    );
+
+    // Rate-limit re-estimation so reviewed contents stay stable until signing
+    require!(
+        cache.created_at == 0
+            || now.saturating_sub(cache.created_at) >= config.min_estimate_interval_secs as i64,
+        ErrorCode::EstimateTooSoon
+    );
+
+
+    // Validate state
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_ESTIMATE)?;
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+
+    // AUDIT: Estimator role (1 member of execute ∪ update until assigned)
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Estimator, signer_infos)?;
+
+    
+    // Check data accounts does not exceed 25 records, each with an optional notification
+    // registration and payout split
+    require!(
+        data_accounts.len() <= MAX_ENTRIES_PER_BATCH * 3,
+        ErrorCode::TooManyRecordsLoaded
+    );
+    let registrations = collect_notification_registrations(ctx.program_id, data_accounts);
+    let splits = collect_payout_splits(ctx.program_id, &info.investment_id, &info.version, data_accounts);
+
+
+    // Mapping accounts to records and records
+    let mut record_map = BTreeMap::new();
+
+    for acc_info in data_accounts.iter() {
+        
+        match Account::<InvestmentRecord>::try_from(acc_info) {
+            Ok(record) => {
+                // Validate record PDA with info.investment_id
+                let (expected_record_pda, _bump) = Pubkey::find_program_address(
+                    &[
+                        b"record",
+                        info.investment_id.as_ref(),
+                        info.version.as_ref(),
+                        batch_id.to_le_bytes().as_ref(),
+                        record.record_id.to_le_bytes().as_ref(),
+                        record.account_id.as_ref(),
+                    ],
+                    ctx.program_id,
+                );
+                require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+                require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+                // reject if record_id is duplicate or not
+                require!(
+                    !record_map.contains_key(&record.record_id),
+                    ErrorCode::DuplicateRecord
+                );
+
+                record_map.insert(record.record_id, record);
+            }
+            Err(_) if registrations.values().any(|key| key == acc_info.key) => {}
+            Err(_) if splits.values().any(|split| split.key() == acc_info.key()) => {}
+            Err(e) => {
+                msg!("🔴 Reason: {}, {:?}", acc_info.key(), e);
+            }
+        }
+    }
+
+    require!(
+        !record_map.is_empty() && record_map.len() <= MAX_ENTRIES_PER_BATCH,
+        ErrorCode::TooManyRecordsLoaded
+    );
+
+
+    // Calculate refund year index
+    let expect_year_index = YearWindow::index_at(info.end_at, now);
+    require!(
+        year_index <= expect_year_index && (START_YEAR_INDEX..=MAX_YEAR_INDEX).contains(&year_index),
+        ErrorCode::RefundPeriodInvalid
+    );
+    
+
+    // Compute refund entries
+    let mut entries: Vec<RefundEntry> = Vec::new();
+    let mut breakdown: Vec<RecordShare> = Vec::new();
+    let mut skipped_revoked_record_ids: Vec<u64> = Vec::new();
+    let mut subtotal_refund_hcoin: u64 = 0;
+
+    
+    // AUDIT: Entries are created in ascending record_id order; sorted explicitly so
+    // entry_index never depends on the map's iteration order
+    let mut ordered_records: Vec<&Account<InvestmentRecord>> = record_map.values().collect();
+    ordered_records.sort_by_key(|record| record.record_id);
+
+    for record in ordered_records {
+        require!(record.account_id.len() == 15, ErrorCode::InvalidAccountIdLength);
+
+        // AUDIT: Provisional records never count until the investor acknowledges them
+        if !record.is_acknowledged() {
+            msg!(
+                "🟡 Skipping unacknowledged record_id={} for account_id={}",
+                record.record_id,
+                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+            );
+            continue;
+        }
+
+        // AUDIT: Revoked records inside the holding period keep their share escrowed
+        let held = record.revocation_held(now);
+
+        // AUDIT: Skip revoked records once the holding period is over; listed in the event
+        if record.revoked_at != 0 && !held {
+            msg!(
+                "🟡 Skipping revoked record_id={} for account_id={}",
+                record.record_id,
+                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+            );
+            skipped_revoked_record_ids.push(record.record_id);
+            continue;
+        }
+
+        // AUDIT: Skip records still awaiting bind_wallet; paying Pubkey::default() would burn funds
+        if !held && record.wallet == Pubkey::default() {
+            msg!(
+                "🟡 Skipping unbound record_id={} for account_id={}",
+                record.record_id,
+                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+            );
+            continue;
+        }
+
+        let wallet = record.wallet;
+
+        let amount = RefundShareCache::compute_refund(
+            config.stage_ratio_for_year(year_index),
+            record.stage,
+            year_index,
+            record.amount_hcoin,
+        )?;
+        // AUDIT: Refunds are computed in AMOUNT_DECIMALS and paid in H2COIN mint units
+        let amount = info.hcoin_units(amount)?;
+
+        subtotal_refund_hcoin = subtotal_refund_hcoin
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        // Pending refunds follow the account's payout split; escrowed refunds never do
+        let split = if held { None } else { splits.get(&record.account_id).map(|s| &**s) };
+
+        for (wallet, share) in PayoutSplit::shares(split, wallet, amount)? {
+            // Pending refunds of the same account, wallet and stage share one entry (one transfer);
+            // escrowed refunds stay per record so each holding period resolves on its own
+            let existing = if held {
+                None
+            } else {
+                entries.iter().position(|e| {
+                    e.status == EntryStatus::Pending
+                        && e.account_id == record.account_id
+                        && e.wallet == wallet
+                        && e.stage == record.stage
+                })
+            };
+
+            let entry_index = match existing {
+                Some(index) => {
+                    let entry = &mut entries[index];
+                    entry.amount_hcoin = entry
+                        .amount_hcoin
+                        .checked_add(share)
+                        .ok_or(ErrorCode::NumericalOverflow)?;
+                    index
+                }
+                None => {
+                    entries.push(RefundEntry {
+                        entry_index: entries.len() as u16,
+                        account_id: record.account_id,
+                        wallet,
+                        amount_hcoin: share,
+                        stage: record.stage,
+                        failure_count: 0,
+                        status: if held { EntryStatus::Escrowed } else { EntryStatus::Pending },
+                        held_record_id: held.then_some(record.record_id),
+                    });
+                    entries.len() - 1
+                }
+            };
+
+            breakdown.push(RecordShare {
+                record_id: record.record_id,
+                entry_index: entry_index as u16,
+                amount: share,
+            });
+        }
+    }
+
+    // Split payouts add entries; the cache holds at most MAX_ENTRIES_PER_BATCH
+    require!(entries.len() <= MAX_ENTRIES_PER_BATCH, ErrorCode::TooManyRecordsLoaded);
+
+
+    // Notify registered investors of their pending refund
+    for entry in entries.iter().filter(|e| e.status == EntryStatus::Pending) {
+        if let Some(registration) = registrations.get(&entry.wallet) {
+            emit!(InvestorNotification {
+                investment_id: info.investment_id,
+                version: info.version,
+                batch_id,
+                year_index: Some(year_index),
+                kind: NotificationKind::RefundEstimated,
+                wallet: entry.wallet,
+                registration: *registration,
+                amount: entry.amount_hcoin,
+                notified_at: now,
+            });
+        }
+    }
+
+    // Estimate SOL cost, including the requested priority fee
+    let entry_count = entries.len() as u16;
+    let priority_fee_per_cu = priority_fee_per_cu.unwrap_or(0);
+    let subtotal_estimate_sol = estimate_execution_sol(&ctx.accounts.global_config, entry_count, priority_fee_per_cu)?;
+
+
+    // Store result to cache
+    cache.batch_id = batch_id;
+    cache.investment_id = info.investment_id;
+    cache.version = info.version;
+    cache.year_index = year_index;
+    cache.subtotal_refund_hcoin = subtotal_refund_hcoin;
+    cache.subtotal_estimate_sol = subtotal_estimate_sol;
+    cache.priority_fee_per_cu = priority_fee_per_cu;
+    cache.executed_at = 0;
+    cache.created_at = now;
+    cache.entries = entries;
+
+    // AUDIT: Self-audit of the freshly built cache (debug-invariants builds only)
+    #[cfg(feature = "debug-invariants")]
+    cache.assert_invariants();
+
+
+    // Emit event
+    emit!(RefundShareEstimated {
+        batch_id,
+        investment_id: cache.investment_id,
+        version: info.version,
+        year_index,
+        subtotal_refund_hcoin,
+        subtotal_estimate_sol,
+        priority_fee_per_cu,
+        created_by: ctx.accounts.payer.key(),
+        created_at: now,
+        entry_count,
+        signers: signer_keys,
+        breakdown,
+        skipped_revoked_record_ids,
+    });
+
+    msg!(
+        "🟢 Estimated refund share: year {}, entries {}, total {} H2COIN",
+        year_index,
+        entry_count,
+        subtotal_refund_hcoin
+    );
+
+    cu_checkpoint("end");
+    Ok(())
+}
+
+
+
+/// Executes the profit share for a given batch_id of records.
+/// Transfers USDT from the vault PDA to each investor's associated token account.
+/// Requires 3-of-5 multisig authorization.
+/// Executes a profit share distribution for a single batch_id.
+/// This function verifies the cache, vault balance, signer set, and distributes tokens
+/// to each investor's associated token account. Only entries associated with the given
+/// `batch_id` will be processed. After completion, the `ProfitShareCache` is marked
+/// as executed to prevent double payouts.
+/// 
+/// Entries are paid in ProfitShareCache.payout_mint, the mint chosen at estimation.
+/// 
+/// An optional `note` (e.g. "2025 Q2 profit, batch 7") is appended to the SPL Memo of
+/// every transfer so recipients and explorers see a human-readable descriptor.
+/// 
+/// An optional `reimburse_sol` pays the executing payer back from the vault's SOL, up to
+/// the cache's subtotal_estimate_sol, in the call that settles the cache.
+/// 
+/// A Merkle round of the batch published after estimation blocks execution while it is
+/// open or partly claimed (`ProfitRoundConflict`).
+/// 
+/// When `close_cache` is set and every entry has been paid, the cache account is
+/// closed in the same transaction and its rent is returned to the vault.
+/// 
+/// Entries that failed in this call are returned through return data as
+/// `Vec<PayoutFailure>`; an empty list means every attempted payout succeeded.
+pub fn execute_profit_share<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ExecuteProfitShare<'info>>,
+    batch_id: u16,
+    close_cache: bool,
+    expected_config_hash: [u8; 32],
+    note: Option<String>,
+    reimburse_sol: Option<u64>,
+) -> Result<Vec<PayoutFailure>>
+where
+    'c: 'info,
+{
+    cu_checkpoint("start");
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.cache;
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+
+    // AUDIT: Signers approved this exact configuration snapshot
+    info.require_config_hash(&expected_config_hash)?;
+
+    // AUDIT: The note is written into every transfer memo of this execution
+    validate_transfer_note(note.as_deref())?;
+
+    // AUDIT: The executor is never reimbursed more than the SOL estimate approved with the cache
+    if let Some(lamports) = reimburse_sol {
+        require!(lamports <= cache.subtotal_estimate_sol, ErrorCode::ReimbursementExceedsEstimate);
+    }
+
+    // Validate the profit_cache PDA
+    let (expected_cache_pda, _) = Pubkey::find_program_address(
+        &[
+            b"profit_cache",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(cache.key(), expected_cache_pda, ErrorCode::InvalidProfitCachePda);
+
+
+    // Validate the expected vault PDA
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+       &[
+           b"vault", 
+           info.investment_id.as_ref(),
+           info.vault_version.as_ref(),
+       ],
+       ctx.program_id,
+    );
+    require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
+
+
+    // Prepare PDA signer seeds
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[vault_bump],
+    ];
+
+
+    // reject if investment info has been deactived or has not been completed
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    require!(info.investment_type == InvestmentType::Standard, ErrorCode::StandardOnly);
+
+    // reject if cache is not initialized or batch_id mismatch
+    require!(!cache.to_account_info().data_is_empty(), ErrorCode::ProfitCacheNotFound);
+    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+
+
+    // reject if execuated_at is not 0 or cache has been executed
+    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
+    // reject if the estimate was voided by cancel_profit_cache
+    require!(cache.cancelled_at == 0, ErrorCode::ProfitCacheCancelled);
+    // reject if the cache is older than GlobalConfig.share_cache_expire_secs
+    require!(now - cache.created_at <= ctx.accounts.global_config.share_cache_expire_secs, ErrorCode::ProfitCacheExpired);
+    // reject if subtotal_profit_usdt is 0
+    require!(cache.subtotal_profit_usdt > 0, ErrorCode::InvalidTotalUsdt);
+    // AUDIT: A batch is paid through one kind of profit round at a time
+    require!(
+        !merkle_round_active(&ctx.accounts.merkle_cache, ctx.program_id, now)?,
+        ErrorCode::ProfitRoundConflict
+    );
+
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned)
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+    
+    // Only entries still pending are transferred; earlier executions may have paid some already
+    let outstanding_usdt = cache
+        .entries
+        .iter()
+        .filter(|e| e.status == EntryStatus::Pending)
+        .try_fold(0u64, |acc, e| acc.checked_add(e.amount_usdt))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // Token checks: the round is paid in the mint chosen at estimation
+    require_keys_eq!(mint.key(), cache.payout_mint, ErrorCode::InvalidTokenMint);
+    require!(mint.decimals == cache.payout_decimals, ErrorCode::MintDecimalsMismatch);
+    require_keys_eq!(vault_token_account.mint, mint.key(), ErrorCode::InvalidTokenMint);
+    require!(vault_token_account.amount >= outstanding_usdt, ErrorCode::InsufficientTokenBalance);
+    require!(vault.to_account_info().lamports() >= cache.subtotal_estimate_sol, ErrorCode::InsufficientSolBalance);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+
+    let mut total_transferred: u64 = 0;
+    let mut successes: Vec<Pubkey> = vec![];
+    let mut failures: Vec<PayoutFailure> = vec![];
+    let mut sol_reimbursed: u64 = 0;
+
+    // Registered investors are notified of each payout
+    let registrations = collect_notification_registrations(ctx.program_id, data_accounts);
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let memo_program = ctx.accounts.memo_program.to_account_info();
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let vault_info = vault.to_account_info();
+    let signer = Some(signer_seeds);
+    let decimals = mint.decimals;
+
+    // Previously failed entries go first so they are not starved by compute limits
+    let order = retry_order(
+        &cache.entries.iter().map(|e| (e.status, e.failure_count)).collect::<Vec<_>>()
+    );
+
+    cu_checkpoint("before payouts");
+    for (done, index) in order.iter().copied().enumerate() {
+        // AUDIT: Fail fast when the remaining budget cannot pay every entry still to go;
+        // the first check runs before any transfer, later ones catch an underestimated cost
+        require_cu_budget(order.len() - done)?;
+        let entry = &mut cache.entries[index];
+        let recipient = entry.wallet;
+        let recipient_ata = get_associated_token_address(&recipient, &mint.key());
+        let memo = with_transfer_note(
+            transfer_memo(
+                info,
+                "profit",
+                Some(&entry.account_id),
+                &[("batch", batch_id as u64), ("entry", entry.entry_index as u64)],
+            ),
+            note.as_deref(),
+        );
+
+        let result = match data_accounts
+            .iter()
+            .find(|acc| acc.key == &recipient_ata)
+        {
+            // transfer token to investors once the ATA proves to be a live account of (wallet, mint)
+            Some(recipient_ata_info) => validate_recipient_ata(recipient_ata_info, &recipient, &mint.key())
+                .and_then(|_| transfer_token_checked(
+                    token_program.clone(),
+                    vault_token_account.to_account_info(),
+                    recipient_ata_info.to_account_info(),
+                    mint_info.clone(),
+                    vault_info.clone(),
+                    signer,
+                    entry.amount_usdt,
+                    decimals,
+                    memo_program.clone(),
+                    &memo,
+                )),
+            None => err!(ErrorCode::MissingAssociatedTokenAccount),
+        };
+
+        match result {
+            Ok(_) => {
+                successes.push(recipient);
+                entry.status = EntryStatus::Paid;
+
+                total_transferred = total_transferred
+                .checked_add(entry.amount_usdt)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+
+                #[cfg(feature = "payout-receipts")]
+                emit!(PayoutReceipt {
+                    investment_id: info.investment_id,
+                    version: info.version,
+                    batch_id,
+                    year_index: None,
+                    entry_index: entry.entry_index,
+                    account_id: entry.account_id,
+                    wallet: recipient,
+                    mint: mint.key(),
+                    amount: entry.amount_usdt,
+                    paid_at: now,
+                });
+
+                if let Some(registration) = registrations.get(&recipient) {
+                    emit!(InvestorNotification {
+                        investment_id: info.investment_id,
+                        version: info.version,
+                        batch_id,
+                        year_index: None,
+                        kind: NotificationKind::ProfitPaid,
+                        wallet: recipient,
+                        registration: *registration,
+                        amount: entry.amount_usdt,
+                        notified_at: now,
+                    });
+                }
+            }
+            // AUDIT: A closed ATA is not the entry's fault; hold it for requeue_entry instead of
+            // counting a failure, so the batch is not re-estimated once the investor recreates it
+            Err(e) if is_closed_recipient_ata(&e) => {
+                msg!("🟡 Recipient ATA of {} is closed, entry requeued", recipient);
+                entry.status = EntryStatus::Requeued;
+
+                emit!(EntryRequeued {
+                    investment_id: info.investment_id,
+                    version: info.version,
+                    batch_id,
+                    year_index: None,
+                    entry_index: entry.entry_index,
+                    account_id: entry.account_id,
+                    wallet: recipient,
+                    amount: entry.amount_usdt,
+                    requeued_at: now,
+                });
+            }
+            Err(e) => {
+                // AUDIT: Log the specific reason (missing, stale/closed or mismatched ATA, CPI failure)
+                msg!("🔴 Payout to {} skipped: {:?}", recipient, e);
+                failures.push(PayoutFailure::new(entry.entry_index, &e));
+                entry.failure_count = entry.failure_count.saturating_add(1);
+
+                // Stop retrying after MAX_ENTRY_FAILURES; the amount stays in the vault for release_escrowed_entry
+                if entry.failure_count >= MAX_ENTRY_FAILURES {
+                    entry.status = EntryStatus::Escrowed;
+
+                    emit!(EntryEscrowed {
+                        investment_id: info.investment_id,
+                        version: info.version,
+                        batch_id,
+                        year_index: None,
+                        entry_index: entry.entry_index,
+                        account_id: entry.account_id,
+                        wallet: recipient,
+                        amount: entry.amount_usdt,
+                        failure_count: entry.failure_count,
+                        escrowed_at: now,
+                    });
+                }
+            }
+        }
+    }
+
+    // Cache is settled once no entry is pending (shared with claim_profit)
+    if settle_profit_cache(cache, ctx.accounts.schedule.as_mut(), batch_id, now)? {
+        msg!("🟢 All settled: {} paid now, {} USDT", successes.len(), total_transferred);
+
+        // AUDIT: Reimburse fronted fees only in the execution that settles the cache (once per cache)
+        if let Some(lamports) = reimburse_sol {
+            sol_reimbursed = reimburse_executor(
+                &ctx.accounts.system_program,
+                vault,
+                &ctx.accounts.payer,
+                signer_seeds,
+                lamports,
+            )?;
+        }
+    } else {
+        msg!("🟡 Partial success: {} succeeded, {} failed", successes.len(), failures.len());
+        if reimburse_sol.is_some() {
+            msg!("🟡 SOL reimbursement skipped: cache not settled");
+        }
+    }
+
+    // Self-audit of the cache after payouts (debug-invariants builds only)
+    #[cfg(feature = "debug-invariants")]
+    cache.assert_invariants();
+
+    // Close only when every entry was paid; escrowed entries must stay on-chain until released and paid
+    let cache_closed = close_cache
+        && cache.entries.iter().all(|e| e.status == EntryStatus::Paid);
+    if close_cache && !cache_closed {
+        msg!("🟡 Cache kept open: not every entry has been paid");
+    }
+
+
+    emit!(ProfitShareExecuted {
+        batch_id: cache.batch_id,
+        investment_id: info.investment_id,
+        version: info.version,
+        total_transfer_usdt: total_transferred,
+        executed_by: ctx.accounts.payer.key(),
+        executed_at: now,
+        signers: signer_keys,
+        cache_closed,
+        note,
+        sol_reimbursed,
+    });
+
+    if cache_closed {
+        // Rent goes back to the vault, which funds future executions
+        cache.close(vault.to_account_info())?;
+        msg!("🟢 Profit cache closed, rent returned to vault");
+    }
+
+    cu_checkpoint("end");
+    end_execution(&mut ctx.accounts.investment_info);
+
+    // AUDIT: Failed entries are returned as (entry_index, failure_reason) return data
+    Ok(failures)
+}
+
+
+/// Executes a refund share for a specific batch in a specific year
+/// 
+/// AUDIT CRITICAL - REFUND SHARE EXECUTION:
+/// This function executes H2COIN refund distribution for a batch of investment records.
+/// It transfers H2COIN from the vault PDA to each investor's associated token account.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Investment state validation (must be active and completed)
+/// - Cache PDA verification to prevent address spoofing
+/// - Vault PDA verification to prevent address spoofing
+/// - Cache validation (initialized, not executed, not expired)
+/// - Token mint validation (H2COIN only)
+/// - Balance sufficiency checks (SOL and H2COIN)
+/// - Cache execution prevention (double-payout protection)
+/// - Cache expiration validation (GlobalConfig.share_cache_expire_secs)
+/// - Total transfer amount validation
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify cache and vault PDA derivation is consistent
+/// [ ] Confirm multisig validation uses correct whitelist
+/// [ ] Check cache execution prevention logic
+/// [ ] Review balance sufficiency validation
+/// [ ] Validate token transfer security
+/// [ ] Confirm event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - batch_id: The target batch of records to execute
+/// - year_index: The refund year index to execute
+/// 
+/// Transfers H2COIN from the vault PDA to records' associated token accounts.
+/// Ensures 3-of-5 multisig, balance sufficiency, and cache validity before execution.
+/// An optional `note` is appended to the SPL Memo of every transfer.
+/// An optional `reimburse_sol` pays the executing payer back from the vault's SOL, up to
+/// the cache's subtotal_estimate_sol, in the call that settles the cache.
+/// When `close_cache` is set and every entry has been paid, the cache account is
+/// closed in the same transaction and its rent is returned to the vault.
+/// 
+/// Entries that failed in this call are returned through return data as
+/// `Vec<PayoutFailure>`; an empty list means every attempted payout succeeded.
+pub fn execute_refund_share<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ExecuteRefundShare<'info>>,
+    batch_id: u16,
+    year_index: u8,
+    close_cache: bool,
+    expected_config_hash: [u8; 32],
+    note: Option<String>,
+    reimburse_sol: Option<u64>,
+) -> Result<Vec<PayoutFailure>>
+where
+    'c: 'info,
+{
+    cu_checkpoint("start");
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.cache;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+    let mint = &ctx.accounts.mint;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+
+    // AUDIT: Signers approved this exact configuration snapshot
+    info.require_config_hash(&expected_config_hash)?;
+
+    // AUDIT: The note is written into every transfer memo of this execution
+    validate_transfer_note(note.as_deref())?;
+
+    // AUDIT: The executor is never reimbursed more than the SOL estimate approved with the cache
+    if let Some(lamports) = reimburse_sol {
+        require!(lamports <= cache.subtotal_estimate_sol, ErrorCode::ReimbursementExceedsEstimate);
+    }
+
+    // Validate the profit_cache PDA
+    let (expected_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"refund_cache",
+            info.investment_id.as_ref(),
+            info.version.as_ref(),
+            batch_id.to_le_bytes().as_ref(),
+            cache.year_index.to_le_bytes().as_ref(),            
+        ],
+        ctx.program_id,
+    );
+    require!(cache.year_index == year_index, ErrorCode::InvalidRefundCachePda);
+    require_keys_eq!(cache.key(), expected_pda, ErrorCode::InvalidRefundCachePda);
+
+
+    // Validate the expected vault PDA
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+       &[
+           b"vault", 
+           info.investment_id.as_ref(),
+           info.vault_version.as_ref(),
+       ],
+       ctx.program_id,
+   );
+   require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
+   
+   
+    // Prepare PDA signer seeds
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[vault_bump],
+    ];
+
+
+    // reject if investment info has been deactived or has not been completed
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    
+
+    // reject if investment info has been deactived or has not been completed
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+    // reject if cache is not initialized or batch_id mismatch
+    require!(!cache.to_account_info().data_is_empty(), ErrorCode::ProfitCacheNotFound);
+    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+
+
+    // reject if execuated_at is not 0 or cache has been executed
+    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
+    // reject if the cache is older than GlobalConfig.share_cache_expire_secs
+    require!(now - cache.created_at <= ctx.accounts.global_config.share_cache_expire_secs, ErrorCode::ProfitCacheExpired);
+    // reject if subtotal_refund_hcoin is 0
+    require!(cache.subtotal_refund_hcoin > 0, ErrorCode::InvalidTotalUsdt);
+
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned)
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+
+    // Token checks
+    require_keys_eq!(mint.key(), ctx.accounts.global_config.hcoin_mint, ErrorCode::InvalidTokenMint);
+    require!(mint.decimals == info.hcoin_decimals, ErrorCode::MintDecimalsMismatch);
+    require_keys_eq!(vault_token_account.mint, mint.key(), ErrorCode::InvalidTokenMint);
+    // Only entries still pending are transferred; earlier executions may have paid some already
+    let outstanding_hcoin = cache
+        .entries
+        .iter()
+        .filter(|e| e.status == EntryStatus::Pending)
+        .try_fold(0u64, |acc, e| acc.checked_add(e.amount_hcoin))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    require!(vault.lamports() >= cache.subtotal_estimate_sol, ErrorCode::InsufficientSolBalance);
+    require!(vault_token_account.amount >= outstanding_hcoin, ErrorCode::InsufficientTokenBalance);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+
+    // Loop through entries and process refund
+    let mut total_transferred = 0u64;
+    let mut successes: Vec<Pubkey> = vec![];
+    let mut failures: Vec<PayoutFailure> = vec![];
+    let mut sol_reimbursed = 0u64;
+
+    // Registered investors are notified of each payout
+    let registrations = collect_notification_registrations(ctx.program_id, data_accounts);
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let memo_program = ctx.accounts.memo_program.to_account_info();
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let vault_info = vault.to_account_info();
+    let signer = Some(signer_seeds);
+    let decimals = mint.decimals;
+
+    // Previously failed entries go first so they are not starved by compute limits
+    let order = retry_order(
+        &cache.entries.iter().map(|e| (e.status, e.failure_count)).collect::<Vec<_>>()
+    );
+
+    cu_checkpoint("before payouts");
+    for (done, index) in order.iter().copied().enumerate() {
+        // AUDIT: Fail fast when the remaining budget cannot pay every entry still to go;
+        // the first check runs before any transfer, later ones catch an underestimated cost
+        require_cu_budget(order.len() - done)?;
+        let entry = &mut cache.entries[index];
+        let recipient = entry.wallet;
+        let recipient_ata = get_associated_token_address(&recipient, &mint.key());
+        let memo = with_transfer_note(
+            transfer_memo(
+                info,
+                "refund",
+                Some(&entry.account_id),
+                &[
+                    ("batch", batch_id as u64),
+                    ("year", year_index as u64),
+                    ("entry", entry.entry_index as u64),
+                ],
+            ),
+            note.as_deref(),
+        );
+
+        let result = match data_accounts
+            .iter()
+            .find(|acc| acc.key == &recipient_ata)
+        {
+            // transfer token to investor once the ATA proves to be a live account of (wallet, mint)
+            Some(recipient_ata_info) => validate_recipient_ata(recipient_ata_info, &recipient, &mint.key())
+                .and_then(|_| transfer_token_checked(
+                    token_program.clone(),
+                    vault_token_account.to_account_info(),
+                    recipient_ata_info.to_account_info(),
+                    mint_info.clone(),
+                    vault_info.clone(),
+                    signer,
+                    entry.amount_hcoin,
+                    decimals,
+                    memo_program.clone(),
+                    &memo,
+                )),
+            None => err!(ErrorCode::MissingAssociatedTokenAccount),
+        };
+
+        match result {
+            Ok(_) => {
+                successes.push(recipient);
+                entry.status = EntryStatus::Paid;
+
+                total_transferred = total_transferred
+                .checked_add(entry.amount_hcoin)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+
+                #[cfg(feature = "payout-receipts")]
+                emit!(PayoutReceipt {
+                    investment_id: info.investment_id,
+                    version: info.version,
+                    batch_id,
+                    year_index: Some(year_index),
+                    entry_index: entry.entry_index,
+                    account_id: entry.account_id,
+                    wallet: recipient,
+                    mint: mint.key(),
+                    amount: entry.amount_hcoin,
+                    paid_at: now,
+                });
+
+                if let Some(registration) = registrations.get(&recipient) {
+                    emit!(InvestorNotification {
+                        investment_id: info.investment_id,
+                        version: info.version,
+                        batch_id,
+                        year_index: Some(year_index),
+                        kind: NotificationKind::RefundPaid,
+                        wallet: recipient,
+                        registration: *registration,
+                        amount: entry.amount_hcoin,
+                        notified_at: now,
+                    });
+                }
+            }
+            // AUDIT: A closed ATA is not the entry's fault; hold it for requeue_entry instead of
+            // counting a failure, so the batch is not re-estimated once the investor recreates it
+            Err(e) if is_closed_recipient_ata(&e) => {
+                msg!("🟡 Recipient ATA of {} is closed, entry requeued", recipient);
+                entry.status = EntryStatus::Requeued;
+
+                emit!(EntryRequeued {
+                    investment_id: info.investment_id,
+                    version: info.version,
+                    batch_id,
+                    year_index: Some(year_index),
+                    entry_index: entry.entry_index,
+                    account_id: entry.account_id,
+                    wallet: recipient,
+                    amount: entry.amount_hcoin,
+                    requeued_at: now,
+                });
+            }
+            Err(e) => {
+                // AUDIT: Log the specific reason (missing, stale/closed or mismatched ATA, CPI failure)
+                msg!("🔴 Payout to {} skipped: {:?}", recipient, e);
+                failures.push(PayoutFailure::new(entry.entry_index, &e));
+                entry.failure_count = entry.failure_count.saturating_add(1);
+
+                // Stop retrying after MAX_ENTRY_FAILURES; the amount stays in the vault for release_escrowed_entry
+                if entry.failure_count >= MAX_ENTRY_FAILURES {
+                    entry.status = EntryStatus::Escrowed;
+
+                    emit!(EntryEscrowed {
+                        investment_id: info.investment_id,
+                        version: info.version,
+                        batch_id,
+                        year_index: Some(year_index),
+                        entry_index: entry.entry_index,
+                        account_id: entry.account_id,
+                        wallet: recipient,
+                        amount: entry.amount_hcoin,
+                        failure_count: entry.failure_count,
+                        escrowed_at: now,
+                    });
+                }
+            }
+        }
+    }
+
+    // Cache is settled once no entry is pending (same reconciliation as profit caches)
+    if settle_refund_cache(cache, ctx.accounts.schedule.as_mut(), batch_id, year_index, now)? {
+        msg!("🟢 All settled: {} paid now, {} H2COIN", successes.len(), total_transferred);
+
+        // AUDIT: Reimburse fronted fees only in the execution that settles the cache (once per cache)
+        if let Some(lamports) = reimburse_sol {
+            sol_reimbursed = reimburse_executor(
+                &ctx.accounts.system_program,
+                vault,
+                &ctx.accounts.payer,
+                signer_seeds,
+                lamports,
+            )?;
+        }
+    } else {
+        msg!("🟡 Partial success: {} succeeded, {} failed", successes.len(), failures.len());
+        if reimburse_sol.is_some() {
+            msg!("🟡 SOL reimbursement skipped: cache not settled");
+        }
+    }
+
+    // Self-audit of the cache after payouts (debug-invariants builds only)
+    #[cfg(feature = "debug-invariants")]
+    cache.assert_invariants();
+
+    // Close only when every entry was paid; escrowed entries must stay on-chain until released and paid
+    let cache_closed = close_cache
+        && cache.entries.iter().all(|e| e.status == EntryStatus::Paid);
+    if close_cache && !cache_closed {
+        msg!("🟡 Cache kept open: not every entry has been paid");
+    }
+
+    emit!(RefundShareExecuted {
+        batch_id:cache.batch_id,
+        investment_id: info.investment_id,
+        version: info.version,
+        year_index: cache.year_index,
+        total_transfer_hcoin: total_transferred,
+        executed_by: ctx.accounts.payer.key(),
+        executed_at: now,
+        signers: signer_keys.clone(),
+        cache_closed,
+        note,
+        sol_reimbursed,
+    });
+
+    if cache_closed {
+        // Rent goes back to the vault, which funds future executions
+        cache.close(vault.to_account_info())?;
+        msg!("🟢 Refund cache closed, rent returned to vault");
+    }
+
+
+    cu_checkpoint("end");
+    end_execution(&mut ctx.accounts.investment_info);
+
+    // AUDIT: Failed entries are returned as (entry_index, failure_reason) return data
+    Ok(failures)
+}
+
+
+/// Pay a requeued profit or refund cache entry
+/// 
+/// AUDIT CRITICAL - REQUEUED ENTRIES:
+/// Execution marks an entry Requeued when its recipient ATA is closed, instead of
+/// counting a failure. Once the investor recreates the ATA, any single execute
+/// whitelist member (or the Squads authority) can pay that one entry here. The
+/// recipient and amount come from the cache, so one signature cannot redirect funds,
+/// and the batch is not re-estimated.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; distribution not paused and investment not frozen
+/// - Investment active and completed
+/// - Signer is an execute whitelist member or the Squads authority
+/// - Exactly one of profit_cache / refund_cache; entry must be Requeued
+/// - Mint matches the cache kind; recipient ATA is the live ATA of (wallet, mint)
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the cache
+/// - year_index: Refund year of refund_cache (ignored for profit_cache)
+/// - entry_index: Position of the entry in the cache
+pub fn requeue_entry(
+    ctx: Context<RequeueEntry>,
+    batch_id: u16,
+    year_index: u8,
+    entry_index: u16,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+    let member = ctx.accounts.member.key();
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+    // AUDIT: One execute member is enough; recipient and amount are fixed by the cache
+    require!(
+        config.execute_whitelist.contains(&member) || config.is_squads_signer(&[member]),
+        ErrorCode::UnauthorizedSigner
+    );
+
+    // AUDIT: Take the entry out of whichever cache was supplied
+    let (entry, expected_mint, cache_year_index, kind) =
+        match (ctx.accounts.profit_cache.as_mut(), ctx.accounts.refund_cache.as_mut()) {
+            (Some(cache), None) => {
+                let entry = cache
+                    .entries
+                    .get_mut(entry_index as usize)
+                    .filter(|e| e.status == EntryStatus::Requeued)
+                    .ok_or(ErrorCode::EntryNotRequeued)?;
+                entry.status = EntryStatus::Paid;
+                ((entry.entry_index, entry.account_id, entry.wallet, entry.amount_usdt), (cache.payout_mint, cache.payout_decimals), None, "profit")
+            }
+            (None, Some(cache)) => {
+                let entry = cache
+                    .entries
+                    .get_mut(entry_index as usize)
+                    .filter(|e| e.status == EntryStatus::Requeued)
+                    .ok_or(ErrorCode::EntryNotRequeued)?;
+                entry.status = EntryStatus::Paid;
+                ((entry.entry_index, entry.account_id, entry.wallet, entry.amount_hcoin), (ctx.accounts.global_config.hcoin_mint, info.hcoin_decimals), Some(year_index), "refund")
+            }
+            _ => return err!(ErrorCode::ReconcileCacheMismatch),
+        };
+    let (entry_index, account_id, wallet, amount) = entry;
+
+    require_keys_eq!(mint.key(), expected_mint.0, ErrorCode::InvalidTokenMint);
+    require!(mint.decimals == expected_mint.1, ErrorCode::MintDecimalsMismatch);
+    require!(vault_token_account.amount >= amount, ErrorCode::InsufficientTokenBalance);
+
+    // AUDIT: The recipient must have recreated the ATA of the entry wallet
+    let recipient_ata = &ctx.accounts.recipient_ata;
+    require_keys_eq!(
+        recipient_ata.key(),
+        get_associated_token_address(&wallet, &mint.key()),
+        ErrorCode::InvalidRecipientOwner
+    );
+    validate_recipient_ata(&recipient_ata.to_account_info(), &wallet, &mint.key())?;
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    require_keys_eq!(vault.key(), info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+    // AUDIT: Same memo as the original execution so reconciliation matches the entry
+    let mut refs = vec![("batch", batch_id as u64)];
+    if let Some(year) = cache_year_index {
+        refs.push(("year", year as u64));
+    }
+    refs.push(("entry", entry_index as u64));
+
+    transfer_token_checked(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account.to_account_info(),
+        recipient_ata.to_account_info(),
+        mint.to_account_info(),
+        vault.to_account_info(),
+        Some(signer_seeds),
+        amount,
+        mint.decimals,
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(info, kind, Some(&account_id), &refs),
+    )?;
+
+    msg!("🟢 Requeued {} entry {} paid to {}", kind, entry_index, wallet);
+
+    #[cfg(feature = "payout-receipts")]
+    emit!(PayoutReceipt {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        year_index: cache_year_index,
+        entry_index,
+        account_id,
+        wallet,
+        mint: mint.key(),
+        amount,
+        paid_at: now,
+    });
+
+    emit!(RequeuedEntryPaid {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        year_index: cache_year_index,
+        entry_index,
+        account_id,
+        wallet,
+        mint: mint.key(),
+        amount,
+        paid_by: member,
+        paid_at: now,
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+
+/// Release an entry escrowed after repeated payout failures
+/// 
+/// AUDIT CRITICAL - ESCROW RELEASE:
+/// Execution stops retrying an entry after MAX_ENTRY_FAILURES and marks it Escrowed;
+/// its amount stays in the vault. Once the cause is fixed (e.g. the investor created
+/// the ATA), the executors move the entry to Requeued here, and requeue_entry pays it
+/// to the same wallet. Recipient and amount still come from the cache.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Executor role (3-of-5 execute_whitelist until assigned)
+/// - Exactly one of profit_cache / refund_cache
+/// - Entry must be Escrowed with failure_count >= MAX_ENTRY_FAILURES; shares escrowed
+///   for a revocation hold are released only by resolve_revocation_contest
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the cache
+/// - year_index: Refund year of refund_cache (ignored for profit_cache)
+/// - entry_index: Position of the entry in the cache
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n]
+pub fn release_escrowed_entry(
+    ctx: Context<ReleaseEscrowedEntry>,
+    batch_id: u16,
+    year_index: u8,
+    entry_index: u16,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned)
+    let (signer_infos, _) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+    // AUDIT: Only entries escrowed by failed payouts; revocation holds have failure_count 0
+    let released = |status: EntryStatus, failure_count: u8| {
+        status == EntryStatus::Escrowed && failure_count >= MAX_ENTRY_FAILURES
+    };
+    let (account_id, wallet, amount, cache_year_index) =
+        match (ctx.accounts.profit_cache.as_mut(), ctx.accounts.refund_cache.as_mut()) {
+            (Some(cache), None) => {
+                let entry = cache
+                    .entries
+                    .get_mut(entry_index as usize)
+                    .filter(|e| released(e.status, e.failure_count))
+                    .ok_or(ErrorCode::EntryNotEscrowed)?;
+                entry.status = EntryStatus::Requeued;
+                (entry.account_id, entry.wallet, entry.amount_usdt, None)
+            }
+            (None, Some(cache)) => {
+                let entry = cache
+                    .entries
+                    .get_mut(entry_index as usize)
+                    .filter(|e| released(e.status, e.failure_count))
+                    .ok_or(ErrorCode::EntryNotEscrowed)?;
+                entry.status = EntryStatus::Requeued;
+                (entry.account_id, entry.wallet, entry.amount_hcoin, Some(year_index))
+            }
+            _ => return err!(ErrorCode::ReconcileCacheMismatch),
+        };
+
+    msg!("🟢 Escrowed entry {} of batch {} released to requeue", entry_index, batch_id);
+
+    emit!(EscrowedEntryReleased {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        year_index: cache_year_index,
+        entry_index,
+        account_id,
+        wallet,
+        amount,
+        released_by: ctx.accounts.payer.key(),
+        signers: signer_keys,
+        released_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Cancel a profit share estimate
+/// 
+/// AUDIT CRITICAL - ESTIMATE CANCELLATION:
+/// Voids a ProfitShareCache whose estimate is wrong (e.g. a mistyped
+/// total_profit_usdt) before anything was paid: the entries and subtotal are
+/// cleared, claims are closed and cancelled_at is set. Execution and claim opening
+/// then fail with ProfitCacheCancelled, and the batch may be re-estimated at once
+/// instead of after min_estimate_interval_secs or the cache expiry.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Executor role (3-of-5 execute_whitelist until assigned)
+/// - Cache not executed, not already cancelled, and no entry Paid
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the cache
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n]
+pub fn cancel_profit_cache(ctx: Context<CancelProfitCache>, batch_id: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.cache;
+
+    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
+    require!(cache.cancelled_at == 0, ErrorCode::ProfitCacheCancelled);
+    // AUDIT: Once an entry is paid the cache is the record of that payout
+    require!(
+        cache.entries.iter().all(|e| e.status != EntryStatus::Paid),
+        ErrorCode::ProfitCacheHasPayouts
+    );
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned)
+    let (signer_infos, _) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+    let subtotal_profit_usdt = cache.subtotal_profit_usdt;
+    let entry_count = cache.entries.len() as u16;
+
+    cache.entries.clear();
+    cache.subtotal_profit_usdt = 0;
+    cache.subtotal_estimate_sol = 0;
+    cache.claims_opened_at = 0;
+    cache.cancelled_at = now;
+
+    msg!("🟢 Profit batch {} cancelled: {} entries voided", batch_id, entry_count);
+
+    emit!(ProfitCacheCancelled {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        subtotal_profit_usdt,
+        total_profit_usdt: cache.total_profit_usdt,
+        entry_count,
+        cancelled_by: ctx.accounts.payer.key(),
+        signers: signer_keys,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Open a profit cache for investor claims
+/// 
+/// AUDIT CRITICAL - PULL-BASED PROFIT CLAIMS:
+/// Push execution needs every recipient ATA in one transaction. Once the executors
+/// open a cache here, each investor may instead pull their own Pending entry with
+/// claim_profit, paying the transaction and any ATA rent themselves. Execution keeps
+/// working on the same cache; both paths pay only Pending entries.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; distribution not paused and investment not frozen
+/// - Investment active, completed and standard; config hash approved by the signers
+/// - Executor role (3-of-5 execute_whitelist until assigned)
+/// - Cache not executed, not expired and not already opened
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the cache
+/// - expected_config_hash: InvestmentInfo.config_hash the signers approved
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n]
+pub fn open_profit_claims(
+    ctx: Context<OpenProfitClaims>,
+    batch_id: u16,
+    expected_config_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.cache;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+
+    // AUDIT: Signers approved this exact configuration snapshot
+    info.require_config_hash(&expected_config_hash)?;
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    require!(info.investment_type == InvestmentType::Standard, ErrorCode::StandardOnly);
+
+    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
+    require!(cache.cancelled_at == 0, ErrorCode::ProfitCacheCancelled);
+    require!(now - cache.created_at <= ctx.accounts.global_config.share_cache_expire_secs, ErrorCode::ProfitCacheExpired);
+    require!(cache.subtotal_profit_usdt > 0, ErrorCode::InvalidTotalUsdt);
+    require!(cache.claims_opened_at == 0, ErrorCode::ProfitClaimsAlreadyOpen);
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned), as for execution
+    let (signer_infos, _) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+    cache.claims_opened_at = now;
+    let pending_entries = cache
+        .entries
+        .iter()
+        .filter(|e| e.status == EntryStatus::Pending)
+        .count() as u16;
+
+    msg!("🟢 Profit batch {} opened for claims: {} pending entries", batch_id, pending_entries);
+
+    emit!(ProfitClaimsOpened {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        payout_mint: cache.payout_mint,
+        pending_entries,
+        opened_by: ctx.accounts.payer.key(),
+        signers: signer_keys,
+        opened_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Claim one profit cache entry as its wallet
+/// 
+/// AUDIT CRITICAL - PULL-BASED PROFIT CLAIMS:
+/// The entry wallet signs and receives its Pending share from the vault into its own
+/// ATA (created if missing). The amount comes from the cache, so the claimant chooses
+/// nothing but the entry. A claim that leaves no Pending entry settles the cache
+/// through settle_profit_cache, exactly as the last execution would.
+/// 
+/// The fee payer is the claimant, or GlobalConfig.claim_relayer for investors without
+/// SOL. A sponsoring relayer pays the transaction and any ATA rent and is reimbursed
+/// estimate_sol_per_entry from the vault's SOL, drawn from the cache's
+/// subtotal_estimate_sol so execution can no longer reimburse the same budget.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; distribution not paused and investment not frozen
+/// - Investment active and completed; cache opened by open_profit_claims
+/// - Cache not older than GlobalConfig.share_cache_expire_secs
+/// - Signer is the entry wallet; entry must be Pending
+/// - Mint and decimals match the cache's payout mint
+/// - A fee payer other than the claimant must be the configured claim relayer
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the cache
+/// - entry_index: Position of the entry in the cache
+pub fn claim_profit(
+    ctx: Context<ClaimProfit>,
+    batch_id: u16,
+    entry_index: u16,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let cache = &mut ctx.accounts.cache;
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+    let claimant = ctx.accounts.claimant.key();
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+    // AUDIT: Only caches the executors opened can be pulled
+    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+    require!(cache.claims_opened_at != 0, ErrorCode::ProfitClaimsNotOpen);
+    // AUDIT: Claims close with the cache, as execution does; a stale estimate is not paid out
+    require!(
+        now.saturating_sub(cache.created_at) <= ctx.accounts.global_config.share_cache_expire_secs,
+        ErrorCode::ProfitCacheExpired
+    );
+
+    require_keys_eq!(mint.key(), cache.payout_mint, ErrorCode::InvalidTokenMint);
+    require!(mint.decimals == cache.payout_decimals, ErrorCode::MintDecimalsMismatch);
+
+    let entry = cache
+        .entries
+        .get_mut(entry_index as usize)
+        .filter(|e| e.status == EntryStatus::Pending)
+        .ok_or(ErrorCode::EntryNotClaimable)?;
+    require_keys_eq!(entry.wallet, claimant, ErrorCode::ClaimantNotEntryWallet);
+    entry.status = EntryStatus::Paid;
+    let (account_id, amount) = (entry.account_id, entry.amount_usdt);
+
+    require!(vault_token_account.amount >= amount, ErrorCode::InsufficientTokenBalance);
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    require_keys_eq!(vault.key(), info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+    // AUDIT: Same memo as execution so reconciliation matches the entry
+    transfer_token_checked(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account.to_account_info(),
+        ctx.accounts.claimant_token_account.to_account_info(),
+        mint.to_account_info(),
+        vault.to_account_info(),
+        Some(signer_seeds),
+        amount,
+        mint.decimals,
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(
+            info,
+            "profit",
+            Some(&account_id),
+            &[("batch", batch_id as u64), ("entry", entry_index as u64)],
+        ),
+    )?;
+
+    // AUDIT: A sponsoring relayer is reimbursed out of the cache's SOL estimate
+    let sol_reimbursed = sponsor_claim(
+        &ctx.accounts.global_config,
+        &ctx.accounts.system_program,
+        vault,
+        &ctx.accounts.fee_payer,
+        &claimant,
+        signer_seeds,
+        Some(&mut cache.subtotal_estimate_sol),
+    )?;
+
+    // AUDIT: A claim that leaves no entry pending settles the cache exactly as execution does
+    let settled = settle_profit_cache(cache, ctx.accounts.schedule.as_mut(), batch_id, now)?;
+    if settled {
+        msg!("🟢 All settled by claim of entry {}", entry_index);
+    }
+
+    msg!("🟢 Profit entry {} claimed by {}", entry_index, claimant);
+
+    #[cfg(feature = "payout-receipts")]
+    emit!(PayoutReceipt {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        year_index: None,
+        entry_index,
+        account_id,
+        wallet: claimant,
+        mint: mint.key(),
+        amount,
+        paid_at: now,
+    });
+
+    emit!(ProfitClaimed {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        entry_index,
+        account_id,
+        wallet: claimant,
+        mint: mint.key(),
+        amount,
+        settled,
+        fee_payer: ctx.accounts.fee_payer.key(),
+        sol_reimbursed,
+        claimed_at: now,
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+
+/// Publish the Merkle root of a profit batch
+/// 
+/// AUDIT CRITICAL - MERKLE PROFIT DISTRIBUTION:
+/// For investor sets too large for a ProfitShareCache, the entries are computed
+/// off-chain and only their Merkle root and totals are stored on-chain. Each investor
+/// then proves their own leaf in claim_profit_with_proof.
+/// 
+/// TRUST MODEL:
+/// The program cannot re-derive the root from the records, and neither the subtotal nor
+/// any leaf amount is checked against them. The Executor role signs the root, the entry
+/// count and the subtotal, and that signed root is trusted as is, in place of the
+/// estimate-then-execute review of the cache flow. On-chain, claims are bounded only by
+/// the signed subtotal, one receipt per leaf, the claim window and cancellation.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; estimation not paused and investment not frozen
+/// - Investment active, completed and standard; config hash approved by the signers
+/// - Executor role (3-of-5 execute_whitelist until assigned)
+/// - Non-zero root, entry count and subtotal; payout mint held by the vault on purpose
+/// - No live or executed ProfitShareCache round for the same batch
+/// - An existing root is replaced only while nothing has been claimed
+/// 
+/// Claims are open until created_at + GlobalConfig.share_cache_expire_secs, or until
+/// cancel_profit_merkle stops the round.
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the distribution
+/// - merkle_root: Root over the leaves (see ProfitMerkleCache::leaf / verify)
+/// - entry_count: Number of leaves
+/// - subtotal_profit_usdt: Sum of all leaf amounts in payout mint base units
+/// - payout_mint: None for the profit mint, or H2COIN / a registered vault mint
+/// - expected_config_hash: InvestmentInfo.config_hash the signers approved
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n]
+pub fn estimate_profit_share_merkle(
+    ctx: Context<EstimateProfitShareMerkle>,
+    batch_id: u16,
+    merkle_root: [u8; 32],
+    entry_count: u32,
+    subtotal_profit_usdt: u64,
+    payout_mint: Option<Pubkey>,
+    expected_config_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_ESTIMATE)?;
+
+    // AUDIT: Signers approved this exact configuration snapshot
+    info.require_config_hash(&expected_config_hash)?;
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    require!(info.investment_type == InvestmentType::Standard, ErrorCode::StandardOnly);
+
+    require!(
+        merkle_root != [0u8; 32] && entry_count > 0 && subtotal_profit_usdt > 0,
+        ErrorCode::InvalidMerkleRoot
+    );
+
+    // AUDIT: The round may only be paid in a mint the vault holds on purpose
+    let (payout_mint, payout_decimals) = resolve_payout_mint(
+        info,
+        &ctx.accounts.global_config,
+        ctx.accounts.vault_token_registry.as_deref(),
+        payout_mint,
+    )?;
+
+    // AUDIT: A batch is paid through one kind of profit round at a time
+    require!(
+        !profit_cache_round_active(
+            &ctx.accounts.profit_cache,
+            ctx.program_id,
+            now,
+            ctx.accounts.global_config.share_cache_expire_secs,
+        )?,
+        ErrorCode::ProfitRoundConflict
+    );
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned), as for execution
+    let (signer_infos, _) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+    let expires_at = now
+        .checked_add(ctx.accounts.global_config.share_cache_expire_secs)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    let cache = &mut ctx.accounts.merkle_cache;
+    // AUDIT: Receipts are keyed by leaf index, so a root cannot change once claimed against
+    let replaced = cache.created_at != 0;
+    require!(cache.claimed_count == 0, ErrorCode::MerkleClaimsStarted);
+
+    cache.batch_id = batch_id;
+    cache.investment_id = info.investment_id;
+    cache.version = info.version;
+    cache.merkle_root = merkle_root;
+    cache.entry_count = entry_count;
+    cache.subtotal_profit_usdt = subtotal_profit_usdt;
+    cache.claimed_usdt = 0;
+    cache.payout_mint = payout_mint;
+    cache.payout_decimals = payout_decimals;
+    cache.created_by = ctx.accounts.payer.key();
+    cache.created_at = now;
+    cache.executed_at = 0;
+    cache.expires_at = expires_at;
+    cache.cancelled_at = 0;
+    cache.bump = ctx.bumps.merkle_cache;
+
+    msg!(
+        "🟢 Merkle root published for batch {}: {} entries, {} in {}",
+        batch_id,
+        entry_count,
+        subtotal_profit_usdt,
+        payout_mint
+    );
+
+    emit!(ProfitMerkleRootPublished {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        merkle_root,
+        entry_count,
+        subtotal_profit_usdt,
+        payout_mint,
+        replaced,
+        expires_at,
+        created_by: ctx.accounts.payer.key(),
+        signers: signer_keys,
+        created_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Claim one Merkle profit entry with a proof
+/// 
+/// AUDIT CRITICAL - MERKLE PROFIT DISTRIBUTION:
+/// The claimant supplies its leaf (index, account_id, amount) and the sibling hashes up
+/// to the root. The leaf is hashed with the claimant's own wallet, so a proof only pays
+/// the wallet it was built for. The MerkleClaimReceipt PDA of the leaf index is created
+/// in the same instruction; a second claim of that index fails at account creation.
+/// 
+/// As in claim_profit, GlobalConfig.claim_relayer may pay the transaction and rents
+/// for the claimant and is reimbursed estimate_sol_per_entry from the vault's SOL; the
+/// receipt limits this to once per leaf.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; distribution not paused and investment not frozen
+/// - Investment active and completed; round not cancelled, expired or fully claimed
+/// - entry_index < entry_count; proof verifies against merkle_root
+/// - Claimed total never exceeds subtotal_profit_usdt
+/// - Mint and decimals match the published payout mint
+/// - A fee payer other than the claimant must be the configured claim relayer
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the distribution
+/// - entry_index: Leaf index
+/// - account_id: Investor account of the leaf
+/// - amount: Leaf amount in payout mint base units
+/// - proof: Sibling hashes from the leaf to the root (at most MAX_MERKLE_PROOF_LEN)
+pub fn claim_profit_with_proof(
+    ctx: Context<ClaimProfitWithProof>,
+    batch_id: u16,
+    entry_index: u32,
+    account_id: [u8; 15],
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let cache = &mut ctx.accounts.merkle_cache;
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+    let claimant = ctx.accounts.claimant.key();
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+    // AUDIT: Claims stop once the round is cancelled or its claim window is over
+    require!(cache.cancelled_at == 0, ErrorCode::MerkleRoundCancelled);
+    require!(now < cache.expires_at, ErrorCode::MerkleRoundExpired);
+
+    // AUDIT: The leaf binds investment, batch, index, account, claimant wallet and amount
+    require!(entry_index < cache.entry_count && amount > 0, ErrorCode::InvalidMerkleProof);
+    let leaf = cache.leaf(entry_index, &account_id, &claimant, amount);
+    require!(cache.verify(&proof, leaf), ErrorCode::InvalidMerkleProof);
+
+    // AUDIT: A root whose leaves overstate the subtotal cannot drain more than the subtotal
+    let claimed_usdt = cache
+        .claimed_usdt
+        .checked_add(amount)
+        .filter(|claimed| *claimed <= cache.subtotal_profit_usdt)
+        .ok_or(ErrorCode::TotalShareMismatch)?;
+
+    require_keys_eq!(mint.key(), cache.payout_mint, ErrorCode::InvalidTokenMint);
+    require!(mint.decimals == cache.payout_decimals, ErrorCode::MintDecimalsMismatch);
+    require!(vault_token_account.amount >= amount, ErrorCode::InsufficientTokenBalance);
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    require_keys_eq!(vault.key(), info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+    transfer_token_checked(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account.to_account_info(),
+        ctx.accounts.claimant_token_account.to_account_info(),
+        mint.to_account_info(),
+        vault.to_account_info(),
+        Some(signer_seeds),
+        amount,
+        mint.decimals,
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(
+            info,
+            "profit",
+            Some(&account_id),
+            &[("batch", batch_id as u64), ("entry", entry_index as u64)],
+        ),
+    )?;
+
+    // AUDIT: A sponsoring relayer is reimbursed once per leaf
+    let sol_reimbursed = sponsor_claim(
+        &ctx.accounts.global_config,
+        &ctx.accounts.system_program,
+        vault,
+        &ctx.accounts.fee_payer,
+        &claimant,
+        signer_seeds,
+        None,
+    )?;
+
+    cache.claimed_usdt = claimed_usdt;
+    cache.claimed_count = cache
+        .claimed_count
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let settled = cache.claimed_count == cache.entry_count;
+    if settled {
+        cache.executed_at = now;
+        msg!("🟢 All {} Merkle entries of batch {} claimed", cache.entry_count, batch_id);
+    }
+
+    let receipt = &mut ctx.accounts.claim_receipt;
+    receipt.investment_id = info.investment_id;
+    receipt.version = info.version;
+    receipt.batch_id = batch_id;
+    receipt.entry_index = entry_index;
+    receipt.wallet = claimant;
+    receipt.amount = amount;
+    receipt.claimed_at = now;
+    receipt.bump = ctx.bumps.claim_receipt;
+
+    msg!("🟢 Merkle profit entry {} claimed by {}", entry_index, claimant);
+
+    emit!(MerkleProfitClaimed {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        entry_index,
+        account_id,
+        wallet: claimant,
+        mint: mint.key(),
+        amount,
+        settled,
+        fee_payer: ctx.accounts.fee_payer.key(),
+        sol_reimbursed,
+        claimed_at: now,
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+
+/// Cancel a Merkle profit round
+/// 
+/// AUDIT CRITICAL - MERKLE ROUND CANCELLATION:
+/// Stops claims of a published root, e.g. one found wrong after publication or a
+/// round whose claim window is over. Claims already made stay paid and keep their
+/// receipts; the unclaimed remainder is no longer reserved in the vault. A round
+/// nobody claimed from can then be published again.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Executor role (3-of-5 execute_whitelist until assigned)
+/// - Round published, not fully claimed and not already cancelled
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the distribution
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n]
+pub fn cancel_profit_merkle(ctx: Context<CancelProfitMerkle>, batch_id: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let cache = &mut ctx.accounts.merkle_cache;
+
+    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
+    require!(cache.cancelled_at == 0, ErrorCode::MerkleRoundCancelled);
+
+    // AUDIT: Executor role (3-of-5 execute_whitelist until assigned)
+    let (signer_infos, _) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_role(ctx.accounts.role_config.as_deref(), Role::Executor, signer_infos)?;
+
+    cache.cancelled_at = now;
+    let unclaimed_usdt = cache
+        .subtotal_profit_usdt
+        .checked_sub(cache.claimed_usdt)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    msg!(
+        "🟢 Merkle batch {} cancelled: {} of {} entries claimed, {} unclaimed",
+        batch_id,
+        cache.claimed_count,
+        cache.entry_count,
+        unclaimed_usdt
+    );
+
+    emit!(ProfitMerkleCancelled {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        merkle_root: cache.merkle_root,
+        claimed_count: cache.claimed_count,
+        claimed_usdt: cache.claimed_usdt,
+        unclaimed_usdt,
+        cancelled_by: ctx.accounts.payer.key(),
+        signers: signer_keys,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Reconciles a batch's investment records against its profit or refund cache
+/// 
+/// AUDIT CRITICAL - BATCH RECONCILIATION:
+/// Re-derives the expected entry of every distributable record (same rules, math and
+/// per-account/wallet aggregation as estimation) and matches it against the cache. Emits a `BatchReconciled` report listing
+/// records missing from the cache, records whose amount differs, and cache entries no
+/// record accounts for. The instruction never modifies state.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be in execute_whitelist or update_whitelist
+/// - Exactly one of profit_cache / refund_cache must be provided
+/// - Record PDA and batch_id validation, duplicate record rejection
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch to reconcile
+/// - year_index: Refund year of refund_cache (ignored for profit_cache)
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n, record_1, ..., record_n, (payout splits)]
+pub fn reconcile_batch<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ReconcileBatch<'info>>,
+    batch_id: u16,
+    year_index: u8,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+
+    // AUDIT: Validate signer against combined whitelists
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    require!(
+        signer_keys
+            .iter()
+            .any(|key| config.execute_whitelist.contains(key) || config.update_whitelist.contains(key)),
+        ErrorCode::UnauthorizedSigner
+    );
+
+    // AUDIT: Collect cache entries as (account_id, wallet, amount)
+    type CacheEntry = ([u8; 15], Pubkey, u64);
+    let (entries, cache_year_index): (Vec<CacheEntry>, Option<u8>) =
+        match (&ctx.accounts.profit_cache, &ctx.accounts.refund_cache) {
+            (Some(cache), None) => (
+                cache.entries.iter().map(|e| (e.account_id, e.wallet, e.amount_usdt)).collect(),
+                None,
+            ),
+            (None, Some(cache)) => (
+                cache.entries.iter().map(|e| (e.account_id, e.wallet, e.amount_hcoin)).collect(),
+                Some(year_index),
+            ),
+            _ => return err!(ErrorCode::ReconcileCacheMismatch),
+        };
+
+    // AUDIT: Load records with the same validation as estimation; payout splits
+    // supplied alongside them are applied the same way
+    require!(
+        data_accounts.len() <= MAX_ENTRIES_PER_BATCH * 2,
+        ErrorCode::TooManyRecordsLoaded
+    );
+    let splits = collect_payout_splits(ctx.program_id, &info.investment_id, &info.version, data_accounts);
+
+    let mut record_map = BTreeMap::new();
+    for acc_info in data_accounts.iter() {
+        if splits.values().any(|split| split.key() == acc_info.key()) {
+            continue;
+        }
+        let record = Account::<InvestmentRecord>::try_from(acc_info)?;
+        let (expected_record_pda, _bump) = Pubkey::find_program_address(
+            &[
+                b"record",
+                info.investment_id.as_ref(),
+                info.version.as_ref(),
+                batch_id.to_le_bytes().as_ref(),
+                record.record_id.to_le_bytes().as_ref(),
+                record.account_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+        require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+        require!(
+            !record_map.contains_key(&record.record_id),
+            ErrorCode::DuplicateRecord
+        );
+        record_map.insert(record.record_id, record);
+    }
+
+    // AUDIT: Group distributable records the way estimation aggregates them:
+    // pending shares per (account_id, wallet, stage / profit multiplier), held revocations per record
+    type GroupKey = ([u8; 15], Pubkey, u16, Option<u64>);
+    let mut groups: BTreeMap<GroupKey, (u64, Vec<u64>)> = BTreeMap::new();
+    let mut records_checked: u16 = 0;
+
+    for (record_id, record) in record_map.iter() {
+        if !record.is_distributable(now) {
+            continue;
+        }
+        records_checked += 1;
+
+        // AUDIT: Same math as estimation, using the inputs stored on the cache and the
+        // current stage multipliers (a multiplier change since estimation shows as mismatches)
+        let (expected, stage) = if let Some(cache) = &ctx.accounts.profit_cache {
+            let (weighted_usdt, multiplier_bp) = info.weighted_profit_usdt(record.stage, record.amount_usdt)?;
+            let share = ProfitShareCache::compute_share(
+                weighted_usdt,
+                cache.total_profit_usdt,
+                cache.total_invest_usdt,
+            )?.1;
+            (normalize_amount(share, AMOUNT_DECIMALS, cache.payout_decimals)?, multiplier_bp)
+        } else {
+            let refund = RefundShareCache::compute_refund(
+                config.stage_ratio_for_year(year_index),
+                record.stage,
+                year_index,
+                record.amount_hcoin,
+            )?;
+            (info.hcoin_units(refund)?, record.stage as u16)
+        };
+
+        let held = record.revocation_held(now).then_some(*record_id);
+        let split = if held.is_some() { None } else { splits.get(&record.account_id).map(|s| &**s) };
+        for (wallet, share) in PayoutSplit::shares(split, record.wallet, expected)? {
+            let group = groups
+                .entry((record.account_id, wallet, stage, held))
+                .or_insert((0, Vec::new()));
+            group.0 = group.0.checked_add(share).ok_or(ErrorCode::NumericalOverflow)?;
+            group.1.push(*record_id);
+        }
+    }
+
+    // AUDIT: Match each group to one unused cache entry
+    let mut used = vec![false; entries.len()];
+    let mut entries_matched: u16 = 0;
+    let mut missing_records: Vec<u64> = Vec::new();
+    let mut amount_mismatches: Vec<u64> = Vec::new();
+
+    for ((account_id, wallet, _stage, _held), (expected, record_ids)) in groups.into_iter() {
+        let candidates: Vec<usize> = entries
+            .iter()
+            .enumerate()
+            .filter(|(i, (entry_account_id, entry_wallet, _))| {
+                !used[*i] && *entry_account_id == account_id && *entry_wallet == wallet
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some(&i) = candidates.iter().find(|&&i| entries[i].2 == expected) {
+            used[i] = true;
+            entries_matched += 1;
+        } else if let Some(&i) = candidates.first() {
+            used[i] = true;
+            amount_mismatches.extend(record_ids);
+        } else {
+            missing_records.extend(record_ids);
+        }
+    }
+
+    let orphan_entries = used.iter().filter(|u| !**u).count() as u16;
+    let is_consistent = missing_records.is_empty() && amount_mismatches.is_empty() && orphan_entries == 0;
+
+    msg!(
+        "🧾 Reconciled batch {}: checked={}, matched={}, missing={}, mismatched={}, orphans={}",
+        batch_id,
+        records_checked,
+        entries_matched,
+        missing_records.len(),
+        amount_mismatches.len(),
+        orphan_entries
+    );
+
+    emit!(BatchReconciled {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        year_index: cache_year_index,
+        records_checked,
+        entries_matched,
+        missing_records,
+        amount_mismatches,
+        orphan_entries,
+        is_consistent,
+        reconciled_by: signer_keys[0],
+        reconciled_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Emits a page of a batch's investment records as a `RecordPageEmitted` event
+/// 
+/// AUDIT CRITICAL - RECORD EXPORT:
+/// Gives reconciliation tools that cannot run getProgramAccounts against mainnet a
+/// chain-native export path: they pass the page's record accounts and read the event
+/// from the transaction logs. The instruction never modifies state.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Record PDA and batch_id validation, duplicate record rejection
+/// - Every record_id must fall within the page's range
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch to export
+/// - page: Page number; covers record_id page * RECORD_PAGE_SIZE .. (page + 1) * RECORD_PAGE_SIZE
+/// 
+/// remaining_accounts: [record_1, ..., record_n]
+pub fn emit_record_page<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, EmitRecordPage<'info>>,
+    batch_id: u16,
+    page: u32,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+
+    require!(
+        ctx.remaining_accounts.len() as u64 <= RECORD_PAGE_SIZE,
+        ErrorCode::TooManyRecordsLoaded
+    );
+
+    let page_start = (page as u64)
+        .checked_mul(RECORD_PAGE_SIZE)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let page_end = page_start
+        .checked_add(RECORD_PAGE_SIZE)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // AUDIT: Load records with the same PDA validation as estimation
+    let mut record_map = BTreeMap::new();
+    for acc_info in ctx.remaining_accounts.iter() {
+        let record = Account::<InvestmentRecord>::try_from(acc_info)?;
+        let (expected_record_pda, _bump) = Pubkey::find_program_address(
+            &[
+                b"record",
+                info.investment_id.as_ref(),
+                info.version.as_ref(),
+                batch_id.to_le_bytes().as_ref(),
+                record.record_id.to_le_bytes().as_ref(),
+                record.account_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+        require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+        require!(
+            (page_start..page_end).contains(&record.record_id),
+            ErrorCode::RecordOutsidePage
+        );
+        require!(
+            !record_map.contains_key(&record.record_id),
+            ErrorCode::DuplicateRecord
+        );
+
+        record_map.insert(
+            record.record_id,
+            RecordSummary {
+                record_id: record.record_id,
+                account_id: record.account_id,
+                wallet: record.wallet,
+                amount_usdt: record.amount_usdt,
+                amount_hcoin: record.amount_hcoin,
+                stage: record.stage,
+                revoked_at: record.revoked_at,
+            },
+        );
+    }
+
+    emit!(RecordPageEmitted {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        page,
+        records: record_map.into_values().collect(),
+        emitted_at: now,
+    });
+
+    Ok(())
+}
+
+/// Emits investment size and stage statistics over a batch's records
+/// 
+/// AUDIT CRITICAL - BATCH STATISTICS:
+/// Gives compliance on-chain-verifiable portfolio statistics: min / max / mean /
+/// median `amount_usdt` and the per-stage distribution over the supplied records.
+/// Only live records are counted (acknowledged and not revoked); the others are
+/// reported as `excluded_count`. The instruction never modifies state.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Record PDA and batch_id validation, duplicate record rejection
+/// - At most MAX_ENTRIES_PER_BATCH records per call
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch to summarize
+/// 
+/// remaining_accounts: [record_1, ..., record_n]
+pub fn emit_batch_statistics<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, EmitBatchStatistics<'info>>,
+    batch_id: u16,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+
+    require!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() <= MAX_ENTRIES_PER_BATCH,
+        ErrorCode::TooManyRecordsLoaded
+    );
+
+    // AUDIT: Load records with the same PDA validation as estimation
+    let mut seen: Vec<u64> = Vec::new();
+    let mut amounts: Vec<u64> = Vec::new();
+    let mut excluded_count: u16 = 0;
+    let mut stage_counts = [0u16; MAX_STAGE];
+    let mut stage_usdt = [0u64; MAX_STAGE];
+    let mut total_usdt: u64 = 0;
+
+    for acc_info in ctx.remaining_accounts.iter() {
+        let record = Account::<InvestmentRecord>::try_from(acc_info)?;
+        let (expected_record_pda, _bump) = Pubkey::find_program_address(
+            &[
+                b"record",
+                info.investment_id.as_ref(),
+                info.version.as_ref(),
+                batch_id.to_le_bytes().as_ref(),
+                record.record_id.to_le_bytes().as_ref(),
+                record.account_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+        require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+        require!(!seen.contains(&record.record_id), ErrorCode::DuplicateRecord);
+        seen.push(record.record_id);
+
+        // AUDIT: Revoked and unacknowledged records are not part of the portfolio
+        if record.revoked_at != 0 || !record.is_acknowledged() {
+            excluded_count += 1;
+            continue;
+        }
+
+        total_usdt = total_usdt
+            .checked_add(record.amount_usdt)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        amounts.push(record.amount_usdt);
+
+        // AUDIT: Stages outside 1..=MAX_STAGE count toward size statistics only
+        if let Some(index) = (record.stage as usize).checked_sub(1).filter(|i| *i < MAX_STAGE) {
+            stage_counts[index] += 1;
+            stage_usdt[index] = stage_usdt[index]
+                .checked_add(record.amount_usdt)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+        }
+    }
+
+    // AUDIT: Median of an even count is the floored mean of the two middle values
+    amounts.sort_unstable();
+    let record_count = amounts.len();
+    let (min_usdt, max_usdt, mean_usdt, median_usdt) = if record_count == 0 {
+        (0, 0, 0, 0)
+    } else {
+        let middle = record_count / 2;
+        let median = if record_count.is_multiple_of(2) {
+            ((amounts[middle - 1] as u128 + amounts[middle] as u128) / 2) as u64
+        } else {
+            amounts[middle]
+        };
+        (
+            amounts[0],
+            amounts[record_count - 1],
+            total_usdt / record_count as u64,
+            median,
+        )
+    };
+
+    emit!(BatchStatisticsEmitted {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_count: record_count as u16,
+        excluded_count,
+        total_usdt,
+        min_usdt,
+        max_usdt,
+        mean_usdt,
+        median_usdt,
+        stage_counts,
+        stage_usdt,
+        emitted_at: now,
+    });
+
+    Ok(())
+}
+
+
+//================ VAULT DEPOSIT AND WITHDRAWAL OPERATIONS ================
+// AUDIT: These functions handle vault deposit and withdrawal operations
+// SECURITY: All operations require proper validation and authorization
+
+/// Deposits SOL to the vault PDA
+/// 
+/// AUDIT CRITICAL - VAULT SOL DEPOSIT:
+/// This function deposits SOL to the vault PDA for operational costs.
+/// It requires investment to be active and pending or completed, since gas is
+/// also needed for pre-completion operations.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment state validation (must be active and pending or completed)
+/// - Vault PDA verification to prevent address spoofing
+/// - Post-deposit balance must keep the vault rent-exempt
+/// - Warns when the balance is below required_sol_reserve
+/// - Safe SOL transfer using system program
+/// - Event emission for audit trail
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify vault PDA derivation is consistent
+/// [ ] Check investment state validation
+/// [ ] Review SOL transfer security
+/// [ ] Validate event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - amount: Amount of SOL to deposit to vault
+/// - reference_id: Optional back-office transfer order ID, echoed in the event
+pub fn deposit_sol_to_vault(
+    ctx: Context<DepositSolToVault>,
+    amount: u64,
+    reference_id: Option<[u8; 16]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let vault = &ctx.accounts.vault;
+    let payer = &ctx.accounts.payer;
+    let system_program = &ctx.accounts.system_program;
+
+
+    // AUDIT: Reject if investment info has been deactivated; gas is needed while pending too
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_DEPOSIT)?;
+    require!(
+        info.state == InvestmentState::Pending || info.state == InvestmentState::Completed,
+        ErrorCode::InvestmentInfoNotCompleted
+    );
+
+    // AUDIT: Validate vault PDA derivation to prevent address spoofing
+    let (vault_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"vault", 
+            info.investment_id.as_ref(),
+            info.vault_version.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: The vault must remain rent-exempt after the deposit
+    let vault_lamports = vault
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+    require!(vault_lamports >= rent_exempt, ErrorCode::VaultBelowRentExempt);
+
+    // AUDIT: Track the gas budget; a shortfall is reported, not rejected
+    let required_reserve = required_sol_reserve(&ctx.accounts.global_config, &vault.to_account_info())?;
+    if vault_lamports < required_reserve {
+        msg!(
+            "🟡 Vault SOL {} below required reserve {} (short {})",
+            vault_lamports,
+            required_reserve,
+            required_reserve - vault_lamports
+        );
+    }
+
+    // AUDIT: Transfer SOL to vault using system program
+    let cpi_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        Transfer {
+            from: payer.to_account_info(),
+            to: vault.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_ctx, amount)?;
+
+    // AUDIT: Emit event for audit trail
+    emit!(VaultDepositSolEvent {
+        investment_id: info.investment_id,
+        version: info.version,
+        from: *payer.key,
+        amount_usdt: amount,
+        vault_lamports,
+        required_reserve,
+        reference_id,
+        deposit_at: now,
+    });
+
+    Ok(())
+}
+
+
+/// Deposits SPL Token to the Vault's associated token account (ATA)
+/// 
+/// AUDIT CRITICAL - VAULT TOKEN DEPOSIT:
+/// This function deposits SPL tokens (USDT or H2COIN) to the vault's associated token account.
+/// It requires investment to be active and completed.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment state validation (must be active and completed)
+/// - Vault PDA verification to prevent address spoofing
+/// - Token mint validation (the investment's profit mint or H2COIN only)
+/// - Vault ATA validation
+/// - Token account ownership validation
+/// - Safe token transfer with proper authorization
+/// - Event emission for audit trail
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify vault PDA derivation is consistent
+/// [ ] Check token mint validation
+/// [ ] Review vault ATA validation
+/// [ ] Validate token transfer security
+/// [ ] Confirm event emission for audit trail
+/// 
+/// PARAMETERS:
+/// - amount: Amount of tokens to deposit to vault
+/// - reference_id: Optional back-office transfer order ID, echoed in the event
+pub fn deposit_token_to_vault(
+    ctx: Context<DepositTokenToVault>,
+    amount: u64,
+    reference_id: Option<[u8; 16]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+
+
+    // AUDIT: Reject if investment info is inactive or not completed
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_DEPOSIT)?;
+    require!(
+        info.state == InvestmentState::Completed,
+        ErrorCode::InvestmentInfoNotCompleted
+    );
+
+    // AUDIT: Derive the expected vault PDA to prevent address spoofing
+    let (vault_pda, _) = Pubkey::find_program_address(
+        &[
+            b"vault",
+            info.investment_id.as_ref(),
+            info.vault_version.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: Validate mint (profit mint or H2COIN only)
+    let mint = ctx.accounts.mint.key();
+    require!(
+        mint == info.profit_mint || mint == ctx.accounts.global_config.hcoin_mint,
+        ErrorCode::InvalidTokenMint
+    );
+
+    // AUDIT: Validate vault ATA ownership
+    let expected_vault_token_ata = get_associated_token_address(&vault_pda, &mint);
+    require_keys_eq!(
+        ctx.accounts.vault_token_account.key(),
+        expected_vault_token_ata,
+        ErrorCode::InvalidVaultAta
+    );
+
+    // AUDIT: Validate token account ownership
+    require_keys_eq!(
+        ctx.accounts.from.owner.key(),
+        ctx.accounts.payer.key(),
+        ErrorCode::InvalidFromOwner
+    );
+
+    // AUDIT: Transfer token to vault ATA with proper authorization
+    transfer_token_checked(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.from.to_account_info(),
+        vault_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        None,
+        amount,
+        ctx.accounts.mint.decimals,
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(info, "deposit", None, &[]),
+    )?;
+
+    // AUDIT: Emit token deposit event for audit trail
+    emit!(VaultDepositTokenEvent {
+        investment_id: info.investment_id,
+        version: info.version,
+        from: ctx.accounts.payer.key(),
+        mint,
+        amount,
+        reference_id,
+        deposit_at: now,
+    });
+
+
+    Ok(())
+}
+
+
+
+/// Withdraws remaining SOL, USDT, and H2COIN from the vault PDA to the withdraw wallet.
+/// Withdraws remaining SOL, USDT, and H2COIN from the vault PDA to the withdraw wallet
+/// 
+/// AUDIT CRITICAL - VAULT WITHDRAWAL:
+/// This function withdraws all remaining funds from the vault to an authorized recipient.
+/// It requires 3-of-5 multisig authorization from the execute_whitelist.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Investment state validation (must be active and completed)
+/// - Vault PDA verification to prevent address spoofing
+/// - Recipient whitelist validation
+/// - Token account ownership validation
+/// - SOL balance calculation with rent exemption
+/// - Safe token transfer with proper authorization
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify vault PDA derivation is consistent
+/// [ ] Confirm multisig validation uses correct whitelist
+/// [ ] Check recipient whitelist validation
+/// [ ] Review SOL balance calculation and rent exemption
+/// [ ] Validate token transfer security
+/// [ ] Confirm event emission for audit trail
+/// 
+/// Requires 'completed' and 'active' state
+/// Requires 3-of-5 execute whitelist signatures.
+/// 
+/// PARAMETERS:
+/// - sequence: Must equal InvestmentInfo.withdraw_sequence (anti-replay)
+/// - assets: Which assets to withdraw (All, Sol, Usdt or Hcoin)
+/// - purpose: Purpose code; the amounts are counted against its WithdrawalBudget line
+/// - amount_usdt / amount_hcoin / amount_sol: Exact amount of a selected asset;
+///   None withdraws its full balance (SOL: everything above the reserve)
+/// 
+/// Instead of co-signers, an approved `Proposal` carrying the same recipient, assets,
+/// amounts, sequence and configuration hash may be passed.
+/// 
+/// When InvestmentInfo.withdraw_tiers is set, the USDT amount withdrawn selects the
+/// required signer count (see set_withdraw_tiers). A tier may lower the co-signed
+/// requirement, never the proposal quorum.
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_from_vault<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, WithdrawFromVault<'info>>,
+    sequence: u64,
+    assets: WithdrawAssets,
+    purpose: WithdrawPurpose,
+    amount_usdt: Option<u64>,
+    amount_hcoin: Option<u64>,
+    amount_sol: Option<u64>,
+    idempotency_key: Option<[u8; 16]>,
+    expected_config_hash: [u8; 32],
+) -> Result<()>
+where
+    'c: 'info,
+{
+    cu_checkpoint("start");
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let usdt_mint = &ctx.accounts.usdt_mint;
+    let hcoin_mint = &ctx.accounts.hcoin_mint;
+
+    let vault = &ctx.accounts.vault;
+    let vault_usdt_account = &ctx.accounts.vault_usdt_account;
+    let vault_hcoin_account = &ctx.accounts.vault_hcoin_account;
+
+    let recipient_account = &ctx.accounts.recipient_account;
+    let recipient_usdt_account = &ctx.accounts.recipient_usdt_account;
+    let recipient_hcoin_account = &ctx.accounts.recipient_hcoin_account;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+
+    // AUDIT: Reject if investment info has been deactivated or has not been completed
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_WITHDRAW)?;
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+    // AUDIT: With the withdrawal timelock on, funds only leave through execute_withdrawal
+    require!(info.withdraw_delay_secs == 0, ErrorCode::WithdrawTimelockActive);
+
+    // AUDIT: Signers approved this exact configuration snapshot
+    info.require_config_hash(&expected_config_hash)?;
+
+    // AUDIT: SOL above the rent-exempt minimum and the execution reserve may leave the vault
+    let withdrawable_lamports = withdrawable_vault_lamports(&ctx.accounts.global_config, vault)?;
+
+    // AUDIT: Only the selected assets are withdrawn, in full unless an amount is given;
+    // the USDT amount selects the withdrawal tier
+    let usdt_amount = resolve_withdraw_amount(assets.includes_usdt(), amount_usdt, vault_usdt_account.amount)?;
+    let hcoin_amount = resolve_withdraw_amount(assets.includes_hcoin(), amount_hcoin, vault_hcoin_account.amount)?;
+    let sol_amount = resolve_withdraw_amount(assets.includes_sol(), amount_sol, withdrawable_lamports)?;
+    let required_signers = info.required_withdraw_signers(usdt_amount, hcoin_amount);
+    let role_config = ctx.accounts.role_config.as_deref();
+
+    // AUDIT: Verify 3-of-5 signers from execute_whitelist, co-signed or through an approved proposal;
+    // with withdrawal tiers set, the co-signed path needs the tier's count instead
+    let signer_keys = match ctx.accounts.proposal.as_mut() {
+        Some(proposal) => {
+            let action = ProposalAction::WithdrawFromVault {
+                recipient: recipient_account.key(),
+                assets,
+                purpose,
+                amount_usdt,
+                amount_hcoin,
+                amount_sol,
+                sequence,
+                expected_config_hash,
+            };
+            let approvals = execute_proposal(proposal, info, config, &action, ctx.accounts.payer.key(), now)?;
+            // AUDIT: A tier above the proposal quorum still applies to the approvals
+            if let Some(required) = required_signers {
+                config.enforce_withdraw_tier(role_config, required, &approvals)?;
+            }
+            approvals
+        }
+        None => authorize_cosigned_withdrawal(config, role_config, required_signers, ctx.remaining_accounts)?,
+    };
+
+    // AUDIT: Anti-replay - signers commit to the current withdrawal sequence
+    require!(sequence == info.withdraw_sequence, ErrorCode::WithdrawSequenceMismatch);
+    info.consume_idempotency_key(idempotency_key)?;
+
+    // AUDIT: Count the payout against its purpose; fails if a cap would be passed
+    let budget = &mut ctx.accounts.withdraw_budget;
+    budget.bind(info, ctx.bumps.withdraw_budget);
+    budget.record(purpose, usdt_amount, hcoin_amount, sol_amount, now)?;
+
+    // AUDIT: The payout must fit the rolling-window rate limit
+    let rate_limit = &mut ctx.accounts.withdraw_rate_limit;
+    rate_limit.bind(info, ctx.bumps.withdraw_rate_limit);
+    rate_limit.record(usdt_amount, hcoin_amount, now)?;
+
+    pay_out_withdrawal(
+        info,
+        config,
+        ctx.program_id,
+        vault,
+        vault_usdt_account,
+        vault_hcoin_account,
+        usdt_mint,
+        hcoin_mint,
+        &recipient_account.to_account_info(),
+        recipient_usdt_account,
+        recipient_hcoin_account,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(
+            info,
+            "withdraw",
+            None,
+            &[("purpose", purpose.index() as u64), ("seq", sequence)],
+        ),
+        usdt_amount,
+        hcoin_amount,
+        sol_amount,
+        now,
+    )?;
+
+    // AUDIT: Consume the sequence so this transaction cannot be re-broadcast
+    info.withdraw_sequence = sequence
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // AUDIT: Emit vault transfer event for audit trail
+    emit!(VaultTransferred {
+        investment_id: info.investment_id,
+        version: info.version,
+        recipient: recipient_account.key(),
+        assets,
+        purpose,
+        sol_amount,
+        usdt_amount,
+        hcoin_amount,
+        executed_by: ctx.accounts.payer.key(),
+        executed_at: now,
+        sequence,
+        signers: signer_keys.clone(),
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+/// Propose a timelocked withdrawal
+/// 
+/// AUDIT CRITICAL - WITHDRAWAL TIMELOCK:
+/// Records recipient and amounts in a PendingWithdrawal that execute_withdrawal pays
+/// out after InvestmentInfo.withdraw_delay_secs. Authorization is the same as a
+/// co-signed withdraw_from_vault (withdrawal tiers or the Withdrawer role); any
+/// whitelist member can cancel_withdrawal in the meantime.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Withdrawal timelock must be configured
+/// - Investment must be active, completed and not frozen; withdrawals not paused
+/// - Configuration hash and withdrawal sequence must match
+/// - Recipient must be on withdraw_whitelist
+/// - Amounts are resolved against the current vault balances and fixed here
+/// - The purpose's budget caps must still cover the amounts
+/// 
+/// PARAMETERS:
+/// - sequence: Must equal InvestmentInfo.withdraw_sequence; consumed on execution
+/// - assets, purpose, amount_usdt / amount_hcoin / amount_sol: As for withdraw_from_vault
+#[allow(clippy::too_many_arguments)]
+pub fn propose_withdrawal(
+    ctx: Context<ProposeWithdrawal>,
+    sequence: u64,
+    assets: WithdrawAssets,
+    purpose: WithdrawPurpose,
+    amount_usdt: Option<u64>,
+    amount_hcoin: Option<u64>,
+    amount_sol: Option<u64>,
+    expected_config_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let recipient = ctx.accounts.recipient_account.key();
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+
+    // AUDIT: Same lifecycle requirements as withdraw_from_vault
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_WITHDRAW)?;
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+    require!(info.withdraw_delay_secs != 0, ErrorCode::WithdrawTimelockDisabled);
+
+    // AUDIT: Signers approved this exact configuration snapshot and sequence
+    info.require_config_hash(&expected_config_hash)?;
+    require!(sequence == info.withdraw_sequence, ErrorCode::WithdrawSequenceMismatch);
+
+    // AUDIT: Reject an unauthorized recipient now rather than after the delay
+    require!(!config.withdraw_whitelist.is_empty(), ErrorCode::EmptyWhitelist);
+    require!(config.withdraw_whitelist.contains(&recipient), ErrorCode::UnauthorizedRecipient);
+
+    // AUDIT: Fix the amounts against the current balances; the USDT amount selects the tier
+    let withdrawable_lamports = withdrawable_vault_lamports(&ctx.accounts.global_config, &ctx.accounts.vault)?;
+    let usdt_amount = resolve_withdraw_amount(assets.includes_usdt(), amount_usdt, ctx.accounts.vault_usdt_account.amount)?;
+    let hcoin_amount = resolve_withdraw_amount(assets.includes_hcoin(), amount_hcoin, ctx.accounts.vault_hcoin_account.amount)?;
+    let sol_amount = resolve_withdraw_amount(assets.includes_sol(), amount_sol, withdrawable_lamports)?;
+    let required_signers = info.required_withdraw_signers(usdt_amount, hcoin_amount);
+
+    // AUDIT: Reject a withdrawal its purpose cap cannot cover now; execution counts it
+    let budget = &mut ctx.accounts.withdraw_budget;
+    budget.bind(info, ctx.bumps.withdraw_budget);
+    budget.lines[purpose.index()].totals_after(usdt_amount, hcoin_amount, sol_amount)?;
+
+    let signer_keys = authorize_cosigned_withdrawal(
+        config,
+        ctx.accounts.role_config.as_deref(),
+        required_signers,
+        ctx.remaining_accounts,
+    )?;
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    pending.investment_id = info.investment_id;
+    pending.version = info.version;
+    pending.recipient = recipient;
+    pending.assets = assets;
+    pending.purpose = purpose;
+    pending.usdt_amount = usdt_amount;
+    pending.hcoin_amount = hcoin_amount;
+    pending.sol_amount = sol_amount;
+    pending.sequence = sequence;
+    pending.config_hash = expected_config_hash;
+    pending.proposed_by = ctx.accounts.payer.key();
+    pending.proposed_at = now;
+    pending.executable_at = now.saturating_add(info.withdraw_delay_secs as i64);
+    pending.bump = ctx.bumps.pending_withdrawal;
+
+    msg!(
+        "🟡 Withdrawal proposed to {}: usdt={} hcoin={} sol={}, executable at {}",
+        recipient,
+        usdt_amount,
+        hcoin_amount,
+        sol_amount,
+        pending.executable_at
+    );
+
+    emit!(WithdrawalProposed {
+        investment_id: info.investment_id,
+        version: info.version,
+        recipient,
+        assets,
+        purpose,
+        usdt_amount,
+        hcoin_amount,
+        sol_amount,
+        sequence,
+        executable_at: pending.executable_at,
+        proposed_by: ctx.accounts.payer.key(),
+        proposed_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Execute a pending withdrawal once its timelock has elapsed
+/// 
+/// AUDIT CRITICAL - WITHDRAWAL TIMELOCK:
+/// Permissionless: the signers approved the withdrawal in propose_withdrawal. Pays
+/// exactly the recorded amounts, consumes the withdrawal sequence and closes the
+/// PendingWithdrawal to its original payer.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Timelock must have elapsed
+/// - Investment must be active, completed and not frozen; withdrawals not paused
+/// - Configuration hash and withdrawal sequence must be unchanged since the proposal
+/// - Recipient must still be on withdraw_whitelist
+/// - Recorded amounts must still be covered by the vault and the purpose's budget caps
+/// - Recorded amounts must fit the rolling-window rate limit at execution time
+pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &mut ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let pending = &ctx.accounts.pending_withdrawal;
+    let vault = &ctx.accounts.vault;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+
+    // AUDIT: Same lifecycle requirements as withdraw_from_vault
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_WITHDRAW)?;
+    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
+
+    // AUDIT: Monitoring had the whole delay to cancel
+    require!(now >= pending.executable_at, ErrorCode::WithdrawalNotReady);
+
+    // AUDIT: A configuration change or another withdrawal voids the proposal
+    info.require_config_hash(&pending.config_hash)?;
+    require!(pending.sequence == info.withdraw_sequence, ErrorCode::WithdrawSequenceMismatch);
+
+    // AUDIT: The recorded amounts must still be available
+    require!(
+        pending.usdt_amount <= ctx.accounts.vault_usdt_account.amount
+            && pending.hcoin_amount <= ctx.accounts.vault_hcoin_account.amount
+            && pending.sol_amount <= withdrawable_vault_lamports(&ctx.accounts.global_config, vault)?,
+        ErrorCode::WithdrawAmountExceedsBalance
+    );
+
+    // AUDIT: Count the payout against its purpose; a cap lowered since the proposal applies
+    let budget = &mut ctx.accounts.withdraw_budget;
+    budget.bind(info, ctx.bumps.withdraw_budget);
+    budget.record(pending.purpose, pending.usdt_amount, pending.hcoin_amount, pending.sol_amount, now)?;
+
+    // AUDIT: The payout must fit the rolling-window rate limit when it executes
+    let rate_limit = &mut ctx.accounts.withdraw_rate_limit;
+    rate_limit.bind(info, ctx.bumps.withdraw_rate_limit);
+    rate_limit.record(pending.usdt_amount, pending.hcoin_amount, now)?;
+
+    pay_out_withdrawal(
+        info,
+        config,
+        ctx.program_id,
+        vault,
+        &ctx.accounts.vault_usdt_account,
+        &ctx.accounts.vault_hcoin_account,
+        &ctx.accounts.usdt_mint,
+        &ctx.accounts.hcoin_mint,
+        &ctx.accounts.recipient_account.to_account_info(),
+        &ctx.accounts.recipient_usdt_account,
+        &ctx.accounts.recipient_hcoin_account,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(
+            info,
+            "withdraw",
+            None,
+            &[("purpose", pending.purpose.index() as u64), ("seq", pending.sequence)],
+        ),
+        pending.usdt_amount,
+        pending.hcoin_amount,
+        pending.sol_amount,
+        now,
+    )?;
+
+    // AUDIT: Consume the sequence so no other approval for it can execute
+    info.withdraw_sequence = pending.sequence
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // AUDIT: Signers are recorded by WithdrawalProposed
+    emit!(VaultTransferred {
+        investment_id: info.investment_id,
+        version: info.version,
+        recipient: pending.recipient,
+        assets: pending.assets,
+        purpose: pending.purpose,
+        sol_amount: pending.sol_amount,
+        usdt_amount: pending.usdt_amount,
+        hcoin_amount: pending.hcoin_amount,
+        executed_by: ctx.accounts.payer.key(),
+        executed_at: now,
+        sequence: pending.sequence,
+        signers: vec![],
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+/// Cancel a pending withdrawal
+/// 
+/// AUDIT CRITICAL - WITHDRAWAL TIMELOCK:
+/// Any single member of the execute, update or withdraw whitelist (or the Squads
+/// authority) can stop a pending withdrawal. Cancelling can only keep funds in the
+/// vault, never move them, so one signature is enough. The PendingWithdrawal is
+/// closed to its original payer.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be a whitelist member or the Squads authority
+pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let pending = &ctx.accounts.pending_withdrawal;
+    let member = ctx.accounts.member.key();
+
+    // AUDIT: One member of any whitelist is enough to cancel
+    require!(
+        config.is_execute_or_update_member(&member)
+            || config.withdraw_whitelist.contains(&member)
+            || config.is_squads_signer(&[member]),
+        ErrorCode::UnauthorizedSigner
+    );
+
+    msg!(
+        "🟠 Withdrawal to {} (sequence {}) cancelled by {}",
+        pending.recipient,
+        pending.sequence,
+        member
+    );
+
+    emit!(WithdrawalCancelled {
+        investment_id: info.investment_id,
+        version: info.version,
+        recipient: pending.recipient,
+        purpose: pending.purpose,
+        usdt_amount: pending.usdt_amount,
+        hcoin_amount: pending.hcoin_amount,
+        sol_amount: pending.sol_amount,
+        sequence: pending.sequence,
+        cancelled_by: member,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
+
+/// Sweep a foreign SPL token out of the vault to the recovery wallet
+/// 
+/// AUDIT CRITICAL - FOREIGN TOKEN RECOVERY:
+/// Tokens other than USDT / H2COIN sent to the vault PDA by mistake cannot leave
+/// through the withdrawal paths. This moves them from any vault-owned token account
+/// to InvestmentInfo.recovery_wallet, the only possible destination. USDT and H2COIN
+/// are rejected so sweeping can never bypass withdrawal tiers, budgets or the timelock.
+/// Sweeping is allowed in any investment state so tokens cannot be stranded.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program not paused; withdrawals not paused and investment not frozen
+/// - 3-of-5 multisig from execute_whitelist
+/// - Mint must not be USDT, H2COIN or listed in the VaultTokenRegistry
+/// - Recovery wallet must be set and match the passed account
+/// - Amount must not exceed the token account balance (None = full balance)
+pub fn sweep_foreign_token(ctx: Context<SweepForeignToken>, amount: Option<u64>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let vault_token_account = &ctx.accounts.vault_token_account;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    info.require_family_active(PAUSE_WITHDRAW)?;
+
+    // AUDIT: Verify 3-of-5 signers from execute_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, false)?;
+
+    // AUDIT: Profit mint / H2COIN only leave through the withdrawal paths
+    require!(
+        mint.key() != info.profit_mint && mint.key() != ctx.accounts.global_config.hcoin_mint,
+        ErrorCode::NotForeignToken
+    );
+
+    // AUDIT: Registered vault mints are held on purpose and are never foreign
+    let registry_info = ctx.accounts.vault_token_registry.to_account_info();
+    if registry_info.owner == ctx.program_id && !registry_info.data_is_empty() {
+        let registry = VaultTokenRegistry::try_deserialize(&mut &registry_info.try_borrow_data()?[..])?;
+        require!(!registry.contains(&mint.key()), ErrorCode::NotForeignToken);
+    }
+
+    // AUDIT: The committee-designated recovery wallet is the only destination
+    require!(info.recovery_wallet != Pubkey::default(), ErrorCode::RecoveryWalletNotSet);
+    require_keys_eq!(
+        ctx.accounts.recovery_wallet.key(),
+        info.recovery_wallet,
+        ErrorCode::UnauthorizedRecipient
+    );
+
+    let amount = resolve_withdraw_amount(true, amount, vault_token_account.amount)?;
+    if amount == 0 {
+        msg!("🟡 Vault holds no {} tokens, skip sweep", mint.key());
+        end_execution(&mut ctx.accounts.investment_info);
+        return Ok(());
+    }
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    require_keys_eq!(vault.key(), info.vault, ErrorCode::InvalidVaultPda);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+    transfer_token_checked(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account.to_account_info(),
+        ctx.accounts.recovery_token_account.to_account_info(),
+        mint.to_account_info(),
+        vault.to_account_info(),
+        Some(signer_seeds),
+        amount,
+        mint.decimals,
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(info, "sweep", None, &[]),
+    )?;
+
+    msg!("🧹 Swept {} of mint {} to {}", amount, mint.key(), info.recovery_wallet);
+
+    emit!(ForeignTokenSwept {
+        investment_id: info.investment_id,
+        version: info.version,
+        mint: mint.key(),
+        source: vault_token_account.key(),
+        recipient: info.recovery_wallet,
+        amount,
+        executed_by: ctx.accounts.payer.key(),
+        executed_at: now,
+        signers: signer_keys,
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+/// Register an additional mint held by the vault
+/// 
+/// AUDIT CRITICAL - VAULT TOKEN REGISTRY:
+/// Lists a mint beyond the profit mint and H2COIN (e.g. a second reward token) in the
+/// investment's VaultTokenRegistry and creates its vault ATA, so new products do not
+/// need another hardcoded vault account in every context. Registered mints are
+/// refused by sweep_foreign_token.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment must be active
+/// - Mint must not be the profit mint or H2COIN, nor already registered
+/// - At most MAX_VAULT_MINTS registered mints
+pub fn register_vault_mint(ctx: Context<RegisterVaultMint>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let mint = &ctx.accounts.mint;
+    let registry = &mut ctx.accounts.vault_token_registry;
+
+    // AUDIT: Reject if investment has been deactivated
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: Multisig validation from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    // AUDIT: The profit mint and H2COIN already have dedicated vault accounts
+    require!(
+        mint.key() != info.profit_mint && mint.key() != ctx.accounts.global_config.hcoin_mint,
+        ErrorCode::VaultMintAlreadyRegistered
+    );
+
+    if registry.created_at == 0 {
+        registry.investment_id = info.investment_id;
+        registry.version = info.version;
+        registry.bump = ctx.bumps.vault_token_registry;
+        registry.created_at = now;
+    }
+
+    let ata = ctx.accounts.vault_token_account.key();
+    registry.register(
+        VaultMintEntry {
+            mint: mint.key(),
+            ata,
+            decimals: mint.decimals,
+            registered_at: now,
+        },
+        now,
+    )?;
+
+    msg!("🟢 Vault mint {} registered, ata={}", mint.key(), ata);
+
+    emit!(VaultMintRegistered {
+        investment_id: info.investment_id,
+        version: info.version,
+        mint: mint.key(),
+        ata,
+        decimals: mint.decimals,
+        registered_by: ctx.accounts.payer.key(),
+        registered_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Deregister an additional vault mint
+/// 
+/// AUDIT CRITICAL:
+/// - Requires 3-of-5 multisig from update_whitelist
+/// - The vault ATA must be empty, so no balance is left untracked
+/// - The ATA stays open; tokens arriving later can be swept as foreign
+pub fn deregister_vault_mint(ctx: Context<DeregisterVaultMint>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let mint = &ctx.accounts.mint;
+
+    // AUDIT: Multisig validation from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    require!(
+        ctx.accounts.vault_token_account.amount == 0,
+        ErrorCode::VaultMintBalanceNotZero
+    );
+
+    let entry = ctx.accounts.vault_token_registry.deregister(&mint.key(), now)?;
+
+    msg!("🟢 Vault mint {} deregistered", mint.key());
+
+    emit!(VaultMintDeregistered {
+        investment_id: info.investment_id,
+        version: info.version,
+        mint: entry.mint,
+        ata: entry.ata,
+        deregistered_by: ctx.accounts.payer.key(),
+        deregistered_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Take an attested snapshot of the vault balances and outstanding cache obligations
+/// 
+/// AUDIT CRITICAL - VAULT SNAPSHOT:
+/// Permissionless. Reads the lamports of the vault PDA and the balances of its USDT /
+/// H2COIN ATAs, and adds up the entries not yet paid (pending, escrowed or requeued)
+/// of every ProfitShareCache / RefundShareCache (and the unclaimed part of every
+/// ProfitMerkleCache) supplied in remaining_accounts. The
+/// result is emitted as VaultSnapshot and, when the snapshot PDA is supplied, stored
+/// as the investment's latest snapshot. Obligations only cover the supplied caches,
+/// so consumers should compare caches_counted with the caches they expect.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Mints are USDT / H2COIN; vault and ATAs validated by the context
+/// - Each cache is owned by this program, of this investment and supplied once
+pub fn snapshot_vault(ctx: Context<SnapshotVault>) -> Result<()> {
+    let clock = Clock::get()?;
+    let info = &ctx.accounts.investment_info;
+
+    require_keys_eq!(ctx.accounts.usdt_mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+    require_keys_eq!(ctx.accounts.hcoin_mint.key(), ctx.accounts.global_config.hcoin_mint, ErrorCode::InvalidTokenMint);
+
+    // AUDIT: Unpaid entries stay reserved in the vault, whatever the cache execution state
+    let mut obligations_usdt: u64 = 0;
+    let mut obligations_hcoin: u64 = 0;
+    let mut seen: HashSet<Pubkey> = HashSet::new();
+    for cache_info in ctx.remaining_accounts.iter() {
+        require!(
+            cache_info.owner == ctx.program_id && seen.insert(cache_info.key()),
+            ErrorCode::InvalidSnapshotCache
+        );
+        let data = cache_info.try_borrow_data()?;
+
+        if let Ok(cache) = ProfitShareCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id && cache.version == info.version,
+                ErrorCode::InvalidSnapshotCache
+            );
+            // AUDIT: Booked against the vault balance of the round's payout mint;
+            // rounds paid in a registered vault mint are not covered by this snapshot
+            let obligations = if cache.payout_mint == info.profit_mint {
+                &mut obligations_usdt
+            } else if cache.payout_mint == ctx.accounts.global_config.hcoin_mint {
+                &mut obligations_hcoin
+            } else {
+                continue;
+            };
+            *obligations = cache
+                .entries
+                .iter()
+                .filter(|e| e.status != EntryStatus::Paid)
+                .try_fold(*obligations, |acc, e| acc.checked_add(e.amount_usdt))
+                .ok_or(ErrorCode::NumericalOverflow)?;
+        } else if let Ok(cache) = ProfitMerkleCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id && cache.version == info.version,
+                ErrorCode::InvalidSnapshotCache
+            );
+            // AUDIT: The unclaimed part of a claimable Merkle round stays reserved like unpaid
+            // entries; a cancelled or expired round can no longer be claimed
+            if !cache.is_claimable(clock.unix_timestamp) {
+                continue;
+            }
+            let obligations = if cache.payout_mint == info.profit_mint {
+                &mut obligations_usdt
+            } else if cache.payout_mint == ctx.accounts.global_config.hcoin_mint {
+                &mut obligations_hcoin
+            } else {
+                continue;
+            };
+            *obligations = cache
+                .subtotal_profit_usdt
+                .checked_sub(cache.claimed_usdt)
+                .and_then(|unclaimed| obligations.checked_add(unclaimed))
+                .ok_or(ErrorCode::NumericalOverflow)?;
+        } else if let Ok(cache) = RefundShareCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id && cache.version == info.version,
+                ErrorCode::InvalidSnapshotCache
+            );
+            obligations_hcoin = cache
+                .entries
+                .iter()
+                .filter(|e| e.status != EntryStatus::Paid)
+                .try_fold(obligations_hcoin, |acc, e| acc.checked_add(e.amount_hcoin))
+                .ok_or(ErrorCode::NumericalOverflow)?;
+        } else {
+            return err!(ErrorCode::InvalidSnapshotCache);
+        }
+    }
+    let caches_counted = seen.len() as u16;
+
+    let sol_lamports = ctx.accounts.vault.lamports();
+    let usdt_balance = ctx.accounts.vault_usdt_account.amount;
+    let hcoin_balance = ctx.accounts.vault_hcoin_account.amount;
+    let taken_by = ctx.accounts.payer.key();
+
+    if let Some(snapshot) = ctx.accounts.snapshot.as_mut() {
+        snapshot.investment_id = info.investment_id;
+        snapshot.version = info.version;
+        snapshot.sol_lamports = sol_lamports;
+        snapshot.usdt_balance = usdt_balance;
+        snapshot.hcoin_balance = hcoin_balance;
+        snapshot.obligations_usdt = obligations_usdt;
+        snapshot.obligations_hcoin = obligations_hcoin;
+        snapshot.caches_counted = caches_counted;
+        snapshot.slot = clock.slot;
+        snapshot.taken_at = clock.unix_timestamp;
+        snapshot.taken_by = taken_by;
+        snapshot.bump = ctx.bumps.snapshot.unwrap_or_default();
+    }
+
+    msg!(
+        "📸 Vault snapshot: {} lamports, {} USDT ({} owed), {} H2COIN ({} owed) over {} caches",
+        sol_lamports,
+        usdt_balance,
+        obligations_usdt,
+        hcoin_balance,
+        obligations_hcoin,
+        caches_counted
+    );
+
+    emit!(VaultSnapshot {
+        investment_id: info.investment_id,
+        version: info.version,
+        vault: info.vault,
+        sol_lamports,
+        usdt_balance,
+        hcoin_balance,
+        obligations_usdt,
+        obligations_hcoin,
+        caches_counted,
+        slot: clock.slot,
+        taken_by,
+        taken_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Verify the vault invariants of an investment and emit a HealthReport
+/// 
+/// AUDIT CRITICAL - INVARIANT HEALTH CHECK:
+/// Permissionless and read-only, meant to be cranked by ops (e.g. hourly). Over the
+/// caches and ledgers supplied in remaining_accounts it checks:
+/// - USDT: unpaid amount of unexecuted, unexpired ProfitShareCaches (subtotal_profit_usdt
+///   less entries already paid by a partial execution) plus the outstanding balance of
+///   ProfitAccrualLedgers <= vault USDT balance
+/// - H2COIN: the same for RefundShareCaches (subtotal_refund_hcoin) <= vault H2COIN balance
+/// - The reentrancy flag is not stuck
+/// 
+/// A failed check does not fail the instruction; it is reported as a false flag so the
+/// report is always emitted. Executed and expired caches are accepted and skipped.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Mints are USDT / H2COIN; vault and ATAs validated by the context
+/// - Each cache / ledger is owned by this program, of this investment and supplied once
+pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let cache_expire_secs = ctx.accounts.global_config.share_cache_expire_secs;
+
+    require_keys_eq!(ctx.accounts.usdt_mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+    require_keys_eq!(ctx.accounts.hcoin_mint.key(), ctx.accounts.global_config.hcoin_mint, ErrorCode::InvalidTokenMint);
+
+    // AUDIT: Unpaid part of a cache still reserved for execution
+    let unpaid = |subtotal: u64, paid: u64| subtotal.checked_sub(paid).ok_or(ErrorCode::NumericalOverflow);
+    let is_live = |executed_at: i64, created_at: i64| {
+        executed_at == 0 && now.saturating_sub(created_at) <= cache_expire_secs
+    };
+
+    let mut pending_profit_usdt: u64 = 0;
+    let mut pending_refund_hcoin: u64 = 0;
+    let mut outstanding_accrual_usdt: u64 = 0;
+    let mut caches_counted: u16 = 0;
+    let mut ledgers_counted: u16 = 0;
+    let mut seen: HashSet<Pubkey> = HashSet::new();
+
+    for acc_info in ctx.remaining_accounts.iter() {
+        require!(
+            acc_info.owner == ctx.program_id && seen.insert(acc_info.key()),
+            ErrorCode::InvalidHealthCheckAccount
+        );
+        let data = acc_info.try_borrow_data()?;
+
+        if let Ok(cache) = ProfitShareCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id && cache.version == info.version,
+                ErrorCode::InvalidHealthCheckAccount
+            );
+            caches_counted += 1;
+            if is_live(cache.executed_at, cache.created_at) {
+                let paid = cache
+                    .entries
+                    .iter()
+                    .filter(|e| e.status == EntryStatus::Paid)
+                    .try_fold(0u64, |acc, e| acc.checked_add(e.amount_usdt))
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                // AUDIT: Rounds paid in H2COIN are covered by the H2COIN balance
+                let pending = if cache.payout_mint == info.profit_mint {
+                    &mut pending_profit_usdt
+                } else if cache.payout_mint == ctx.accounts.global_config.hcoin_mint {
+                    &mut pending_refund_hcoin
+                } else {
+                    continue;
+                };
+                *pending = pending
+                    .checked_add(unpaid(cache.subtotal_profit_usdt, paid)?)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+            }
+        } else if let Ok(cache) = ProfitMerkleCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id && cache.version == info.version,
+                ErrorCode::InvalidHealthCheckAccount
+            );
+            caches_counted += 1;
+            // AUDIT: The round is live until fully claimed, cancelled or past its claim window
+            if cache.is_claimable(now) {
+                let pending = if cache.payout_mint == info.profit_mint {
+                    &mut pending_profit_usdt
+                } else if cache.payout_mint == ctx.accounts.global_config.hcoin_mint {
+                    &mut pending_refund_hcoin
+                } else {
+                    continue;
+                };
+                *pending = pending
+                    .checked_add(unpaid(cache.subtotal_profit_usdt, cache.claimed_usdt)?)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+            }
+        } else if let Ok(cache) = RefundShareCache::try_deserialize(&mut &data[..]) {
+            require!(
+                cache.investment_id == info.investment_id && cache.version == info.version,
+                ErrorCode::InvalidHealthCheckAccount
+            );
+            caches_counted += 1;
+            if is_live(cache.executed_at, cache.created_at) {
+                let paid = cache
+                    .entries
+                    .iter()
+                    .filter(|e| e.status == EntryStatus::Paid)
+                    .try_fold(0u64, |acc, e| acc.checked_add(e.amount_hcoin))
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                pending_refund_hcoin = pending_refund_hcoin
+                    .checked_add(unpaid(cache.subtotal_refund_hcoin, paid)?)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+            }
+        } else if let Ok(ledger) = ProfitAccrualLedger::try_deserialize(&mut &data[..]) {
+            require!(
+                ledger.investment_id == info.investment_id && ledger.version == info.version,
+                ErrorCode::InvalidHealthCheckAccount
+            );
+            ledgers_counted += 1;
+            outstanding_accrual_usdt = outstanding_accrual_usdt
+                .checked_add(ledger.outstanding_usdt()?)
+                .ok_or(ErrorCode::NumericalOverflow)?;
+        } else {
+            return err!(ErrorCode::InvalidHealthCheckAccount);
+        }
+    }
+
+    let usdt_balance = ctx.accounts.vault_usdt_account.amount;
+    let hcoin_balance = ctx.accounts.vault_hcoin_account.amount;
+
+    let usdt_covered = pending_profit_usdt
+        .checked_add(outstanding_accrual_usdt)
+        .is_some_and(|owed| owed <= usdt_balance);
+    let hcoin_covered = pending_refund_hcoin <= hcoin_balance;
+    let reentrancy_clear = !info.execution_in_progress;
+    let healthy = usdt_covered && hcoin_covered && reentrancy_clear;
+
+    if healthy {
+        msg!("🩺 Health check passed over {} caches and {} ledgers", caches_counted, ledgers_counted);
+    } else {
+        msg!(
+            "🔴 Health check failed: usdt_covered={}, hcoin_covered={}, reentrancy_clear={}",
+            usdt_covered,
+            hcoin_covered,
+            reentrancy_clear
+        );
+    }
+
+    emit!(HealthReport {
+        investment_id: info.investment_id,
+        version: info.version,
+        usdt_balance,
+        hcoin_balance,
+        pending_profit_usdt,
+        outstanding_accrual_usdt,
+        pending_refund_hcoin,
+        caches_counted,
+        ledgers_counted,
+        usdt_covered,
+        hcoin_covered,
+        reentrancy_clear,
+        healthy,
+        slot: clock.slot,
+        checked_at: now,
+    });
+
+    Ok(())
+}
+
+/// Re-create a closed vault ATA and verify its state
+/// 
+/// AUDIT CRITICAL - VAULT ATA REPAIR:
+/// Permissionless. A vault ATA closed by mistake would otherwise leave every deposit,
+/// payout and withdrawal of the investment failing. When the canonical ATA of the vault
+/// PDA is missing it is re-created through the associated token program (the payer
+/// funds the rent); an existing ATA is left in place. Either way the account is then
+/// verified (canonical address, owner = vault, mint), a delegate or vault-held close
+/// authority is cleared through guard_vault_token_account, and the balance is reported
+/// in VaultAtaRepaired. Run health_check afterwards to compare it with the obligations.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Investment must be active, so ATAs closed by close_vault stay closed
+/// - Mint must be USDT or H2COIN; ATA address must be the canonical vault ATA
+pub fn repair_vault_ata(ctx: Context<RepairVaultAta>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let mint = &ctx.accounts.mint;
+    let vault = &ctx.accounts.vault;
+    let ata_info = ctx.accounts.vault_token_account.to_account_info();
+
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(
+        mint.key() == info.profit_mint || mint.key() == ctx.accounts.global_config.hcoin_mint,
+        ErrorCode::InvalidTokenMint
+    );
+    require_keys_eq!(
+        ata_info.key(),
+        get_associated_token_address(&vault.key(), &mint.key()),
+        ErrorCode::InvalidVaultAta
+    );
+
+    // AUDIT: Only a missing ATA is created; the associated token program derives the same address
+    let recreated = ata_info.data_is_empty();
+    if recreated {
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: ata_info.clone(),
+                authority: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+        msg!("🛠️ Re-created vault ATA {} for mint {}", ata_info.key(), mint.key());
+    }
+
+    // AUDIT: Verify the (re-created) account is the vault's token account of the mint
+    require_keys_eq!(*ata_info.owner, TOKEN_PROGRAM_ID, ErrorCode::InvalidVaultAta);
+    let vault_token_account = token::TokenAccount::try_deserialize(&mut &ata_info.try_borrow_data()?[..])?;
+    require_keys_eq!(vault_token_account.owner, vault.key(), ErrorCode::InvalidVaultOwner);
+    require_keys_eq!(vault_token_account.mint, mint.key(), ErrorCode::InvalidVaultAta);
+
+    let vault_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    guard_token_account_state(
+        ctx.accounts.token_program.to_account_info(),
+        ata_info.clone(),
+        &vault_token_account,
+        vault.to_account_info(),
+        vault_seeds,
+        info,
+        now,
+    )?;
+
+    let balance = vault_token_account.amount;
+    let is_frozen = vault_token_account.is_frozen();
+    if is_frozen {
+        msg!("🔴 Vault ATA {} is frozen by the mint freeze authority", ata_info.key());
+    }
+
+    emit!(VaultAtaRepaired {
+        investment_id: info.investment_id,
+        version: info.version,
+        mint: mint.key(),
+        token_account: ata_info.key(),
+        recreated,
+        balance,
+        is_frozen,
+        repaired_by: ctx.accounts.payer.key(),
+        repaired_at: now,
+    });
+
+    Ok(())
+}
+
+/// Transfer an authorized withdrawal from the vault to the recipient
+/// 
+/// AUDIT CRITICAL:
+/// - Shared by withdraw_from_vault and execute_withdrawal once authorization, the
+///   configuration hash and the withdrawal sequence have been checked
+/// - Re-derives the vault PDA and re-checks the recipient against withdraw_whitelist
+/// - Clears or reports unexpected delegate / close authority before paying out
+/// - A zero amount skips its transfer
+fn pay_out_withdrawal<'info>(
+    info: &InvestmentInfo,
+    config: &InvestmentConfig,
+    program_id: &Pubkey,
+    vault: &AccountInfo<'info>,
+    vault_usdt_account: &Account<'info, token::TokenAccount>,
+    vault_hcoin_account: &Account<'info, token::TokenAccount>,
+    usdt_mint: &Account<'info, token::Mint>,
+    hcoin_mint: &Account<'info, token::Mint>,
+    recipient_account: &AccountInfo<'info>,
+    recipient_usdt_account: &Account<'info, token::TokenAccount>,
+    recipient_hcoin_account: &Account<'info, token::TokenAccount>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    memo_program: AccountInfo<'info>,
+    memo: &str,
+    usdt_amount: u64,
+    hcoin_amount: u64,
+    sol_amount: u64,
+    now: i64,
+) -> Result<()> {
+    // AUDIT: Derive vault PDA and verify correctness to prevent address spoofing
+    let (vault_pda, vault_bump) = Pubkey::find_program_address(
+        &[
+            b"vault", 
+            info.investment_id.as_ref(),
+            info.vault_version.as_ref(),
+        ],
+        program_id,
+    );
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[vault_bump],
+    ];
+    require!(
+        vault.key() == info.vault && vault_pda.key() == info.vault, 
+        ErrorCode::InvalidVaultPda
+    );
+
+    // AUDIT: Check recipient is on withdraw whitelist for authorization
+    require!(!config.withdraw_whitelist.is_empty(), ErrorCode::EmptyWhitelist);
+    require!(config.withdraw_whitelist.contains(&recipient_account.key()), ErrorCode::UnauthorizedRecipient);
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        token_program.clone(),
+        vault_usdt_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+    guard_vault_token_account(
+        token_program.clone(),
+        vault_hcoin_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+    // AUDIT: Transfer USDT if selected, balance > 0 and vault ATA owner is correct
+    if vault_usdt_account.mint == usdt_mint.key() && usdt_amount > 0 {
+        // AUDIT: Transfer token from vault ATA to recipient ATA with PDA authorization
+        transfer_token_checked(
+            token_program.clone(),
+            vault_usdt_account.to_account_info(),
+            recipient_usdt_account.to_account_info(),
+            usdt_mint.to_account_info(),
+            vault.to_account_info(),
+            Some(signer_seeds),
+            usdt_amount,
+            usdt_mint.decimals,
+            memo_program.clone(),
+            memo,
+        )?;
+    } else {
+        msg!("🟡 USDT not selected or vault USDT amount = 0, skip transfer");
+    }
+ 
+    // AUDIT: Transfer H2COIN if selected, balance > 0 and vault ATA owner is correct   
+    if vault_hcoin_account.mint == hcoin_mint.key() && hcoin_amount > 0 {
+        // AUDIT: Transfer token from vault ATA to recipient ATA with PDA authorization
+        transfer_token_checked(
+            token_program.clone(),
+            vault_hcoin_account.to_account_info(),
+            recipient_hcoin_account.to_account_info(),
+            hcoin_mint.to_account_info(),
+            vault.to_account_info(),
+            Some(signer_seeds),
+            hcoin_amount,
+            hcoin_mint.decimals,
+            memo_program.clone(),
+            memo,
+        )?;
+    } else {
+        msg!("🟡 H2COIN not selected or vault H2COIN amount = 0, skip transfer");
+    }
+
+    // AUDIT: Transfer SOL if selected and available with PDA authorization
+    if sol_amount > 0 {
+        let signer: &[&[&[u8]]] = &[signer_seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            system_program,
+            Transfer {
+                from: vault.to_account_info(),
+                to: recipient_account.to_account_info(),
+            },
+            signer,
+        );
+
+        system_program::transfer(cpi_ctx, sol_amount)?;
+    } else {
+        msg!("🟡 SOL not selected or no withdrawable SOL (rent-exempt only), skip transfer.");
+    }
+
+    Ok(())
+}
+
+/// Check the co-signers of a withdrawal and return their keys
+/// 
+/// AUDIT CRITICAL:
+/// - With withdrawal tiers set, the tier's signer count applies
+/// - Otherwise the Withdrawer role (legacy: 3-of-5 execute_whitelist) applies
+fn authorize_cosigned_withdrawal(
+    config: &InvestmentConfig,
+    role_config: Option<&RoleConfig>,
+    required_signers: Option<u8>,
+    remaining_accounts: &[AccountInfo],
+) -> Result<Vec<Pubkey>> {
+    let (signer_infos, _) = split_signer_accounts(remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    match required_signers {
+        Some(required) => config.enforce_withdraw_tier(role_config, required, &signer_keys)?,
+        None => config.enforce_role(role_config, Role::Withdrawer, signer_infos)?,
+    }
+    Ok(signer_keys)
+}
+
+/// Reimburse the executing payer from the vault's SOL for fees fronted
+/// 
+/// AUDIT CRITICAL:
+/// - Callers cap `lamports` at the cache's subtotal_estimate_sol and call this once per cache
+/// - The vault keeps at least its rent-exempt minimum
+fn reimburse_executor<'info>(
+    system_program: &Program<'info, System>,
+    vault: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    signer_seeds: &[&[u8]],
+    lamports: u64,
+) -> Result<u64> {
+    if lamports == 0 {
+        return Ok(0);
+    }
+    let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+    require!(
+        vault.lamports().saturating_sub(rent_exempt) >= lamports,
+        ErrorCode::InsufficientSolBalance
+    );
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: payer.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        lamports,
+    )?;
+    msg!("🟢 Reimbursed {} lamports to executor {}", lamports, payer.key());
+    Ok(lamports)
+}
+
+/// Authorize the fee payer of a claim and reimburse a sponsoring relayer
+/// 
+/// AUDIT CRITICAL:
+/// - A claim paid by the claimant itself is not sponsored and reimburses nothing
+/// - Otherwise the fee payer must be GlobalConfig.claim_relayer, and sponsorship enabled
+/// - The relayer receives estimate_sol_per_entry, capped by and deducted from `budget`
+///   when the claim draws on a cache's subtotal_estimate_sol
+fn sponsor_claim<'info>(
+    global: &GlobalConfig,
+    system_program: &Program<'info, System>,
+    vault: &AccountInfo<'info>,
+    fee_payer: &Signer<'info>,
+    claimant: &Pubkey,
+    signer_seeds: &[&[u8]],
+    budget: Option<&mut u64>,
+) -> Result<u64> {
+    if fee_payer.key() == *claimant {
+        return Ok(0);
+    }
+    require!(
+        global.claim_relayer != Pubkey::default() && fee_payer.key() == global.claim_relayer,
+        ErrorCode::UnauthorizedClaimRelayer
+    );
+
+    let lamports = match budget {
+        Some(budget) => {
+            let lamports = global.estimate_sol_per_entry.min(*budget);
+            *budget -= lamports;
+            lamports
+        }
+        None => global.estimate_sol_per_entry,
+    };
+    reimburse_executor(system_program, vault, fee_payer, signer_seeds, lamports)
+}
+
+/// Lamports of the vault that may be withdrawn
+/// 
+/// AUDIT: Everything above the rent-exempt minimum and the execution reserve of one entry
+fn withdrawable_vault_lamports(global: &GlobalConfig, vault: &AccountInfo) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+    Ok(vault.lamports()
+        .saturating_sub(rent_exempt)
+        .saturating_sub(global.estimate_sol_base)
+        .saturating_sub(global.estimate_sol_per_entry))
+}
+
+/// Amount of one asset to withdraw
+/// 
+/// AUDIT CRITICAL:
+/// - An unselected asset withdraws nothing and must not carry an amount
+/// - None withdraws the full `available` balance; Some(amount) must not exceed it
+fn resolve_withdraw_amount(selected: bool, requested: Option<u64>, available: u64) -> Result<u64> {
+    if !selected {
+        require!(requested.is_none(), ErrorCode::WithdrawAmountForUnselectedAsset);
+        return Ok(0);
+    }
+
+    match requested {
+        Some(amount) => {
+            require!(amount <= available, ErrorCode::WithdrawAmountExceedsBalance);
+            Ok(amount)
+        }
+        None => Ok(available),
+    }
+}
+
+/// Log the remaining compute units at a named checkpoint
+/// 
+/// AUDIT: Compiled to a no-op unless the program is built with the `cu-trace` feature
+#[inline(always)]
+fn cu_checkpoint(_label: &str) {
+    #[cfg(feature = "cu-trace")]
+    msg!("⏱️ CU [{}] remaining: {}", _label, sol_remaining_compute_units());
+}
+
+/// SOL the vault must hold to execute a batch of `entry_count` entries
+/// 
+/// AUDIT: GlobalConfig base and per-entry fees plus the priority fee for the compute units the
+/// execution budget guard reserves (CU_PER_TRANSFER per entry plus CU_EXECUTE_TAIL).
+/// `priority_fee_per_cu` is in micro-lamports; the fee is rounded up to whole lamports.
+fn estimate_execution_sol(global: &GlobalConfig, entry_count: u16, priority_fee_per_cu: u64) -> Result<u64> {
+    let compute_units = (entry_count as u64)
+        .checked_mul(CU_PER_TRANSFER)
+        .and_then(|v| v.checked_add(CU_EXECUTE_TAIL))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let priority_fee = (compute_units as u128)
+        .checked_mul(priority_fee_per_cu as u128)
+        .map(|v| v.div_ceil(1_000_000))
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    (entry_count as u64)
+        .checked_mul(global.estimate_sol_per_entry)
+        .and_then(|v| v.checked_add(global.estimate_sol_base))
+        .and_then(|v| v.checked_add(priority_fee))
+        .ok_or_else(|| error!(ErrorCode::NumericalOverflow))
+}
+
+/// Lamports the vault should hold to stay rent-exempt and fund a full batch
+/// 
+/// AUDIT: Rent-exempt minimum plus the SOL estimate of a MAX_ENTRIES_PER_BATCH batch,
+/// the same per-entry figures estimation charges against the vault
+fn required_sol_reserve(global: &GlobalConfig, vault: &AccountInfo) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
+    (MAX_ENTRIES_PER_BATCH as u64)
+        .checked_mul(global.estimate_sol_per_entry)
+        .and_then(|v| v.checked_add(global.estimate_sol_base))
+        .and_then(|v| v.checked_add(rent_exempt))
+        .ok_or_else(|| error!(ErrorCode::NumericalOverflow))
+}
+
+/// Require enough compute units for `transfers` more payouts plus the settlement tail
+/// 
+/// AUDIT CRITICAL - COMPUTE BUDGET GUARD:
+/// Fails with ComputeBudgetExceeded before a transfer is attempted that the
+/// remaining budget cannot finish, instead of an opaque compute exhaustion
+/// in the middle of the payout loop.
+/// 
+/// The first check reserves CU_PER_TRANSFER * entries + CU_EXECUTE_TAIL: 930,000 CU
+/// for a full MAX_ENTRIES_PER_BATCH batch, within MAX_TX_COMPUTE_UNITS. Under the
+/// default 200,000 CU limit a batch of about 5 entries fits; larger batches need a
+/// SetComputeUnitLimit instruction.
+/// 
+/// AUDIT POINTS:
+/// [ ] Confirm CU_PER_TRANSFER and CU_EXECUTE_TAIL cover a transfer_checked CPI and the final event
+fn require_cu_budget(transfers: usize) -> Result<()> {
+    let needed = CU_PER_TRANSFER
+        .checked_mul(transfers as u64)
+        .and_then(|v| v.checked_add(CU_EXECUTE_TAIL))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let remaining = sol_remaining_compute_units();
+    if remaining < needed {
+        msg!("🔴 CU budget: {} remaining, {} needed for {} transfer(s)", remaining, needed, transfers);
+        return err!(ErrorCode::ComputeBudgetExceeded);
+    }
+    Ok(())
+}
+
+/// Whether a batch's Merkle round is open or has paid claims
+/// 
+/// AUDIT CRITICAL:
+/// - An uninitialized PDA (no round ever published) is not active
+/// - A round with claims stays active after expiry or cancellation, so its claimants
+///   are not paid the batch again
+/// - Keeps estimate_profit_share / execute_profit_share from paying a batch whose
+///   Merkle round is active
+fn merkle_round_active(merkle_info: &AccountInfo, program_id: &Pubkey, now: i64) -> Result<bool> {
+    if merkle_info.data_is_empty() || merkle_info.owner != program_id {
+        return Ok(false);
+    }
+    let data = merkle_info.try_borrow_data()?;
+    let cache = ProfitMerkleCache::try_deserialize(&mut &data[..])?;
+    Ok(cache.is_claimable(now) || cache.claimed_count > 0)
+}
+
+/// Whether a batch's ProfitShareCache holds a live or executed round
+/// 
+/// AUDIT CRITICAL:
+/// - Live: estimated, not cancelled and not expired
+/// - Executed or partly paid rounds count as well; a closed cache does not
+/// - Keeps estimate_profit_share_merkle from publishing a round over the same batch
+fn profit_cache_round_active(
+    cache_info: &AccountInfo,
+    program_id: &Pubkey,
+    now: i64,
+    expire_secs: i64,
+) -> Result<bool> {
+    if cache_info.data_is_empty() || cache_info.owner != program_id {
+        return Ok(false);
+    }
+    let data = cache_info.try_borrow_data()?;
+    let cache = ProfitShareCache::try_deserialize(&mut &data[..])?;
+    let live = cache.created_at != 0
+        && cache.cancelled_at == 0
+        && now.saturating_sub(cache.created_at) <= expire_secs;
+    Ok(live
+        || cache.executed_at != 0
+        || cache.entries.iter().any(|e| e.status == EntryStatus::Paid))
+}
+
+/// Settle a profit cache once no entry is Pending
+/// 
+/// AUDIT CRITICAL - CACHE SETTLEMENT:
+/// Shared by execute_profit_share and claim_profit, so a cache settled by a claim is
+/// reconciled like one settled by execution: paid, escrowed and requeued entries must
+/// sum to subtotal_profit_usdt. Then executed_at is set and the schedule slot of the
+/// batch, when supplied, is marked executed.
+/// 
+/// Returns false while an entry is still Pending.
+fn settle_profit_cache(
+    cache: &mut ProfitShareCache,
+    schedule: Option<&mut Account<DistributionSchedule>>,
+    batch_id: u16,
+    now: i64,
+) -> Result<bool> {
+    if cache.entries.iter().any(|e| e.status == EntryStatus::Pending) {
+        return Ok(false);
+    }
+
+    let settled_usdt = cache
+        .entries
+        .iter()
+        .try_fold(0u64, |acc, e| acc.checked_add(e.amount_usdt))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    require!(
+        settled_usdt == cache.subtotal_profit_usdt,
+        ErrorCode::TotalShareMismatch
+    );
+
+    cache.executed_at = now;
+    mark_schedule_executed(schedule, DistributionKind::Profit, batch_id, 0, now)?;
+
+    Ok(true)
+}
+
+/// Settle a refund cache once no entry is Pending
+/// 
+/// AUDIT CRITICAL - CACHE SETTLEMENT:
+/// The refund counterpart of settle_profit_cache: paid, escrowed and requeued entries
+/// must sum to subtotal_refund_hcoin, then executed_at is set and the schedule slot of
+/// the batch and year, when supplied, is marked executed.
+/// 
+/// Returns false while an entry is still Pending.
+fn settle_refund_cache(
+    cache: &mut RefundShareCache,
+    schedule: Option<&mut Account<DistributionSchedule>>,
+    batch_id: u16,
+    year_index: u8,
+    now: i64,
+) -> Result<bool> {
+    if cache.entries.iter().any(|e| e.status == EntryStatus::Pending) {
+        return Ok(false);
+    }
+
+    let settled_hcoin = cache
+        .entries
+        .iter()
+        .try_fold(0u64, |acc, e| acc.checked_add(e.amount_hcoin))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    require!(
+        settled_hcoin == cache.subtotal_refund_hcoin,
+        ErrorCode::TotalShareMismatch
+    );
+
+    cache.executed_at = now;
+    mark_schedule_executed(schedule, DistributionKind::Refund, batch_id, year_index, now)?;
+
+    Ok(true)
+}
+
+/// Mark the planned round of a settled cache executed when a schedule is referenced
+fn mark_schedule_executed(
+    schedule: Option<&mut Account<DistributionSchedule>>,
+    kind: DistributionKind,
+    batch_id: u16,
+    year_index: u8,
+    now: i64,
+) -> Result<()> {
+    if let Some(schedule) = schedule {
+        let slot = schedule.find_slot(kind, batch_id, year_index)?;
+        schedule.slots[slot].executed_at = now;
+        schedule.updated_at = now;
+        msg!("🟢 Schedule slot {} marked executed", slot);
+    }
+    Ok(())
+}
+
+/// Order pending cache entries for execution
+/// 
+/// AUDIT CRITICAL - RETRY PRIORITY:
+/// Returns the indices of all `Pending` entries, previously failed entries first
+/// (highest failure_count first). The sort is stable, so entries with equal
+/// failure counts keep their cache (record_id) order.
+/// 
+/// AUDIT POINTS:
+/// [ ] Confirm Paid, Escrowed and Requeued entries are never returned
+fn retry_order(entries: &[(EntryStatus, u8)]) -> Vec<usize> {
+    let mut order: Vec<usize> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, (status, _))| *status == EntryStatus::Pending)
+        .map(|(i, _)| i)
+        .collect();
+    order.sort_by(|a, b| entries[*b].1.cmp(&entries[*a].1));
+    order
+}
+
+/// Whether a payout failed because the recipient ATA is closed (or was never created)
+/// 
+/// AUDIT: Matches only RecipientAtaNotInitialized from validate_recipient_ata; an ATA
+/// missing from remaining_accounts is an operator omission and stays a failure
+fn is_closed_recipient_ata(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::AnchorError(error)
+            if error.error_code_number == u32::from(ErrorCode::RecipientAtaNotInitialized)
+    )
+}
+
+/// Reject untrusted CPI callers and re-entry into the fund-moving instructions
+/// 
+/// AUDIT CRITICAL - EXECUTION GUARD:
+/// Entered by every fund-moving instruction (FREEZE_FAMILIES) before anything else:
+/// - Top-level calls are accepted; a CPI is only accepted directly from the Squads
+///   program (stack height 2), which executes multisig-approved transactions
+/// - InvestmentInfo.execution_in_progress is set and written to the account at once,
+///   since Anchor otherwise serializes only when the instruction returns, so a
+///   nested call sees it and is rejected
+/// 
+/// The caller clears the flag with end_execution before returning; a failed
+/// transaction reverts the flag together with everything else.
+fn begin_execution(
+    info: &mut Account<InvestmentInfo>,
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    let stack_height = get_stack_height();
+    if stack_height > TRANSACTION_LEVEL_STACK_HEIGHT {
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        let top_level = load_instruction_at_checked(current_index as usize, instructions_sysvar)?;
+        require!(
+            stack_height == TRANSACTION_LEVEL_STACK_HEIGHT + 1
+                && top_level.program_id == SQUADS_PROGRAM_ID,
+            ErrorCode::UntrustedCpiCaller
+        );
+    }
+
+    require!(!info.execution_in_progress, ErrorCode::ExecutionInProgress);
+    info.execution_in_progress = true;
+    info.exit(&crate::ID)
+}
+
+/// Clear the reentrancy flag set by begin_execution
+/// 
+/// AUDIT: Serialized with the rest of InvestmentInfo when the instruction returns
+fn end_execution(info: &mut Account<InvestmentInfo>) {
+    info.execution_in_progress = false;
+}
+
+/// Clear or report unexpected authorities on a vault token account
+/// 
+/// AUDIT CRITICAL - TOKEN ACCOUNT TAMPERING GUARD:
+/// Vault token accounts are owned by the vault PDA and should never carry a delegate
+/// or a foreign close authority. Before any payout:
+/// - a delegate is revoked via CPI (the vault, as owner, is authorized to do so)
+/// - a close authority held by the vault itself is cleared via CPI
+/// - a close authority held by anyone else cannot be cleared by the program and is reported
+/// 
+/// Every finding is emitted as `VaultTokenAccountGuarded`.
+fn guard_vault_token_account<'info>(
+    token_program: AccountInfo<'info>,
+    vault_token_account: &Account<'info, token::TokenAccount>,
+    vault: AccountInfo<'info>,
+    vault_seeds: &[&[u8]],
+    info: &InvestmentInfo,
+    now: i64,
+) -> Result<()> {
+    guard_token_account_state(
+        token_program,
+        vault_token_account.to_account_info(),
+        vault_token_account,
+        vault,
+        vault_seeds,
+        info,
+        now,
+    )
+}
+
+/// Body of guard_vault_token_account over an already deserialized token account
+/// 
+/// AUDIT: `state` must be the deserialized contents of `vault_token_account`
+fn guard_token_account_state<'info>(
+    token_program: AccountInfo<'info>,
+    vault_token_account: AccountInfo<'info>,
+    state: &token::TokenAccount,
+    vault: AccountInfo<'info>,
+    vault_seeds: &[&[u8]],
+    info: &InvestmentInfo,
+    now: i64,
+) -> Result<()> {
+    let delegate: Option<Pubkey> = state.delegate.into();
+    let close_authority: Option<Pubkey> = state.close_authority.into();
+    if delegate.is_none() && close_authority.is_none() {
+        return Ok(());
+    }
+
+    require!(
+        token_program.key() == TOKEN_PROGRAM_ID,
+        ErrorCode::InvalidTokenProgramID
+    );
+    let signer: &[&[&[u8]]] = &[vault_seeds];
+
+    // AUDIT: The owner can always revoke a delegate
+    if delegate.is_some() {
+        token::revoke(CpiContext::new_with_signer(
+            token_program.clone(),
+            Revoke {
+                source: vault_token_account.clone(),
+                authority: vault.clone(),
+            },
+            signer,
+        ))?;
+    }
+
+    // AUDIT: Only a close authority held by the vault can be cleared by the program
+    let close_authority_cleared = close_authority == Some(vault.key());
+    if close_authority_cleared {
+        token::set_authority(
+            CpiContext::new_with_signer(
+                token_program,
+                SetAuthority {
+                    account_or_mint: vault_token_account.clone(),
+                    current_authority: vault,
+                },
+                signer,
+            ),
+            AuthorityType::CloseAccount,
+            None,
+        )?;
+    } else if let Some(authority) = close_authority {
+        msg!(
+            "🔴 Vault token account {} has foreign close authority {}",
+            vault_token_account.key(),
+            authority
+        );
+    }
+
+    emit!(VaultTokenAccountGuarded {
+        investment_id: info.investment_id,
+        version: info.version,
+        token_account: vault_token_account.key(),
+        mint: state.mint,
+        delegate,
+        delegate_revoked: delegate.is_some(),
+        close_authority,
+        close_authority_cleared,
+        detected_at: now,
+    });
+
+    Ok(())
+}
+
+/// Re-validate a recipient ATA found in remaining_accounts before paying it
+/// 
+/// AUDIT CRITICAL - RECIPIENT ATA VALIDATION:
+/// Execution locates the recipient ATA by address only. A closed or never-created
+/// account at that address would still match, so the account is deserialized and its
+/// token-account fields are checked against the cache entry.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Account must be owned by the token program and initialized
+/// - Token account owner must be the entry wallet
+/// - Token account mint must be the mint being paid
+fn validate_recipient_ata(
+    recipient_ata_info: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<()> {
+    // AUDIT: A closed ATA is handed back to the system program with no data
+    require!(
+        recipient_ata_info.owner == &TOKEN_PROGRAM_ID && !recipient_ata_info.data_is_empty(),
+        ErrorCode::RecipientAtaNotInitialized
+    );
+
+    // AUDIT: Unpacking rejects uninitialized token accounts
+    let recipient_ata = token::TokenAccount::try_deserialize(&mut &recipient_ata_info.try_borrow_data()?[..])
+        .map_err(|_| error!(ErrorCode::RecipientAtaNotInitialized))?;
+
+    require_keys_eq!(recipient_ata.owner, *wallet, ErrorCode::InvalidRecipientOwner);
+    require_keys_eq!(recipient_ata.mint, *mint, ErrorCode::InvalidRecipientMint);
+    Ok(())
+}
+
+/// Screen a record wallet against the exchange registry
+/// 
+/// AUDIT CRITICAL - EXCHANGE WALLET SCREENING:
+/// Payouts to exchange deposit wallets are frequently lost or credited to the wrong
+/// exchange account. A wallet matches when its address or its owning program is
+/// listed in the ExchangeRegistry. Returns whether the wallet matched and was allowed,
+/// so the caller can emit ExchangeWalletFlagged.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Registry address is fixed by the context seeds
+/// - A registry not yet created (or not owned by this program) screens nothing
+/// - A match without allow_exchange_wallet is rejected
+fn screen_exchange_wallet(
+    program_id: &Pubkey,
+    registry_info: &AccountInfo,
+    wallet_info: &AccountInfo,
+    allow_exchange_wallet: bool,
+) -> Result<bool> {
+    if registry_info.owner != program_id || registry_info.data_is_empty() {
+        return Ok(false);
+    }
+    let registry = ExchangeRegistry::try_deserialize(&mut &registry_info.try_borrow_data()?[..])?;
+    if !registry.matches(wallet_info) {
+        return Ok(false);
+    }
+
+    // AUDIT: Intentional exchange payouts need an explicit operator override
+    require!(allow_exchange_wallet, ErrorCode::ExchangeWalletNotAllowed);
+    Ok(true)
+}
+
+/// Execute token transfer with comprehensive validation
+/// 
+/// AUDIT CRITICAL - TOKEN TRANSFER UTILITY:
+/// This utility function handles SPL token transfers with comprehensive validation.
+/// It supports both regular wallet and PDA-based transfers.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Token program ID validation
+/// - Recipient account ownership validation
+/// - PDA signer seed validation
+/// - Transfer amount and decimal validation
+/// - Safe CPI call construction
+/// 
+/// AUDIT POINTS:
+/// [ ] Verify token program ID validation
+/// [ ] Check recipient account ownership
+/// [ ] Review PDA signer seed handling
+/// [ ] Confirm transfer amount validation
+/// [ ] Validate CPI call security
+#[allow(clippy::too_many_arguments)]
+fn transfer_token_checked<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    authority_seeds: Option<&[&[u8]]>,
+    amount: u64,
+    decimals: u8,
+    memo_program: AccountInfo<'info>,
+    memo: &str,
+) -> Result<()> {
+    // AUDIT: Validate token program ID to prevent unauthorized transfers
+    require!(
+        token_program.key() == TOKEN_PROGRAM_ID,
+        ErrorCode::InvalidTokenProgramID
+    );
+
+    // AUDIT: Validate recipient account ownership for security
+    require!(
+        to.owner == &TOKEN_PROGRAM_ID,
+        ErrorCode::InvalidRecipientOwner
+    );
+
+    let cpi_accounts = TransferChecked {
+        from,
+        to,
+        mint,
+        authority,
+    };
+
+    // AUDIT: Handle PDA-based transfers with proper signer seeds
+    if let Some(seeds_inner) = authority_seeds {
+        if !seeds_inner.is_empty() {
+            msg!("🟢 using PDA signer with {} seed(s)", seeds_inner.len());
+            let signer: &[&[&[u8]]] = &[seeds_inner];
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_program,
+                cpi_accounts,
+                signer,
+            );
+            token::transfer_checked(cpi_ctx, amount, decimals)?;
+        } else {
+            msg!("🟢 signer seeds is empty → using no signer");
+            let cpi_ctx = CpiContext::new(
+                token_program,
+                cpi_accounts,
+            );
+            token::transfer_checked(cpi_ctx, amount, decimals)?;
+        }
+    } else {
+        // AUDIT: Handle regular wallet-based transfers
+        msg!("🟢 no signer (authority is expected to be a wallet)");
+        let cpi_ctx = CpiContext::new(
+            token_program,
+            cpi_accounts,
+        );
+        token::transfer_checked(cpi_ctx, amount, decimals)?;
+    }
+
+    // AUDIT: Tag the completed transfer so recipients can reconcile it from history
+    require_keys_eq!(memo_program.key(), memo::ID, ErrorCode::InvalidMemoProgram);
+    memo::build_memo(CpiContext::new(memo_program, BuildMemo {}), memo.as_bytes())?;
+
+    Ok(())
+}
+
+/// Reconciliation tag sent as an SPL Memo with every token transfer
+/// 
+/// AUDIT CRITICAL:
+/// - Canonical form: `h2coin:{"inv":…,"ver":…,"kind":…[,"acct":…][,"<ref>":n]…}`
+/// - Fields appear in a fixed order so custodians can match them as plain text
+/// - Identifiers are cut at the first NUL and stripped of quotes, backslashes and
+///   non-printable bytes, so the tag is always valid UTF-8 and valid JSON
+fn transfer_memo(
+    info: &InvestmentInfo,
+    kind: &str,
+    account_id: Option<&[u8; 15]>,
+    refs: &[(&str, u64)],
+) -> String {
+    fn memo_id(bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .take_while(|b| **b != 0)
+            .filter(|b| b.is_ascii_graphic() && **b != b'"' && **b != b'\\')
+            .map(|b| *b as char)
+            .collect()
+    }
+
+    let mut tag = format!(
+        "h2coin:{{\"inv\":\"{}\",\"ver\":\"{}\",\"kind\":\"{}\"",
+        memo_id(&info.investment_id),
+        memo_id(&info.version),
+        kind
+    );
+    if let Some(account_id) = account_id {
+        tag.push_str(&format!(",\"acct\":\"{}\"", memo_id(account_id)));
+    }
+    for (key, value) in refs {
+        tag.push_str(&format!(",\"{}\":{}", key, value));
+    }
+    tag.push('}');
+    tag
+}
+
+/// Validate an optional human-readable note of a distribution execution
+/// 
+/// AUDIT: Rejected rather than sanitized, so the memo carries exactly the text the
+/// signers approved
+fn validate_transfer_note(note: Option<&str>) -> Result<()> {
+    if let Some(note) = note {
+        require!(
+            !note.is_empty()
+                && note.len() <= MAX_TRANSFER_NOTE_LEN
+                && !note.chars().any(|c| c.is_control() || c == '"' || c == '\\'),
+            ErrorCode::InvalidTransferNote
+        );
+    }
+    Ok(())
+}
+
+/// Append a validated note to a reconciliation tag as its last field
+/// 
+/// AUDIT: `h2coin:{…,"note":"2025 Q2 profit, batch 7"}`; the tag is unchanged without a note
+fn with_transfer_note(mut tag: String, note: Option<&str>) -> String {
+    if let Some(note) = note {
+        tag.pop();
+        tag.push_str(&format!(",\"note\":\"{}\"}}", note));
+    }
+    tag
+}
+
+
+//================ PROGRAM CONFIGURATION ================
+// AUDIT: These functions manage the program-wide configuration
+// SECURITY: Emergency stop across all investments
+
+/// Initialize the program-wide configuration
+/// 
+/// AUDIT CRITICAL - PROGRAM CONFIG INITIALIZATION:
+/// Creates the singleton ProgramConfig PDA; the `authority` signer becomes the
+/// config authority. Normally that signer is the current program upgrade authority.
+/// Once initialize_upgrade_governance moved the upgrade authority to the governance
+/// PDA (or the committee renounced it), the PDA cannot sign, so 3-of-5 of the upgrade
+/// committee approve `authority` instead. A multisig vault holding the upgrade
+/// authority signs as `authority` through its own execution.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - ProgramData account must match this program's programdata address
+/// - Signer must be the upgrade authority, or the upgrade authority is the governance
+///   PDA (or renounced) and 3-of-5 committee members sign
+/// - PDA `init` prevents re-initialization
+/// 
+/// remaining_accounts: [committee_signer_1, ..., committee_signer_n] (governance path only)
+pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+
+    // AUDIT: Only the upgrade authority, or the committee governing it, may claim the config
+    require!(
+        ctx.accounts.program.programdata_address()? == Some(ctx.accounts.program_data.key()),
+        ErrorCode::UnauthorizedConfigAuthority
+    );
+    let upgrade_authority = ctx.accounts.program_data.upgrade_authority_address;
+    if upgrade_authority != Some(authority) {
+        let governance = ctx
+            .accounts
+            .governance
+            .as_ref()
+            .ok_or(ErrorCode::UnauthorizedConfigAuthority)?;
+        require!(
+            upgrade_authority == Some(governance.key()) || upgrade_authority.is_none(),
+            ErrorCode::UnauthorizedConfigAuthority
+        );
+        governance.enforce_3_of_5_signers(ctx.remaining_accounts)?;
+        msg!("🟢 Program config authority {} approved by the upgrade committee", authority);
+    }
+
+    let config = &mut ctx.accounts.program_config;
+    config.authority = authority;
+    config.paused = false;
+    config.updated_at = now;
+    config.created_at = now;
+
+    emit!(ProgramConfigInitialized {
+        authority,
+        created_at: now,
+    });
+
+    Ok(())
+}
+
+/// Toggle the program-wide emergency pause
+/// 
+/// AUDIT CRITICAL - EMERGENCY STOP:
+/// While paused, execute_profit_share, execute_refund_share and withdraw_from_vault
+/// are rejected for every investment. Estimation and record management stay available.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the config authority
+pub fn set_program_pause(ctx: Context<SetProgramPause>, paused: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.program_config;
+
+    // AUDIT: Only the config authority may toggle the pause
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        config.authority,
+        ErrorCode::UnauthorizedConfigAuthority
+    );
+
+    config.paused = paused;
+    config.updated_at = now;
+
+    msg!("🟢 Program pause set to {} by {}", paused, config.authority);
+
+    emit!(ProgramPauseUpdated {
+        paused,
+        updated_by: config.authority,
+        updated_at: now,
+    });
+
+    Ok(())
+}
+
+/// Publish the program limits on chain
+/// 
+/// AUDIT CRITICAL - LIMITS PUBLICATION:
+/// Creates the singleton LimitsConfig PDA filled from the compiled constants, so
+/// integrators read batch sizes, cache expiry, year range, whitelist length and
+/// thresholds from chain instead of hard-coding them.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the config authority
+/// - PDA `init` prevents re-initialization
+pub fn initialize_limits_config(ctx: Context<InitializeLimitsConfig>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+
+    // AUDIT: Only the config authority may publish limits
+    require_keys_eq!(
+        authority,
+        ctx.accounts.program_config.authority,
+        ErrorCode::UnauthorizedConfigAuthority
+    );
+
+    let limits = &mut ctx.accounts.limits_config;
+    limits.load_defaults();
+    limits.updated_at = now;
+    limits.created_at = now;
+
+    emit!(LimitsConfigUpdated {
+        max_entries_per_batch: limits.max_entries_per_batch,
+        share_cache_expire_secs: limits.share_cache_expire_secs,
+        max_upgrade_window_secs: limits.max_upgrade_window_secs,
+        updated_by: authority,
+        updated_at: now,
+    });
+
+    Ok(())
+}
+
+/// Override published operating limits
+/// 
+/// AUDIT CRITICAL - GOVERNANCE OVERRIDE:
+/// Only ceilings can be overridden and only downwards: the compiled constants stay
+/// the hard limits enforced by the program, and the override publishes the tighter
+/// operating limit that integrators and operators are expected to honour.
+/// `None` leaves a field unchanged.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the config authority
+/// - Every override must be positive and not exceed its compiled constant
+pub fn set_limits_config(
+    ctx: Context<SetLimitsConfig>,
+    max_entries_per_batch: Option<u16>,
+    share_cache_expire_secs: Option<i64>,
+    max_upgrade_window_secs: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+
+    // AUDIT: Only the config authority may override limits
+    require_keys_eq!(
+        authority,
+        ctx.accounts.program_config.authority,
+        ErrorCode::UnauthorizedConfigAuthority
+    );
+
+    let limits = &mut ctx.accounts.limits_config;
+
+    // AUDIT: Overrides can never loosen the compiled ceilings
+    if let Some(value) = max_entries_per_batch {
+        require!(
+            value > 0 && value as usize <= MAX_ENTRIES_PER_BATCH,
+            ErrorCode::InvalidLimitsConfig
+        );
+        limits.max_entries_per_batch = value;
+    }
+    if let Some(value) = share_cache_expire_secs {
+        require!(
+            value > 0 && value <= SHARE_CACHE_EXPIRE_SECS,
+            ErrorCode::InvalidLimitsConfig
+        );
+        limits.share_cache_expire_secs = value;
+    }
+    if let Some(value) = max_upgrade_window_secs {
+        require!(
+            value > 0 && value <= MAX_UPGRADE_WINDOW_SECS,
+            ErrorCode::InvalidLimitsConfig
+        );
+        limits.max_upgrade_window_secs = value;
+    }
+    limits.updated_at = now;
+
+    msg!("🟢 Limits config updated by {}", authority);
+
+    emit!(LimitsConfigUpdated {
+        max_entries_per_batch: limits.max_entries_per_batch,
+        share_cache_expire_secs: limits.share_cache_expire_secs,
+        max_upgrade_window_secs: limits.max_upgrade_window_secs,
+        updated_by: authority,
+        updated_at: now,
+    });
+
+    Ok(())
+}
+
+/// Create the global parameters from the compiled constants
+/// 
+/// AUDIT CRITICAL - GLOBAL CONFIG INITIALIZATION:
+/// Creates the singleton GlobalConfig PDA holding the mints, the SOL fee estimates
+/// and the cache expiry, filled from the compiled constants of this build. From
+/// then on instructions read these values from the account and only the committee
+/// set here can change them.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the config authority
+/// - Committee must have exactly 5 unique members
+/// - PDA `init` prevents re-initialization
+pub fn initialize_global_config(
+    ctx: Context<InitializeGlobalConfig>,
+    committee: Vec<Pubkey>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+
+    // AUDIT: Only the config authority may create the global parameters
+    require_keys_eq!(
+        authority,
+        ctx.accounts.program_config.authority,
+        ErrorCode::UnauthorizedConfigAuthority
+    );
+
+    // AUDIT: Committee must have exactly 5 unique members
+    require!(committee.len() == MAX_WHITELIST_LEN, ErrorCode::WhitelistMustBeFive);
+    let unique: HashSet<Pubkey> = committee.iter().copied().collect();
+    require!(unique.len() == MAX_WHITELIST_LEN, ErrorCode::WhitelistMustBeFive);
+
+    let global = &mut ctx.accounts.global_config;
+    global.committee = committee;
+    global.load_defaults();
+    global.validate()?;
+    global.bump = ctx.bumps.global_config;
+    global.updated_at = now;
+    global.created_at = now;
+
+    emit!(GlobalConfigUpdated {
+        usdt_mint: global.usdt_mint,
+        hcoin_mint: global.hcoin_mint,
+        allowed_profit_mints: global.allowed_profit_mints.clone(),
+        estimate_sol_base: global.estimate_sol_base,
+        estimate_sol_per_entry: global.estimate_sol_per_entry,
+        share_cache_expire_secs: global.share_cache_expire_secs,
+        claim_relayer: global.claim_relayer,
+        updated_by: authority,
+        updated_at: now,
+        signers: Vec::new(),
+    });
+
+    Ok(())
+}
+
+/// Change the global parameters
+/// 
+/// AUDIT CRITICAL - GOVERNANCE CHANGE:
+/// `None` leaves a parameter unchanged. The whole resulting set is validated, so a
+/// change cannot leave the account inconsistent (e.g. H2COIN listed as a profit mint).
+/// Investments keep the profit_mint they were created with; changing hcoin_mint
+/// applies to every investment immediately, so their vault H2COIN balances must be
+/// migrated first. claim_relayer = Some(Pubkey::default()) disables sponsored claims.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from the GlobalConfig committee
+/// - GlobalConfig::validate on the resulting parameter set
+#[allow(clippy::too_many_arguments)]
+pub fn set_global_config(
+    ctx: Context<SetGlobalConfig>,
+    usdt_mint: Option<Pubkey>,
+    hcoin_mint: Option<Pubkey>,
+    allowed_profit_mints: Option<Vec<Pubkey>>,
+    estimate_sol_base: Option<u64>,
+    estimate_sol_per_entry: Option<u64>,
+    share_cache_expire_secs: Option<i64>,
+    claim_relayer: Option<Pubkey>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let global = &mut ctx.accounts.global_config;
+
+    // AUDIT: 3-of-5 multisig validation from the GlobalConfig committee
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    global.enforce_3_of_5_signers(signer_infos)?;
+
+    if let Some(mint) = usdt_mint {
+        global.usdt_mint = mint;
+    }
+    if let Some(mint) = hcoin_mint {
+        global.hcoin_mint = mint;
+    }
+    if let Some(mints) = allowed_profit_mints {
+        global.allowed_profit_mints = mints;
+    }
+    if let Some(value) = estimate_sol_base {
+        global.estimate_sol_base = value;
+    }
+    if let Some(value) = estimate_sol_per_entry {
+        global.estimate_sol_per_entry = value;
+    }
+    if let Some(value) = share_cache_expire_secs {
+        global.share_cache_expire_secs = value;
+    }
+    if let Some(relayer) = claim_relayer {
+        global.claim_relayer = relayer;
+    }
+    global.validate()?;
+    global.updated_at = now;
+
+    msg!("🟢 Global config updated by {}", ctx.accounts.payer.key());
+
+    emit!(GlobalConfigUpdated {
+        usdt_mint: global.usdt_mint,
+        hcoin_mint: global.hcoin_mint,
+        allowed_profit_mints: global.allowed_profit_mints.clone(),
+        estimate_sol_base: global.estimate_sol_base,
+        estimate_sol_per_entry: global.estimate_sol_per_entry,
+        share_cache_expire_secs: global.share_cache_expire_secs,
+        claim_relayer: global.claim_relayer,
+        updated_by: ctx.accounts.payer.key(),
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Validate a deployment end to end and emit a readiness report
+/// 
+/// AUDIT CRITICAL - DEPLOYMENT GATE:
+/// Checks the program config, the expected mints and one investment's PDAs and vault
+/// ATAs in a single call. Nothing is rejected or written: every check is reported in
+/// `BootstrapReport`, whose `ready` flag release automation uses as its gate.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - ProgramConfig and GlobalConfig PDAs, owners and discriminators
+/// - Profit / H2COIN mints against InvestmentInfo.profit_mint / GlobalConfig.hcoin_mint
+///   (get_hcoin_mint while GlobalConfig is missing)
+/// - InvestmentInfo, InvestmentConfig and vault PDAs (with stored bumps)
+/// - Vault rent exemption and vault ATA addresses, mints and owners
+pub fn bootstrap_check(ctx: Context<BootstrapCheck>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let program_id = ctx.program_id;
+    let info = &ctx.accounts.investment_info;
+    let vault = ctx.accounts.vault.to_account_info();
+
+    // AUDIT: Program config singleton
+    let (config_pda, _bump) = Pubkey::find_program_address(&[b"program_config"], program_id);
+    let program_config = ctx.accounts.program_config.to_account_info();
+    let program_config = if program_config.key() == config_pda && program_config.owner == program_id {
+        ProgramConfig::try_deserialize(&mut &program_config.try_borrow_data()?[..]).ok()
+    } else {
+        None
+    };
+    let program_config_ok = program_config.is_some();
+    let paused = program_config.map(|c| c.paused).unwrap_or(false);
+
+    // AUDIT: Global parameters singleton
+    let (global_pda, _bump) = Pubkey::find_program_address(&[b"global_config"], program_id);
+    let global_config = ctx.accounts.global_config.to_account_info();
+    let global_config = if global_config.key() == global_pda && global_config.owner == program_id {
+        GlobalConfig::try_deserialize(&mut &global_config.try_borrow_data()?[..]).ok()
+    } else {
+        None
+    };
+    let global_config_ok = global_config.is_some();
+    let hcoin_mint = global_config.map(|g| g.hcoin_mint).unwrap_or_else(get_hcoin_mint);
+
+    // AUDIT: Mints must be the expected ones and real token mints
+    let mint_ok = |acc: &AccountInfo, expected: Pubkey| {
+        acc.key() == expected
+            && acc.owner == &TOKEN_PROGRAM_ID
+            && acc
+                .try_borrow_data()
+                .map(|data| token::Mint::try_deserialize(&mut &data[..]).is_ok())
+                .unwrap_or(false)
+    };
+    let usdt_mint_ok = mint_ok(&ctx.accounts.usdt_mint.to_account_info(), info.profit_mint);
+    let hcoin_mint_ok = mint_ok(&ctx.accounts.hcoin_mint.to_account_info(), hcoin_mint);
+
+    // AUDIT: Investment PDAs with their stored bumps
+    let pda_ok = |seed: &[u8], key: Pubkey, bump: u8| {
+        Pubkey::create_program_address(
+            &[seed, info.investment_id.as_ref(), info.version.as_ref(), &[bump]],
+            program_id,
+        )
+        .map(|pda| pda == key)
+        .unwrap_or(false)
+    };
+    let investment_info_ok = pda_ok(b"investment", info.key(), info.bump);
+
+    let investment_config = ctx.accounts.investment_config.to_account_info();
+    let investment_config_ok = pda_ok(b"investment_config", investment_config.key(), info.config_bump)
+        && investment_config.owner == program_id
+        && InvestmentConfig::try_deserialize(&mut &investment_config.try_borrow_data()?[..]).is_ok();
+
+    let vault_ok = Pubkey::create_program_address(
+        &[b"vault", info.investment_id.as_ref(), info.vault_version.as_ref(), &[info.vault_bump]],
+        program_id,
+    )
+    .map(|pda| pda == vault.key())
+    .unwrap_or(false)
+        && vault.key() == info.vault
+        && Rent::get()?.is_exempt(vault.lamports(), vault.data_len());
+
+    // AUDIT: Vault ATAs must be the canonical ATAs, with the right mint and owner
+    let ata_ok = |acc: &AccountInfo, mint: Pubkey| {
+        acc.key() == get_associated_token_address(&vault.key(), &mint)
+            && acc.owner == &TOKEN_PROGRAM_ID
+            && acc
+                .try_borrow_data()
+                .ok()
+                .and_then(|data| token::TokenAccount::try_deserialize(&mut &data[..]).ok())
+                .map(|ata| ata.mint == mint && ata.owner == vault.key())
+                .unwrap_or(false)
+    };
+    let vault_usdt_ata_ok = ata_ok(&ctx.accounts.vault_usdt_account.to_account_info(), info.profit_mint);
+    let vault_hcoin_ata_ok = ata_ok(&ctx.accounts.vault_hcoin_account.to_account_info(), hcoin_mint);
+
+    let ready = program_config_ok
+        && !paused
+        && global_config_ok
+        && usdt_mint_ok
+        && hcoin_mint_ok
+        && investment_info_ok
+        && investment_config_ok
+        && vault_ok
+        && vault_usdt_ata_ok
+        && vault_hcoin_ata_ok
+        && info.is_active;
+
+    if ready {
+        msg!("🟢 Deployment ready");
+    } else {
+        msg!("🔴 Deployment not ready; see BootstrapReport");
+    }
+
+    emit!(BootstrapReport {
+        investment_id: info.investment_id,
+        version: info.version,
+        program_config_ok,
+        paused,
+        global_config_ok,
+        usdt_mint_ok,
+        hcoin_mint_ok,
+        investment_info_ok,
+        investment_config_ok,
+        vault_ok,
+        vault_usdt_ata_ok,
+        vault_hcoin_ata_ok,
+        is_active: info.is_active,
+        ready,
+        checked_by: ctx.accounts.payer.key(),
+        checked_at: now,
+    });
+
+    Ok(())
+}
+
+
+//================ DISTRIBUTION SCHEDULE ================
+// AUDIT: These functions manage the on-chain distribution calendar
+// SECURITY: Slot registration requires committee multisig
+
+/// Adds a planned distribution round to the investment's schedule
+/// 
+/// AUDIT CRITICAL - DISTRIBUTION CALENDAR:
+/// The committee pre-registers profit rounds and refund years with target dates.
+/// Cranks and UIs read the schedule; executions that pass it mark their slot executed.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from update_whitelist
+/// - Investment must be active
+/// - Refund year must be within START_YEAR_INDEX..=MAX_YEAR_INDEX; profit rounds use 0
+/// - (kind, batch_id, year_index) must be unique
+/// - Slot count bounded by MAX_SCHEDULE_SLOTS
+/// 
+/// PARAMETERS:
+/// - kind: Profit or Refund round
+/// - batch_id: Batch the round distributes to
+/// - year_index: Refund year (must be 0 for profit rounds)
+/// - target_at: Planned execution date (UNIX timestamp)
+pub fn schedule_distribution(
+    ctx: Context<ScheduleDistribution>,
+    kind: DistributionKind,
+    batch_id: u16,
+    year_index: u8,
+    target_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let schedule = &mut ctx.accounts.schedule;
+
+    // AUDIT: Validate investment is active
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: 3-of-5 multisig validation from update_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, true)?;
+
+    // AUDIT: Validate the round identifier
+    match kind {
+        DistributionKind::Profit => require!(year_index == 0, ErrorCode::RefundPeriodInvalid),
+        DistributionKind::Refund => require!(
+            (START_YEAR_INDEX..=MAX_YEAR_INDEX).contains(&year_index),
+            ErrorCode::RefundPeriodInvalid
+        ),
+    }
+
+    // AUDIT: First use initializes the schedule identity
+    if schedule.slots.is_empty() {
+        schedule.investment_id = info.investment_id;
+        schedule.version = info.version;
+    }
+
+    require!(schedule.find_slot(kind, batch_id, year_index).is_err(), ErrorCode::ScheduleSlotExists);
+    require!(schedule.slots.len() < MAX_SCHEDULE_SLOTS, ErrorCode::ScheduleFull);
+
+    schedule.slots.push(ScheduleSlot {
+        kind,
+        batch_id,
+        year_index,
+        target_at,
+        executed_at: 0,
+    });
+    schedule.updated_at = now;
+
+    emit!(DistributionScheduled {
+        investment_id: info.investment_id,
+        version: info.version,
+        kind,
+        batch_id,
+        year_index,
+        target_at,
+        scheduled_by: ctx.accounts.payer.key(),
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+//================ UPGRADE GOVERNANCE ================
+// AUDIT: These functions put program upgrades under committee control
+// SECURITY: Upgrade authority is held by a PDA; every action needs 3-of-5
+
+/// Hand the program upgrade authority to the governance PDA
+/// 
+/// AUDIT CRITICAL - UPGRADE GOVERNANCE INITIALIZATION:
+/// Creates the singleton UpgradeGovernance PDA and, in the same instruction,
+/// sets it as the program upgrade authority. From then on no single key can
+/// upgrade the program.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - ProgramData account must match this program's programdata address
+/// - Signer must be the current upgrade authority
+/// - Committee must have exactly 5 unique members
+/// - PDA `init` prevents re-initialization
+/// 
+/// PARAMETERS:
+/// - committee: 5-member upgrade committee
+pub fn initialize_upgrade_governance(
+    ctx: Context<InitializeUpgradeGovernance>,
+    committee: Vec<Pubkey>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+    let governance_key = ctx.accounts.governance.key();
+
+    // AUDIT: Only the current upgrade authority may hand it over
+    require!(
+        ctx.accounts.program.programdata_address()? == Some(ctx.accounts.program_data.key()),
+        ErrorCode::UnauthorizedUpgradeAuthority
+    );
+    require!(
+        ctx.accounts.program_data.upgrade_authority_address == Some(authority),
+        ErrorCode::UnauthorizedUpgradeAuthority
+    );
+
+    // AUDIT: Committee must have exactly 5 unique members
+    require!(committee.len() == MAX_WHITELIST_LEN, ErrorCode::WhitelistMustBeFive);
+    let unique: HashSet<Pubkey> = committee.iter().copied().collect();
+    require!(unique.len() == MAX_WHITELIST_LEN, ErrorCode::WhitelistMustBeFive);
+
+    // AUDIT: Current authority signs the handoff to the governance PDA
+    let ix = loader_set_upgrade_authority(&crate::ID, &authority, Some(&governance_key));
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.governance.to_account_info(),
+            ctx.accounts.bpf_loader_upgradeable_program.to_account_info(),
+        ],
+    )?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.committee = committee.clone();
+    governance.holds_authority = true;
+    governance.window_buffer = Pubkey::default();
+    governance.window_start = 0;
+    governance.window_end = 0;
+    governance.bump = ctx.bumps.governance;
+    governance.updated_at = now;
+    governance.created_at = now;
+
+    emit!(UpgradeGovernanceInitialized {
+        governance: governance_key,
+        committee,
+        previous_authority: authority,
+        created_at: now,
+    });
+
+    Ok(())
+}
+
+/// Hand the upgrade authority from the governance PDA to a new authority
+/// 
+/// AUDIT CRITICAL - AUTHORITY HANDOFF:
+/// Used to migrate upgrade control (e.g. to a newer governance program).
+/// After the handoff this governance account can no longer upgrade the program.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from the upgrade committee
+/// - Governance must still hold the upgrade authority
+/// - ProgramData account must match this program's programdata address
+pub fn set_upgrade_authority(ctx: Context<ManageUpgradeAuthority>) -> Result<()> {
+    let new_authority = ctx
+        .accounts
+        .new_authority
+        .as_ref()
+        .ok_or(ErrorCode::UnauthorizedUpgradeAuthority)?
+        .key();
+    transfer_upgrade_authority(ctx, Some(new_authority))
+}
+
+/// Renounce the upgrade authority, making the program immutable
+/// 
+/// AUDIT CRITICAL - IRREVERSIBLE:
+/// Once renounced the program can never be upgraded again.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from the upgrade committee
+/// - Governance must still hold the upgrade authority
+/// - ProgramData account must match this program's programdata address
+pub fn renounce_upgrade_authority(ctx: Context<ManageUpgradeAuthority>) -> Result<()> {
+    transfer_upgrade_authority(ctx, None)
+}
+
+/// Shared body of set_upgrade_authority / renounce_upgrade_authority
+fn transfer_upgrade_authority(
+    ctx: Context<ManageUpgradeAuthority>,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let governance_key = ctx.accounts.governance.key();
+
+    // AUDIT: 3-of-5 multisig validation from the upgrade committee
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    ctx.accounts.governance.enforce_3_of_5_signers(signer_infos)?;
+
+    require!(ctx.accounts.governance.holds_authority, ErrorCode::UpgradeAuthorityReleased);
+    require!(
+        ctx.accounts.program.programdata_address()? == Some(ctx.accounts.program_data.key()),
+        ErrorCode::UnauthorizedUpgradeAuthority
+    );
+
+    // AUDIT: Governance PDA signs the loader CPI
+    let ix = loader_set_upgrade_authority(&crate::ID, &governance_key, new_authority.as_ref());
+    let mut account_infos = vec![
+        ctx.accounts.program_data.to_account_info(),
+        ctx.accounts.governance.to_account_info(),
+    ];
+    if let Some(new_authority_info) = ctx.accounts.new_authority.as_ref() {
+        account_infos.push(new_authority_info.to_account_info());
+    }
+    account_infos.push(ctx.accounts.bpf_loader_upgradeable_program.to_account_info());
+
+    let bump = ctx.accounts.governance.bump;
+    let signer_seeds: &[&[u8]] = &[b"upgrade_governance", &[bump]];
+    invoke_signed(&ix, &account_infos, &[signer_seeds])?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.holds_authority = false;
+    governance.window_buffer = Pubkey::default();
+    governance.window_start = 0;
+    governance.window_end = 0;
+    governance.updated_at = now;
+
+    emit!(UpgradeAuthorityTransferred {
+        new_authority,
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Approve a buffer and the time window in which it may be deployed
+/// 
+/// AUDIT CRITICAL - UPGRADE APPROVAL:
+/// The committee pins the exact buffer account. A later schedule replaces
+/// the previous one, so a mistaken approval can be overridden.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from the upgrade committee
+/// - Governance must still hold the upgrade authority
+/// - window_start < window_end, window_end in the future
+/// - Window no longer than MAX_UPGRADE_WINDOW_SECS
+/// 
+/// PARAMETERS:
+/// - buffer: Buffer account holding the approved program bytes
+/// - window_start/window_end: Window bounds (UNIX timestamps)
+pub fn schedule_upgrade_window(
+    ctx: Context<ScheduleUpgradeWindow>,
+    buffer: Pubkey,
+    window_start: i64,
+    window_end: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let governance = &mut ctx.accounts.governance;
+
+    // AUDIT: 3-of-5 multisig validation from the upgrade committee
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    governance.enforce_3_of_5_signers(signer_infos)?;
+
+    require!(governance.holds_authority, ErrorCode::UpgradeAuthorityReleased);
+
+    // AUDIT: Validate window bounds
+    require!(buffer != Pubkey::default(), ErrorCode::InvalidUpgradeWindow);
+    require!(window_start < window_end, ErrorCode::InvalidUpgradeWindow);
+    require!(window_end > now, ErrorCode::InvalidUpgradeWindow);
+    require!(
+        window_end - window_start <= MAX_UPGRADE_WINDOW_SECS,
+        ErrorCode::InvalidUpgradeWindow
+    );
+
+    governance.window_buffer = buffer;
+    governance.window_start = window_start;
+    governance.window_end = window_end;
+    governance.updated_at = now;
+
+    emit!(UpgradeWindowScheduled {
+        buffer,
+        window_start,
+        window_end,
+        signers: signer_keys,
+        scheduled_at: now,
+    });
+
+    Ok(())
+}
+
+/// Deploy the approved buffer inside its window
+/// 
+/// AUDIT CRITICAL - PROGRAM UPGRADE:
+/// Any committee member may execute; the 3-of-5 approval was given when the
+/// window was scheduled. The window is consumed so the approval is single use.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Executor must be a committee member
+/// - Governance must still hold the upgrade authority
+/// - Buffer must match the scheduled buffer and the window must be open
+/// - ProgramData account must match this program's programdata address
+pub fn execute_program_upgrade(ctx: Context<ExecuteProgramUpgrade>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let governance_key = ctx.accounts.governance.key();
+    let buffer_key = ctx.accounts.buffer.key();
+    let executor = ctx.accounts.executor.key();
+
+    // AUDIT: Executor must be a committee member
+    require!(
+        ctx.accounts.governance.committee.contains(&executor),
+        ErrorCode::UnauthorizedSigner
+    );
+    require!(ctx.accounts.governance.holds_authority, ErrorCode::UpgradeAuthorityReleased);
+    require!(
+        ctx.accounts.governance.window_open(&buffer_key, now),
+        ErrorCode::UpgradeWindowClosed
+    );
+
+    // AUDIT: ProgramData must be this program's (program is writable, so derive directly)
+    let (expected_program_data, _bump) = Pubkey::find_program_address(
+        &[crate::ID.as_ref()],
+        &ctx.accounts.bpf_loader_upgradeable_program.key(),
+    );
+    require_keys_eq!(
+        ctx.accounts.program_data.key(),
+        expected_program_data,
+        ErrorCode::UnauthorizedUpgradeAuthority
+    );
+
+    // AUDIT: Governance PDA signs the loader CPI
+    let ix = loader_upgrade(&crate::ID, &buffer_key, &governance_key, &ctx.accounts.spill.key());
+    let bump = ctx.accounts.governance.bump;
+    let signer_seeds: &[&[u8]] = &[b"upgrade_governance", &[bump]];
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+            ctx.accounts.buffer.to_account_info(),
+            ctx.accounts.spill.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.governance.to_account_info(),
+            ctx.accounts.bpf_loader_upgradeable_program.to_account_info(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    // AUDIT: Consume the window - each approval deploys at most once
+    let governance = &mut ctx.accounts.governance;
+    governance.window_buffer = Pubkey::default();
+    governance.window_start = 0;
+    governance.window_end = 0;
+    governance.updated_at = now;
+
+    emit!(ProgramUpgraded {
+        buffer: buffer_key,
+        executed_by: executor,
+        executed_at: now,
+    });
+
+    Ok(())
+}
+
+//================ OPERATING ALLOWANCE ================
+// AUDIT: These functions manage standing operating-expense withdrawals
+// SECURITY: Committee sets the cap once; claims cannot exceed it per period
+
+/// Set, change or disable the standing operating allowance
+/// 
+/// AUDIT CRITICAL - STANDING WITHDRAWAL AUTHORIZATION:
+/// Lets recurring operating expenses be paid from the vault without a full
+/// multisig every month. Setting the allowance starts a fresh period.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Investment must be active
+/// - Recipient must be on withdraw_whitelist
+/// - period_secs must be at least MIN_ALLOWANCE_PERIOD_SECS
+/// 
+/// PARAMETERS:
+/// - recipient: Wallet receiving the allowance
+/// - amount: Maximum USDT (base units) per period; 0 disables the allowance
+/// - period_secs: Period length in seconds
+pub fn set_operating_allowance(
+    ctx: Context<SetOperatingAllowance>,
+    recipient: Pubkey,
+    amount: u64,
+    period_secs: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let allowance = &mut ctx.accounts.allowance;
+
+    // AUDIT: Validate investment is active
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+
+    // AUDIT: 3-of-5 multisig validation from execute_whitelist
+    let signer_infos = &ctx.remaining_accounts;
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, false)?;
+
+    // AUDIT: Allowance can only pay a withdraw_whitelist wallet
+    require!(config.withdraw_whitelist.contains(&recipient), ErrorCode::UnauthorizedRecipient);
+    require!(period_secs >= MIN_ALLOWANCE_PERIOD_SECS, ErrorCode::InvalidAllowancePeriod);
+
+    // AUDIT: First use initializes the allowance identity
+    if allowance.created_at == 0 {
+        allowance.investment_id = info.investment_id;
+        allowance.version = info.version;
+        allowance.bump = ctx.bumps.allowance;
+        allowance.created_at = now;
+    }
+
+    allowance.recipient = recipient;
+    allowance.amount = amount;
+    allowance.period_secs = period_secs;
+    allowance.period_start = now;
+    allowance.claimed_in_period = 0;
+    allowance.updated_at = now;
+
+    emit!(OperatingAllowanceSet {
+        investment_id: info.investment_id,
+        version: info.version,
+        recipient,
+        amount,
+        period_secs,
+        updated_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
+
+/// Claim part of the operating allowance for the current period
+/// 
+/// AUDIT CRITICAL - PERMISSIONLESS TRANSFER:
+/// Anyone may submit the claim; USDT only reaches allowance.recipient and
+/// the per-period total never exceeds allowance.amount.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program must not be paused
+/// - Investment must be active
+/// - Allowance must be active (amount > 0)
+/// - Recipient and USDT mint must match
+/// - claimed_in_period + amount must not exceed the allowance
+/// - The claim is counted as OpEx and must fit the OpEx budget caps
+/// - The claim must fit the rolling-window rate limit
+/// 
+/// PARAMETERS:
+/// - amount: USDT (base units) to transfer
+pub fn claim_operating_allowance(
+    ctx: Context<ClaimOperatingAllowance>,
+    amount: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
+    let info = &ctx.accounts.investment_info;
+    let allowance = &mut ctx.accounts.allowance;
+    let usdt_mint = &ctx.accounts.usdt_mint;
+    let vault_usdt_account = &ctx.accounts.vault_usdt_account;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_WITHDRAW)?;
+    require!(allowance.amount > 0, ErrorCode::OperatingAllowanceInactive);
+
+    // AUDIT: Funds only go to the committee-set recipient, in USDT
+    require_keys_eq!(ctx.accounts.recipient_account.key(), allowance.recipient, ErrorCode::UnauthorizedRecipient);
+    require_keys_eq!(usdt_mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+
+    // AUDIT: Enforce the per-period cap
+    allowance.roll_period(now);
+    let claimed = allowance
+        .claimed_in_period
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    require!(claimed <= allowance.amount, ErrorCode::OperatingAllowanceExceeded);
+    require!(vault_usdt_account.amount >= amount, ErrorCode::InsufficientTokenBalance);
+
+    // AUDIT: Allowance claims are operating expenses and share the OpEx budget line
+    let budget = &mut ctx.accounts.withdraw_budget;
+    budget.bind(info, ctx.bumps.withdraw_budget);
+    budget.record(WithdrawPurpose::OpEx, amount, 0, 0, now)?;
+
+    // AUDIT: Claims share the rolling-window rate limit with all other payouts
+    let rate_limit = &mut ctx.accounts.withdraw_rate_limit;
+    rate_limit.bind(info, ctx.bumps.withdraw_rate_limit);
+    rate_limit.record(amount, 0, now)?;
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_usdt_account,
+        ctx.accounts.vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+    transfer_token_checked(
+        ctx.accounts.token_program.to_account_info(),
+        vault_usdt_account.to_account_info(),
+        ctx.accounts.recipient_usdt_account.to_account_info(),
+        usdt_mint.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        Some(signer_seeds),
+        amount,
+        usdt_mint.decimals,
+        ctx.accounts.memo_program.to_account_info(),
+        &transfer_memo(info, "allowance", None, &[("purpose", WithdrawPurpose::OpEx.index() as u64)]),
+    )?;
+
+    allowance.claimed_in_period = claimed;
+    allowance.updated_at = now;
+
+    emit!(OperatingAllowanceClaimed {
+        investment_id: info.investment_id,
+        version: info.version,
+        recipient: allowance.recipient,
+        amount,
+        claimed_in_period: claimed,
+        period_start: allowance.period_start,
+        claimed_by: ctx.accounts.payer.key(),
+        claimed_at: now,
+    });
+
+    end_execution(&mut ctx.accounts.investment_info);
+    Ok(())
+}
+
+//================ DEPOSIT RETURN ================
+// AUDIT: These functions return recorded USDT when a raise is cancelled
+// SECURITY: Only for cancelled investments; each record is returned once
+
+/// Return recorded USDT deposits to investors of a cancelled investment
+/// 
+/// AUDIT CRITICAL - DEPOSIT RETURN:
+/// Pays every supplied record its amount_usdt from the vault to the USDT ATA of
+/// the record wallet, then stamps returned_at. Can be called repeatedly with
+/// further records until the whole batch has been returned.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Program must not be paused
+/// - Investment must be active and Cancelled
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Record PDA and batch_id validation
+/// - Revoked, unbound and already returned records are skipped
+/// - Vault balance must cover the whole transaction
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch of the supplied records
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n, record_1..record_n, recipient USDT ATAs]
+pub fn return_deposits<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ReturnDeposits<'info>>,
+    batch_id: u16,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let usdt_mint = &ctx.accounts.usdt_mint;
+    let vault_usdt_account = &ctx.accounts.vault_usdt_account;
+
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    require!(info.state == InvestmentState::Cancelled, ErrorCode::InvestmentInfoNotCancelled);
+    require_keys_eq!(usdt_mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+
+    // AUDIT: 3-of-5 multisig validation from execute_whitelist
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, false)?;
+
+    let signer_seeds: &[&[u8]] = &[
+        b"vault",
+        info.investment_id.as_ref(),
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
+    ];
+
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_usdt_account,
+        ctx.accounts.vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
+
+    let mut record_ids: Vec<u64> = Vec::new();
+    let mut total_usdt: u64 = 0;
+
+    for acc_info in data_accounts.iter() {
+        // AUDIT: Recipient ATAs are owned by the token program and skipped here
+        if acc_info.owner != ctx.program_id {
+            continue;
+        }
+        require!(record_ids.len() < MAX_ENTRIES_PER_BATCH, ErrorCode::TooManyRecordsLoaded);
+
+        let mut data = acc_info.try_borrow_mut_data()?;
+        let mut record = InvestmentRecord::try_deserialize(&mut &data[..])?;
+
+        // AUDIT: Validate record PDA with info.investment_id
+        let (expected_record_pda, _bump) = Pubkey::find_program_address(
+            &[
+                b"record",
+                info.investment_id.as_ref(),
+                info.version.as_ref(),
+                batch_id.to_le_bytes().as_ref(),
+                record.record_id.to_le_bytes().as_ref(),
+                record.account_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+        require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+        // AUDIT: Skip records that must not (or no longer) be paid
+        if record.returned_at != 0 || record.revoked_at != 0 || record.wallet == Pubkey::default() {
+            msg!("🟡 Skipping record_id={}", record.record_id);
+            continue;
+        }
+
+        let recipient_ata = get_associated_token_address(&record.wallet, &usdt_mint.key());
+        let recipient_ata_info = data_accounts
+            .iter()
+            .find(|acc| acc.key == &recipient_ata)
+            .ok_or(ErrorCode::MissingAssociatedTokenAccount)?;
+
+        total_usdt = total_usdt
+            .checked_add(record.amount_usdt)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        require!(vault_usdt_account.amount >= total_usdt, ErrorCode::InsufficientTokenBalance);
+
+        transfer_token_checked(
+            ctx.accounts.token_program.to_account_info(),
+            vault_usdt_account.to_account_info(),
+            recipient_ata_info.to_account_info(),
+            usdt_mint.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            Some(signer_seeds),
+            record.amount_usdt,
+            usdt_mint.decimals,
+            ctx.accounts.memo_program.to_account_info(),
+            &transfer_memo(
+                info,
+                "return",
+                Some(&record.account_id),
+                &[("batch", batch_id as u64), ("rec", record.record_id)],
+            ),
+        )?;
+
+        // AUDIT: Stamp the record so it cannot be returned twice
+        record.returned_at = now;
+        record.try_serialize(&mut &mut data[..])?;
+        record_ids.push(record.record_id);
+    }
+
+    require!(!record_ids.is_empty(), ErrorCode::NoRecordsUpdated);
+
+    emit!(DepositsReturned {
+        investment_id: info.investment_id,
+        version: info.version,
+        batch_id,
+        record_ids,
+        total_usdt,
+        executed_by: ctx.accounts.payer.key(),
+        executed_at: now,
+        signers: signer_keys,
+    });
+
+    Ok(())
+}
 
+//================ ANNUAL STATEMENT ================
+// AUDIT: These functions build per-investor yearly statements from chain state
+// SECURITY: Read-only over records and caches; writes only the statement PDA
 
-    // Calculate refund year index
-    const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+/// Generate (or regenerate) an investor's statement for one calendar year
+/// 
+/// AUDIT CRITICAL - ANNUAL STATEMENT:
+/// Rebuilds the statement from the supplied accounts so tax reports can be produced
+/// directly from chain state:
+/// - invested amounts: non-revoked records of the investor created in the year
+/// - profits / refunds: Paid entries of the investor in caches settled (executed_at) in the year
+///
+/// Caches closed after full payment can no longer be read, so statements for a year
+/// should be generated before its caches are closed.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be in execute_whitelist or update_whitelist
+/// - Record and cache PDA validation; the same account is never counted twice
+/// 
+/// PARAMETERS:
+/// - account_id: Investor account identifier
+/// - year: Calendar year (UTC)
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n, record / profit cache / refund cache accounts...]
+pub fn generate_annual_statement<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, GenerateAnnualStatement<'info>>,
+    account_id: [u8; 15],
+    year: u16,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
 
-    let elapsed_secs = now.saturating_sub(info.end_at);
-    let expect_year_index = (elapsed_secs / SECONDS_PER_YEAR) as u8;
+    // AUDIT: Validate signer against combined whitelists
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    let signer_keys = extract_signer_keys(signer_infos);
     require!(
-        year_index <= expect_year_index && (START_YEAR_INDEX..=MAX_YEAR_INDEX).contains(&year_index),
-        ErrorCode::RefundPeriodInvalid
+        signer_keys
+            .iter()
+            .any(|key| config.execute_whitelist.contains(key) || config.update_whitelist.contains(key)),
+        ErrorCode::UnauthorizedSigner
     );
-    
 
-    // Compute refund entries
-    let mut entries: Vec<RefundEntry> = Vec::new();
-    let mut subtotal_refund_hcoin: u64 = 0;
+    let mut seen: HashSet<Pubkey> = HashSet::new();
+    let mut invested_usdt: u64 = 0;
+    let mut invested_hcoin: u64 = 0;
+    let mut profit_usdt: u64 = 0;
+    let mut refund_hcoin: u64 = 0;
+    let mut records_counted: u16 = 0;
+    let mut caches_counted: u16 = 0;
 
-    
-    for (_record_id, record) in record_map.iter() {
-        require!(record.account_id.len() == 15, ErrorCode::InvalidAccountIdLength);
-        if record.revoked_at != 0 {
-            msg!(
-                "🟡 Skipping revoked record_id={} for account_id={}",
-                record.record_id,
-                String::from_utf8_lossy(&record.account_id).trim_end_matches('\0')
+    for acc_info in data_accounts.iter() {
+        require!(seen.insert(acc_info.key()), ErrorCode::DuplicateRecord);
+
+        if let Ok(record) = Account::<InvestmentRecord>::try_from(acc_info) {
+            let (expected_record_pda, _bump) = Pubkey::find_program_address(
+                &[
+                    b"record",
+                    info.investment_id.as_ref(),
+                    info.version.as_ref(),
+                    record.batch_id.to_le_bytes().as_ref(),
+                    record.record_id.to_le_bytes().as_ref(),
+                    record.account_id.as_ref(),
+                ],
+                ctx.program_id,
             );
-            continue;
+            require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+
+            if record.account_id == account_id
+                && record.revoked_at == 0
+                && AnnualStatement::calendar_year(record.created_at) == year
+            {
+                invested_usdt = invested_usdt
+                    .checked_add(record.amount_usdt)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                invested_hcoin = invested_hcoin
+                    .checked_add(record.amount_hcoin)
+                    .ok_or(ErrorCode::NumericalOverflow)?;
+                records_counted += 1;
+            }
+        } else if let Ok(cache) = Account::<ProfitShareCache>::try_from(acc_info) {
+            let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+                &[
+                    b"profit_cache",
+                    info.investment_id.as_ref(),
+                    info.version.as_ref(),
+                    cache.batch_id.to_le_bytes().as_ref(),
+                ],
+                ctx.program_id,
+            );
+            require_keys_eq!(acc_info.key(), expected_cache_pda, ErrorCode::InvalidProfitCachePda);
+
+            // AUDIT: profit_usdt covers rounds paid in the profit mint only
+            if cache.payout_mint != info.profit_mint {
+                msg!("🟡 Skipping profit batch {} paid in mint {}", cache.batch_id, cache.payout_mint);
+            } else if cache.executed_at != 0 && AnnualStatement::calendar_year(cache.executed_at) == year {
+                for entry in cache.entries.iter() {
+                    if entry.account_id == account_id && entry.status == EntryStatus::Paid {
+                        profit_usdt = profit_usdt
+                            .checked_add(entry.amount_usdt)
+                            .ok_or(ErrorCode::NumericalOverflow)?;
+                    }
+                }
+                caches_counted += 1;
+            }
+        } else if let Ok(cache) = Account::<RefundShareCache>::try_from(acc_info) {
+            let (expected_cache_pda, _bump) = Pubkey::find_program_address(
+                &[
+                    b"refund_cache",
+                    info.investment_id.as_ref(),
+                    info.version.as_ref(),
+                    cache.batch_id.to_le_bytes().as_ref(),
+                    cache.year_index.to_le_bytes().as_ref(),
+                ],
+                ctx.program_id,
+            );
+            require_keys_eq!(acc_info.key(), expected_cache_pda, ErrorCode::InvalidRefundCachePda);
+
+            if cache.executed_at != 0 && AnnualStatement::calendar_year(cache.executed_at) == year {
+                for entry in cache.entries.iter() {
+                    if entry.account_id == account_id && entry.status == EntryStatus::Paid {
+                        refund_hcoin = refund_hcoin
+                            .checked_add(entry.amount_hcoin)
+                            .ok_or(ErrorCode::NumericalOverflow)?;
+                    }
+                }
+                caches_counted += 1;
+            }
+        } else {
+            msg!("🟡 Skipping unrecognized account {}", acc_info.key());
         }
+    }
 
-        let wallet = record.wallet;
-
-        let percent = RefundShareCache::get_refund_percentage(
-            &info.stage_ratio,
-            record.stage,
-            year_index,
-        );
-
-        let amount = record.amount_hcoin
-            .checked_mul(percent as u64)
-            .and_then(|x| x.checked_div(100))
-            .ok_or(ErrorCode::NumericalOverflow)?;
+    // AUDIT: Overwrite the statement; it always reflects the latest generation
+    let statement = &mut ctx.accounts.statement;
+    statement.investment_id = info.investment_id;
+    statement.version = info.version;
+    statement.account_id = account_id;
+    statement.year = year;
+    statement.invested_usdt = invested_usdt;
+    statement.invested_hcoin = invested_hcoin;
+    statement.profit_usdt = profit_usdt;
+    statement.refund_hcoin = refund_hcoin;
+    statement.records_counted = records_counted;
+    statement.caches_counted = caches_counted;
+    statement.generated_at = now;
+    statement.bump = ctx.bumps.statement;
+
+    emit!(AnnualStatementGenerated {
+        investment_id: info.investment_id,
+        version: info.version,
+        account_id,
+        year,
+        invested_usdt,
+        invested_hcoin,
+        profit_usdt,
+        refund_hcoin,
+        records_counted,
+        caches_counted,
+        generated_by: signer_keys[0],
+        generated_at: now,
+    });
 
-        subtotal_refund_hcoin = subtotal_refund_hcoin
-            .checked_add(amount)
-            .ok_or(ErrorCode::NumericalOverflow)?;
+    Ok(())
+}
 
-        entries.push(RefundEntry {
-            account_id: record.account_id,
-            wallet,
-            amount_hcoin: amount,
-            stage: record.stage,
-        });
-    }
+//================ NOTIFICATION REGISTRY ================
+// AUDIT: These functions manage investor opt-in notification registrations
+// SECURITY: Only the wallet itself can create, update or close its registration
 
+/// Register or update the wallet's notification channel
+/// 
+/// AUDIT CRITICAL - NOTIFICATION OPT-IN:
+/// Stores an encrypted contact hash or push-channel identifier under the wallet's PDA.
+/// Estimation and execution emit `InvestorNotification` for entries whose registration
+/// is passed in remaining_accounts, so an off-chain service can notify investors
+/// without a central database.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Wallet signature required (PDA seeded by the signer)
+/// - Contact must not be empty
+/// 
+/// PARAMETERS:
+/// - channel: How `contact` is interpreted
+/// - contact: Encrypted contact hash or push-channel identifier
+pub fn register_notification(
+    ctx: Context<RegisterNotification>,
+    channel: NotificationChannel,
+    contact: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
 
-    // Estimate SOL cost
-    let entry_count = entries.len() as u16;
-    let subtotal_estimate_sol =
-        ESTIMATE_SOL_BASE + (entry_count as u64) * ESTIMATE_SOL_PER_ENTRY;
+    require!(contact != [0u8; 32], ErrorCode::InvalidNotificationContact);
 
+    let registration = &mut ctx.accounts.registration;
+    if registration.created_at == 0 {
+        registration.wallet = ctx.accounts.wallet.key();
+        registration.bump = ctx.bumps.registration;
+        registration.created_at = now;
+    }
+    registration.channel = channel;
+    registration.contact = contact;
+    registration.updated_at = now;
+
+    emit!(NotificationRegistered {
+        wallet: registration.wallet,
+        registration: registration.key(),
+        channel,
+        contact,
+        registered_at: now,
+    });
 
-    // Store result to cache
-    cache.batch_id = batch_id;
-    cache.investment_id = info.investment_id;
-    cache.version = info.version;
-    cache.year_index = year_index;
-    cache.subtotal_refund_hcoin = subtotal_refund_hcoin;
-    cache.subtotal_estimate_sol = subtotal_estimate_sol;
-    cache.executed_at = 0;
-    cache.created_at = now;
-    cache.entries = entries;
+    Ok(())
+}
 
+/// Remove the wallet's notification registration
+/// 
+/// AUDIT CRITICAL:
+/// - Closes the PDA and returns rent to the wallet
+/// - Later estimations and executions no longer notify the wallet
+pub fn unregister_notification(ctx: Context<UnregisterNotification>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
 
-    // Emit event
-    emit!(RefundShareEstimated {
-        batch_id,
-        investment_id: cache.investment_id,
-        version: info.version,
-        year_index,
-        subtotal_refund_hcoin,
-        subtotal_estimate_sol,
-        created_by: ctx.accounts.payer.key(),
-        created_at: now,
-        entry_count,
-        signers: signer_keys,
+    emit!(NotificationUnregistered {
+        wallet: ctx.accounts.wallet.key(),
+        registration: ctx.accounts.registration.key(),
+        unregistered_at: now,
     });
 
-    msg!(
-        "🟢 Estimated refund share: year {}, entries {}, total {} H2COIN",
-        year_index,
-        entry_count,
-        subtotal_refund_hcoin
-    );
-
     Ok(())
 }
 
 
+//================ PROFIT ACCRUAL ================
+// AUDIT: Quarterly profit is credited to per-investor balances; transfers happen on settlement
+// SECURITY: Accruals require execute multisig; settlements only pay recorded wallets
 
-/// Executes the profit share for a given batch_id of records.
-/// Transfers USDT from the vault PDA to each investor's associated token account.
-/// Requires 3-of-5 multisig authorization.
-/// Executes a profit share distribution for a single batch_id.
-/// This function verifies the cache, vault balance, signer set, and distributes tokens
-/// to each investor's associated token account. Only entries associated with the given
-/// `batch_id` will be processed. After completion, the `ProfitShareCache` is marked
-/// as executed to prevent double payouts.
-pub fn execute_profit_share<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, ExecuteProfitShare<'info>>,
+/// Credit a quarter's profit share to a batch's accrual ledger
+/// 
+/// AUDIT CRITICAL - PROFIT ACCRUAL:
+/// Decouples the accounting cadence from transfer cost: each quarter the execute
+/// committee credits every distributable record's share (same formula as
+/// estimate_profit_share) to a per-(account_id, wallet) balance without moving tokens.
+/// Balances are transferred later by settle_accrual. Profit credited here must not
+/// also be distributed through a profit share cache.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - 3-of-5 multisig validation from execute_whitelist
+/// - Investment must be active, completed and Standard
+/// - Quarter index must exceed the ledger's last_quarter
+/// - Record PDA and batch_id validation, duplicate record rejection
+/// - Outstanding balances must stay covered by the vault's USDT
+/// 
+/// PARAMETERS:
+/// - batch_id: Batch to accrue for
+/// - quarter: Monotonic quarter index (e.g. year × 4 + quarter)
+/// - total_profit_usdt: Profit of the quarter across the investment
+/// - total_invest_usdt: Total USDT invested across all batches
+/// 
+/// remaining_accounts: [signer_1, ..., signer_n, record_1, ..., record_n]
+pub fn accrue_profit<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, AccrueProfit<'info>>,
     batch_id: u16,
-) -> Result<()> 
+    quarter: u16,
+    total_profit_usdt: u64,
+    total_invest_usdt: u64,
+) -> Result<()>
 where
     'c: 'info,
 {
     let now = Clock::get()?.unix_timestamp;
     let info = &ctx.accounts.investment_info;
-    let cache = &mut ctx.accounts.cache;
-    let mint = &ctx.accounts.mint;
-    let vault = &ctx.accounts.vault;
-    let vault_token_account = &ctx.accounts.vault_token_account;
-
-
-
-    // Validate the profit_cache PDA
-    let (expected_cache_pda, _) = Pubkey::find_program_address(
-        &[
-            b"profit_cache",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require_keys_eq!(cache.key(), expected_cache_pda, ErrorCode::InvalidProfitCachePda);
-
-
-    // Validate the expected vault PDA
-    let (vault_pda, vault_bump) = Pubkey::find_program_address(
-       &[
-           b"vault", 
-           info.investment_id.as_ref(),
-           info.version.as_ref(),
-       ],
-       ctx.program_id,
-    );
-    require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
-
-
-    // Prepare PDA signer seeds
-    let signer_seeds: &[&[u8]] = &[
-        b"vault",
-        info.investment_id.as_ref(),
-        info.version.as_ref(),
-        &[vault_bump],
-    ];
+    let config = &ctx.accounts.investment_config;
+    let ledger = &mut ctx.accounts.ledger;
 
-
-    // reject if investment info has been deactived or has not been completed
+    // AUDIT: Validate investment is active and completed
     require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
     require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
     require!(info.investment_type == InvestmentType::Standard, ErrorCode::StandardOnly);
 
-    // reject if cache is not initialized or batch_id mismatch
-    require!(!cache.to_account_info().data_is_empty(), ErrorCode::ProfitCacheNotFound);
-    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
-
-
-    // reject if execuated_at is not 0 or cache has been executed
-    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
-    // reject if cache created_at execceds 25 days
-    require!(now - cache.created_at <= SHARE_CACHE_EXPIRE_SECS, ErrorCode::ProfitCacheExpired);
-    // reject if subtotal_profit_usdt is 0
-    require!(cache.subtotal_profit_usdt > 0, ErrorCode::InvalidTotalUsdt);
+    // AUDIT: Multisig validation from execute_whitelist
+    let (signer_infos, data_accounts) = split_signer_accounts(ctx.remaining_accounts);
+    require!(!data_accounts.is_empty(), ErrorCode::TooManyRecordsLoaded);
+    let signer_keys = extract_signer_keys(signer_infos);
+    config.enforce_signers(signer_infos, false)?;
 
+    // AUDIT: A quarter can only be credited once
+    require!(quarter > ledger.last_quarter, ErrorCode::AccrualQuarterNotIncreasing);
+    require_keys_eq!(ctx.accounts.mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
 
-    // Ensure signer is part of 3-of-5 execute whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
-    let signer_keys = extract_signer_keys(signer_infos);
-    info.enforce_3_of_5_signers(signer_infos, false)?;
+    // AUDIT: Load records with the same validation as estimation
+    require!(
+        data_accounts.len() <= MAX_ENTRIES_PER_BATCH,
+        ErrorCode::TooManyRecordsLoaded
+    );
 
-    
-    // Token checks
-    require_keys_eq!(mint.key(), get_usdt_mint(), ErrorCode::InvalidTokenMint);
-    require_keys_eq!(vault_token_account.mint, mint.key(), ErrorCode::InvalidTokenMint);
-    require!(vault_token_account.amount >= cache.subtotal_profit_usdt, ErrorCode::InsufficientTokenBalance);
-    require!(vault.to_account_info().lamports() >= cache.subtotal_estimate_sol, ErrorCode::InsufficientSolBalance);
+    let mut record_map = BTreeMap::new();
+    for acc_info in data_accounts.iter() {
+        let record = Account::<InvestmentRecord>::try_from(acc_info)?;
+        let (expected_record_pda, _bump) = Pubkey::find_program_address(
+            &[
+                b"record",
+                info.investment_id.as_ref(),
+                info.version.as_ref(),
+                batch_id.to_le_bytes().as_ref(),
+                record.record_id.to_le_bytes().as_ref(),
+                record.account_id.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require!(record.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+        require_keys_eq!(acc_info.key(), expected_record_pda, ErrorCode::InvalidRecordPda);
+        require!(
+            !record_map.contains_key(&record.record_id),
+            ErrorCode::DuplicateRecord
+        );
+        record_map.insert(record.record_id, record);
+    }
 
+    if ledger.updated_at == 0 {
+        ledger.investment_id = info.investment_id;
+        ledger.version = info.version;
+        ledger.batch_id = batch_id;
+        ledger.bump = ctx.bumps.ledger;
+    }
 
-    let mut total_transferred: u64 = 0;
-    let mut successes: Vec<Pubkey> = vec![];
-    let mut failures: Vec<Pubkey> = vec![];
+    // AUDIT: Credit every acknowledged, non-revoked, bound record; others accrue nothing
+    let mut subtotal_accrued_usdt: u64 = 0;
+    let mut breakdown: Vec<RecordShare> = Vec::new();
 
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let mint_info = ctx.accounts.mint.to_account_info();
-    let vault_info = vault.to_account_info();
-    let signer = Some(signer_seeds);
-    let decimals = mint.decimals;
+    for (_record_id, record) in record_map.iter() {
+        if !record.is_acknowledged() || record.revoked_at != 0 || record.wallet == Pubkey::default() {
+            msg!(
+                "🟡 Skipping record_id={} (unacknowledged, revoked or unbound)",
+                record.record_id
+            );
+            continue;
+        }
 
-    for entry in cache.entries.iter() {
-        let recipient = entry.wallet;
-        let recipient_ata = get_associated_token_address(&recipient, &mint.key());
+        let (weighted_usdt, _) = info.weighted_profit_usdt(record.stage, record.amount_usdt)?;
+        let (_ratio_bp, amount) = ProfitShareCache::compute_share(
+            weighted_usdt,
+            total_profit_usdt,
+            total_invest_usdt,
+        )?;
+        let amount = info.profit_units(amount)?;
 
-        let recipient_ata_info = ctx
-            .remaining_accounts[3..]
+        let entry_index = match ledger
+            .entries
             .iter()
-            .find(|acc| acc.key == &recipient_ata)
-            .ok_or(ErrorCode::MissingAssociatedTokenAccount)?;
-
+            .position(|e| e.account_id == record.account_id && e.wallet == record.wallet)
+        {
+            Some(index) => index,
+            None => {
+                require!(
+                    ledger.entries.len() < MAX_ENTRIES_PER_BATCH,
+                    ErrorCode::TooManyRecordsLoaded
+                );
+                ledger.entries.push(AccrualEntry {
+                    account_id: record.account_id,
+                    wallet: record.wallet,
+                    accrued_usdt: 0,
+                    settled_usdt: 0,
+                });
+                ledger.entries.len() - 1
+            }
+        };
 
-        // transfer token to investors
-        let result = transfer_token_checked(
-            token_program.clone(),
-            vault_token_account.to_account_info(),
-            recipient_ata_info.to_account_info(),
-            mint_info.clone(),
-            vault_info.clone(),
-            signer,
-            entry.amount_usdt,
-            decimals,
-        );
+        let entry = &mut ledger.entries[entry_index];
+        entry.accrued_usdt = entry
+            .accrued_usdt
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        subtotal_accrued_usdt = subtotal_accrued_usdt
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
 
-        match result {
-            Ok(_) => {
-                successes.push(recipient);
-                
-                total_transferred = total_transferred
-                .checked_add(entry.amount_usdt)
-                .ok_or(ErrorCode::NumericalOverflow)?;
-            }
-            Err(_e) => {
-                failures.push(recipient);
-            }
-        }
+        breakdown.push(RecordShare {
+            record_id: record.record_id,
+            entry_index: entry_index as u16,
+            amount,
+        });
     }
 
+    ledger.total_accrued_usdt = ledger
+        .total_accrued_usdt
+        .checked_add(subtotal_accrued_usdt)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    ledger.last_quarter = quarter;
+    ledger.updated_at = now;
+
+    // AUDIT: Every unsettled balance must be payable from the vault today
     require!(
-        total_transferred == cache.subtotal_profit_usdt,
-        ErrorCode::TotalShareMismatch
+        ctx.accounts.vault_token_account.amount >= ledger.outstanding_usdt()?,
+        ErrorCode::InsufficientTokenBalance
     );
 
-    if successes.len() == cache.entries.len() {
-        cache.executed_at = now;
-        msg!("🟢 All succeeded: {}, {} USDT", successes.len(), total_transferred);
-    } else {
-        msg!("🟡 Partial success: {} succeeded, {} failed", successes.len(), failures.len());
-    }
-
+    msg!(
+        "🟢 Accrued quarter {} for batch {}: {} USDT",
+        quarter,
+        batch_id,
+        subtotal_accrued_usdt
+    );
 
-    emit!(ProfitShareExecuted {
-        batch_id: cache.batch_id,
+    emit!(ProfitAccrued {
         investment_id: info.investment_id,
         version: info.version,
-        total_transfer_usdt: total_transferred,
-        executed_by: ctx.accounts.payer.key(),
-        executed_at: now,
+        batch_id,
+        quarter,
+        total_profit_usdt,
+        subtotal_accrued_usdt,
+        entry_count: ledger.entries.len() as u16,
+        breakdown,
+        accrued_by: ctx.accounts.payer.key(),
+        accrued_at: now,
         signers: signer_keys,
     });
 
     Ok(())
 }
 
-
-/// Executes a refund share for a specific batch in a specific year
+/// Transfer an investor's accrued balances from the vault
 /// 
-/// AUDIT CRITICAL - REFUND SHARE EXECUTION:
-/// This function executes H2COIN refund distribution for a batch of investment records.
-/// It transfers H2COIN from the vault PDA to each investor's associated token account.
+/// AUDIT CRITICAL - ACCRUAL SETTLEMENT:
+/// Permissionless so the investor (or a crank) decides when transfer cost is worth
+/// paying. Every ledger entry of account_id with an unsettled balance is paid to the
+/// wallet recorded at accrual time; the recipient ATAs are matched by address among
+/// remaining_accounts.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - 3-of-5 multisig validation from execute_whitelist
-/// - Investment state validation (must be active and completed)
-/// - Cache PDA verification to prevent address spoofing
-/// - Vault PDA verification to prevent address spoofing
-/// - Cache validation (initialized, not executed, not expired)
-/// - Token mint validation (H2COIN only)
-/// - Balance sufficiency checks (SOL and H2COIN)
-/// - Cache execution prevention (double-payout protection)
-/// - Cache expiration validation (25-day limit)
-/// - Total transfer amount validation
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify cache and vault PDA derivation is consistent
-/// [ ] Confirm multisig validation uses correct whitelist
-/// [ ] Check cache execution prevention logic
-/// [ ] Review balance sufficiency validation
-/// [ ] Validate token transfer security
-/// [ ] Confirm event emission for audit trail
+/// - Program pause flag
+/// - Investment must be active
+/// - Destinations are fixed by the ledger; the caller cannot redirect funds
+/// - Vault token account guard before paying out
 /// 
 /// PARAMETERS:
-/// - batch_id: The target batch of records to execute
-/// - year_index: The refund year index to execute
-/// 
-/// Transfers H2COIN from the vault PDA to records' associated token accounts.
-/// Ensures 3-of-5 multisig, balance sufficiency, and cache validity before execution.
-pub fn execute_refund_share<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, ExecuteRefundShare<'info>>,
+/// - batch_id: Batch of the ledger
+/// - account_id: Investor account to settle
+pub fn settle_accrual<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SettleAccrual<'info>>,
     batch_id: u16,
-    year_index: u8
+    account_id: [u8; 15],
 ) -> Result<()>
 where
     'c: 'info,
 {
     let now = Clock::get()?.unix_timestamp;
+    // AUDIT: Reject untrusted CPI callers and re-entry before reading any state
+    begin_execution(&mut ctx.accounts.investment_info, &ctx.accounts.instructions_sysvar)?;
     let info = &ctx.accounts.investment_info;
-    let cache = &mut ctx.accounts.cache;
+    let ledger = &mut ctx.accounts.ledger;
+    let mint = &ctx.accounts.mint;
     let vault = &ctx.accounts.vault;
     let vault_token_account = &ctx.accounts.vault_token_account;
-    let mint = &ctx.accounts.mint;
-
-
-
-    // Validate the profit_cache PDA
-    let (expected_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"refund_cache",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-            batch_id.to_le_bytes().as_ref(),
-            cache.year_index.to_le_bytes().as_ref(),            
-        ],
-        ctx.program_id,
-    );
-    require!(cache.year_index == year_index, ErrorCode::InvalidRefundCachePda);
-    require_keys_eq!(cache.key(), expected_pda, ErrorCode::InvalidRefundCachePda);
 
+    // AUDIT: Program-wide emergency stop
+    require!(!ctx.accounts.program_config.paused, ErrorCode::ProgramPaused);
+    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
+    info.require_family_active(PAUSE_DISTRIBUTION)?;
+    require!(ledger.batch_id == batch_id, ErrorCode::BatchIdMismatch);
+    require_keys_eq!(mint.key(), info.profit_mint, ErrorCode::InvalidTokenMint);
+    require!(mint.decimals == info.profit_decimals, ErrorCode::MintDecimalsMismatch);
 
-    // Validate the expected vault PDA
-    let (vault_pda, vault_bump) = Pubkey::find_program_address(
-       &[
-           b"vault", 
-           info.investment_id.as_ref(),
-           info.version.as_ref(),
-       ],
-       ctx.program_id,
-   );
-   require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
-   
-   
-    // Prepare PDA signer seeds
     let signer_seeds: &[&[u8]] = &[
         b"vault",
         info.investment_id.as_ref(),
-        info.version.as_ref(),
-        &[vault_bump],
+        info.vault_version.as_ref(),
+        &[info.vault_bump],
     ];
 
+    // AUDIT: Clear or report unexpected delegate / close authority before paying out
+    guard_vault_token_account(
+        ctx.accounts.token_program.to_account_info(),
+        vault_token_account,
+        vault.to_account_info(),
+        signer_seeds,
+        info,
+        now,
+    )?;
 
-    // reject if investment info has been deactived or has not been completed
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
-    
-
-    // reject if investment info has been deactived or has not been completed
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
-
-    // reject if cache is not initialized or batch_id mismatch
-    require!(!cache.to_account_info().data_is_empty(), ErrorCode::ProfitCacheNotFound);
-    require!(cache.batch_id == batch_id, ErrorCode::BatchIdMismatch);
-
-
-    // reject if execuated_at is not 0 or cache has been executed
-    require!(cache.executed_at == 0, ErrorCode::ProfitAlreadyExecuted);
-    // reject if cache created_at execceds 25 days
-    require!(now - cache.created_at <= SHARE_CACHE_EXPIRE_SECS, ErrorCode::ProfitCacheExpired);
-    // reject if subtotal_refund_hcoin is 0
-    require!(cache.subtotal_refund_hcoin > 0, ErrorCode::InvalidTotalUsdt);
-
-
-    // Ensure signer is part of 3-of-5 execute whitelist
-    let signer_infos = &ctx.remaining_accounts[..3];
-    let signer_keys = extract_signer_keys(signer_infos);
-    info.enforce_3_of_5_signers(signer_infos, false)?; 
-
-
-    // Token checks
-    require_keys_eq!(mint.key(), get_hcoin_mint(), ErrorCode::InvalidTokenMint);
-    require_keys_eq!(vault_token_account.mint, mint.key(), ErrorCode::InvalidTokenMint);
-    require!(vault.lamports() >= cache.subtotal_estimate_sol, ErrorCode::InsufficientSolBalance);
-    require!(vault_token_account.amount >= cache.subtotal_refund_hcoin, ErrorCode::InsufficientTokenBalance);
-
-
-    // Loop through entries and process refund
-    let mut total_transferred = 0u64;
-    let mut successes: Vec<Pubkey> = vec![];
-    let mut failures: Vec<Pubkey> = vec![];
-
-    let token_program = ctx.accounts.token_program.to_account_info();
-    let mint_info = ctx.accounts.mint.to_account_info();
-    let vault_info = vault.to_account_info();
-    let signer = Some(signer_seeds);
-    let decimals = mint.decimals;
-
-    for entry in cache.entries.iter() {
-        let recipient = entry.wallet;
-        let recipient_ata = get_associated_token_address(&recipient, &mint.key());
-            
+    let mut settled_total: u64 = 0;
+    for entry in ledger
+        .entries
+        .iter_mut()
+        .filter(|e| e.account_id == account_id && e.accrued_usdt > 0)
+    {
+        let recipient_ata = get_associated_token_address(&entry.wallet, &mint.key());
         let recipient_ata_info = ctx
-            .remaining_accounts[3..]
+            .remaining_accounts
             .iter()
             .find(|acc| acc.key == &recipient_ata)
             .ok_or(ErrorCode::MissingAssociatedTokenAccount)?;
 
-        // transfer token to investor
-        let result = transfer_token_checked(
-            token_program.clone(),
+        let amount = entry.accrued_usdt;
+        transfer_token_checked(
+            ctx.accounts.token_program.to_account_info(),
             vault_token_account.to_account_info(),
             recipient_ata_info.to_account_info(),
-            mint_info.clone(),
-            vault_info.clone(),
-            signer,
-            entry.amount_hcoin,
-            decimals,
-        );
+            mint.to_account_info(),
+            vault.to_account_info(),
+            Some(signer_seeds),
+            amount,
+            mint.decimals,
+            ctx.accounts.memo_program.to_account_info(),
+            &transfer_memo(info, "accrual", Some(&account_id), &[("batch", batch_id as u64)]),
+        )?;
 
-        match result {
-            Ok(_) => {
-                successes.push(recipient);
+        entry.accrued_usdt = 0;
+        entry.settled_usdt = entry
+            .settled_usdt
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        settled_total = settled_total
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
 
-                total_transferred = total_transferred
-                .checked_add(entry.amount_hcoin)
-                .ok_or(ErrorCode::NumericalOverflow)?;
-            }
-            Err(_e) => {
-                failures.push(recipient);
-            }
-        }
+        emit!(AccrualSettled {
+            investment_id: info.investment_id,
+            version: info.version,
+            batch_id,
+            account_id,
+            wallet: entry.wallet,
+            amount_usdt: amount,
+            settled_by: ctx.accounts.payer.key(),
+            settled_at: now,
+        });
     }
 
-    require!(
-        total_transferred == cache.subtotal_refund_hcoin,
-        ErrorCode::TotalShareMismatch
-    );
-
-    if successes.len() == cache.entries.len() {
-        cache.executed_at = now;
-        msg!("🟢 All succeeded: {}, {} H2COIN", successes.len(), total_transferred);
-    } else {
-        msg!("🟡 Partial success: {} succeeded, {} failed", successes.len(), failures.len());
-    }
+    require!(settled_total > 0, ErrorCode::NothingToSettle);
 
-    emit!(RefundShareExecuted {
-        batch_id:cache.batch_id,
-        investment_id: info.investment_id,
-        version: info.version,
-        year_index: cache.year_index,
-        total_transfer_hcoin: total_transferred,
-        executed_by: ctx.accounts.payer.key(),
-        executed_at: now,
-        signers: signer_keys.clone(),
-    });
+    ledger.total_settled_usdt = ledger
+        .total_settled_usdt
+        .checked_add(settled_total)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    ledger.updated_at = now;
 
+    msg!(
+        "🟢 Settled {} USDT for account_id={} in batch {}",
+        settled_total,
+        String::from_utf8_lossy(&account_id).trim_end_matches('\0'),
+        batch_id
+    );
 
+    end_execution(&mut ctx.accounts.investment_info);
     Ok(())
 }
 
 
-//================ VAULT DEPOSIT AND WITHDRAWAL OPERATIONS ================
-// AUDIT: These functions handle vault deposit and withdrawal operations
-// SECURITY: All operations require proper validation and authorization
+//================ TENANT MANAGEMENT ================
+// AUDIT: A tenant groups investments of one platform customer under its own admin
+// SECURITY: Defaults are copied at investment creation; later changes never reach existing investments
 
-/// Deposits SOL to the vault PDA
+/// Create a tenant
 /// 
-/// AUDIT CRITICAL - VAULT SOL DEPOSIT:
-/// This function deposits SOL to the vault PDA for operational costs.
-/// It requires investment to be active and completed.
+/// AUDIT CRITICAL - TENANT CREATION:
+/// Creates the Tenant PDA of one platform customer with its admin, default platform
+/// fee and default whitelists. The admin then creates investments under the tenant,
+/// which inherit these defaults.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - Investment state validation (must be active and completed)
-/// - Vault PDA verification to prevent address spoofing
-/// - Safe SOL transfer using system program
-/// - Event emission for audit trail
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify vault PDA derivation is consistent
-/// [ ] Check investment state validation
-/// [ ] Review SOL transfer security
-/// [ ] Validate event emission for audit trail
-/// 
-/// PARAMETERS:
-/// - amount: Amount of SOL to deposit to vault
-pub fn deposit_sol_to_vault(ctx: Context<DepositSolToVault>, amount: u64) -> Result<()> {
+/// - Signer must be the config authority
+/// - Fee within MAX_TENANT_FEE_BP; default whitelists empty or 5 distinct members
+/// - PDA `init` prevents re-initialization
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_tenant(
+    ctx: Context<InitializeTenant>,
+    tenant_id: [u8; 15],
+    admin: Pubkey,
+    fee_bp: u16,
+    fee_recipient: Pubkey,
+    execute_whitelist: Vec<Pubkey>,
+    update_whitelist: Vec<Pubkey>,
+    withdraw_whitelist: Vec<Pubkey>,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    let info = &ctx.accounts.investment_info;
-    let vault = &ctx.accounts.vault;
-    let payer = &ctx.accounts.payer;
-    let system_program = &ctx.accounts.system_program;
-
-
-    // AUDIT: Reject if investment info has been deactivated or has not been completed
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
-
-    // AUDIT: Validate vault PDA derivation to prevent address spoofing
-    let (vault_pda, _bump) = Pubkey::find_program_address(
-        &[
-            b"vault", 
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
+    let authority = ctx.accounts.authority.key();
 
-    // AUDIT: Transfer SOL to vault using system program
-    let cpi_ctx = CpiContext::new(
-        system_program.to_account_info(),
-        Transfer {
-            from: payer.to_account_info(),
-            to: vault.to_account_info(),
-        },
+    // AUDIT: Only the config authority may onboard tenants
+    require_keys_eq!(
+        authority,
+        ctx.accounts.program_config.authority,
+        ErrorCode::UnauthorizedConfigAuthority
     );
-    system_program::transfer(cpi_ctx, amount)?;
 
-    // AUDIT: Emit event for audit trail
-    emit!(VaultDepositSolEvent {
-        investment_id: info.investment_id,
-        version: info.version,
-        from: *payer.key,
-        amount_usdt: amount,
-        deposit_at: now,
+    let tenant = &mut ctx.accounts.tenant;
+    tenant.tenant_id = tenant_id;
+    tenant.admin = admin;
+    tenant.fee_bp = fee_bp;
+    tenant.fee_recipient = fee_recipient;
+    tenant.execute_whitelist = execute_whitelist;
+    tenant.update_whitelist = update_whitelist;
+    tenant.withdraw_whitelist = withdraw_whitelist;
+    tenant.investment_count = 0;
+    tenant.bump = ctx.bumps.tenant;
+    tenant.updated_at = now;
+    tenant.created_at = now;
+    tenant.validate_defaults()?;
+
+    msg!("🏢 Tenant {} created", String::from_utf8_lossy(&tenant_id).trim_end_matches('\0'));
+
+    emit!(TenantUpdated {
+        tenant_id,
+        admin,
+        fee_bp,
+        fee_recipient,
+        execute_whitelist: tenant.execute_whitelist.clone(),
+        update_whitelist: tenant.update_whitelist.clone(),
+        withdraw_whitelist: tenant.withdraw_whitelist.clone(),
+        updated_by: authority,
+        updated_at: now,
     });
 
     Ok(())
 }
 
-
-/// Deposits SPL Token to the Vault's associated token account (ATA)
+/// Change a tenant's admin or defaults
 /// 
-/// AUDIT CRITICAL - VAULT TOKEN DEPOSIT:
-/// This function deposits SPL tokens (USDT or H2COIN) to the vault's associated token account.
-/// It requires investment to be active and completed.
+/// AUDIT CRITICAL - TENANT DEFAULTS:
+/// `None` leaves a field unchanged; `Some(vec![])` clears a default whitelist.
+/// Investments already created keep the whitelists and fee settings they inherited.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - Investment state validation (must be active and completed)
-/// - Vault PDA verification to prevent address spoofing
-/// - Token mint validation (USDT or H2COIN only)
-/// - Vault ATA validation
-/// - Token account ownership validation
-/// - Safe token transfer with proper authorization
-/// - Event emission for audit trail
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify vault PDA derivation is consistent
-/// [ ] Check token mint validation
-/// [ ] Review vault ATA validation
-/// [ ] Validate token transfer security
-/// [ ] Confirm event emission for audit trail
-/// 
-/// PARAMETERS:
-/// - amount: Amount of tokens to deposit to vault
-pub fn deposit_token_to_vault(ctx: Context<DepositTokenToVault>, amount: u64) -> Result<()> {
+/// - Signer must be the tenant admin
+/// - Fee within MAX_TENANT_FEE_BP; default whitelists empty or 5 distinct members
+pub fn update_tenant(
+    ctx: Context<UpdateTenant>,
+    new_admin: Option<Pubkey>,
+    fee_bp: Option<u16>,
+    fee_recipient: Option<Pubkey>,
+    execute_whitelist: Option<Vec<Pubkey>>,
+    update_whitelist: Option<Vec<Pubkey>>,
+    withdraw_whitelist: Option<Vec<Pubkey>>,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
-    let info = &ctx.accounts.investment_info;
-    let vault = &ctx.accounts.vault;
-    let vault_token_account = &ctx.accounts.vault_token_account;
-
+    let admin = ctx.accounts.admin.key();
+    let tenant = &mut ctx.accounts.tenant;
 
-    // AUDIT: Reject if investment info is inactive or not completed
-    require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(
-        info.state == InvestmentState::Completed,
-        ErrorCode::InvestmentInfoNotCompleted
-    );
+    // AUDIT: Only the current admin maintains the tenant
+    require_keys_eq!(admin, tenant.admin, ErrorCode::UnauthorizedTenantAdmin);
 
-    // AUDIT: Derive the expected vault PDA to prevent address spoofing
-    let (vault_pda, _) = Pubkey::find_program_address(
-        &[
-            b"vault",
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    require!(vault.key() == vault_pda && vault.key() == info.vault, ErrorCode::InvalidVaultPda);
+    if let Some(new_admin) = new_admin {
+        tenant.admin = new_admin;
+    }
+    if let Some(fee_bp) = fee_bp {
+        tenant.fee_bp = fee_bp;
+    }
+    if let Some(fee_recipient) = fee_recipient {
+        tenant.fee_recipient = fee_recipient;
+    }
+    if let Some(whitelist) = execute_whitelist {
+        tenant.execute_whitelist = whitelist;
+    }
+    if let Some(whitelist) = update_whitelist {
+        tenant.update_whitelist = whitelist;
+    }
+    if let Some(whitelist) = withdraw_whitelist {
+        tenant.withdraw_whitelist = whitelist;
+    }
+    tenant.validate_defaults()?;
+    tenant.updated_at = now;
+
+    emit!(TenantUpdated {
+        tenant_id: tenant.tenant_id,
+        admin: tenant.admin,
+        fee_bp: tenant.fee_bp,
+        fee_recipient: tenant.fee_recipient,
+        execute_whitelist: tenant.execute_whitelist.clone(),
+        update_whitelist: tenant.update_whitelist.clone(),
+        withdraw_whitelist: tenant.withdraw_whitelist.clone(),
+        updated_by: admin,
+        updated_at: now,
+    });
 
-    // AUDIT: Validate mint (USDT or H2COIN only)
-    let mint = ctx.accounts.mint.key();
-    require!(
-        mint == get_usdt_mint() || mint == get_hcoin_mint(),
-        ErrorCode::InvalidTokenMint
-    );
+    Ok(())
+}
 
-    // AUDIT: Validate vault ATA ownership
-    let expected_vault_token_ata = get_associated_token_address(&vault_pda, &mint);
-    require_keys_eq!(
-        ctx.accounts.vault_token_account.key(),
-        expected_vault_token_ata,
-        ErrorCode::InvalidVaultAta
-    );
 
-    // AUDIT: Validate token account ownership
+//================ EXCHANGE WALLET SCREENING ================
+// AUDIT: Record wallets are screened against known exchange deposit wallets
+// SECURITY: The registry is maintained by the config authority only
+
+/// Set the exchange registry
+/// 
+/// AUDIT CRITICAL - EXCHANGE REGISTRY:
+/// Replaces the list of exchange deposit program owners and hot wallet addresses
+/// that add_investment_record and update_investment_record_wallets screen record
+/// wallets against. Records created before a change are not re-screened.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Signer must be the config authority
+/// - At most MAX_EXCHANGE_OWNERS distinct, non-default entries
+pub fn set_exchange_registry(ctx: Context<SetExchangeRegistry>, owners: Vec<Pubkey>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+
+    // AUDIT: Only the config authority maintains the registry
     require_keys_eq!(
-        ctx.accounts.from.owner.key(),
-        ctx.accounts.payer.key(),
-        ErrorCode::InvalidFromOwner
+        authority,
+        ctx.accounts.program_config.authority,
+        ErrorCode::UnauthorizedConfigAuthority
     );
 
-    // AUDIT: Transfer token to vault ATA with proper authorization
-    transfer_token_checked(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.from.to_account_info(),
-        vault_token_account.to_account_info(),
-        ctx.accounts.mint.to_account_info(),
-        ctx.accounts.payer.to_account_info(),
-        None,
-        amount,
-        ctx.accounts.mint.decimals,
-    )?;
+    let registry = &mut ctx.accounts.exchange_registry;
+    if registry.created_at == 0 {
+        registry.created_at = now;
+    }
+    registry.owners = owners;
+    registry.bump = ctx.bumps.exchange_registry;
+    registry.updated_at = now;
+    registry.validate()?;
 
-    // AUDIT: Emit token deposit event for audit trail
-    emit!(VaultDepositTokenEvent {
-        investment_id: info.investment_id,
-        version: info.version,
-        from: ctx.accounts.payer.key(),
-        mint,
-        amount,
-        deposit_at: now,
-    });
+    msg!("🏦 Exchange registry set with {} entries", registry.owners.len());
 
+    emit!(ExchangeRegistryUpdated {
+        owners: registry.owners.clone(),
+        updated_by: authority,
+        updated_at: now,
+    });
 
     Ok(())
 }
 
 
+//================ MULTISIG PROPOSALS ================
+// AUDIT: Asynchronous alternative to co-signing one transaction with 3 whitelist members
+// SECURITY: Approvals are re-checked against the current whitelist when the proposal executes
 
-/// Withdraws remaining SOL, USDT, and H2COIN from the vault PDA to the withdraw wallet.
-/// Withdraws remaining SOL, USDT, and H2COIN from the vault PDA to the withdraw wallet
+/// Create a multisig proposal
 /// 
-/// AUDIT CRITICAL - VAULT WITHDRAWAL:
-/// This function withdraws all remaining funds from the vault to an authorized recipient.
-/// It requires 3-of-5 multisig authorization from the execute_whitelist.
+/// AUDIT CRITICAL - PROPOSAL CREATION:
+/// Records the exact arguments of an update_investment_info or withdraw_from_vault
+/// call for whitelist members to approve one by one. The proposer's approval is
+/// recorded immediately. The action executes when the target instruction is called
+/// with this proposal before it expires.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - 3-of-5 multisig validation from execute_whitelist
-/// - Investment state validation (must be active and completed)
-/// - Vault PDA verification to prevent address spoofing
-/// - Recipient whitelist validation
-/// - Token account ownership validation
-/// - SOL balance calculation with rent exemption
-/// - Safe token transfer with proper authorization
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify vault PDA derivation is consistent
-/// [ ] Confirm multisig validation uses correct whitelist
-/// [ ] Check recipient whitelist validation
-/// [ ] Review SOL balance calculation and rent exemption
-/// [ ] Validate token transfer security
-/// [ ] Confirm event emission for audit trail
+/// - Investment must be active
+/// - Proposer must be a member of the approving whitelist
+///   (update_whitelist for updates, execute_whitelist for withdrawals)
+/// - PDA `init` prevents reuse of a proposal_id
 /// 
-/// Requires 'completed' and 'active' state
-/// Requires 3-of-5 execute whitelist signatures.
-pub fn withdraw_from_vault<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, WithdrawFromVault<'info>>,
-) -> Result<()>
-where
-    'c: 'info,
-{
+/// PARAMETERS:
+/// - proposal_id: Caller-chosen identifier, unique per investment
+/// - action: Target instruction and its exact arguments
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    proposal_id: u64,
+    action: ProposalAction,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
     let info = &ctx.accounts.investment_info;
-    let usdt_mint = &ctx.accounts.usdt_mint;
-    let hcoin_mint = &ctx.accounts.hcoin_mint;
-
-    let vault = &ctx.accounts.vault;
-    let vault_usdt_account = &ctx.accounts.vault_usdt_account;
-    let vault_hcoin_account = &ctx.accounts.vault_hcoin_account;
-
-    let recipient_account = &ctx.accounts.recipient_account;
-    let recipient_usdt_account = &ctx.accounts.recipient_usdt_account;
-    let recipient_hcoin_account = &ctx.accounts.recipient_hcoin_account;
+    let config = &ctx.accounts.investment_config;
+    let proposer = ctx.accounts.proposer.key();
 
-    // AUDIT: Reject if investment info has been deactivated or has not been completed
+    // AUDIT: Reject if investment has been deactivated
     require!(info.is_active, ErrorCode::InvestmentInfoDeactivated);
-    require!(info.state == InvestmentState::Completed, ErrorCode::InvestmentInfoNotCompleted);
-
-    // AUDIT: Extract and verify 3-of-5 signer keys from execute_whitelist
-    let signer_infos: &[AccountInfo<'info>] = &ctx.remaining_accounts[0..3];
-    let signer_keys = extract_signer_keys(signer_infos);
-    info.enforce_3_of_5_signers(signer_infos, false)?;
 
-    // AUDIT: Derive vault PDA and verify correctness to prevent address spoofing
-    let (vault_pda, vault_bump) = Pubkey::find_program_address(
-        &[
-            b"vault", 
-            info.investment_id.as_ref(),
-            info.version.as_ref(),
-        ],
-        ctx.program_id,
-    );
-    let signer_seeds: &[&[u8]] = &[
-        b"vault",
-        info.investment_id.as_ref(),
-        info.version.as_ref(),
-        &[vault_bump],
-    ];
+    // AUDIT: Only members of the approving whitelist may propose
+    let role = if action.is_update() { SignerRole::Update } else { SignerRole::Execute };
     require!(
-        vault.key() == info.vault && vault_pda.key() == info.vault, 
-        ErrorCode::InvalidVaultPda
+        config.count_whitelisted_signers(&[proposer], role) == 1,
+        ErrorCode::UnauthorizedSigner
     );
 
-    // AUDIT: Check recipient is on withdraw whitelist for authorization
-    require!(!info.withdraw_whitelist.is_empty(), ErrorCode::EmptyWhitelist);
-    require!(info.withdraw_whitelist.contains(&recipient_account.key()), ErrorCode::UnauthorizedRecipient);
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.investment_id = info.investment_id;
+    proposal.version = info.version;
+    proposal.proposal_id = proposal_id;
+    proposal.action = action;
+    proposal.proposer = proposer;
+    proposal.approvals = vec![proposer];
+    proposal.created_at = now;
+    proposal.expires_at = now
+        .checked_add(PROPOSAL_TTL_SECS)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    proposal.executed_at = 0;
+    proposal.bump = ctx.bumps.proposal;
+
+    msg!("🗳️ Proposal {} created by {}", proposal_id, proposer);
+
+    emit!(ProposalCreated {
+        investment_id: info.investment_id,
+        version: info.version,
+        proposal_id,
+        action,
+        proposer,
+        created_at: now,
+        expires_at: proposal.expires_at,
+    });
 
-    // AUDIT: Transfer USDT if balance > 0 and vault ATA owner is correct
-    if vault_usdt_account.mint == usdt_mint.key() && vault_usdt_account.amount > 0 {
-        // AUDIT: Transfer token from vault ATA to recipient ATA with PDA authorization
-        transfer_token_checked(
-            ctx.accounts.token_program.to_account_info(),
-            vault_usdt_account.to_account_info(),
-            recipient_usdt_account.to_account_info(),
-            usdt_mint.to_account_info(),
-            vault.to_account_info(),
-            Some(signer_seeds),
-            vault_usdt_account.amount,
-            usdt_mint.decimals,
-        )?;
-    } else {
-        msg!("🟡 Vault USDT amount = 0, skip transfer");
-    }
- 
-    // AUDIT: Transfer H2COIN if balance > 0 and vault ATA owner is correct   
-    if vault_hcoin_account.mint == hcoin_mint.key() && vault_hcoin_account.amount > 0 {
-        // AUDIT: Transfer token from vault ATA to recipient ATA with PDA authorization
-        transfer_token_checked(
-            ctx.accounts.token_program.to_account_info(),
-            vault_hcoin_account.to_account_info(),
-            recipient_hcoin_account.to_account_info(),
-            hcoin_mint.to_account_info(),
-            vault.to_account_info(),
-            Some(signer_seeds),
-            vault_hcoin_account.amount,
-            hcoin_mint.decimals,
-        )?;
-    } else {
-        msg!("🟡 Vault H2COIN amount = 0, skip transfer");
-    }
+    Ok(())
+}
 
-    // AUDIT: Get lamport balance and calculate rent-exempt threshold for safe SOL withdrawal
-    let remaining_lamports = vault.lamports();
-    let rent_exempt = Rent::get()?.minimum_balance(vault.data_len());
-    let withdraw_lamports = vault.lamports()
-        .saturating_sub(rent_exempt)
-        .saturating_sub(ESTIMATE_SOL_BASE)
-        .saturating_sub(ESTIMATE_SOL_PER_ENTRY);
+/// Approve a multisig proposal
+/// 
+/// AUDIT CRITICAL - PROPOSAL APPROVAL:
+/// Adds the signer's approval. Members sign separate transactions, possibly days
+/// apart, until the approvals satisfy the whitelist threshold.
+/// 
+/// SECURITY CHECKS IMPLEMENTED:
+/// - Proposal must not be executed or expired
+/// - Approver must be a member of the approving whitelist
+/// - Each member approves once
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let info = &ctx.accounts.investment_info;
+    let config = &ctx.accounts.investment_config;
+    let approver = ctx.accounts.approver.key();
+    let proposal = &mut ctx.accounts.proposal;
 
-    // AUDIT: Transfer SOL if available with PDA authorization
-    if withdraw_lamports > 0 {
-        let signer: &[&[&[u8]]] = &[signer_seeds];
+    proposal.require_open(now)?;
 
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: vault.to_account_info(),
-                to: recipient_account.to_account_info(),
-            },
-            signer,
-        );
+    // AUDIT: Only members of the approving whitelist may approve
+    let role = if proposal.action.is_update() { SignerRole::Update } else { SignerRole::Execute };
+    require!(
+        config.count_whitelisted_signers(&[approver], role) == 1,
+        ErrorCode::UnauthorizedSigner
+    );
+    require!(!proposal.approvals.contains(&approver), ErrorCode::ProposalAlreadyApproved);
 
-        system_program::transfer(cpi_ctx, withdraw_lamports)?;
-    } else {
-        msg!("🟡 No withdrawable SOL (rent-exempt only), skip transfer.");
-    }
+    // AUDIT: Bounded by the whitelist size, since every approver is a distinct member
+    proposal.approvals.push(approver);
 
-    // AUDIT: Emit vault transfer event for audit trail
-    emit!(VaultTransferred {
+    msg!(
+        "🗳️ Proposal {} approved by {} ({} approvals)",
+        proposal.proposal_id,
+        approver,
+        proposal.approvals.len()
+    );
+
+    emit!(ProposalApproved {
         investment_id: info.investment_id,
         version: info.version,
-        recipient: recipient_account.key(),
-        sol_amount: remaining_lamports,
-        usdt_amount: vault_usdt_account.amount,
-        hcoin_amount: vault_hcoin_account.amount,
-        executed_by: ctx.accounts.payer.key(),
-        executed_at: now,
-        signers: signer_keys.clone(),
+        proposal_id: proposal.proposal_id,
+        approver,
+        approvals: proposal.approvals.clone(),
+        approved_at: now,
     });
 
     Ok(())
 }
 
-/// Execute token transfer with comprehensive validation
+/// Execute an approved proposal in place of co-signers
 /// 
-/// AUDIT CRITICAL - TOKEN TRANSFER UTILITY:
-/// This utility function handles SPL token transfers with comprehensive validation.
-/// It supports both regular wallet and PDA-based transfers.
+/// AUDIT CRITICAL - PROPOSAL EXECUTION:
+/// Called by the target instruction when a proposal is supplied. The instruction's
+/// own arguments form `action`, so the call must repeat exactly what was approved.
+/// Returns the approvals as the signer set recorded in the instruction's event.
 /// 
 /// SECURITY CHECKS IMPLEMENTED:
-/// - Token program ID validation
-/// - Recipient account ownership validation
-/// - PDA signer seed validation
-/// - Transfer amount and decimal validation
-/// - Safe CPI call construction
-/// 
-/// AUDIT POINTS:
-/// [ ] Verify token program ID validation
-/// [ ] Check recipient account ownership
-/// [ ] Review PDA signer seed handling
-/// [ ] Confirm transfer amount validation
-/// [ ] Validate CPI call security
-#[allow(clippy::too_many_arguments)]
-fn transfer_token_checked<'info>(
-    token_program: AccountInfo<'info>,
-    from: AccountInfo<'info>,
-    to: AccountInfo<'info>,
-    mint: AccountInfo<'info>,
-    authority: AccountInfo<'info>,
-    authority_seeds: Option<&[&[u8]]>,
-    amount: u64,
-    decimals: u8,
-) -> Result<()> {
-    // AUDIT: Validate token program ID to prevent unauthorized transfers
-    require!(
-        token_program.key() == TOKEN_PROGRAM_ID,
-        ErrorCode::InvalidTokenProgramID
-    );
+/// - Proposal belongs to the investment and matches the action
+/// - Not executed and not expired
+/// - Approvals satisfy the current whitelist and signer weights
+fn execute_proposal(
+    proposal: &mut Account<Proposal>,
+    info: &InvestmentInfo,
+    config: &InvestmentConfig,
+    action: &ProposalAction,
+    executed_by: Pubkey,
+    now: i64,
+) -> Result<Vec<Pubkey>> {
+    let approvals = proposal.consume(info, config, action, now)?;
+
+    msg!("🗳️ Proposal {} executed by {}", proposal.proposal_id, executed_by);
+
+    emit!(ProposalExecuted {
+        investment_id: info.investment_id,
+        version: info.version,
+        proposal_id: proposal.proposal_id,
+        executed_by,
+        executed_at: now,
+        approvals: approvals.clone(),
+    });
 
-    // AUDIT: Validate recipient account ownership for security
-    require!(
-        to.owner == &TOKEN_PROGRAM_ID,
-        ErrorCode::InvalidRecipientOwner
-    );
+    Ok(approvals)
+}
 
-    let cpi_accounts = TransferChecked {
-        from,
-        to,
-        mint,
-        authority,
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // AUDIT: Handle PDA-based transfers with proper signer seeds
-    if let Some(seeds_inner) = authority_seeds {
-        if !seeds_inner.is_empty() {
-            msg!("🟢 using PDA signer with {} seed(s)", seeds_inner.len());
-            let signer: &[&[&[u8]]] = &[seeds_inner];
-            let cpi_ctx = CpiContext::new_with_signer(
-                token_program,
-                cpi_accounts,
-                signer,
-            );
-            token::transfer_checked(cpi_ctx, amount, decimals)?;
-        } else {
-            msg!("🟢 signer seeds is empty → using no signer");
-            let cpi_ctx = CpiContext::new(
-                token_program,
-                cpi_accounts,
-            );
-            token::transfer_checked(cpi_ctx, amount, decimals)?;
-        }
-    } else {
-        // AUDIT: Handle regular wallet-based transfers
-        msg!("🟢 no signer (authority is expected to be a wallet)");
-        let cpi_ctx = CpiContext::new(
-            token_program,
-            cpi_accounts,
-        );
-        token::transfer_checked(cpi_ctx, amount, decimals)?;
+    fn profit_cache(entries: &[(u64, EntryStatus)]) -> ProfitShareCache {
+        let mut cache = ProfitShareCache::deserialize(&mut &vec![0u8; ProfitShareCache::SIZE][..]).unwrap();
+        cache.entries = entries
+            .iter()
+            .enumerate()
+            .map(|(index, (amount_usdt, status))| ProfitEntry {
+                entry_index: index as u16,
+                account_id: [0u8; 15],
+                wallet: Pubkey::new_unique(),
+                amount_usdt: *amount_usdt,
+                ratio_bp: 0,
+                multiplier_bp: PROFIT_STAGE_MULTIPLIER_ONE_BP,
+                failure_count: 0,
+                status: *status,
+                held_record_id: None,
+            })
+            .collect();
+        cache.subtotal_profit_usdt = entries.iter().map(|(amount, _)| amount).sum();
+        cache
     }
 
-    Ok(())
+    #[test]
+    fn retry_order_puts_failed_pending_entries_first() {
+        let entries = [
+            (EntryStatus::Pending, 0),
+            (EntryStatus::Pending, 2),
+            (EntryStatus::Paid, 0),
+            (EntryStatus::Pending, 1),
+            (EntryStatus::Escrowed, 3),
+            (EntryStatus::Pending, 2),
+            (EntryStatus::Requeued, 0),
+        ];
+        // Highest failure_count first; ties keep cache order; settled entries are skipped
+        assert_eq!(retry_order(&entries), vec![1, 5, 3, 0]);
+        assert!(retry_order(&[(EntryStatus::Paid, 0), (EntryStatus::Escrowed, 3)]).is_empty());
+    }
+
+    #[test]
+    fn settle_profit_cache_waits_for_every_pending_entry() {
+        let mut cache = profit_cache(&[(100, EntryStatus::Paid), (200, EntryStatus::Pending)]);
+        assert!(!settle_profit_cache(&mut cache, None, 1, 42).unwrap());
+        assert_eq!(cache.executed_at, 0);
+
+        // A partly executed cache keeps its paid entries; the rest settles it later
+        cache.entries[1].status = EntryStatus::Escrowed;
+        assert!(settle_profit_cache(&mut cache, None, 1, 42).unwrap());
+        assert_eq!(cache.executed_at, 42);
+    }
+
+    #[test]
+    fn settle_profit_cache_rejects_entries_off_the_subtotal() {
+        let mut cache = profit_cache(&[(100, EntryStatus::Paid), (200, EntryStatus::Requeued)]);
+        cache.subtotal_profit_usdt = 301;
+        assert!(settle_profit_cache(&mut cache, None, 1, 42).is_err());
+        assert_eq!(cache.executed_at, 0);
+    }
+
+    #[test]
+    fn settle_refund_cache_reconciles_like_profit_caches() {
+        let mut cache = RefundShareCache::deserialize(&mut &vec![0u8; RefundShareCache::SIZE][..]).unwrap();
+        cache.entries = [(100, EntryStatus::Paid), (50, EntryStatus::Pending)]
+            .iter()
+            .enumerate()
+            .map(|(index, (amount_hcoin, status))| RefundEntry {
+                entry_index: index as u16,
+                account_id: [0u8; 15],
+                wallet: Pubkey::new_unique(),
+                amount_hcoin: *amount_hcoin,
+                stage: 1,
+                failure_count: 0,
+                status: *status,
+                held_record_id: None,
+            })
+            .collect();
+        cache.subtotal_refund_hcoin = 150;
+
+        assert!(!settle_refund_cache(&mut cache, None, 1, 0, 42).unwrap());
+        cache.entries[1].status = EntryStatus::Paid;
+        cache.subtotal_refund_hcoin = 151;
+        assert!(settle_refund_cache(&mut cache, None, 1, 0, 42).is_err());
+        cache.subtotal_refund_hcoin = 150;
+        assert!(settle_refund_cache(&mut cache, None, 1, 0, 42).unwrap());
+        assert_eq!(cache.executed_at, 42);
+    }
 }
@@ -26,6 +26,8 @@
 
 #![allow(unexpected_cfgs)]
 #![allow(clippy::result_large_err)]
+// Generated cpi:: wrappers (feature `cpi`) take one argument per instruction parameter
+#![allow(clippy::too_many_arguments)]
 
 use anchor_lang::prelude::*;
 
@@ -36,7 +38,10 @@ pub mod state;        // Data structures and state management
 pub mod event;        // Event emission for off-chain tracking
 pub mod constants;    // Program constants and configuration
 pub mod error;        // Custom error definitions
+#[cfg(feature = "replay-fixtures")]
+pub mod fixture;      // Off-chain replay fixtures for local incident reproduction
 
+use crate::constants::MAX_STAGE;
 use crate::state::*;
 use crate::context::*;
 
@@ -73,7 +78,9 @@ pub mod h2coin_vault_share {
     /// - Whitelist size validation (exactly 5 members)
     /// - Stage ratio validation (0-100%, contiguous non-zero values)
     /// - PDA derivation verification
-    /// - Token mint validation
+    /// - Token mint validation; the profit mint must be allowlisted (USDT, USDC)
+    /// - defer_open starts in Init until open_investment is called
+    /// - With a tenant, empty whitelists and the fee settings are inherited from it
     #[allow(clippy::too_many_arguments)]
     pub fn initialize_investment_info(
         ctx: Context<InitializeInvestmentInfo>,
@@ -87,6 +94,7 @@ pub mod h2coin_vault_share {
         execute_whitelist: Vec<Pubkey>,
         update_whitelist: Vec<Pubkey>,
         withdraw_whitelist: Vec<Pubkey>,
+        defer_open: bool,
     ) -> Result<()> {
         instructions::initialize_investment_info(
             ctx,
@@ -100,6 +108,7 @@ pub mod h2coin_vault_share {
             execute_whitelist,
             update_whitelist,
             withdraw_whitelist,
+            defer_open,
         )
     }
 
@@ -107,7 +116,7 @@ pub mod h2coin_vault_share {
     /// 
     /// AUDIT CRITICAL:
     /// - Requires 3-of-5 multisig from update_whitelist
-    /// - Can modify stage ratios and investment limits
+    /// - Can modify stage ratios, investment limits and the H2COIN rate range
     /// - Only allowed when investment is active and not completed
     /// 
     /// SECURITY CHECKS:
@@ -118,8 +127,29 @@ pub mod h2coin_vault_share {
         ctx: Context<UpdateInvestmentInfo>,
         new_stage_ratio: Option<[[u8; 10]; 3]>,
         new_upper_limit: Option<u64>,
+        new_hcoin_rate_range: Option<HcoinRateRange>,
+        new_min_estimate_interval_secs: Option<u32>,
+        new_stage_ratio_effective_year: Option<u8>,
     ) -> Result<()> {
-        instructions::update_investment_info(ctx, new_stage_ratio, new_upper_limit)
+        instructions::update_investment_info(
+            ctx,
+            new_stage_ratio,
+            new_upper_limit,
+            new_hcoin_rate_range,
+            new_min_estimate_interval_secs,
+            new_stage_ratio_effective_year,
+        )
+    }
+
+    /// Simulate refunds under a proposed stage ratio
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only; returns per-year refund amounts of the sample record via return data
+    pub fn simulate_stage_ratio(
+        ctx: Context<SimulateStageRatio>,
+        new_ratio: [[u8; 10]; 3],
+    ) -> Result<Vec<u64>> {
+        instructions::simulate_stage_ratio(ctx, new_ratio)
     }
 
     /// Mark investment as completed
@@ -137,6 +167,141 @@ pub mod h2coin_vault_share {
         instructions::completed_investment_info(ctx)
     }    
 
+    /// Cancel a pending investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Changes investment state from Pending to Cancelled
+    /// - Blocks distributions and enables return_deposits
+    pub fn cancel_investment_info(ctx: Context<CancelInvestmentInfo>) -> Result<()> {
+        instructions::cancel_investment_info(ctx)
+    }
+
+    /// Open an investment that was initialized in Init
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Changes investment state from Init to Pending
+    /// - Records are only accepted once the investment is open
+    pub fn open_investment(ctx: Context<OpenInvestment>) -> Result<()> {
+        instructions::open_investment(ctx)
+    }
+
+    /// Pause or resume instruction families of one investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Mask of PAUSE_* bits; 0 resumes every family
+    pub fn set_instruction_pause(ctx: Context<SetInstructionPause>, paused_families: u8) -> Result<()> {
+        instructions::set_instruction_pause(ctx, paused_families)
+    }
+
+    /// Assign or clear an RBAC role
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Empty members with threshold 0 restores the whitelist rule
+    pub fn set_role_assignment(
+        ctx: Context<SetRoleAssignment>,
+        role: Role,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_role_assignment(ctx, role, members, threshold)
+    }
+
+    /// Set the amount-based signer tiers of withdraw_from_vault
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Empty list restores the Withdrawer role rule
+    pub fn set_withdraw_tiers(ctx: Context<SetWithdrawTiers>, tiers: Vec<WithdrawTier>) -> Result<()> {
+        instructions::set_withdraw_tiers(ctx, tiers)
+    }
+
+    /// Set the withdrawal timelock
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - 24h to 72h; once set, withdrawals go through propose_withdrawal / execute_withdrawal
+    pub fn set_withdraw_delay(ctx: Context<SetWithdrawDelay>, delay_secs: u32) -> Result<()> {
+        instructions::set_withdraw_delay(ctx, delay_secs)
+    }
+
+    /// Set the caps of one withdrawal purpose
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Caps bound the cumulative amounts withdrawn under the purpose
+    pub fn set_withdraw_budget(
+        ctx: Context<SetWithdrawBudget>,
+        purpose: WithdrawPurpose,
+        cap: BudgetCap,
+    ) -> Result<()> {
+        instructions::set_withdraw_budget(ctx, purpose, cap)
+    }
+
+    /// Configure the rolling-window withdrawal rate limit
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Caps USDT / H2COIN withdrawn within any window across all withdrawal paths
+    pub fn set_withdraw_rate_limit(
+        ctx: Context<SetWithdrawRateLimit>,
+        window_secs: u32,
+        cap_usdt: Option<u64>,
+        cap_hcoin: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_withdraw_rate_limit(ctx, window_secs, cap_usdt, cap_hcoin)
+    }
+
+    /// Switch the four-eyes rule of record edits
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - When enabled, the payer of a record wallet update or revocation must not sign it
+    pub fn set_record_four_eyes(ctx: Context<SetRecordFourEyes>, enabled: bool) -> Result<()> {
+        instructions::set_record_four_eyes(ctx, enabled)
+    }
+
+    /// Designate the recovery wallet of foreign token sweeps
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - The default pubkey clears the wallet and disables sweeping
+    pub fn set_recovery_wallet(ctx: Context<SetRecoveryWallet>, wallet: Pubkey) -> Result<()> {
+        instructions::set_recovery_wallet(ctx, wallet)
+    }
+
+    /// Set the profit multipliers per investment stage
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - All zero switches stage weighting off; otherwise each within 1..=50_000 bp
+    pub fn set_profit_stage_multipliers(
+        ctx: Context<SetProfitStageMultipliers>,
+        multipliers_bp: [u16; MAX_STAGE],
+    ) -> Result<()> {
+        instructions::set_profit_stage_multipliers(ctx, multipliers_bp)
+    }
+
+    /// Put an investment under an emergency freeze
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Any single execute / update whitelist member
+    /// - Blocks execution, settlement and withdrawal until unfrozen
+    pub fn freeze_investment(ctx: Context<FreezeInvestment>) -> Result<()> {
+        instructions::freeze_investment(ctx)
+    }
+
+    /// Lift an emergency freeze
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    pub fn unfreeze_investment(ctx: Context<UnfreezeInvestment>) -> Result<()> {
+        instructions::unfreeze_investment(ctx)
+    }
+
     /// Deactivate investment info
     /// 
     /// AUDIT CRITICAL:
@@ -151,34 +316,109 @@ pub mod h2coin_vault_share {
         instructions::deactivate_investment_info(ctx)
     }    
 
+    /// Attest the final settlement and archive a completed investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Every profit round and refund year of every batch is settled or explicitly waived
+    /// - Terminal: Archived and inactive; unlocks the residual sweep in close_vault
+    /// 
+    /// SECURITY CHECKS:
+    /// - Caches (remaining_accounts after the signers) validated by PDA
+    /// - Waivers listed in the InvestmentArchived event
+    pub fn archive_investment<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ArchiveInvestment<'info>>,
+        waivers: Vec<SettlementWaiver>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::archive_investment(ctx, waivers)
+    }
+
+    /// Close the vault of a deactivated investment and reclaim its rent
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist
+    /// - Closes both (empty) vault ATAs and moves all vault lamports to a withdraw whitelist wallet
+    /// - An archived investment first sweeps residual USDT / H2COIN to the recipient's ATAs
+    pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
+        instructions::close_vault(ctx)
+    }
+
+    /// Link this version to an earlier version's vault
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from both versions' update_whitelists
+    /// - Deactivates the earlier version; no funds move
+    pub fn link_shared_vault(ctx: Context<LinkSharedVault>) -> Result<()> {
+        instructions::link_shared_vault(ctx)
+    }
+
     /// Update execute whitelist members
     /// 
     /// AUDIT CRITICAL:
     /// - Requires 3-of-5 multisig from current execute_whitelist
-    /// - Can replace whitelist members one at a time
+    /// - Queues the replacement of one member; applied after WHITELIST_PATCH_DELAY_SECS
+    /// - to_label replaces the outgoing member's label when applied
     /// - Affects authorization for profit/refund execution
     /// 
     /// SECURITY CHECKS:
     /// - Multisig validation (3-of-5)
     /// - Whitelist member validation
     /// - Duplicate address prevention
-    pub fn patch_execute_whitelist(ctx: Context<UpdateExecuteWallet>) -> Result<()> {
-        instructions::patch_execute_whitelist(ctx)
+    pub fn patch_execute_whitelist(
+        ctx: Context<UpdateExecuteWallet>,
+        to_label: [u8; 16],
+        idempotency_key: Option<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::patch_execute_whitelist(ctx, to_label, idempotency_key)
     }
 
     /// Update update whitelist members
     /// 
     /// AUDIT CRITICAL:
     /// - Requires 3-of-5 multisig from current update_whitelist
-    /// - Can replace whitelist members one at a time
+    /// - Queues the replacement of one member; applied after WHITELIST_PATCH_DELAY_SECS
+    /// - to_label replaces the outgoing member's label when applied
     /// - Affects authorization for investment info updates
     /// 
     /// SECURITY CHECKS:
     /// - Multisig validation (3-of-5)
     /// - Whitelist member validation
     /// - Duplicate address prevention
-    pub fn patch_update_whitelist(ctx: Context<UpdateUpdateWallet>) -> Result<()> {
-        instructions::patch_update_whitelist(ctx)
+    pub fn patch_update_whitelist(
+        ctx: Context<UpdateUpdateWallet>,
+        to_label: [u8; 16],
+        idempotency_key: Option<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::patch_update_whitelist(ctx, to_label, idempotency_key)
+    }
+
+    /// Accept a seat offered by a pending whitelist patch
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be signed by the incoming member (`to`)
+    /// - Required before apply_whitelist_patch
+    pub fn accept_whitelist_seat(ctx: Context<AcceptWhitelistSeat>) -> Result<()> {
+        instructions::accept_whitelist_seat(ctx)
+    }
+
+    /// Apply a pending execute / update whitelist patch
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless once the patch's effective_at has passed and the seat is accepted
+    /// - Re-checks the replacement against the current whitelist
+    pub fn apply_whitelist_patch(ctx: Context<ApplyWhitelistPatch>) -> Result<()> {
+        instructions::apply_whitelist_patch(ctx)
+    }
+
+    /// Cancel a pending execute / update whitelist patch
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires WHITELIST_PATCH_CANCEL_SIGNERS members of the patched whitelist
+    pub fn cancel_whitelist_patch(ctx: Context<CancelWhitelistPatch>) -> Result<()> {
+        instructions::cancel_whitelist_patch(ctx)
     }
 
     /// Update withdraw whitelist members
@@ -186,14 +426,58 @@ pub mod h2coin_vault_share {
     /// AUDIT CRITICAL:
     /// - Requires 3-of-5 multisig from current withdraw_whitelist
     /// - Can replace whitelist members one at a time
+    /// - Takes one label per new member
     /// - Affects authorization for vault withdrawals
     /// 
     /// SECURITY CHECKS:
     /// - Multisig validation (3-of-5)
     /// - Whitelist member validation
     /// - Duplicate address prevention
-    pub fn patch_withdraw_whitelist(ctx: Context<UpdateWithdrawWallet>) -> Result<()> {
-        instructions::patch_withdraw_whitelist(ctx)
+    pub fn patch_withdraw_whitelist(
+        ctx: Context<UpdateWithdrawWallet>,
+        labels: Vec<[u8; 16]>,
+        idempotency_key: Option<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::patch_withdraw_whitelist(ctx, labels, idempotency_key)
+    }
+
+    /// Set signer weights for the execute or update whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires the target whitelist's current multisig rule
+    /// - Weight threshold replaces the 3-of-5 count; threshold 0 restores it
+    pub fn set_signer_weights(
+        ctx: Context<UpdateSignerWeights>,
+        is_update: bool,
+        weights: SignerWeights,
+    ) -> Result<()> {
+        instructions::set_signer_weights(ctx, is_update, weights)
+    }
+
+    /// Set the member labels of a whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires the multisig that patches the whitelist
+    /// - Labels are display metadata and never take part in authorization
+    pub fn set_whitelist_labels(
+        ctx: Context<SetWhitelistLabels>,
+        role: SignerRole,
+        labels: Vec<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::set_whitelist_labels(ctx, role, labels)
+    }
+
+    /// Enable, change or disable the Squads authorization mode
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Stores the Squads v4 vault PDA of `multisig`; None disables the mode
+    pub fn set_squads_authority(
+        ctx: Context<SetSquadsAuthority>,
+        multisig: Option<Pubkey>,
+        vault_index: u8,
+    ) -> Result<()> {
+        instructions::set_squads_authority(ctx, multisig, vault_index)
     }
 
     //================ INVESTMENT RECORD MANAGEMENT ================
@@ -204,6 +488,8 @@ pub mod h2coin_vault_share {
     /// 
     /// AUDIT CRITICAL:
     /// - Creates individual investment records
+    /// - `provisional` records stay inert until acknowledge_record
+    /// - Exchange deposit wallets require `allow_exchange_wallet`
     /// - Transfers tokens from recipient to vault
     /// - Records investment amounts and stage information
     /// - Generates unique record identifiers
@@ -222,8 +508,31 @@ pub mod h2coin_vault_share {
         amount_usdt: u64,
         amount_hcoin: u64,
         investment_stage: u8,
+        provisional: bool,
+        allow_exchange_wallet: bool,
+    ) -> Result<()> {
+        instructions::add_investment_record(ctx, batch_id, record_id, account_id, amount_usdt, amount_hcoin, investment_stage, provisional, allow_exchange_wallet)
+    }
+
+    /// Backfill a historical investment record with its original timestamp
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist, as add_investment_record
+    /// - created_at must lie within [start_at, min(end_at, now)]
+    /// - Emits InvestmentRecordBackfilled alongside InvestmentRecordAdded
+    #[allow(clippy::too_many_arguments)]
+    pub fn backfill_investment_record(
+        ctx: Context<AddInvestmentRecords>,
+        batch_id: u16,
+        record_id: u64,
+        account_id: [u8; 15],
+        amount_usdt: u64,
+        amount_hcoin: u64,
+        investment_stage: u8,
+        allow_exchange_wallet: bool,
+        created_at: i64,
     ) -> Result<()> {
-        instructions::add_investment_record(ctx, batch_id, record_id, account_id, amount_usdt, amount_hcoin, investment_stage)
+        instructions::backfill_investment_record(ctx, batch_id, record_id, account_id, amount_usdt, amount_hcoin, investment_stage, allow_exchange_wallet, created_at)
     }
 
     /// Update wallet address for investment records
@@ -232,6 +541,7 @@ pub mod h2coin_vault_share {
     /// - Requires 3-of-5 multisig from update_whitelist
     /// - Updates wallet addresses for existing records
     /// - Affects future profit/refund distributions
+    /// - Exchange deposit wallets require `allow_exchange_wallet`
     /// 
     /// SECURITY CHECKS:
     /// - Multisig validation (3-of-5)
@@ -240,11 +550,14 @@ pub mod h2coin_vault_share {
     pub fn update_investment_record_wallets<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, UpdateInvestmentRecordWallets<'info>>,
         account_id: [u8; 15],
+        reason: RecordChangeReason,
+        idempotency_key: Option<[u8; 16]>,
+        allow_exchange_wallet: bool,
     ) -> Result<()> 
     where 
         'c: 'info,
     {
-        instructions::update_investment_record_wallets(ctx, account_id)
+        instructions::update_investment_record_wallets(ctx, account_id, reason, idempotency_key, allow_exchange_wallet)
     }
 
     /// Revoke an investment record
@@ -263,8 +576,172 @@ pub mod h2coin_vault_share {
         batch_id: u16,
         record_id: u64,
         account_id: [u8; 15],
+        reason: RecordChangeReason,
+    ) -> Result<()> {
+        instructions::revoked_investment_record(ctx, batch_id, record_id, account_id, reason)
+    }
+
+    /// Bind an investor wallet to a record created without one
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signed by the investor wallet being bound
+    /// - Co-signed by one update_whitelist member as identity attestation
+    /// - Only applies to records whose wallet is still unset
+    /// 
+    /// SECURITY CHECKS:
+    /// - Record PDA verification
+    /// - Record state validation (unbound, not revoked)
+    /// - Investor ATA validation
+    pub fn bind_wallet(
+        ctx: Context<BindInvestmentRecordWallet>,
+        batch_id: u16,
+        record_id: u64,
+        account_id: [u8; 15],
+    ) -> Result<()> {
+        instructions::bind_wallet(ctx, batch_id, record_id, account_id)
+    }
+
+    /// Acknowledge a provisional record
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signed by the record's investor wallet
+    /// - Activates the record for distributions
+    /// 
+    /// SECURITY CHECKS:
+    /// - Record PDA verification
+    /// - Record provisional, not revoked, within RECORD_ACK_WINDOW_SECS
+    pub fn acknowledge_record(
+        ctx: Context<AcknowledgeRecord>,
+        batch_id: u16,
+        record_id: u64,
+        account_id: [u8; 15],
+    ) -> Result<()> {
+        instructions::acknowledge_record(ctx, batch_id, record_id, account_id)
+    }
+
+    /// Contest a revocation during the holding period
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signed by the record's investor wallet
+    /// - Keeps the record's share escrowed until the contest is resolved
+    /// 
+    /// SECURITY CHECKS:
+    /// - Record PDA verification
+    /// - Record revoked, uncontested, within REVOCATION_HOLD_SECS
+    pub fn contest_revocation(
+        ctx: Context<ContestRevocation>,
+        batch_id: u16,
+        record_id: u64,
+        account_id: [u8; 15],
+    ) -> Result<()> {
+        instructions::contest_revocation(ctx, batch_id, record_id, account_id)
+    }
+
+    /// Resolve a contested revocation
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Reinstates the record or upholds the revocation
+    /// - On reinstatement, releases the record's escrowed shares in the supplied caches
+    /// 
+    /// SECURITY CHECKS:
+    /// - Multisig validation (3-of-5)
+    /// - Record must have an open contest
+    /// - Caches must belong to the record's batch
+    pub fn resolve_revocation_contest(
+        ctx: Context<ResolveRevocationContest>,
+        batch_id: u16,
+        record_id: u64,
+        account_id: [u8; 15],
+        reinstate: bool,
+    ) -> Result<()> {
+        instructions::resolve_revocation_contest(ctx, batch_id, record_id, account_id, reinstate)
+    }
+
+    /// Release a revoked record's hold from the profit denominator
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless crank
+    /// - Revoked records count in the denominator until their hold is released
+    /// 
+    /// SECURITY CHECKS:
+    /// - Record PDA verification
+    /// - Record revoked, hold and contest over, not yet released
+    pub fn release_revocation_hold(
+        ctx: Context<ReleaseRevocationHold>,
+        batch_id: u16,
+        record_id: u64,
+        account_id: [u8; 15],
     ) -> Result<()> {
-        instructions::revoked_investment_record(ctx, batch_id, record_id, account_id)
+        instructions::release_revocation_hold(ctx, batch_id, record_id, account_id)
+    }
+
+    /// Report the future allocation a revocation would free
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only and permissionless; emits RevocationImpactReported
+    pub fn report_revocation_impact(
+        ctx: Context<ReportRevocationImpact>,
+        total_invest_usdt: u64,
+    ) -> Result<()> {
+        instructions::report_revocation_impact(ctx, total_invest_usdt)
+    }
+
+    /// Count the planned signers that belong to a role's whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only and permissionless; the count is returned via return data
+    /// - Does not authorize anything
+    pub fn prevalidate_signers<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, PrevalidateSigners<'info>>,
+        role: SignerRole,
+    ) -> Result<u8> {
+        instructions::prevalidate_signers(ctx, role)
+    }
+
+    /// UTC date window of a refund year_index
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only and permissionless; the window is returned via return data
+    /// - None resolves the year_index in force now
+    pub fn year_index_window(ctx: Context<YearIndexWindow>, year_index: Option<u8>) -> Result<YearWindow> {
+        instructions::year_index_window(ctx, year_index)
+    }
+
+    /// Emit an event stream checkpoint
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless, rate limited by CHECKPOINT_MIN_INTERVAL_SECS
+    /// - Sequence numbers let indexers detect missed events
+    pub fn emit_checkpoint(ctx: Context<EmitCheckpoint>) -> Result<()> {
+        instructions::emit_checkpoint(ctx)
+    }
+
+    /// Set an account-level payout split
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Up to 3 wallets whose percentages sum to 100
+    /// 
+    /// SECURITY CHECKS:
+    /// - Multisig validation (3-of-5)
+    /// - Split validation
+    pub fn set_payout_split(
+        ctx: Context<SetPayoutSplit>,
+        account_id: [u8; 15],
+        wallets: [Pubkey; 3],
+        percents: [u8; 3],
+    ) -> Result<()> {
+        instructions::set_payout_split(ctx, account_id, wallets, percents)
+    }
+
+    /// Remove an account-level payout split
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Later estimates pay the record wallets again
+    pub fn clear_payout_split(ctx: Context<ClearPayoutSplit>, account_id: [u8; 15]) -> Result<()> {
+        instructions::clear_payout_split(ctx, account_id)
     }
 
     //================ PROFIT SHARE MANAGEMENT ================
@@ -278,22 +755,27 @@ pub mod h2coin_vault_share {
     /// - Creates profit share cache for batch processing
     /// - Requires 3-of-5 multisig from execute_whitelist
     /// - Affects actual profit distribution amounts
+    /// - payout_mint selects profit_mint (default), H2COIN or a registered vault mint
     /// 
     /// SECURITY CHECKS:
     /// - Multisig validation (3-of-5)
     /// - Investment state validation
     /// - Profit amount validation
     /// - Cache existence validation
+    /// - A live estimate is replaced only with overwrite = true (or after cancel_profit_cache)
+    /// - The invested total is derived from InvestmentInfo.invested_usdt, never supplied
     pub fn estimate_profit_share<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, EstimateProfitShare<'info>>,
         batch_id: u16,
         total_profit_usdt: u64,
-        total_invest_usdt: u64,
+        priority_fee_per_cu: Option<u64>,
+        payout_mint: Option<Pubkey>,
+        overwrite: bool,
     ) -> Result<()>
     where
         'c: 'info,
     {
-        instructions::estimate_profit_share(ctx, batch_id, total_profit_usdt, total_invest_usdt)
+        instructions::estimate_profit_share(ctx, batch_id, total_profit_usdt, priority_fee_per_cu, payout_mint, overwrite)
     }
 
     /// Execute profit share distribution
@@ -309,14 +791,22 @@ pub mod h2coin_vault_share {
     /// - Cache existence and validation
     /// - Token balance validation
     /// - Transfer amount validation
+    /// - Optional cache close only after every entry is paid
+    /// - Optional note is appended to every transfer memo (validated)
+    /// - Optional SOL reimbursement to the payer, once per cache, capped at subtotal_estimate_sol
+    /// - Returns the failed entries as (entry_index, failure_reason) via return data
     pub fn execute_profit_share<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, ExecuteProfitShare<'info>>,
         batch_id: u16,
-    ) -> Result<()>
+        close_cache: bool,
+        expected_config_hash: [u8; 32],
+        note: Option<String>,
+        reimburse_sol: Option<u64>,
+    ) -> Result<Vec<PayoutFailure>>
     where
         'c: 'info,
     {
-        instructions::execute_profit_share(ctx, batch_id)
+        instructions::execute_profit_share(ctx, batch_id, close_cache, expected_config_hash, note, reimburse_sol)
     }
 
     //================ REFUND SHARE MANAGEMENT ================
@@ -339,12 +829,13 @@ pub mod h2coin_vault_share {
     pub fn estimate_refund_share<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, EstimateRefundShare<'info>>,
         batch_id: u16,
-        year_index: u8
+        year_index: u8,
+        priority_fee_per_cu: Option<u64>,
     ) -> Result<()>
     where
         'c: 'info, 
     {
-        instructions::estimate_refund_share(ctx, batch_id, year_index)
+        instructions::estimate_refund_share(ctx, batch_id, year_index, priority_fee_per_cu)
     }
 
     /// Execute refund share distribution
@@ -360,53 +851,248 @@ pub mod h2coin_vault_share {
     /// - Cache existence and validation
     /// - Token balance validation
     /// - Transfer amount validation
+    /// - Optional cache close only after every entry is paid
+    /// - Optional note is appended to every transfer memo (validated)
+    /// - Optional SOL reimbursement to the payer, once per cache, capped at subtotal_estimate_sol
+    /// - Returns the failed entries as (entry_index, failure_reason) via return data
     pub fn execute_refund_share<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, ExecuteRefundShare<'info>>,
         batch_id: u16,
-        year_index: u8
-    ) -> Result<()>
+        year_index: u8,
+        close_cache: bool,
+        expected_config_hash: [u8; 32],
+        note: Option<String>,
+        reimburse_sol: Option<u64>,
+    ) -> Result<Vec<PayoutFailure>>
     where
         'c: 'info,
     {
-        instructions::execute_refund_share(ctx, batch_id, year_index)
+        instructions::execute_refund_share(ctx, batch_id, year_index, close_cache, expected_config_hash, note, reimburse_sol)
     }
 
-    //================ VAULT MANAGEMENT ================
-    // AUDIT: These functions handle vault deposits and withdrawals
-    // SECURITY: Critical operations affecting vault balances
-    
-    /// Deposit SOL to vault
+    /// Pay one requeued profit or refund entry after the investor recreated the ATA
     /// 
     /// AUDIT CRITICAL:
-    /// - Transfers SOL from signer to vault
-    /// - Updates vault SOL balance
-    /// - Requires proper vault account validation
-    /// 
-    /// SECURITY CHECKS:
-    /// - Vault account validation
-    /// - Amount validation
-    /// - SOL transfer validation
-    pub fn deposit_sol_to_vault(ctx: Context<DepositSolToVault>, amount: u64) -> Result<()> {
-        instructions::deposit_sol_to_vault(ctx, amount)
+    /// - A single execute whitelist member (or the Squads authority) may submit it
+    /// - Recipient and amount come from the cache entry; only Requeued entries are paid
+    pub fn requeue_entry(
+        ctx: Context<RequeueEntry>,
+        batch_id: u16,
+        year_index: u8,
+        entry_index: u16,
+    ) -> Result<()> {
+        instructions::requeue_entry(ctx, batch_id, year_index, entry_index)
     }
 
-    /// Deposit tokens to vault
+    /// Release an entry escrowed after repeated payout failures to requeue_entry
     /// 
     /// AUDIT CRITICAL:
-    /// - Transfers tokens from signer to vault
-    /// - Updates vault token balance
-    /// - Requires proper vault and token account validation
-    /// 
-    /// SECURITY CHECKS:
-    /// - Vault account validation
-    /// - Token account validation
-    /// - Amount validation
-    /// - Token transfer validation
-    pub fn deposit_token_to_vault(ctx: Context<DepositTokenToVault>, amount: u64) -> Result<()> {
-        instructions::deposit_token_to_vault(ctx, amount)
+    /// - Requires 3-of-5 multisig from execute_whitelist (Executor role)
+    /// - Only entries escrowed after MAX_ENTRY_FAILURES; recipient and amount are unchanged
+    pub fn release_escrowed_entry(
+        ctx: Context<ReleaseEscrowedEntry>,
+        batch_id: u16,
+        year_index: u8,
+        entry_index: u16,
+    ) -> Result<()> {
+        instructions::release_escrowed_entry(ctx, batch_id, year_index, entry_index)
     }
 
-    /// Withdraw from vault
+    /// Cancel a wrong profit estimate so the batch can be re-estimated at once
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist (Executor role)
+    /// - Only before any entry is paid; clears the entries and marks the cache cancelled
+    pub fn cancel_profit_cache(ctx: Context<CancelProfitCache>, batch_id: u16) -> Result<()> {
+        instructions::cancel_profit_cache(ctx, batch_id)
+    }
+
+    /// Open a profit cache so investors can claim their entries themselves
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist (Executor role)
+    /// - Approves the cache for claim_profit; execution keeps working alongside
+    pub fn open_profit_claims(
+        ctx: Context<OpenProfitClaims>,
+        batch_id: u16,
+        expected_config_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::open_profit_claims(ctx, batch_id, expected_config_hash)
+    }
+
+    /// Claim one Pending profit entry as its wallet
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signed by the entry wallet; amount and mint come from the cache
+    /// - Only for caches opened by open_profit_claims; the entry becomes Paid
+    /// - Closes with the cache after GlobalConfig.share_cache_expire_secs
+    pub fn claim_profit(
+        ctx: Context<ClaimProfit>,
+        batch_id: u16,
+        entry_index: u16,
+    ) -> Result<()> {
+        instructions::claim_profit(ctx, batch_id, entry_index)
+    }
+
+    /// Publish the Merkle root and totals of a profit batch
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist (Executor role)
+    /// - Entries stay off-chain; investors claim with claim_profit_with_proof
+    /// - The root is replaceable only until the first claim
+    /// - The signed root and subtotal are trusted; leaves are not checked against the records
+    /// - Claims open for share_cache_expire_secs; rejected while the batch has a cache round
+    pub fn estimate_profit_share_merkle(
+        ctx: Context<EstimateProfitShareMerkle>,
+        batch_id: u16,
+        merkle_root: [u8; 32],
+        entry_count: u32,
+        subtotal_profit_usdt: u64,
+        payout_mint: Option<Pubkey>,
+        expected_config_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::estimate_profit_share_merkle(
+            ctx,
+            batch_id,
+            merkle_root,
+            entry_count,
+            subtotal_profit_usdt,
+            payout_mint,
+            expected_config_hash,
+        )
+    }
+
+    /// Stop the claims of a Merkle profit round
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist (Executor role)
+    /// - Claims already made stay paid; the rest can no longer be claimed
+    pub fn cancel_profit_merkle(ctx: Context<CancelProfitMerkle>, batch_id: u16) -> Result<()> {
+        instructions::cancel_profit_merkle(ctx, batch_id)
+    }
+
+    /// Claim one Merkle profit entry with a proof
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The leaf is hashed with the signing wallet; the proof must reach the published root
+    /// - A per-entry receipt PDA prevents double claims
+    pub fn claim_profit_with_proof(
+        ctx: Context<ClaimProfitWithProof>,
+        batch_id: u16,
+        entry_index: u32,
+        account_id: [u8; 15],
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_profit_with_proof(ctx, batch_id, entry_index, account_id, amount, proof)
+    }
+
+    /// Reconcile a batch's records against its profit or refund cache
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only verification that every distributable record appears exactly once
+    ///   with the amount estimation would produce
+    /// - Emits a BatchReconciled report
+    /// 
+    /// SECURITY CHECKS:
+    /// - Signer in execute_whitelist or update_whitelist
+    /// - Record PDA and batch validation
+    pub fn reconcile_batch<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ReconcileBatch<'info>>,
+        batch_id: u16,
+        year_index: u8,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::reconcile_batch(ctx, batch_id, year_index)
+    }
+
+    /// Emit a page of a batch's records as an event
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only and permissionless export for reconciliation tools
+    /// - Page N covers record_id N * RECORD_PAGE_SIZE .. (N + 1) * RECORD_PAGE_SIZE
+    /// 
+    /// SECURITY CHECKS:
+    /// - Record PDA, batch and page validation
+    pub fn emit_record_page<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, EmitRecordPage<'info>>,
+        batch_id: u16,
+        page: u32,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::emit_record_page(ctx, batch_id, page)
+    }
+
+    /// Emit investment size and stage statistics over a batch's records
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only and permissionless report for compliance
+    /// - Only acknowledged, non-revoked records are counted
+    /// 
+    /// SECURITY CHECKS:
+    /// - Record PDA and batch validation
+    pub fn emit_batch_statistics<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, EmitBatchStatistics<'info>>,
+        batch_id: u16,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::emit_batch_statistics(ctx, batch_id)
+    }
+
+    //================ VAULT MANAGEMENT ================
+    // AUDIT: These functions handle vault deposits and withdrawals
+    // SECURITY: Critical operations affecting vault balances
+    
+    /// Deposit SOL to vault
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Transfers SOL from signer to vault
+    /// - Updates vault SOL balance
+    /// - Requires proper vault account validation
+    /// 
+    /// SECURITY CHECKS:
+    /// - Vault account validation
+    /// - Amount validation
+    /// - SOL transfer validation
+    /// 
+    /// reference_id: Optional back-office transfer order ID echoed in the event
+    pub fn deposit_sol_to_vault(
+        ctx: Context<DepositSolToVault>,
+        amount: u64,
+        reference_id: Option<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::deposit_sol_to_vault(ctx, amount, reference_id)
+    }
+
+    /// Deposit tokens to vault
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Transfers tokens from signer to vault
+    /// - Updates vault token balance
+    /// - Requires proper vault and token account validation
+    /// 
+    /// SECURITY CHECKS:
+    /// - Vault account validation
+    /// - Token account validation
+    /// - Amount validation
+    /// - Token transfer validation
+    /// 
+    /// reference_id: Optional back-office transfer order ID echoed in the event
+    pub fn deposit_token_to_vault(
+        ctx: Context<DepositTokenToVault>,
+        amount: u64,
+        reference_id: Option<[u8; 16]>,
+    ) -> Result<()> {
+        instructions::deposit_token_to_vault(ctx, amount, reference_id)
+    }
+
+    /// Withdraw from vault
     /// 
     /// AUDIT CRITICAL:
     /// - Requires 3-of-5 multisig from withdraw_whitelist
@@ -418,12 +1104,537 @@ pub mod h2coin_vault_share {
     /// - Vault account validation
     /// - Balance validation
     /// - Transfer amount validation
+    /// - Withdrawal sequence must match (anti-replay)
+    /// - Only the selected assets are withdrawn
+    /// - amount_* = None withdraws the full balance of that asset
+    /// - Counted against, and capped by, the budget line of purpose
+    #[allow(clippy::too_many_arguments)]
     pub fn withdraw_from_vault<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, WithdrawFromVault<'info>>,
+        sequence: u64,
+        assets: WithdrawAssets,
+        purpose: WithdrawPurpose,
+        amount_usdt: Option<u64>,
+        amount_hcoin: Option<u64>,
+        amount_sol: Option<u64>,
+        idempotency_key: Option<[u8; 16]>,
+        expected_config_hash: [u8; 32],
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::withdraw_from_vault(
+            ctx,
+            sequence,
+            assets,
+            purpose,
+            amount_usdt,
+            amount_hcoin,
+            amount_sol,
+            idempotency_key,
+            expected_config_hash,
+        )
+    }
+
+    /// Propose a timelocked withdrawal
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Same signers as a co-signed withdraw_from_vault
+    /// - Records recipient and amounts; executable after withdraw_delay_secs
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_withdrawal(
+        ctx: Context<ProposeWithdrawal>,
+        sequence: u64,
+        assets: WithdrawAssets,
+        purpose: WithdrawPurpose,
+        amount_usdt: Option<u64>,
+        amount_hcoin: Option<u64>,
+        amount_sol: Option<u64>,
+        expected_config_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::propose_withdrawal(
+            ctx,
+            sequence,
+            assets,
+            purpose,
+            amount_usdt,
+            amount_hcoin,
+            amount_sol,
+            expected_config_hash,
+        )
+    }
+
+    /// Execute a pending withdrawal
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless once the timelock has elapsed
+    /// - Re-checks configuration hash, sequence, recipient and balances
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        instructions::execute_withdrawal(ctx)
+    }
+
+    /// Cancel a pending withdrawal
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Any single whitelist member
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::cancel_withdrawal(ctx)
+    }
+
+    /// Sweep a token other than USDT / H2COIN out of the vault to the recovery wallet
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist
+    /// - Only pays InvestmentInfo.recovery_wallet; USDT / H2COIN and registered vault mints are rejected
+    pub fn sweep_foreign_token(ctx: Context<SweepForeignToken>, amount: Option<u64>) -> Result<()> {
+        instructions::sweep_foreign_token(ctx, amount)
+    }
+
+    /// Register an additional mint held by the vault
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Creates the vault ATA; registered mints cannot be swept as foreign
+    pub fn register_vault_mint(ctx: Context<RegisterVaultMint>) -> Result<()> {
+        instructions::register_vault_mint(ctx)
+    }
+
+    /// Deregister an additional vault mint
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - The vault ATA of the mint must be empty
+    pub fn deregister_vault_mint(ctx: Context<DeregisterVaultMint>) -> Result<()> {
+        instructions::deregister_vault_mint(ctx)
+    }
+
+    /// Emit an attested snapshot of the vault balances and unpaid cache obligations
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless; caches are supplied through remaining_accounts
+    /// - Optionally persists the result in the LatestVaultSnapshot PDA
+    pub fn snapshot_vault(ctx: Context<SnapshotVault>) -> Result<()> {
+        instructions::snapshot_vault(ctx)
+    }
+
+    /// Verify vault coverage invariants and emit a HealthReport
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless and read-only; caches and ledgers are supplied through remaining_accounts
+    /// - Failed checks are reported as flags, never as an instruction error
+    pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+        instructions::health_check(ctx)
+    }
+
+    /// Re-create a closed vault ATA and verify its state
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless; only the canonical USDT / H2COIN ATA of an active investment's vault
+    /// - An existing ATA is verified and guarded, never replaced
+    pub fn repair_vault_ata(ctx: Context<RepairVaultAta>) -> Result<()> {
+        instructions::repair_vault_ata(ctx)
+    }
+
+    //================ DISTRIBUTION SCHEDULE ================
+    // AUDIT: These functions manage the on-chain distribution calendar
+    // SECURITY: Slot registration requires committee multisig
+
+    /// Schedule a distribution round
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from update_whitelist
+    /// - Adds a planned profit round or refund year with a target date
+    /// 
+    /// SECURITY CHECKS:
+    /// - Multisig validation (3-of-5)
+    /// - Round uniqueness and year range validation
+    pub fn schedule_distribution(
+        ctx: Context<ScheduleDistribution>,
+        kind: DistributionKind,
+        batch_id: u16,
+        year_index: u8,
+        target_at: i64,
+    ) -> Result<()> {
+        instructions::schedule_distribution(ctx, kind, batch_id, year_index, target_at)
+    }
+
+    //================ PROGRAM CONFIGURATION ================
+    // AUDIT: These functions manage the program-wide configuration
+    // SECURITY: Emergency stop across all investments
+
+    /// Initialize the program config
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Creates the singleton ProgramConfig PDA
+    /// - Signer must be the program upgrade authority, or 3-of-5 of the upgrade
+    ///   committee approve it once the governance PDA holds the upgrade authority
+    pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>) -> Result<()> {
+        instructions::initialize_program_config(ctx)
+    }
+
+    /// Toggle the program-wide pause
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Blocks execution and withdrawal instructions across all investments
+    /// - Signer must be the config authority
+    pub fn set_program_pause(ctx: Context<SetProgramPause>, paused: bool) -> Result<()> {
+        instructions::set_program_pause(ctx, paused)
+    }
+
+    /// Publish the program limits
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Creates the singleton LimitsConfig PDA from the compiled constants
+    /// - Signer must be the config authority
+    pub fn initialize_limits_config(ctx: Context<InitializeLimitsConfig>) -> Result<()> {
+        instructions::initialize_limits_config(ctx)
+    }
+
+    /// Override published operating limits
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Overrides may only tighten the compiled constants
+    /// - Signer must be the config authority
+    pub fn set_limits_config(
+        ctx: Context<SetLimitsConfig>,
+        max_entries_per_batch: Option<u16>,
+        share_cache_expire_secs: Option<i64>,
+        max_upgrade_window_secs: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_limits_config(
+            ctx,
+            max_entries_per_batch,
+            share_cache_expire_secs,
+            max_upgrade_window_secs,
+        )
+    }
+
+    /// Create the global parameters
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Creates the singleton GlobalConfig PDA from the compiled constants
+    /// - Signer must be the config authority; committee of exactly 5 members
+    pub fn initialize_global_config(
+        ctx: Context<InitializeGlobalConfig>,
+        committee: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize_global_config(ctx, committee)
+    }
+
+    /// Change the global parameters (mints, SOL fee estimates, cache expiry, claim relayer)
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 GlobalConfig committee signatures
+    /// - The resulting parameter set is validated as a whole
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_global_config(
+        ctx: Context<SetGlobalConfig>,
+        usdt_mint: Option<Pubkey>,
+        hcoin_mint: Option<Pubkey>,
+        allowed_profit_mints: Option<Vec<Pubkey>>,
+        estimate_sol_base: Option<u64>,
+        estimate_sol_per_entry: Option<u64>,
+        share_cache_expire_secs: Option<i64>,
+        claim_relayer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_global_config(
+            ctx,
+            usdt_mint,
+            hcoin_mint,
+            allowed_profit_mints,
+            estimate_sol_base,
+            estimate_sol_per_entry,
+            share_cache_expire_secs,
+            claim_relayer,
+        )
+    }
+
+    /// Validate a deployment and emit a readiness report
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Read-only and permissionless; used as a release gate
+    pub fn bootstrap_check(ctx: Context<BootstrapCheck>) -> Result<()> {
+        instructions::bootstrap_check(ctx)
+    }
+
+    //================ UPGRADE GOVERNANCE ================
+    // AUDIT: These functions put program upgrades under committee control
+    // SECURITY: Upgrade authority is held by a PDA; every action needs 3-of-5
+
+    /// Hand the upgrade authority to the governance PDA
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signer must be the current program upgrade authority
+    /// - Registers the 5-member upgrade committee
+    pub fn initialize_upgrade_governance(
+        ctx: Context<InitializeUpgradeGovernance>,
+        committee: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize_upgrade_governance(ctx, committee)
+    }
+
+    /// Hand the upgrade authority to a new authority
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from the upgrade committee
+    /// - Governance can no longer upgrade afterwards
+    pub fn set_upgrade_authority(ctx: Context<ManageUpgradeAuthority>) -> Result<()> {
+        instructions::set_upgrade_authority(ctx)
+    }
+
+    /// Renounce the upgrade authority
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from the upgrade committee
+    /// - Irreversible: the program becomes immutable
+    pub fn renounce_upgrade_authority(ctx: Context<ManageUpgradeAuthority>) -> Result<()> {
+        instructions::renounce_upgrade_authority(ctx)
+    }
+
+    /// Approve a buffer and upgrade window
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from the upgrade committee
+    /// - Window bounded by MAX_UPGRADE_WINDOW_SECS
+    pub fn schedule_upgrade_window(
+        ctx: Context<ScheduleUpgradeWindow>,
+        buffer: Pubkey,
+        window_start: i64,
+        window_end: i64,
+    ) -> Result<()> {
+        instructions::schedule_upgrade_window(ctx, buffer, window_start, window_end)
+    }
+
+    /// Deploy the approved buffer
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Executor must be a committee member
+    /// - Only the scheduled buffer, only inside its window
+    pub fn execute_program_upgrade(ctx: Context<ExecuteProgramUpgrade>) -> Result<()> {
+        instructions::execute_program_upgrade(ctx)
+    }
+
+    //================ OPERATING ALLOWANCE ================
+    // AUDIT: These functions manage standing operating-expense withdrawals
+    // SECURITY: Committee sets the cap once; claims cannot exceed it per period
+
+    /// Set the standing operating allowance
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist
+    /// - Recipient must be on withdraw_whitelist; amount 0 disables
+    pub fn set_operating_allowance(
+        ctx: Context<SetOperatingAllowance>,
+        recipient: Pubkey,
+        amount: u64,
+        period_secs: i64,
+    ) -> Result<()> {
+        instructions::set_operating_allowance(ctx, recipient, amount, period_secs)
+    }
+
+    /// Claim the operating allowance
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless; pays only the allowance recipient
+    /// - At most the allowance amount per period
+    pub fn claim_operating_allowance(
+        ctx: Context<ClaimOperatingAllowance>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::claim_operating_allowance(ctx, amount)
+    }
+
+    //================ DEPOSIT RETURN ================
+    // AUDIT: These functions return recorded USDT when a raise is cancelled
+    // SECURITY: Only for cancelled investments; each record is returned once
+
+    /// Return recorded USDT deposits of a cancelled investment
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist
+    /// - Each record is paid its amount_usdt once
+    pub fn return_deposits<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ReturnDeposits<'info>>,
+        batch_id: u16,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::return_deposits(ctx, batch_id)
+    }
+
+    //================ ANNUAL STATEMENT ================
+    // AUDIT: These functions build per-investor yearly statements from chain state
+    // SECURITY: Read-only over records and caches; writes only the statement PDA
+
+    /// Generate an investor's annual statement
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signer in execute_whitelist or update_whitelist
+    /// - Rebuilt from the supplied records and settled caches
+    pub fn generate_annual_statement<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, GenerateAnnualStatement<'info>>,
+        account_id: [u8; 15],
+        year: u16,
     ) -> Result<()>
     where
         'c: 'info,
     {
-        instructions::withdraw_from_vault(ctx)
+        instructions::generate_annual_statement(ctx, account_id, year)
+    }
+
+    //================ NOTIFICATION REGISTRY ================
+    // AUDIT: These functions manage investor opt-in notification registrations
+    // SECURITY: Only the wallet itself can create, update or close its registration
+
+    /// Register or update the wallet's notification channel
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signed by the wallet; contact must not be empty
+    pub fn register_notification(
+        ctx: Context<RegisterNotification>,
+        channel: NotificationChannel,
+        contact: [u8; 32],
+    ) -> Result<()> {
+        instructions::register_notification(ctx, channel, contact)
+    }
+
+    /// Remove the wallet's notification registration
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signed by the wallet; rent returned to it
+    pub fn unregister_notification(ctx: Context<UnregisterNotification>) -> Result<()> {
+        instructions::unregister_notification(ctx)
+    }
+
+    //================ PROFIT ACCRUAL ================
+    // AUDIT: Quarterly accounting writes decoupled from token transfers
+    // SECURITY: Accruals require execute multisig; settlements only pay recorded wallets
+
+    /// Credit a quarter's profit share to a batch's accrual ledger
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Requires 3-of-5 multisig from execute_whitelist
+    /// - No tokens move; each quarter can be credited once
+    pub fn accrue_profit<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, AccrueProfit<'info>>,
+        batch_id: u16,
+        quarter: u16,
+        total_profit_usdt: u64,
+        total_invest_usdt: u64,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::accrue_profit(ctx, batch_id, quarter, total_profit_usdt, total_invest_usdt)
+    }
+
+    /// Transfer an investor's accrued balances
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Permissionless (investor or crank)
+    /// - Pays only the wallets recorded in the ledger
+    pub fn settle_accrual<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SettleAccrual<'info>>,
+        batch_id: u16,
+        account_id: [u8; 15],
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::settle_accrual(ctx, batch_id, account_id)
+    }
+    //================ TENANT MANAGEMENT ================
+    // AUDIT: Multi-tenant platform defaults inherited by new investments
+    // SECURITY: Tenants are created by the config authority and run by their own admin
+
+    /// Create a tenant with its admin and defaults
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signer must be the config authority
+    /// - Defaults are validated before they can be inherited
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_tenant(
+        ctx: Context<InitializeTenant>,
+        tenant_id: [u8; 15],
+        admin: Pubkey,
+        fee_bp: u16,
+        fee_recipient: Pubkey,
+        execute_whitelist: Vec<Pubkey>,
+        update_whitelist: Vec<Pubkey>,
+        withdraw_whitelist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize_tenant(
+            ctx,
+            tenant_id,
+            admin,
+            fee_bp,
+            fee_recipient,
+            execute_whitelist,
+            update_whitelist,
+            withdraw_whitelist,
+        )
+    }
+
+    /// Change a tenant's admin or defaults
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signer must be the tenant admin
+    /// - Only future investments inherit the new defaults
+    pub fn update_tenant(
+        ctx: Context<UpdateTenant>,
+        new_admin: Option<Pubkey>,
+        fee_bp: Option<u16>,
+        fee_recipient: Option<Pubkey>,
+        execute_whitelist: Option<Vec<Pubkey>>,
+        update_whitelist: Option<Vec<Pubkey>>,
+        withdraw_whitelist: Option<Vec<Pubkey>>,
+    ) -> Result<()> {
+        instructions::update_tenant(
+            ctx,
+            new_admin,
+            fee_bp,
+            fee_recipient,
+            execute_whitelist,
+            update_whitelist,
+            withdraw_whitelist,
+        )
+    }
+    //================ EXCHANGE WALLET SCREENING ================
+    // AUDIT: Known exchange deposit wallets screened at record creation and wallet updates
+    // SECURITY: Maintained by the config authority only
+
+    /// Replace the exchange registry entries
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Signer must be the config authority
+    /// - Matching record wallets are rejected unless explicitly allowed
+    pub fn set_exchange_registry(ctx: Context<SetExchangeRegistry>, owners: Vec<Pubkey>) -> Result<()> {
+        instructions::set_exchange_registry(ctx, owners)
+    }
+    //================ MULTISIG PROPOSALS ================
+    // AUDIT: Asynchronous approval of update_investment_info and withdraw_from_vault
+    // SECURITY: The target instruction executes the proposal and re-checks its approvals
+
+    /// Create a proposal for an update or withdrawal
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Proposer must be on the approving whitelist and counts as first approval
+    /// - Expires after PROPOSAL_TTL_SECS
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        proposal_id: u64,
+        action: ProposalAction,
+    ) -> Result<()> {
+        instructions::create_proposal(ctx, proposal_id, action)
+    }
+
+    /// Approve a proposal
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Approver must be on the approving whitelist
+    /// - Each member approves once
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal(ctx)
     }
 }
\ No newline at end of file
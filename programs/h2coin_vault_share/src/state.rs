@@ -9,7 +9,8 @@
 // Account sizes are carefully calculated to prevent overflow and ensure efficiency.
 //
 // CRITICAL STRUCTURES:
-// - InvestmentInfo: Main investment configuration
+// - InvestmentInfo: Main investment account (hot path)
+// - InvestmentConfig: Whitelists and stage ratios (cold path)
 // - InvestmentRecord: Individual investment records
 // - ProfitShareCache: Profit distribution calculations
 // - RefundShareCache: Refund distribution calculations
@@ -28,20 +29,19 @@ use core::{convert::TryFrom, result::Result as StdResult};
 use crate::constants::*;
 use crate::error::ErrorCode;
 
-/// Main investment configuration account
+/// Main investment account (hot)
 /// 
 /// AUDIT CRITICAL:
-/// - Contains all investment parameters and configuration
-/// - Manages whitelists for different operation types
-/// - Controls investment state transitions
-/// - Stores vault PDA reference
+/// - Contains lifecycle state, limits and the vault reference
+/// - Read by every instruction, so kept small to cut deserialization cost
+/// - Whitelists and stage ratios live in the companion InvestmentConfig account
+/// - Stores canonical bumps so contexts skip find_program_address
 /// - Central control point for all investment operations
 /// 
 /// SECURITY FEATURES:
 /// - Fixed account size prevents overflow
 /// - PDA-based address derivation
 /// - State validation prevents invalid transitions
-/// - Whitelist validation for access control
 /// - Comprehensive input validation
 /// - State consistency enforcement
 #[account]
@@ -62,11 +62,6 @@ pub struct InvestmentInfo {
     /// SECURITY: Controls access to profit distribution features
     pub investment_type: InvestmentType,
     
-    /// Refund percentage ratios for each stage and year
-    /// AUDIT: 3 stages × 10 years = 30 values, each 0-100%
-    /// SECURITY: Must be validated to prevent mathematical errors
-    pub stage_ratio: [[u8; 10]; MAX_STAGE],
-    
     /// Investment start timestamp
     /// AUDIT: Used for timing validation
     /// SECURITY: Prevents premature operations
@@ -82,23 +77,8 @@ pub struct InvestmentInfo {
     /// SECURITY: Controls fund exposure and risk management
     pub investment_upper_limit: u64,
     
-    /// Whitelist for profit/refund execution operations
-    /// AUDIT: Exactly 5 members for 3-of-5 multisig
-    /// SECURITY: Controls access to critical financial operations
-    pub execute_whitelist: Vec<Pubkey>,
-    
-    /// Whitelist for investment info update operations
-    /// AUDIT: Exactly 5 members for 3-of-5 multisig
-    /// SECURITY: Controls access to configuration changes
-    pub update_whitelist: Vec<Pubkey>,
-    
-    /// Whitelist for vault withdrawal operations
-    /// AUDIT: Exactly 5 members for 3-of-5 multisig
-    /// SECURITY: Controls access to fund withdrawals
-    pub withdraw_whitelist: Vec<Pubkey>,
-    
     /// Vault PDA address for fund storage
-    /// AUDIT: Derived from investment_id and version
+    /// AUDIT: Derived from investment_id and vault_version
     /// SECURITY: Prevents vault spoofing and ensures proper fund storage
     pub vault: Pubkey,
     
@@ -116,10 +96,119 @@ pub struct InvestmentInfo {
     /// AUDIT: Used for audit trail
     /// SECURITY: Provides temporal context for operations
     pub created_at: i64,
+
+    /// Canonical bump of this InvestmentInfo PDA
+    /// AUDIT: Recorded at initialization
+    pub bump: u8,
+
+    /// Canonical bump of the vault PDA
+    /// AUDIT: Recorded at initialization
+    pub vault_bump: u8,
+
+    /// Canonical bump of the companion InvestmentConfig PDA
+    /// AUDIT: Recorded at initialization
+    pub config_bump: u8,
+
+    /// Sequence number expected by the next withdraw_from_vault
+    /// AUDIT: Incremented on every withdrawal
+    /// SECURITY: A signed withdraw transaction cannot be replayed later
+    pub withdraw_sequence: u64,
+
+    /// Version whose vault PDA this investment uses
+    /// AUDIT: Equals version unless linked to an earlier version's vault
+    /// SECURITY: Every vault seed derivation uses this field
+    pub vault_version: [u8; 4],
+
+    /// Recently consumed client idempotency keys (all-zero = empty slot)
+    /// AUDIT: Written by whitelist patches, record wallet updates and withdrawals
+    /// SECURITY: Retries after an ambiguous RPC failure cannot apply twice
+    pub recent_keys: [[u8; 16]; RECENT_IDEMPOTENCY_KEYS],
+
+    /// Next recent_keys slot to overwrite
+    /// AUDIT: Wraps at RECENT_IDEMPOTENCY_KEYS
+    pub recent_key_cursor: u8,
+
+    /// SHA-256 snapshot of the critical configuration
+    /// AUDIT: InvestmentConfig::snapshot_hash, refreshed on every configuration change
+    /// SECURITY: Execution and withdrawal signers pass the hash they approved
+    pub config_hash: [u8; 32],
+
+    /// Paused instruction families (PAUSE_* bits)
+    /// AUDIT: Set by the update committee through set_instruction_pause
+    /// SECURITY: Checked in addition to the program-wide pause
+    pub paused_families: u8,
+
+    /// Emergency freeze timestamp (0 = not frozen)
+    /// AUDIT: Set by any single execute / update member, cleared by 3-of-5 update_whitelist
+    /// SECURITY: Blocks FREEZE_FAMILIES while non-zero
+    pub frozen_at: i64,
+
+    /// Reentrancy flag of the fund-moving instructions
+    /// AUDIT: Persisted as true at entry and cleared at exit; a failed transaction reverts it
+    /// SECURITY: A nested call into any FREEZE_FAMILIES instruction sees true and is rejected
+    pub execution_in_progress: bool,
+
+    /// Amount-based signer tiers of withdraw_from_vault (required_signers == 0 = empty slot)
+    /// AUDIT: Set by the update committee through set_withdraw_tiers
+    /// SECURITY: All slots empty keeps the Withdrawer role rule unchanged
+    pub withdraw_tiers: [WithdrawTier; MAX_WITHDRAW_TIERS],
+
+    /// Timelock between propose_withdrawal and execute_withdrawal (0 = timelock off)
+    /// AUDIT: Set by the update committee through set_withdraw_delay
+    /// SECURITY: Once set, withdraw_from_vault is rejected and the delay cannot be removed
+    pub withdraw_delay_secs: u32,
+
+    /// Four-eyes rule of record edits (payer must not be an approving signer)
+    /// AUDIT: Set by the update committee through set_record_four_eyes
+    /// SECURITY: Applies to update_investment_record_wallets and revoked_investment_record
+    pub record_four_eyes: bool,
+
+    /// Wallet receiving foreign SPL tokens swept out of the vault (default = unset)
+    /// AUDIT: Set by the update committee through set_recovery_wallet
+    /// SECURITY: sweep_foreign_token can only pay this wallet
+    pub recovery_wallet: Pubkey,
+
+    /// Whether this version's vault is now operated by a later version
+    /// AUDIT: Set on the source version by link_shared_vault
+    /// SECURITY: close_vault refuses a handed-off vault so it cannot close a vault still in use
+    pub vault_handed_off: bool,
+
+    /// Profit multipliers per stage in basis points (all zero = stage weighting off)
+    /// AUDIT: Set by the update committee through set_profit_stage_multipliers
+    /// SECURITY: Weights amount_usdt in profit estimation; refunds are unaffected
+    pub profit_stage_multipliers_bp: [u16; MAX_STAGE],
+
+    /// Cumulative amount_usdt of every record created for this investment, per stage
+    /// (index 0 = stages outside 1..=MAX_STAGE)
+    /// AUDIT: Incremented by add_investment_record / backfill_investment_record; revocation keeps it
+    /// SECURITY: Its sum (total_invested_usdt) never exceeds investment_upper_limit
+    pub invested_usdt: [u64; MAX_STAGE + 1],
+
+    /// Stablecoin mint in which profits are recorded and paid (USDT, USDC, ...)
+    /// AUDIT: Fixed at initialize_investment_info from GlobalConfig.allowed_profit_mints
+    /// SECURITY: Every per-investment "USDT" mint check compares against this field
+    pub profit_mint: Pubkey,
+
+    /// Decimals of profit_mint
+    /// AUDIT: Recorded at initialize_investment_info
+    /// SECURITY: Profit shares are normalized to it; payouts require a mint with these decimals
+    pub profit_decimals: u8,
+
+    /// Decimals of the H2COIN mint
+    /// AUDIT: Recorded at initialize_investment_info
+    /// SECURITY: Refund shares are normalized to it; payouts require a mint with these decimals
+    pub hcoin_decimals: u8,
+
+    /// Part of invested_usdt outside the profit denominator, per stage: unacknowledged
+    /// provisional records and revocations whose hold was released
+    /// AUDIT: Maintained by record creation, acknowledge_record and release_revocation_hold
+    /// SECURITY: invested_usdt - inactive_invested_usdt is the denominator of estimate_profit_share;
+    /// no caller-supplied total is trusted
+    pub inactive_invested_usdt: [u64; MAX_STAGE + 1],
 }
 
 impl InvestmentInfo {
-    /// Total account size: 772 bytes
+    /// Total account size: 459 bytes
     /// 
     /// AUDIT CRITICAL:
     /// - Fixed size prevents account overflow
@@ -132,33 +221,440 @@ impl InvestmentInfo {
     /// - 15 bytes: investment_id
     /// - 4 bytes: version
     /// - 1 byte: investment_type (enum)
-    /// - 30 bytes: stage_ratio (3×10)
     /// - 8 bytes: start_at
     /// - 8 bytes: end_at
     /// - 8 bytes: investment_upper_limit
-    /// - 164 bytes: execute_whitelist (4 + 5×32)
-    /// - 164 bytes: update_whitelist (4 + 5×32)
-    /// - 164 bytes: withdraw_whitelist (4 + 5×32)
     /// - 32 bytes: vault
     /// - 2 bytes: state (repr(u16))
     /// - 1 byte: is_active
     /// - 8 bytes: created_at
+    /// - 3 bytes: bump, vault_bump, config_bump
+    /// - 8 bytes: withdraw_sequence
+    /// - 4 bytes: vault_version
+    /// - 128 bytes: recent_keys (8 × 16)
+    /// - 1 byte: recent_key_cursor
+    /// - 32 bytes: config_hash
+    /// - 1 byte: paused_families
+    /// - 8 bytes: frozen_at
+    /// - 1 byte: execution_in_progress
+    /// - 36 bytes: withdraw_tiers (4 × 9)
+    /// - 4 bytes: withdraw_delay_secs
+    /// - 1 byte: record_four_eyes
+    /// - 32 bytes: recovery_wallet
+    /// - 1 byte: vault_handed_off
+    /// - 6 bytes: profit_stage_multipliers_bp (3 × 2)
+    /// - 32 bytes: invested_usdt (4 × 8)
+    /// - 32 bytes: profit_mint
+    /// - 2 bytes: profit_decimals, hcoin_decimals
+    /// - 32 bytes: inactive_invested_usdt (4 × 8)
     pub const SIZE: usize =
         8 +  // discriminator
         15 + // investment_id
         4 +  // version
         1 +  // investment_type (enum InvestmentType)
-        30 + // stage_ratio
         8 +  // start_at
         8 +  // end_at
         8 +  // investment_upper_limit
-        4 + (MAX_WHITELIST_LEN * 32) + // execute_whitelist
-        4 + (MAX_WHITELIST_LEN * 32) + // update_whitelist
-        4 + (MAX_WHITELIST_LEN * 32) + // withdraw_whitelist
         32 + // vault
         2 +  // state (as repr(u16))
         1 +  // is_active
-        8;   // created_at
+        8 +  // created_at
+        3 +  // bump + vault_bump + config_bump
+        8 +  // withdraw_sequence
+        4 +  // vault_version
+        16 * RECENT_IDEMPOTENCY_KEYS + // recent_keys
+        1 +  // recent_key_cursor
+        32 + // config_hash
+        1 +  // paused_families
+        8 +  // frozen_at
+        1 +  // execution_in_progress
+        WithdrawTier::SIZE * MAX_WITHDRAW_TIERS + // withdraw_tiers
+        4 +  // withdraw_delay_secs
+        1 +  // record_four_eyes
+        32 + // recovery_wallet
+        1 +  // vault_handed_off
+        2 * MAX_STAGE + // profit_stage_multipliers_bp
+        8 * (MAX_STAGE + 1) + // invested_usdt
+        32 + // profit_mint
+        2 +  // profit_decimals + hcoin_decimals
+        8 * (MAX_STAGE + 1); // inactive_invested_usdt
+
+    /// Recompute config_hash after a configuration change
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Must be called by every instruction that writes InvestmentConfig or the upper limit
+    pub fn refresh_config_hash(&mut self, config: &InvestmentConfig) -> Result<()> {
+        self.config_hash = config.snapshot_hash(self.investment_upper_limit)?;
+        Ok(())
+    }
+
+    /// Require the caller-approved configuration snapshot to be the current one
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Binds execution and withdrawal signatures to an exact configuration
+    pub fn require_config_hash(&self, expected_config_hash: &[u8; 32]) -> Result<()> {
+        require!(
+            self.config_hash == *expected_config_hash,
+            ErrorCode::ConfigHashMismatch
+        );
+        Ok(())
+    }
+
+    /// Require an instruction family not to be paused for this investment
+    /// 
+    /// AUDIT: `family` is one of the PAUSE_* bits; FREEZE_FAMILIES also stop while frozen
+    pub fn require_family_active(&self, family: u8) -> Result<()> {
+        require!(self.paused_families & family == 0, ErrorCode::InstructionFamilyPaused);
+        if family & FREEZE_FAMILIES != 0 {
+            require!(self.frozen_at == 0, ErrorCode::InvestmentFrozen);
+        }
+        Ok(())
+    }
+
+    /// Record a client idempotency key, rejecting one already in the ring
+    /// 
+    /// AUDIT CRITICAL:
+    /// - `None` and the all-zero key skip the check
+    /// - Call after authorization so unsigned callers cannot fill the ring
+    pub fn consume_idempotency_key(&mut self, key: Option<[u8; 16]>) -> Result<()> {
+        let Some(key) = key.filter(|key| *key != [0u8; 16]) else {
+            return Ok(());
+        };
+        require!(!self.recent_keys.contains(&key), ErrorCode::IdempotencyKeyReused);
+
+        let slot = self.recent_key_cursor as usize % RECENT_IDEMPOTENCY_KEYS;
+        self.recent_keys[slot] = key;
+        self.recent_key_cursor = ((slot + 1) % RECENT_IDEMPOTENCY_KEYS) as u8;
+        Ok(())
+    }
+
+    /// Enforce the four-eyes rule of record edits when enabled
+    /// 
+    /// AUDIT CRITICAL:
+    /// - The account preparing and paying for the transaction must not also approve it
+    /// - No-op while record_four_eyes is off
+    pub fn require_four_eyes(&self, payer: &Pubkey, signer_keys: &[Pubkey]) -> Result<()> {
+        if self.record_four_eyes {
+            require!(!signer_keys.contains(payer), ErrorCode::PayerIsApprover);
+        }
+        Ok(())
+    }
+
+    /// Stage-weighted USDT amount of a record's profit share
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Returns (weighted_amount_usdt, multiplier_bp)
+    /// - While profit_stage_multipliers_bp is all zero, the amount is returned unchanged
+    ///   with PROFIT_STAGE_MULTIPLIER_ONE_BP
+    /// - Stages outside 1..=MAX_STAGE are weighted 1×
+    pub fn weighted_profit_usdt(&self, stage: u8, amount_usdt: u64) -> Result<(u64, u16)> {
+        let multiplier_bp = if self.profit_stage_multipliers_bp == [0u16; MAX_STAGE] {
+            PROFIT_STAGE_MULTIPLIER_ONE_BP
+        } else {
+            (stage as usize)
+                .checked_sub(1)
+                .and_then(|i| self.profit_stage_multipliers_bp.get(i).copied())
+                .unwrap_or(PROFIT_STAGE_MULTIPLIER_ONE_BP)
+        };
+
+        let weighted = (amount_usdt as u128)
+            .checked_mul(multiplier_bp as u128)
+            .map(|x| x / PROFIT_STAGE_MULTIPLIER_ONE_BP as u128)
+            .and_then(|x| u64::try_from(x).ok())
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        Ok((weighted, multiplier_bp))
+    }
+
+    /// Convert an AMOUNT_DECIMALS profit amount to profit_mint base units
+    /// 
+    /// AUDIT: Identity for 6-decimal stablecoins (USDT, USDC)
+    pub fn profit_units(&self, amount: u64) -> Result<u64> {
+        normalize_amount(amount, AMOUNT_DECIMALS, self.profit_decimals)
+    }
+
+    /// Convert an AMOUNT_DECIMALS refund amount to H2COIN base units
+    /// 
+    /// AUDIT: Identity for the 6-decimal H2COIN mint
+    pub fn hcoin_units(&self, amount: u64) -> Result<u64> {
+        normalize_amount(amount, AMOUNT_DECIMALS, self.hcoin_decimals)
+    }
+
+    /// Cumulative amount_usdt of every record (sum of invested_usdt)
+    /// 
+    /// AUDIT: Bounded by investment_upper_limit through register_investment
+    pub fn total_invested_usdt(&self) -> Result<u64> {
+        self.invested_usdt
+            .iter()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or(error!(ErrorCode::NumericalOverflow))
+    }
+
+    /// Count a new record's USDT against investment_upper_limit
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Fails without changing the total when the limit would be exceeded
+    /// - The record joins the profit denominator unless excluded with deactivate_investment
+    pub fn register_investment(&mut self, stage: u8, amount_usdt: u64) -> Result<()> {
+        let total = self
+            .total_invested_usdt()?
+            .checked_add(amount_usdt)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        require!(
+            total <= self.investment_upper_limit,
+            ErrorCode::InvestmentUpperLimitExceeded
+        );
+        let bucket = &mut self.invested_usdt[Self::active_stage_index(stage)];
+        *bucket = bucket.checked_add(amount_usdt).ok_or(ErrorCode::NumericalOverflow)?;
+        Ok(())
+    }
+
+    /// Stage bucket of invested_usdt / inactive_invested_usdt
+    /// 
+    /// AUDIT: Stages outside 1..=MAX_STAGE share bucket 0, which is weighted 1×
+    fn active_stage_index(stage: u8) -> usize {
+        match stage as usize {
+            index @ 1..=MAX_STAGE => index,
+            _ => 0,
+        }
+    }
+
+    /// Return an acknowledged record to the profit denominator
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Call exactly once, when a provisional record is acknowledged
+    /// - Underflow means the aggregate drifted from the records and fails loudly
+    pub fn activate_investment(&mut self, stage: u8, amount_usdt: u64) -> Result<()> {
+        let bucket = &mut self.inactive_invested_usdt[Self::active_stage_index(stage)];
+        *bucket = bucket.checked_sub(amount_usdt).ok_or(ErrorCode::NumericalOverflow)?;
+        Ok(())
+    }
+
+    /// Take a registered record out of the profit denominator
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Call exactly once per record leaving the denominator: a provisional record at
+    ///   creation, a revocation when its hold is released
+    /// - The inactive part never exceeds the invested amount of its stage
+    pub fn deactivate_investment(&mut self, stage: u8, amount_usdt: u64) -> Result<()> {
+        let index = Self::active_stage_index(stage);
+        let inactive = self.inactive_invested_usdt[index]
+            .checked_add(amount_usdt)
+            .filter(|inactive| *inactive <= self.invested_usdt[index])
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        self.inactive_invested_usdt[index] = inactive;
+        Ok(())
+    }
+
+    /// Stage-weighted total of invested_usdt - inactive_invested_usdt
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Acknowledged records stay in it while revoked until their hold is released, so
+    ///   every batch estimated meanwhile shares one investment-wide denominator
+    /// - Weighted with the multipliers in force now, like each record at estimation
+    /// - Weighting a bucket's sum floors once, so the total is never below the
+    ///   sum of the per-record weighted amounts
+    pub fn active_profit_denominator(&self) -> Result<u64> {
+        self.invested_usdt
+            .iter()
+            .zip(self.inactive_invested_usdt.iter())
+            .enumerate()
+            .try_fold(0u64, |total, (index, (invested, inactive))| {
+                let active = invested.checked_sub(*inactive).ok_or(ErrorCode::NumericalOverflow)?;
+                let (weighted, _) = self.weighted_profit_usdt(index as u8, active)?;
+                total.checked_add(weighted).ok_or(error!(ErrorCode::NumericalOverflow))
+            })
+    }
+
+    /// Distinct Withdrawer signers a withdrawal needs under withdraw_tiers
+    /// 
+    /// AUDIT CRITICAL:
+    /// - None while no tier is set; the Withdrawer role rule then applies unchanged
+    /// - The first tier whose max_amount_usdt covers `usdt_amount` sets the count;
+    ///   above the highest tier all MAX_WHITELIST_LEN members must sign
+    /// - H2COIN has no on-chain USDT price, so a withdrawal moving any H2COIN never
+    ///   needs fewer than MULTISIG_THRESHOLD signers
+    pub fn required_withdraw_signers(&self, usdt_amount: u64, hcoin_amount: u64) -> Option<u8> {
+        let mut tiers = self.withdraw_tiers.iter().filter(|tier| tier.is_set()).peekable();
+        tiers.peek()?;
+
+        let required = tiers
+            .find(|tier| usdt_amount <= tier.max_amount_usdt)
+            .map_or(MAX_WHITELIST_LEN as u8, |tier| tier.required_signers);
+
+        if hcoin_amount > 0 {
+            Some(required.max(MULTISIG_THRESHOLD as u8))
+        } else {
+            Some(required)
+        }
+    }
+}
+
+/// Investment configuration account (cold)
+/// 
+/// AUDIT CRITICAL:
+/// - Holds whitelists, stage ratios and the H2COIN rate range
+/// - Companion of InvestmentInfo, derived from the same investment_id and version
+/// - Only loaded by instructions that check authorization or compute refunds
+/// 
+/// SECURITY FEATURES:
+/// - Fixed account size prevents overflow
+/// - PDA-based address derivation
+/// - Whitelist validation for access control
+/// - Multisig validation is enforced here
+#[account]
+#[derive()]
+pub struct InvestmentConfig {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Matches the companion InvestmentInfo, used for PDA derivation
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Matches the companion InvestmentInfo, used for PDA derivation
+    pub version: [u8; 4],
+
+    /// Refund percentage ratios for each stage and year
+    /// AUDIT: 3 stages × 10 years = 30 values, each 0-100%
+    /// SECURITY: Must be validated to prevent mathematical errors
+    pub stage_ratio: [[u8; 10]; MAX_STAGE],
+
+    /// Whitelist for profit/refund execution operations
+    /// AUDIT: Exactly 5 members for 3-of-5 multisig
+    /// SECURITY: Controls access to critical financial operations
+    pub execute_whitelist: Vec<Pubkey>,
+    
+    /// Whitelist for investment info update operations
+    /// AUDIT: Exactly 5 members for 3-of-5 multisig
+    /// SECURITY: Controls access to configuration changes
+    pub update_whitelist: Vec<Pubkey>,
+    
+    /// Whitelist for vault withdrawal operations
+    /// AUDIT: Exactly 5 members for 3-of-5 multisig
+    /// SECURITY: Controls access to fund withdrawals
+    pub withdraw_whitelist: Vec<Pubkey>,
+
+    /// Accepted H2COIN-per-USDT conversion rate range for new records
+    /// AUDIT: Checked against amount_usdt / amount_hcoin in add_investment_record
+    /// SECURITY: Catches data-entry mismatches before they skew refunds
+    pub hcoin_rate_range: HcoinRateRange,
+
+    /// Signer weights for the execute_whitelist
+    /// AUDIT: Index-aligned with execute_whitelist; disabled means plain 3-of-5
+    /// SECURITY: Threshold is always above any single member's weight
+    pub execute_weights: SignerWeights,
+
+    /// Signer weights for the update_whitelist
+    /// AUDIT: Index-aligned with update_whitelist; disabled means plain 3-of-5
+    /// SECURITY: Threshold is always above any single member's weight
+    pub update_weights: SignerWeights,
+
+    /// Minimum seconds between estimates of the same profit / refund cache
+    /// AUDIT: Checked against the cache's created_at on re-estimation
+    /// SECURITY: Prevents rapid cache thrashing between review and execution
+    pub min_estimate_interval_secs: u32,
+
+    /// Stage ratio scheduled to take effect from stage_ratio_effective_year
+    /// AUDIT: Applies to refund years >= stage_ratio_effective_year only
+    /// SECURITY: Earlier refund years keep the terms in force at the time
+    pub scheduled_stage_ratio: [[u8; 10]; MAX_STAGE],
+
+    /// First refund year_index governed by scheduled_stage_ratio
+    /// AUDIT: 0 means no schedule is stored
+    pub stage_ratio_effective_year: u8,
+
+    /// Tenant the investment was created under
+    /// AUDIT: Pubkey::default() for investments created without a tenant
+    pub tenant: Pubkey,
+
+    /// Platform fee in basis points inherited from the tenant
+    /// AUDIT: Recorded for tenant billing; distributions do not deduct it
+    pub fee_bp: u16,
+
+    /// Platform fee recipient inherited from the tenant
+    pub fee_recipient: Pubkey,
+
+    /// Squads v4 vault PDA accepted in place of a whitelist quorum
+    /// AUDIT: Pubkey::default() disables the Squads authorization mode
+    /// SECURITY: Only ever derived from the Squads program id, never set directly
+    pub squads_authority: Pubkey,
+
+    /// Labels of the execute_whitelist members (e.g. "CFO")
+    /// AUDIT: Index-aligned with execute_whitelist; all-zero means unlabelled
+    /// SECURITY: Display metadata only, never consulted for authorization
+    pub execute_labels: [[u8; WHITELIST_LABEL_LEN]; MAX_WHITELIST_LEN],
+
+    /// Labels of the update_whitelist members
+    /// AUDIT: Index-aligned with update_whitelist; all-zero means unlabelled
+    /// SECURITY: Display metadata only, never consulted for authorization
+    pub update_labels: [[u8; WHITELIST_LABEL_LEN]; MAX_WHITELIST_LEN],
+
+    /// Labels of the withdraw_whitelist members
+    /// AUDIT: Index-aligned with withdraw_whitelist; all-zero means unlabelled
+    /// SECURITY: Display metadata only, never consulted for authorization
+    pub withdraw_labels: [[u8; WHITELIST_LABEL_LEN]; MAX_WHITELIST_LEN],
+}
+
+impl InvestmentConfig {
+    /// SHA-256 over the serialized configuration and the investment upper limit
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Covers whitelists and their labels, signer weights, the Squads authority, stage ratios
+    ///   (current and scheduled), the H2COIN rate range, the estimate interval
+    ///   and the upper limit
+    /// - Any field change yields a different hash
+    pub fn snapshot_hash(&self, investment_upper_limit: u64) -> Result<[u8; 32]> {
+        let mut data = Vec::with_capacity(Self::SIZE + 8);
+        self.serialize(&mut data)?;
+        data.extend_from_slice(&investment_upper_limit.to_le_bytes());
+        Ok(anchor_lang::solana_program::hash::hash(&data).to_bytes())
+    }
+
+    /// Total account size: 950 bytes
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Fixed size prevents account overflow
+    /// - Must match actual data structure size
+    /// - Used for account initialization
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 30 bytes: stage_ratio (3×10)
+    /// - 164 bytes: execute_whitelist (4 + 5×32)
+    /// - 164 bytes: update_whitelist (4 + 5×32)
+    /// - 164 bytes: withdraw_whitelist (4 + 5×32)
+    /// - 16 bytes: hcoin_rate_range (min + max)
+    /// - 12 bytes: execute_weights + update_weights (2 × (5 + 1))
+    /// - 4 bytes: min_estimate_interval_secs
+    /// - 30 bytes: scheduled_stage_ratio (3×10)
+    /// - 1 byte: stage_ratio_effective_year
+    /// - 32 bytes: tenant
+    /// - 2 bytes: fee_bp
+    /// - 32 bytes: fee_recipient
+    /// - 32 bytes: squads_authority
+    /// - 240 bytes: execute_labels + update_labels + withdraw_labels (3 × 5×16)
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        30 + // stage_ratio
+        4 + (MAX_WHITELIST_LEN * 32) + // execute_whitelist
+        4 + (MAX_WHITELIST_LEN * 32) + // update_whitelist
+        4 + (MAX_WHITELIST_LEN * 32) + // withdraw_whitelist
+        16 + // hcoin_rate_range
+        (MAX_WHITELIST_LEN + 1) + // execute_weights
+        (MAX_WHITELIST_LEN + 1) + // update_weights
+        4 +  // min_estimate_interval_secs
+        30 + // scheduled_stage_ratio
+        1 +  // stage_ratio_effective_year
+        32 + // tenant
+        2 +  // fee_bp
+        32 + // fee_recipient
+        32 + // squads_authority
+        (MAX_WHITELIST_LEN * WHITELIST_LABEL_LEN) + // execute_labels
+        (MAX_WHITELIST_LEN * WHITELIST_LABEL_LEN) + // update_labels
+        (MAX_WHITELIST_LEN * WHITELIST_LABEL_LEN);  // withdraw_labels
 
     /// Validate stage ratio configuration
     /// 
@@ -181,19 +677,26 @@ impl InvestmentInfo {
     /// - Prevents mathematical overflow in calculations
     /// - Maintains business logic integrity
     pub fn validate_stage_ratio(&self) -> Result<()> {
+        Self::check_stage_ratio(&self.stage_ratio)
+    }
+
+    /// Validate a stage ratio table that is not (yet) stored on the config
+    /// 
+    /// AUDIT: Same rules as validate_stage_ratio; used to vet proposed ratios
+    pub fn check_stage_ratio(stage_ratio: &[[u8; 10]; 3]) -> Result<()> {
         let mut any_nonzero = false;
 
-        for stage in 0..MAX_STAGE {
+        for stage in stage_ratio.iter().take(MAX_STAGE) {
             let mut sum = 0u32;
             let mut started = false;
 
             // Ensure each stage has exactly MAX_YEAR_INDEX + 1 entries
             require!(
-                self.stage_ratio[stage].len() == (MAX_YEAR_INDEX as usize) + 1,
+                stage.len() == (MAX_YEAR_INDEX as usize) + 1,
                 ErrorCode::InvalidStageRatioLength
             );
 
-            for (i, &val) in self.stage_ratio[stage].iter().enumerate() {
+            for (i, &val) in stage.iter().enumerate() {
                 // Validate individual percentage values
                 require!(val <= 100, ErrorCode::InvalidStageRatioValue);
 
@@ -206,7 +709,7 @@ impl InvestmentInfo {
                 if started && val == 0 && i < 9 {
                     // Once started, must not have trailing zero before end
                     require!(
-                        self.stage_ratio[stage][i + 1..].iter().all(|&v| v == 0),
+                        stage[i + 1..].iter().all(|&v| v == 0),
                         ErrorCode::NonContiguousStage
                     );
                     break;
@@ -224,7 +727,56 @@ impl InvestmentInfo {
         Ok(())
     }
 
-    /// Verify that at least 3-of-5 signers match the whitelist
+    /// Stage ratio table in force for a refund year
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Years before stage_ratio_effective_year use stage_ratio
+    /// - Years from stage_ratio_effective_year on use scheduled_stage_ratio
+    /// - Every refund computation (estimation and reconciliation) goes through here
+    pub fn stage_ratio_for_year(&self, year_index: u8) -> &[[u8; 10]; MAX_STAGE] {
+        if self.stage_ratio_effective_year != 0 && year_index >= self.stage_ratio_effective_year {
+            &self.scheduled_stage_ratio
+        } else {
+            &self.stage_ratio
+        }
+    }
+
+    /// Schedule a stage ratio that takes effect from a future refund year
+    /// 
+    /// AUDIT CRITICAL:
+    /// - effective_year must be after current_year and within MAX_YEAR_INDEX
+    /// - A schedule that is still pending is simply replaced
+    /// - A schedule already in force is first folded into stage_ratio, column by
+    ///   column, so past refund years keep the percentages they were computed with
+    /// 
+    /// SECURITY IMPLICATIONS:
+    /// - Ratio changes can never rewrite the terms of refund years already open
+    pub fn schedule_stage_ratio(
+        &mut self,
+        stage_ratio: [[u8; 10]; MAX_STAGE],
+        effective_year: u8,
+        current_year: u8,
+    ) -> Result<()> {
+        require!(
+            effective_year > current_year && effective_year <= MAX_YEAR_INDEX,
+            ErrorCode::InvalidStageRatioEffectiveYear
+        );
+        Self::check_stage_ratio(&stage_ratio)?;
+
+        // AUDIT: Fold a schedule already in force so its years stay unchanged
+        let in_force = self.stage_ratio_effective_year;
+        if in_force != 0 && in_force <= current_year {
+            for (base, scheduled) in self.stage_ratio.iter_mut().zip(self.scheduled_stage_ratio.iter()) {
+                base[in_force as usize..].copy_from_slice(&scheduled[in_force as usize..]);
+            }
+        }
+
+        self.scheduled_stage_ratio = stage_ratio;
+        self.stage_ratio_effective_year = effective_year;
+        Ok(())
+    }
+
+    /// Verify that the signers satisfy the whitelist's multisig rule
     /// 
     /// AUDIT CRITICAL:
     /// - Core multisig validation logic
@@ -234,38 +786,62 @@ impl InvestmentInfo {
     /// 
     /// SECURITY CHECKS:
     /// - Whitelist must have exactly 5 members
-    /// - At least 3 signers must be in whitelist
+    /// - Weighted: summed weight of distinct signing members must reach the threshold
+    /// - Unweighted: at least 3 distinct whitelist members must have signed
+    /// - Squads mode: a signature of squads_authority stands in for the quorum
     /// - Different whitelists for different operation types
     /// - Prevents single point of failure
     /// - Ensures proper authorization
-    pub fn verify_signers_3_of_5(&self, signer_keys: &[Pubkey], is_update: bool) -> Result<()> {
-        let whitelist = if is_update {
-            &self.update_whitelist
+    pub fn verify_signers(&self, signer_keys: &[Pubkey], is_update: bool) -> Result<()> {
+        let (whitelist, weights) = if is_update {
+            (&self.update_whitelist, &self.update_weights)
         } else {
-            &self.execute_whitelist
+            (&self.execute_whitelist, &self.execute_weights)
         };
 
+        // AUDIT: The Squads vault PDA can only sign through the Squads program,
+        // which invokes us only after its own member threshold approved the transaction
+        if self.is_squads_signer(signer_keys) {
+            return Ok(());
+        }
+
         // Enforce exactly 5 members during execution
         require!(
             whitelist.len() == MAX_WHITELIST_LEN,
             ErrorCode::WhitelistMustBeFive
         );
 
-        // Count matching signers
-        let match_count = signer_keys
+        // Weighted rule: each member counts once, with its configured weight
+        if weights.is_enabled() {
+            let signed_weight: u16 = whitelist
+                .iter()
+                .zip(weights.weights.iter())
+                .filter(|(member, _)| signer_keys.contains(member))
+                .map(|(_, &weight)| weight as u16)
+                .sum();
+
+            require!(
+                signed_weight >= weights.threshold as u16,
+                ErrorCode::UnauthorizedSigner
+            );
+            return Ok(());
+        }
+
+        // Count distinct whitelist members among the signers; an account passed twice counts once
+        let match_count = whitelist
             .iter()
-            .filter(|key| whitelist.contains(key))
+            .filter(|member| signer_keys.contains(member))
             .count();
 
         // Require at least 3-of-5 signatures
-        require!(match_count >= 3, ErrorCode::UnauthorizedSigner);
+        require!(match_count >= MULTISIG_THRESHOLD, ErrorCode::UnauthorizedSigner);
         Ok(())
     }
 
-    /// Enforce 3-of-5 multisig validation using AccountInfo
+    /// Enforce multisig validation using AccountInfo
     /// 
     /// AUDIT CRITICAL:
-    /// - Wrapper for verify_signers_3_of_5 with AccountInfo
+    /// - Wrapper for verify_signers with AccountInfo
     /// - Extracts signer keys from AccountInfo objects
     /// - Used in instruction contexts
     /// - Provides convenient interface for validation
@@ -275,7 +851,7 @@ impl InvestmentInfo {
     /// - Validates against appropriate whitelist
     /// - Prevents unauthorized operations
     /// - Ensures proper multisig enforcement
-    pub fn enforce_3_of_5_signers<'info>(
+    pub fn enforce_signers<'info>(
         &self,
         signer_infos: &[AccountInfo<'info>],
         is_update: bool,
@@ -286,27 +862,656 @@ impl InvestmentInfo {
             .map(|info| *info.key)
             .collect();
 
-        self.verify_signers_3_of_5(&signer_keys, is_update)
+        self.verify_signers(&signer_keys, is_update)
     }
-}
 
-/// Investment type enumeration
-/// 
-/// AUDIT CRITICAL:
-/// - Controls profit sharing eligibility
-/// - Affects available operations
-/// - Used for business logic validation
-/// 
-/// SECURITY:
-/// - Prevents unauthorized profit sharing
-/// - Controls feature access based on investment type
-/// - Ensures proper business logic enforcement
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
-pub enum InvestmentType {
+    /// Whether the configured Squads vault is among the signers
+    /// 
+    /// AUDIT: Always false while squads_authority is Pubkey::default()
+    pub fn is_squads_signer(&self, signer_keys: &[Pubkey]) -> bool {
+        self.squads_authority != Pubkey::default() && signer_keys.contains(&self.squads_authority)
+    }
+
+    /// Squads v4 vault PDA of a multisig
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Seeds ["multisig", multisig, "vault", vault_index] under SQUADS_PROGRAM_ID
+    /// - A PDA has no private key, so only the Squads program can sign for it
+    pub fn squads_vault(multisig: &Pubkey, vault_index: u8) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"multisig", multisig.as_ref(), b"vault", &[vault_index]],
+            &SQUADS_PROGRAM_ID,
+        )
+        .0
+    }
+
+    /// Whether `key` belongs to the execute or update whitelist
+    /// 
+    /// AUDIT: Single-member authority used only by freeze_investment, which can
+    /// block funds but never move them
+    pub fn is_execute_or_update_member(&self, key: &Pubkey) -> bool {
+        self.execute_whitelist.contains(key) || self.update_whitelist.contains(key)
+    }
+
+    /// Enforce an RBAC role on the given signer accounts
+    /// 
+    /// AUDIT CRITICAL:
+    /// - A role assigned in RoleConfig is checked against its own members and threshold
+    /// - An unassigned role (or no RoleConfig) falls back to Role::legacy_rule, the
+    ///   whitelist check the instruction used before roles existed
+    /// - The Squads authority satisfies every role
+    pub fn enforce_role<'info>(
+        &self,
+        role_config: Option<&RoleConfig>,
+        role: Role,
+        signer_infos: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let signer_keys: Vec<Pubkey> = signer_infos
+            .iter()
+            .filter(|info| info.is_signer)
+            .map(|info| *info.key)
+            .collect();
+
+        if self.is_squads_signer(&signer_keys) {
+            return Ok(());
+        }
+
+        if let Some(assignment) = role_config.and_then(|config| config.assignment(role)) {
+            require!(assignment.is_satisfied_by(&signer_keys), ErrorCode::UnauthorizedSigner);
+            return Ok(());
+        }
+
+        match role {
+            Role::Estimator => {
+                // AUDIT: Any single member of the execute or update whitelist
+                require!(
+                    signer_keys.iter().any(|key| self.is_execute_or_update_member(key)),
+                    ErrorCode::UnauthorizedSigner
+                );
+                Ok(())
+            }
+            Role::Executor | Role::Withdrawer => self.verify_signers(&signer_keys, false),
+            Role::RecordAdmin | Role::Pauser => self.verify_signers(&signer_keys, true),
+        }
+    }
+
+    /// Enforce a withdrawal tier on the given signer keys
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Counts distinct members of the Withdrawer set: the assigned role's members,
+    ///   else the execute_whitelist
+    /// - `required` is capped at the size of that set, so "every member" stays reachable
+    /// - Signer weights do not apply; a tier is a plain member count
+    /// - The Squads authority satisfies every tier
+    pub fn enforce_withdraw_tier(
+        &self,
+        role_config: Option<&RoleConfig>,
+        required: u8,
+        signer_keys: &[Pubkey],
+    ) -> Result<()> {
+        if self.is_squads_signer(signer_keys) {
+            return Ok(());
+        }
+
+        let members: &[Pubkey] = match role_config.and_then(|config| config.assignment(Role::Withdrawer)) {
+            Some(assignment) => &assignment.members[..assignment.member_count as usize],
+            None => {
+                require!(
+                    self.execute_whitelist.len() == MAX_WHITELIST_LEN,
+                    ErrorCode::WhitelistMustBeFive
+                );
+                &self.execute_whitelist
+            }
+        };
+
+        let matched = members
+            .iter()
+            .filter(|member| signer_keys.contains(member))
+            .count();
+        require!(
+            matched >= (required as usize).min(members.len()),
+            ErrorCode::UnauthorizedSigner
+        );
+        Ok(())
+    }
+
+    /// Count the distinct members of a role's whitelist among the given signers
+    /// 
+    /// AUDIT: Read-only; used by prevalidate_signers and never as an authorization check
+    pub fn count_whitelisted_signers(&self, signer_keys: &[Pubkey], role: SignerRole) -> u8 {
+        let whitelist = match role {
+            SignerRole::Execute => &self.execute_whitelist,
+            SignerRole::Update => &self.update_whitelist,
+            SignerRole::Withdraw => &self.withdraw_whitelist,
+        };
+
+        whitelist
+            .iter()
+            .filter(|member| signer_keys.contains(member))
+            .count() as u8
+    }
+
+    /// Label of a whitelist member, or all zeros for a non-member
+    /// 
+    /// AUDIT: Read-only; used to annotate whitelist events
+    pub fn label_of(&self, role: SignerRole, member: &Pubkey) -> [u8; WHITELIST_LABEL_LEN] {
+        let (whitelist, labels) = match role {
+            SignerRole::Execute => (&self.execute_whitelist, &self.execute_labels),
+            SignerRole::Update => (&self.update_whitelist, &self.update_labels),
+            SignerRole::Withdraw => (&self.withdraw_whitelist, &self.withdraw_labels),
+        };
+
+        whitelist
+            .iter()
+            .position(|key| key == member)
+            .map(|index| labels[index])
+            .unwrap_or_default()
+    }
+
+    /// Labels of a role's whitelist, one per member in whitelist order
+    pub fn labels(&self, role: SignerRole) -> Vec<[u8; WHITELIST_LABEL_LEN]> {
+        let (len, labels) = match role {
+            SignerRole::Execute => (self.execute_whitelist.len(), &self.execute_labels),
+            SignerRole::Update => (self.update_whitelist.len(), &self.update_labels),
+            SignerRole::Withdraw => (self.withdraw_whitelist.len(), &self.withdraw_labels),
+        };
+        labels[..len.min(MAX_WHITELIST_LEN)].to_vec()
+    }
+}
+
+/// Per-member signer weights for one whitelist
+/// 
+/// AUDIT CRITICAL:
+/// - weights[i] belongs to whitelist[i]; a patched member inherits its slot's weight
+/// - threshold == 0 disables weighting and restores the 3-of-5 count
+/// 
+/// SECURITY:
+/// - No single member can reach the threshold alone
+/// - The whole whitelist must be able to reach the threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SignerWeights {
+    pub weights: [u8; MAX_WHITELIST_LEN],
+    pub threshold: u8,
+}
+
+impl SignerWeights {
+    /// Whether weighted signing is configured
+    pub fn is_enabled(&self) -> bool {
+        self.threshold != 0
+    }
+
+    /// Validate the weights against the threshold
+    /// 
+    /// AUDIT: A disabled configuration must carry no weights
+    pub fn validate(&self) -> Result<()> {
+        if !self.is_enabled() {
+            require!(self.weights.iter().all(|&w| w == 0), ErrorCode::InvalidSignerWeights);
+            return Ok(());
+        }
+
+        let total: u16 = self.weights.iter().map(|&w| w as u16).sum();
+        let max = self.weights.iter().copied().max().unwrap_or(0);
+        require!(
+            total >= self.threshold as u16 && max < self.threshold,
+            ErrorCode::InvalidSignerWeights
+        );
+        Ok(())
+    }
+}
+
+/// Investment type enumeration
+/// 
+/// AUDIT CRITICAL:
+/// - Controls profit sharing eligibility
+/// - Affects available operations
+/// - Used for business logic validation
+/// 
+/// SECURITY:
+/// - Prevents unauthorized profit sharing
+/// - Controls feature access based on investment type
+/// - Ensures proper business logic enforcement
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum InvestmentType {
     Standard, // Eligible for profit sharing
     Csr,      // Not eligible for profit sharing
 }
 
+/// Operator reason code for record revocations and wallet updates
+/// 
+/// AUDIT CRITICAL:
+/// - Carried in InvestmentRecordRevoked / InvestmentRecordWalletUpdated events
+/// - Lets audits distinguish why a record was changed
+/// 
+/// SECURITY:
+/// - Informational only; does not affect authorization or payouts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RecordChangeReason {
+    Other,           // Not covered by the codes below
+    InvestorRequest, // Requested by the investor
+    KycFailure,      // Investor failed or lapsed KYC
+    Fraud,           // Suspected or confirmed fraud
+    DataCorrection,  // Operator data-entry correction
+    LostWallet,      // Investor lost access to the previous wallet
+}
+
+/// Assets swept by withdraw_from_vault
+/// 
+/// AUDIT CRITICAL:
+/// - Carried in VaultTransferred so the audit trail shows the intended scope
+/// - Unselected assets stay in the vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WithdrawAssets {
+    All,   // SOL (above the reserve), USDT and H2COIN
+    Sol,   // SOL only
+    Usdt,  // USDT only
+    Hcoin, // H2COIN only
+}
+
+impl WithdrawAssets {
+    /// Whether SOL is withdrawn
+    pub fn includes_sol(self) -> bool {
+        matches!(self, WithdrawAssets::All | WithdrawAssets::Sol)
+    }
+
+    /// Whether USDT is withdrawn
+    pub fn includes_usdt(self) -> bool {
+        matches!(self, WithdrawAssets::All | WithdrawAssets::Usdt)
+    }
+
+    /// Whether H2COIN is withdrawn
+    pub fn includes_hcoin(self) -> bool {
+        matches!(self, WithdrawAssets::All | WithdrawAssets::Hcoin)
+    }
+}
+
+/// Purpose code of a vault withdrawal
+/// 
+/// AUDIT CRITICAL:
+/// - Chosen by the signers and carried in VaultTransferred, WithdrawalProposed and Proposal
+/// - Selects the WithdrawalBudget line the withdrawal is counted against;
+///   claim_operating_allowance always counts as OpEx
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WithdrawPurpose {
+    OpEx,           // Operating expenses
+    InvestorPayout, // Funds paid out to investors outside the share instructions
+    Treasury,       // Transfer to the company treasury
+    Emergency,      // Emergency evacuation of the vault
+}
+
+impl WithdrawPurpose {
+    /// Number of purposes; one WithdrawalBudget line each
+    pub const COUNT: usize = 4;
+
+    /// Index of the purpose's WithdrawalBudget line
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Optional per-asset caps of one withdrawal purpose
+/// 
+/// AUDIT CRITICAL:
+/// - None leaves the asset uncapped; Some(cap) bounds the cumulative amount withdrawn
+/// - Set by the update committee through set_withdraw_budget
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct BudgetCap {
+    /// Cumulative USDT cap (base units)
+    pub usdt: Option<u64>,
+
+    /// Cumulative H2COIN cap (base units)
+    pub hcoin: Option<u64>,
+
+    /// Cumulative SOL cap (lamports)
+    pub sol: Option<u64>,
+}
+
+impl BudgetCap {
+    /// Serialized size: 27 bytes
+    pub const SIZE: usize = 3 * 9;
+}
+
+/// Cumulative totals and caps of one withdrawal purpose
+/// 
+/// AUDIT: Totals only grow; they count every withdrawal paid out under the purpose
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct BudgetLine {
+    /// USDT withdrawn so far (base units)
+    pub withdrawn_usdt: u64,
+
+    /// H2COIN withdrawn so far (base units)
+    pub withdrawn_hcoin: u64,
+
+    /// SOL withdrawn so far (lamports)
+    pub withdrawn_sol: u64,
+
+    /// Committee-approved caps
+    pub cap: BudgetCap,
+}
+
+impl BudgetLine {
+    /// Serialized size: 51 bytes
+    pub const SIZE: usize = 3 * 8 + BudgetCap::SIZE;
+
+    /// Totals after adding a withdrawal, checked against the caps
+    /// 
+    /// AUDIT: Fails with WithdrawBudgetExceeded when any capped total would pass its cap
+    pub fn totals_after(&self, usdt_amount: u64, hcoin_amount: u64, sol_amount: u64) -> Result<(u64, u64, u64)> {
+        let usdt = self.withdrawn_usdt.checked_add(usdt_amount).ok_or(ErrorCode::NumericalOverflow)?;
+        let hcoin = self.withdrawn_hcoin.checked_add(hcoin_amount).ok_or(ErrorCode::NumericalOverflow)?;
+        let sol = self.withdrawn_sol.checked_add(sol_amount).ok_or(ErrorCode::NumericalOverflow)?;
+        for (total, cap) in [(usdt, self.cap.usdt), (hcoin, self.cap.hcoin), (sol, self.cap.sol)] {
+            if let Some(cap) = cap {
+                require!(total <= cap, ErrorCode::WithdrawBudgetExceeded);
+            }
+        }
+        Ok((usdt, hcoin, sol))
+    }
+}
+
+/// Signer tier of withdraw_from_vault
+/// 
+/// AUDIT CRITICAL:
+/// - Applies to withdrawals of at most max_amount_usdt USDT (base units)
+/// - required_signers == 0 marks an empty slot
+/// 
+/// SECURITY:
+/// - Set tiers ascend strictly by amount and never by fewer signers, so a larger
+///   withdrawal can never need less authorization than a smaller one
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct WithdrawTier {
+    /// Largest USDT amount covered by this tier
+    pub max_amount_usdt: u64,
+
+    /// Distinct Withdrawer members that must sign
+    pub required_signers: u8,
+}
+
+impl WithdrawTier {
+    /// Serialized size: 9 bytes
+    pub const SIZE: usize = 8 + 1;
+
+    /// Whether the slot holds a tier
+    pub fn is_set(&self) -> bool {
+        self.required_signers != 0
+    }
+
+    /// Validate a tier list and pack it into the fixed InvestmentInfo slots
+    /// 
+    /// AUDIT: At most MAX_WITHDRAW_TIERS tiers, 1 <= required_signers <= MAX_WHITELIST_LEN,
+    /// max_amount_usdt strictly ascending and required_signers non-decreasing;
+    /// an empty list clears every tier
+    pub fn pack(tiers: &[WithdrawTier]) -> Result<[WithdrawTier; MAX_WITHDRAW_TIERS]> {
+        require!(tiers.len() <= MAX_WITHDRAW_TIERS, ErrorCode::InvalidWithdrawTiers);
+
+        let mut packed = [WithdrawTier::default(); MAX_WITHDRAW_TIERS];
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(
+                tier.is_set() && tier.required_signers as usize <= MAX_WHITELIST_LEN,
+                ErrorCode::InvalidWithdrawTiers
+            );
+            if let Some(previous) = i.checked_sub(1).map(|j| &tiers[j]) {
+                require!(
+                    tier.max_amount_usdt > previous.max_amount_usdt
+                        && tier.required_signers >= previous.required_signers,
+                    ErrorCode::InvalidWithdrawTiers
+                );
+            }
+            packed[i] = *tier;
+        }
+        Ok(packed)
+    }
+}
+
+/// Whitelist selected by prevalidate_signers
+/// 
+/// AUDIT: Informational only; each protected instruction still picks its own whitelist
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SignerRole {
+    Execute,  // execute_whitelist
+    Update,   // update_whitelist
+    Withdraw, // withdraw_whitelist
+}
+
+/// Operational role of the RBAC layer
+/// 
+/// AUDIT CRITICAL:
+/// - Each role gates a fixed set of instructions; members and threshold live in RoleConfig
+/// - Until assigned, a role falls back to the whitelist rule noted on each variant
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Estimator,   // estimate_profit_share, estimate_refund_share; 1 of execute ∪ update
+    Executor,    // execute_profit_share, execute_refund_share; 3-of-5 execute_whitelist
+    RecordAdmin, // add / backfill / revoke records, update record wallets; 3-of-5 update_whitelist
+    Withdrawer,  // withdraw_from_vault (co-signed); 3-of-5 execute_whitelist
+    Pauser,      // set_instruction_pause; 3-of-5 update_whitelist
+}
+
+/// Members and threshold of one role
+/// 
+/// AUDIT: threshold == 0 means unassigned (legacy whitelist rule applies)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct RoleAssignment {
+    /// Role members; only the first member_count entries are meaningful
+    pub members: [Pubkey; MAX_WHITELIST_LEN],
+
+    /// Number of members
+    pub member_count: u8,
+
+    /// Distinct members that must sign
+    pub threshold: u8,
+}
+
+impl RoleAssignment {
+    /// Serialized size: 162 bytes
+    pub const SIZE: usize = 32 * MAX_WHITELIST_LEN + 1 + 1;
+
+    /// Build and validate an assignment
+    /// 
+    /// AUDIT: Members must be distinct, non-default and at most MAX_WHITELIST_LEN;
+    /// an empty list with threshold 0 clears the role
+    pub fn new(members: &[Pubkey], threshold: u8) -> Result<Self> {
+        let mut assignment = Self::default();
+        if members.is_empty() && threshold == 0 {
+            return Ok(assignment);
+        }
+
+        require!(
+            members.len() <= MAX_WHITELIST_LEN
+                && threshold >= 1
+                && threshold as usize <= members.len(),
+            ErrorCode::InvalidRoleAssignment
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..i].contains(member),
+                ErrorCode::InvalidRoleAssignment
+            );
+            assignment.members[i] = *member;
+        }
+        assignment.member_count = members.len() as u8;
+        assignment.threshold = threshold;
+        Ok(assignment)
+    }
+
+    /// Whether the role has been assigned
+    pub fn is_assigned(&self) -> bool {
+        self.threshold != 0
+    }
+
+    /// Whether enough distinct members are among the signers
+    pub fn is_satisfied_by(&self, signer_keys: &[Pubkey]) -> bool {
+        let matched = self.members[..self.member_count as usize]
+            .iter()
+            .filter(|member| signer_keys.contains(member))
+            .count();
+        matched >= self.threshold as usize
+    }
+}
+
+/// Action approved through a Proposal
+/// 
+/// AUDIT CRITICAL:
+/// - Holds the exact arguments of the target instruction; execution must repeat them
+/// - UpdateInvestmentInfo is approved by the update_whitelist, WithdrawFromVault by
+///   the execute_whitelist, the same whitelists that co-sign the instructions directly
+/// 
+/// SECURITY:
+/// - WithdrawFromVault pins the withdrawal sequence and configuration hash, so a
+///   proposal lapses when either changes before execution
+/// - WithdrawFromVault also pins the requested amounts; None means the full balance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalAction {
+    UpdateInvestmentInfo {
+        new_stage_ratio: Option<[[u8; 10]; 3]>,
+        new_upper_limit: Option<u64>,
+        new_hcoin_rate_range: Option<HcoinRateRange>,
+        new_min_estimate_interval_secs: Option<u32>,
+        new_stage_ratio_effective_year: Option<u8>,
+    },
+    WithdrawFromVault {
+        recipient: Pubkey,
+        assets: WithdrawAssets,
+        purpose: WithdrawPurpose,
+        amount_usdt: Option<u64>,
+        amount_hcoin: Option<u64>,
+        amount_sol: Option<u64>,
+        sequence: u64,
+        expected_config_hash: [u8; 32],
+    },
+}
+
+impl ProposalAction {
+    /// Serialized size of the largest variant plus the tag: 102 bytes
+    pub const SIZE: usize =
+        1 + // variant tag
+        32 + 1 + 1 + 3 * 9 + 8 + 32; // WithdrawFromVault (UpdateInvestmentInfo: 31 + 9 + 17 + 5 + 2 = 64)
+
+    /// Whether the action is approved by the update_whitelist
+    pub fn is_update(&self) -> bool {
+        matches!(self, ProposalAction::UpdateInvestmentInfo { .. })
+    }
+}
+
+/// UTC time window of one refund year_index
+/// 
+/// AUDIT CRITICAL:
+/// - year_index k covers [end_at + k × SECONDS_PER_YEAR, end_at + (k + 1) × SECONDS_PER_YEAR)
+/// - The window's start_at is the first moment refunds for year k may be estimated
+/// - Shared by estimate_refund_share, update_investment_info, report_revocation_impact
+///   and the year_index_window view, so clients and program agree on year boundaries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct YearWindow {
+    /// Refund year index
+    pub year_index: u8,
+
+    /// First second of the year (inclusive, UNIX timestamp)
+    pub start_at: i64,
+
+    /// First second of the next year (exclusive, UNIX timestamp)
+    pub end_at: i64,
+}
+
+impl YearWindow {
+    /// year_index in force at `unix_ts` for an investment that ended at `investment_end_at`
+    /// 
+    /// AUDIT: Timestamps before investment_end_at map to year 0; saturates at u8::MAX
+    pub fn index_at(investment_end_at: i64, unix_ts: i64) -> u8 {
+        let elapsed_secs = unix_ts.saturating_sub(investment_end_at).max(0);
+        (elapsed_secs / SECONDS_PER_YEAR).min(u8::MAX as i64) as u8
+    }
+
+    /// Window of `year_index` for an investment that ended at `investment_end_at`
+    pub fn of(investment_end_at: i64, year_index: u8) -> Self {
+        let start_at = investment_end_at.saturating_add(year_index as i64 * SECONDS_PER_YEAR);
+        Self {
+            year_index,
+            start_at,
+            end_at: start_at.saturating_add(SECONDS_PER_YEAR),
+        }
+    }
+
+    /// Window containing `unix_ts`
+    pub fn at(investment_end_at: i64, unix_ts: i64) -> Self {
+        Self::of(investment_end_at, Self::index_at(investment_end_at, unix_ts))
+    }
+}
+
+/// Accepted H2COIN-per-USDT conversion rate range
+/// 
+/// AUDIT CRITICAL:
+/// - Rates are scaled by RATE_SCALE: amount_hcoin * RATE_SCALE / amount_usdt
+/// - A record is accepted only when its implied rate lies within [min, max]
+/// - Both bounds at zero disables the check
+/// 
+/// SECURITY:
+/// - Catches swapped or mistyped amounts at record creation
+/// - Refunds are computed from amount_hcoin, so bad input would skew payouts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HcoinRateRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl HcoinRateRange {
+    /// Whether the range is configured
+    pub fn is_enabled(&self) -> bool {
+        self.min != 0 || self.max != 0
+    }
+
+    /// Validate the range bounds
+    /// 
+    /// AUDIT: min must not exceed max
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min <= self.max, ErrorCode::InvalidHcoinRateRange);
+        Ok(())
+    }
+
+    /// Check that the implied rate of a record lies inside the range
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Cross-multiplies in u128 to avoid division rounding and overflow
+    /// - A zero amount_usdt is only accepted together with a zero amount_hcoin
+    pub fn check_amounts(&self, amount_usdt: u64, amount_hcoin: u64) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let scaled_hcoin = (amount_hcoin as u128)
+            .checked_mul(RATE_SCALE as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let lower = (amount_usdt as u128)
+            .checked_mul(self.min as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let upper = (amount_usdt as u128)
+            .checked_mul(self.max as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        require!(
+            scaled_hcoin >= lower && scaled_hcoin <= upper,
+            ErrorCode::HcoinAmountOutOfRateRange
+        );
+        Ok(())
+    }
+}
+
+/// Distribution round explicitly waived at archival
+/// 
+/// AUDIT CRITICAL:
+/// - year_index None = the profit round of batch_id; Some(y) = refund year y of batch_id
+/// - Approved by the same 3-of-5 update_whitelist signatures as archive_investment and
+///   listed in InvestmentArchived, so every skipped round stays on record
+/// 
+/// SECURITY:
+/// - A round that is already settled cannot be waived
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SettlementWaiver {
+    /// Batch of the waived round
+    pub batch_id: u16,
+
+    /// Refund year of the waived round (None = profit round)
+    pub year_index: Option<u8>,
+}
+
 /// Investment state enumeration
 /// 
 /// AUDIT CRITICAL:
@@ -320,9 +1525,11 @@ pub enum InvestmentType {
 /// - Ensures proper state management
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum InvestmentState {
-    Init = 0,      // Initial state after creation
+    Init = 0,      // Created with defer_open; records not yet accepted
     Pending = 1,   // Active investment period
     Completed = 999, // Investment completed, ready for distributions
+    Cancelled = 500, // Raise failed before completion; deposits are returned
+    Archived = 1000, // Final settlement attested; terminal, only close_vault remains
 }
 
 impl InvestmentState {
@@ -354,6 +1561,8 @@ impl TryFrom<u16> for InvestmentState {
             0 => Ok(InvestmentState::Init),
             1 => Ok(InvestmentState::Pending),
             999 => Ok(InvestmentState::Completed),
+            500 => Ok(InvestmentState::Cancelled),
+            1000 => Ok(InvestmentState::Archived),
             _ => Err(()),
         }
     }
@@ -430,10 +1639,35 @@ pub struct InvestmentRecord {
     /// AUDIT: Used for audit trail
     /// SECURITY: Provides temporal context
     pub created_at: i64,
+
+    /// Revocation contest timestamp (0 if not contested)
+    /// AUDIT: Set by contest_revocation, cleared by resolve_revocation_contest
+    /// SECURITY: Keeps the record's share escrowed while the dispute is open
+    pub contested_at: i64,
+
+    /// Deposit return timestamp (0 if not returned)
+    /// AUDIT: Set by return_deposits on a cancelled investment
+    /// SECURITY: Prevents returning the same deposit twice
+    pub returned_at: i64,
+
+    /// Acknowledgment deadline of a provisional record (0 if not provisional)
+    /// AUDIT: created_at + RECORD_ACK_WINDOW_SECS
+    /// SECURITY: acknowledge_record is rejected after this time
+    pub ack_deadline: i64,
+
+    /// Investor acknowledgment timestamp (0 while provisional)
+    /// AUDIT: Equal to created_at for records added without the two-phase flow
+    /// SECURITY: Unacknowledged records never count toward distributions
+    pub acknowledged_at: i64,
+
+    /// Timestamp release_revocation_hold took the revoked record out of the profit denominator (0 if not)
+    /// AUDIT: Set once, after the hold and any contest are over
+    /// SECURITY: Prevents removing the same record from the denominator twice
+    pub hold_released_at: i64,
 }
 
 impl InvestmentRecord {
-    /// Total account size: 120 bytes
+    /// Total account size: 157 bytes
     /// 
     /// AUDIT CRITICAL:
     /// - Fixed size prevents account overflow
@@ -454,6 +1688,11 @@ impl InvestmentRecord {
     /// - 1 byte: stage
     /// - 8 bytes: revoked_at
     /// - 8 bytes: created_at
+    /// - 8 bytes: contested_at
+    /// - 8 bytes: returned_at
+    /// - 8 bytes: ack_deadline
+    /// - 8 bytes: acknowledged_at
+    /// - 8 bytes: hold_released_at
     pub const SIZE: usize =
         8 +  // discriminator
         2 +  // batch_id
@@ -466,11 +1705,51 @@ impl InvestmentRecord {
         8 +  // amount_hcoin
         1 +  // stage
         8 +  // revoked_at
-        8;   // created_at
-}
+        8 +  // created_at
+        8 +  // contested_at
+        8 +  // returned_at
+        8 +  // ack_deadline
+        8 +  // acknowledged_at
+        8;   // hold_released_at
 
-/// Profit share cache account for batch processing
-/// 
+    /// Whether the investor has acknowledged the record
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Provisional records stay out of every distribution until acknowledged
+    pub fn is_acknowledged(&self) -> bool {
+        self.acknowledged_at != 0
+    }
+
+    /// Whether a revoked record's share is still held in escrow
+    /// 
+    /// AUDIT CRITICAL:
+    /// - True during REVOCATION_HOLD_SECS after revocation
+    /// - True while a contest is open, regardless of elapsed time
+    /// - Always false for records that are not revoked
+    pub fn revocation_held(&self, now: i64) -> bool {
+        self.revoked_at != 0
+            && (self.contested_at != 0
+                || now < self.revoked_at.saturating_add(REVOCATION_HOLD_SECS))
+    }
+
+    /// Whether the record gets a cache entry at estimation time
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Held revocations are included (as escrowed entries)
+    /// - Released revocations, unbound wallets and unacknowledged records are excluded
+    pub fn is_distributable(&self, now: i64) -> bool {
+        if !self.is_acknowledged() {
+            return false;
+        }
+        if self.revocation_held(now) {
+            return true;
+        }
+        self.revoked_at == 0 && self.wallet != Pubkey::default()
+    }
+}
+
+/// Profit share cache account for batch processing
+/// 
 /// AUDIT CRITICAL:
 /// - Stores pre-calculated profit distribution data
 /// - Enables efficient batch execution
@@ -506,15 +1785,46 @@ pub struct ProfitShareCache {
     /// SECURITY: Prevents fund exhaustion
     pub subtotal_profit_usdt: u64,
     
+    /// Estimation input: profit distributed over the whole investment
+    /// AUDIT: Kept so entries can be re-derived by reconcile_batch
+    pub total_profit_usdt: u64,
+
+    /// Estimation input: total USDT invested across all batches
+    /// AUDIT: Kept so entries can be re-derived by reconcile_batch
+    pub total_invest_usdt: u64,
+
+    /// Mint this round is paid in (profit_mint, H2COIN or a registered vault mint)
+    /// AUDIT: Chosen at estimation; execution and requeue transfer only this mint
+    /// SECURITY: Amounts (`*_usdt` fields) are in this mint's base units
+    pub payout_mint: Pubkey,
+
+    /// Decimals of payout_mint at estimation
+    /// AUDIT: execute_profit_share rejects a mint whose decimals changed since
+    pub payout_decimals: u8,
+
     /// Estimated SOL cost for execution
     /// AUDIT: Used for gas cost estimation
     /// SECURITY: Ensures sufficient gas coverage
     pub subtotal_estimate_sol: u64,
+
+    /// Priority fee (micro-lamports per CU) included in subtotal_estimate_sol
+    /// AUDIT: Caller-provided at estimation; 0 when none was given
+    pub priority_fee_per_cu: u64,
     
     /// Execution timestamp (0 if not executed)
     /// AUDIT: Prevents double execution
     /// SECURITY: Ensures idempotency
     pub executed_at: i64,
+
+    /// Timestamp the executors opened the cache for claim_profit (0 = push only)
+    /// AUDIT: Set by open_profit_claims; reset by every re-estimation
+    /// SECURITY: Investors cannot pull funds from a cache the executors did not approve
+    pub claims_opened_at: i64,
+
+    /// Timestamp cancel_profit_cache voided this estimate (0 if not cancelled)
+    /// AUDIT: A cancelled cache has no entries and cannot be executed or opened for claims
+    /// SECURITY: Reset by the next estimation, which may run at once
+    pub cancelled_at: i64,
     
     /// Cache creation timestamp
     /// AUDIT: Used for expiration validation
@@ -540,6 +1850,11 @@ pub struct ProfitShareCache {
 /// - Prevents calculation errors
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ProfitEntry {
+    /// Position of this entry in the cache
+    /// AUDIT: Assigned at estimation in ascending record_id order; echoed by per-entry events
+    /// SECURITY: Lets off-chain reconciliation align entries, ATAs and outcomes
+    pub entry_index: u16,
+
     /// Account identifier (15 bytes)
     /// AUDIT: Links entry to specific account
     /// SECURITY: Ensures proper account association
@@ -554,19 +1869,91 @@ pub struct ProfitEntry {
     /// AUDIT: Calculated based on investment amount and profit ratio
     /// SECURITY: Determines actual transfer amount
     pub amount_usdt: u64,
+
     
     /// Profit ratio in basis points
     /// AUDIT: Used for calculation validation
     /// SECURITY: Ensures calculation accuracy
     pub ratio_bp: u16,
+
+    /// Stage profit multiplier applied to this entry, in basis points
+    /// AUDIT: PROFIT_STAGE_MULTIPLIER_ONE_BP when stage weighting was off at estimation
+    /// SECURITY: Pending shares aggregate only with entries of the same multiplier
+    pub multiplier_bp: u16,
+
+    /// Number of failed payout attempts for this entry
+    /// AUDIT: Failed entries are retried first on the next execution
+    /// SECURITY: Bounded by MAX_ENTRY_FAILURES before escrow
+    pub failure_count: u8,
+
+    /// Payout status of this entry
+    /// AUDIT: Only Pending entries are transferred during execution
+    /// SECURITY: Prevents double payment on retried executions
+    pub status: EntryStatus,
+
+    /// Record whose revocation hold escrowed this entry (None for every other entry)
+    /// AUDIT: Set at estimation; held shares are never aggregated, so one entry per record
+    /// SECURITY: resolve_revocation_contest releases the entry of exactly this record
+    pub held_record_id: Option<u64>,
+}
+
+/// Payout status of a single profit/refund cache entry
+/// 
+/// AUDIT CRITICAL:
+/// - Pending entries are (re)tried by execution
+/// - Paid entries are never transferred again
+/// - Escrowed entries exceeded MAX_ENTRY_FAILURES (or belong to a held revocation) and are
+///   held in the vault; release_escrowed_entry moves failed ones to Requeued
+/// - Requeued entries found the recipient ATA closed or were released from escrow;
+///   requeue_entry pays them once the ATA is live
+/// - Pending profit entries of a cache opened for claims may also be paid by claim_profit
+/// 
+/// SECURITY:
+/// - Per-entry status makes partial execution safe to resume
+/// - Prevents double-spending across retries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EntryStatus {
+    Pending,  // Awaiting transfer
+    Paid,     // Transferred to recipient ATA
+    Escrowed, // Held in vault after repeated failures
+    Requeued, // Recipient ATA closed; held in vault for requeue_entry
+}
+
+/// Failed payout reported in the return data of execute_profit_share / execute_refund_share
+/// 
+/// AUDIT:
+/// - failure_reason is the program error code (e.g. 6xxx of ErrorCode, or a custom code
+///   of another program); u32::MAX marks a runtime error without a numeric code
+/// - At most MAX_ENTRIES_PER_BATCH entries (4 + 30 × 6 bytes), well below the return data limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PayoutFailure {
+    /// entry_index of the cache entry that was not paid
+    pub entry_index: u16,
+
+    /// Error code of the failed payout
+    pub failure_reason: u32,
+}
+
+impl PayoutFailure {
+    /// Build the report of one failed payout
+    pub fn new(entry_index: u16, error: &Error) -> Self {
+        let failure_reason = match error {
+            Error::AnchorError(error) => error.error_code_number,
+            Error::ProgramError(error) => match error.program_error {
+                ProgramError::Custom(code) => code,
+                _ => u32::MAX,
+            },
+        };
+        Self { entry_index, failure_reason }
+    }
 }
 
 impl ProfitShareCache {
-    /// Size of a single profit entry: 57 bytes
+    /// Size of a single profit entry: 72 bytes
     /// 
     /// AUDIT: Used for size calculations
     /// SECURITY: Ensures proper memory allocation
-    pub const ENTRY_SIZE: usize = 15 + 32 + 8 + 2;
+    pub const ENTRY_SIZE: usize = 2 + 15 + 32 + 8 + 2 + 2 + 1 + 1 + 9;
 
     /// Total account size calculation
     /// 
@@ -582,8 +1969,15 @@ impl ProfitShareCache {
     /// - 15 bytes: investment_id
     /// - 4 bytes: version
     /// - 8 bytes: subtotal_profit_usdt
+    /// - 8 bytes: total_profit_usdt
+    /// - 8 bytes: total_invest_usdt
+    /// - 32 bytes: payout_mint
+    /// - 1 byte: payout_decimals
     /// - 8 bytes: subtotal_estimate_sol
+    /// - 8 bytes: priority_fee_per_cu
     /// - 8 bytes: executed_at
+    /// - 8 bytes: claims_opened_at
+    /// - 8 bytes: cancelled_at
     /// - 8 bytes: created_at
     /// - 4 bytes: entries vector length
     /// - N * ENTRY_SIZE: entries data
@@ -593,10 +1987,42 @@ impl ProfitShareCache {
         15 + // investment_id
         4 +  // version
         8 +  // subtotal_profit_usdt
+        8 +  // total_profit_usdt
+        8 +  // total_invest_usdt
+        32 + // payout_mint
+        1 +  // payout_decimals
         8 +  // subtotal_estimate_sol
+        8 +  // priority_fee_per_cu
         8 +  // executed_at
+        8 +  // claims_opened_at
+        8 +  // cancelled_at
         8 +  // created_at
         4 + (MAX_ENTRIES_PER_BATCH * Self::ENTRY_SIZE); // entries
+
+    /// Compute a record's profit share
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Single source of truth for estimate_profit_share and reconcile_batch
+    /// - amount_usdt is already stage-weighted (InvestmentInfo::weighted_profit_usdt)
+    /// - Returns (ratio_bp, amount_usdt)
+    /// - ratio_bp = amount_usdt × 10_000 / total_invest_usdt, truncated
+    pub fn compute_share(
+        amount_usdt: u64,
+        total_profit_usdt: u64,
+        total_invest_usdt: u64,
+    ) -> Result<(u16, u64)> {
+        // AUDIT: Calculate ratio with overflow protection
+        let ratio_bp = u16::try_from(
+            amount_usdt.saturating_mul(10_000) / total_invest_usdt
+        ).map_err(|_| ErrorCode::BpRatioOverflow)?;
+
+        // AUDIT: Calculate amount with overflow protection
+        let amount = total_profit_usdt
+            .saturating_mul(ratio_bp as u64)
+            / 10_000;
+
+        Ok((ratio_bp, amount))
+    }
 }
 
 /// Refund share cache account for batch processing
@@ -645,6 +2071,10 @@ pub struct RefundShareCache {
     /// AUDIT: Used for gas cost estimation
     /// SECURITY: Ensures sufficient gas coverage
     pub subtotal_estimate_sol: u64,
+
+    /// Priority fee (micro-lamports per CU) included in subtotal_estimate_sol
+    /// AUDIT: Caller-provided at estimation; 0 when none was given
+    pub priority_fee_per_cu: u64,
     
     /// Execution timestamp (0 if not executed)
     /// AUDIT: Prevents double execution
@@ -675,6 +2105,11 @@ pub struct RefundShareCache {
 /// - Prevents calculation errors
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct RefundEntry {
+    /// Position of this entry in the cache
+    /// AUDIT: Assigned at estimation in ascending record_id order; echoed by per-entry events
+    /// SECURITY: Lets off-chain reconciliation align entries, ATAs and outcomes
+    pub entry_index: u16,
+
     /// Account identifier (15 bytes)
     /// AUDIT: Links entry to specific account
     /// SECURITY: Ensures proper account association
@@ -689,19 +2124,102 @@ pub struct RefundEntry {
     /// AUDIT: Calculated based on investment amount and refund percentage
     /// SECURITY: Determines actual transfer amount
     pub amount_hcoin: u64,
+
     
     /// Investment stage (1, 2, or 3)
     /// AUDIT: Used for refund percentage calculation
     /// SECURITY: Ensures proper refund calculation
     pub stage: u8,
+
+    /// Number of failed payout attempts for this entry
+    /// AUDIT: Failed entries are retried first on the next execution
+    /// SECURITY: Bounded by MAX_ENTRY_FAILURES before escrow
+    pub failure_count: u8,
+
+    /// Payout status of this entry
+    /// AUDIT: Only Pending entries are transferred during execution
+    /// SECURITY: Prevents double payment on retried executions
+    pub status: EntryStatus,
+
+    /// Record whose revocation hold escrowed this entry (None for every other entry)
+    /// AUDIT: Set at estimation; held shares are never aggregated, so one entry per record
+    /// SECURITY: resolve_revocation_contest releases the entry of exactly this record
+    pub held_record_id: Option<u64>,
+}
+
+/// Re-assert the invariants shared by profit and refund caches
+/// 
+/// AUDIT CRITICAL:
+/// - Only compiled with the `debug-invariants` feature; mainnet builds carry no cost
+/// - Panics on violation so regressions fail test builds immediately
+/// 
+/// INVARIANTS:
+/// - Entry amounts sum to the cache subtotal
+/// - entry_index equals the entry's position
+/// - Pending entries never repeat an (account_id, wallet) pair; those are aggregated
+/// - An executed cache has no Pending entries
+#[cfg(feature = "debug-invariants")]
+fn assert_cache_invariants(
+    subtotal: u64,
+    executed_at: i64,
+    entries: &[(u16, [u8; 15], Pubkey, u64, EntryStatus)],
+) {
+    let sum = entries
+        .iter()
+        .try_fold(0u64, |acc, e| acc.checked_add(e.3))
+        .expect("invariant: entry sum overflowed");
+    assert_eq!(sum, subtotal, "invariant: entry sum differs from subtotal");
+
+    for (position, entry) in entries.iter().enumerate() {
+        assert_eq!(entry.0 as usize, position, "invariant: entry_index out of order");
+    }
+
+    let pending: Vec<_> = entries.iter().filter(|e| e.4 == EntryStatus::Pending).collect();
+    for (i, a) in pending.iter().enumerate() {
+        assert!(
+            pending[i + 1..].iter().all(|b| (b.1, b.2) != (a.1, a.2)),
+            "invariant: duplicate pending wallet entry"
+        );
+    }
+
+    assert!(
+        executed_at == 0 || pending.is_empty(),
+        "invariant: executed cache still has pending entries"
+    );
+}
+
+#[cfg(feature = "debug-invariants")]
+impl ProfitShareCache {
+    /// Re-assert cache invariants at the end of estimate/execute
+    pub fn assert_invariants(&self) {
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|e| (e.entry_index, e.account_id, e.wallet, e.amount_usdt, e.status))
+            .collect();
+        assert_cache_invariants(self.subtotal_profit_usdt, self.executed_at, &entries);
+    }
+}
+
+#[cfg(feature = "debug-invariants")]
+impl RefundShareCache {
+    /// Re-assert cache invariants at the end of estimate/execute
+    pub fn assert_invariants(&self) {
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|e| (e.entry_index, e.account_id, e.wallet, e.amount_hcoin, e.status))
+            .collect();
+        assert_cache_invariants(self.subtotal_refund_hcoin, self.executed_at, &entries);
+    }
 }
 
 impl RefundShareCache {
-    /// Size of a single refund entry: 56 bytes
+    /// Size of a single refund entry: 69 bytes
     /// 
     /// AUDIT: Used for size calculations
     /// SECURITY: Ensures proper memory allocation
-    pub const ENTRY_SIZE: usize = 15 + 32 + 8 + 1;
+    pub const ENTRY_SIZE: usize = 2 + 15 + 32 + 8 + 1 + 1 + 1 + 9;
 
     /// Total account size calculation
     /// 
@@ -719,6 +2237,7 @@ impl RefundShareCache {
     /// - 4 bytes: version
     /// - 8 bytes: subtotal_refund_hcoin
     /// - 8 bytes: subtotal_estimate_sol
+    /// - 8 bytes: priority_fee_per_cu
     /// - 8 bytes: executed_at
     /// - 8 bytes: created_at
     /// - 4 bytes: entries vector length
@@ -731,6 +2250,7 @@ impl RefundShareCache {
         4 +  // version
         8 +  // subtotal_refund_hcoin
         8 +  // subtotal_estimate_sol
+        8 +  // priority_fee_per_cu
         8 +  // executed_at
         8 +  // created_at
         4 + (MAX_ENTRIES_PER_BATCH * Self::ENTRY_SIZE); // entries
@@ -762,4 +2282,2552 @@ impl RefundShareCache {
         // Get percentage for stage and year
         stage_ratio[(stage - 1) as usize][year_index as usize]
     }
+
+    /// Compute a record's refund amount for a year
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Single source of truth for estimate_refund_share and reconcile_batch
+    /// - amount = amount_hcoin × percent / 100, truncated
+    pub fn compute_refund(
+        stage_ratio: &[[u8; 10]; 3],
+        stage: u8,
+        year_index: u8,
+        amount_hcoin: u64,
+    ) -> Result<u64> {
+        let percent = Self::get_refund_percentage(stage_ratio, stage, year_index);
+
+        let amount = amount_hcoin
+            .checked_mul(percent as u64)
+            .and_then(|x| x.checked_div(100))
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        Ok(amount)
+    }
+}
+
+/// Program-wide configuration account (singleton PDA)
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"program_config"], one per program deployment
+/// - Authority is the program upgrade authority at initialization time
+/// - `paused` blocks execution and withdrawal instructions across all investments
+/// 
+/// SECURITY FEATURES:
+/// - Emergency stop usable during an incident while an upgrade is prepared
+/// - Only the config authority can toggle the pause
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct ProgramConfig {
+    /// Config authority allowed to toggle the pause
+    /// AUDIT: Set to the upgrade authority on initialization
+    /// SECURITY: Single emergency key, independent of per-investment whitelists
+    pub authority: Pubkey,
+
+    /// Program-wide emergency stop
+    /// AUDIT: Checked by execute_profit_share, execute_refund_share and withdraw_from_vault
+    /// SECURITY: Halts all outgoing fund movements when set
+    pub paused: bool,
+
+    /// Last pause toggle timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl ProgramConfig {
+    /// Total account size: 57 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 32 bytes: authority
+    /// - 1 byte: paused
+    /// - 8 bytes: updated_at
+    /// - 8 bytes: created_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        32 + // authority
+        1 +  // paused
+        8 +  // updated_at
+        8;   // created_at
+}
+
+/// Published program limits (singleton PDA)
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"limits_config"]
+/// - Initialized from the compiled constants by the ProgramConfig authority
+/// - Integrators read limits here instead of hard-coding them from source
+/// - The compiled constants remain the hard ceilings enforced by every instruction;
+///   governance overrides can only publish tighter operating limits
+/// 
+/// SECURITY FEATURES:
+/// - Overrides are validated against the compiled constants, never looser
+/// - Structural limits (years, stages, whitelist, threshold) are read-only
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct LimitsConfig {
+    /// Maximum investment record entries per profit/refund batch
+    /// AUDIT: Overridable, 1..=MAX_ENTRIES_PER_BATCH
+    pub max_entries_per_batch: u16,
+
+    /// Validity of a ProfitShareCache / RefundShareCache in seconds
+    /// AUDIT: Overridable, 1..=SHARE_CACHE_EXPIRE_SECS
+    pub share_cache_expire_secs: i64,
+
+    /// Maximum length of a scheduled program upgrade window in seconds
+    /// AUDIT: Overridable, 1..=MAX_UPGRADE_WINDOW_SECS
+    pub max_upgrade_window_secs: i64,
+
+    /// First refund year index (START_YEAR_INDEX)
+    pub start_year_index: u8,
+
+    /// Last refund year index, inclusive (MAX_YEAR_INDEX)
+    pub max_year_index: u8,
+
+    /// Number of investment stages (MAX_STAGE)
+    pub max_stage: u8,
+
+    /// Length of each whitelist (MAX_WHITELIST_LEN)
+    pub max_whitelist_len: u8,
+
+    /// Unweighted multisig threshold (MULTISIG_THRESHOLD)
+    pub multisig_threshold: u8,
+
+    /// Maximum wallets in a payout split (MAX_PAYOUT_SPLITS)
+    pub max_payout_splits: u8,
+
+    /// Failed payout attempts before an entry is escrowed (MAX_ENTRY_FAILURES)
+    pub max_entry_failures: u8,
+
+    /// Holding period of a revoked record's share (REVOCATION_HOLD_SECS)
+    pub revocation_hold_secs: i64,
+
+    /// Default minimum interval between estimates (DEFAULT_MIN_ESTIMATE_INTERVAL_SECS)
+    pub default_min_estimate_interval_secs: u32,
+
+    /// Shortest operating allowance period (MIN_ALLOWANCE_PERIOD_SECS)
+    pub min_allowance_period_secs: i64,
+
+    /// Record ids per emit_record_page page (RECORD_PAGE_SIZE)
+    pub record_page_size: u64,
+
+    /// Last override timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl LimitsConfig {
+    /// Total account size: 89 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 2 bytes: max_entries_per_batch
+    /// - 8 bytes: share_cache_expire_secs
+    /// - 8 bytes: max_upgrade_window_secs
+    /// - 7 bytes: u8 structural limits
+    /// - 8 bytes: revocation_hold_secs
+    /// - 4 bytes: default_min_estimate_interval_secs
+    /// - 8 bytes: min_allowance_period_secs
+    /// - 8 bytes: record_page_size
+    /// - 8 bytes: updated_at
+    /// - 8 bytes: created_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        2 +  // max_entries_per_batch
+        8 +  // share_cache_expire_secs
+        8 +  // max_upgrade_window_secs
+        7 +  // start/max year, stage, whitelist, threshold, splits, failures
+        8 +  // revocation_hold_secs
+        4 +  // default_min_estimate_interval_secs
+        8 +  // min_allowance_period_secs
+        8 +  // record_page_size
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Reset every field to the compiled constants
+    /// 
+    /// AUDIT: Used on initialization; overrides are applied on top afterwards
+    pub fn load_defaults(&mut self) {
+        self.max_entries_per_batch = MAX_ENTRIES_PER_BATCH as u16;
+        self.share_cache_expire_secs = SHARE_CACHE_EXPIRE_SECS;
+        self.max_upgrade_window_secs = MAX_UPGRADE_WINDOW_SECS;
+        self.start_year_index = START_YEAR_INDEX;
+        self.max_year_index = MAX_YEAR_INDEX;
+        self.max_stage = MAX_STAGE as u8;
+        self.max_whitelist_len = MAX_WHITELIST_LEN as u8;
+        self.multisig_threshold = MULTISIG_THRESHOLD as u8;
+        self.max_payout_splits = MAX_PAYOUT_SPLITS as u8;
+        self.max_entry_failures = MAX_ENTRY_FAILURES;
+        self.revocation_hold_secs = REVOCATION_HOLD_SECS;
+        self.default_min_estimate_interval_secs = DEFAULT_MIN_ESTIMATE_INTERVAL_SECS;
+        self.min_allowance_period_secs = MIN_ALLOWANCE_PERIOD_SECS;
+        self.record_page_size = RECORD_PAGE_SIZE;
+    }
+}
+
+/// Tenant account (one per platform customer)
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"tenant", tenant_id]
+/// - Created by the ProgramConfig authority; managed afterwards by its own admin
+/// - New investments created with the tenant inherit its defaults: every whitelist
+///   passed empty to initialize_investment_info is taken from here, and the fee
+///   settings are copied into InvestmentConfig
+/// 
+/// SECURITY FEATURES:
+/// - Only the tenant admin may create investments under the tenant
+/// - Changing the defaults never touches existing investments
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct Tenant {
+    /// Tenant identifier (15 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub tenant_id: [u8; 15],
+
+    /// Tenant admin
+    /// AUDIT: Creates investments under the tenant and maintains its defaults
+    pub admin: Pubkey,
+
+    /// Default platform fee in basis points
+    /// AUDIT: 0..=MAX_TENANT_FEE_BP; copied into new investments
+    pub fee_bp: u16,
+
+    /// Default platform fee recipient
+    /// AUDIT: Copied into new investments
+    pub fee_recipient: Pubkey,
+
+    /// Default execute whitelist (empty = no default)
+    /// AUDIT: Exactly MAX_WHITELIST_LEN members when set
+    pub execute_whitelist: Vec<Pubkey>,
+
+    /// Default update whitelist (empty = no default)
+    /// AUDIT: Exactly MAX_WHITELIST_LEN members when set
+    pub update_whitelist: Vec<Pubkey>,
+
+    /// Default withdraw whitelist (empty = no default)
+    /// AUDIT: Exactly MAX_WHITELIST_LEN members when set
+    pub withdraw_whitelist: Vec<Pubkey>,
+
+    /// Number of investments created under the tenant
+    pub investment_count: u32,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Last defaults change timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl Tenant {
+    /// Total account size: 602 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: tenant_id
+    /// - 32 bytes: admin
+    /// - 2 bytes: fee_bp
+    /// - 32 bytes: fee_recipient
+    /// - 492 bytes: three default whitelists (3 × (4 + 5×32))
+    /// - 4 bytes: investment_count
+    /// - 1 byte: bump
+    /// - 8 bytes: updated_at
+    /// - 8 bytes: created_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // tenant_id
+        32 + // admin
+        2 +  // fee_bp
+        32 + // fee_recipient
+        3 * (4 + (MAX_WHITELIST_LEN * 32)) + // default whitelists
+        4 +  // investment_count
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Validate the default fee and whitelists
+    /// 
+    /// AUDIT: A default whitelist is either empty or a full set of distinct members
+    pub fn validate_defaults(&self) -> Result<()> {
+        require!(self.fee_bp <= MAX_TENANT_FEE_BP, ErrorCode::InvalidTenantDefaults);
+        for whitelist in [&self.execute_whitelist, &self.update_whitelist, &self.withdraw_whitelist] {
+            if whitelist.is_empty() {
+                continue;
+            }
+            require!(whitelist.len() == MAX_WHITELIST_LEN, ErrorCode::InvalidTenantDefaults);
+            for (i, member) in whitelist.iter().enumerate() {
+                require!(
+                    *member != Pubkey::default() && !whitelist[i + 1..].contains(member),
+                    ErrorCode::InvalidTenantDefaults
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Pick the tenant default for a whitelist passed empty
+    pub fn inherit_whitelist(provided: Vec<Pubkey>, default: &[Pubkey]) -> Vec<Pubkey> {
+        if provided.is_empty() { default.to_vec() } else { provided }
+    }
+}
+
+/// Exchange registry account (singleton PDA)
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"exchange_registry"]
+/// - Lists known exchange deposit program owners and hot wallet addresses
+/// - add_investment_record and update_investment_record_wallets screen the record
+///   wallet against it; a match is rejected unless the operators set
+///   allow_exchange_wallet, in which case an ExchangeWalletFlagged event is emitted
+/// 
+/// SECURITY FEATURES:
+/// - Maintained by the ProgramConfig authority only
+/// - Absent registry screens nothing, so deployments without it are unaffected
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct ExchangeRegistry {
+    /// Exchange deposit program owners and hot wallet addresses
+    /// AUDIT: A wallet matches when its key or its owning program is listed
+    pub owners: Vec<Pubkey>,
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Last change timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl ExchangeRegistry {
+    /// Total account size: 541 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 516 bytes: owners (4 + 16×32)
+    /// - 1 byte: bump
+    /// - 8 bytes: updated_at
+    /// - 8 bytes: created_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        4 + (MAX_EXCHANGE_OWNERS * 32) + // owners
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Validate the registry entries
+    /// 
+    /// AUDIT: Bounded, distinct and non-default entries
+    pub fn validate(&self) -> Result<()> {
+        require!(self.owners.len() <= MAX_EXCHANGE_OWNERS, ErrorCode::InvalidExchangeRegistry);
+        for (i, owner) in self.owners.iter().enumerate() {
+            require!(
+                *owner != Pubkey::default() && !self.owners[i + 1..].contains(owner),
+                ErrorCode::InvalidExchangeRegistry
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether a wallet account is an exchange deposit wallet
+    /// 
+    /// AUDIT: Matches the wallet address itself or the program owning the account
+    pub fn matches(&self, wallet: &AccountInfo) -> bool {
+        self.owners.contains(wallet.key) || self.owners.contains(wallet.owner)
+    }
+}
+
+/// Pending execute / update whitelist patch
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"whitelist_patch", investment_id, version, [is_update as u8]]
+/// - Queued by patch_execute_whitelist / patch_update_whitelist under the 3-of-5 rule
+/// - The incoming member must sign accept_whitelist_seat before it can be applied
+/// - Applied by apply_whitelist_patch from effective_at, or cancelled by
+///   WHITELIST_PATCH_CANCEL_SIGNERS members of the same whitelist
+/// 
+/// SECURITY FEATURES:
+/// - One pending patch per whitelist; the PDA is closed on apply or cancel
+/// - from / to are re-checked against the whitelist when applied
+#[account]
+#[derive()]
+pub struct PendingWhitelistPatch {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// true for update_whitelist, false for execute_whitelist
+    pub is_update: bool,
+
+    /// Member to be replaced
+    pub from: Pubkey,
+
+    /// Replacement member
+    pub to: Pubkey,
+
+    /// Label written to the slot together with `to` (e.g. "CFO")
+    /// AUDIT: Display metadata only; replaces the outgoing member's label
+    pub to_label: [u8; WHITELIST_LABEL_LEN],
+
+    /// Payer of the PDA rent, refunded when the PDA is closed
+    pub queued_by: Pubkey,
+
+    /// Queue timestamp
+    /// AUDIT: Used for audit trail
+    pub queued_at: i64,
+
+    /// First timestamp at which the patch may be applied
+    pub effective_at: i64,
+
+    /// Timestamp at which `to` signed accept_whitelist_seat (0 = not accepted)
+    /// AUDIT: Proves the incoming key exists and its holder consents to the seat
+    pub accepted_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingWhitelistPatch {
+    /// Total account size: 165 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 1 byte: is_update
+    /// - 32 bytes: from
+    /// - 32 bytes: to
+    /// - 16 bytes: to_label
+    /// - 32 bytes: queued_by
+    /// - 8 bytes: queued_at
+    /// - 8 bytes: effective_at
+    /// - 8 bytes: accepted_at
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        1 +  // is_update
+        32 + // from
+        32 + // to
+        WHITELIST_LABEL_LEN + // to_label
+        32 + // queued_by
+        8 +  // queued_at
+        8 +  // effective_at
+        8 +  // accepted_at
+        1;   // bump
+
+    /// Replace `from` with `to` in the whitelist
+    /// 
+    /// AUDIT CRITICAL:
+    /// - from must still be a member and to must still be absent
+    /// - The slot index is kept, so `to` inherits the replaced member's signer weight
+    /// - The slot label is overwritten with to_label
+    pub fn apply_to(
+        &self,
+        whitelist: &mut [Pubkey],
+        labels: &mut [[u8; WHITELIST_LABEL_LEN]; MAX_WHITELIST_LEN],
+    ) -> Result<()> {
+        require!(!whitelist.contains(&self.to), ErrorCode::WhitelistAddressExists);
+        let index = whitelist
+            .iter()
+            .position(|member| member == &self.from)
+            .ok_or(ErrorCode::WhitelistAddressNotFound)?;
+        whitelist[index] = self.to;
+        labels[index] = self.to_label;
+        Ok(())
+    }
+}
+
+/// Withdrawal waiting out the withdrawal timelock
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"pending_withdrawal", investment_id, version]; one per investment
+/// - Created by propose_withdrawal under the same authorization as withdraw_from_vault
+/// - execute_withdrawal pays it out from executable_at; cancel_withdrawal drops it
+/// 
+/// SECURITY FEATURES:
+/// - Amounts are fixed at proposal time; execution never re-reads vault balances
+/// - Pins the withdrawal sequence and configuration hash, so it lapses when either changes
+#[account]
+#[derive()]
+pub struct PendingWithdrawal {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Destination wallet; must still be on withdraw_whitelist at execution
+    pub recipient: Pubkey,
+
+    /// Assets selected for the withdrawal
+    pub assets: WithdrawAssets,
+
+    /// Purpose code; selects the WithdrawalBudget line
+    pub purpose: WithdrawPurpose,
+
+    /// USDT to transfer (base units)
+    pub usdt_amount: u64,
+
+    /// H2COIN to transfer (base units)
+    pub hcoin_amount: u64,
+
+    /// SOL to transfer (lamports)
+    pub sol_amount: u64,
+
+    /// Withdrawal sequence the proposal was approved for
+    /// AUDIT: Must still equal InvestmentInfo.withdraw_sequence at execution
+    pub sequence: u64,
+
+    /// Configuration hash the signers approved
+    pub config_hash: [u8; 32],
+
+    /// Payer of the PDA rent, refunded when the PDA is closed
+    pub proposed_by: Pubkey,
+
+    /// Proposal timestamp
+    /// AUDIT: Used for audit trail
+    pub proposed_at: i64,
+
+    /// First timestamp at which execute_withdrawal may run
+    pub executable_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingWithdrawal {
+    /// Total account size: 174 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 32 bytes: recipient
+    /// - 1 byte: assets
+    /// - 1 byte: purpose
+    /// - 24 bytes: usdt_amount, hcoin_amount, sol_amount
+    /// - 8 bytes: sequence
+    /// - 32 bytes: config_hash
+    /// - 32 bytes: proposed_by
+    /// - 16 bytes: proposed_at, executable_at
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        32 + // recipient
+        1 +  // assets
+        1 +  // purpose
+        8 +  // usdt_amount
+        8 +  // hcoin_amount
+        8 +  // sol_amount
+        8 +  // sequence
+        32 + // config_hash
+        32 + // proposed_by
+        8 +  // proposed_at
+        8 +  // executable_at
+        1;   // bump
+}
+
+/// Per-purpose withdrawal totals and caps of an investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"withdraw_budget", investment_id, version]
+/// - Created on first use by any withdrawal instruction or by set_withdraw_budget
+/// - lines[WithdrawPurpose::index()] counts every payout made under that purpose
+/// 
+/// SECURITY FEATURES:
+/// - Totals are only written after a successful payout and never decrease
+/// - Caps are written only by set_withdraw_budget under update_whitelist multisig
+#[account]
+#[derive()]
+pub struct WithdrawalBudget {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// One budget line per WithdrawPurpose
+    pub lines: [BudgetLine; WithdrawPurpose::COUNT],
+
+    /// Last payout or cap change
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl WithdrawalBudget {
+    /// Total account size: 240 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 204 bytes: lines (4 × 51)
+    /// - 8 bytes: updated_at
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        WithdrawPurpose::COUNT * BudgetLine::SIZE + // lines
+        8 +  // updated_at
+        1;   // bump
+
+    /// Bind a freshly created budget to its investment
+    /// 
+    /// AUDIT: No-op once bound; the account is created through init_if_needed
+    pub fn bind(&mut self, info: &InvestmentInfo, bump: u8) {
+        if self.bump == 0 {
+            self.investment_id = info.investment_id;
+            self.version = info.version;
+            self.bump = bump;
+        }
+    }
+
+    /// Count a payout against its purpose
+    /// 
+    /// AUDIT CRITICAL: Fails with WithdrawBudgetExceeded before anything is written
+    pub fn record(
+        &mut self,
+        purpose: WithdrawPurpose,
+        usdt_amount: u64,
+        hcoin_amount: u64,
+        sol_amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        let line = &mut self.lines[purpose.index()];
+        let (usdt, hcoin, sol) = line.totals_after(usdt_amount, hcoin_amount, sol_amount)?;
+        line.withdrawn_usdt = usdt;
+        line.withdrawn_hcoin = hcoin;
+        line.withdrawn_sol = sol;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// One bucket of the rolling withdrawal window
+/// 
+/// AUDIT: `slot` is now / bucket length; a bucket outside the window counts as empty
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RateBucket {
+    /// Bucket number (now / bucket length)
+    pub slot: i64,
+
+    /// USDT withdrawn in the bucket (base units)
+    pub usdt: u64,
+
+    /// H2COIN withdrawn in the bucket (base units)
+    pub hcoin: u64,
+}
+
+impl RateBucket {
+    /// Serialized size: 24 bytes
+    pub const SIZE: usize = 8 + 8 + 8;
+}
+
+/// Rolling-window withdrawal rate limit of an investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"withdraw_rate_limit", investment_id, version]
+/// - Created on first use by any withdrawal instruction or by set_withdraw_rate_limit
+/// - Caps the USDT and H2COIN leaving the vault within any window_secs, whatever the
+///   purpose or signers, so a compromised withdrawal quorum cannot drain the vault at once
+/// 
+/// SECURITY FEATURES:
+/// - Window, caps and buckets are written only by set_withdraw_rate_limit under
+///   update_whitelist multisig, and by payouts
+/// - window_secs == 0 turns the limit off
+#[account]
+#[derive()]
+pub struct WithdrawRateLimit {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Length of the rolling window (0 = rate limit off)
+    pub window_secs: u32,
+
+    /// USDT allowed within one window (None = uncapped)
+    pub cap_usdt: Option<u64>,
+
+    /// H2COIN allowed within one window (None = uncapped)
+    pub cap_hcoin: Option<u64>,
+
+    /// Withdrawn amounts per bucket; ring indexed by slot % RATE_LIMIT_BUCKETS
+    pub buckets: [RateBucket; RATE_LIMIT_BUCKETS],
+
+    /// Last payout or configuration change
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl WithdrawRateLimit {
+    /// Total account size: 250 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 4 bytes: window_secs
+    /// - 18 bytes: cap_usdt, cap_hcoin (Option<u64>)
+    /// - 192 bytes: buckets (8 × 24)
+    /// - 8 bytes: updated_at
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        4 +  // window_secs
+        9 +  // cap_usdt
+        9 +  // cap_hcoin
+        RATE_LIMIT_BUCKETS * RateBucket::SIZE + // buckets
+        8 +  // updated_at
+        1;   // bump
+
+    /// Bind a freshly created rate limit to its investment
+    /// 
+    /// AUDIT: No-op once bound; the account is created through init_if_needed
+    pub fn bind(&mut self, info: &InvestmentInfo, bump: u8) {
+        if self.bump == 0 {
+            self.investment_id = info.investment_id;
+            self.version = info.version;
+            self.bump = bump;
+        }
+    }
+
+    /// Bucket number containing `now`
+    fn slot_at(&self, now: i64) -> i64 {
+        let bucket_secs = (self.window_secs as i64 / RATE_LIMIT_BUCKETS as i64).max(1);
+        now.div_euclid(bucket_secs)
+    }
+
+    /// USDT and H2COIN withdrawn within the window ending at `now`
+    pub fn window_totals(&self, now: i64) -> (u64, u64) {
+        let slot = self.slot_at(now);
+        self.buckets
+            .iter()
+            .filter(|bucket| bucket.slot > slot - RATE_LIMIT_BUCKETS as i64 && bucket.slot <= slot)
+            .fold((0u64, 0u64), |(usdt, hcoin), bucket| {
+                (usdt.saturating_add(bucket.usdt), hcoin.saturating_add(bucket.hcoin))
+            })
+    }
+
+    /// Count a payout in the current bucket
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Fails with WithdrawRateLimitExceeded before anything is written
+    /// - No-op while the rate limit is off
+    pub fn record(&mut self, usdt_amount: u64, hcoin_amount: u64, now: i64) -> Result<()> {
+        if self.window_secs == 0 {
+            return Ok(());
+        }
+
+        let (window_usdt, window_hcoin) = self.window_totals(now);
+        for (total, amount, cap) in [
+            (window_usdt, usdt_amount, self.cap_usdt),
+            (window_hcoin, hcoin_amount, self.cap_hcoin),
+        ] {
+            if let Some(cap) = cap {
+                let total = total.checked_add(amount).ok_or(ErrorCode::NumericalOverflow)?;
+                require!(total <= cap, ErrorCode::WithdrawRateLimitExceeded);
+            }
+        }
+
+        let slot = self.slot_at(now);
+        let bucket = &mut self.buckets[slot.rem_euclid(RATE_LIMIT_BUCKETS as i64) as usize];
+        if bucket.slot != slot {
+            *bucket = RateBucket { slot, ..RateBucket::default() };
+        }
+        bucket.usdt = bucket.usdt.checked_add(usdt_amount).ok_or(ErrorCode::NumericalOverflow)?;
+        bucket.hcoin = bucket.hcoin.checked_add(hcoin_amount).ok_or(ErrorCode::NumericalOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Latest attested vault balance snapshot of an investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"vault_snapshot", investment_id, version]
+/// - Written by the permissionless snapshot_vault when the caller supplies it
+/// - Obligations cover only the caches supplied to that call (caches_counted)
+/// 
+/// SECURITY FEATURES:
+/// - Balances are read from the validated vault PDA and ATAs, never from arguments
+/// - Informational only; no instruction reads it for authorization or payouts
+#[account]
+#[derive()]
+pub struct LatestVaultSnapshot {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Lamports held by the vault PDA
+    pub sol_lamports: u64,
+
+    /// USDT held by the vault ATA
+    pub usdt_balance: u64,
+
+    /// H2COIN held by the vault ATA
+    pub hcoin_balance: u64,
+
+    /// USDT still owed by the supplied profit caches (entries not yet paid)
+    pub obligations_usdt: u64,
+
+    /// H2COIN still owed by the supplied refund caches (entries not yet paid)
+    pub obligations_hcoin: u64,
+
+    /// Number of caches counted in the obligations
+    pub caches_counted: u16,
+
+    /// Slot at which the snapshot was taken
+    pub slot: u64,
+
+    /// UNIX timestamp of the snapshot
+    pub taken_at: i64,
+
+    /// Payer who took the snapshot
+    pub taken_by: Pubkey,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl LatestVaultSnapshot {
+    /// Total account size: 126 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 40 bytes: sol_lamports, usdt_balance, hcoin_balance, obligations_usdt, obligations_hcoin
+    /// - 2 bytes: caches_counted
+    /// - 8 bytes: slot
+    /// - 8 bytes: taken_at
+    /// - 32 bytes: taken_by
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        8 * 5 + // balances and obligations
+        2 +  // caches_counted
+        8 +  // slot
+        8 +  // taken_at
+        32 + // taken_by
+        1;   // bump
+}
+
+/// Batch assignment counter of an investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"batch_counter", investment_id, version]
+/// - add_investment_record with batch_id = 0 takes the batch assigned here
+/// - A batch is full at MAX_ENTRIES_PER_BATCH records, the most one estimation loads
+/// 
+/// SECURITY FEATURES:
+/// - Written only by add_investment_record under update_whitelist multisig
+/// - Explicit batch_ids at or above the current batch move the counter past them,
+///   so assigned batches never mix with manually numbered ones
+#[account]
+#[derive()]
+pub struct BatchCounter {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Batch currently being filled (0 before the first record)
+    pub batch_id: u16,
+
+    /// Records added to batch_id so far
+    /// AUDIT: Never exceeds MAX_ENTRIES_PER_BATCH for an assigned batch
+    pub records_in_batch: u16,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl BatchCounter {
+    /// Total account size: 32 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 2 bytes: batch_id
+    /// - 2 bytes: records_in_batch
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        2 +  // batch_id
+        2 +  // records_in_batch
+        1;   // bump
+
+    /// Batch a new record goes to
+    /// 
+    /// AUDIT: A non-zero requested batch_id is kept; 0 resolves to the current batch,
+    /// or the next one when the current batch is full or none exists yet
+    pub fn resolve_batch_id(&self, requested: u16) -> u16 {
+        if requested != 0 {
+            requested
+        } else if self.batch_id == 0 || self.records_in_batch as usize >= MAX_ENTRIES_PER_BATCH {
+            self.batch_id.saturating_add(1)
+        } else {
+            self.batch_id
+        }
+    }
+
+    /// Count a record added to `batch_id`
+    /// 
+    /// AUDIT CRITICAL:
+    /// - A batch at or above the current one becomes the current batch
+    /// - Records in earlier (manually numbered) batches are not tracked
+    /// - An assigned batch that would exceed MAX_ENTRIES_PER_BATCH is rejected
+    pub fn register(&mut self, batch_id: u16, assigned: bool) -> Result<()> {
+        if batch_id > self.batch_id {
+            self.batch_id = batch_id;
+            self.records_in_batch = 0;
+        }
+        if batch_id == self.batch_id {
+            require!(
+                !assigned || (self.records_in_batch as usize) < MAX_ENTRIES_PER_BATCH,
+                ErrorCode::NumericalOverflow
+            );
+            self.records_in_batch = self.records_in_batch.saturating_add(1);
+        }
+        Ok(())
+    }
+}
+
+/// Event stream checkpoint counter of an investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"checkpoint", investment_id, version]
+/// - Created by the first emit_checkpoint; sequence increases by exactly one per Checkpoint
+/// 
+/// SECURITY FEATURES:
+/// - Written only by emit_checkpoint
+/// - An indexer that sees sequence n + 2 after n knows it missed a checkpoint
+#[account]
+#[derive()]
+pub struct CheckpointState {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Sequence number of the last emitted Checkpoint (0 before the first)
+    pub sequence: u64,
+
+    /// Timestamp of the last emitted Checkpoint
+    pub last_checkpoint_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CheckpointState {
+    /// Total account size: 44 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 8 bytes: sequence
+    /// - 8 bytes: last_checkpoint_at
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        8 +  // sequence
+        8 +  // last_checkpoint_at
+        1;   // bump
+}
+
+/// RBAC role configuration of an investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"role_config", investment_id, version]
+/// - One RoleAssignment per Role, indexed by the Role discriminant
+/// - Passed as an optional account to the role-gated instructions; when omitted, or
+///   for an unassigned role, the legacy whitelist rule applies
+/// 
+/// SECURITY FEATURES:
+/// - Written only by set_role_assignment under update_whitelist multisig
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct RoleConfig {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Assignment of each role
+    pub assignments: [RoleAssignment; ROLE_COUNT],
+
+    /// PDA bump
+    pub bump: u8,
+
+    /// Last change timestamp
+    pub updated_at: i64,
+}
+
+impl RoleConfig {
+    /// Total account size: 846 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 810 bytes: assignments (5 × 162)
+    /// - 1 byte: bump
+    /// - 8 bytes: updated_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        RoleAssignment::SIZE * ROLE_COUNT + // assignments
+        1 +  // bump
+        8;   // updated_at
+
+    /// Assignment of `role`, if assigned
+    pub fn assignment(&self, role: Role) -> Option<&RoleAssignment> {
+        Some(&self.assignments[role as usize]).filter(|assignment| assignment.is_assigned())
+    }
+}
+
+/// Multisig proposal account
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"proposal", investment_id, version, proposal_id]
+/// - Collects approvals one transaction at a time, so whitelist members can approve
+///   asynchronously instead of co-signing a single transaction
+/// - Executed by passing it to the target instruction in place of co-signers; the
+///   approvals are re-checked against the current whitelist at that point
+/// 
+/// SECURITY FEATURES:
+/// - Executes at most once and only before expires_at
+/// - Approvals from members rotated out of the whitelist stop counting
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct Proposal {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Investment version (4 bytes)
+    pub version: [u8; 4],
+
+    /// Proposal identifier chosen by the proposer
+    /// AUDIT: Used for PDA derivation
+    pub proposal_id: u64,
+
+    /// Approved action with its exact arguments
+    pub action: ProposalAction,
+
+    /// Whitelist member who created the proposal
+    pub proposer: Pubkey,
+
+    /// Whitelist members who approved, proposer first
+    /// AUDIT: At most MAX_WHITELIST_LEN distinct members
+    pub approvals: Vec<Pubkey>,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// End of the approval window
+    pub expires_at: i64,
+
+    /// Execution timestamp (0 = not executed)
+    pub executed_at: i64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Total account size: 358 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 15 bytes: investment_id
+    /// - 4 bytes: version
+    /// - 8 bytes: proposal_id
+    /// - 102 bytes: action
+    /// - 32 bytes: proposer
+    /// - 164 bytes: approvals (4 + 5×32)
+    /// - 24 bytes: created_at, expires_at, executed_at
+    /// - 1 byte: bump
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        8 +  // proposal_id
+        ProposalAction::SIZE + // action
+        32 + // proposer
+        4 + (MAX_WHITELIST_LEN * 32) + // approvals
+        8 +  // created_at
+        8 +  // expires_at
+        8 +  // executed_at
+        1;   // bump
+
+    /// Whether approvals and execution are still accepted
+    /// 
+    /// AUDIT: Executed and expired proposals are final
+    pub fn require_open(&self, now: i64) -> Result<()> {
+        require!(self.executed_at == 0, ErrorCode::ProposalAlreadyExecuted);
+        require!(now <= self.expires_at, ErrorCode::ProposalExpired);
+        Ok(())
+    }
+
+    /// Validate the proposal for execution and mark it executed
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Proposal must belong to this investment and carry exactly `action`
+    /// - Approvals must still satisfy the current whitelist and weights
+    /// - Returns the approvals as the signer set for the audit trail
+    pub fn consume(
+        &mut self,
+        info: &InvestmentInfo,
+        config: &InvestmentConfig,
+        action: &ProposalAction,
+        now: i64,
+    ) -> Result<Vec<Pubkey>> {
+        require!(
+            self.investment_id == info.investment_id && self.version == info.version,
+            ErrorCode::ProposalActionMismatch
+        );
+        require!(self.action == *action, ErrorCode::ProposalActionMismatch);
+        self.require_open(now)?;
+        config.verify_signers(&self.approvals, action.is_update())?;
+        self.executed_at = now;
+        Ok(self.approvals.clone())
+    }
+}
+
+/// Upgrade authority governance account (singleton PDA)
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"upgrade_governance"]; the PDA itself becomes the program upgrade authority
+/// - Every authority change or upgrade requires 3-of-5 of `committee`
+/// - An upgrade can only deploy `window_buffer` between window_start and window_end
+/// 
+/// SECURITY FEATURES:
+/// - Upgradability is subject to the same quorum as configuration changes
+/// - Renouncing is explicit and irreversible
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct UpgradeGovernance {
+    /// Upgrade committee
+    /// AUDIT: Exactly 5 members, 3 signatures required
+    /// SECURITY: Controls program upgradability
+    pub committee: Vec<Pubkey>,
+
+    /// Whether this PDA is still the program upgrade authority
+    /// AUDIT: Cleared when the authority is handed off or renounced
+    pub holds_authority: bool,
+
+    /// Approved buffer for the scheduled upgrade
+    /// AUDIT: Pubkey::default() when no upgrade is scheduled
+    pub window_buffer: Pubkey,
+
+    /// Upgrade window start (inclusive)
+    pub window_start: i64,
+
+    /// Upgrade window end (inclusive)
+    pub window_end: i64,
+
+    /// Canonical bump of this PDA
+    /// AUDIT: Used for PDA signing in loader CPIs
+    pub bump: u8,
+
+    /// Last change timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl UpgradeGovernance {
+    /// Total account size: 238 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 164 bytes: committee (4 + 5×32)
+    /// - 1 byte: holds_authority
+    /// - 32 bytes: window_buffer
+    /// - 8 bytes: window_start
+    /// - 8 bytes: window_end
+    /// - 1 byte: bump
+    /// - 8 bytes: updated_at
+    /// - 8 bytes: created_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        4 + (MAX_WHITELIST_LEN * 32) + // committee
+        1 +  // holds_authority
+        32 + // window_buffer
+        8 +  // window_start
+        8 +  // window_end
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Enforce 3-of-5 committee signatures
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Same quorum rule as unweighted InvestmentConfig::verify_signers
+    /// - Only accounts that actually signed are counted
+    pub fn enforce_3_of_5_signers(&self, signer_infos: &[AccountInfo]) -> Result<()> {
+        enforce_committee_signers(&self.committee, signer_infos)
+    }
+
+    /// Whether an upgrade of `buffer` is allowed at `now`
+    pub fn window_open(&self, buffer: &Pubkey, now: i64) -> bool {
+        self.window_buffer != Pubkey::default()
+            && self.window_buffer == *buffer
+            && now >= self.window_start
+            && now <= self.window_end
+    }
+}
+
+/// Rescale an amount from one decimal convention to another
+/// 
+/// AUDIT CRITICAL:
+/// - Scaling up is exact and fails with NumericalOverflow instead of wrapping
+/// - Scaling down truncates, so a conversion never pays more than the input value
+pub fn normalize_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64> {
+    let factor = |diff: u8| {
+        10u64
+            .checked_pow(diff as u32)
+            .ok_or(ErrorCode::NumericalOverflow)
+    };
+
+    match to_decimals.cmp(&from_decimals) {
+        core::cmp::Ordering::Equal => Ok(amount),
+        core::cmp::Ordering::Greater => Ok(amount
+            .checked_mul(factor(to_decimals - from_decimals)?)
+            .ok_or(ErrorCode::NumericalOverflow)?),
+        core::cmp::Ordering::Less => Ok(amount / factor(from_decimals - to_decimals)?),
+    }
+}
+
+/// Enforce 3-of-5 signatures of a program-level committee
+/// 
+/// AUDIT CRITICAL:
+/// - Shared by UpgradeGovernance and GlobalConfig
+/// - Only accounts that actually signed are counted, each member once
+fn enforce_committee_signers(committee: &[Pubkey], signer_infos: &[AccountInfo]) -> Result<()> {
+    require!(
+        committee.len() == MAX_WHITELIST_LEN,
+        ErrorCode::WhitelistMustBeFive
+    );
+
+    let mut matched: Vec<Pubkey> = Vec::new();
+    for info in signer_infos.iter().filter(|i| i.is_signer) {
+        if committee.contains(info.key) && !matched.contains(info.key) {
+            matched.push(*info.key);
+        }
+    }
+
+    require!(matched.len() >= MULTISIG_THRESHOLD, ErrorCode::UnauthorizedSigner);
+    Ok(())
+}
+
+/// Program-wide operating parameters (singleton PDA)
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"global_config"]
+/// - Initialized from the compiled constants by the ProgramConfig authority
+/// - Every later change requires 3-of-5 of `committee`
+/// - Instructions read mints, SOL fee estimates and the cache expiry from here,
+///   so these parameters change without a redeploy
+/// 
+/// SECURITY FEATURES:
+/// - Cache expiry can never exceed the compiled SHARE_CACHE_EXPIRE_SECS
+/// - Changing hcoin_mint redirects every investment's H2COIN checks at once
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct GlobalConfig {
+    /// Parameter committee
+    /// AUDIT: Exactly 5 members, 3 signatures required
+    /// SECURITY: Controls every parameter below
+    pub committee: Vec<Pubkey>,
+
+    /// Program-level USDT mint
+    /// AUDIT: Default of get_usdt_mint; per-investment checks use InvestmentInfo.profit_mint
+    pub usdt_mint: Pubkey,
+
+    /// H2COIN mint used by refunds, deposits and vault checks
+    /// AUDIT: Default of get_hcoin_mint
+    pub hcoin_mint: Pubkey,
+
+    /// Stablecoins a new investment may choose as its profit mint
+    /// AUDIT: Default of get_allowed_profit_mints; 1..=MAX_PROFIT_MINTS unique mints
+    pub allowed_profit_mints: Vec<Pubkey>,
+
+    /// Base SOL charged per execution batch
+    /// AUDIT: Default of ESTIMATE_SOL_BASE
+    pub estimate_sol_base: u64,
+
+    /// SOL charged per executed entry
+    /// AUDIT: Default of ESTIMATE_SOL_PER_ENTRY
+    pub estimate_sol_per_entry: u64,
+
+    /// Validity of a ProfitShareCache / RefundShareCache in seconds
+    /// AUDIT: 1..=SHARE_CACHE_EXPIRE_SECS
+    pub share_cache_expire_secs: i64,
+
+    /// Relayer allowed to pay claim transactions on behalf of investors
+    /// AUDIT: Pubkey::default() disables sponsored claims
+    pub claim_relayer: Pubkey,
+
+    /// Canonical bump of this PDA
+    /// AUDIT: Recorded at initialization
+    pub bump: u8,
+
+    /// Last change timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl GlobalConfig {
+    /// Total account size: 441 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 8 bytes: Anchor discriminator
+    /// - 164 bytes: committee (4 + 5×32)
+    /// - 32 bytes: usdt_mint
+    /// - 32 bytes: hcoin_mint
+    /// - 132 bytes: allowed_profit_mints (4 + 4×32)
+    /// - 8 bytes: estimate_sol_base
+    /// - 8 bytes: estimate_sol_per_entry
+    /// - 8 bytes: share_cache_expire_secs
+    /// - 32 bytes: claim_relayer
+    /// - 1 byte: bump
+    /// - 8 bytes: updated_at
+    /// - 8 bytes: created_at
+    pub const SIZE: usize =
+        8 +  // discriminator
+        4 + (MAX_WHITELIST_LEN * 32) + // committee
+        32 + // usdt_mint
+        32 + // hcoin_mint
+        4 + (MAX_PROFIT_MINTS * 32) + // allowed_profit_mints
+        8 +  // estimate_sol_base
+        8 +  // estimate_sol_per_entry
+        8 +  // share_cache_expire_secs
+        32 + // claim_relayer
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Reset every parameter to the compiled constants
+    /// 
+    /// AUDIT: Used on initialization; committee changes are applied on top afterwards
+    pub fn load_defaults(&mut self) {
+        self.usdt_mint = get_usdt_mint();
+        self.hcoin_mint = get_hcoin_mint();
+        self.allowed_profit_mints = get_allowed_profit_mints();
+        self.estimate_sol_base = ESTIMATE_SOL_BASE;
+        self.estimate_sol_per_entry = ESTIMATE_SOL_PER_ENTRY;
+        self.share_cache_expire_secs = SHARE_CACHE_EXPIRE_SECS;
+        self.claim_relayer = Pubkey::default();
+    }
+
+    /// Enforce 3-of-5 committee signatures
+    pub fn enforce_3_of_5_signers(&self, signer_infos: &[AccountInfo]) -> Result<()> {
+        enforce_committee_signers(&self.committee, signer_infos)
+    }
+
+    /// Validate the current parameter set
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Mints are set, and H2COIN is never an allowed profit mint
+    /// - allowed_profit_mints holds 1..=MAX_PROFIT_MINTS unique mints
+    /// - SOL fee estimates are positive; cache expiry within the compiled ceiling
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.usdt_mint != Pubkey::default() && self.hcoin_mint != Pubkey::default(),
+            ErrorCode::InvalidGlobalConfig
+        );
+        require!(
+            !self.allowed_profit_mints.is_empty()
+                && self.allowed_profit_mints.len() <= MAX_PROFIT_MINTS,
+            ErrorCode::InvalidGlobalConfig
+        );
+        for (i, mint) in self.allowed_profit_mints.iter().enumerate() {
+            require!(
+                *mint != Pubkey::default()
+                    && *mint != self.hcoin_mint
+                    && !self.allowed_profit_mints[..i].contains(mint),
+                ErrorCode::InvalidGlobalConfig
+            );
+        }
+        require!(
+            self.estimate_sol_base > 0 && self.estimate_sol_per_entry > 0,
+            ErrorCode::InvalidGlobalConfig
+        );
+        require!(
+            self.share_cache_expire_secs > 0
+                && self.share_cache_expire_secs <= SHARE_CACHE_EXPIRE_SECS,
+            ErrorCode::InvalidGlobalConfig
+        );
+        Ok(())
+    }
+}
+
+/// Kind of planned distribution round
+/// 
+/// AUDIT: Selects which cache a schedule slot is matched against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DistributionKind {
+    Profit, // Matched against ProfitShareCache (year_index is 0)
+    Refund, // Matched against RefundShareCache
+}
+
+/// Planned distribution round
+/// 
+/// AUDIT CRITICAL:
+/// - (kind, batch_id, year_index) identifies the slot
+/// - executed_at is set by the execution that settles the matching cache
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleSlot {
+    pub kind: DistributionKind,
+    pub batch_id: u16,
+    pub year_index: u8,
+    pub target_at: i64,
+    pub executed_at: i64,
+}
+
+/// Distribution calendar for one investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"schedule", investment_id, version]
+/// - Committee pre-registers planned profit rounds and refund years
+/// - Executions passing this account mark their slot executed
+/// 
+/// SECURITY FEATURES:
+/// - Slot registration requires 3-of-5 update_whitelist multisig
+/// - Informational for cranks and UIs; does not gate payouts
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct DistributionSchedule {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Links the schedule to its investment
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Links the schedule to its investment version
+    pub version: [u8; 4],
+
+    /// Planned rounds
+    /// AUDIT: At most MAX_SCHEDULE_SLOTS, unique by (kind, batch_id, year_index)
+    pub slots: Vec<ScheduleSlot>,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+}
+
+impl DistributionSchedule {
+    /// Per-slot size: 20 bytes
+    /// 
+    /// SIZE BREAKDOWN:
+    /// - 1 byte: kind (enum)
+    /// - 2 bytes: batch_id
+    /// - 1 byte: year_index
+    /// - 8 bytes: target_at
+    /// - 8 bytes: executed_at
+    pub const SLOT_SIZE: usize = 1 + 2 + 1 + 8 + 8;
+
+    /// Total account size: 679 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        4 + (MAX_SCHEDULE_SLOTS * Self::SLOT_SIZE) + // slots
+        8;   // updated_at
+
+    /// Locate the slot for a given round
+    /// 
+    /// AUDIT: Returns the slot index, or ScheduleSlotNotFound
+    pub fn find_slot(&self, kind: DistributionKind, batch_id: u16, year_index: u8) -> Result<usize> {
+        self.slots
+            .iter()
+            .position(|slot| slot.kind == kind && slot.batch_id == batch_id && slot.year_index == year_index)
+            .ok_or_else(|| error!(ErrorCode::ScheduleSlotNotFound))
+    }
+}
+
+/// Standing operating-expense allowance for one investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"operating_allowance", investment_id, version]
+/// - Set by 3-of-5 execute_whitelist multisig; claims are permissionless
+/// - At most `amount` USDT reaches `recipient` per `period_secs`
+/// 
+/// SECURITY FEATURES:
+/// - Recipient must be on the withdraw_whitelist when the allowance is set
+/// - Unused allowance does not carry over to later periods
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct OperatingAllowance {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Links the allowance to its investment
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Links the allowance to its investment version
+    pub version: [u8; 4],
+
+    /// Wallet receiving the allowance
+    /// AUDIT: Fixed by the committee; claimers cannot redirect funds
+    pub recipient: Pubkey,
+
+    /// Maximum USDT (base units) per period
+    /// AUDIT: 0 disables the allowance
+    pub amount: u64,
+
+    /// Period length in seconds
+    /// AUDIT: At least MIN_ALLOWANCE_PERIOD_SECS
+    pub period_secs: i64,
+
+    /// Start of the current period
+    /// AUDIT: Advanced by whole periods on claim
+    pub period_start: i64,
+
+    /// USDT already claimed in the current period
+    /// AUDIT: Reset when the period rolls over
+    pub claimed_in_period: u64,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl OperatingAllowance {
+    /// Total account size: 108 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        32 + // recipient
+        8 +  // amount
+        8 +  // period_secs
+        8 +  // period_start
+        8 +  // claimed_in_period
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Roll the current period forward to the one containing `now`
+    /// 
+    /// AUDIT: Advances by whole periods so the schedule does not drift
+    pub fn roll_period(&mut self, now: i64) {
+        if self.period_secs > 0 && now >= self.period_start.saturating_add(self.period_secs) {
+            let elapsed = (now - self.period_start) / self.period_secs;
+            self.period_start += elapsed * self.period_secs;
+            self.claimed_in_period = 0;
+        }
+    }
+}
+
+/// Yearly statement of one investor (account_id) in one investment
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"statement", investment_id, version, account_id, year]
+/// - Rebuilt from scratch on every generate_annual_statement call
+/// - Totals only cover the records and caches supplied to that call
+/// 
+/// SECURITY FEATURES:
+/// - Derived from on-chain records and settled caches only
+/// - Counts of inputs let tax tooling check completeness
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct AnnualStatement {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Links the statement to its investment
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Links the statement to its investment version
+    pub version: [u8; 4],
+
+    /// Investor account identifier (15 bytes)
+    /// AUDIT: Matches InvestmentRecord / cache entry account_id
+    pub account_id: [u8; 15],
+
+    /// Calendar year (UTC)
+    pub year: u16,
+
+    /// USDT invested through records created in the year
+    pub invested_usdt: u64,
+
+    /// H2COIN allocated through records created in the year
+    pub invested_hcoin: u64,
+
+    /// USDT profit paid by profit caches settled in the year
+    pub profit_usdt: u64,
+
+    /// H2COIN refunds paid by refund caches settled in the year
+    pub refund_hcoin: u64,
+
+    /// Records of the investor counted
+    pub records_counted: u16,
+
+    /// Settled caches of the year examined
+    pub caches_counted: u16,
+
+    /// Last generation timestamp
+    /// AUDIT: Used for audit trail
+    pub generated_at: i64,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+}
+
+impl AnnualStatement {
+    /// Total account size: 89 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        15 + // account_id
+        2 +  // year
+        8 +  // invested_usdt
+        8 +  // invested_hcoin
+        8 +  // profit_usdt
+        8 +  // refund_hcoin
+        2 +  // records_counted
+        2 +  // caches_counted
+        8 +  // generated_at
+        1;   // bump
+
+    /// Calendar year (UTC) of a UNIX timestamp
+    /// 
+    /// AUDIT: Proleptic Gregorian civil-from-days conversion; no leap-second handling
+    pub fn calendar_year(unix_ts: i64) -> u16 {
+        let z = unix_ts.div_euclid(86400) + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let year = yoe + era * 400 + if mp >= 10 { 1 } else { 0 };
+        year as u16
+    }
+}
+
+/// Delivery channel of a notification registration
+/// 
+/// AUDIT: Tells the off-chain service how to interpret `contact`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotificationChannel {
+    ContactHash, // Hash of an encrypted contact (e-mail / phone) held off-chain
+    PushChannel, // Push-channel identifier
+}
+
+/// Kind of investor notification
+/// 
+/// AUDIT: Emitted with InvestorNotification for registered wallets only
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NotificationKind {
+    ProfitEstimated,
+    ProfitPaid,
+    RefundEstimated,
+    RefundPaid,
+}
+
+/// Investor opt-in notification registration
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"notification", wallet]; one registration per wallet across investments
+/// - Created and closed only by the wallet itself
+/// - Holds no plaintext contact data, only a hash or channel identifier
+/// 
+/// SECURITY FEATURES:
+/// - Estimation / execution accept it from remaining_accounts only after PDA validation
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct NotificationRegistration {
+    /// Registered wallet
+    /// AUDIT: Owner of the registration
+    pub wallet: Pubkey,
+
+    /// How `contact` is interpreted
+    pub channel: NotificationChannel,
+
+    /// Encrypted contact hash or push-channel identifier
+    /// AUDIT: Never all zeros
+    pub contact: [u8; 32],
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl NotificationRegistration {
+    /// Total account size: 90 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        32 + // wallet
+        1 +  // channel
+        32 + // contact
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+}
+
+/// Account-level payout split
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"split", investment_id, version, account_id]
+/// - Set and cleared by the update_whitelist multisig
+/// - Estimation expands an account's pending shares into one share per split wallet
+/// 
+/// SECURITY FEATURES:
+/// - Percentages always sum to 100, so splitting never changes the total paid
+/// - Estimation / reconciliation accept it from remaining_accounts only after PDA validation
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct PayoutSplit {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub version: [u8; 4],
+
+    /// Investor account identifier the split applies to
+    /// AUDIT: Used for PDA derivation
+    pub account_id: [u8; 15],
+
+    /// Payout wallets; unused slots are Pubkey::default()
+    /// SECURITY: Used wallets are distinct and never the default key
+    pub wallets: [Pubkey; MAX_PAYOUT_SPLITS],
+
+    /// Percentage per wallet (index-aligned with wallets)
+    /// AUDIT: Sum is exactly 100; unused slots are 0
+    pub percents: [u8; MAX_PAYOUT_SPLITS],
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+}
+
+impl PayoutSplit {
+    /// Total account size: 150 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        15 + // account_id
+        32 * MAX_PAYOUT_SPLITS + // wallets
+        MAX_PAYOUT_SPLITS +      // percents
+        1 +  // bump
+        8;   // updated_at
+
+    /// Validate a proposed split
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Used slots: non-default, distinct wallet with a non-zero percentage
+    /// - Unused slots: default wallet and 0%
+    /// - Percentages sum to exactly 100
+    pub fn validate(wallets: &[Pubkey; MAX_PAYOUT_SPLITS], percents: &[u8; MAX_PAYOUT_SPLITS]) -> Result<()> {
+        let mut total: u16 = 0;
+        for (i, (wallet, &percent)) in wallets.iter().zip(percents.iter()).enumerate() {
+            if percent == 0 {
+                require!(*wallet == Pubkey::default(), ErrorCode::InvalidPayoutSplit);
+                continue;
+            }
+            require!(*wallet != Pubkey::default(), ErrorCode::InvalidPayoutSplit);
+            require!(!wallets[..i].contains(wallet), ErrorCode::InvalidPayoutSplit);
+            total += percent as u16;
+        }
+        require!(total == 100, ErrorCode::InvalidPayoutSplit);
+        Ok(())
+    }
+
+    /// Split an amount across the payout wallets
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Without a split the whole amount goes to `wallet`
+    /// - Each share is floor(amount × percent / 100); the last used wallet receives
+    ///   the rounding remainder so the shares always add up to `amount`
+    pub fn shares(split: Option<&PayoutSplit>, wallet: Pubkey, amount: u64) -> Result<Vec<(Pubkey, u64)>> {
+        let Some(split) = split else {
+            return Ok(vec![(wallet, amount)]);
+        };
+
+        let mut shares = Vec::with_capacity(MAX_PAYOUT_SPLITS);
+        let mut allotted: u64 = 0;
+        for (wallet, &percent) in split.wallets.iter().zip(split.percents.iter()) {
+            if percent == 0 {
+                continue;
+            }
+            let share = (amount as u128)
+                .checked_mul(percent as u128)
+                .ok_or(ErrorCode::NumericalOverflow)?
+                / 100;
+            let share = u64::try_from(share).map_err(|_| ErrorCode::NumericalOverflow)?;
+            allotted = allotted.checked_add(share).ok_or(ErrorCode::NumericalOverflow)?;
+            shares.push((*wallet, share));
+        }
+
+        if let Some(last) = shares.last_mut() {
+            last.1 = last
+                .1
+                .checked_add(amount.saturating_sub(allotted))
+                .ok_or(ErrorCode::NumericalOverflow)?;
+        }
+        Ok(shares)
+    }
+}
+
+/// Quarterly profit accrual ledger for one batch
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"accrual", investment_id, version, batch_id]
+/// - accrue_profit credits each quarter's share to per-investor balances (no transfers)
+/// - settle_accrual transfers an investor's accrued balance and zeroes it
+/// 
+/// SECURITY FEATURES:
+/// - last_quarter only increases, so a quarter can never be credited twice
+/// - Outstanding balances are always backed by the vault's USDT at accrual time
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct ProfitAccrualLedger {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub version: [u8; 4],
+
+    /// Batch the ledger accrues for
+    /// AUDIT: Used for PDA derivation
+    pub batch_id: u16,
+
+    /// Last quarter credited (monotonic index, e.g. year × 4 + quarter)
+    /// SECURITY: Each accrual must use a larger index
+    pub last_quarter: u16,
+
+    /// USDT credited across all quarters
+    pub total_accrued_usdt: u64,
+
+    /// USDT transferred by settlements
+    pub total_settled_usdt: u64,
+
+    /// Per-investor balances
+    /// AUDIT: One entry per (account_id, wallet), at most MAX_ENTRIES_PER_BATCH
+    pub entries: Vec<AccrualEntry>,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+}
+
+impl ProfitAccrualLedger {
+    /// Size of one AccrualEntry: 63 bytes
+    pub const ENTRY_SIZE: usize =
+        15 + // account_id
+        32 + // wallet
+        8 +  // accrued_usdt
+        8;   // settled_usdt
+
+    /// Total account size: 1950 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        2 +  // batch_id
+        2 +  // last_quarter
+        8 +  // total_accrued_usdt
+        8 +  // total_settled_usdt
+        4 + (MAX_ENTRIES_PER_BATCH * Self::ENTRY_SIZE) + // entries
+        1 +  // bump
+        8;   // updated_at
+
+    /// USDT credited but not yet settled
+    /// 
+    /// AUDIT: Must stay covered by the vault's USDT balance
+    pub fn outstanding_usdt(&self) -> Result<u64> {
+        self.entries
+            .iter()
+            .try_fold(0u64, |acc, e| acc.checked_add(e.accrued_usdt))
+            .ok_or(ErrorCode::NumericalOverflow.into())
+    }
+}
+
+/// Per-investor balance in a ProfitAccrualLedger
+/// 
+/// AUDIT: accrued_usdt is unsettled; settled_usdt is the running total paid out
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AccrualEntry {
+    /// Investor account identifier
+    pub account_id: [u8; 15],
+
+    /// Recipient wallet (the record wallet at accrual time)
+    pub wallet: Pubkey,
+
+    /// Unsettled USDT balance
+    pub accrued_usdt: u64,
+
+    /// USDT settled so far
+    pub settled_usdt: u64,
+}
+
+/// Program-wide directory of one investor's records
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"investor_directory", sha256(account_id)]; one directory per account_id
+///   across every investment and version
+/// - Appended by add_investment_record / backfill_investment_record in the same transaction
+///   as the record, so it lists every record created since its introduction
+/// - Holds only the hash of account_id, never the identifier itself
+/// 
+/// SECURITY FEATURES:
+/// - Informational only; no instruction reads it for authorization or payouts
+/// - Links are never removed; revocation and payouts are read from the linked records
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct InvestorDirectory {
+    /// SHA-256 of the investor account identifier
+    /// AUDIT: Used for PDA derivation
+    pub account_id_hash: [u8; 32],
+
+    /// Records of this investor, in creation order
+    /// AUDIT: At most MAX_DIRECTORY_ENTRIES
+    pub entries: Vec<DirectoryEntry>,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+}
+
+impl InvestorDirectory {
+    /// Size of one DirectoryEntry: 53 bytes
+    pub const ENTRY_SIZE: usize =
+        15 + // investment_id
+        4 +  // version
+        2 +  // batch_id
+        32;  // record
+
+    /// Total account size: 3445 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        32 + // account_id_hash
+        4 + (MAX_DIRECTORY_ENTRIES * Self::ENTRY_SIZE) + // entries
+        1 +  // bump
+        8;   // updated_at
+
+    /// Seed of an investor's directory PDA
+    /// 
+    /// AUDIT: SHA-256 keeps the raw account_id out of the address derivation inputs
+    pub fn account_id_hash(account_id: &[u8; 15]) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hash(account_id).to_bytes()
+    }
+
+    /// Link a newly created record
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Rejects a record already linked and a full directory
+    pub fn link(&mut self, entry: DirectoryEntry, now: i64) -> Result<()> {
+        require!(
+            !self.entries.iter().any(|e| e.record == entry.record),
+            ErrorCode::DuplicateRecord
+        );
+        require!(
+            self.entries.len() < MAX_DIRECTORY_ENTRIES,
+            ErrorCode::InvestorDirectoryFull
+        );
+        self.entries.push(entry);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Record linked by an InvestorDirectory
+/// 
+/// AUDIT: Identifies the investment and the record PDA; amounts are read from the record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DirectoryEntry {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Investment version
+    pub version: [u8; 4],
+
+    /// Batch of the record
+    pub batch_id: u16,
+
+    /// InvestmentRecord PDA
+    pub record: Pubkey,
+}
+
+/// Additional mints held by an investment vault
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"vault_token_registry", investment_id, version]
+/// - Lists mints beyond the profit mint and H2COIN (e.g. a second reward token)
+///   together with the vault ATA created for each
+/// - Changed only by the update_whitelist multisig through register_vault_mint /
+///   deregister_vault_mint
+/// 
+/// SECURITY FEATURES:
+/// - sweep_foreign_token refuses registered mints, so they cannot be swept as foreign
+/// - A mint is deregistered only once its vault ATA is empty
+/// - Fixed account size prevents overflow
+#[account]
+#[derive()]
+pub struct VaultTokenRegistry {
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Used for PDA derivation
+    pub version: [u8; 4],
+
+    /// Registered mints, in registration order
+    /// AUDIT: At most MAX_VAULT_MINTS; mints are unique
+    pub entries: Vec<VaultMintEntry>,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    /// AUDIT: Used for audit trail
+    pub updated_at: i64,
+
+    /// Creation timestamp
+    /// AUDIT: Used for audit trail
+    pub created_at: i64,
+}
+
+impl VaultTokenRegistry {
+    /// Size of one VaultMintEntry: 73 bytes
+    pub const ENTRY_SIZE: usize =
+        32 + // mint
+        32 + // ata
+        1 +  // decimals
+        8;   // registered_at
+
+    /// Total account size: 632 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        4 + (MAX_VAULT_MINTS * Self::ENTRY_SIZE) + // entries
+        1 +  // bump
+        8 +  // updated_at
+        8;   // created_at
+
+    /// Whether a mint is registered
+    pub fn contains(&self, mint: &Pubkey) -> bool {
+        self.entries.iter().any(|e| e.mint == *mint)
+    }
+
+    /// Register a mint and its vault ATA
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Rejects a mint already registered and a full registry
+    pub fn register(&mut self, entry: VaultMintEntry, now: i64) -> Result<()> {
+        require!(!self.contains(&entry.mint), ErrorCode::VaultMintAlreadyRegistered);
+        require!(
+            self.entries.len() < MAX_VAULT_MINTS,
+            ErrorCode::VaultTokenRegistryFull
+        );
+        self.entries.push(entry);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Remove a registered mint and return its entry
+    /// 
+    /// AUDIT: Registration order of the remaining mints is kept
+    pub fn deregister(&mut self, mint: &Pubkey, now: i64) -> Result<VaultMintEntry> {
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.mint == *mint)
+            .ok_or(error!(ErrorCode::VaultMintNotRegistered))?;
+        self.updated_at = now;
+        Ok(self.entries.remove(position))
+    }
+}
+
+/// Mint registered in a VaultTokenRegistry
+/// 
+/// AUDIT: ata is the canonical vault ATA created at registration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VaultMintEntry {
+    /// Registered mint
+    pub mint: Pubkey,
+
+    /// Vault associated token account of the mint
+    pub ata: Pubkey,
+
+    /// Mint decimals at registration
+    pub decimals: u8,
+
+    /// Registration timestamp
+    pub registered_at: i64,
+}
+
+/// Merkle-root profit distribution of one batch
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"profit_merkle", investment_id, version, batch_id]
+/// - Stores only the root and totals; the entries live off-chain and each investor
+///   proves their own leaf in claim_profit_with_proof
+/// - The root cannot be re-derived on-chain, so it is published by the Executor role
+///   (3-of-5 execute_whitelist until assigned) through estimate_profit_share_merkle
+/// 
+/// SECURITY FEATURES:
+/// - The root is replaceable only until the first claim
+/// - The signed root and subtotal are trusted: leaf amounts are not checked against the records
+/// - Claims never exceed subtotal_profit_usdt; a MerkleClaimReceipt per entry blocks double claims
+/// - Claims stop at expires_at or once the round is cancelled
+/// - Fixed account size regardless of the number of recipients
+#[account]
+#[derive()]
+pub struct ProfitMerkleCache {
+    /// Batch identifier
+    /// AUDIT: Part of every leaf, so proofs of one batch fail in another
+    pub batch_id: u16,
+
+    /// Investment identifier (15 bytes)
+    /// AUDIT: Used for PDA derivation and part of every leaf
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    /// AUDIT: Used for PDA derivation and part of every leaf
+    pub version: [u8; 4],
+
+    /// Root of the entry tree (see ProfitMerkleCache::leaf / verify)
+    /// AUDIT: Never zero
+    pub merkle_root: [u8; 32],
+
+    /// Number of leaves; leaf indices are 0..entry_count
+    pub entry_count: u32,
+
+    /// Sum of all leaf amounts in payout_mint base units
+    /// AUDIT: Upper bound of claimed_usdt, signed by the Executor role
+    pub subtotal_profit_usdt: u64,
+
+    /// Amount claimed so far
+    pub claimed_usdt: u64,
+
+    /// Number of entries claimed so far
+    pub claimed_count: u32,
+
+    /// Mint this round is paid in (profit_mint, H2COIN or a registered vault mint)
+    pub payout_mint: Pubkey,
+
+    /// Decimals of payout_mint at publication
+    pub payout_decimals: u8,
+
+    /// Transaction payer who published the root
+    pub created_by: Pubkey,
+
+    /// Publication timestamp of the current root
+    pub created_at: i64,
+
+    /// Timestamp every entry was claimed (0 while claims remain)
+    pub executed_at: i64,
+
+    /// End of the claim window (created_at + GlobalConfig.share_cache_expire_secs)
+    pub expires_at: i64,
+
+    /// Timestamp cancel_profit_merkle stopped the round (0 = not cancelled)
+    pub cancelled_at: i64,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+}
+
+impl ProfitMerkleCache {
+    /// Total account size: 183 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        2 +  // batch_id
+        15 + // investment_id
+        4 +  // version
+        32 + // merkle_root
+        4 +  // entry_count
+        8 +  // subtotal_profit_usdt
+        8 +  // claimed_usdt
+        4 +  // claimed_count
+        32 + // payout_mint
+        1 +  // payout_decimals
+        32 + // created_by
+        8 +  // created_at
+        8 +  // executed_at
+        8 +  // expires_at
+        8 +  // cancelled_at
+        1;   // bump
+
+    /// Whether entries can still be claimed at `now`
+    /// 
+    /// AUDIT: Not fully claimed, not cancelled and inside the claim window
+    pub fn is_claimable(&self, now: i64) -> bool {
+        self.executed_at == 0 && self.cancelled_at == 0 && now < self.expires_at
+    }
+
+    /// Hash of one entry
+    /// 
+    /// AUDIT CRITICAL:
+    /// - sha256(0x00 || investment_id || version || batch_id || entry_index || account_id || wallet || amount),
+    ///   integers little-endian
+    /// - The 0x00 prefix keeps leaves distinct from inner nodes (0x01)
+    pub fn leaf(
+        &self,
+        entry_index: u32,
+        account_id: &[u8; 15],
+        wallet: &Pubkey,
+        amount: u64,
+    ) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[
+            &[0u8],
+            &self.investment_id,
+            &self.version,
+            &self.batch_id.to_le_bytes(),
+            &entry_index.to_le_bytes(),
+            account_id,
+            wallet.as_ref(),
+            &amount.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Whether a proof leads from a leaf to merkle_root
+    /// 
+    /// AUDIT CRITICAL:
+    /// - Inner node = sha256(0x01 || min(a, b) || max(a, b)); sorted pairs, so the
+    ///   proof carries no left/right flags
+    /// - Proofs longer than MAX_MERKLE_PROOF_LEN are rejected
+    pub fn verify(&self, proof: &[[u8; 32]], leaf: [u8; 32]) -> bool {
+        if proof.len() > MAX_MERKLE_PROOF_LEN {
+            return false;
+        }
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            let (a, b) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+            anchor_lang::solana_program::hash::hashv(&[&[1u8], &a, &b]).to_bytes()
+        });
+        root == self.merkle_root
+    }
+}
+
+/// Claim marker of one Merkle profit entry
+/// 
+/// AUDIT CRITICAL:
+/// - Seeds: [b"merkle_claim", investment_id, version, batch_id, entry_index]
+/// - Created (`init`) by claim_profit_with_proof, so a second claim of the same entry fails
+/// - Rent is paid by the claimant
+#[account]
+#[derive()]
+pub struct MerkleClaimReceipt {
+    /// Investment identifier (15 bytes)
+    pub investment_id: [u8; 15],
+
+    /// Version identifier (4 bytes)
+    pub version: [u8; 4],
+
+    /// Batch of the ProfitMerkleCache
+    pub batch_id: u16,
+
+    /// Claimed leaf index
+    pub entry_index: u32,
+
+    /// Claiming wallet
+    pub wallet: Pubkey,
+
+    /// Amount transferred
+    pub amount: u64,
+
+    /// Claim timestamp
+    pub claimed_at: i64,
+
+    /// Canonical bump of this PDA
+    pub bump: u8,
+}
+
+impl MerkleClaimReceipt {
+    /// Total account size: 82 bytes
+    pub const SIZE: usize =
+        8 +  // discriminator
+        15 + // investment_id
+        4 +  // version
+        2 +  // batch_id
+        4 +  // entry_index
+        32 + // wallet
+        8 +  // amount
+        8 +  // claimed_at
+        1;   // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Account of the given size with every field zeroed, as after `init`
+    fn zeroed<T: AnchorDeserialize>(size: usize) -> T {
+        T::deserialize(&mut &vec![0u8; size][..]).unwrap()
+    }
+
+    fn investment_info() -> InvestmentInfo {
+        let mut info: InvestmentInfo = zeroed(InvestmentInfo::SIZE);
+        info.investment_upper_limit = u64::MAX;
+        info
+    }
+
+    fn merkle_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        anchor_lang::solana_program::hash::hashv(&[&[1u8], &lo, &hi]).to_bytes()
+    }
+
+    #[test]
+    fn weighted_profit_usdt_is_identity_while_multipliers_are_unset() {
+        let info = investment_info();
+        for stage in 0..=MAX_STAGE as u8 + 1 {
+            assert_eq!(
+                info.weighted_profit_usdt(stage, 1_000_000).unwrap(),
+                (1_000_000, PROFIT_STAGE_MULTIPLIER_ONE_BP)
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_profit_usdt_applies_the_stage_multiplier() {
+        let mut info = investment_info();
+        info.profit_stage_multipliers_bp = [15_000, 12_000, 10_000];
+
+        assert_eq!(info.weighted_profit_usdt(1, 1_000_000).unwrap(), (1_500_000, 15_000));
+        assert_eq!(info.weighted_profit_usdt(2, 1_000_001).unwrap(), (1_200_001, 12_000));
+        assert_eq!(info.weighted_profit_usdt(3, 1_000_000).unwrap(), (1_000_000, 10_000));
+        // Stages outside 1..=MAX_STAGE are weighted 1×
+        assert_eq!(info.weighted_profit_usdt(0, 7).unwrap(), (7, PROFIT_STAGE_MULTIPLIER_ONE_BP));
+        assert_eq!(info.weighted_profit_usdt(4, 7).unwrap(), (7, PROFIT_STAGE_MULTIPLIER_ONE_BP));
+        assert!(info.weighted_profit_usdt(1, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn register_investment_enforces_the_upper_limit() {
+        let mut info = investment_info();
+        info.investment_upper_limit = 100;
+
+        info.register_investment(1, 60).unwrap();
+        assert!(info.register_investment(2, 41).is_err());
+        assert_eq!(info.total_invested_usdt().unwrap(), 60);
+
+        info.register_investment(2, 40).unwrap();
+        assert_eq!(info.total_invested_usdt().unwrap(), 100);
+    }
+
+    #[test]
+    fn active_profit_denominator_follows_deactivation_and_activation() {
+        let mut info = investment_info();
+        info.register_investment(1, 100).unwrap();
+        info.register_investment(2, 200).unwrap();
+        assert_eq!(info.active_profit_denominator().unwrap(), 300);
+
+        info.deactivate_investment(2, 50).unwrap();
+        assert_eq!(info.active_profit_denominator().unwrap(), 250);
+        assert_eq!(info.total_invested_usdt().unwrap(), 300);
+
+        info.activate_investment(2, 50).unwrap();
+        assert_eq!(info.active_profit_denominator().unwrap(), 300);
+    }
+
+    #[test]
+    fn active_profit_denominator_is_stage_weighted() {
+        let mut info = investment_info();
+        info.register_investment(1, 100).unwrap();
+        info.register_investment(2, 200).unwrap();
+        info.register_investment(3, 300).unwrap();
+        info.profit_stage_multipliers_bp = [15_000, 12_000, 10_000];
+
+        assert_eq!(info.active_profit_denominator().unwrap(), 150 + 240 + 300);
+
+        // The weighted shares of all records never exceed the denominator
+        let shares: u64 = [(1, 100), (2, 200), (3, 300)]
+            .iter()
+            .map(|(stage, amount)| info.weighted_profit_usdt(*stage, *amount).unwrap().0)
+            .sum();
+        assert!(shares <= info.active_profit_denominator().unwrap());
+    }
+
+    #[test]
+    fn deactivate_investment_is_bounded_by_the_invested_amount() {
+        let mut info = investment_info();
+        info.register_investment(1, 100).unwrap();
+
+        assert!(info.deactivate_investment(1, 101).is_err());
+        assert!(info.deactivate_investment(2, 1).is_err());
+        info.deactivate_investment(1, 100).unwrap();
+        assert_eq!(info.active_profit_denominator().unwrap(), 0);
+
+        // Activation past the inactive amount means the aggregate drifted
+        info.activate_investment(1, 100).unwrap();
+        assert!(info.activate_investment(1, 1).is_err());
+    }
+
+    #[test]
+    fn compute_share_truncates_ratio_and_amount() {
+        assert_eq!(ProfitShareCache::compute_share(250, 10_000, 1_000).unwrap(), (2_500, 2_500));
+        assert_eq!(ProfitShareCache::compute_share(1, 1_000_000, 3).unwrap(), (3_333, 333_300));
+        assert_eq!(ProfitShareCache::compute_share(1, 1_000_000, 20_001).unwrap(), (0, 0));
+        assert!(ProfitShareCache::compute_share(7_000, 1_000, 1_000).is_err());
+    }
+
+    #[test]
+    fn compute_share_never_exceeds_the_round_profit() {
+        let amounts = [333_333u64, 333_333, 333_334];
+        let total: u64 = amounts.iter().sum();
+        let paid: u64 = amounts
+            .iter()
+            .map(|amount| ProfitShareCache::compute_share(*amount, 1_000_000, total).unwrap().1)
+            .sum();
+        assert!(paid <= 1_000_000);
+    }
+
+    #[test]
+    fn merkle_proofs_verify_every_leaf_and_nothing_else() {
+        let mut cache: ProfitMerkleCache = zeroed(ProfitMerkleCache::SIZE);
+        cache.investment_id = *b"INV-TEST-000001";
+        cache.version = *b"v001";
+        cache.batch_id = 7;
+
+        let wallets = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let account_id = *b"ACCOUNT-0000001";
+        let leaves: Vec<[u8; 32]> = wallets
+            .iter()
+            .enumerate()
+            .map(|(index, wallet)| cache.leaf(index as u32, &account_id, wallet, 1_000))
+            .collect();
+
+        // An odd node is carried up: root = node(node(l0, l1), l2)
+        let left = merkle_node(leaves[0], leaves[1]);
+        cache.merkle_root = merkle_node(left, leaves[2]);
+
+        assert!(cache.verify(&[leaves[1], leaves[2]], leaves[0]));
+        assert!(cache.verify(&[leaves[0], leaves[2]], leaves[1]));
+        assert!(cache.verify(&[left], leaves[2]));
+
+        // Another amount, index or wallet is a different leaf
+        assert!(!cache.verify(&[leaves[1], leaves[2]], cache.leaf(0, &account_id, &wallets[0], 1_001)));
+        assert!(!cache.verify(&[leaves[0], leaves[2]], cache.leaf(0, &account_id, &wallets[1], 1_000)));
+        assert!(!cache.verify(&[leaves[1], leaves[2]], cache.leaf(0, &account_id, &wallets[1], 1_000)));
+        // A proof for one leaf does not verify another
+        assert!(!cache.verify(&[leaves[1], leaves[2]], leaves[1]));
+    }
+
+    #[test]
+    fn merkle_leaf_binds_the_round() {
+        let mut cache: ProfitMerkleCache = zeroed(ProfitMerkleCache::SIZE);
+        let wallet = Pubkey::new_unique();
+        let account_id = [1u8; 15];
+        let leaf = cache.leaf(0, &account_id, &wallet, 1_000);
+
+        cache.batch_id = 1;
+        assert_ne!(cache.leaf(0, &account_id, &wallet, 1_000), leaf);
+        cache.batch_id = 0;
+        cache.version = *b"v002";
+        assert_ne!(cache.leaf(0, &account_id, &wallet, 1_000), leaf);
+    }
+
+    #[test]
+    fn merkle_proofs_longer_than_the_limit_are_rejected() {
+        let mut cache: ProfitMerkleCache = zeroed(ProfitMerkleCache::SIZE);
+        let leaf = cache.leaf(0, &[0u8; 15], &Pubkey::new_unique(), 1);
+        let proof = vec![[9u8; 32]; MAX_MERKLE_PROOF_LEN + 1];
+        let root_of = |proof: &[[u8; 32]]| proof.iter().fold(leaf, |node, sibling| merkle_node(node, *sibling));
+
+        cache.merkle_root = root_of(&proof[..MAX_MERKLE_PROOF_LEN]);
+        assert!(cache.verify(&proof[..MAX_MERKLE_PROOF_LEN], leaf));
+
+        cache.merkle_root = root_of(&proof);
+        assert!(!cache.verify(&proof, leaf));
+    }
 }
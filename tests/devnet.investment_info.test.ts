@@ -95,6 +95,16 @@ describe("Investment Info", async function() {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
 			[
@@ -124,16 +134,20 @@ describe("Investment Info", async function() {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -186,15 +200,16 @@ describe("Investment Info", async function() {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ investment info Summary:`);
 		console.log(`${indent}	investmentId:`, bytesToFixedString(investmentInfo.investmentId));
 		console.log(`${indent}	version:`, Buffer.from(version).toString('hex'));
 		console.log(`${indent}	investmentType:`, Object.keys(investmentInfo.investmentType)[0]);
-		console.log(`${indent}	stageRatio:`, investmentInfo.stageRatio.toString());
+		console.log(`${indent}	stageRatio:`, investmentConfig.stageRatio.toString());
 		console.log(`${indent}	investmentUpperLimit:`, investmentInfo.investmentUpperLimit.toString());
-		console.log(`${indent}	executeWhitelist:`, investmentInfo.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	updateWhitelist:`, investmentInfo.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	withdrawWhitelist:`, investmentInfo.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	executeWhitelist:`, investmentConfig.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	updateWhitelist:`, investmentConfig.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	withdrawWhitelist:`, investmentConfig.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
 		console.log(`${indent}	state:`, Object.keys(investmentInfo.state)[0]);
 		console.log(`${indent}	startAt:`, new Date(investmentInfo.startAt.toNumber()*1000));
 		console.log(`${indent}	endAt:`, new Date(investmentInfo.endAt.toNumber()*1000));
@@ -251,6 +266,16 @@ describe("Investment Info", async function() {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
 			[
@@ -280,16 +305,20 @@ describe("Investment Info", async function() {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -342,15 +371,16 @@ describe("Investment Info", async function() {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ (0) Initialize investment info:`, {
 			investmentId: bytesToFixedString(investmentInfo.investmentId),
 			version: Buffer.from(version).toString('hex'),
 			investmentType: Object.keys(investmentInfo.investmentType)[0],
-			stageRatio: investmentInfo.stageRatio.toString(),
+			stageRatio: investmentConfig.stageRatio.toString(),
 			investmentUpperLimit: investmentInfo.investmentUpperLimit.toString(),
-			executeWhitelist: investmentInfo.executeWhitelist.map(r=>r.toBase58()).join(', '),
-			updateWhitelist: investmentInfo.updateWhitelist.map(r=>r.toBase58()).join(', '),
-			withdrawWhitelist: investmentInfo.withdrawWhitelist.map(r=>r.toBase58()).join(', '),
+			executeWhitelist: investmentConfig.executeWhitelist.map(r=>r.toBase58()).join(', '),
+			updateWhitelist: investmentConfig.updateWhitelist.map(r=>r.toBase58()).join(', '),
+			withdrawWhitelist: investmentConfig.withdrawWhitelist.map(r=>r.toBase58()).join(', '),
 			state: Object.keys(investmentInfo.state)[0],
 			startAt: new Date(investmentInfo.startAt.toNumber()*1000),
 			endAt: new Date(investmentInfo.endAt.toNumber()*1000),
@@ -373,6 +403,7 @@ describe("Investment Info", async function() {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 		const newStageRatioRows = [
 			{ mid: 6.0, last: 4.0 },
@@ -389,9 +420,14 @@ describe("Investment Info", async function() {
 			.updateInvestmentInfo(
 				newStageRatio,
 				newUpperLimit,
+				null,
+				null,
+				null,
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				proposal: null,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -416,11 +452,12 @@ describe("Investment Info", async function() {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ (2) Update investment info works:`, {
 			investmentId: bytesToFixedString(investmentInfo.investmentId),
 			version: investmentInfo.version.map(n => n.toString(16).padStart(2, '0')).join(''),
 			investmentType: Object.keys(investmentInfo.investmentType)[0],
-			stageRatio: investmentInfo.stageRatio.toString(),
+			stageRatio: investmentConfig.stageRatio.toString(),
 			investmentUpperLimit: investmentInfo.investmentUpperLimit.toString(),
 			state: Object.keys(investmentInfo.state)[0],
 			isActive: investmentInfo.isActive,
@@ -443,12 +480,14 @@ describe("Investment Info", async function() {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda; // 10 million H2coin (6 decimals)
+		const investmentConfigPda = R.investmentConfigPda;
 
 		try {
 			const tx = await program.methods
 			.completedInvestmentInfo()
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -489,6 +528,7 @@ describe("Investment Info", async function() {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 		const newStageRatioRows = [
 			{ mid: 1.0, last: 4.0 },
@@ -504,9 +544,14 @@ describe("Investment Info", async function() {
 			.updateInvestmentInfo(
 				newStageRatio,
 				newUpperLimit,
+				null,
+				null,
+				null,
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				proposal: null,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -531,11 +576,12 @@ describe("Investment Info", async function() {
 		
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ (4) Fail update investment info:`, {
 			investmentId: bytesToFixedString(investmentInfo.investmentId),
 			version: investmentInfo.version.map(n => n.toString(16).padStart(2, '0')).join(''),
 			investmentType: Object.keys(investmentInfo.investmentType)[0],
-			stageRatio: investmentInfo.stageRatio.toString(),
+			stageRatio: investmentConfig.stageRatio.toString(),
 			investmentUpperLimit: investmentInfo.investmentUpperLimit.toString(),
 			state: Object.keys(investmentInfo.state)[0],
 			isActive: investmentInfo.isActive,
@@ -555,12 +601,14 @@ describe("Investment Info", async function() {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda; // 10 million H2coin (6 decimals)
+		const investmentConfigPda = R.investmentConfigPda;
 
 		try {
 			const tx = await program.methods
 			.deactivateInvestmentInfo()
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -598,6 +646,7 @@ describe("Investment Info", async function() {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		
 		const updateWhistLists = loadUpdateWhitelistKeypairs();
 		const threeSigners = updateWhistLists.slice(0, 3);
@@ -609,9 +658,10 @@ describe("Investment Info", async function() {
 		let caught = false;
 		try {
 			await program.methods
-			.patchUpdateWhitelist()
+			.patchUpdateWhitelist(stringToFixedU8Array("Updater", 16), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
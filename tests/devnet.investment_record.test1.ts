@@ -34,7 +34,7 @@ import {
 import { stringToFixedU8Array, stage_ratio_map, 
 	loadExecuteWhitelistKeypairs, loadUpdateWhitelistKeypairs, 
 	loadWithdrawWhitelistKeypairs, 
-	bytesToFixedString,	u16ToLEBytes
+	bytesToFixedString,	u16ToLEBytes, investorDirectoryPda
 } from "./lib/lib";
 import {Runtime as R} from "./runtime";
 
@@ -110,6 +110,16 @@ describe("Investment Record management", async () => {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
 			[
@@ -139,16 +149,20 @@ describe("Investment Record management", async () => {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -188,15 +202,16 @@ describe("Investment Record management", async () => {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ investment info Summary:`);
 		console.log(`${indent}	investmentId:`, bytesToFixedString(investmentInfo.investmentId));
 		console.log(`${indent}	version:`, Buffer.from(version).toString('hex'));
 		console.log(`${indent}	investmentType:`, Object.keys(investmentInfo.investmentType)[0]);
-		console.log(`${indent}	stageRatio:`, investmentInfo.stageRatio.toString());
+		console.log(`${indent}	stageRatio:`, investmentConfig.stageRatio.toString());
 		console.log(`${indent}	investmentUpperLimit:`, investmentInfo.investmentUpperLimit.toString());
-		console.log(`${indent}	executeWhitelist:`, investmentInfo.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	updateWhitelist:`, investmentInfo.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	withdrawWhitelist:`, investmentInfo.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	executeWhitelist:`, investmentConfig.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	updateWhitelist:`, investmentConfig.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	withdrawWhitelist:`, investmentConfig.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
 		console.log(`${indent}	state:`, Object.keys(investmentInfo.state)[0]);
 		console.log(`${indent}	startAt:`, new Date(investmentInfo.startAt.toNumber()*1000));
 		console.log(`${indent}	endAt:`, new Date(investmentInfo.endAt.toNumber()*1000));
@@ -215,6 +230,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const investmentRecordPdas:PublicKey[] = [];
 		const version = R.version;	
 		const payer = provider.wallet;
@@ -300,11 +316,16 @@ describe("Investment Record management", async () => {
 						accountIdBytes,
 						new Anchor.BN(amountUsdt),
 						new Anchor.BN(amountHcoin),
-						1
+						1,
+						false,
+						false
 					)
 					.accounts({
 						investmentInfo: investmentInfoPda,
+						investmentConfig: investmentConfigPda,
+						roleConfig: null,
 						investmentRecord: recordPda,
+						investorDirectory: investorDirectoryPda(accountIdBytes, program.programId),
 
 						usdtMint: usdt_mint,
 						hcoinMint: h2coin_mint,
@@ -412,6 +433,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const investmentRecordPdas:PublicKey[] = [];
 		const version = R.version;	
 		const payer = provider.wallet;
@@ -480,9 +502,14 @@ describe("Investment Record management", async () => {
 			const ix = await program.methods
 			.updateInvestmentRecordWallets(
 				fix_account_id,
+				{ investorRequest: {} },
+				null,
+				false,
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
@@ -573,6 +600,7 @@ describe("Investment Record management", async () => {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda; // 10 million H2coin (6 decimals)
+		const investmentConfigPda = R.investmentConfigPda;
 
 		const before_record_list = await program.account.investmentRecord.all([
 			{
@@ -609,10 +637,13 @@ describe("Investment Record management", async () => {
 			.revokedInvestmentRecord(
 				before_record.batchId,
 				before_record.recordId,
-				before_record.accountId
+				before_record.accountId,
+				{ dataCorrection: {} },
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -668,6 +699,28 @@ describe("Investment Record management", async () => {
 				"InvestmentInfoNotCompleted"
 			]);
 		}
+
+		// The revoked record stays in the profit denominator until its hold is over
+		try {
+			await program.methods
+			.releaseRevocationHold(
+				before_record.batchId,
+				before_record.recordId,
+				before_record.accountId,
+			)
+			.accounts({
+				investmentInfo: investmentInfoPda,
+				payer: provider.wallet.publicKey,
+			} as any)
+			.rpc();
+			expect.fail("releasing a held revocation must fail");
+		} catch (e:any) {
+			expect(e).to.have.property("error");
+			expect(e.error.errorCode.code, `Actual code: ${e.error.errorCode.code}`).to.be.oneOf([
+				"RevocationStillHeld",
+				"RevocationHoldAlreadyReleased"
+			]);
+		}
 	});
 
 	it("(3) Set investment state to complete", async function() {
@@ -677,6 +730,7 @@ describe("Investment Record management", async () => {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 
 		try {
@@ -684,6 +738,7 @@ describe("Investment Record management", async () => {
 			.completedInvestmentInfo()
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -716,6 +771,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const investmentRecordPdas:PublicKey[] = [];
 		const version = R.version;	
 		const payer = provider.wallet;
@@ -784,9 +840,14 @@ describe("Investment Record management", async () => {
 			const ix = await program.methods
 			.updateInvestmentRecordWallets(
 				fix_account_id,
+				{ investorRequest: {} },
+				null,
+				false,
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
@@ -950,6 +1011,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const usdt_mint = R.usdt_mint;
 		const payer = provider.wallet.publicKey;
@@ -1007,11 +1069,14 @@ describe("Investment Record management", async () => {
 		let errorCaught = false;
 		try {
 			const estimateIx = await program.methods
-			.estimateProfitShare(1, totalProfitUsdt, totalInvestUsdt)
+			.estimateProfitShare(1, totalProfitUsdt, null, null, false)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				mint: usdt_mint,
 				cache: cachePda,
+				vaultTokenRegistry: null,
 				payer: provider.wallet.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
 			} as any)
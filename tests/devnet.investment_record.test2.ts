@@ -36,7 +36,7 @@ import { getAssociatedTokenAddress,
 import { stringToFixedU8Array, stage_ratio_map, 
 	loadExecuteWhitelistKeypairs, loadUpdateWhitelistKeypairs, 
 	loadWithdrawWhitelistKeypairs, 
-	bytesToFixedString,	u16ToLEBytes
+	bytesToFixedString,	u16ToLEBytes, investorDirectoryPda
 } from "./lib/lib";
 import {Runtime as R} from "./runtime";
 
@@ -114,6 +114,16 @@ describe("Investment Record management", async () => {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
 			[
@@ -143,16 +153,20 @@ describe("Investment Record management", async () => {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -192,15 +206,16 @@ describe("Investment Record management", async () => {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ investment info Summary:`);
 		console.log(`${indent}	investmentId:`, bytesToFixedString(investmentInfo.investmentId));
 		console.log(`${indent}	version:`, Buffer.from(version).toString('hex'));
 		console.log(`${indent}	investmentType:`, Object.keys(investmentInfo.investmentType)[0]);
-		console.log(`${indent}	stageRatio:`, investmentInfo.stageRatio.toString());
+		console.log(`${indent}	stageRatio:`, investmentConfig.stageRatio.toString());
 		console.log(`${indent}	investmentUpperLimit:`, investmentInfo.investmentUpperLimit.toString());
-		console.log(`${indent}	executeWhitelist:`, investmentInfo.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	updateWhitelist:`, investmentInfo.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	withdrawWhitelist:`, investmentInfo.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	executeWhitelist:`, investmentConfig.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	updateWhitelist:`, investmentConfig.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	withdrawWhitelist:`, investmentConfig.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
 		console.log(`${indent}	state:`, Object.keys(investmentInfo.state)[0]);
 		console.log(`${indent}	startAt:`, new Date(investmentInfo.startAt.toNumber()*1000));
 		console.log(`${indent}	endAt:`, new Date(investmentInfo.endAt.toNumber()*1000));
@@ -219,6 +234,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const investmentRecordPdas:PublicKey[] = [];
 		const version = R.version;	
 		const payer = provider.wallet;
@@ -302,11 +318,16 @@ describe("Investment Record management", async () => {
 						accountIdBytes,
 						new Anchor.BN(amountUsdt),
 						new Anchor.BN(amountHcoin),
-						1
+						1,
+						false,
+						false
 					)
 					.accounts({
 						investmentInfo: investmentInfoPda,
+						investmentConfig: investmentConfigPda,
+						roleConfig: null,
 						investmentRecord: recordPda,
+						investorDirectory: investorDirectoryPda(accountIdBytes, program.programId),
 
 						usdtMint: usdt_mint,
 						hcoinMint: h2coin_mint,
@@ -412,6 +433,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const investmentRecordPdas:PublicKey[] = [];
 		const version = R.version;	
 		const payer = provider.wallet;
@@ -478,9 +500,11 @@ describe("Investment Record management", async () => {
 		// ✅ Verification
 		try {
 			const ix = await program.methods
-			.updateInvestmentRecordWallets(fix_account_id)
+			.updateInvestmentRecordWallets(fix_account_id, { investorRequest: {} }, null, false)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
@@ -572,6 +596,7 @@ describe("Investment Record management", async () => {
 		const investmentId = R.investmentId;
 		const version = R.version;
 		const investmentInfoPda = R.investmentInfoPda; // 10 million H2coin (6 decimals)
+		const investmentConfigPda = R.investmentConfigPda;
 
 		const before_record_list = await program.account.investmentRecord.all([
 			{
@@ -608,10 +633,13 @@ describe("Investment Record management", async () => {
 			.revokedInvestmentRecord(
 				before_record.batchId,
 				before_record.recordId,
-				before_record.accountId
+				before_record.accountId,
+				{ dataCorrection: {} },
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -679,6 +707,7 @@ describe("Investment Record management", async () => {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 
 		try {
@@ -686,6 +715,7 @@ describe("Investment Record management", async () => {
 			.completedInvestmentInfo()
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -718,6 +748,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const investmentRecordPdas:PublicKey[] = [];
 		const version = R.version;	
 		const payer = provider.wallet;
@@ -783,9 +814,11 @@ describe("Investment Record management", async () => {
 
 		try {
 			const ix = await program.methods
-			.updateInvestmentRecordWallets(fix_account_id)
+			.updateInvestmentRecordWallets(fix_account_id, { investorRequest: {} }, null, false)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
@@ -952,6 +985,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const payer = provider.wallet.publicKey;
 		const totalProfitUsdt = new Anchor.BN(1_000_000_000_000);
@@ -1004,10 +1038,13 @@ describe("Investment Record management", async () => {
 
 		try {
 			const estimateIx = await program.methods
-			.estimateProfitShare(batchId, totalProfitUsdt, totalInvestUsdt)
+			.estimateProfitShare(batchId, totalProfitUsdt, null, null, false)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				cache: cachePda,
+				vaultTokenRegistry: null,
 				payer: provider.wallet.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
 			} as any)
@@ -1118,6 +1155,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const payer = provider.wallet.publicKey;
 		const lookupTableAddress = R.lookupTableMap.get('record')!.get(batchId);		
@@ -1163,9 +1201,11 @@ describe("Investment Record management", async () => {
 		let errorCaught = false;
 		try {
 			const estimateIx = await program.methods
-			.estimateRefundShare(batchId, yearIndex)
+			.estimateRefundShare(batchId, yearIndex, null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				cache: cachePda,
 				payer: provider.wallet.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -1335,6 +1375,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const usdtMint = R.usdt_mint;
 		const h2coinMint = R.h2coin_mint;
@@ -1405,7 +1446,7 @@ describe("Investment Record management", async () => {
 
 		try {
 			const ix1 = await program.methods
-				.depositSolToVault(subtotalEstimateSol)
+				.depositSolToVault(subtotalEstimateSol, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
 					payer,
@@ -1419,7 +1460,7 @@ describe("Investment Record management", async () => {
 			const fromUsdtAta = await getAssociatedTokenAddress(usdtMint, payer);
 			const vaultUsdtAta = await getAssociatedTokenAddress(usdtMint, vaultPda, true);
 			const ix2 = await program.methods
-				.depositTokenToVault(subtotalProfitUsdt)
+				.depositTokenToVault(subtotalProfitUsdt, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
 					mint: usdtMint,
@@ -1438,7 +1479,7 @@ describe("Investment Record management", async () => {
 			const fromHcoinAta = await getAssociatedTokenAddress(h2coinMint, payer);
 			const vaultHcoinAta = await getAssociatedTokenAddress(h2coinMint, vaultPda, true);
 			const ix3 = await program.methods
-				.depositTokenToVault(subtotalRefundHcoin)
+				.depositTokenToVault(subtotalRefundHcoin, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
 					mint: h2coinMint,
@@ -1496,6 +1537,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const lookupTableAddress = R.lookupTableMap.get('profit')!.get(batchId);
 		const usdtMint = R.usdt_mint;
@@ -1543,10 +1585,14 @@ describe("Investment Record management", async () => {
 		try {
 			const computeIx = modifyComputeUnits;
 
+			const { configHash } = await program.account.investmentInfo.fetch(investmentInfoPda);
 			const execIx = await program.methods
-				.executeProfitShare(batchId)
+				.executeProfitShare(batchId, false, configHash, null, null)
 				.accounts({
+					schedule: null,
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					mint: usdtMint,
 					cache: cachePda,
 					vault: vaultPda,
@@ -1723,6 +1769,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const lookupTableAddress = R.lookupTableMap.get('refund')!.get(batchId);
 		const h2coin_mint = R.h2coin_mint;
@@ -1767,10 +1814,14 @@ describe("Investment Record management", async () => {
 			.then(res => res.value!);
 
 
+			const { configHash } = await program.account.investmentInfo.fetch(investmentInfoPda);
 			const execIx = await program.methods
-				.executeRefundShare(batchId, yearIndex)
+				.executeRefundShare(batchId, yearIndex, false, configHash, null, null)
 				.accounts({
+					schedule: null,
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					mint: h2coin_mint,
 					cache: cachePda,
 					vault: vaultPda,
@@ -1856,6 +1907,7 @@ describe("Investment Record management", async () => {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const usdt_mint = R.usdt_mint;
 		const h2coin_mint = R.h2coin_mint;
@@ -1911,11 +1963,16 @@ describe("Investment Record management", async () => {
 		const RecipientHcoinAta = await getAssociatedTokenAddress(h2coin_mint, recipient);
 
 
+		// Current withdrawal sequence (anti-replay)
+		const infoBefore = await program.account.investmentInfo.fetch(investmentInfoPda);
+
 		// Withdraw instruction
 		const withdrawIx = await program.methods
-			.withdrawFromVault()
+			.withdrawFromVault(infoBefore.withdrawSequence, { all: {} }, { treasury: {} }, null, null, null, null, infoBefore.configHash)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
@@ -1926,6 +1983,7 @@ describe("Investment Record management", async () => {
 				recipientAccount: recipient,
 				recipientUsdtAccount: RecipientUsdtAta,
 				recipientHcoinAccount: RecipientHcoinAta,
+				proposal: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -21,6 +21,7 @@ import { expect } from "chai";
 import {describe, it} from "mocha";
 import bs58 from "bs58";
 import TrimId from "trimid";
+import { createHash } from "crypto";
 import * as Anchor from "@coral-xyz/anchor";
 import { 
 	ComputeBudgetProgram, 
@@ -34,9 +35,9 @@ import {
 } from "@solana/spl-token";
 
 import {
-	stringToFixedU8Array, bytesToFixedString, stage_ratio_map, u16ToLEBytes,
+	stringToFixedU8Array, bytesToFixedString, stage_ratio_map, u16ToLEBytes, u32ToLEBytes,
 	loadUpdateWhitelistKeypairs, loadExecuteWhitelistKeypairs,
-	loadWithdrawWhitelistKeypairs
+	loadWithdrawWhitelistKeypairs, investorDirectoryPda
 } from "./lib/lib";
 import {Runtime as R} from "./runtime";
 
@@ -56,6 +57,7 @@ describe("Profit/Refund share Management", function () {
 	const MAX_ENTRIES = 1500;
 	const MAX_ENTRIES_PER_BATCH = 30;
 	const MAX_RECORDS_PER_TX = 2;
+	const STAGE_MULTIPLIERS_BP = [15_000, 12_000, 10_000];
 
 	const STAGE = 3;
 
@@ -118,6 +120,16 @@ describe("Profit/Refund share Management", function () {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		// Derive the vaultPda PDA for a specific investmentId and version
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
@@ -148,16 +160,20 @@ describe("Profit/Refund share Management", function () {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -197,15 +213,16 @@ describe("Profit/Refund share Management", function () {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ investment info Summary:`);
 		console.log(`${indent}	investmentId:`, bytesToFixedString(investmentInfo.investmentId));
 		console.log(`${indent}	version:`, Buffer.from(version).toString('hex'));
 		console.log(`${indent}	investmentType:`, Object.keys(investmentInfo.investmentType)[0]);
-		console.log(`${indent}	stageRatio:`, investmentInfo.stageRatio.toString());
+		console.log(`${indent}	stageRatio:`, investmentConfig.stageRatio.toString());
 		console.log(`${indent}	investmentUpperLimit:`, investmentInfo.investmentUpperLimit.toString());
-		console.log(`${indent}	executeWhitelist:`, investmentInfo.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	updateWhitelist:`, investmentInfo.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	withdrawWhitelist:`, investmentInfo.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	executeWhitelist:`, investmentConfig.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	updateWhitelist:`, investmentConfig.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	withdrawWhitelist:`, investmentConfig.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
 		console.log(`${indent}	state:`, Object.keys(investmentInfo.state)[0]);
 		console.log(`${indent}	startAt:`, new Date(investmentInfo.startAt.toNumber()*1000));
 		console.log(`${indent}	endAt:`, new Date(investmentInfo.endAt.toNumber()*1000));
@@ -225,6 +242,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;	
 		const payer = provider.wallet;
 		const usdt_mint = R.usdt_mint;
@@ -313,11 +331,16 @@ describe("Profit/Refund share Management", function () {
 					accountIdBytes,
 					amountUsdt,
 					amountHcoin,
-					STAGE
+					STAGE,
+					false,
+					false
 				)
 				.accounts({
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					investmentRecord: recordPda,
+					investorDirectory: investorDirectoryPda(accountIdBytes, program.programId),
 
 					usdtMint: usdt_mint,
 					hcoinMint: h2coin_mint,
@@ -424,12 +447,14 @@ describe("Profit/Refund share Management", function () {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda; // 10 million USDT (6 decimals)
+		const investmentConfigPda = R.investmentConfigPda;
 
 		try {
 			const tx = await program.methods
 			.completedInvestmentInfo()
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts(
@@ -566,6 +591,27 @@ describe("Profit/Refund share Management", function () {
 		}
 	});
 
+	it("(2a) Weight profit shares by investment stage", async function () {
+		this.timeout(1000 * 60 * 5); // 5 minutes timeout
+		const indent = ResolveIndent(this, 1);
+
+		// Stage 1 earns 1.5×, stage 2 1.2×, stage 3 1×; (3) estimates with these and (3a) resets them
+		const sig = await R.program.methods
+			.setProfitStageMultipliers(STAGE_MULTIPLIERS_BP)
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+				investmentConfig: R.investmentConfigPda,
+				payer: R.provider.wallet.publicKey,
+			} as any)
+			.remainingAccounts(threeUpdateSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true })))
+			.signers(threeUpdateSigners)
+			.rpc();
+
+		const info = await R.program.account.investmentInfo.fetch(R.investmentInfoPda);
+		expect(info.profitStageMultipliersBp).to.deep.equal(STAGE_MULTIPLIERS_BP);
+		console.log(`${indent}✅ Stage multipliers set to ${STAGE_MULTIPLIERS_BP.join("/")} bp: ${sig}`);
+	});
+
 	it("(3) Estimate profit share using ALT with standard type", async function () {
 		this.timeout(1000 * 60 * 30); // 30 minutes timeout
 		const indent = ResolveIndent(this, 1);
@@ -576,6 +622,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const payer = provider.wallet.publicKey;
 		const totalProfitUsdt = new Anchor.BN(5_000_000_000_000); // = 1,000,000 USDT (6 decimals)
@@ -649,10 +696,14 @@ describe("Profit/Refund share Management", function () {
 
 			try {
 				const estimateIx = await program.methods
-				.estimateProfitShare(batchId, totalProfitUsdt, totalInvestUsdt)
+				.estimateProfitShare(batchId, totalProfitUsdt, null, null, false)
 				.accounts({
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					cache: cachePda,
+					merkleCache: merklePdaOf(batchIdBytes),
+					vaultTokenRegistry: null,
 					payer: provider.wallet.publicKey,
 					systemProgram: Anchor.web3.SystemProgram.programId,
 				} as any)
@@ -714,6 +765,12 @@ describe("Profit/Refund share Management", function () {
 					const info = await program.account.investmentInfo.fetch(investmentInfoPda);
 					const cache = await program.account.profitShareCache.fetch(cachePda);
 	
+					// The denominator is the stage-weighted active total of InvestmentInfo, not the record sum
+					expect(cache.totalInvestUsdt.toString()).to.equal(weightedDenominator(info).toString());
+					for (const entry of cache.entries) {
+						expect(STAGE_MULTIPLIERS_BP).to.include(entry.multiplierBp);
+					}
+
 					console.log(`${indent}📦 Profit Share Cache summary at batchId: ${batchId}`);
 					console.log(`${indent}		investmentId:`, Buffer.from(cache.investmentId).toString().replace(/\0/g, ""));
 					console.log(`${indent}		version:`, Buffer.from(version).toString('hex'));
@@ -757,6 +814,31 @@ describe("Profit/Refund share Management", function () {
 		} // end for
 	});
 
+	it("(3a) Switch stage weighting off again", async function () {
+		this.timeout(1000 * 60 * 5); // 5 minutes timeout
+		const indent = ResolveIndent(this, 1);
+
+		await R.program.methods
+			.setProfitStageMultipliers([0, 0, 0])
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+				investmentConfig: R.investmentConfigPda,
+				payer: R.provider.wallet.publicKey,
+			} as any)
+			.remainingAccounts(threeUpdateSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true })))
+			.signers(threeUpdateSigners)
+			.rpc();
+
+		// Unweighted, the denominator is the plain active total
+		const info = await R.program.account.investmentInfo.fetch(R.investmentInfoPda);
+		const active = info.investedUsdt.reduce(
+			(sum: Anchor.BN, invested: Anchor.BN, i: number) => sum.add(invested.sub(info.inactiveInvestedUsdt[i])),
+			new Anchor.BN(0)
+		);
+		expect(weightedDenominator(info).toString()).to.equal(active.toString());
+		console.log(`${indent}✅ Stage weighting off; active invested total ${active.toString()}`);
+	});
+
 	it("(4) Estimate refund share using ALT with standard type", async function () {
 		this.timeout(1000 * 60 * 30); // 30 minutes timeout
 		const indent = ResolveIndent(this, 1);
@@ -767,6 +849,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const payer = provider.wallet.publicKey;
 
@@ -840,9 +923,11 @@ describe("Profit/Refund share Management", function () {
 
 			try {
 				const estimateIx = await program.methods
-				.estimateRefundShare(batchId, yearIndex)
+				.estimateRefundShare(batchId, yearIndex, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					cache: cachePda,
 					payer: provider.wallet.publicKey,
 					systemProgram: Anchor.web3.SystemProgram.programId,
@@ -1058,6 +1143,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const usdtMint = R.usdt_mint;
 		const h2coinMint = R.h2coin_mint;
@@ -1141,7 +1227,7 @@ describe("Profit/Refund share Management", function () {
 
 		try {
 			const ix1 = await program.methods
-				.depositSolToVault(subtotalEstimateSol)
+				.depositSolToVault(subtotalEstimateSol, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
 					payer,
@@ -1155,7 +1241,7 @@ describe("Profit/Refund share Management", function () {
 			const userUsdtAta = await getAssociatedTokenAddress(usdtMint, payer);
 			const vaultUsdtAta = await getAssociatedTokenAddress(usdtMint, vaultPda, true);
 			const ix2 = await program.methods
-				.depositTokenToVault(subtotalProfitUsdt)
+				.depositTokenToVault(subtotalProfitUsdt, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
 					payer,
@@ -1174,7 +1260,7 @@ describe("Profit/Refund share Management", function () {
 			const userHcoinAta = await getAssociatedTokenAddress(h2coinMint, payer);
 			const vaultHcoinAta = await getAssociatedTokenAddress(h2coinMint, vaultPda, true);
 			const ix3 = await program.methods
-				.depositTokenToVault(subtotalRefundHcoin)
+				.depositTokenToVault(subtotalRefundHcoin, null)
 				.accounts({
 					investmentInfo: investmentInfoPda,
 					payer,
@@ -1233,6 +1319,330 @@ describe("Profit/Refund share Management", function () {
 		}
 	});
 
+	it("(6a) Escrow a repeatedly failing profit entry, release and requeue it", async function () {
+		this.timeout(1000 * 60 * 10); // 10 minutes timeout
+		const indent = ResolveIndent(this, 1);
+
+		const program = R.program;
+		const provider = R.provider;
+		const investmentId = R.investmentId;
+		const version = R.version;
+		const usdtMint = R.usdt_mint;
+		const payer = provider.wallet.publicKey;
+
+		// The last batch is settled here; (7) skips settled caches
+		const batchId = findMaxBatchId();
+		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("vault"), Buffer.from(investmentId), Buffer.from(version)],
+			program.programId
+		);
+		const vaultTokenAta = await getAssociatedTokenAddress(usdtMint, vaultPda, true);
+		const [cachePda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("profit_cache"), Buffer.from(investmentId), Buffer.from(version), u16ToLEBytes(batchId)],
+			program.programId
+		);
+
+		const cache = await program.account.profitShareCache.fetch(cachePda);
+		const target = cache.entries[0];
+		const targetAta = await getAssociatedTokenAddress(usdtMint, target.wallet);
+
+		// Every recipient ATA except the target's, so only the target fails
+		const walletATA: PublicKey[] = [];
+		for (const entry of cache.entries) {
+			const ata = await getAssociatedTokenAddress(usdtMint, entry.wallet);
+			if (!ata.equals(targetAta)) walletATA.push(ata);
+		}
+
+		const lookupTableAddress = R.lookupTableMap.get('profit')!.get(batchId)!;
+		const lookupTableAccount = await provider.connection
+			.getAddressLookupTable(lookupTableAddress)
+			.then((res) => res.value!);
+
+		// Three executions without the target ATA escrow the target entry (MAX_ENTRY_FAILURES = 3)
+		for (let attempt = 1; attempt <= 3; attempt++) {
+			const { configHash } = await program.account.investmentInfo.fetch(R.investmentInfoPda);
+			const execIx = await program.methods
+				.executeProfitShare(batchId, false, configHash, null, null)
+				.accounts({
+					schedule: null,
+					investmentInfo: R.investmentInfoPda,
+					investmentConfig: R.investmentConfigPda,
+					roleConfig: null,
+					cache: cachePda,
+					merkleCache: merklePdaOf(u16ToLEBytes(batchId)),
+					payer,
+					vault: vaultPda,
+					mint: usdtMint,
+					vaultTokenAccount: vaultTokenAta,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					systemProgram: Anchor.web3.SystemProgram.programId,
+					associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+				} as any)
+				.remainingAccounts([
+					...threeExecSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true })),
+					...walletATA.map((ata) => ({ pubkey: ata, isWritable: true, isSigner: false })),
+				])
+				.instruction();
+
+			const blockhash = await provider.connection.getLatestBlockhash();
+			const message = new Anchor.web3.TransactionMessage({
+				payerKey: payer,
+				recentBlockhash: blockhash.blockhash,
+				instructions: [modifyComputeUnits, execIx],
+			}).compileToV0Message([lookupTableAccount]);
+			const versionedTx = new Anchor.web3.VersionedTransaction(message);
+			versionedTx.sign([...threeExecSigners, provider.wallet.payer!]);
+
+			const signature = await provider.connection.sendTransaction(versionedTx);
+			await provider.connection.confirmTransaction(
+				{ signature, blockhash: blockhash.blockhash, lastValidBlockHeight: blockhash.lastValidBlockHeight },
+				"confirmed"
+			);
+			console.log(`${indent}🟡 Execution ${attempt} without the target ATA: ${signature}`);
+		}
+
+		let after = await program.account.profitShareCache.fetch(cachePda);
+		expect(after.entries[0].status).to.have.property("escrowed");
+		expect(after.entries[0].failureCount).to.equal(3);
+		expect(after.executedAt.isZero()).to.equal(false);
+
+		// A Pending or Paid entry is not releasable
+		try {
+			await program.methods
+				.releaseEscrowedEntry(batchId, 0, after.entries[1].entryIndex)
+				.accounts({
+					investmentInfo: R.investmentInfoPda,
+					investmentConfig: R.investmentConfigPda,
+					roleConfig: null,
+					profitCache: cachePda,
+					refundCache: null,
+					payer,
+				} as any)
+				.remainingAccounts(threeExecSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true })))
+				.signers(threeExecSigners)
+				.rpc();
+			expect.fail("releasing a paid entry must fail");
+		} catch (e: any) {
+			expect(e.error?.errorCode?.code).to.equal("EntryNotEscrowed");
+		}
+
+		// Executors release the escrowed entry to the requeue path
+		await program.methods
+			.releaseEscrowedEntry(batchId, 0, target.entryIndex)
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+				investmentConfig: R.investmentConfigPda,
+				roleConfig: null,
+				profitCache: cachePda,
+				refundCache: null,
+				payer,
+			} as any)
+			.remainingAccounts(threeExecSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true })))
+			.signers(threeExecSigners)
+			.rpc();
+
+		after = await program.account.profitShareCache.fetch(cachePda);
+		expect(after.entries[0].status).to.have.property("requeued");
+
+		// One execute member pays it once the ATA is supplied
+		const before = await getAccount(provider.connection, targetAta);
+		const sig = await program.methods
+			.requeueEntry(batchId, 0, target.entryIndex)
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+				globalConfig: R.globalConfigPda,
+				investmentConfig: R.investmentConfigPda,
+				profitCache: cachePda,
+				refundCache: null,
+				mint: usdtMint,
+				vault: vaultPda,
+				vaultTokenAccount: vaultTokenAta,
+				recipientAta: targetAta,
+				member: threeExecSigners[0].publicKey,
+				tokenProgram: TOKEN_PROGRAM_ID,
+			} as any)
+			.signers([threeExecSigners[0]])
+			.rpc();
+		console.log(`${indent}✅ Released entry paid through requeue_entry: ${sig}`);
+
+		after = await program.account.profitShareCache.fetch(cachePda);
+		expect(after.entries[0].status).to.have.property("paid");
+		const paid = await getAccount(provider.connection, targetAta);
+		expect((paid.amount - before.amount).toString()).to.equal(target.amountUsdt.toString());
+	});
+
+	it("(6b) Publish a Merkle profit round and claim with valid and invalid proofs", async function () {
+		this.timeout(1000 * 60 * 10); // 10 minutes timeout
+		const indent = ResolveIndent(this, 1);
+
+		const program = R.program;
+		const provider = R.provider;
+		const investmentId = R.investmentId;
+		const version = R.version;
+		const usdtMint = R.usdt_mint;
+		const payer = provider.wallet.publicKey;
+
+		// A fresh batch id per run; a round with claims cannot be re-published
+		const batchId = findMaxBatchId() + 1 + Math.floor(Math.random() * 30000);
+		const amount = 1_000_000;
+		const wallets = Array.from({ length: 4 }, () => Keypair.generate());
+		const accountIds = wallets.map(() => Buffer.from(stringToFixedU8Array(TrimId.shortid(), 15)));
+
+		const leaves = wallets.map((kp, i) => merkleLeaf(investmentId, version, batchId, i, accountIds[i], kp.publicKey, amount));
+		const root = merkleRoot(leaves);
+		const subtotal = amount * wallets.length;
+
+		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("vault"), Buffer.from(investmentId), Buffer.from(version)],
+			program.programId
+		);
+		const vaultTokenAta = await getAssociatedTokenAddress(usdtMint, vaultPda, true);
+		const [merkleCachePda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("profit_merkle"), Buffer.from(investmentId), Buffer.from(version), u16ToLEBytes(batchId)],
+			program.programId
+		);
+		const execSignerMetas = threeExecSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true }));
+
+		const publish = async (roundBatchId: number) => {
+			const { configHash } = await program.account.investmentInfo.fetch(R.investmentInfoPda);
+			const batchBytes = u16ToLEBytes(roundBatchId);
+			const [profitCachePda] = Anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("profit_cache"), Buffer.from(investmentId), Buffer.from(version), batchBytes],
+				program.programId
+			);
+			const [roundMerklePda] = Anchor.web3.PublicKey.findProgramAddressSync(
+				[Buffer.from("profit_merkle"), Buffer.from(investmentId), Buffer.from(version), batchBytes],
+				program.programId
+			);
+			return program.methods
+				.estimateProfitShareMerkle(
+					roundBatchId, Array.from(root), wallets.length,
+					new Anchor.BN(subtotal), null, configHash
+				)
+				.accounts({
+					investmentInfo: R.investmentInfoPda,
+					globalConfig: R.globalConfigPda,
+					investmentConfig: R.investmentConfigPda,
+					roleConfig: null,
+					vaultTokenRegistry: null,
+					profitCache: profitCachePda,
+					merkleCache: roundMerklePda,
+					payer,
+					systemProgram: Anchor.web3.SystemProgram.programId,
+				} as any)
+				.remainingAccounts(execSignerMetas)
+				.signers(threeExecSigners)
+				.rpc();
+		};
+
+		// The record batch already ran a profit cache round in (6a); a Merkle round may not overlap it
+		try {
+			await publish(findMaxBatchId());
+			expect.fail("a Merkle round over an executed profit cache must fail");
+		} catch (e: any) {
+			expect(e.error?.errorCode?.code).to.equal("ProfitRoundConflict");
+		}
+
+		const sig = await publish(batchId);
+		console.log(`${indent}✅ Merkle root published for batch ${batchId}: ${sig}`);
+
+		// Claimants pay their own ATA, receipt rent and fees
+		const fund = new Anchor.web3.Transaction();
+		for (const kp of wallets.slice(0, 2)) {
+			fund.add(Anchor.web3.SystemProgram.transfer({
+				fromPubkey: payer,
+				toPubkey: kp.publicKey,
+				lamports: 0.01 * Anchor.web3.LAMPORTS_PER_SOL,
+			}));
+		}
+		await provider.sendAndConfirm(fund);
+
+		const claim = async (claimant: Keypair, index: number, claimedAmount: number, proof: Buffer[]) => {
+			const [receiptPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+				[
+					Buffer.from("merkle_claim"), Buffer.from(investmentId), Buffer.from(version),
+					u16ToLEBytes(batchId), u32ToLEBytes(index),
+				],
+				program.programId
+			);
+			return program.methods
+				.claimProfitWithProof(batchId, index, Array.from(accountIds[index]), new Anchor.BN(claimedAmount), proof.map((p) => Array.from(p)))
+				.accounts({
+					investmentInfo: R.investmentInfoPda,
+					globalConfig: R.globalConfigPda,
+					merkleCache: merkleCachePda,
+					claimReceipt: receiptPda,
+					mint: usdtMint,
+					vault: vaultPda,
+					vaultTokenAccount: vaultTokenAta,
+					claimantTokenAccount: await getAssociatedTokenAddress(usdtMint, claimant.publicKey),
+					claimant: claimant.publicKey,
+					feePayer: claimant.publicKey,
+					systemProgram: Anchor.web3.SystemProgram.programId,
+					tokenProgram: TOKEN_PROGRAM_ID,
+					associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+				} as any)
+				.signers([claimant])
+				.rpc();
+		};
+
+		// A tampered amount does not reach the root
+		try {
+			await claim(wallets[0], 0, amount + 1, merkleProof(leaves, 0));
+			expect.fail("a tampered amount must fail");
+		} catch (e: any) {
+			expect(e.error?.errorCode?.code).to.equal("InvalidMerkleProof");
+		}
+
+		// Another entry's proof does not verify for a different wallet
+		try {
+			await claim(wallets[0], 1, amount, merkleProof(leaves, 1));
+			expect.fail("a proof for another wallet must fail");
+		} catch (e: any) {
+			expect(e.error?.errorCode?.code).to.equal("InvalidMerkleProof");
+		}
+
+		// The valid proof pays the claimant once
+		const claimSig = await claim(wallets[0], 0, amount, merkleProof(leaves, 0));
+		console.log(`${indent}✅ Entry 0 claimed with a valid proof: ${claimSig}`);
+		const claimed = await getAccount(provider.connection, await getAssociatedTokenAddress(usdtMint, wallets[0].publicKey));
+		expect(claimed.amount.toString()).to.equal(amount.toString());
+
+		try {
+			await claim(wallets[0], 0, amount, merkleProof(leaves, 0));
+			expect.fail("a second claim must fail");
+		} catch (e: any) {
+			expect(e).to.be.an("error");
+		}
+
+		let cache = await program.account.profitMerkleCache.fetch(merkleCachePda);
+		expect(cache.claimedCount).to.equal(1);
+
+		// Executors cancel the round; remaining valid proofs are refused
+		await program.methods
+			.cancelProfitMerkle(batchId)
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+				investmentConfig: R.investmentConfigPda,
+				roleConfig: null,
+				merkleCache: merkleCachePda,
+				payer,
+			} as any)
+			.remainingAccounts(execSignerMetas)
+			.signers(threeExecSigners)
+			.rpc();
+
+		cache = await program.account.profitMerkleCache.fetch(merkleCachePda);
+		expect(cache.cancelledAt.isZero()).to.equal(false);
+
+		try {
+			await claim(wallets[1], 1, amount, merkleProof(leaves, 1));
+			expect.fail("a claim on a cancelled round must fail");
+		} catch (e: any) {
+			expect(e.error?.errorCode?.code).to.equal("MerkleRoundCancelled");
+		}
+	});
+
 	it("(7) Execute profit share using ALT", async function () {
 		this.timeout(1000 * 60 * 30); // 30 minutes timeout
 		const indent = ResolveIndent(this, 1);
@@ -1243,6 +1653,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const usdtMint = R.usdt_mint;
 		const payer = provider.wallet.publicKey;
@@ -1283,6 +1694,10 @@ describe("Profit/Refund share Management", function () {
 				program.programId
 			);
 			const cache = await program.account.profitShareCache.fetch(cachePda);
+			if (!cache.executedAt.isZero()) {
+				console.log(`${indent}⏭️ Batch ${batchId} already settled`);
+				continue;
+			}
 			
 			
 			// Get recipient ATA on each batchId
@@ -1321,11 +1736,16 @@ describe("Profit/Refund share Management", function () {
 			try {
 				const computeIx = modifyComputeUnits;
 				
+				const { configHash } = await program.account.investmentInfo.fetch(investmentInfoPda);
 				const execIx = await program.methods
-				.executeProfitShare(batchId)
+				.executeProfitShare(batchId, false, configHash, null, null)
 				.accounts({
+					schedule: null,
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					cache: cachePda,
+					merkleCache: merklePdaOf(batchIdBytes),
 					payer,
 					vault: vaultPda,
 					mint: usdtMint,
@@ -1527,6 +1947,109 @@ describe("Profit/Refund share Management", function () {
 		}
 	});
 
+	it("(8a) Partly execute a refund cache and reject its re-estimation", async function () {
+		this.timeout(1000 * 60 * 10); // 10 minutes timeout
+		const indent = ResolveIndent(this, 1);
+
+		const program = R.program;
+		const provider = R.provider;
+		const investmentId = R.investmentId;
+		const version = R.version;
+		const h2coinMint = R.h2coin_mint;
+		const payer = provider.wallet.publicKey;
+
+		// The last batch is partly paid here; (9) pays the rest
+		const batchId = findMaxBatchId();
+		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("vault"), Buffer.from(investmentId), Buffer.from(version)],
+			program.programId
+		);
+		const vaultTokenAta = await getAssociatedTokenAddress(h2coinMint, vaultPda, true);
+		const [cachePda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("refund_cache"), Buffer.from(investmentId), Buffer.from(version), u16ToLEBytes(batchId), yearIndexBytes],
+			program.programId
+		);
+
+		const cache = await program.account.refundShareCache.fetch(cachePda);
+		const heldAta = await getAssociatedTokenAddress(h2coinMint, cache.entries[0].wallet);
+
+		// Every recipient ATA except the first entry's, so that entry stays Pending
+		const walletATA: PublicKey[] = [];
+		for (const entry of cache.entries) {
+			const ata = await getAssociatedTokenAddress(h2coinMint, entry.wallet);
+			if (!ata.equals(heldAta)) walletATA.push(ata);
+		}
+
+		const lookupTableAddress = R.lookupTableMap.get('refund')!.get(batchId)!;
+		const lookupTableAccount = await provider.connection
+			.getAddressLookupTable(lookupTableAddress)
+			.then((res) => res.value!);
+
+		const { configHash } = await program.account.investmentInfo.fetch(R.investmentInfoPda);
+		const execIx = await program.methods
+			.executeRefundShare(batchId, yearIndex, false, configHash, null, null)
+			.accounts({
+				schedule: null,
+				investmentInfo: R.investmentInfoPda,
+				investmentConfig: R.investmentConfigPda,
+				roleConfig: null,
+				mint: h2coinMint,
+				cache: cachePda,
+				vault: vaultPda,
+				vaultTokenAccount: vaultTokenAta,
+				payer,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				systemProgram: Anchor.web3.SystemProgram.programId,
+				associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			} as any)
+			.remainingAccounts([
+				...threeExecSigners.map((kp) => ({ pubkey: kp.publicKey, isWritable: false, isSigner: true })),
+				...walletATA.map((ata) => ({ pubkey: ata, isWritable: true, isSigner: false })),
+			])
+			.instruction();
+
+		const blockhash = await provider.connection.getLatestBlockhash();
+		const message = new Anchor.web3.TransactionMessage({
+			payerKey: payer,
+			recentBlockhash: blockhash.blockhash,
+			instructions: [modifyComputeUnits, execIx],
+		}).compileToV0Message([lookupTableAccount]);
+		const versionedTx = new Anchor.web3.VersionedTransaction(message);
+		versionedTx.sign([...threeExecSigners, provider.wallet.payer!]);
+
+		const signature = await provider.connection.sendTransaction(versionedTx);
+		await provider.connection.confirmTransaction(
+			{ signature, blockhash: blockhash.blockhash, lastValidBlockHeight: blockhash.lastValidBlockHeight },
+			"confirmed"
+		);
+		console.log(`${indent}🟡 Partial refund execution without the first ATA: ${signature}`);
+
+		const after = await program.account.refundShareCache.fetch(cachePda);
+		expect(after.executedAt.isZero()).to.equal(true);
+		expect(after.entries[0].status).to.have.property("pending");
+		expect(after.entries.some((e: any) => "paid" in e.status)).to.equal(true);
+
+		// Re-estimating would reset the paid entries to Pending
+		try {
+			await program.methods
+				.estimateRefundShare(batchId, yearIndex, null)
+				.accounts({
+					investmentInfo: R.investmentInfoPda,
+					investmentConfig: R.investmentConfigPda,
+					roleConfig: null,
+					cache: cachePda,
+					payer,
+					systemProgram: Anchor.web3.SystemProgram.programId,
+				} as any)
+				.remainingAccounts([{ pubkey: threeUpdateSigners[0].publicKey, isWritable: false, isSigner: true }])
+				.signers([threeUpdateSigners[0]])
+				.rpc();
+			expect.fail("re-estimating a partly paid refund cache must fail");
+		} catch (e: any) {
+			expect(e.error?.errorCode?.code).to.equal("RefundCacheHasPayouts");
+		}
+	});
+
 	it("(9) Execute refund share using ALT", async function () {
 		this.timeout(1000 * 60 * 30); // 30 minutes timeout
 		const indent = ResolveIndent(this, 1);
@@ -1537,6 +2060,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const h2coinMint = R.h2coin_mint;
 		const payer = provider.wallet.publicKey;
@@ -1613,10 +2137,14 @@ describe("Profit/Refund share Management", function () {
 			try {
 				const computeIx = modifyComputeUnits;
 	
+				const { configHash } = await program.account.investmentInfo.fetch(investmentInfoPda);
 				const execIx = await program.methods
-				.executeRefundShare(batchId, yearIndex)
+				.executeRefundShare(batchId, yearIndex, false, configHash, null, null)
 				.accounts({
+					schedule: null,
 					investmentInfo: investmentInfoPda,
+					investmentConfig: investmentConfigPda,
+					roleConfig: null,
 					mint: h2coinMint,
 					cache: cachePda,
 					vault: vaultPda,
@@ -1723,6 +2251,7 @@ describe("Profit/Refund share Management", function () {
 		const provider = R.provider;
 		const investmentId = R.investmentId;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const version = R.version;
 		const usdt_mint = R.usdt_mint;
 		const h2coin_mint = R.h2coin_mint;
@@ -1777,11 +2306,16 @@ describe("Profit/Refund share Management", function () {
 		const RecipientHcoinAta = await getAssociatedTokenAddress(h2coin_mint, recipient);
 
 
+		// Current withdrawal sequence (anti-replay)
+		const infoBefore = await program.account.investmentInfo.fetch(investmentInfoPda);
+
 		// Withdraw instruction
 		const withdrawIx = await program.methods
-			.withdrawFromVault()
+			.withdrawFromVault(infoBefore.withdrawSequence, { all: {} }, { treasury: {} }, null, null, null, null, infoBefore.configHash)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
+				roleConfig: null,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
@@ -1792,6 +2326,7 @@ describe("Profit/Refund share Management", function () {
 				recipientAccount: recipient,
 				recipientUsdtAccount: RecipientUsdtAta,
 				recipientHcoinAccount: RecipientHcoinAta,
+				proposal: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -1870,7 +2405,76 @@ describe("Profit/Refund share Management", function () {
 		}
 	});
 
+	// Mirrors InvestmentInfo::active_profit_denominator: bucket 0 (no stage) is weighted 1×
+	function weightedDenominator(info: any): Anchor.BN {
+		const weighting = info.profitStageMultipliersBp.some((m: number) => m !== 0);
+		return info.investedUsdt.reduce((total: Anchor.BN, invested: Anchor.BN, i: number) => {
+			const active = invested.sub(info.inactiveInvestedUsdt[i]);
+			const multiplier = weighting && i > 0 ? info.profitStageMultipliersBp[i - 1] : 10_000;
+			return total.add(active.muln(multiplier).divn(10_000));
+		}, new Anchor.BN(0));
+	}
+
 	function findMaxBatchId() {
 		return MAX_ENTRIES/MAX_ENTRIES_PER_BATCH;
 	}
+
+	// ProfitMerkleCache of a batch; profit cache rounds pass it to prove no Merkle round is open
+	function merklePdaOf(batchIdBytes: Buffer): PublicKey {
+		const [pda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[Buffer.from("profit_merkle"), Buffer.from(R.investmentId), Buffer.from(R.version), batchIdBytes],
+			R.program.programId
+		);
+		return pda;
+	}
+
+	// Leaf layout mirrors the program: 0x00 ‖ id ‖ version ‖ batch ‖ index ‖ account ‖ wallet ‖ amount
+	function merkleLeaf(
+		investmentId: number[], version: number[], batchId: number, index: number,
+		accountId: Buffer, wallet: PublicKey, amount: number
+	): Buffer {
+		const amountBytes = Buffer.alloc(8);
+		amountBytes.writeBigUInt64LE(BigInt(amount), 0);
+		return createHash("sha256").update(Buffer.concat([
+			Buffer.from([0x00]),
+			Buffer.from(investmentId), Buffer.from(version),
+			u16ToLEBytes(batchId), u32ToLEBytes(index),
+			accountId, wallet.toBuffer(), amountBytes,
+		])).digest();
+	}
+
+	// Inner nodes hash the sorted pair under the 0x01 prefix; an odd node is carried up
+	function merkleParent(a: Buffer, b: Buffer): Buffer {
+		const [lo, hi] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+		return createHash("sha256").update(Buffer.concat([Buffer.from([0x01]), lo, hi])).digest();
+	}
+
+	function merkleRoot(leaves: Buffer[]): Buffer {
+		let level = leaves;
+		while (level.length > 1) {
+			const next: Buffer[] = [];
+			for (let i = 0; i < level.length; i += 2) {
+				next.push(i + 1 < level.length ? merkleParent(level[i], level[i + 1]) : level[i]);
+			}
+			level = next;
+		}
+		return level[0];
+	}
+
+	function merkleProof(leaves: Buffer[], index: number): Buffer[] {
+		const proof: Buffer[] = [];
+		let level = leaves;
+		let i = index;
+		while (level.length > 1) {
+			const sibling = i ^ 1;
+			if (sibling < level.length) proof.push(level[sibling]);
+			const next: Buffer[] = [];
+			for (let j = 0; j < level.length; j += 2) {
+				next.push(j + 1 < level.length ? merkleParent(level[j], level[j + 1]) : level[j]);
+			}
+			level = next;
+			i >>= 1;
+		}
+		return proof;
+	}
 });
\ No newline at end of file
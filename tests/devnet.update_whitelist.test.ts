@@ -90,6 +90,16 @@ describe("🚀h2coin whitelist-check", async () => {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
 			[
@@ -119,16 +129,20 @@ describe("🚀h2coin whitelist-check", async () => {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -168,15 +182,16 @@ describe("🚀h2coin whitelist-check", async () => {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ investment info Summary:`);
 		console.log(`${indent}	investmentId:`, bytesToFixedString(investmentInfo.investmentId));
 		console.log(`${indent}	version:`, Buffer.from(version).toString('hex'));
 		console.log(`${indent}	investmentType:`, Object.keys(investmentInfo.investmentType)[0]);
-		console.log(`${indent}	stageRatio:`, investmentInfo.stageRatio.toString());
+		console.log(`${indent}	stageRatio:`, investmentConfig.stageRatio.toString());
 		console.log(`${indent}	investmentUpperLimit:`, investmentInfo.investmentUpperLimit.toString());
-		console.log(`${indent}	executeWhitelist:`, investmentInfo.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	updateWhitelist:`, investmentInfo.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	withdrawWhitelist:`, investmentInfo.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	executeWhitelist:`, investmentConfig.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	updateWhitelist:`, investmentConfig.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	withdrawWhitelist:`, investmentConfig.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
 		console.log(`${indent}	state:`, Object.keys(investmentInfo.state)[0]);
 		console.log(`${indent}	startAt:`, new Date(investmentInfo.startAt.toNumber()*1000));
 		console.log(`${indent}	endAt:`, new Date(investmentInfo.endAt.toNumber()*1000));
@@ -194,6 +209,7 @@ describe("🚀h2coin whitelist-check", async () => {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 		
 		const updateWhistLists = loadUpdateWhitelistKeypairs();
@@ -207,9 +223,10 @@ describe("🚀h2coin whitelist-check", async () => {
 		let caught = false;
 		try {
 			await program.methods
-			.patchUpdateWhitelist()
+			.patchUpdateWhitelist(stringToFixedU8Array("Updater", 16), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
@@ -236,7 +253,8 @@ describe("🚀h2coin whitelist-check", async () => {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const updateWhiteLists = investmentInfo.updateWhitelist;
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const updateWhiteLists = investmentConfig.updateWhitelist;
 		let all_matched = true;
 		for(let i=0; i<updateWhiteLists.length; i++) {
 			const index = updateWhistLists.findIndex((v)=>{
@@ -261,6 +279,7 @@ describe("🚀h2coin whitelist-check", async () => {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 		
 		const updateWhistLists = loadUpdateWhitelistKeypairs();
@@ -272,9 +291,10 @@ describe("🚀h2coin whitelist-check", async () => {
 		let caught = false;
 		try {
 			await program.methods
-			.patchUpdateWhitelist()
+			.patchUpdateWhitelist(stringToFixedU8Array("Updater", 16), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
@@ -300,7 +320,8 @@ describe("🚀h2coin whitelist-check", async () => {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const updateWhiteLists = investmentInfo.updateWhitelist;
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const updateWhiteLists = investmentConfig.updateWhitelist;
 		let all_matched = true;
 		for(let i=0; i<updateWhiteLists.length; i++) {
 			const index = updateWhistLists.findIndex((v)=>{
@@ -316,153 +337,125 @@ describe("🚀h2coin whitelist-check", async () => {
 		}
 	});
 
-	it("(2) Replace existing key with new key", async function() {
+	it("(2) Queue replacement of existing key with new key", async function() {
 		this.timeout(1000 * 60 * 5); // 5 minutes timeout
 		const indent = ResolveIndent(this, 1);
-		console.log(`🚀 Replace existing key with new key...`);
+		console.log(`${indent}📃 Queue replacement of existing key with new key...`);
+
 
 		const program = R.program;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const provider = R.provider;
 
-		
-		const updateWhistLists = loadUpdateWhitelistKeypairs();
-		const threeSigners = updateWhistLists.slice(0, 3);
-
+		const whitelistKeypairs = loadUpdateWhitelistKeypairs();
+		const threeSigners = whitelistKeypairs.slice(0, 3);
 
 		const from = new PublicKey("5QUUjS7i2akMphaXV9QGWANhucQY73BsC9cNedR3feqB");
 		const to  = new PublicKey("CR7HxrTiCiCvWxgJF2gGV3XjQnm1pveBp5XCrXqwJnLy");
-				
-		let caught = false;
-		try {
-			await program.methods
-			.patchUpdateWhitelist()
-			.accounts({
-				investmentInfo: investmentInfoPda,
-				payer: provider.wallet.publicKey,
-			} as any)
-			.remainingAccounts([
-				...threeSigners.map(kp => ({
-					pubkey: kp.publicKey,
-					isWritable: false,
-					isSigner: true,
-				})),
-				{ pubkey: from, isWritable: false, isSigner: false },
-				{ pubkey: to, isWritable: false, isSigner: false },
-			])
-			.signers(threeSigners)
-			.preInstructions([modifyComputeUnits])
-			.rpc();
-		} catch (e: any) {
-			caught = true;
-			expect(e).to.have.property("error");
-			expect(e.error.errorCode.code, `Actual code: ${e.error.errorCode.code}`).to.be.oneOf([
-				"WhitelistAddressExists",
-				"WhitelistAddressNotFound"
-			]);
-		}
-
-
-		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const updateWhiteLists = investmentInfo.updateWhitelist;
-		let all_matched = true;
-		for(let i=0; i<updateWhiteLists.length; i++) {
-			const target = updateWhiteLists[i];
-			const index = updateWhistLists.findIndex((v)=>{
-				return v.publicKey.equals(target);
-			});
 
-			let pass:boolean;
-			if ( target.equals(from) ) {
-				pass = false;
-			}
-			else if ( target.equals(to) ) {
-				pass = index < 0;
-			}
-			else {
-				pass = index >= 0;
-			}
-
-			all_matched = all_matched && pass;
-			console.log(`${indent}${target.toBase58()} ${pass ? '✅' : '❌'}`);
-		}
+		const [pendingPatchPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("whitelist_patch"),
+				Buffer.from(R.investmentId!),
+				Buffer.from(R.version!),
+				Buffer.from([1])
+			],
+			program.programId
+		);
 
-		if ( !all_matched ) {
-			throw new Error("Not matched!");
-		}
+		await program.methods
+		.patchUpdateWhitelist(stringToFixedU8Array("Updater", 16), null)
+		.accounts({
+			investmentInfo: investmentInfoPda,
+			investmentConfig: investmentConfigPda,
+			pendingPatch: pendingPatchPda,
+			payer: provider.wallet.publicKey,
+		} as any)
+		.remainingAccounts([
+			...threeSigners.map(kp => ({
+				pubkey: kp.publicKey,
+				isWritable: false,
+				isSigner: true,
+			})),
+			{ pubkey: from, isWritable: false, isSigner: false },
+			{ pubkey: to, isWritable: false, isSigner: false },
+		])
+		.signers(threeSigners)
+		.preInstructions([modifyComputeUnits])
+		.rpc();
+
+
+		// The patch is only queued: the whitelist is unchanged until apply_whitelist_patch
+		const pendingPatch = await program.account.pendingWhitelistPatch.fetch(pendingPatchPda);
+		expect(pendingPatch.from.equals(from)).to.be.true;
+		expect(pendingPatch.to.equals(to)).to.be.true;
+		console.log(`${indent}pending patch effective at:`, new Date(pendingPatch.effectiveAt.toNumber()*1000));
+
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const whitelist = investmentConfig.updateWhitelist;
+		expect(whitelist.some((v: PublicKey) => v.equals(from))).to.be.true;
+		expect(whitelist.some((v: PublicKey) => v.equals(to))).to.be.false;
 	});
 
-	it("(3) Reset to original whitelist", async function() {
+	it("(3) Cancel the pending patch with two members", async function() {
 		this.timeout(1000 * 60 * 5); // 5 minutes timeout
 		const indent = ResolveIndent(this, 1);
-		console.log(`🚀 Reset to original whitelist...`);
+		console.log(`${indent}📃 Cancel the pending patch...`);
+
 
 		const program = R.program;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const provider = R.provider;
 
-		
-		const updateWhistLists = loadUpdateWhitelistKeypairs();
-		const threeSigners = updateWhistLists.slice(0, 3);
-
-
-		const from  = new PublicKey("CR7HxrTiCiCvWxgJF2gGV3XjQnm1pveBp5XCrXqwJnLy");
-		const to	= new PublicKey("5QUUjS7i2akMphaXV9QGWANhucQY73BsC9cNedR3feqB");
-				
-		let caught = false;
-		try {
-			await program.methods
-			.patchUpdateWhitelist()
-			.accounts({
-				investmentInfo: investmentInfoPda,
-				payer: provider.wallet.publicKey,
-			} as any)
-			.remainingAccounts([
-				...threeSigners.map(kp => ({
-					pubkey: kp.publicKey,
-					isWritable: false,
-					isSigner: true,
-				})),
-				{ pubkey: from, isWritable: false, isSigner: false },
-				{ pubkey: to, isWritable: false, isSigner: false },
-			])
-			.signers(threeSigners)
-			.preInstructions([modifyComputeUnits])
-			.rpc();
-		} catch (e: any) {
-			caught = true;
-			console.log(e);
-			
-			expect(e).to.have.property("error");
-			expect(e.error.errorCode.code, `Actual code: ${e.error.errorCode.code}`).to.be.oneOf([
-				"WhitelistAddressExists",
-				"WhitelistAddressNotFound"
-			]);
-		}
+		const whitelistKeypairs = loadUpdateWhitelistKeypairs();
+		const twoSigners = whitelistKeypairs.slice(3, 5);
 
+		const [pendingPatchPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("whitelist_patch"),
+				Buffer.from(R.investmentId!),
+				Buffer.from(R.version!),
+				Buffer.from([1])
+			],
+			program.programId
+		);
 
-		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const updateWhiteLists = investmentInfo.updateWhitelist;
+		await program.methods
+		.cancelWhitelistPatch()
+		.accounts({
+			investmentInfo: investmentInfoPda,
+			investmentConfig: investmentConfigPda,
+			pendingPatch: pendingPatchPda,
+			rentReceiver: provider.wallet.publicKey,
+			payer: provider.wallet.publicKey,
+		} as any)
+		.remainingAccounts(
+			twoSigners.map(kp => ({
+				pubkey: kp.publicKey,
+				isWritable: false,
+				isSigner: true,
+			}))
+		)
+		.signers(twoSigners)
+		.preInstructions([modifyComputeUnits])
+		.rpc();
+
+
+		const pendingInfo = await provider.connection.getAccountInfo(pendingPatchPda);
+		expect(pendingInfo).to.be.null;
+
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const whitelist = investmentConfig.updateWhitelist;
 		let all_matched = true;
-		for(let i=0; i<updateWhiteLists.length; i++) {
-			const target = updateWhiteLists[i];
-			const index = updateWhistLists.findIndex((v)=>{
-				return v.publicKey.equals(target);
-			});			
-
-			let pass:boolean;
-			if ( target.equals(from) ) {
-				pass = false;
-			}
-			else if ( target.equals(to) ) {
-				pass = index >= 0;
-			}
-			else {
-				pass = true;
-			}
-
-			all_matched = all_matched && pass;
-			console.log(`${indent}${target.toBase58()} ${pass ? '✅' : '❌'}`);
+		for(let i=0; i<whitelist.length; i++) {
+			const index = whitelistKeypairs.findIndex((v)=>{
+				return v.publicKey.equals(whitelist[i]);
+			});
+
+			all_matched = all_matched && index >= 0;
+			console.log(`${indent}${whitelist[i].toBase58()} ${index >= 0 ? '✅' : '❌'}`);
 		}
 
 		if ( !all_matched ) {
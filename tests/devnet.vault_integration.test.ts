@@ -0,0 +1,131 @@
+/**
+ * @fileoverview Vault Integration Example Test Suite for H2Coin Vault Share Program
+ * Exercises the `vault_integration` example program, which deposits into a vault and
+ * reads investment state through CPI against the real program interfaces.
+ *
+ * SECURITY CONSIDERATIONS:
+ * - The payer signature must carry through the CPI unchanged
+ * - InvestmentInfo must be owner- and discriminator-checked when read by another program
+ * - The vault program repeats every deposit check behind the example's pre-check
+ *
+ * @audit This test suite is the living integration reference for partners and should be reviewed for:
+ * - CPI account ordering matching DepositTokenToVault
+ * - Rejection of accounts that are not InvestmentInfo
+ * - Balance changes landing in the vault ATA
+ */
+
+import { expect } from "chai";
+import {describe, it} from "mocha";
+import * as Anchor from "@coral-xyz/anchor";
+import { Program, Idl } from "@coral-xyz/anchor";
+import { ComputeBudgetProgram } from "@solana/web3.js";
+
+import {
+	ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID,
+	getAssociatedTokenAddress, getAccount,
+} from "@solana/spl-token";
+
+import integrationIdlJson from "../target/idl/vault_integration.json";
+import { VaultIntegration } from "../target/types/vault_integration";
+
+import {Runtime as R} from "./runtime";
+
+
+
+describe("h2coin vault integration example", async () => {
+	const modifyComputeUnits = ComputeBudgetProgram.setComputeUnitLimit({
+		units: 400_000,
+	});
+
+	const depositAmount = new Anchor.BN(1_000_000); // 1 USDT (6 decimals)
+
+	let integration: Program<VaultIntegration>;
+	let vaultPda: Anchor.web3.PublicKey;
+
+	before("Load the example program and derive the vault PDA", async function() {
+		integration = new Program<VaultIntegration>(integrationIdlJson as Idl, R.provider);
+
+		const investmentInfo = await R.program.account.investmentInfo.fetch(R.investmentInfoPda);
+		[vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("vault"),
+				Buffer.from(R.investmentId),
+				Buffer.from(investmentInfo.vaultVersion),
+			],
+			R.programId
+		);
+	});
+
+
+	it("Reads investment state through the example program", async function() {
+		const indent = ResolveIndent(this, 1);
+
+		const snapshot = await integration.methods
+			.readInvestmentState()
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+			} as any)
+			.view();
+
+		const investmentInfo = await R.program.account.investmentInfo.fetch(R.investmentInfoPda);
+		console.log(`${indent}📦 state=${JSON.stringify(snapshot.state)}, active=${snapshot.isActive}, frozen=${snapshot.frozen}`);
+
+		expect(Array.from(snapshot.investmentId)).to.deep.equal(R.investmentId);
+		expect(snapshot.vault.toBase58()).to.equal(investmentInfo.vault.toBase58());
+		expect(snapshot.isActive).to.equal(investmentInfo.isActive);
+		expect(snapshot.withdrawSequence.toString()).to.equal(investmentInfo.withdrawSequence.toString());
+		expect(snapshot.frozen).to.equal(!investmentInfo.frozenAt.isZero());
+		expect(snapshot.profitMint.toBase58()).to.equal(R.usdt_mint.toBase58());
+	});
+
+
+	it("Rejects an account that is not an InvestmentInfo", async function() {
+		let caught = false;
+		try {
+			await integration.methods
+				.readInvestmentState()
+				.accounts({
+					investmentInfo: R.investmentConfigPda,
+				} as any)
+				.view();
+		} catch (e: any) {
+			caught = true;
+			const logs = e.simulationResponse?.logs?.join("\n") || e.message || JSON.stringify(e);
+			expect(logs).to.include("AccountDiscriminatorMismatch");
+		}
+		expect(caught, "InvestmentConfig must not be read as InvestmentInfo").to.equal(true);
+	});
+
+
+	it("Deposits USDT into the vault through CPI", async function() {
+		this.timeout(1000 * 60);
+		const indent = ResolveIndent(this, 1);
+
+		const payer = R.provider.wallet.publicKey;
+		const fromUsdtAta = await getAssociatedTokenAddress(R.usdt_mint, payer);
+		const vaultUsdtAta = await getAssociatedTokenAddress(R.usdt_mint, vaultPda, true);
+		const before = await getAccount(R.provider.connection, vaultUsdtAta);
+
+		const sig = await integration.methods
+			.depositToVault(depositAmount, null)
+			.accounts({
+				investmentInfo: R.investmentInfoPda,
+				globalConfig: R.globalConfigPda,
+				mint: R.usdt_mint,
+				from: fromUsdtAta,
+				vault: vaultPda,
+				vaultTokenAccount: vaultUsdtAta,
+				payer,
+				vaultProgram: R.programId,
+				systemProgram: Anchor.web3.SystemProgram.programId,
+				tokenProgram: TOKEN_PROGRAM_ID,
+				associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+			} as any)
+			.preInstructions([modifyComputeUnits])
+			.rpc();
+		console.log(`${indent}✅ CPI deposit tx:`, sig);
+
+		const after = await getAccount(R.provider.connection, vaultUsdtAta);
+		expect((after.amount - before.amount).toString()).to.equal(depositAmount.toString());
+	});
+});
@@ -95,6 +95,16 @@ describe("h2coin whitelist-check", async () => {
 		);
 		R.investmentInfoPda = investmentInfoPda;
 
+		const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+			[
+				Buffer.from("investment_config"), 
+				Buffer.from(investmentId), 
+				Buffer.from(version)
+			],
+			program.programId
+		);
+		R.investmentConfigPda = investmentConfigPda;
+
 
 		const [vaultPda] = Anchor.web3.PublicKey.findProgramAddressSync(
 			[
@@ -124,16 +134,20 @@ describe("h2coin whitelist-check", async () => {
 				upperLimit,
 				executeWhitelist,
 				updateWhitelist,
-				withdrawWhitelist
+				withdrawWhitelist,
+				false
 			)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				usdtMint: usdt_mint,
 				hcoinMint: h2coin_mint,
 
 				vault: vaultPda,
 				vaultUsdtAccount: vaultUsdtAta,
 				vaultHcoinAccount: vaultH2coinAta,
+				tenant: null,
+				tenantAdmin: null,
 
 				payer: payer.publicKey,
 				systemProgram: Anchor.web3.SystemProgram.programId,
@@ -173,15 +187,16 @@ describe("h2coin whitelist-check", async () => {
 
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
 		console.log(`${indent}✅ investment info Summary:`);
 		console.log(`${indent}	investmentId:`, bytesToFixedString(investmentInfo.investmentId));
 		console.log(`${indent}	version:`, Buffer.from(version).toString('hex'));
 		console.log(`${indent}	investmentType:`, Object.keys(investmentInfo.investmentType)[0]);
-		console.log(`${indent}	stageRatio:`, investmentInfo.stageRatio.toString());
+		console.log(`${indent}	stageRatio:`, investmentConfig.stageRatio.toString());
 		console.log(`${indent}	investmentUpperLimit:`, investmentInfo.investmentUpperLimit.toString());
-		console.log(`${indent}	executeWhitelist:`, investmentInfo.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	updateWhitelist:`, investmentInfo.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
-		console.log(`${indent}	withdrawWhitelist:`, investmentInfo.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	executeWhitelist:`, investmentConfig.executeWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	updateWhitelist:`, investmentConfig.updateWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
+		console.log(`${indent}	withdrawWhitelist:`, investmentConfig.withdrawWhitelist.map((v: PublicKey) => v.toBase58()).join(', '));
 		console.log(`${indent}	state:`, Object.keys(investmentInfo.state)[0]);
 		console.log(`${indent}	startAt:`, new Date(investmentInfo.startAt.toNumber()*1000));
 		console.log(`${indent}	endAt:`, new Date(investmentInfo.endAt.toNumber()*1000));
@@ -199,6 +214,7 @@ describe("h2coin whitelist-check", async () => {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 		
 		const withdrawList: PublicKey[] = [];
@@ -207,9 +223,10 @@ describe("h2coin whitelist-check", async () => {
 		let caught = false;
 		try {
 			const tx = await program.methods
-			.patchWithdrawWhitelist()
+			.patchWithdrawWhitelist(withdrawList.map((_, i) => stringToFixedU8Array(`Withdrawer-${i + 1}`, 16)), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
@@ -236,7 +253,8 @@ describe("h2coin whitelist-check", async () => {
 	
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const withdrawWhitelist = investmentInfo.withdrawWhitelist;
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const withdrawWhitelist = investmentConfig.withdrawWhitelist;
 		const testList = [
 			new PublicKey("CF5yyzXav4KfxxCAtDwMoptaQXZJeVqMZrFLxePMvZGW")
 		];
@@ -262,6 +280,7 @@ describe("h2coin whitelist-check", async () => {
 
 		const program = R.program;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const provider = R.provider;
 
 
@@ -273,9 +292,10 @@ describe("h2coin whitelist-check", async () => {
 		let caught = false;
 		try {
 			const tx = await program.methods
-			.patchWithdrawWhitelist()
+			.patchWithdrawWhitelist(withdrawList.map((_, i) => stringToFixedU8Array(`Withdrawer-${i + 1}`, 16)), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
@@ -301,7 +321,8 @@ describe("h2coin whitelist-check", async () => {
 	
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const withdrawWhitelist = investmentInfo.withdrawWhitelist;
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const withdrawWhitelist = investmentConfig.withdrawWhitelist;
 		const testList = [
 			new PublicKey("CF5yyzXav4KfxxCAtDwMoptaQXZJeVqMZrFLxePMvZGW")
 		];
@@ -328,6 +349,7 @@ describe("h2coin whitelist-check", async () => {
 		const program = R.program;
 		const provider = R.provider;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 
 
 		const withdrawList:PublicKey[] = [
@@ -338,9 +360,10 @@ describe("h2coin whitelist-check", async () => {
 		let caught = false;
 		try {
 			const tx = await program.methods
-			.patchWithdrawWhitelist()
+			.patchWithdrawWhitelist(withdrawList.map((_, i) => stringToFixedU8Array(`Withdrawer-${i + 1}`, 16)), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
@@ -366,7 +389,8 @@ describe("h2coin whitelist-check", async () => {
 	
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const withdrawWhitelist = investmentInfo.withdrawWhitelist;
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const withdrawWhitelist = investmentConfig.withdrawWhitelist;
 		let all_matched = withdrawWhitelist.length === withdrawList.length;
 		for(let i=0; i<withdrawWhitelist.length; i++) {
 			const index = withdrawList.findIndex((v)=>{
@@ -388,6 +412,7 @@ describe("h2coin whitelist-check", async () => {
 
 		const program = R.program;
 		const investmentInfoPda = R.investmentInfoPda;
+		const investmentConfigPda = R.investmentConfigPda;
 		const provider = R.provider;
 
 
@@ -397,9 +422,10 @@ describe("h2coin whitelist-check", async () => {
 		let caught = false;
 		try {
 			const tx = await program.methods
-			.patchWithdrawWhitelist()
+			.patchWithdrawWhitelist(withdrawList.map((_, i) => stringToFixedU8Array(`Withdrawer-${i + 1}`, 16)), null)
 			.accounts({
 				investmentInfo: investmentInfoPda,
+				investmentConfig: investmentConfigPda,
 				payer: provider.wallet.publicKey,
 			} as any)
 			.remainingAccounts([
@@ -425,7 +451,8 @@ describe("h2coin whitelist-check", async () => {
 	
 
 		const investmentInfo = await program.account.investmentInfo.fetch(investmentInfoPda);
-		const withdrawWhitelist = investmentInfo.withdrawWhitelist;
+		const investmentConfig = await program.account.investmentConfig.fetch(investmentConfigPda);
+		const withdrawWhitelist = investmentConfig.withdrawWhitelist;
 		
 		let all_matched = true;
 		for(let i=0; i<withdrawWhitelist.length; i++) {
@@ -17,6 +17,7 @@
  */
 
 import fs from "fs";
+import crypto from "crypto";
 import path from "path";
 import * as Anchor from "@coral-xyz/anchor";
 import { PublicKey,	Keypair } from "@solana/web3.js";
@@ -68,6 +69,38 @@ export function u16ToLEBytes(n: number): Buffer {
   return buf;
 }
 
+/**
+ * Converts a 32-bit unsigned integer to little-endian byte representation
+ * 
+ * @param n - 32-bit unsigned integer
+ * @returns 4-byte buffer in little-endian format
+ * 
+ * @audit Used for Merkle leaf hashing and claim receipt seeds
+ */
+export function u32ToLEBytes(n: number): Buffer {
+  const buf = Buffer.alloc(4);
+  buf.writeUInt32LE(n, 0);
+  return buf;
+}
+
+/**
+ * Derives the program-wide InvestorDirectory PDA of an investor account ID
+ * 
+ * @param accountIdBytes - 15-byte investor account ID
+ * @param programId - H2Coin vault share program ID
+ * @returns InvestorDirectory PDA (seeds: "investor_directory", sha256(account_id))
+ * 
+ * @audit Anchor cannot resolve the hashed seed, so record creation passes this explicitly
+ */
+export function investorDirectoryPda(accountIdBytes: number[] | Uint8Array, programId: PublicKey): PublicKey {
+	const accountIdHash = crypto.createHash("sha256").update(Buffer.from(accountIdBytes)).digest();
+	const [pda] = PublicKey.findProgramAddressSync(
+		[Buffer.from("investor_directory"), accountIdHash],
+		programId
+	);
+	return pda;
+}
+
 /**
  * Converts a byte array to a fixed string, removing null terminators
  * 
@@ -51,6 +51,8 @@ const R:{
 	version?: number[];
 	investmentId?: number[];
 	investmentInfoPda?: PublicKey;
+	investmentConfigPda?: PublicKey;
+	globalConfigPda?: PublicKey;
 	usdt_mint?: PublicKey;
 	h2coin_mint?: PublicKey;
 	lookupTableMap?: Map<AltTableType, Map<number, PublicKey>>;
@@ -127,6 +129,36 @@ before(async()=>{
 	);
 	Runtime.investmentInfoPda = investmentInfoPda;
 
+	/**
+	 * Derive Investment Config PDA
+	 * Companion of the Investment Info PDA holding whitelists and stage ratios
+	 * 
+	 * @audit PDA derivation uses:
+	 * - "investment_config" seed for namespace separation
+	 * - investmentId and version, same as the Investment Info PDA
+	 */
+	const [investmentConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+		[
+			Buffer.from("investment_config"), 
+			Buffer.from(investmentId),
+			Buffer.from(version),
+		],
+		program.programId
+	);
+	Runtime.investmentConfigPda = investmentConfigPda;
+
+	/**
+	 * Derive Global Config PDA
+	 * Program-wide singleton holding the mints, SOL fee estimates and cache expiry
+	 * 
+	 * @audit Must be initialized (initialize_global_config) before any investment instruction
+	 */
+	const [globalConfigPda] = Anchor.web3.PublicKey.findProgramAddressSync(
+		[Buffer.from("global_config")],
+		program.programId
+	);
+	Runtime.globalConfigPda = globalConfigPda;
+
 	// Initialize lookup table maps for efficient address resolution
 	// @audit These maps improve transaction efficiency but should be validated
 	Runtime.lookupTableMap.set('record', new Map());